@@ -0,0 +1,186 @@
+//! Query-path benchmarks over synthetic data.
+//!
+//! Run with `cargo bench --bench query_path`. The perf budget each group is held
+//! to is listed in the README under "Performance budget"; a storage or query
+//! redesign should compare against these numbers on the same machine.
+
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use rustcost_core::core::persistence::metrics::k8s::node::hour::metric_node_hour_fs_adapter::MetricNodeHourFsAdapter;
+use rustcost_core::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use rustcost_core::core::persistence::metrics::k8s::pod::day::metric_pod_day_fs_adapter::MetricPodDayFsAdapter;
+use rustcost_core::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_fs_adapter::MetricPodHourFsAdapter;
+use rustcost_core::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use rustcost_core::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
+use rustcost_core::core::persistence::metrics::metric_store_trait::MetricStore;
+use rustcost_core::domain::metric::k8s::cluster::service::aggregate_cluster_points;
+use rustcost_core::domain::metric::k8s::common::dto::{
+    CommonMetricValuesDto, FilesystemMetricDto, NetworkMetricDto, UniversalMetricPointDto,
+};
+
+const POD: &str = "bench-pod";
+const DAYS: i64 = 7;
+const MIB: u64 = 1024 * 1024;
+
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap()
+}
+
+fn pod_row(time: DateTime<Utc>, i: u64) -> MetricPodEntity {
+    MetricPodEntity {
+        time,
+        cpu_usage_nano_cores: Some(100_000_000 + (i % 60) * 1_000_000),
+        cpu_usage_core_nano_seconds: Some(i * 6_000_000_000),
+        memory_usage_bytes: Some((256 + i % 32) * MIB),
+        memory_working_set_bytes: Some((200 + i % 32) * MIB),
+        memory_rss_bytes: Some(150 * MIB),
+        memory_page_faults: Some(i * 10),
+        network_physical_rx_bytes: Some(i * MIB),
+        network_physical_tx_bytes: Some(i * MIB / 2),
+        network_physical_rx_errors: Some(0),
+        network_physical_tx_errors: Some(0),
+        es_used_bytes: Some(100 * MIB),
+        es_capacity_bytes: Some(10_240 * MIB),
+        es_inodes_used: Some(500),
+        es_inodes: Some(1_000_000),
+        ..Default::default()
+    }
+}
+
+fn node_row(time: DateTime<Utc>, i: u64) -> MetricNodeEntity {
+    MetricNodeEntity {
+        time,
+        cpu_usage_nano_cores: Some(2_000_000_000 + (i % 60) * 10_000_000),
+        cpu_usage_core_nano_seconds: Some(i * 120_000_000_000),
+        memory_usage_bytes: Some((8_192 + i % 64) * MIB),
+        memory_working_set_bytes: Some((6_144 + i % 64) * MIB),
+        memory_rss_bytes: Some(4_096 * MIB),
+        memory_page_faults: Some(i * 100),
+        network_physical_rx_bytes: Some(i * 10 * MIB),
+        network_physical_tx_bytes: Some(i * 4 * MIB),
+        network_physical_rx_errors: Some(0),
+        network_physical_tx_errors: Some(0),
+        fs_used_bytes: Some(30_720 * MIB),
+        fs_capacity_bytes: Some(102_400 * MIB),
+        fs_inodes_used: Some(200_000),
+        fs_inodes: Some(6_000_000),
+        ..Default::default()
+    }
+}
+
+/// Seven days of minute rows and hour rows for one pod in a fresh data directory.
+fn seed_store() {
+    static SEEDED: OnceLock<()> = OnceLock::new();
+    SEEDED.get_or_init(|| {
+        let dir = env::temp_dir().join("rustcost_bench_base");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        env::set_var("RUSTCOST_BASE_PATH", &dir);
+
+        let now = Utc::now();
+        for i in 0..(DAYS * 24 * 60) as u64 {
+            let row = pod_row(start() + Duration::minutes(i as i64), i);
+            MetricPodMinuteFsAdapter.append_row(POD, &row, now).unwrap();
+        }
+        for i in 0..(DAYS * 24) as u64 {
+            let row = pod_row(start() + Duration::hours(i as i64), i);
+            MetricPodHourFsAdapter.append_row(POD, &row, now).unwrap();
+        }
+    });
+}
+
+fn bench_get_row_between(c: &mut Criterion) {
+    seed_store();
+    let mut group = c.benchmark_group("get_row_between");
+
+    for hours in [1i64, 24, DAYS * 24] {
+        group.bench_with_input(BenchmarkId::new("pod_minute", format!("{}h", hours)), &hours, |b, &hours| {
+            let end = start() + Duration::hours(hours);
+            b.iter(|| MetricPodMinuteFsAdapter.get_row_between(start(), end, POD, None, None).unwrap())
+        });
+    }
+    group.bench_function("pod_hour/7d", |b| {
+        let end = start() + Duration::days(DAYS);
+        b.iter(|| MetricPodHourFsAdapter.get_row_between(start(), end, POD, None, None).unwrap())
+    });
+
+    group.finish();
+}
+
+fn bench_aggregation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("aggregate_rows");
+    let hour_end = start() + Duration::hours(1);
+    let day_end = start() + Duration::days(1);
+
+    let minutes: Vec<MetricPodEntity> = (0..60).map(|i| pod_row(start() + Duration::minutes(i), i as u64)).collect();
+    group.bench_function("pod_minute_to_hour", |b| {
+        b.iter(|| MetricPodHourFsAdapter::aggregate_rows(black_box(minutes.clone()), start(), hour_end).unwrap())
+    });
+
+    let node_minutes: Vec<MetricNodeEntity> = (0..60).map(|i| node_row(start() + Duration::minutes(i), i as u64)).collect();
+    group.bench_function("node_minute_to_hour", |b| {
+        b.iter(|| MetricNodeHourFsAdapter::aggregate_rows(black_box(node_minutes.clone()), start(), hour_end).unwrap())
+    });
+
+    let hours: Vec<MetricPodEntity> = (0..24).map(|i| pod_row(start() + Duration::hours(i), i as u64)).collect();
+    group.bench_function("pod_hour_to_day", |b| {
+        b.iter(|| MetricPodDayFsAdapter::aggregate_rows(black_box(hours.clone()), start(), day_end).unwrap())
+    });
+
+    group.finish();
+}
+
+/// One hourly point per node over a week, as the cluster endpoints build them.
+fn cluster_points(nodes: u64) -> Vec<Vec<UniversalMetricPointDto>> {
+    (0..nodes)
+        .map(|n| {
+            (0..(DAYS * 24) as u64).map(move |h| UniversalMetricPointDto {
+                time: start() + Duration::hours(h as i64),
+                cpu_memory: CommonMetricValuesDto {
+                    cpu_usage_nano_cores: Some((2_000_000_000 + n * 1_000_000 + h) as f64),
+                    cpu_usage_core_nano_seconds: Some((h * 7_200_000_000_000) as f64),
+                    memory_usage_bytes: Some(((8_192 + n) * MIB) as f64),
+                    memory_working_set_bytes: Some(((6_144 + n) * MIB) as f64),
+                    memory_rss_bytes: Some((4_096 * MIB) as f64),
+                    memory_page_faults: Some((h * 100) as f64),
+                },
+                filesystem: Some(FilesystemMetricDto {
+                    used_bytes: Some((30_720 * MIB) as f64),
+                    capacity_bytes: Some((102_400 * MIB) as f64),
+                    inodes_used: Some(200_000.0),
+                    inodes: Some(6_000_000.0),
+                }),
+                network: Some(NetworkMetricDto {
+                    rx_bytes: Some((h * 10 * MIB) as f64),
+                    tx_bytes: Some((h * 4 * MIB) as f64),
+                    rx_errors: Some(0.0),
+                    tx_errors: Some(0.0),
+                }),
+                ..Default::default()
+            })
+            .collect()
+        })
+        .collect()
+}
+
+fn bench_cluster_points(c: &mut Criterion) {
+    let mut group = c.benchmark_group("aggregate_cluster_points");
+
+    for nodes in [10u64, 50, 200] {
+        let points = cluster_points(nodes);
+        group.bench_with_input(BenchmarkId::new("nodes_7d_hourly", nodes), &points, |b, points| {
+            b.iter(|| aggregate_cluster_points(black_box(points.clone()), Duration::hours(1)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_get_row_between, bench_aggregation, bench_cluster_points);
+criterion_main!(benches);
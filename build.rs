@@ -0,0 +1,10 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Use the vendored protoc so builds don't depend on a system install
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+
+    println!("cargo:rerun-if-changed=proto");
+    tonic_build::configure()
+        .build_client(false)
+        .compile_protos(&["proto/rustcost/v1/metrics.proto"], &["proto"])?;
+    Ok(())
+}
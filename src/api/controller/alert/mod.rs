@@ -0,0 +1,27 @@
+use axum::extract::State;
+use axum::{Extension, Json};
+use chrono::Utc;
+
+use crate::api::dto::ApiResponse;
+use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::app_state::AppState;
+use crate::domain::alert::dto::alert_firing_dto::AlertFiringResponseDto;
+use crate::errors::AppError;
+
+pub struct AlertController;
+
+impl AlertController {
+    pub async fn get_firing(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+    ) -> Result<Json<ApiResponse<AlertFiringResponseDto>>, AppError> {
+        // Rules are evaluated against node-level metrics
+        scope.require_unrestricted()?;
+        let firing = state.alert_rules.lock().unwrap().firing();
+        to_json(Ok(AlertFiringResponseDto {
+            evaluated_at: Utc::now(),
+            firing,
+        }))
+    }
+}
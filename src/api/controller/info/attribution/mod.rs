@@ -0,0 +1,60 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::attribution::attribution_rule_entity::{
+    AttributionRuleEntity, InfoAttributionRuleEntity,
+};
+use crate::domain::info::dto::info_attribution_rule_dto::{
+    InfoAttributionConflictReportDto, InfoAttributionPreviewDto, InfoAttributionPreviewRequest,
+    InfoAttributionRuleUpsertRequest,
+};
+use crate::errors::AppError;
+
+pub struct InfoAttributionRuleController;
+
+impl InfoAttributionRuleController {
+    pub async fn list_attribution_rules(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoAttributionRuleEntity>>, AppError> {
+        to_json(state.info_service.list_attribution_rules().await)
+    }
+
+    pub async fn create_attribution_rule(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoAttributionRuleUpsertRequest>,
+    ) -> Result<Json<ApiResponse<AttributionRuleEntity>>, AppError> {
+        to_json(state.info_service.create_attribution_rule(payload).await)
+    }
+
+    pub async fn update_attribution_rule(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Json(payload): Json<InfoAttributionRuleUpsertRequest>,
+    ) -> Result<Json<ApiResponse<AttributionRuleEntity>>, AppError> {
+        to_json(state.info_service.update_attribution_rule(id, payload).await)
+    }
+
+    pub async fn delete_attribution_rule(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.delete_attribution_rule(id).await)
+    }
+
+    pub async fn preview_attribution_rules(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoAttributionPreviewRequest>,
+    ) -> Result<Json<ApiResponse<InfoAttributionPreviewDto>>, AppError> {
+        to_json(state.info_service.preview_attribution_rules(state.clone(), payload).await)
+    }
+
+    pub async fn get_attribution_conflicts(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoAttributionConflictReportDto>>, AppError> {
+        to_json(state.info_service.get_attribution_conflicts(state.clone()).await)
+    }
+}
@@ -0,0 +1,29 @@
+use axum::extract::{Query, State};
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::dto::info_dto::{BillingImportQuery, BillingRangeQuery};
+use crate::api::dto::ApiResponse;
+use crate::api::util::json::to_json;
+use crate::app_state::AppState;
+use crate::core::persistence::info::billing::info_billing_day_entity::InfoBillingDayEntity;
+use crate::errors::AppError;
+
+pub struct InfoBillingController;
+
+impl InfoBillingController {
+    pub async fn import_billing(
+        State(state): State<AppState>,
+        Query(q): Query<BillingImportQuery>,
+        body: String,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.import_billing(q, body).await)
+    }
+
+    pub async fn list_billing(
+        State(state): State<AppState>,
+        Query(q): Query<BillingRangeQuery>,
+    ) -> Result<Json<ApiResponse<Vec<InfoBillingDayEntity>>>, AppError> {
+        to_json(state.info_service.list_billing(q).await)
+    }
+}
@@ -0,0 +1,27 @@
+use axum::extract::State;
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::carbon::info_carbon_entity::InfoCarbonEntity;
+use crate::domain::info::dto::info_carbon_upsert_request::InfoCarbonUpsertRequest;
+use crate::errors::AppError;
+
+pub struct InfoCarbonController;
+
+impl InfoCarbonController {
+    pub async fn get_info_carbon(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoCarbonEntity>>, AppError> {
+        to_json(state.info_service.get_info_carbon().await)
+    }
+
+    pub async fn upsert_info_carbon(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoCarbonUpsertRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.upsert_info_carbon(payload).await)
+    }
+}
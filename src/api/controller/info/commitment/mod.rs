@@ -0,0 +1,44 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::commitment::commitment_entity::{
+    CommitmentEntity, InfoCommitmentEntity,
+};
+use crate::domain::info::dto::info_commitment_dto::InfoCommitmentUpsertRequest;
+use crate::errors::AppError;
+
+pub struct InfoCommitmentController;
+
+impl InfoCommitmentController {
+    pub async fn list_commitments(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoCommitmentEntity>>, AppError> {
+        to_json(state.info_service.list_commitments().await)
+    }
+
+    pub async fn create_commitment(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoCommitmentUpsertRequest>,
+    ) -> Result<Json<ApiResponse<CommitmentEntity>>, AppError> {
+        to_json(state.info_service.create_commitment(payload).await)
+    }
+
+    pub async fn update_commitment(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Json(payload): Json<InfoCommitmentUpsertRequest>,
+    ) -> Result<Json<ApiResponse<CommitmentEntity>>, AppError> {
+        to_json(state.info_service.update_commitment(id, payload).await)
+    }
+
+    pub async fn delete_commitment(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.delete_commitment(id).await)
+    }
+}
@@ -0,0 +1,27 @@
+use axum::extract::State;
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::efficiency_target::info_efficiency_target_entity::InfoEfficiencyTargetEntity;
+use crate::domain::info::dto::info_efficiency_target_upsert_request::InfoEfficiencyTargetUpsertRequest;
+use crate::errors::AppError;
+
+pub struct InfoEfficiencyTargetController;
+
+impl InfoEfficiencyTargetController {
+    pub async fn get_info_efficiency_targets(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoEfficiencyTargetEntity>>, AppError> {
+        to_json(state.info_service.get_info_efficiency_targets().await)
+    }
+
+    pub async fn upsert_info_efficiency_targets(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoEfficiencyTargetUpsertRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.upsert_info_efficiency_targets(payload).await)
+    }
+}
@@ -0,0 +1,44 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::fixed_cost::fixed_cost_entity::{
+    FixedCostEntity, InfoFixedCostEntity,
+};
+use crate::domain::info::dto::info_fixed_cost_dto::InfoFixedCostUpsertRequest;
+use crate::errors::AppError;
+
+pub struct InfoFixedCostController;
+
+impl InfoFixedCostController {
+    pub async fn list_fixed_costs(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoFixedCostEntity>>, AppError> {
+        to_json(state.info_service.list_fixed_costs().await)
+    }
+
+    pub async fn create_fixed_cost(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoFixedCostUpsertRequest>,
+    ) -> Result<Json<ApiResponse<FixedCostEntity>>, AppError> {
+        to_json(state.info_service.create_fixed_cost(payload).await)
+    }
+
+    pub async fn update_fixed_cost(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Json(payload): Json<InfoFixedCostUpsertRequest>,
+    ) -> Result<Json<ApiResponse<FixedCostEntity>>, AppError> {
+        to_json(state.info_service.update_fixed_cost(id, payload).await)
+    }
+
+    pub async fn delete_fixed_cost(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.delete_fixed_cost(id).await)
+    }
+}
@@ -8,6 +8,7 @@ use crate::api::dto::ApiResponse;
 use crate::api::util::json::to_json;
 use crate::app_state::AppState;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_history_entity::InfoUnitPriceHistoryEntity;
 use crate::core::persistence::info::fixed::version::info_version_entity::InfoVersionEntity;
 use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
 use crate::errors::AppError;
@@ -28,6 +29,12 @@ impl InfoController {
         to_json(state.info_service.upsert_info_unit_prices(payload).await)
     }
 
+    pub async fn get_info_unit_price_history(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoUnitPriceHistoryEntity>>, AppError> {
+        to_json(state.info_service.get_info_unit_price_history().await)
+    }
+
     pub async fn get_info_versions(
         State(state): State<AppState>,
     ) -> Result<Json<ApiResponse<InfoVersionEntity>>, AppError> {
@@ -1,9 +1,12 @@
-use axum::extract::{State};
+use axum::extract::{Path, State};
 use axum::Json;
+use serde_json::Value;
 
 use crate::api::util::json::to_json;
 use crate::api::dto::ApiResponse;
 use crate::app_state::AppState;
+use crate::core::persistence::info::k8s::namespace::info_namespace_entity::InfoNamespaceEntity;
+use crate::domain::info::dto::info_k8s_namespace_patch_request::InfoK8sNamespacePatchRequest;
 use crate::errors::AppError;
 
 pub struct InfoK8sNamespaceController;
@@ -14,4 +17,32 @@ impl InfoK8sNamespaceController {
     ) -> Result<Json<ApiResponse<serde_json::Value>>, AppError> {
         to_json(state.info_k8s_service.get_k8s_namespaces().await)
     }
+
+    pub async fn list_info_k8s_namespaces(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<Vec<InfoNamespaceEntity>>>, AppError> {
+        to_json(state.info_k8s_service.list_info_k8s_namespaces().await)
+    }
+
+    pub async fn get_info_k8s_namespace(
+        State(state): State<AppState>,
+        Path(name): Path<String>,
+    ) -> Result<Json<ApiResponse<InfoNamespaceEntity>>, AppError> {
+        to_json(state.info_k8s_service.get_info_k8s_namespace(name).await)
+    }
+
+    pub async fn patch_info_k8s_namespace(
+        State(state): State<AppState>,
+        Path(name): Path<String>,
+        Json(payload): Json<InfoK8sNamespacePatchRequest>,
+    ) -> Result<Json<ApiResponse<InfoNamespaceEntity>>, AppError> {
+        to_json(state.info_k8s_service.patch_info_k8s_namespace(name, payload).await)
+    }
+
+    pub async fn delete_info_k8s_namespace(
+        State(state): State<AppState>,
+        Path(name): Path<String>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_k8s_service.delete_info_k8s_namespace(name).await)
+    }
 }
@@ -9,6 +9,9 @@ use crate::api::dto::k8s_pod_query_request_dto::K8sPodQueryRequestDto;
 use crate::api::dto::paginated_response::PaginatedResponse;
 use crate::app_state::AppState;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::domain::info::dto::info_k8s_pod_bulk_patch_request::{
+    InfoK8sPodBulkPatchRequest, InfoK8sPodBulkPatchResponseDto,
+};
 use crate::domain::info::dto::info_k8s_pod_patch_request::InfoK8sPodPatchRequest;
 use crate::errors::AppError;
 use k8s_openapi::api::core::v1::Pod;
@@ -41,6 +44,13 @@ impl InfoK8sPodController {
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         to_json(state.info_k8s_service.patch_info_k8s_pod(id, payload).await)
     }
+
+    pub async fn bulk_patch_info_k8s_pods(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoK8sPodBulkPatchRequest>,
+    ) -> Result<Json<ApiResponse<InfoK8sPodBulkPatchResponseDto>>, AppError> {
+        to_json(state.info_k8s_service.bulk_patch_info_k8s_pods(payload).await)
+    }
 }
 
 impl InfoK8sLivePodController {
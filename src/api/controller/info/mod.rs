@@ -1,5 +1,12 @@
 pub mod setting;
 pub mod alerts;
+pub mod attribution;
+pub mod billing;
+pub mod carbon;
+pub mod efficiency_target;
+pub mod commitment;
+pub mod virtual_node_price;
+pub mod fixed_cost;
 pub mod llm;
 pub mod info_controller;
 pub mod k8s;
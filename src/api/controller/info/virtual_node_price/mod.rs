@@ -0,0 +1,27 @@
+use axum::extract::State;
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_entity::InfoVirtualNodePriceEntity;
+use crate::domain::info::dto::info_virtual_node_price_upsert_request::InfoVirtualNodePriceUpsertRequest;
+use crate::errors::AppError;
+
+pub struct InfoVirtualNodePriceController;
+
+impl InfoVirtualNodePriceController {
+    pub async fn get_info_virtual_node_prices(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoVirtualNodePriceEntity>>, AppError> {
+        to_json(state.info_service.get_info_virtual_node_prices().await)
+    }
+
+    pub async fn upsert_info_virtual_node_prices(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoVirtualNodePriceUpsertRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.upsert_info_virtual_node_prices(payload).await)
+    }
+}
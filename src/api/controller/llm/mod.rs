@@ -1,12 +1,17 @@
-use axum::extract::State;
-use axum::Json;
+use axum::extract::{Path, State};
+use axum::{Extension, Json};
 use serde_json::Value;
 
 use crate::api::dto::ApiResponse;
 use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
 use crate::app_state::AppState;
 use crate::domain::llm::dto::llm_chat_request::LlmChatRequest;
 use crate::domain::llm::dto::llm_chat_with_context_request::LlmChatWithContextRequest;
+use crate::core::persistence::info::llm_conversation::llm_conversation_entity::LlmConversationEntity;
+use crate::domain::llm::dto::llm_conversation_dto::{
+    LlmConversationCreateRequest, LlmConversationMessageRequest, LlmConversationSummaryDto,
+};
 use crate::errors::AppError;
 
 pub struct LlmController;
@@ -21,8 +26,61 @@ impl LlmController {
 
     pub async fn chat_with_context(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Json(payload): Json<LlmChatWithContextRequest>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
-        to_json(state.llm_service.chat_with_context(payload).await)
+        // The context is built from cluster-wide metrics
+        scope.require_unrestricted()?;
+        if payload.enable_tools {
+            state.k8s_state.ensure_resynced().await?;
+        }
+        to_json(state.llm_service.chat_with_context(state.clone(), payload).await)
+    }
+
+    // Conversations are shared and may quote cluster-wide data, like chat-with-context
+
+    pub async fn create_conversation(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Json(req): Json<LlmConversationCreateRequest>,
+    ) -> Result<Json<ApiResponse<LlmConversationEntity>>, AppError> {
+        scope.require_unrestricted()?;
+        to_json(state.llm_service.create_conversation(req).await)
+    }
+
+    pub async fn list_conversations(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+    ) -> Result<Json<ApiResponse<Vec<LlmConversationSummaryDto>>>, AppError> {
+        scope.require_unrestricted()?;
+        to_json(state.llm_service.list_conversations().await)
+    }
+
+    pub async fn get_conversation(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(id): Path<String>,
+    ) -> Result<Json<ApiResponse<LlmConversationEntity>>, AppError> {
+        scope.require_unrestricted()?;
+        to_json(state.llm_service.get_conversation(id).await)
+    }
+
+    pub async fn append_conversation_message(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(id): Path<String>,
+        Json(req): Json<LlmConversationMessageRequest>,
+    ) -> Result<Json<ApiResponse<LlmConversationEntity>>, AppError> {
+        scope.require_unrestricted()?;
+        to_json(state.llm_service.append_conversation_message(id, req).await)
+    }
+
+    pub async fn delete_conversation(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(id): Path<String>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        to_json(state.llm_service.delete_conversation(id).await)
     }
 }
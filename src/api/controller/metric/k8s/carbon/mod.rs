@@ -0,0 +1,80 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+
+/// Estimated energy and carbon next to cost. Node and cluster views need an
+/// unrestricted key, like their cost counterparts.
+pub struct K8sCarbonMetricsController;
+
+impl K8sCarbonMetricsController {
+    pub async fn get_metric_k8s_cluster_carbon(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_cluster_carbon(q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodes_carbon(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodes_carbon(q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_node_carbon(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(node_name): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        to_json(state.metric_service.get_metric_k8s_node_carbon(node_name, q).await)
+    }
+
+    pub async fn get_metric_k8s_namespaces_carbon(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let ns_names = scope.namespaces(&state).await;
+        to_json(state.metric_service.get_metric_k8s_namespaces_carbon(q, ns_names).await)
+    }
+
+    pub async fn get_metric_k8s_namespace_carbon(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(namespace): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.check_namespace(&namespace)?;
+        to_json(state.metric_service.get_metric_k8s_namespace_carbon(namespace, q).await)
+    }
+
+    pub async fn get_metric_k8s_pod_carbon(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(pod_uid): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_pod(&state, &pod_uid).await?;
+        to_json(state.metric_service.get_metric_k8s_pod_carbon(pod_uid, q).await)
+    }
+}
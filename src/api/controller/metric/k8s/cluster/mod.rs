@@ -1,8 +1,9 @@
 use axum::extract::{Query, State};
-use axum::Json;
+use axum::{Extension, Json};
 use serde_json::Value;
-use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::api::dto::{info_dto::BillingRangeQuery, metrics_dto::{CostCompareQuery, RangeQuery}, ApiResponse};
 use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
 use crate::app_state::AppState;
 use crate::errors::AppError;
 
@@ -11,9 +12,11 @@ pub struct K8sClusterMetricsController;
 impl K8sClusterMetricsController {
     pub async fn get_metric_k8s_cluster_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
 
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
 
@@ -27,9 +30,11 @@ impl K8sClusterMetricsController {
 
     pub async fn get_metric_k8s_cluster_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
 
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
 
@@ -43,9 +48,11 @@ impl K8sClusterMetricsController {
 
     pub async fn get_metric_k8s_cluster_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
 
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
 
@@ -54,9 +61,11 @@ impl K8sClusterMetricsController {
 
     pub async fn get_metric_k8s_cluster_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
 
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
 
@@ -70,9 +79,11 @@ impl K8sClusterMetricsController {
 
     pub async fn get_metric_k8s_cluster_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
 
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
 
@@ -84,11 +95,85 @@ impl K8sClusterMetricsController {
         )
     }
 
+    pub async fn get_metric_k8s_cluster_cost_events(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cluster_cost_events(q, node_names)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_cluster_cost_by_account(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cluster_cost_by_account(q, node_names)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_cost_reconcile(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cost_reconcile(q, node_names)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_cluster_cost_billing_reconcile(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<BillingRangeQuery>
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cluster_cost_billing_reconcile(q, node_names)
+                .await,
+        )
+    }
+
     pub async fn get_metric_k8s_cluster_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
 
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
 
@@ -99,4 +184,40 @@ impl K8sClusterMetricsController {
                 .await,
         )
     }
+
+    pub async fn get_metric_k8s_cluster_raw_efficiency_trend(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cluster_raw_efficiency_trend(q, node_names)
+                .await,
+        )
+    }
+
+    /// Cost summary for the query window next to a baseline window, with deltas.
+    pub async fn get_metric_k8s_cluster_cost_compare(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(cmp): Query<CostCompareQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cluster_cost_compare(q, cmp, node_names)
+                .await,
+        )
+    }
 }
@@ -1,11 +1,14 @@
 use axum::{
     extract::{Path, Query, State},
-    Json,
+    response::Response,
+    Extension, Json,
 };
 use serde_json::Value;
 
 use crate::api::util::json::to_json;
-use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::api::util::ndjson::to_ndjson;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::{CostCompareQuery, RangeQuery}, ApiResponse};
 use crate::app_state::AppState;
 use crate::errors::AppError;
 
@@ -14,10 +17,11 @@ pub struct K8sContainerMetricsController;
 impl K8sContainerMetricsController {
     pub async fn get_metric_k8s_containers_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let container_keys = state.k8s_state.get_container_keys().await;
+        let container_keys = scope.container_keys(&state).await;
         to_json(
             state
                 .metric_service
@@ -26,12 +30,29 @@ impl K8sContainerMetricsController {
         )
     }
 
+    /// NDJSON variant of [`Self::get_metric_k8s_containers_raw`] for long windows.
+    pub async fn get_metric_k8s_containers_raw_stream(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Response, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let container_keys = scope.container_keys(&state).await;
+        to_ndjson(
+            state
+                .metric_service
+                .stream_metric_k8s_containers_raw(q, container_keys)
+                .await,
+        )
+    }
+
     pub async fn get_metric_k8s_containers_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let container_keys = state.k8s_state.get_container_keys().await;
+        let container_keys = scope.container_keys(&state).await;
         to_json(
             state
                 .metric_service
@@ -42,10 +63,11 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_containers_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let container_keys = state.k8s_state.get_container_keys().await;
+        let container_keys = scope.container_keys(&state).await;
         to_json(
             state
                 .metric_service
@@ -56,10 +78,12 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_container_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(id): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_container(&state, &id).await?;
         to_json(
             state
                 .metric_service
@@ -70,10 +94,12 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_container_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(id): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_container(&state, &id).await?;
         to_json(
             state
                 .metric_service
@@ -84,10 +110,12 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_container_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(id): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_container(&state, &id).await?;
         to_json(
             state
                 .metric_service
@@ -98,10 +126,11 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_containers_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let container_keys = state.k8s_state.get_container_keys().await;
+        let container_keys = scope.container_keys(&state).await;
         to_json(
             state
                 .metric_service
@@ -112,10 +141,11 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_containers_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let container_keys = state.k8s_state.get_container_keys().await;
+        let container_keys = scope.container_keys(&state).await;
         to_json(
             state
                 .metric_service
@@ -126,10 +156,11 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_containers_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let container_keys = state.k8s_state.get_container_keys().await;
+        let container_keys = scope.container_keys(&state).await;
         to_json(
             state
                 .metric_service
@@ -140,10 +171,12 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_container_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(id): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_container(&state, &id).await?;
         to_json(
             state
                 .metric_service
@@ -154,10 +187,12 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_container_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(id): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_container(&state, &id).await?;
         to_json(
             state
                 .metric_service
@@ -168,10 +203,12 @@ impl K8sContainerMetricsController {
 
     pub async fn get_metric_k8s_container_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(id): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_container(&state, &id).await?;
         to_json(
             state
                 .metric_service
@@ -179,4 +216,21 @@ impl K8sContainerMetricsController {
                 .await,
         )
     }
+
+    /// Cost summary for the query window next to a baseline window, with deltas.
+    pub async fn get_metric_k8s_containers_cost_compare(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(cmp): Query<CostCompareQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let container_keys = scope.container_keys(&state).await;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_containers_cost_compare(q, cmp, container_keys)
+                .await,
+        )
+    }
 }
@@ -1,11 +1,12 @@
 use axum::{
     extract::{Path, Query, State},
-    Json,
+    Extension, Json,
 };
 use serde_json::Value;
 
 use crate::api::util::json::to_json;
-use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::{CostCompareQuery, RangeQuery}, ApiResponse};
 use crate::app_state::AppState;
 use crate::errors::AppError;
 
@@ -14,10 +15,11 @@ pub struct K8sDeploymentMetricsController;
 impl K8sDeploymentMetricsController {
     pub async fn get_metric_k8s_deployments_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let deployment_names = state.k8s_state.get_deployments().await;
+        let deployment_names = scope.deployments(&state).await;
         to_json(
             state
                 .metric_service
@@ -28,10 +30,11 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployments_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let deployment_names = state.k8s_state.get_deployments().await;
+        let deployment_names = scope.deployments(&state).await;
         to_json(
             state
                 .metric_service
@@ -42,10 +45,11 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployments_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let deployment_names = state.k8s_state.get_deployments().await;
+        let deployment_names = scope.deployments(&state).await;
         to_json(
             state
                 .metric_service
@@ -56,10 +60,12 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployment_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(deployment): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_deployment(&state, &deployment).await?;
         to_json(
             state
                 .metric_service
@@ -70,10 +76,12 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployment_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(deployment): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_deployment(&state, &deployment).await?;
         to_json(
             state
                 .metric_service
@@ -84,10 +92,12 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployment_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(deployment): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_deployment(&state, &deployment).await?;
         to_json(
             state
                 .metric_service
@@ -98,10 +108,11 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployments_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let deployment_names = state.k8s_state.get_deployments().await;
+        let deployment_names = scope.deployments(&state).await;
         to_json(
             state
                 .metric_service
@@ -112,10 +123,11 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployments_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let deployment_names = state.k8s_state.get_deployments().await;
+        let deployment_names = scope.deployments(&state).await;
         to_json(
             state
                 .metric_service
@@ -126,10 +138,11 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployments_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let deployment_names = state.k8s_state.get_deployments().await;
+        let deployment_names = scope.deployments(&state).await;
         to_json(
             state
                 .metric_service
@@ -140,10 +153,12 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployment_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(deployment): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_deployment(&state, &deployment).await?;
         to_json(
             state
                 .metric_service
@@ -154,10 +169,12 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployment_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(deployment): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_deployment(&state, &deployment).await?;
         to_json(
             state
                 .metric_service
@@ -168,10 +185,12 @@ impl K8sDeploymentMetricsController {
 
     pub async fn get_metric_k8s_deployment_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(deployment): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_deployment(&state, &deployment).await?;
         to_json(
             state
                 .metric_service
@@ -179,4 +198,21 @@ impl K8sDeploymentMetricsController {
                 .await,
         )
     }
+
+    /// Cost summary for the query window next to a baseline window, with deltas.
+    pub async fn get_metric_k8s_deployments_cost_compare(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(cmp): Query<CostCompareQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let deployment_names = scope.deployments(&state).await;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_deployments_cost_compare(q, cmp, deployment_names)
+                .await,
+        )
+    }
 }
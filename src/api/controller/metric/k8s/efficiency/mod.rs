@@ -0,0 +1,37 @@
+use axum::{
+    extract::{Query, State},
+    Extension, Json,
+};
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::{EfficiencyScoreQuery, RangeQuery}, ApiResponse};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+
+/// Team / namespace efficiency grades. Restricted keys only see the
+/// namespaces they may read.
+pub struct K8sEfficiencyMetricsController;
+
+impl K8sEfficiencyMetricsController {
+    pub async fn get_metric_k8s_efficiency_scores(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(score): Query<EfficiencyScoreQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let ns_names = scope.namespaces(&state).await;
+        let scores = state
+            .metric_service
+            .get_metric_k8s_efficiency_scores(q, ns_names, score.group_by.unwrap_or_default())
+            .await;
+        // Explained after the cache so model output is never cached with the scores
+        let scores = match scores {
+            Ok(v) if score.explain.unwrap_or(false) => state.metric_service.explain_metric_k8s_efficiency_scores(v).await,
+            other => other,
+        };
+        to_json(scores)
+    }
+}
@@ -0,0 +1,160 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+
+/// Jobs and CronJobs are namespaced, so a namespace check is enough for scoped keys.
+pub struct K8sJobMetricsController;
+
+impl K8sJobMetricsController {
+    pub async fn get_metric_k8s_job_raw(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_job_raw(namespace, name, q)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_job_cost(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_job_cost(namespace, name, q)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_job_cost_summary(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_job_cost_summary(namespace, name, q)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_job_cost_trend(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_job_cost_trend(namespace, name, q)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_cronjob_raw(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cronjob_raw(namespace, name, q)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_cronjob_cost(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cronjob_cost(namespace, name, q)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_cronjob_cost_summary(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cronjob_cost_summary(namespace, name, q)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_cronjob_cost_trend(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cronjob_cost_trend(namespace, name, q)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_cronjob_cost_runs(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cronjob_cost_runs(namespace, name, q)
+                .await,
+        )
+    }
+}
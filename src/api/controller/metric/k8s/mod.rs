@@ -1,6 +1,13 @@
+pub mod carbon;
 pub mod cluster;
 pub mod container;
 pub mod deployment;
+pub mod efficiency;
+pub mod job;
 pub mod namespace;
+pub mod network;
 pub mod node;
-pub mod pod;
\ No newline at end of file
+pub mod node_pool;
+pub mod pod;
+pub mod pvc;
+pub mod query;
@@ -1,23 +1,43 @@
 use axum::{
     extract::{Path, Query, State},
-    Json,
+    Extension, Json,
 };
 use serde_json::Value;
 
 use crate::api::util::json::to_json;
-use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::{CostCompareQuery, NamespaceListQuery, RangeQuery}, ApiResponse};
 use crate::app_state::AppState;
 use crate::errors::AppError;
 
 pub struct K8sNamespaceMetricsController;
 
+/// Namespaces a list view covers: the requested ones (each must be in the caller's
+/// scope), or every namespace the caller may see when none were requested.
+/// Requested namespaces need not exist any more, so deleted ones keep their history.
+async fn target_namespaces(
+    state: &AppState,
+    scope: &TenantScope,
+    filter: NamespaceListQuery,
+) -> Result<Vec<String>, AppError> {
+    if filter.namespaces.is_empty() {
+        return Ok(scope.namespaces(state).await);
+    }
+    for ns in &filter.namespaces {
+        scope.check_namespace(ns)?;
+    }
+    Ok(filter.namespaces)
+}
+
 impl K8sNamespaceMetricsController {
     pub async fn get_metric_k8s_namespaces_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<NamespaceListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let ns_names = state.k8s_state.get_namespaces().await;
+        let ns_names = target_namespaces(&state, &scope, filter).await?;
         to_json(
             state
                 .metric_service
@@ -28,10 +48,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespaces_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<NamespaceListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let ns_names = state.k8s_state.get_namespaces().await;
+        let ns_names = target_namespaces(&state, &scope, filter).await?;
         to_json(
             state
                 .metric_service
@@ -42,10 +64,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespaces_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<NamespaceListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let ns_names = state.k8s_state.get_namespaces().await;
+        let ns_names = target_namespaces(&state, &scope, filter).await?;
         to_json(
             state
                 .metric_service
@@ -56,10 +80,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespace_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(namespace): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
         to_json(
             state
                 .metric_service
@@ -70,10 +96,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespace_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(namespace): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
         to_json(
             state
                 .metric_service
@@ -84,10 +112,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespace_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(namespace): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
         to_json(
             state
                 .metric_service
@@ -98,10 +128,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespaces_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<NamespaceListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let ns_names = state.k8s_state.get_namespaces().await;
+        let ns_names = target_namespaces(&state, &scope, filter).await?;
         to_json(
             state
                 .metric_service
@@ -112,10 +144,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespaces_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<NamespaceListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let ns_names = state.k8s_state.get_namespaces().await;
+        let ns_names = target_namespaces(&state, &scope, filter).await?;
         to_json(
             state
                 .metric_service
@@ -126,10 +160,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespaces_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<NamespaceListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
-        let ns_names = state.k8s_state.get_namespaces().await;
+        let ns_names = target_namespaces(&state, &scope, filter).await?;
         to_json(
             state
                 .metric_service
@@ -140,10 +176,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespace_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(namespace): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
         to_json(
             state
                 .metric_service
@@ -154,10 +192,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespace_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(namespace): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
         to_json(
             state
                 .metric_service
@@ -168,10 +208,12 @@ impl K8sNamespaceMetricsController {
 
     pub async fn get_metric_k8s_namespace_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(namespace): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_namespace(&namespace)?;
         to_json(
             state
                 .metric_service
@@ -179,4 +221,22 @@ impl K8sNamespaceMetricsController {
                 .await,
         )
     }
+
+    /// Cost summary for the query window next to a baseline window, with deltas.
+    pub async fn get_metric_k8s_namespaces_cost_compare(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(cmp): Query<CostCompareQuery>,
+        Query(filter): Query<NamespaceListQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let ns_names = target_namespaces(&state, &scope, filter).await?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_namespaces_cost_compare(q, cmp, ns_names)
+                .await,
+        )
+    }
 }
@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+
+/// Namespace egress by destination: measured by the optional network flow source, or
+/// estimated across zones from pod placement.
+pub struct K8sNetworkMetricsController;
+
+async fn visible_namespaces(state: &AppState, scope: &TenantScope) -> Option<Vec<String>> {
+    if scope.is_restricted() {
+        Some(scope.namespaces(state).await)
+    } else {
+        None
+    }
+}
+
+impl K8sNetworkMetricsController {
+    pub async fn get_metric_k8s_network_flows(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        let namespaces = visible_namespaces(&state, &scope).await;
+        to_json(state.metric_service.get_metric_k8s_network_flows(q, namespaces).await)
+    }
+
+    pub async fn get_metric_k8s_namespace_network_flows(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(namespace): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.check_namespace(&namespace)?;
+        to_json(state.metric_service.get_metric_k8s_namespace_network_flows(namespace, q).await)
+    }
+
+    pub async fn get_metric_k8s_network_cross_zone_cost(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        let namespaces = visible_namespaces(&state, &scope).await;
+        to_json(state.metric_service.get_metric_k8s_network_cross_zone_cost(q, namespaces).await)
+    }
+}
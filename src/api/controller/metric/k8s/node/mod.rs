@@ -1,9 +1,13 @@
 use axum::extract::{Path, Query, State};
+use axum::Extension;
 use axum::Json;
+use axum::response::Response;
 use serde_json::Value;
 
 use crate::api::util::json::to_json;
-use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::api::util::ndjson::to_ndjson;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::{CostCompareQuery, RangeQuery}, ApiResponse};
 use crate::app_state::AppState;
 use crate::errors::AppError;
 
@@ -12,17 +16,33 @@ pub struct K8sNodeMetricsController;
 impl K8sNodeMetricsController {
     pub async fn get_metric_k8s_nodes_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
         to_json(state.metric_service.get_metric_k8s_nodes_raw(q, node_names).await)
     }
 
+    /// NDJSON variant of [`Self::get_metric_k8s_nodes_raw`] for long windows.
+    pub async fn get_metric_k8s_nodes_raw_stream(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Response, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_ndjson(state.metric_service.stream_metric_k8s_nodes_raw(q, node_names).await)
+    }
+
     pub async fn get_metric_k8s_nodes_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
         to_json(
@@ -35,8 +55,10 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_nodes_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
         to_json(
@@ -49,9 +71,11 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_node_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(node_name): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         to_json(
             state
@@ -63,9 +87,11 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_node_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(node_name): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         to_json(
             state
@@ -77,9 +103,11 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_node_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(node_name): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         to_json(
             state
@@ -91,8 +119,10 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_nodes_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
         to_json(state.metric_service.get_metric_k8s_nodes_cost(q, node_names).await)
@@ -100,8 +130,10 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_nodes_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
         to_json(
@@ -114,8 +146,10 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_nodes_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         let node_names = state.k8s_state.get_nodes().await;
         to_json(
@@ -128,9 +162,11 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_node_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(node_name): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         to_json(
             state
@@ -142,9 +178,11 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_node_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(node_name): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         to_json(
             state
@@ -156,9 +194,11 @@ impl K8sNodeMetricsController {
 
     pub async fn get_metric_k8s_node_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(node_name): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
         state.k8s_state.ensure_resynced().await?;
         to_json(
             state
@@ -167,4 +207,22 @@ impl K8sNodeMetricsController {
                 .await,
         )
     }
+
+    /// Cost summary for the query window next to a baseline window, with deltas.
+    pub async fn get_metric_k8s_nodes_cost_compare(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(cmp): Query<CostCompareQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_nodes_cost_compare(q, cmp, node_names)
+                .await,
+        )
+    }
 }
@@ -0,0 +1,170 @@
+use axum::extract::{Path, Query, State};
+use axum::Extension;
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::{CostCompareQuery, RangeQuery}, ApiResponse};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+
+pub struct K8sNodePoolMetricsController;
+
+impl K8sNodePoolMetricsController {
+    pub async fn get_metric_k8s_nodepools_raw(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepools_raw(q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodepools_raw_efficiency(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepools_raw_efficiency(q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodepools_cost(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepools_cost(q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodepools_cost_summary(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepools_cost_summary(q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodepools_cost_trend(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepools_cost_trend(q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodepool_raw(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(pool): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepool_raw(pool, q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodepool_raw_efficiency(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(pool): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepool_raw_efficiency(pool, q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodepool_cost(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(pool): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepool_cost(pool, q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodepool_cost_summary(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(pool): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepool_cost_summary(pool, q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodepool_cost_trend(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(pool): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodepool_cost_trend(pool, q, node_names).await)
+    }
+
+    /// Node cost series per operating system (`linux`, `windows`, ...).
+    pub async fn get_metric_k8s_nodes_os_cost(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodes_os_cost(q, node_names).await)
+    }
+
+    pub async fn get_metric_k8s_nodes_os_cost_summary(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(state.metric_service.get_metric_k8s_nodes_os_cost_summary(q, node_names).await)
+    }
+
+    /// Cost summary for the query window next to a baseline window, with deltas.
+    pub async fn get_metric_k8s_nodepools_cost_compare(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(cmp): Query<CostCompareQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.require_unrestricted()?;
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_nodepools_cost_compare(q, cmp, node_names)
+                .await,
+        )
+    }
+}
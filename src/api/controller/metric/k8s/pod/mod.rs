@@ -1,41 +1,74 @@
 use axum::extract::{Path, Query, State};
+use axum::Extension;
 use axum::Json;
+use axum::response::Response;
 use serde_json::Value;
 
 use crate::api::util::json::to_json;
-use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::api::util::ndjson::to_ndjson;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::{CostCompareQuery, PodListQuery, PodListRequest, RangeQuery}, ApiResponse};
 use crate::app_state::AppState;
 use crate::errors::AppError;
 
 pub struct K8sPodMetricsController;
 
+/// Pod UIDs a list view covers: `key`, else the requested UIDs, else every pod the
+/// caller may see. Explicit UIDs must all be inside the caller's scope.
+async fn target_pod_uids(
+    state: &AppState,
+    scope: &TenantScope,
+    q: &RangeQuery,
+    requested: Vec<String>,
+) -> Result<Vec<String>, AppError> {
+    if let Some(key) = &q.key {
+        scope.check_pod(state, key).await?;
+        return Ok(vec![key.to_string()]);
+    }
+    if requested.is_empty() {
+        return Ok(scope.pod_uids(state).await);
+    }
+    scope.check_pods(state, &requested).await?;
+    Ok(requested)
+}
+
 impl K8sPodMetricsController {
     pub async fn get_metric_k8s_pods_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<PodListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
 
-        let pod_uids = if let Some(key) = &q.key {
-            vec![key.to_string()]       // or whatever q.key represents
-        } else {
-            state.k8s_state.get_pods().await
-        };
+        let pod_uids = target_pod_uids(&state, &scope, &q, filter.pod_uids).await?;
 
         to_json(state.metric_service.get_metric_k8s_pods_raw(q, pod_uids).await)
     }
 
+    /// NDJSON variant of [`Self::get_metric_k8s_pods_raw`] for long windows.
+    pub async fn get_metric_k8s_pods_raw_stream(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(filter): Query<PodListQuery>,
+    ) -> Result<Response, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+
+        let pod_uids = target_pod_uids(&state, &scope, &q, filter.pod_uids).await?;
+
+        to_ndjson(state.metric_service.stream_metric_k8s_pods_raw(q, pod_uids).await)
+    }
+
     pub async fn get_metric_k8s_pods_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<PodListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
 
-        let pod_uids = if let Some(key) = &q.key {
-            vec![key.to_string()]       // or whatever q.key represents
-        } else {
-            state.k8s_state.get_pods().await
-        };
+        let pod_uids = target_pod_uids(&state, &scope, &q, filter.pod_uids).await?;
         to_json(
             state
                 .metric_service
@@ -46,15 +79,13 @@ impl K8sPodMetricsController {
 
     pub async fn get_metric_k8s_pods_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<PodListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
 
-        let pod_uids = if let Some(key) = &q.key {
-            vec![key.to_string()]       // or whatever q.key represents
-        } else {
-            state.k8s_state.get_pods().await
-        };
+        let pod_uids = target_pod_uids(&state, &scope, &q, filter.pod_uids).await?;
         to_json(
             state
                 .metric_service
@@ -65,10 +96,12 @@ impl K8sPodMetricsController {
 
     pub async fn get_metric_k8s_pod_raw(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(pod_uid): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_pod(&state, &pod_uid).await?;
         to_json(
             state
                 .metric_service
@@ -79,10 +112,12 @@ impl K8sPodMetricsController {
 
     pub async fn get_metric_k8s_pod_raw_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(pod_uid): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_pod(&state, &pod_uid).await?;
         to_json(
             state
                 .metric_service
@@ -93,10 +128,12 @@ impl K8sPodMetricsController {
 
     pub async fn get_metric_k8s_pod_raw_efficiency(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(pod_uid): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_pod(&state, &pod_uid).await?;
         to_json(
             state
                 .metric_service
@@ -107,29 +144,25 @@ impl K8sPodMetricsController {
 
     pub async fn get_metric_k8s_pods_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<PodListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
 
-        let pod_uids = if let Some(key) = &q.key {
-            vec![key.to_string()]       // or whatever q.key represents
-        } else {
-            state.k8s_state.get_pods().await
-        };
+        let pod_uids = target_pod_uids(&state, &scope, &q, filter.pod_uids).await?;
         to_json(state.metric_service.get_metric_k8s_pods_cost(q, pod_uids).await)
     }
 
     pub async fn get_metric_k8s_pods_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<PodListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
 
-        let pod_uids = if let Some(key) = &q.key {
-            vec![key.to_string()]       // or whatever q.key represents
-        } else {
-            state.k8s_state.get_pods().await
-        };
+        let pod_uids = target_pod_uids(&state, &scope, &q, filter.pod_uids).await?;
         to_json(
             state
                 .metric_service
@@ -140,15 +173,13 @@ impl K8sPodMetricsController {
 
     pub async fn get_metric_k8s_pods_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Query(q): Query<RangeQuery>,
+        Query(filter): Query<PodListQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
 
-        let pod_uids = if let Some(key) = &q.key {
-            vec![key.to_string()]       // or whatever q.key represents
-        } else {
-            state.k8s_state.get_pods().await
-        };
+        let pod_uids = target_pod_uids(&state, &scope, &q, filter.pod_uids).await?;
         to_json(
             state
                 .metric_service
@@ -159,10 +190,12 @@ impl K8sPodMetricsController {
 
     pub async fn get_metric_k8s_pod_cost(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(pod_uid): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_pod(&state, &pod_uid).await?;
         to_json(
             state
                 .metric_service
@@ -173,10 +206,12 @@ impl K8sPodMetricsController {
 
     pub async fn get_metric_k8s_pod_cost_summary(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(pod_uid): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_pod(&state, &pod_uid).await?;
         to_json(
             state
                 .metric_service
@@ -187,10 +222,12 @@ impl K8sPodMetricsController {
 
     pub async fn get_metric_k8s_pod_cost_trend(
         State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
         Path(pod_uid): Path<String>,
         Query(q): Query<RangeQuery>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
         state.k8s_state.ensure_resynced().await?;
+        scope.check_pod(&state, &pod_uid).await?;
         to_json(
             state
                 .metric_service
@@ -198,4 +235,125 @@ impl K8sPodMetricsController {
                 .await,
         )
     }
+
+    /// Pod cost split per container, to spot expensive sidecars.
+    pub async fn get_metric_k8s_pod_cost_breakdown(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path(pod_uid): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        scope.check_pod(&state, &pod_uid).await?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_pod_cost_breakdown(pod_uid, q)
+                .await,
+        )
+    }
+
+    /// Cost summary for the query window next to a baseline window, with deltas.
+    pub async fn get_metric_k8s_pods_cost_compare(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(cmp): Query<CostCompareQuery>,
+        Query(filter): Query<PodListQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+
+        let pod_uids = target_pod_uids(&state, &scope, &q, filter.pod_uids).await?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_pods_cost_compare(q, cmp, pod_uids)
+                .await,
+        )
+    }
+
+    pub async fn get_metric_k8s_pods_reliability(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Query(filter): Query<PodListQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+
+        let pod_uids = target_pod_uids(&state, &scope, &q, filter.pod_uids).await?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_pods_reliability(q, pod_uids)
+                .await,
+        )
+    }
+
+    // POST variants: same views with the pod selection in a `PodListRequest` body.
+
+    pub async fn post_metric_k8s_pods_raw(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Json(body): Json<PodListRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let pod_uids = target_pod_uids(&state, &scope, &q, body.pod_uids).await?;
+        to_json(state.metric_service.get_metric_k8s_pods_raw(q, pod_uids).await)
+    }
+
+    pub async fn post_metric_k8s_pods_raw_summary(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Json(body): Json<PodListRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let pod_uids = target_pod_uids(&state, &scope, &q, body.pod_uids).await?;
+        to_json(state.metric_service.get_metric_k8s_pods_raw_summary(q, pod_uids).await)
+    }
+
+    pub async fn post_metric_k8s_pods_raw_efficiency(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Json(body): Json<PodListRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let pod_uids = target_pod_uids(&state, &scope, &q, body.pod_uids).await?;
+        to_json(state.metric_service.get_metric_k8s_pods_raw_efficiency(q, pod_uids).await)
+    }
+
+    pub async fn post_metric_k8s_pods_cost(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Json(body): Json<PodListRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let pod_uids = target_pod_uids(&state, &scope, &q, body.pod_uids).await?;
+        to_json(state.metric_service.get_metric_k8s_pods_cost(q, pod_uids).await)
+    }
+
+    pub async fn post_metric_k8s_pods_cost_summary(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Json(body): Json<PodListRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let pod_uids = target_pod_uids(&state, &scope, &q, body.pod_uids).await?;
+        to_json(state.metric_service.get_metric_k8s_pods_cost_summary(q, pod_uids).await)
+    }
+
+    pub async fn post_metric_k8s_pods_cost_trend(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+        Json(body): Json<PodListRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let pod_uids = target_pod_uids(&state, &scope, &q, body.pod_uids).await?;
+        to_json(state.metric_service.get_metric_k8s_pods_cost_trend(q, pod_uids).await)
+    }
 }
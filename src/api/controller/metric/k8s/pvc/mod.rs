@@ -0,0 +1,63 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+
+/// Claims are namespaced; list views only include the caller's namespaces.
+/// Claims that were deleted keep their history, so unscoped keys see every stored claim.
+pub struct K8sPvcMetricsController;
+
+async fn visible_namespaces(state: &AppState, scope: &TenantScope) -> Option<Vec<String>> {
+    if scope.is_restricted() {
+        Some(scope.namespaces(state).await)
+    } else {
+        None
+    }
+}
+
+impl K8sPvcMetricsController {
+    pub async fn get_metric_k8s_pvcs_raw(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        let namespaces = visible_namespaces(&state, &scope).await;
+        to_json(state.metric_service.get_metric_k8s_pvcs_raw(q, namespaces).await)
+    }
+
+    pub async fn get_metric_k8s_pvcs_cost(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        let namespaces = visible_namespaces(&state, &scope).await;
+        to_json(state.metric_service.get_metric_k8s_pvcs_cost(q, namespaces).await)
+    }
+
+    pub async fn get_metric_k8s_pvc_raw(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.check_namespace(&namespace)?;
+        to_json(state.metric_service.get_metric_k8s_pvc_raw(namespace, name, q).await)
+    }
+
+    pub async fn get_metric_k8s_pvc_cost(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Path((namespace, name)): Path<(String, String)>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        scope.check_namespace(&namespace)?;
+        to_json(state.metric_service.get_metric_k8s_pvc_cost(namespace, name, q).await)
+    }
+}
@@ -0,0 +1,34 @@
+use axum::{
+    extract::State,
+    Extension, Json,
+};
+use serde_json::Value;
+
+use crate::api::dto::metrics_query_dto::MetricQueryRequest;
+use crate::api::dto::ApiResponse;
+use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::app_state::AppState;
+use crate::domain::metric::k8s::common::service_helpers::check_granularity;
+use crate::domain::metric::k8s::query::engine::MetricQuery;
+use crate::errors::AppError;
+
+/// `POST /metrics/query`: the per-scope list views behind one JSON body.
+pub struct K8sMetricQueryController;
+
+impl K8sMetricQueryController {
+    pub async fn post_metric_k8s_query(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Json(req): Json<MetricQueryRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+
+        let targets = scope.query_targets(&state, req.scope, req.targets.clone()).await?;
+        let query = MetricQuery::from_request(&req, targets);
+        query.validate().map_err(|e| AppError::BodyParsingError(e.to_string()))?;
+        check_granularity(&query.range).map_err(AppError::InvalidGranularity)?;
+
+        to_json(state.metric_service.query(query).await)
+    }
+}
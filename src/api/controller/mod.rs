@@ -5,3 +5,4 @@ pub mod metric;
 pub mod info;
 pub mod llm;
 pub mod state;
+pub mod alert;
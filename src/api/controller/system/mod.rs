@@ -1,13 +1,19 @@
 //! System controller: connects routes to system usecases
 
+use axum::body::Body;
 use axum::extract::{Path, Query, State};
-use axum::Json;
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
 use serde_json::Value;
 
 
-use crate::api::dto::system_dto::{LogQuery, PaginatedLogResponse};
+use crate::api::dto::system_dto::{BackfillQuery, ClosePeriodQuery, CostAllocationQuery, ExportQuery, JobHistoryQuery, LogQuery, PaginatedLogResponse, SimulateSeedQuery};
+use crate::core::persistence::jobs::job_run_entity::JobRunEntity;
+use crate::core::persistence::logs::log_filter::LogLineFilter;
 use crate::api::dto::ApiResponse;
 use crate::api::util::json::to_json;
+use crate::api::util::tenant_scope::TenantScope;
 use crate::app_state::AppState;
 use crate::errors::AppError;
 
@@ -32,6 +38,83 @@ impl SystemController {
         to_json(state.system_service.backup().await)
     }
 
+    pub async fn download_backup(State(state): State<AppState>) -> Response {
+        let (file_name, stream) = state.system_service.backup_archive();
+        (
+            [
+                (header::CONTENT_TYPE, "application/zstd".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}\"", file_name),
+                ),
+            ],
+            Body::from_stream(stream),
+        )
+            .into_response()
+    }
+
+    pub async fn restore(
+        State(state): State<AppState>,
+        body: Body,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.system_service.restore(body).await)
+    }
+
+    pub async fn backfill_aggregates(
+        State(state): State<AppState>,
+        Query(q): Query<BackfillQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.system_service.backfill_aggregates(q).await)
+    }
+
+    pub async fn seed_simulation(
+        State(state): State<AppState>,
+        Query(q): Query<SimulateSeedQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.system_service.seed_simulation(q).await)
+    }
+
+    pub async fn export_cost_allocation(
+        State(state): State<AppState>,
+        Query(q): Query<ExportQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.system_service.export_cost_allocation(q).await)
+    }
+
+    pub async fn get_cost_allocation(
+        State(state): State<AppState>,
+        Extension(scope): Extension<TenantScope>,
+        Query(q): Query<CostAllocationQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.system_service.get_cost_allocation(q, scope).await)
+    }
+
+    pub async fn close_cost_period(
+        State(state): State<AppState>,
+        Query(q): Query<ClosePeriodQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.system_service.close_cost_period(q).await)
+    }
+
+    pub async fn list_closed_periods(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.system_service.list_closed_periods().await)
+    }
+
+    pub async fn get_metric_gc_report(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.system_service.get_metric_gc_report().await)
+    }
+
+    pub async fn get_job_history(
+        State(state): State<AppState>,
+        Query(q): Query<JobHistoryQuery>,
+    ) -> Result<Json<ApiResponse<Vec<JobRunEntity>>>, AppError> {
+        to_json(state.system_service.get_job_history(q).await)
+    }
+
     pub async fn resync(
         State(state): State<AppState>,
     ) -> Result<Json<ApiResponse<Value>>, AppError> {
@@ -49,10 +132,19 @@ impl SystemController {
         Path(date): Path<String>,
         Query(query): Query<LogQuery>,
     ) -> Result<Json<ApiResponse<PaginatedLogResponse>>, AppError> {
+        let filter = LogLineFilter::new(
+            query.level.as_deref(),
+            query.contains,
+            query.regex.as_deref(),
+            query.from,
+            query.to,
+        )
+        .map_err(|e| AppError::BodyParsingError(e.to_string()))?;
+
         to_json(
             state
                 .log_service
-                .get_system_log_lines(&date, query.cursor, query.limit)
+                .get_system_log_lines(&date, query.cursor, query.limit, filter)
                 .await,
         )
     }
@@ -1,8 +1,11 @@
 //! Info API DTOs
 
+use chrono::NaiveDate;
 use serde::Deserialize;
+use utoipa::IntoParams;
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct K8sListQuery {
     pub namespace: Option<String>,
     #[serde(alias = "label-selector")]
@@ -10,7 +13,8 @@ pub struct K8sListQuery {
     pub node_name: Option<String>, // for pods by node
 }
 
-#[derive(Deserialize, Debug, Default)]
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct K8sListNodeQuery {
     #[serde(alias = "label-selector")]
     pub label_selector: Option<String>,
@@ -19,9 +23,39 @@ pub struct K8sListNodeQuery {
     pub env: Option<String>, // "dev", "stage", "prod"
 }
 
-#[derive(Deserialize, Debug, Default)]
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 #[serde(default)]
 pub struct PaginationQuery {
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 }
+
+/// Query for `/info/billing/import`; the CSV file is the request body.
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BillingImportQuery {
+    /// `aws_cur` (CUR / CUR 2.0 CSV) or `gcp` (billing export CSV)
+    pub source: String,
+}
+
+/// Days `[start, end]` (UTC, inclusive) of imported billing.
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BillingRangeQuery {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Comma-separated billed services to count (e.g. `AmazonEC2,AmazonEBS`); all when unset
+    pub service: Option<String>,
+    /// Only this AWS account / GCP project
+    pub account: Option<String>,
+}
+
+impl BillingRangeQuery {
+    pub fn services(&self) -> Vec<String> {
+        self.service
+            .as_deref()
+            .map(|s| s.split(',').map(|v| v.trim().to_string()).filter(|v| !v.is_empty()).collect())
+            .unwrap_or_default()
+    }
+}
@@ -2,8 +2,10 @@
 
 use chrono::NaiveDateTime;
 use serde::Deserialize;
+use utoipa::IntoParams;
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct K8sPodQueryRequestDto {
     pub start: Option<NaiveDateTime>,
     pub end: Option<NaiveDateTime>,
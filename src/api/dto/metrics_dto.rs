@@ -2,6 +2,7 @@
 
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use crate::domain::metric::k8s::common::dto::MetricGranularity;
 
 /// Represents the standard query parameters for fetching metrics.
@@ -10,7 +11,8 @@ use crate::domain::metric::k8s::common::dto::MetricGranularity;
 /// 1. **Time Range & Resolution**: Defining the window and granularity of data.
 /// 2. **Pagination**: Controlling the size and order of the result set.
 /// 3. **Filtering**: Narrowing down the scope to specific teams, services, or resources.
-#[derive(Deserialize, Debug, Clone, Serialize)]
+#[derive(Deserialize, Debug, Clone, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct RangeQuery {
     // --- Time Range Configuration ---
 
@@ -27,9 +29,22 @@ pub struct RangeQuery {
     ///
     /// If not provided, the system may auto-calculate granularity based on the
     /// duration between `start` and `end`.
-    /// Valid values: `minute`, `hour`, `day`.
+    /// Valid values: `minute`, `hour`, `day`, `month` (never picked automatically).
+    /// `minute` is limited to 3 hours and `hour` to 3 days; longer windows are
+    /// rejected with an `InvalidGranularity` error naming the granularity to use.
     pub granularity: Option<MetricGranularity>,
 
+    /// Time zone for `start`/`end` and day buckets: a fixed UTC offset (`+09:00`,
+    /// `UTC+9`) or one of the supported zone names without daylight saving
+    /// (e.g. `Asia/Seoul`). `start`/`end` are read as local wall-clock times and
+    /// daily rows align to local midnight. Defaults to UTC.
+    pub tz: Option<String>,
+
+    /// Named window resolved on the server against "now" in `tz`:
+    /// `last24h`, `last7d`, `mtd` (local month start to now) or `lastMonth`
+    /// (the previous full local month). Takes precedence over `start`/`end`.
+    pub range: Option<RelativeRange>,
+
     // --- Pagination & Sorting ---
 
     /// The maximum number of records to return (page size).
@@ -38,8 +53,13 @@ pub struct RangeQuery {
     /// The number of records to skip before starting to return results.
     pub offset: Option<usize>,
 
+    /// Opaque cursor from a previous response's `next_cursor`; takes precedence over `offset`.
+    pub cursor: Option<String>,
+
     /// The sort order string.
     /// Format convention: `field_name` (asc) or `-field_name` (desc).
+    /// Node and pod lists also accept `cost` and `usage` (mean CPU cores), computed
+    /// over the requested window and always highest first; these don't support `cursor`.
     pub sort: Option<String>,
 
 
@@ -50,7 +70,18 @@ pub struct RangeQuery {
     #[serde(default)]
     pub mode: CostMode,
 
+    /// What CPU and memory are priced on: `usage`, `request` or `max(usage,request)`.
+    /// Applies to pod and container costs; defaults to `max(usage,request)` under
+    /// `mode=chargeback` and to `usage` otherwise.
+    #[serde(rename = "costBasis", alias = "cost_basis")]
+    pub cost_basis: Option<CostBasis>,
+
     // --- Scope Filters ---
+    //
+    // `team`, `service`, `env` and `namespace` (and their `exclude*` counterparts) take a
+    // comma-separated list of values; a value prefixed with `~` is a case-insensitive
+    // regex over the whole field.
+    // Example: `namespace=payments,~team-.*`
 
     /// Filter metrics by the owning team.
     pub team: Option<String>,
@@ -65,6 +96,22 @@ pub struct RangeQuery {
     /// Filter by Kubernetes namespace.
     pub namespace: Option<String>,
 
+    /// Drop matching namespaces, e.g. `kube-system,monitoring`. Applied after `namespace`.
+    #[serde(rename = "excludeNamespace", alias = "exclude_namespace")]
+    pub exclude_namespace: Option<String>,
+
+    /// Drop objects owned by matching teams.
+    #[serde(rename = "excludeTeam", alias = "exclude_team")]
+    pub exclude_team: Option<String>,
+
+    /// Drop objects belonging to matching services.
+    #[serde(rename = "excludeService", alias = "exclude_service")]
+    pub exclude_service: Option<String>,
+
+    /// Drop objects in matching environments.
+    #[serde(rename = "excludeEnv", alias = "exclude_env")]
+    pub exclude_env: Option<String>,
+
     /// Filter by resource labels.
     /// Expected format (convention-based):
     /// - `key=value`
@@ -102,7 +149,7 @@ pub struct RangeQuery {
 ///
 /// Choosing the default mode affects how users interpret cost data
 /// and requires careful discussion.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum CostMode {
     Showback,
@@ -114,3 +161,188 @@ impl Default for CostMode {
         CostMode::Showback
     }
 }
+
+/// Named query window; see [`RangeQuery::range`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum RelativeRange {
+    #[serde(rename = "last24h")]
+    Last24h,
+    #[serde(rename = "last7d")]
+    Last7d,
+    #[serde(rename = "mtd")]
+    MonthToDate,
+    #[serde(rename = "lastMonth", alias = "last_month")]
+    LastMonth,
+}
+
+/// Quantity CPU and memory cost is computed from. Storage and network are always usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum CostBasis {
+    #[serde(rename = "usage")]
+    Usage,
+    /// Container requests, whether or not they were used.
+    #[serde(rename = "request")]
+    Request,
+    /// Whichever is higher per point, as chargeback policies usually bill.
+    #[serde(rename = "max(usage,request)", alias = "max")]
+    Max,
+}
+
+impl RangeQuery {
+    pub fn effective_cost_basis(&self) -> CostBasis {
+        match (self.cost_basis, &self.mode) {
+            (Some(basis), _) => basis,
+            (None, CostMode::Chargeback) => CostBasis::Max,
+            (None, CostMode::Showback) => CostBasis::Usage,
+        }
+    }
+}
+
+/// Body `data` of an `InvalidGranularity` error: the requested granularity would
+/// read too many rows for the window, so the caller should retry with `suggested`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GranularityRejection {
+    pub requested: MetricGranularity,
+    pub suggested: MetricGranularity,
+    /// Length of the resolved window in hours
+    pub range_hours: f64,
+    pub message: String,
+}
+
+/// What `efficiency/scores` ranks.
+#[derive(Deserialize, Debug, Clone, Copy, Default, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EfficiencyGroupBy {
+    #[default]
+    Team,
+    Namespace,
+}
+
+/// Grouping for `efficiency/scores`, sent alongside the regular [`RangeQuery`].
+#[derive(Deserialize, Debug, Clone, Default, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EfficiencyScoreQuery {
+    /// `team` (default) or `namespace`
+    #[serde(rename = "groupBy", alias = "group_by")]
+    pub group_by: Option<EfficiencyGroupBy>,
+    /// `true` adds an LLM-written justification to each score, grounded in its
+    /// numbers; needs `/info/llm` to be configured
+    pub explain: Option<bool>,
+}
+
+/// Explicit namespace subset for namespace list views, sent alongside the regular
+/// [`RangeQuery`]: `namespaces=a,b`, `namespaces=a&namespaces=b` or both.
+/// Empty means every namespace visible to the caller.
+#[derive(Debug, Clone, Default, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct NamespaceListQuery {
+    /// Namespaces to include; repeat the parameter or separate values with commas.
+    #[param(style = Form, explode = true)]
+    pub namespaces: Vec<String>,
+}
+
+impl<'de> Deserialize<'de> for NamespaceListQuery {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let namespaces = deserialize_list_param(deserializer, &["namespaces"])?;
+        Ok(Self { namespaces })
+    }
+}
+
+/// Explicit pod subset for pod list views, sent alongside the regular [`RangeQuery`]:
+/// `podUids=a,b,c` and/or repeated `podUids`. Long lists can be sent as a
+/// [`PodListRequest`] body to the POST variant instead. Ignored when `key` is set.
+#[derive(Debug, Clone, Default, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PodListQuery {
+    /// Pod UIDs to include; repeat the parameter or separate values with commas.
+    #[serde(rename = "podUids")]
+    #[param(style = Form, explode = true)]
+    pub pod_uids: Vec<String>,
+}
+
+impl<'de> Deserialize<'de> for PodListQuery {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pod_uids = deserialize_list_param(deserializer, &["podUids", "pod_uids"])?;
+        Ok(Self { pod_uids })
+    }
+}
+
+/// Body of the POST variant of pod list views, for selections too long for a URL.
+#[derive(Deserialize, Debug, Clone, Default, Serialize, ToSchema)]
+pub struct PodListRequest {
+    #[serde(rename = "podUids", alias = "pod_uids", default)]
+    pub pod_uids: Vec<String>,
+}
+
+/// Collects every value of the query parameters named `keys`, split on commas,
+/// trimmed and de-duplicated in order. Hand-written because derived structs reject
+/// repeated keys; every other parameter of the query string is ignored.
+fn deserialize_list_param<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+    keys: &'static [&'static str],
+) -> Result<Vec<String>, D::Error> {
+    struct ListVisitor(&'static [&'static str]);
+
+    impl<'de> serde::de::Visitor<'de> for ListVisitor {
+        type Value = Vec<String>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("query parameters")
+        }
+
+        fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut values: Vec<String> = Vec::new();
+            while let Some((key, value)) = map.next_entry::<String, String>()? {
+                if !self.0.contains(&key.as_str()) {
+                    continue;
+                }
+                for v in value.split(',').map(str::trim).filter(|v| !v.is_empty()) {
+                    if !values.iter().any(|existing| existing == v) {
+                        values.push(v.to_string());
+                    }
+                }
+            }
+            Ok(values)
+        }
+    }
+
+    deserializer.deserialize_map(ListVisitor(keys))
+}
+
+/// Baseline window for `cost/compare`, sent alongside the regular [`RangeQuery`].
+///
+/// When omitted, the baseline is the window of the same length ending where the
+/// current one starts ("this week vs last week").
+#[derive(Deserialize, Debug, Clone, Default, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CostCompareQuery {
+    /// Baseline start; defaults to `start` minus the window length.
+    #[serde(rename = "compareStart", alias = "compare_start")]
+    pub compare_start: Option<NaiveDateTime>,
+
+    /// Baseline end; defaults to `start`.
+    #[serde(rename = "compareEnd", alias = "compare_end")]
+    pub compare_end: Option<NaiveDateTime>,
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_params_accept_repeated_and_comma_separated_values() {
+        let q: NamespaceListQuery = serde_json::from_str(
+            r#"{"start":"2024-01-01T00:00:00","namespaces":"payments, checkout","namespaces":"search,payments"}"#,
+        )
+        .unwrap();
+        assert_eq!(q.namespaces, vec!["payments", "checkout", "search"]);
+
+        let none: NamespaceListQuery = serde_json::from_str(r#"{"limit":"5"}"#).unwrap();
+        assert!(none.namespaces.is_empty());
+
+        let pods: PodListQuery = serde_json::from_str(r#"{"podUids":"a,b","pod_uids":"c"}"#).unwrap();
+        assert_eq!(pods.pod_uids, vec!["a", "b", "c"]);
+    }
+}
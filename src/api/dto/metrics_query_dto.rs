@@ -0,0 +1,176 @@
+//! Body of `POST /api/v1/metrics/query`: one structured request for selections
+//! that are awkward to spell as query strings (UID lists, label maps, grouping).
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::api::dto::metrics_dto::{CostBasis, CostMode, RangeQuery, RelativeRange};
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+#[derive(Deserialize, Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricQueryRequest {
+    pub scope: MetricQueryScope,
+
+    /// Which view of the scope to return; defaults to `raw`.
+    #[serde(default)]
+    pub view: MetricQueryView,
+
+    /// Objects of `scope` to include (node names, pod UIDs, container keys,
+    /// namespaces or deployments). Empty means every object the caller may see.
+    /// Ignored for `cluster`.
+    #[serde(default)]
+    pub targets: Vec<String>,
+
+    #[serde(default)]
+    pub window: MetricQueryWindow,
+
+    #[serde(default)]
+    pub selectors: MetricQuerySelectors,
+
+    /// Merges the series of `raw` and `cost` views into one series per group.
+    /// `all` works for every scope; the other dimensions need `pod` or `container`.
+    pub group_by: Option<MetricQueryGroupBy>,
+
+    /// How points at the same timestamp are combined within a group; defaults to `sum`.
+    #[serde(default)]
+    pub aggregation: MetricQueryAggregation,
+
+    #[serde(default)]
+    pub mode: CostMode,
+
+    pub cost_basis: Option<CostBasis>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricQueryScope {
+    Cluster,
+    Node,
+    Namespace,
+    Deployment,
+    Pod,
+    Container,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricQueryView {
+    #[default]
+    Raw,
+    RawSummary,
+    RawEfficiency,
+    Cost,
+    CostSummary,
+    CostTrend,
+}
+
+impl MetricQueryView {
+    /// Views answering with one series per object, which grouping can merge.
+    pub fn is_series(self) -> bool {
+        matches!(self, MetricQueryView::Raw | MetricQueryView::Cost)
+    }
+}
+
+/// Same fields and defaults as the time parameters of [`RangeQuery`].
+#[derive(Deserialize, Debug, Clone, Default, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricQueryWindow {
+    pub start: Option<NaiveDateTime>,
+    pub end: Option<NaiveDateTime>,
+    pub range: Option<RelativeRange>,
+    pub tz: Option<String>,
+    pub granularity: Option<MetricGranularity>,
+}
+
+/// Scope filters as lists; values keep the query-string syntax, so `~` still
+/// marks a case-insensitive regex.
+#[derive(Deserialize, Debug, Clone, Default, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricQuerySelectors {
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+    #[serde(default)]
+    pub teams: Vec<String>,
+    #[serde(default)]
+    pub services: Vec<String>,
+    #[serde(default)]
+    pub envs: Vec<String>,
+    #[serde(default)]
+    pub exclude_namespaces: Vec<String>,
+    #[serde(default)]
+    pub exclude_teams: Vec<String>,
+    #[serde(default)]
+    pub exclude_services: Vec<String>,
+    #[serde(default)]
+    pub exclude_envs: Vec<String>,
+    /// Label selector, every entry must match.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricQueryGroupBy {
+    /// One series for the whole selection.
+    All,
+    Namespace,
+    Node,
+    Team,
+    Service,
+    Env,
+    /// Top-level controller (`Deployment/api`, `CronJob/report`, ...).
+    Workload,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricQueryAggregation {
+    #[default]
+    Sum,
+    Avg,
+    Max,
+    Min,
+}
+
+impl MetricQueryRequest {
+    /// The equivalent query-string parameters, for the per-scope services.
+    pub fn range_query(&self) -> RangeQuery {
+        let list = |values: &[String]| (!values.is_empty()).then(|| values.join(","));
+        let s = &self.selectors;
+        let labels = (!s.labels.is_empty()).then(|| {
+            s.labels
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join(",")
+        });
+
+        RangeQuery {
+            start: self.window.start,
+            end: self.window.end,
+            granularity: self.window.granularity.clone(),
+            tz: self.window.tz.clone(),
+            range: self.window.range,
+            limit: None,
+            offset: None,
+            cursor: None,
+            sort: None,
+            mode: self.mode.clone(),
+            cost_basis: self.cost_basis,
+            team: list(&s.teams),
+            service: list(&s.services),
+            env: list(&s.envs),
+            namespace: list(&s.namespaces),
+            exclude_namespace: list(&s.exclude_namespaces),
+            exclude_team: list(&s.exclude_teams),
+            exclude_service: list(&s.exclude_services),
+            exclude_env: list(&s.exclude_envs),
+            labels,
+            key: None,
+        }
+    }
+}
@@ -3,6 +3,7 @@
 use serde::Serialize;
 
 pub mod metrics_dto;
+pub mod metrics_query_dto;
 pub mod info_dto;
 pub mod system_dto;
 pub mod k8s_pod_query_request_dto;
@@ -1,9 +1,24 @@
 //! System API DTOs
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
-#[derive(Deserialize)]
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct LogQuery {
+    /// Raw line number to resume from (`next_cursor` of the previous page)
     pub cursor: Option<usize>,
     pub limit: Option<usize>,
+    /// Minimum severity: `error`, `warn` or `info`
+    pub level: Option<String>,
+    /// Case-sensitive substring the line must contain
+    pub contains: Option<String>,
+    /// Regular expression the line must match
+    pub regex: Option<String>,
+    /// Only lines logged at or after this instant (RFC 3339)
+    pub from: Option<DateTime<Utc>>,
+    /// Only lines logged at or before this instant (RFC 3339)
+    pub to: Option<DateTime<Utc>>,
 }
 
 #[derive(Serialize)]
@@ -11,4 +26,66 @@ pub struct PaginatedLogResponse {
     pub date: String,
     pub lines: Vec<String>,
     pub next_cursor: Option<usize>,
-}
\ No newline at end of file
+}
+
+/// Query for `/system/aggregate/backfill`. Times are interpreted as UTC.
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BackfillQuery {
+    /// `pod`, `node`, `container` or `all`
+    pub scope: String,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+/// Query for `/system/export`.
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportQuery {
+    /// UTC day to export (`YYYY-MM-DD`)
+    pub date: NaiveDate,
+}
+
+/// Query for `/system/close`.
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ClosePeriodQuery {
+    /// UTC month to close (`YYYY-MM`); must have ended
+    pub period: String,
+    /// Free-form remark stored with the snapshot
+    pub note: Option<String>,
+}
+
+/// Query for `/system/cost-allocation`.
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CostAllocationQuery {
+    /// First UTC day (`YYYY-MM-DD`)
+    pub start: NaiveDate,
+    /// Last UTC day, inclusive; at most 31 days after `start`
+    pub end: NaiveDate,
+}
+
+/// Query for `/system/jobs`.
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct JobHistoryQuery {
+    /// Only runs of this job (`collection`, `hour_aggregation`, `pvc_collection`,
+    /// `network_flow_collection`, `day_aggregation`, `month_aggregation`, `cost_export`, `cost_materialization`, `retention`, `compaction`, `metric_gc`, `backfill`)
+    pub job: Option<String>,
+    pub limit: Option<usize>,
+}
+
+/// Query for `/system/simulate/seed`.
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SimulateSeedQuery {
+    /// Number of synthetic nodes (default 3, at most 200)
+    pub nodes: Option<u32>,
+    /// Number of synthetic pods spread over the nodes (default 30, at most 5000)
+    pub pods: Option<u32>,
+    /// Days of hourly history ending at the current hour (default 7, at most 31)
+    pub days: Option<u32>,
+    /// Seed for the per-object sizes; the same seed yields the same cluster
+    pub seed: Option<u64>,
+}
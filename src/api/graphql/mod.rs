@@ -0,0 +1,68 @@
+//! GraphQL endpoint for cost exploration.
+//!
+//! Lets the UI fetch nested views (namespace → deployments → pods → costSummary)
+//! in one round trip. Read-only; every resolver honours the caller's [`TenantScope`].
+
+use std::sync::OnceLock;
+
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{EmptyMutation, EmptySubscription, Schema};
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use axum::extract::State;
+use axum::response::Html;
+use axum::Extension;
+
+use crate::api::util::tenant_scope::TenantScope;
+use crate::app_state::AppState;
+use crate::errors::AppError;
+
+pub mod query;
+pub mod types;
+
+use query::QueryRoot;
+
+pub type RustcostSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Each metric resolver reads from disk, so keep queries shallow.
+const MAX_QUERY_DEPTH: usize = 8;
+
+/// The schema is stateless; `AppState` and the tenant scope are attached per request.
+pub fn schema() -> &'static RustcostSchema {
+    static SCHEMA: OnceLock<RustcostSchema> = OnceLock::new();
+    SCHEMA.get_or_init(|| {
+        Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+            .limit_depth(MAX_QUERY_DEPTH)
+            .finish()
+    })
+}
+
+/// `POST /api/v1/graphql`
+pub async fn graphql_handler(
+    State(state): State<AppState>,
+    Extension(scope): Extension<TenantScope>,
+    req: GraphQLRequest,
+) -> Result<GraphQLResponse, AppError> {
+    state.k8s_state.ensure_resynced().await?;
+    let request = req.into_inner().data(state).data(scope);
+    Ok(schema().execute(request).await.into())
+}
+
+/// `GET /graphiql`
+pub async fn graphiql() -> Html<String> {
+    Html(GraphiQLSource::build().endpoint("/api/v1/graphql").finish())
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_exposes_nested_cost_fields() {
+        let sdl = schema().sdl();
+        assert!(sdl.contains("type Namespace"));
+        assert!(sdl.contains("deployments: [Deployment!]!"));
+        assert!(sdl.contains("costSummary(range: RangeInput): CostSummary!"));
+    }
+}
@@ -0,0 +1,72 @@
+//! Root query type. Entry points filter by tenant scope; nested fields inherit it.
+
+use std::collections::HashSet;
+
+use async_graphql::{Context, Object, Result};
+
+use super::types::{context, visible_deployments, Deployment, Namespace, Pod};
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Namespaces visible to the caller.
+    async fn namespaces(&self, ctx: &Context<'_>) -> Result<Vec<Namespace>> {
+        let (state, tenant) = context(ctx)?;
+        Ok(tenant
+            .namespaces(state)
+            .await
+            .into_iter()
+            .map(|name| Namespace { name })
+            .collect())
+    }
+
+    async fn namespace(&self, ctx: &Context<'_>, name: String) -> Result<Option<Namespace>> {
+        let (state, tenant) = context(ctx)?;
+        tenant.check_namespace(&name)?;
+        let exists = state.k8s_state.get_namespaces().await.contains(&name);
+        Ok(exists.then_some(Namespace { name }))
+    }
+
+    async fn deployments(&self, ctx: &Context<'_>, namespace: Option<String>) -> Result<Vec<Deployment>> {
+        let (state, tenant) = context(ctx)?;
+        Ok(visible_deployments(state, tenant, namespace.as_deref()).await)
+    }
+
+    async fn deployment(&self, ctx: &Context<'_>, name: String) -> Result<Option<Deployment>> {
+        let (state, tenant) = context(ctx)?;
+        Ok(visible_deployments(state, tenant, None)
+            .await
+            .into_iter()
+            .find(|d| d.name == name))
+    }
+
+    async fn pods(&self, ctx: &Context<'_>, namespace: Option<String>) -> Result<Vec<Pod>> {
+        let (state, tenant) = context(ctx)?;
+        let visible: HashSet<String> = tenant.pod_uids(state).await.into_iter().collect();
+        Ok(state
+            .k8s_state
+            .get_runtime_pods()
+            .await
+            .into_iter()
+            .filter(|p| visible.contains(&p.uid))
+            .filter(|p| match &namespace {
+                Some(ns) => &p.namespace == ns,
+                None => true,
+            })
+            .map(Pod::from)
+            .collect())
+    }
+
+    async fn pod(&self, ctx: &Context<'_>, uid: String) -> Result<Option<Pod>> {
+        let (state, tenant) = context(ctx)?;
+        tenant.check_pod(state, &uid).await?;
+        Ok(state
+            .k8s_state
+            .get_runtime_pods()
+            .await
+            .into_iter()
+            .find(|p| p.uid == uid)
+            .map(Pod::from))
+    }
+}
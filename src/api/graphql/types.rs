@@ -0,0 +1,283 @@
+//! GraphQL object types. Cost and efficiency fields resolve lazily, so a query only
+//! pays for the metrics it selects.
+
+use async_graphql::{ComplexObject, Context, Enum, InputObject, Result, SimpleObject};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::Value;
+
+use crate::api::dto::metrics_dto::{CostMode, RangeQuery};
+use crate::api::util::tenant_scope::TenantScope;
+use crate::app_state::AppState;
+use crate::core::state::runtime::k8s::k8s_runtime_state::RuntimePod;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryResponseDto;
+use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::MetricRawEfficiencyResponseDto;
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+// ---------------- Inputs ----------------
+
+#[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    Minute,
+    Hour,
+    Day,
+    Month,
+}
+
+/// Time window for a cost/efficiency field; defaults match the REST API.
+#[derive(InputObject, Debug, Clone, Default)]
+pub struct RangeInput {
+    pub start: Option<NaiveDateTime>,
+    pub end: Option<NaiveDateTime>,
+    pub granularity: Option<Granularity>,
+}
+
+impl RangeInput {
+    fn to_query(&self) -> RangeQuery {
+        RangeQuery {
+            start: self.start,
+            end: self.end,
+            granularity: self.granularity.map(|g| match g {
+                Granularity::Minute => MetricGranularity::Minute,
+                Granularity::Hour => MetricGranularity::Hour,
+                Granularity::Day => MetricGranularity::Day,
+                Granularity::Month => MetricGranularity::Month,
+            }),
+            tz: None,
+            range: None,
+            limit: None,
+            offset: None,
+            cursor: None,
+            sort: None,
+            mode: CostMode::default(),
+            cost_basis: None,
+            team: None,
+            service: None,
+            env: None,
+            namespace: None,
+            exclude_namespace: None,
+            exclude_team: None,
+            exclude_service: None,
+            exclude_env: None,
+            labels: None,
+            key: None,
+        }
+    }
+}
+
+// ---------------- Metric results ----------------
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct CostSummary {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub total_cost_usd: f64,
+    pub cpu_cost_usd: f64,
+    pub memory_cost_usd: f64,
+    pub ephemeral_storage_cost_usd: f64,
+    pub persistent_storage_cost_usd: f64,
+    pub network_cost_usd: f64,
+    pub loadbalancer_cost_usd: f64,
+    pub fixed_cost_usd: f64,
+}
+
+/// Average usage over allocatable capacity, each in `0.0..=1.0`.
+#[derive(SimpleObject, Debug, Clone)]
+pub struct Efficiency {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub cpu_efficiency: f64,
+    pub memory_efficiency: f64,
+    pub storage_efficiency: f64,
+    pub overall_efficiency: f64,
+}
+
+fn cost_summary(value: anyhow::Result<Value>) -> Result<CostSummary> {
+    let dto: MetricCostSummaryResponseDto = serde_json::from_value(value?)?;
+    let s = dto.summary;
+    Ok(CostSummary {
+        start: dto.start,
+        end: dto.end,
+        total_cost_usd: s.total_cost_usd,
+        cpu_cost_usd: s.cpu_cost_usd,
+        memory_cost_usd: s.memory_cost_usd,
+        ephemeral_storage_cost_usd: s.ephemeral_storage_cost_usd,
+        persistent_storage_cost_usd: s.persistent_storage_cost_usd,
+        network_cost_usd: s.network_cost_usd,
+        loadbalancer_cost_usd: s.loadbalancer_cost_usd,
+        fixed_cost_usd: s.fixed_cost_usd,
+    })
+}
+
+fn efficiency(value: anyhow::Result<Value>) -> Result<Efficiency> {
+    let dto: MetricRawEfficiencyResponseDto = serde_json::from_value(value?)?;
+    let e = dto.efficiency;
+    Ok(Efficiency {
+        start: dto.start,
+        end: dto.end,
+        cpu_efficiency: e.cpu_efficiency,
+        memory_efficiency: e.memory_efficiency,
+        storage_efficiency: e.storage_efficiency,
+        overall_efficiency: e.overall_efficiency,
+    })
+}
+
+pub(super) fn context<'a>(ctx: &Context<'a>) -> Result<(&'a AppState, &'a TenantScope)> {
+    Ok((ctx.data::<AppState>()?, ctx.data::<TenantScope>()?))
+}
+
+// ---------------- Namespace ----------------
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct Namespace {
+    pub name: String,
+}
+
+#[ComplexObject]
+impl Namespace {
+    /// Deployments whose pods run in this namespace.
+    async fn deployments(&self, ctx: &Context<'_>) -> Result<Vec<Deployment>> {
+        let (state, tenant) = context(ctx)?;
+        Ok(visible_deployments(state, tenant, Some(&self.name)).await)
+    }
+
+    async fn pods(&self, ctx: &Context<'_>) -> Result<Vec<Pod>> {
+        let (state, _) = context(ctx)?;
+        let pods = state.k8s_state.get_pods_by_namespace(&self.name).await;
+        Ok(pods.into_iter().map(Pod::from).collect())
+    }
+
+    async fn cost_summary(&self, ctx: &Context<'_>, range: Option<RangeInput>) -> Result<CostSummary> {
+        let (state, _) = context(ctx)?;
+        let q = range.unwrap_or_default().to_query();
+        cost_summary(
+            state
+                .metric_service
+                .get_metric_k8s_namespace_cost_summary(self.name.clone(), q)
+                .await,
+        )
+    }
+
+    async fn efficiency(&self, ctx: &Context<'_>, range: Option<RangeInput>) -> Result<Efficiency> {
+        let (state, _) = context(ctx)?;
+        let q = range.unwrap_or_default().to_query();
+        efficiency(
+            state
+                .metric_service
+                .get_metric_k8s_namespace_raw_efficiency(self.name.clone(), q)
+                .await,
+        )
+    }
+}
+
+// ---------------- Deployment ----------------
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct Deployment {
+    pub name: String,
+    /// Namespace of the deployment's pods; `None` when it has no running pods.
+    pub namespace: Option<String>,
+}
+
+#[ComplexObject]
+impl Deployment {
+    async fn pods(&self, ctx: &Context<'_>) -> Result<Vec<Pod>> {
+        let (state, _) = context(ctx)?;
+        let pods = state.k8s_state.get_pods_by_deployment(&self.name).await;
+        Ok(pods.into_iter().map(Pod::from).collect())
+    }
+
+    async fn cost_summary(&self, ctx: &Context<'_>, range: Option<RangeInput>) -> Result<CostSummary> {
+        let (state, _) = context(ctx)?;
+        let q = range.unwrap_or_default().to_query();
+        cost_summary(
+            state
+                .metric_service
+                .get_metric_k8s_deployment_cost_summary(self.name.clone(), q)
+                .await,
+        )
+    }
+
+    async fn efficiency(&self, ctx: &Context<'_>, range: Option<RangeInput>) -> Result<Efficiency> {
+        let (state, _) = context(ctx)?;
+        let q = range.unwrap_or_default().to_query();
+        efficiency(
+            state
+                .metric_service
+                .get_metric_k8s_deployment_raw_efficiency(self.name.clone(), q)
+                .await,
+        )
+    }
+}
+
+/// Deployments the caller may see, optionally limited to one namespace.
+pub(super) async fn visible_deployments(
+    state: &AppState,
+    tenant: &TenantScope,
+    namespace: Option<&str>,
+) -> Vec<Deployment> {
+    let mut out = Vec::new();
+    for name in tenant.deployments(state).await {
+        let pods = state.k8s_state.get_pods_by_deployment(&name).await;
+        let ns = pods.first().map(|p| p.namespace.clone());
+        if let Some(filter) = namespace {
+            if ns.as_deref() != Some(filter) {
+                continue;
+            }
+        }
+        out.push(Deployment { name, namespace: ns });
+    }
+    out
+}
+
+// ---------------- Pod ----------------
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct Pod {
+    pub uid: String,
+    pub name: String,
+    pub namespace: String,
+    pub node: String,
+    pub deployment: Option<String>,
+    pub containers: Vec<String>,
+}
+
+impl From<RuntimePod> for Pod {
+    fn from(p: RuntimePod) -> Self {
+        Self {
+            uid: p.uid,
+            name: p.name,
+            namespace: p.namespace,
+            node: p.node,
+            deployment: p.deployment,
+            containers: p.containers,
+        }
+    }
+}
+
+#[ComplexObject]
+impl Pod {
+    async fn cost_summary(&self, ctx: &Context<'_>, range: Option<RangeInput>) -> Result<CostSummary> {
+        let (state, _) = context(ctx)?;
+        let q = range.unwrap_or_default().to_query();
+        cost_summary(
+            state
+                .metric_service
+                .get_metric_k8s_pod_cost_summary(self.uid.clone(), q)
+                .await,
+        )
+    }
+
+    async fn efficiency(&self, ctx: &Context<'_>, range: Option<RangeInput>) -> Result<Efficiency> {
+        let (state, _) = context(ctx)?;
+        let q = range.unwrap_or_default().to_query();
+        efficiency(
+            state
+                .metric_service
+                .get_metric_k8s_pod_raw_efficiency(self.uid.clone(), q)
+                .await,
+        )
+    }
+}
@@ -0,0 +1,293 @@
+//! API key / Kubernetes TokenReview authentication with viewer and admin roles.
+//!
+//! Callers send `Authorization: Bearer <token>` or `X-API-Key: <token>`.
+//! Reads need a viewer token, except those exposing credentials or raw
+//! operational data (settings, LLM config, alert channels, billing imports,
+//! logs, backups); anything that changes state (prices, settings, backups,
+//! restores, resyncs) needs an admin token.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{OriginalUri, Request},
+    http::{header, Method},
+    middleware::Next,
+    response::Response,
+};
+use k8s_openapi::api::authentication::v1::{TokenReview, TokenReviewSpec};
+use kube::api::{Api, PostParams};
+use subtle::ConstantTimeEq;
+use tracing::{debug, warn};
+
+use crate::api::util::tenant_scope::TenantScope;
+use crate::config::{config, AuthConfig};
+use crate::core::client::kube_client::build_kube_client;
+use crate::errors::AppError;
+
+/// How long a TokenReview verdict is reused before asking the API server again.
+const TOKEN_REVIEW_TTL: Duration = Duration::from_secs(60);
+
+/// Access level of an authenticated caller. Admin includes everything a viewer can do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    Viewer,
+    Admin,
+}
+
+impl FromStr for Role {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "viewer" | "read" | "readonly" | "read-only" => Ok(Role::Viewer),
+            "admin" => Ok(Role::Admin),
+            other => anyhow::bail!("Unknown API role '{}'", other),
+        }
+    }
+}
+
+/// Read-only requests that still expose secrets or whole-dataset copies:
+/// export sink and remote store tokens (settings), the LLM token, alert
+/// webhook URLs, imported billing lines.
+const ADMIN_READ_PATHS: &[&str] = &[
+    "/api/v1/system/backup/download",
+    "/api/v1/info/settings",
+    "/api/v1/info/llm",
+    "/api/v1/info/alerts",
+    "/api/v1/info/billing",
+];
+
+/// Like [`ADMIN_READ_PATHS`] for routes carrying ids: log files can hold
+/// request URLs and provider errors with credentials in them.
+const ADMIN_READ_PREFIXES: &[&str] = &["/api/v1/system/logs"];
+
+/// POST endpoints that only read data (LLM chat, GraphQL queries, dry runs) and are fine for viewers.
+const VIEWER_WRITE_PATHS: &[&str] = &[
+    "/api/v1/graphql",
+    "/api/v1/llm/chat",
+    "/api/v1/llm/chat-with-context",
+    "/api/v1/info/attribution-rules/preview",
+    "/api/v1/metrics/pods/raw",
+    "/api/v1/metrics/pods/raw/summary",
+    "/api/v1/metrics/pods/raw/efficiency",
+    "/api/v1/metrics/pods/cost",
+    "/api/v1/metrics/pods/cost/summary",
+    "/api/v1/metrics/pods/cost/trend",
+    "/api/v1/metrics/query",
+];
+
+/// Prefixes under which viewers may POST, for routes carrying ids: starting a
+/// conversation and adding messages. Conversations have no owner, so deleting
+/// one stays admin-only.
+const VIEWER_POST_PREFIXES: &[&str] = &["/api/v1/llm/conversations"];
+
+/// Minimum role needed for a request.
+pub fn required_role(method: &Method, path: &str) -> Role {
+    let path = path.trim_end_matches('/');
+    let is_read = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+
+    if is_read {
+        if ADMIN_READ_PATHS.contains(&path) || ADMIN_READ_PREFIXES.iter().any(|p| is_under(path, p)) {
+            Role::Admin
+        } else {
+            Role::Viewer
+        }
+    } else if VIEWER_WRITE_PATHS.contains(&path)
+        || (*method == Method::POST && VIEWER_POST_PREFIXES.iter().any(|p| is_under(path, p)))
+    {
+        Role::Viewer
+    } else {
+        Role::Admin
+    }
+}
+
+/// `prefix` itself or a path below it, so `/logs` doesn't also cover `/logsets`.
+fn is_under(path: &str, prefix: &str) -> bool {
+    path.strip_prefix(prefix).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Axum middleware enforcing [`required_role`] for every request it wraps.
+///
+/// Also attaches the caller's [`TenantScope`] so handlers can filter what they return.
+pub async fn require_auth(mut req: Request, next: Next) -> Result<Response, AppError> {
+    let auth = config().await.auth();
+    if !auth.is_enabled() || req.method() == Method::OPTIONS {
+        req.extensions_mut().insert(TenantScope::default());
+        return Ok(next.run(req).await);
+    }
+
+    let (role, scope) = authorize(extract_token(&req)).await?;
+
+    // Nested routers strip their prefix; match on the full path the client sent
+    let path = req
+        .extensions()
+        .get::<OriginalUri>()
+        .map(|uri| uri.path().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+
+    if role < required_role(req.method(), &path) {
+        return Err(AppError::Forbidden(format!(
+            "{} {} requires the admin role",
+            req.method(),
+            path
+        )));
+    }
+
+    req.extensions_mut().insert(scope);
+
+    Ok(next.run(req).await)
+}
+
+/// Resolves a caller's role and tenant scope from its token.
+///
+/// Shared by the HTTP middleware and the gRPC service; with auth disabled every
+/// caller is an unscoped admin.
+pub async fn authorize(token: Option<String>) -> Result<(Role, TenantScope), AppError> {
+    let auth = config().await.auth();
+    if !auth.is_enabled() {
+        return Ok((Role::Admin, TenantScope::default()));
+    }
+
+    let token =
+        token.ok_or_else(|| AppError::Unauthorized("Missing API key or bearer token".into()))?;
+
+    let role = authenticate(auth, &token)
+        .await
+        .ok_or_else(|| AppError::Unauthorized("Invalid API key or bearer token".into()))?;
+
+    let scope = auth.key_scopes.get(&token).cloned().unwrap_or_default();
+    Ok((role, scope))
+}
+
+fn extract_token(req: &Request) -> Option<String> {
+    let headers = req.headers();
+
+    if let Some(value) = headers.get("x-api-key").and_then(|v| v.to_str().ok()) {
+        return Some(value.trim().to_string()).filter(|t| !t.is_empty());
+    }
+
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer ").or_else(|| v.strip_prefix("bearer ")))
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+async fn authenticate(auth: &AuthConfig, token: &str) -> Option<Role> {
+    if let Some(role) = match_api_key(auth, token) {
+        return Some(role);
+    }
+
+    if auth.token_review {
+        return review_token(auth, token).await;
+    }
+
+    None
+}
+
+/// Compares `token` against every configured key in constant time and without
+/// stopping at a match, so response timing doesn't reveal how much of a key was right.
+fn match_api_key(auth: &AuthConfig, token: &str) -> Option<Role> {
+    auth.api_keys.iter().fold(None, |found, (key, role)| {
+        let matches = bool::from(key.as_bytes().ct_eq(token.as_bytes()));
+        found.or(matches.then_some(*role))
+    })
+}
+
+// ---------------- TokenReview ----------------
+
+fn review_cache() -> &'static Mutex<HashMap<String, (Option<Role>, Instant)>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, (Option<Role>, Instant)>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Validates `token` with the API server; admin if the user is in one of the admin groups.
+async fn review_token(auth: &AuthConfig, token: &str) -> Option<Role> {
+    if let Some((role, at)) = review_cache().lock().unwrap().get(token) {
+        if at.elapsed() < TOKEN_REVIEW_TTL {
+            return *role;
+        }
+    }
+
+    let role = match request_token_review(token).await {
+        Ok(Some(groups)) => {
+            if groups.iter().any(|g| auth.admin_groups.contains(g)) {
+                Some(Role::Admin)
+            } else {
+                Some(Role::Viewer)
+            }
+        }
+        Ok(None) => None,
+        Err(e) => {
+            // Not cached, so the next request retries once the API server is reachable
+            warn!(?e, "TokenReview request failed");
+            return None;
+        }
+    };
+
+    let mut cache = review_cache().lock().unwrap();
+    cache.retain(|_, (_, at)| at.elapsed() < TOKEN_REVIEW_TTL);
+    cache.insert(token.to_string(), (role, Instant::now()));
+    role
+}
+
+/// Returns the user's groups when the token is authenticated.
+async fn request_token_review(token: &str) -> anyhow::Result<Option<Vec<String>>> {
+    let client = build_kube_client().await?;
+    let api: Api<TokenReview> = Api::all(client);
+
+    let review = TokenReview {
+        spec: TokenReviewSpec {
+            token: Some(token.to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let status = api.create(&PostParams::default(), &review).await?.status;
+    let Some(status) = status.filter(|s| s.authenticated == Some(true)) else {
+        debug!("TokenReview rejected token");
+        return Ok(None);
+    };
+
+    Ok(Some(
+        status.user.and_then(|u| u.groups).unwrap_or_default(),
+    ))
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_role_splits_reads_and_writes() {
+        assert_eq!(required_role(&Method::GET, "/api/v1/metrics/pods/cost"), Role::Viewer);
+        assert_eq!(required_role(&Method::POST, "/api/v1/llm/chat"), Role::Viewer);
+        assert_eq!(required_role(&Method::POST, "/api/v1/graphql"), Role::Viewer);
+        assert_eq!(required_role(&Method::POST, "/api/v1/info/attribution-rules/preview"), Role::Viewer);
+        assert_eq!(required_role(&Method::POST, "/api/v1/metrics/pods/cost"), Role::Viewer);
+        assert_eq!(required_role(&Method::POST, "/api/v1/info/attribution-rules"), Role::Admin);
+        assert_eq!(required_role(&Method::POST, "/api/v1/llm/conversations"), Role::Viewer);
+        assert_eq!(required_role(&Method::POST, "/api/v1/llm/conversations/conv-1/messages"), Role::Viewer);
+        assert_eq!(required_role(&Method::DELETE, "/api/v1/llm/conversations/conv-1"), Role::Admin);
+        assert_eq!(required_role(&Method::PUT, "/api/v1/llm/conversations/conv-1"), Role::Admin);
+        assert_eq!(required_role(&Method::PUT, "/api/v1/info/unit-prices"), Role::Admin);
+        assert_eq!(required_role(&Method::PATCH, "/api/v1/info/k8s/store/nodes/n1/price"), Role::Admin);
+        assert_eq!(required_role(&Method::POST, "/api/v1/system/backup"), Role::Admin);
+        assert_eq!(required_role(&Method::GET, "/api/v1/system/backup/download"), Role::Admin);
+        assert_eq!(required_role(&Method::GET, "/api/v1/info/settings/"), Role::Admin);
+        assert_eq!(required_role(&Method::GET, "/api/v1/info/alerts"), Role::Admin);
+        assert_eq!(required_role(&Method::GET, "/api/v1/info/billing"), Role::Admin);
+        assert_eq!(required_role(&Method::GET, "/api/v1/system/logs"), Role::Admin);
+        assert_eq!(required_role(&Method::GET, "/api/v1/system/logs/2025-03-01"), Role::Admin);
+        assert_eq!(required_role(&Method::GET, "/api/v1/info/unit-prices"), Role::Viewer);
+        assert_eq!(required_role(&Method::GET, "/api/v1/system/jobs"), Role::Viewer);
+        assert_eq!(required_role(&Method::POST, "/api/v1/llm/conversations-export"), Role::Admin);
+    }
+}
@@ -0,0 +1,23 @@
+//! Rejects metric queries whose explicit `granularity` is too fine for the window.
+//!
+//! Without this, a request like `granularity=minute` over three months would be
+//! quietly downgraded deep in the service layer; callers now get a 400 carrying
+//! the granularity to retry with.
+
+use axum::{
+    extract::{Query, Request},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::domain::metric::k8s::common::service_helpers::check_granularity;
+use crate::errors::AppError;
+
+pub async fn guard_granularity(req: Request, next: Next) -> Result<Response, AppError> {
+    // Unparseable queries are left to the handler's own extractor
+    if let Ok(Query(q)) = Query::<RangeQuery>::try_from_uri(req.uri()) {
+        check_granularity(&q).map_err(AppError::InvalidGranularity)?;
+    }
+    Ok(next.run(req).await)
+}
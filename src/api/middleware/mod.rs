@@ -0,0 +1,8 @@
+//! Request middleware applied to the API router
+
+pub mod auth;
+pub mod granularity_guard;
+pub mod read_only;
+pub mod repositories_scope;
+pub mod request_metrics;
+pub mod request_id;
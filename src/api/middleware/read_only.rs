@@ -0,0 +1,46 @@
+//! Rejects mutating requests on a `ROLE=reader` replica.
+//!
+//! Readers serve from a data directory owned by the writer, so settings,
+//! patches, backups and the like must go to the writer instead.
+
+use axum::{
+    extract::Request,
+    http::Method,
+    middleware::Next,
+    response::Response,
+};
+
+use crate::config::is_reader;
+use crate::errors::AppError;
+
+/// POST routes (relative to `/api/v1`) that only read.
+const READ_ONLY_POSTS: &[&str] = &[
+    "/graphql",
+    "/llm/chat",
+    "/llm/chat-with-context",
+    "/info/attribution-rules/preview",
+    "/metrics/pods/raw",
+    "/metrics/pods/raw/summary",
+    "/metrics/pods/raw/efficiency",
+    "/metrics/pods/cost",
+    "/metrics/pods/cost/summary",
+    "/metrics/pods/cost/trend",
+    "/metrics/query",
+];
+
+pub async fn reject_writes_on_reader(req: Request, next: Next) -> Result<Response, AppError> {
+    if is_reader() && !is_read_only(req.method(), req.uri().path()) {
+        return Err(AppError::Forbidden(
+            "This replica is read-only (ROLE=reader); send changes to the writer".into(),
+        ));
+    }
+    Ok(next.run(req).await)
+}
+
+fn is_read_only(method: &Method, path: &str) -> bool {
+    match *method {
+        Method::GET | Method::HEAD | Method::OPTIONS => true,
+        Method::POST => READ_ONLY_POSTS.contains(&path),
+        _ => false,
+    }
+}
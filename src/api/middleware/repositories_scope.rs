@@ -0,0 +1,15 @@
+//! Runs each API request inside the `AppState`'s repository scope, so handlers
+//! and the services they call (not only `MetricService`) read through the
+//! state's repositories.
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::app_state::AppState;
+
+pub async fn scope_repositories(State(state): State<AppState>, req: Request, next: Next) -> Response {
+    state.scoped(next.run(req)).await
+}
@@ -0,0 +1,54 @@
+//! Tags every request with an id and runs it inside a `request` span, so each
+//! log line written while handling it carries `request_id`.
+//!
+//! An incoming `X-Request-Id` is reused (e.g. from an ingress); otherwise one is
+//! generated. The id is echoed back on the response.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use chrono::Utc;
+use tracing::{info_span, Instrument};
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied id we accept; anything longer is replaced.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Id of the request being handled, available to handlers as an extension.
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+pub async fn attach_request_id(mut req: Request, next: Next) -> Response {
+    let request_id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| !v.is_empty() && v.len() <= MAX_REQUEST_ID_LEN)
+        .map(str::to_string)
+        .unwrap_or_else(generate_request_id);
+
+    req.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let span = info_span!("request", request_id = %request_id);
+    let mut response = next.run(req).instrument(span).await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+/// Timestamp plus a process-wide counter, so ids stay unique within a nanosecond.
+fn generate_request_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let now = Utc::now();
+    let nanos = now.timestamp_nanos_opt().unwrap_or_else(|| now.timestamp_micros());
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{:x}-{:x}", nanos, seq)
+}
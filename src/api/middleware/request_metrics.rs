@@ -0,0 +1,38 @@
+//! Records per-route request latency into the self-metrics registry and wraps
+//! each request in an `http_request` span (exported when OTLP tracing is on).
+
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+
+use tracing::{field, info_span, Instrument};
+
+use crate::core::self_metrics::observe_http_request;
+
+pub async fn record_request_metrics(req: Request, next: Next) -> Response {
+    let method = req.method().to_string();
+    // Route template, so path parameters don't blow up the label set
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let span = info_span!(
+        "http_request",
+        http.method = %method,
+        http.route = %route,
+        http.status_code = field::Empty,
+    );
+
+    let started = Instant::now();
+    let response = next.run(req).instrument(span.clone()).await;
+    span.record("http.status_code", response.status().as_u16());
+    observe_http_request(&method, &route, response.status().as_u16(), started.elapsed().as_secs_f64());
+
+    response
+}
@@ -4,3 +4,6 @@ pub mod dto;
 pub mod routes;
 pub mod controller;
 pub mod util;
+pub mod middleware;
+pub mod openapi;
+pub mod graphql;
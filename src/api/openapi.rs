@@ -0,0 +1,855 @@
+//! OpenAPI 3 document for the REST API, served at `/openapi.json` with Swagger UI at `/docs`.
+//!
+//! Endpoints are listed by hand next to the routers they describe; the metric routes
+//! follow one pattern per resource and are generated from [`METRIC_RESOURCES`].
+//! When adding a route, add it here too (the test below catches the metric ones).
+
+use std::collections::BTreeMap;
+
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::path::{
+    HttpMethod, Operation, OperationBuilder, Parameter, ParameterBuilder, ParameterIn,
+    PathItemBuilder,
+};
+use utoipa::openapi::request_body::RequestBodyBuilder;
+use utoipa::openapi::response::ResponseBuilder;
+use utoipa::openapi::schema::{KnownFormat, ObjectBuilder, Ref, Schema, SchemaFormat, Type};
+use utoipa::openapi::security::{
+    ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme,
+};
+use utoipa::openapi::{
+    ComponentsBuilder, InfoBuilder, OpenApi, OpenApiBuilder, PathsBuilder, Required,
+};
+use utoipa::IntoParams;
+
+use crate::api::dto::info_dto::{BillingImportQuery, BillingRangeQuery, K8sListNodeQuery, K8sListQuery, PaginationQuery};
+use crate::api::dto::k8s_pod_query_request_dto::K8sPodQueryRequestDto;
+use crate::api::dto::metrics_query_dto::{
+    MetricQueryAggregation, MetricQueryGroupBy, MetricQueryRequest, MetricQueryScope, MetricQuerySelectors,
+    MetricQueryView, MetricQueryWindow,
+};
+use crate::api::dto::metrics_dto::{CostBasis, CostCompareQuery, CostMode, EfficiencyScoreQuery, NamespaceListQuery, PodListQuery, PodListRequest, RangeQuery, RelativeRange};
+use crate::api::dto::system_dto::{BackfillQuery, ClosePeriodQuery, CostAllocationQuery, ExportQuery, JobHistoryQuery, LogQuery, SimulateSeedQuery};
+use crate::core::persistence::info::fixed::alerts::alert_rule_entity::{
+    AlertChannel, AlertMetricType, AlertOperator, AlertSeverity,
+};
+use crate::core::persistence::info::fixed::alerts::efficiency_alert_rule_entity::AlertEfficiencyScope;
+use crate::core::persistence::info::fixed::llm::llm_provider::LlmProvider;
+use crate::core::persistence::info::k8s::node::info_node_entity::NodePricePeriod;
+use crate::domain::info::dto::info_alert_upsert_request::{
+    AlertRuleUpsertRequest, AlertSilenceUpsertRequest, EfficiencyAlertRuleUpsertRequest,
+    InfoAlertUpsertRequest,
+};
+use crate::domain::info::dto::info_k8s_container_patch_request::InfoK8sContainerPatchRequest;
+use crate::domain::info::dto::info_k8s_node_patch_request::{InfoK8sNodePatchRequest, InfoK8sNodePricePatchRequest};
+use crate::domain::info::dto::info_k8s_pod_patch_request::InfoK8sPodPatchRequest;
+use crate::domain::info::dto::info_k8s_namespace_patch_request::InfoK8sNamespacePatchRequest;
+use crate::domain::info::dto::info_k8s_pod_bulk_patch_request::InfoK8sPodBulkPatchRequest;
+use crate::domain::info::dto::info_attribution_rule_dto::{
+    InfoAttributionPreviewRequest, InfoAttributionRuleUpsertRequest,
+};
+use crate::core::persistence::info::fixed::fixed_cost::fixed_cost_entity::{
+    FixedCostAllocation, FixedCostPeriod,
+};
+use crate::domain::info::dto::info_fixed_cost_dto::InfoFixedCostUpsertRequest;
+use crate::core::persistence::info::fixed::carbon::info_carbon_entity::PowerProfileEntity;
+use crate::domain::info::dto::info_carbon_upsert_request::InfoCarbonUpsertRequest;
+use crate::domain::info::dto::info_efficiency_target_upsert_request::InfoEfficiencyTargetUpsertRequest;
+use crate::domain::info::dto::info_virtual_node_price_upsert_request::InfoVirtualNodePriceUpsertRequest;
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_entity::VirtualNodeRate;
+use crate::core::persistence::info::fixed::commitment::commitment_entity::{CommitmentKind, CommitmentTerm};
+use crate::domain::info::dto::info_commitment_dto::InfoCommitmentUpsertRequest;
+use crate::domain::info::dto::info_llm_upsert_request::InfoLlmUpsertRequest;
+use crate::domain::info::dto::info_setting_upsert_request::InfoSettingUpsertRequest;
+use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
+use crate::domain::llm::dto::llm_chat_request::{LlmChatRequest, LlmMessage};
+use crate::domain::llm::dto::llm_chat_with_context_request::LlmChatWithContextRequest;
+use crate::domain::llm::dto::llm_tool_call_dto::LlmToolCallDto;
+use crate::domain::llm::dto::llm_conversation_dto::{
+    LlmConversationCreateRequest, LlmConversationMessageRequest, LlmConversationSummaryDto,
+};
+use crate::core::persistence::info::llm_conversation::llm_conversation_entity::{
+    LlmConversationEntity, LlmConversationMessageEntity,
+};
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+/// Metric resources: (path segment, tag, single-object path parameter).
+const METRIC_RESOURCES: &[(&str, &str, Option<&str>)] = &[
+    ("nodes", "Node metrics", Some("node_name")),
+    ("pods", "Pod metrics", Some("pod_uid")),
+    ("containers", "Container metrics", Some("id")),
+    ("namespaces", "Namespace metrics", Some("namespace")),
+    ("deployments", "Deployment metrics", Some("deployment")),
+    ("cluster", "Cluster metrics", None),
+];
+
+/// Views every metric resource exposes, both for the list and a single object.
+const METRIC_VIEWS: &[(&str, &str)] = &[
+    ("raw", "Raw usage series"),
+    ("raw/summary", "Raw usage summary"),
+    ("raw/efficiency", "Usage efficiency"),
+    ("cost", "Cost series"),
+    ("cost/summary", "Cost summary"),
+    ("cost/trend", "Cost trend"),
+];
+
+#[derive(Debug, Clone, Copy)]
+enum QueryParams {
+    None,
+    Range,
+    /// Range plus the `compareStart`/`compareEnd` baseline.
+    CostCompare,
+    /// Range plus the `namespaces` subset of namespace list views.
+    NamespaceList,
+    /// `CostCompare` plus the `namespaces` subset.
+    NamespaceCostCompare,
+    /// Range plus the `podUids` subset of pod list views.
+    PodList,
+    /// `CostCompare` plus the `podUids` subset.
+    PodCostCompare,
+    EfficiencyScore,
+    Pagination,
+    PodFilter,
+    ContainerFilter,
+    NodeFilter,
+    Logs,
+    Backfill,
+    SimulateSeed,
+    Export,
+    ClosePeriod,
+    CostAllocation,
+    JobHistory,
+    BillingImport,
+    BillingRange,
+}
+
+impl QueryParams {
+    fn parameters(self) -> Vec<Parameter> {
+        let query = || Some(ParameterIn::Query);
+        match self {
+            QueryParams::None => Vec::new(),
+            QueryParams::Range => RangeQuery::into_params(query),
+            QueryParams::CostCompare => {
+                let mut params = RangeQuery::into_params(query);
+                params.extend(CostCompareQuery::into_params(query));
+                params
+            }
+            QueryParams::NamespaceList => {
+                let mut params = RangeQuery::into_params(query);
+                params.extend(NamespaceListQuery::into_params(query));
+                params
+            }
+            QueryParams::NamespaceCostCompare => {
+                let mut params = QueryParams::CostCompare.parameters();
+                params.extend(NamespaceListQuery::into_params(query));
+                params
+            }
+            QueryParams::PodList => {
+                let mut params = RangeQuery::into_params(query);
+                params.extend(PodListQuery::into_params(query));
+                params
+            }
+            QueryParams::PodCostCompare => {
+                let mut params = QueryParams::CostCompare.parameters();
+                params.extend(PodListQuery::into_params(query));
+                params
+            }
+            QueryParams::EfficiencyScore => {
+                let mut params = RangeQuery::into_params(query);
+                params.extend(EfficiencyScoreQuery::into_params(query));
+                params
+            }
+            QueryParams::Pagination => PaginationQuery::into_params(query),
+            QueryParams::PodFilter => K8sPodQueryRequestDto::into_params(query),
+            QueryParams::ContainerFilter => K8sListQuery::into_params(query),
+            QueryParams::NodeFilter => K8sListNodeQuery::into_params(query),
+            QueryParams::Logs => LogQuery::into_params(query),
+            QueryParams::Backfill => BackfillQuery::into_params(query),
+            QueryParams::SimulateSeed => SimulateSeedQuery::into_params(query),
+            QueryParams::Export => ExportQuery::into_params(query),
+            QueryParams::ClosePeriod => ClosePeriodQuery::into_params(query),
+            QueryParams::CostAllocation => CostAllocationQuery::into_params(query),
+            QueryParams::JobHistory => JobHistoryQuery::into_params(query),
+            QueryParams::BillingImport => BillingImportQuery::into_params(query),
+            QueryParams::BillingRange => BillingRangeQuery::into_params(query),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Body {
+    None,
+    /// JSON body described by the named component schema.
+    Json(&'static str),
+    /// Raw `.tar.zst` backup archive.
+    Archive,
+    /// CSV file (cloud billing export).
+    Csv,
+}
+
+struct Endpoint {
+    method: HttpMethod,
+    /// Lowercase method name, used for operation ids.
+    verb: &'static str,
+    path: String,
+    tag: &'static str,
+    summary: String,
+    query: QueryParams,
+    body: Body,
+}
+
+impl Endpoint {
+    fn new(method: HttpMethod, verb: &'static str, path: &str, tag: &'static str, summary: &str) -> Self {
+        Self {
+            method,
+            verb,
+            path: path.into(),
+            tag,
+            summary: summary.into(),
+            query: QueryParams::None,
+            body: Body::None,
+        }
+    }
+
+    fn query(mut self, query: QueryParams) -> Self {
+        self.query = query;
+        self
+    }
+
+    fn body(mut self, body: Body) -> Self {
+        self.body = body;
+        self
+    }
+}
+
+fn get(path: &str, tag: &'static str, summary: &str) -> Endpoint {
+    Endpoint::new(HttpMethod::Get, "get", path, tag, summary)
+}
+
+fn post(path: &str, tag: &'static str, summary: &str) -> Endpoint {
+    Endpoint::new(HttpMethod::Post, "post", path, tag, summary)
+}
+
+fn put(path: &str, tag: &'static str, summary: &str) -> Endpoint {
+    Endpoint::new(HttpMethod::Put, "put", path, tag, summary)
+}
+
+fn patch(path: &str, tag: &'static str, summary: &str) -> Endpoint {
+    Endpoint::new(HttpMethod::Patch, "patch", path, tag, summary)
+}
+
+fn delete(path: &str, tag: &'static str, summary: &str) -> Endpoint {
+    Endpoint::new(HttpMethod::Delete, "delete", path, tag, summary)
+}
+
+fn metric_endpoints() -> Vec<Endpoint> {
+    let mut endpoints = Vec::new();
+
+    for &(resource, tag, object_param) in METRIC_RESOURCES {
+        let list_query = match resource {
+            "namespaces" => QueryParams::NamespaceList,
+            "pods" => QueryParams::PodList,
+            _ => QueryParams::Range,
+        };
+        for &(view, summary) in METRIC_VIEWS {
+            endpoints.push(
+                get(&format!("/api/v1/metrics/{}/{}", resource, view), tag, summary)
+                    .query(list_query),
+            );
+            if let Some(param) = object_param {
+                endpoints.push(
+                    get(
+                        &format!("/api/v1/metrics/{}/{{{}}}/{}", resource, param, view),
+                        tag,
+                        &format!("{} for one object", summary),
+                    )
+                    .query(QueryParams::Range),
+                );
+            }
+        }
+    }
+
+    for &(view, summary) in METRIC_VIEWS {
+        endpoints.push(
+            post(
+                &format!("/api/v1/metrics/pods/{}", view),
+                "Pod metrics",
+                &format!("{} for the pods listed in the body", summary),
+            )
+            .query(QueryParams::Range)
+            .body(Body::Json("PodListRequest")),
+        );
+    }
+
+    endpoints.push(
+        post(
+            "/api/v1/metrics/query",
+            "Metric query",
+            "Any scope and view from one JSON body, with optional grouping",
+        )
+        .body(Body::Json("MetricQueryRequest")),
+    );
+
+    for (resource, tag) in [("nodes", "Node metrics"), ("pods", "Pod metrics"), ("containers", "Container metrics")] {
+        endpoints.push(
+            get(
+                &format!("/api/v1/metrics/{}/raw/stream", resource),
+                tag,
+                "Raw usage series as NDJSON: a header line, then one series per line",
+            )
+            .query(QueryParams::Range),
+        );
+    }
+
+    for (resource, tag) in [
+        ("nodes", "Node metrics"),
+        ("pods", "Pod metrics"),
+        ("containers", "Container metrics"),
+        ("namespaces", "Namespace metrics"),
+        ("deployments", "Deployment metrics"),
+        ("nodepools", "Node pool metrics"),
+        ("cluster", "Cluster metrics"),
+    ] {
+        endpoints.push(
+            get(
+                &format!("/api/v1/metrics/{}/cost/compare", resource),
+                tag,
+                "Cost summary against a baseline window, with deltas",
+            )
+            .query(match resource {
+                "namespaces" => QueryParams::NamespaceCostCompare,
+                "pods" => QueryParams::PodCostCompare,
+                _ => QueryParams::CostCompare,
+            }),
+        );
+    }
+
+    endpoints.push(
+        get("/api/v1/metrics/pods/{pod_uid}/cost/breakdown", "Pod metrics", "Pod cost split per container")
+            .query(QueryParams::Range),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/pods/reliability", "Pod metrics", "Container restarts, OOM kills and crash-loop cost")
+            .query(QueryParams::Range),
+    );
+    for (view, summary) in [
+        ("raw", "Raw usage series"),
+        ("raw/efficiency", "Usage efficiency"),
+        ("cost", "Cost series"),
+        ("cost/summary", "Cost summary"),
+        ("cost/trend", "Cost trend"),
+    ] {
+        endpoints.push(
+            get(&format!("/api/v1/metrics/nodepools/{}", view), "Node pool metrics", &format!("{} per node pool", summary))
+                .query(QueryParams::Range),
+        );
+        endpoints.push(
+            get(&format!("/api/v1/metrics/nodepools/{{pool}}/{}", view), "Node pool metrics", &format!("{} for one node pool", summary))
+                .query(QueryParams::Range),
+        );
+    }
+    endpoints.push(
+        get("/api/v1/metrics/nodes/cost/by-os", "Node metrics", "Node cost series per operating system")
+            .query(QueryParams::Range),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/nodes/cost/by-os/summary", "Node metrics", "Node cost summary per operating system")
+            .query(QueryParams::Range),
+    );
+    for (resource, param, tag) in [("jobs", "job", "Job metrics"), ("cronjobs", "cronjob", "CronJob metrics")] {
+        for (view, summary) in [
+            ("raw", "Raw usage series"),
+            ("cost", "Cost series"),
+            ("cost/summary", "Cost summary"),
+            ("cost/trend", "Cost trend"),
+        ] {
+            endpoints.push(
+                get(&format!("/api/v1/metrics/{}/{{namespace}}/{{{}}}/{}", resource, param, view), tag, summary)
+                    .query(QueryParams::Range),
+            );
+        }
+    }
+    endpoints.push(
+        get("/api/v1/metrics/cronjobs/{namespace}/{cronjob}/cost/runs", "CronJob metrics", "Cost of each Job run")
+            .query(QueryParams::Range),
+    );
+    for (view, summary) in [("raw", "Capacity and usage series"), ("cost", "Provisioned storage cost, split by unmounted hours")] {
+        endpoints.push(
+            get(&format!("/api/v1/metrics/pvcs/{}", view), "PVC metrics", &format!("{} per claim", summary))
+                .query(QueryParams::Range),
+        );
+        endpoints.push(
+            get(&format!("/api/v1/metrics/pvcs/{{namespace}}/{{pvc}}/{}", view), "PVC metrics", &format!("{} for one claim", summary))
+                .query(QueryParams::Range),
+        );
+    }
+    for (path, summary) in [
+        ("cluster/carbon", "Estimated energy and carbon of the cluster's nodes"),
+        ("nodes/carbon", "Estimated energy and carbon per node"),
+        ("nodes/{node_name}/carbon", "Estimated energy and carbon of one node"),
+        ("namespaces/carbon", "Estimated energy and carbon per namespace"),
+        ("namespaces/{namespace}/carbon", "Estimated energy and carbon of one namespace"),
+        ("pods/{pod_uid}/carbon", "Estimated energy and carbon of one pod"),
+    ] {
+        endpoints.push(get(&format!("/api/v1/metrics/{}", path), "Carbon metrics", summary).query(QueryParams::Range));
+    }
+    endpoints.push(
+        get("/api/v1/metrics/efficiency/scores", "Efficiency metrics", "A–F efficiency grade per team or namespace")
+            .query(QueryParams::EfficiencyScore),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/network/flows", "Network metrics", "Egress per namespace by destination zone")
+            .query(QueryParams::Range),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/network/flows/{namespace}", "Network metrics", "Egress of one namespace by destination zone")
+            .query(QueryParams::Range),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/network/cross-zone-cost", "Network metrics", "Estimated cross-zone transfer per namespace and zone pair")
+            .query(QueryParams::Range),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/cluster/raw/efficiency/trend", "Cluster metrics", "Efficiency per time bucket")
+            .query(QueryParams::Range),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/cluster/cost/accounts", "Cluster metrics", "Cost grouped by cloud account and project")
+            .query(QueryParams::Range),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/cluster/cost/billing", "Cluster metrics", "Cluster cost reconciled against imported cloud billing")
+            .query(QueryParams::BillingRange),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/cost/reconcile", "Cluster metrics", "Cluster cost against the sums of node and namespace costs")
+            .query(QueryParams::Range),
+    );
+    endpoints.push(
+        get("/api/v1/metrics/cluster/cost/events", "Cluster metrics", "Cost series annotated with node scale-up/down events")
+            .query(QueryParams::Range),
+    );
+    endpoints
+}
+
+fn info_endpoints() -> Vec<Endpoint> {
+    const TAG: &str = "Info";
+    const LIVE: &str = "Live Kubernetes info";
+
+    let mut endpoints = vec![
+        get("/api/v1/info/settings", TAG, "Get settings"),
+        put("/api/v1/info/settings", TAG, "Update settings").body(Body::Json("InfoSettingUpsertRequest")),
+        get("/api/v1/info/alerts", TAG, "Get alert configuration"),
+        put("/api/v1/info/alerts", TAG, "Update alert configuration").body(Body::Json("InfoAlertUpsertRequest")),
+        get("/api/v1/info/attribution-rules", TAG, "List team/service/env attribution rules"),
+        post("/api/v1/info/attribution-rules", TAG, "Create an attribution rule")
+            .body(Body::Json("InfoAttributionRuleUpsertRequest")),
+        put("/api/v1/info/attribution-rules/{id}", TAG, "Replace an attribution rule")
+            .body(Body::Json("InfoAttributionRuleUpsertRequest")),
+        delete("/api/v1/info/attribution-rules/{id}", TAG, "Delete an attribution rule"),
+        post("/api/v1/info/attribution-rules/preview", TAG, "Dry-run attribution rules against stored pods")
+            .body(Body::Json("InfoAttributionPreviewRequest")),
+        get("/api/v1/info/attribution/conflicts", TAG, "Pods whose team disagrees with their namespace team"),
+        get("/api/v1/info/fixed-costs", TAG, "List fixed cost line items"),
+        post("/api/v1/info/fixed-costs", TAG, "Create a fixed cost line item")
+            .body(Body::Json("InfoFixedCostUpsertRequest")),
+        put("/api/v1/info/fixed-costs/{id}", TAG, "Replace a fixed cost line item")
+            .body(Body::Json("InfoFixedCostUpsertRequest")),
+        delete("/api/v1/info/fixed-costs/{id}", TAG, "Delete a fixed cost line item"),
+        get("/api/v1/info/carbon", TAG, "Get energy and carbon settings"),
+        put("/api/v1/info/carbon", TAG, "Update energy and carbon settings").body(Body::Json("InfoCarbonUpsertRequest")),
+        get("/api/v1/info/efficiency-targets", TAG, "Get team / namespace efficiency targets"),
+        put("/api/v1/info/efficiency-targets", TAG, "Update efficiency targets").body(Body::Json("InfoEfficiencyTargetUpsertRequest")),
+        get("/api/v1/info/virtual-node-prices", TAG, "Get Fargate / virtual node rates"),
+        put("/api/v1/info/virtual-node-prices", TAG, "Update virtual node rates")
+            .body(Body::Json("InfoVirtualNodePriceUpsertRequest")),
+        get("/api/v1/info/commitments", TAG, "List savings plans and reserved instances"),
+        post("/api/v1/info/commitments", TAG, "Register a savings plan or reserved instance")
+            .body(Body::Json("InfoCommitmentUpsertRequest")),
+        put("/api/v1/info/commitments/{id}", TAG, "Replace a commitment")
+            .body(Body::Json("InfoCommitmentUpsertRequest")),
+        delete("/api/v1/info/commitments/{id}", TAG, "Delete a commitment"),
+        get("/api/v1/info/llm", TAG, "Get LLM configuration"),
+        put("/api/v1/info/llm", TAG, "Update LLM configuration").body(Body::Json("InfoLlmUpsertRequest")),
+        get("/api/v1/info/unit-prices", TAG, "Get unit prices"),
+        put("/api/v1/info/unit-prices", TAG, "Update unit prices").body(Body::Json("InfoUnitPriceUpsertRequest")),
+        get("/api/v1/info/unit-prices/history", TAG, "List effective-dated unit prices"),
+        get("/api/v1/info/versions", TAG, "Get component versions"),
+        post("/api/v1/info/billing/import", TAG, "Import an AWS CUR or GCP billing export slice")
+            .query(QueryParams::BillingImport)
+            .body(Body::Csv),
+        get("/api/v1/info/billing", TAG, "List imported billing days").query(QueryParams::BillingRange),
+        get("/api/v1/info/k8s/store/nodes", TAG, "List stored nodes").query(QueryParams::NodeFilter),
+        get("/api/v1/info/k8s/store/pods", TAG, "List stored pods").query(QueryParams::PodFilter),
+        get("/api/v1/info/k8s/store/containers", TAG, "List stored containers").query(QueryParams::ContainerFilter),
+        get("/api/v1/info/k8s/store/nodes/{node_name}", TAG, "Get stored node"),
+        get("/api/v1/info/k8s/store/pods/{pod_uid}", TAG, "Get stored pod"),
+        get("/api/v1/info/k8s/store/namespaces", TAG, "List stored namespaces with quota limits"),
+        get("/api/v1/info/k8s/store/namespaces/{name}", TAG, "Get stored namespace"),
+        patch("/api/v1/info/k8s/store/namespaces/{name}", TAG, "Update namespace team/service/env")
+            .body(Body::Json("InfoK8sNamespacePatchRequest")),
+        delete("/api/v1/info/k8s/store/namespaces/{name}", TAG, "Delete a stored namespace record"),
+        get("/api/v1/info/k8s/store/containers/{id}", TAG, "Get stored container"),
+        patch("/api/v1/info/k8s/store/nodes/{node_name}/filter", TAG, "Update node team/service/env")
+            .body(Body::Json("InfoK8sNodePatchRequest")),
+        patch("/api/v1/info/k8s/store/nodes/{node_name}/price", TAG, "Update node price")
+            .body(Body::Json("InfoK8sNodePricePatchRequest")),
+        patch("/api/v1/info/k8s/store/pods/{pod_uid}", TAG, "Update pod team/service/env")
+            .body(Body::Json("InfoK8sPodPatchRequest")),
+        post("/api/v1/info/k8s/store/pods/bulk-patch", TAG, "Set team/service/env on every pod matching a selector")
+            .body(Body::Json("InfoK8sPodBulkPatchRequest")),
+        patch("/api/v1/info/k8s/store/containers/{id}", TAG, "Update container team/service/env")
+            .body(Body::Json("InfoK8sContainerPatchRequest")),
+        get("/api/v1/info/k8s/live/namespaces", LIVE, "List namespaces"),
+        get("/api/v1/info/k8s/live/resourcequotas", LIVE, "List resource quotas"),
+        get("/api/v1/info/k8s/live/limitranges", LIVE, "List limit ranges"),
+        get("/api/v1/info/k8s/live/horizontalpodautoscalers", LIVE, "List HPAs"),
+        get("/api/v1/info/k8s/live/nodes/{node_name}", LIVE, "Get node"),
+        get("/api/v1/info/k8s/live/pods/{pod_uid}", LIVE, "Get pod"),
+        get("/api/v1/info/k8s/live/containers/{id}", LIVE, "Get container"),
+        get("/api/v1/info/k8s/live/persistentvolumes/{name}", LIVE, "Get persistent volume"),
+    ];
+
+    // Paginated lists with a namespaced single-object lookup
+    for (kind, label) in [
+        ("deployments", "deployment"),
+        ("statefulsets", "statefulset"),
+        ("daemonsets", "daemonset"),
+        ("jobs", "job"),
+        ("cronjobs", "cronjob"),
+        ("services", "service"),
+        ("ingresses", "ingress"),
+        ("persistentvolumeclaims", "persistent volume claim"),
+    ] {
+        endpoints.push(
+            get(&format!("/api/v1/info/k8s/live/{}", kind), LIVE, &format!("List {}s", label))
+                .query(QueryParams::Pagination),
+        );
+        endpoints.push(get(
+            &format!("/api/v1/info/k8s/live/{}/{{namespace}}/{{name}}", kind),
+            LIVE,
+            &format!("Get {}", label),
+        ));
+    }
+
+    for (kind, label) in [
+        ("persistentvolumes", "persistent volumes"),
+        ("nodes", "nodes"),
+        ("pods", "pods"),
+        ("containers", "containers"),
+    ] {
+        endpoints.push(
+            get(&format!("/api/v1/info/k8s/live/{}", kind), LIVE, &format!("List {}", label))
+                .query(QueryParams::Pagination),
+        );
+    }
+
+    endpoints
+}
+
+fn other_endpoints() -> Vec<Endpoint> {
+    const SYSTEM: &str = "System";
+    const LLM: &str = "LLM";
+    const STATES: &str = "Runtime state";
+    const ALERTS: &str = "Alerts";
+
+    vec![
+        get("/api/v1/system/status", SYSTEM, "System status"),
+        get("/api/v1/system/health", SYSTEM, "Component health"),
+        post("/api/v1/system/backup", SYSTEM, "Create a backup on the server"),
+        get("/api/v1/system/backup/download", SYSTEM, "Download a backup archive (.tar.zst)"),
+        post("/api/v1/system/restore", SYSTEM, "Restore from a backup archive").body(Body::Archive),
+        post("/api/v1/system/resync", SYSTEM, "Resync Kubernetes runtime state"),
+        post("/api/v1/system/aggregate/backfill", SYSTEM, "Recompute hour/day rollups").query(QueryParams::Backfill),
+        post("/api/v1/system/simulate/seed", SYSTEM, "Seed a synthetic cluster with diurnal usage into the store").query(QueryParams::SimulateSeed),
+        post("/api/v1/system/export", SYSTEM, "Export one day of cost allocation to the configured sink").query(QueryParams::Export),
+        get("/api/v1/system/cost-allocation", SYSTEM, "Daily cost allocation records within the caller's tenant scope, from the snapshot for closed months").query(QueryParams::CostAllocation),
+        post("/api/v1/system/close", SYSTEM, "Close a month, freezing its cost allocation").query(QueryParams::ClosePeriod),
+        get("/api/v1/system/close", SYSTEM, "List closed periods"),
+        get("/api/v1/system/jobs", SYSTEM, "Background job history").query(QueryParams::JobHistory),
+        get("/api/v1/system/gc", SYSTEM, "Dry-run report of orphaned metric directories the nightly GC would remove"),
+        get("/api/v1/system/logs", SYSTEM, "List log files"),
+        get("/api/v1/system/logs/{date}", SYSTEM, "Read log lines").query(QueryParams::Logs),
+        post("/api/v1/llm/chat", LLM, "Chat with the configured LLM").body(Body::Json("LlmChatRequest")),
+        post("/api/v1/llm/chat-with-context", LLM, "Chat with cluster context attached, optionally with metric tool calls")
+            .body(Body::Json("LlmChatWithContextRequest")),
+        get("/api/v1/llm/conversations", LLM, "List chat conversations"),
+        post("/api/v1/llm/conversations", LLM, "Create a chat conversation")
+            .body(Body::Json("LlmConversationCreateRequest")),
+        get("/api/v1/llm/conversations/{id}", LLM, "Get a conversation with its messages"),
+        delete("/api/v1/llm/conversations/{id}", LLM, "Delete a conversation"),
+        post("/api/v1/llm/conversations/{id}/messages", LLM, "Append a message to a conversation")
+            .body(Body::Json("LlmConversationMessageRequest")),
+        get("/api/v1/states/k8s", STATES, "Full Kubernetes runtime state"),
+        get("/api/v1/states/k8s/summary", STATES, "Kubernetes runtime state summary"),
+        get("/api/v1/states/alerts", STATES, "Active alerts"),
+        get("/api/v1/states/alerts/all", STATES, "All alerts"),
+        post("/api/v1/states/alerts/fire", STATES, "Fire an alert"),
+        post("/api/v1/states/alerts/resolve/{id}", STATES, "Resolve an alert"),
+        get("/api/v1/alerts/firing", ALERTS, "Alert rules currently firing, per node or workload"),
+    ]
+}
+
+fn endpoints() -> Vec<Endpoint> {
+    let mut endpoints = metric_endpoints();
+    endpoints.extend(info_endpoints());
+    endpoints.extend(other_endpoints());
+    endpoints
+}
+
+// ---------------- Document ----------------
+
+/// Builds the full OpenAPI document.
+pub fn api_doc() -> OpenApi {
+    let mut items: BTreeMap<String, PathItemBuilder> = BTreeMap::new();
+    for endpoint in endpoints() {
+        let op = operation(&endpoint);
+        let item = items.remove(&endpoint.path).unwrap_or_default();
+        items.insert(endpoint.path.clone(), item.operation(endpoint.method, op));
+    }
+
+    let mut paths = PathsBuilder::new();
+    for (path, item) in items {
+        paths = paths.path(path, item.build());
+    }
+
+    OpenApiBuilder::new()
+        .info(
+            InfoBuilder::new()
+                .title("RustCost API")
+                .version(env!("CARGO_PKG_VERSION"))
+                .description(Some(
+                    "Every endpoint returns the `ApiResponse` envelope; `data` holds the endpoint payload.",
+                ))
+                .build(),
+        )
+        .paths(paths.build())
+        .components(Some(components()))
+        .security(Some(vec![
+            SecurityRequirement::new("bearer", Vec::<String>::new()),
+            SecurityRequirement::new("api_key", Vec::<String>::new()),
+        ]))
+        .build()
+}
+
+fn operation(endpoint: &Endpoint) -> Operation {
+    let mut parameters = path_parameters(&endpoint.path);
+    parameters.extend(endpoint.query.parameters());
+
+    let mut op = OperationBuilder::new()
+        .tag(endpoint.tag)
+        .summary(Some(endpoint.summary.clone()))
+        .operation_id(Some(operation_id(endpoint.verb, &endpoint.path)))
+        .parameters(Some(parameters))
+        .response("200", envelope_response("Success"))
+        .response("400", envelope_response("Invalid request"))
+        .response("401", envelope_response("Missing or invalid API key"))
+        .response("403", envelope_response("Role or tenant scope does not allow this request"));
+
+    match endpoint.body {
+        Body::None => {}
+        Body::Json(schema) => {
+            op = op.request_body(Some(
+                RequestBodyBuilder::new()
+                    .content(
+                        "application/json",
+                        ContentBuilder::new()
+                            .schema(Some(Ref::from_schema_name(schema)))
+                            .build(),
+                    )
+                    .required(Some(Required::True))
+                    .build(),
+            ));
+        }
+        Body::Archive => {
+            op = op.request_body(Some(
+                RequestBodyBuilder::new()
+                    .content(
+                        "application/zstd",
+                        ContentBuilder::new()
+                            .schema(Some(
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Binary))),
+                            ))
+                            .build(),
+                    )
+                    .required(Some(Required::True))
+                    .build(),
+            ));
+        }
+        Body::Csv => {
+            op = op.request_body(Some(
+                RequestBodyBuilder::new()
+                    .content(
+                        "text/csv",
+                        ContentBuilder::new()
+                            .schema(Some(ObjectBuilder::new().schema_type(Type::String)))
+                            .build(),
+                    )
+                    .required(Some(Required::True))
+                    .build(),
+            ));
+        }
+    }
+
+    op.build()
+}
+
+fn envelope_response(description: &str) -> utoipa::openapi::Response {
+    ResponseBuilder::new()
+        .description(description)
+        .content(
+            "application/json",
+            ContentBuilder::new()
+                .schema(Some(Ref::from_schema_name("ApiResponse")))
+                .build(),
+        )
+        .build()
+}
+
+/// One required string parameter per `{name}` segment.
+fn path_parameters(path: &str) -> Vec<Parameter> {
+    path.split('/')
+        .filter_map(|seg| seg.strip_prefix('{').and_then(|s| s.strip_suffix('}')))
+        .map(|name| {
+            ParameterBuilder::new()
+                .name(name)
+                .parameter_in(ParameterIn::Path)
+                .required(Required::True)
+                .schema(Some(ObjectBuilder::new().schema_type(Type::String)))
+                .build()
+        })
+        .collect()
+}
+
+/// e.g. `GET /api/v1/metrics/pods/{pod_uid}/cost` → `get_metrics_pods_pod_uid_cost`.
+fn operation_id(verb: &str, path: &str) -> String {
+    let path = path
+        .trim_start_matches("/api/v1/")
+        .replace(['{', '}'], "")
+        .replace(['/', '-'], "_");
+    format!("{}_{}", verb, path)
+}
+
+fn components() -> utoipa::openapi::Components {
+    ComponentsBuilder::new()
+        .schema("ApiResponse", api_response_schema())
+        .schema_from::<MetricGranularity>()
+        .schema_from::<CostMode>()
+        .schema_from::<CostBasis>()
+        .schema_from::<PodListRequest>()
+        .schema_from::<MetricQueryRequest>()
+        .schema_from::<MetricQueryScope>()
+        .schema_from::<MetricQueryView>()
+        .schema_from::<MetricQueryWindow>()
+        .schema_from::<MetricQuerySelectors>()
+        .schema_from::<MetricQueryGroupBy>()
+        .schema_from::<MetricQueryAggregation>()
+        .schema_from::<RelativeRange>()
+        .schema_from::<InfoSettingUpsertRequest>()
+        .schema_from::<InfoAlertUpsertRequest>()
+        .schema_from::<InfoAttributionRuleUpsertRequest>()
+        .schema_from::<InfoAttributionPreviewRequest>()
+        .schema_from::<InfoFixedCostUpsertRequest>()
+        .schema_from::<FixedCostPeriod>()
+        .schema_from::<FixedCostAllocation>()
+        .schema_from::<InfoCarbonUpsertRequest>()
+        .schema_from::<InfoEfficiencyTargetUpsertRequest>()
+        .schema_from::<InfoVirtualNodePriceUpsertRequest>()
+        .schema_from::<VirtualNodeRate>()
+        .schema_from::<PowerProfileEntity>()
+        .schema_from::<InfoCommitmentUpsertRequest>()
+        .schema_from::<CommitmentKind>()
+        .schema_from::<CommitmentTerm>()
+        .schema_from::<AlertRuleUpsertRequest>()
+        .schema_from::<AlertMetricType>()
+        .schema_from::<AlertOperator>()
+        .schema_from::<AlertSeverity>()
+        .schema_from::<AlertChannel>()
+        .schema_from::<AlertSilenceUpsertRequest>()
+        .schema_from::<EfficiencyAlertRuleUpsertRequest>()
+        .schema_from::<AlertEfficiencyScope>()
+        .schema_from::<InfoLlmUpsertRequest>()
+        .schema_from::<LlmProvider>()
+        .schema_from::<InfoUnitPriceUpsertRequest>()
+        .schema_from::<InfoK8sNodePatchRequest>()
+        .schema_from::<InfoK8sNodePricePatchRequest>()
+        .schema_from::<NodePricePeriod>()
+        .schema_from::<InfoK8sPodPatchRequest>()
+        .schema_from::<InfoK8sNamespacePatchRequest>()
+        .schema_from::<InfoK8sPodBulkPatchRequest>()
+        .schema_from::<InfoK8sContainerPatchRequest>()
+        .schema_from::<LlmChatRequest>()
+        .schema_from::<LlmChatWithContextRequest>()
+        .schema_from::<LlmMessage>()
+        .schema_from::<LlmToolCallDto>()
+        .schema_from::<LlmConversationCreateRequest>()
+        .schema_from::<LlmConversationMessageRequest>()
+        .schema_from::<LlmConversationSummaryDto>()
+        .schema_from::<LlmConversationEntity>()
+        .schema_from::<LlmConversationMessageEntity>()
+        .security_scheme(
+            "bearer",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        )
+        .security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-API-Key"))),
+        )
+        .build()
+}
+
+fn api_response_schema() -> Schema {
+    Schema::Object(
+        ObjectBuilder::new()
+            .description(Some("Standard envelope returned by every endpoint"))
+            .property("is_successful", ObjectBuilder::new().schema_type(Type::Boolean))
+            .required("is_successful")
+            .property("data", ObjectBuilder::new().description(Some("Endpoint-specific payload")))
+            .property("error_code", ObjectBuilder::new().schema_type(Type::String))
+            .property("error_msg", ObjectBuilder::new().schema_type(Type::String))
+            .build(),
+    )
+}
+
+/// Minimal Swagger UI page pointing at `/openapi.json` (assets served from unpkg).
+pub const SWAGGER_UI_HTML: &str = r##"<!doctype html>
+<html>
+<head>
+  <meta charset="utf-8">
+  <title>RustCost API</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+  <script>
+    window.ui = SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+  </script>
+</body>
+</html>
+"##;
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn documents_metric_routes_with_path_params() {
+        let doc = api_doc();
+        let paths = &doc.paths.paths;
+
+        assert!(paths.contains_key("/api/v1/metrics/cluster/cost/accounts"));
+
+        let item = &paths["/api/v1/metrics/pods/{pod_uid}/cost/trend"];
+        let params = item.get.as_ref().unwrap().parameters.as_ref().unwrap();
+        assert!(params.iter().any(|p| p.name == "pod_uid"));
+        assert!(params.iter().any(|p| p.name == "granularity"));
+
+        let settings = &paths["/api/v1/info/settings"];
+        assert!(settings.get.is_some() && settings.put.is_some());
+    }
+}
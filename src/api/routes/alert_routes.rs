@@ -0,0 +1,9 @@
+use axum::{routing::get, Router};
+
+use crate::api::controller::alert::AlertController;
+use crate::app_state::AppState;
+
+pub fn alert_routes() -> Router<AppState> {
+    Router::new()
+        .route("/firing", get(AlertController::get_firing))
+}
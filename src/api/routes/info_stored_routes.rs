@@ -1,16 +1,27 @@
 //! Stored info routes (backed by persisted data)
 
 use axum::{
-    routing::{get, patch},
+    extract::DefaultBodyLimit,
+    routing::{get, patch, post, put},
     Router,
 };
 use crate::api::controller::info::alerts::InfoAlertController;
+use crate::api::controller::info::attribution::InfoAttributionRuleController;
+use crate::api::controller::info::billing::InfoBillingController;
+use crate::api::controller::info::carbon::InfoCarbonController;
+use crate::api::controller::info::efficiency_target::InfoEfficiencyTargetController;
+use crate::api::controller::info::virtual_node_price::InfoVirtualNodePriceController;
+use crate::api::controller::info::commitment::InfoCommitmentController;
+use crate::api::controller::info::fixed_cost::InfoFixedCostController;
 use crate::api::controller::info::llm::InfoLlmController;
 use crate::api::controller::info::info_controller::InfoController;
-use crate::api::controller::info::k8s::{container, node, pod};
+use crate::api::controller::info::k8s::{container, namespace, node, pod};
 use crate::api::controller::info::setting::InfoSettingController;
 use crate::app_state::AppState;
 
+/// Billing export slices are far larger than JSON payloads (a day of CUR easily tops 2 MB).
+const BILLING_IMPORT_MAX_BYTES: usize = 256 * 1024 * 1024;
+
 pub fn info_stored_routes() -> Router<AppState> {
     Router::new()
         .route(
@@ -23,6 +34,59 @@ pub fn info_stored_routes() -> Router<AppState> {
             get(InfoAlertController::get_info_alerts)
                 .put(InfoAlertController::upsert_info_alerts),
         )
+        .route(
+            "/attribution-rules",
+            get(InfoAttributionRuleController::list_attribution_rules)
+                .post(InfoAttributionRuleController::create_attribution_rule),
+        )
+        .route(
+            "/attribution-rules/preview",
+            post(InfoAttributionRuleController::preview_attribution_rules),
+        )
+        .route(
+            "/attribution-rules/{id}",
+            put(InfoAttributionRuleController::update_attribution_rule)
+                .delete(InfoAttributionRuleController::delete_attribution_rule),
+        )
+        .route(
+            "/attribution/conflicts",
+            get(InfoAttributionRuleController::get_attribution_conflicts),
+        )
+        .route(
+            "/fixed-costs",
+            get(InfoFixedCostController::list_fixed_costs)
+                .post(InfoFixedCostController::create_fixed_cost),
+        )
+        .route(
+            "/fixed-costs/{id}",
+            put(InfoFixedCostController::update_fixed_cost)
+                .delete(InfoFixedCostController::delete_fixed_cost),
+        )
+        .route(
+            "/carbon",
+            get(InfoCarbonController::get_info_carbon)
+                .put(InfoCarbonController::upsert_info_carbon),
+        )
+        .route(
+            "/efficiency-targets",
+            get(InfoEfficiencyTargetController::get_info_efficiency_targets)
+                .put(InfoEfficiencyTargetController::upsert_info_efficiency_targets),
+        )
+        .route(
+            "/virtual-node-prices",
+            get(InfoVirtualNodePriceController::get_info_virtual_node_prices)
+                .put(InfoVirtualNodePriceController::upsert_info_virtual_node_prices),
+        )
+        .route(
+            "/commitments",
+            get(InfoCommitmentController::list_commitments)
+                .post(InfoCommitmentController::create_commitment),
+        )
+        .route(
+            "/commitments/{id}",
+            put(InfoCommitmentController::update_commitment)
+                .delete(InfoCommitmentController::delete_commitment),
+        )
         .route(
             "/llm",
             get(InfoLlmController::get_info_llm)
@@ -33,12 +97,28 @@ pub fn info_stored_routes() -> Router<AppState> {
             get(InfoController::get_info_unit_prices)
                 .put(InfoController::upsert_info_unit_prices),
         )
+        .route("/unit-prices/history", get(InfoController::get_info_unit_price_history))
         .route("/versions", get(InfoController::get_info_versions))
+        .route("/billing", get(InfoBillingController::list_billing))
+        .route(
+            "/billing/import",
+            post(InfoBillingController::import_billing).layer(DefaultBodyLimit::max(BILLING_IMPORT_MAX_BYTES)),
+        )
         .route(
             "/k8s/store/nodes",
             get(node::InfoK8sNodeController::list_k8s_nodes),
         )
         .route("/k8s/store/pods", get(pod::InfoK8sPodController::list_k8s_pods))
+        .route(
+            "/k8s/store/namespaces",
+            get(namespace::InfoK8sNamespaceController::list_info_k8s_namespaces),
+        )
+        .route(
+            "/k8s/store/namespaces/{name}",
+            get(namespace::InfoK8sNamespaceController::get_info_k8s_namespace)
+                .patch(namespace::InfoK8sNamespaceController::patch_info_k8s_namespace)
+                .delete(namespace::InfoK8sNamespaceController::delete_info_k8s_namespace),
+        )
         .route(
             "/k8s/store/containers",
             get(container::InfoK8sContainerController::list_k8s_containers),
@@ -67,6 +147,10 @@ pub fn info_stored_routes() -> Router<AppState> {
             "/k8s/store/pods/{pod_uid}",
             patch(pod::InfoK8sPodController::patch_info_k8s_pod),
         )
+        .route(
+            "/k8s/store/pods/bulk-patch",
+            post(pod::InfoK8sPodController::bulk_patch_info_k8s_pods),
+        )
         .route(
             "/k8s/store/containers/{id}",
             patch(container::InfoK8sContainerController::patch_info_k8s_container),
@@ -1,4 +1,4 @@
-use axum::{routing::post, Router};
+use axum::{routing::{get, post}, Router};
 
 use crate::api::controller::llm::LlmController;
 use crate::app_state::AppState;
@@ -7,4 +7,13 @@ pub fn llm_routes() -> Router<AppState> {
     Router::new()
         .route("/chat", post(LlmController::chat))
         .route("/chat-with-context", post(LlmController::chat_with_context))
+        .route(
+            "/conversations",
+            get(LlmController::list_conversations).post(LlmController::create_conversation),
+        )
+        .route(
+            "/conversations/{id}",
+            get(LlmController::get_conversation).delete(LlmController::delete_conversation),
+        )
+        .route("/conversations/{id}/messages", post(LlmController::append_conversation_message))
 }
@@ -1,13 +1,21 @@
 //! Metrics routes (e.g., /api/v1/metrics/*)
 
-use axum::{routing::get, Router};
+use axum::{middleware, routing::{get, post}, Router};
 
 use crate::api::controller::metric::k8s::namespace::K8sNamespaceMetricsController;
 use crate::api::controller::metric::k8s::node::K8sNodeMetricsController;
 use crate::api::controller::metric::k8s::container::K8sContainerMetricsController;
 use crate::api::controller::metric::k8s::deployment::K8sDeploymentMetricsController;
+use crate::api::controller::metric::k8s::job::K8sJobMetricsController;
+use crate::api::controller::metric::k8s::node_pool::K8sNodePoolMetricsController;
 use crate::api::controller::metric::k8s::pod::K8sPodMetricsController;
+use crate::api::controller::metric::k8s::carbon::K8sCarbonMetricsController;
+use crate::api::controller::metric::k8s::efficiency::K8sEfficiencyMetricsController;
+use crate::api::controller::metric::k8s::network::K8sNetworkMetricsController;
+use crate::api::controller::metric::k8s::pvc::K8sPvcMetricsController;
+use crate::api::controller::metric::k8s::query::K8sMetricQueryController;
 use crate::api::controller::metric::k8s::cluster::K8sClusterMetricsController;
+use crate::api::middleware::granularity_guard::guard_granularity;
 use crate::app_state::AppState;
 
 /// Build the router for metrics endpoints under /api/v1/metrics
@@ -15,6 +23,7 @@ pub fn metrics_routes() -> Router<AppState> {
     Router::new()
         // Nodes
         .route("/nodes/raw", get(K8sNodeMetricsController::get_metric_k8s_nodes_raw))
+        .route("/nodes/raw/stream", get(K8sNodeMetricsController::get_metric_k8s_nodes_raw_stream))
         .route("/nodes/raw/summary", get(K8sNodeMetricsController::get_metric_k8s_nodes_raw_summary))
         .route("/nodes/raw/efficiency", get(K8sNodeMetricsController::get_metric_k8s_nodes_raw_efficiency))
         .route("/nodes/{node_name}/raw", get(K8sNodeMetricsController::get_metric_k8s_node_raw))
@@ -23,26 +32,37 @@ pub fn metrics_routes() -> Router<AppState> {
         .route("/nodes/cost", get(K8sNodeMetricsController::get_metric_k8s_nodes_cost))
         .route("/nodes/cost/summary", get(K8sNodeMetricsController::get_metric_k8s_nodes_cost_summary))
         .route("/nodes/cost/trend", get(K8sNodeMetricsController::get_metric_k8s_nodes_cost_trend))
+        .route("/nodes/cost/compare", get(K8sNodeMetricsController::get_metric_k8s_nodes_cost_compare))
+        .route("/nodes/cost/by-os", get(K8sNodePoolMetricsController::get_metric_k8s_nodes_os_cost))
+        .route("/nodes/cost/by-os/summary", get(K8sNodePoolMetricsController::get_metric_k8s_nodes_os_cost_summary))
         .route("/nodes/{node_name}/cost", get(K8sNodeMetricsController::get_metric_k8s_node_cost))
         .route("/nodes/{node_name}/cost/summary", get(K8sNodeMetricsController::get_metric_k8s_node_cost_summary))
         .route("/nodes/{node_name}/cost/trend", get(K8sNodeMetricsController::get_metric_k8s_node_cost_trend))
+        .route("/nodes/carbon", get(K8sCarbonMetricsController::get_metric_k8s_nodes_carbon))
+        .route("/nodes/{node_name}/carbon", get(K8sCarbonMetricsController::get_metric_k8s_node_carbon))
 
         // Pods
-        .route("/pods/raw", get(K8sPodMetricsController::get_metric_k8s_pods_raw))
-        .route("/pods/raw/summary", get(K8sPodMetricsController::get_metric_k8s_pods_raw_summary))
-        .route("/pods/raw/efficiency", get(K8sPodMetricsController::get_metric_k8s_pods_raw_efficiency))
+        .route("/pods/raw", get(K8sPodMetricsController::get_metric_k8s_pods_raw).post(K8sPodMetricsController::post_metric_k8s_pods_raw))
+        .route("/pods/raw/stream", get(K8sPodMetricsController::get_metric_k8s_pods_raw_stream))
+        .route("/pods/raw/summary", get(K8sPodMetricsController::get_metric_k8s_pods_raw_summary).post(K8sPodMetricsController::post_metric_k8s_pods_raw_summary))
+        .route("/pods/raw/efficiency", get(K8sPodMetricsController::get_metric_k8s_pods_raw_efficiency).post(K8sPodMetricsController::post_metric_k8s_pods_raw_efficiency))
         .route("/pods/{pod_uid}/raw", get(K8sPodMetricsController::get_metric_k8s_pod_raw))
         .route("/pods/{pod_uid}/raw/summary", get(K8sPodMetricsController::get_metric_k8s_pod_raw_summary))
         .route("/pods/{pod_uid}/raw/efficiency", get(K8sPodMetricsController::get_metric_k8s_pod_raw_efficiency))
-        .route("/pods/cost", get(K8sPodMetricsController::get_metric_k8s_pods_cost))
-        .route("/pods/cost/summary", get(K8sPodMetricsController::get_metric_k8s_pods_cost_summary))
-        .route("/pods/cost/trend", get(K8sPodMetricsController::get_metric_k8s_pods_cost_trend))
+        .route("/pods/cost", get(K8sPodMetricsController::get_metric_k8s_pods_cost).post(K8sPodMetricsController::post_metric_k8s_pods_cost))
+        .route("/pods/cost/summary", get(K8sPodMetricsController::get_metric_k8s_pods_cost_summary).post(K8sPodMetricsController::post_metric_k8s_pods_cost_summary))
+        .route("/pods/cost/trend", get(K8sPodMetricsController::get_metric_k8s_pods_cost_trend).post(K8sPodMetricsController::post_metric_k8s_pods_cost_trend))
+        .route("/pods/cost/compare", get(K8sPodMetricsController::get_metric_k8s_pods_cost_compare))
         .route("/pods/{pod_uid}/cost", get(K8sPodMetricsController::get_metric_k8s_pod_cost))
         .route("/pods/{pod_uid}/cost/summary", get(K8sPodMetricsController::get_metric_k8s_pod_cost_summary))
         .route("/pods/{pod_uid}/cost/trend", get(K8sPodMetricsController::get_metric_k8s_pod_cost_trend))
+        .route("/pods/{pod_uid}/cost/breakdown", get(K8sPodMetricsController::get_metric_k8s_pod_cost_breakdown))
+        .route("/pods/{pod_uid}/carbon", get(K8sCarbonMetricsController::get_metric_k8s_pod_carbon))
+        .route("/pods/reliability", get(K8sPodMetricsController::get_metric_k8s_pods_reliability))
 
         // Containers
         .route("/containers/raw", get(K8sContainerMetricsController::get_metric_k8s_containers_raw))
+        .route("/containers/raw/stream", get(K8sContainerMetricsController::get_metric_k8s_containers_raw_stream))
         .route("/containers/raw/summary", get(K8sContainerMetricsController::get_metric_k8s_containers_raw_summary))
         .route("/containers/raw/efficiency", get(K8sContainerMetricsController::get_metric_k8s_containers_raw_efficiency))
         .route("/containers/{id}/raw", get(K8sContainerMetricsController::get_metric_k8s_container_raw))
@@ -51,10 +71,24 @@ pub fn metrics_routes() -> Router<AppState> {
         .route("/containers/cost", get(K8sContainerMetricsController::get_metric_k8s_containers_cost))
         .route("/containers/cost/summary", get(K8sContainerMetricsController::get_metric_k8s_containers_cost_summary))
         .route("/containers/cost/trend", get(K8sContainerMetricsController::get_metric_k8s_containers_cost_trend))
+        .route("/containers/cost/compare", get(K8sContainerMetricsController::get_metric_k8s_containers_cost_compare))
         .route("/containers/{id}/cost", get(K8sContainerMetricsController::get_metric_k8s_container_cost))
         .route("/containers/{id}/cost/summary", get(K8sContainerMetricsController::get_metric_k8s_container_cost_summary))
         .route("/containers/{id}/cost/trend", get(K8sContainerMetricsController::get_metric_k8s_container_cost_trend))
 
+        // Node pools
+        .route("/nodepools/raw", get(K8sNodePoolMetricsController::get_metric_k8s_nodepools_raw))
+        .route("/nodepools/raw/efficiency", get(K8sNodePoolMetricsController::get_metric_k8s_nodepools_raw_efficiency))
+        .route("/nodepools/cost", get(K8sNodePoolMetricsController::get_metric_k8s_nodepools_cost))
+        .route("/nodepools/cost/summary", get(K8sNodePoolMetricsController::get_metric_k8s_nodepools_cost_summary))
+        .route("/nodepools/cost/trend", get(K8sNodePoolMetricsController::get_metric_k8s_nodepools_cost_trend))
+        .route("/nodepools/cost/compare", get(K8sNodePoolMetricsController::get_metric_k8s_nodepools_cost_compare))
+        .route("/nodepools/{pool}/raw", get(K8sNodePoolMetricsController::get_metric_k8s_nodepool_raw))
+        .route("/nodepools/{pool}/raw/efficiency", get(K8sNodePoolMetricsController::get_metric_k8s_nodepool_raw_efficiency))
+        .route("/nodepools/{pool}/cost", get(K8sNodePoolMetricsController::get_metric_k8s_nodepool_cost))
+        .route("/nodepools/{pool}/cost/summary", get(K8sNodePoolMetricsController::get_metric_k8s_nodepool_cost_summary))
+        .route("/nodepools/{pool}/cost/trend", get(K8sNodePoolMetricsController::get_metric_k8s_nodepool_cost_trend))
+
         // Namespaces
         .route("/namespaces/raw", get(K8sNamespaceMetricsController::get_metric_k8s_namespaces_raw))
         .route("/namespaces/raw/summary", get(K8sNamespaceMetricsController::get_metric_k8s_namespaces_raw_summary))
@@ -65,9 +99,12 @@ pub fn metrics_routes() -> Router<AppState> {
         .route("/namespaces/cost", get(K8sNamespaceMetricsController::get_metric_k8s_namespaces_cost))
         .route("/namespaces/cost/summary", get(K8sNamespaceMetricsController::get_metric_k8s_namespaces_cost_summary))
         .route("/namespaces/cost/trend", get(K8sNamespaceMetricsController::get_metric_k8s_namespaces_cost_trend))
+        .route("/namespaces/cost/compare", get(K8sNamespaceMetricsController::get_metric_k8s_namespaces_cost_compare))
         .route("/namespaces/{namespace}/cost", get(K8sNamespaceMetricsController::get_metric_k8s_namespace_cost))
         .route("/namespaces/{namespace}/cost/summary", get(K8sNamespaceMetricsController::get_metric_k8s_namespace_cost_summary))
         .route("/namespaces/{namespace}/cost/trend", get(K8sNamespaceMetricsController::get_metric_k8s_namespace_cost_trend))
+        .route("/namespaces/carbon", get(K8sCarbonMetricsController::get_metric_k8s_namespaces_carbon))
+        .route("/namespaces/{namespace}/carbon", get(K8sCarbonMetricsController::get_metric_k8s_namespace_carbon))
 
         // Deployments
         .route("/deployments/raw", get(K8sDeploymentMetricsController::get_metric_k8s_deployments_raw))
@@ -79,15 +116,51 @@ pub fn metrics_routes() -> Router<AppState> {
         .route("/deployments/cost", get(K8sDeploymentMetricsController::get_metric_k8s_deployments_cost))
         .route("/deployments/cost/summary", get(K8sDeploymentMetricsController::get_metric_k8s_deployments_cost_summary))
         .route("/deployments/cost/trend", get(K8sDeploymentMetricsController::get_metric_k8s_deployments_cost_trend))
+        .route("/deployments/cost/compare", get(K8sDeploymentMetricsController::get_metric_k8s_deployments_cost_compare))
         .route("/deployments/{deployment}/cost", get(K8sDeploymentMetricsController::get_metric_k8s_deployment_cost))
         .route("/deployments/{deployment}/cost/summary", get(K8sDeploymentMetricsController::get_metric_k8s_deployment_cost_summary))
         .route("/deployments/{deployment}/cost/trend", get(K8sDeploymentMetricsController::get_metric_k8s_deployment_cost_trend))
 
+        // Jobs and CronJobs
+        .route("/jobs/{namespace}/{job}/raw", get(K8sJobMetricsController::get_metric_k8s_job_raw))
+        .route("/jobs/{namespace}/{job}/cost", get(K8sJobMetricsController::get_metric_k8s_job_cost))
+        .route("/jobs/{namespace}/{job}/cost/summary", get(K8sJobMetricsController::get_metric_k8s_job_cost_summary))
+        .route("/jobs/{namespace}/{job}/cost/trend", get(K8sJobMetricsController::get_metric_k8s_job_cost_trend))
+        .route("/cronjobs/{namespace}/{cronjob}/raw", get(K8sJobMetricsController::get_metric_k8s_cronjob_raw))
+        .route("/cronjobs/{namespace}/{cronjob}/cost", get(K8sJobMetricsController::get_metric_k8s_cronjob_cost))
+        .route("/cronjobs/{namespace}/{cronjob}/cost/summary", get(K8sJobMetricsController::get_metric_k8s_cronjob_cost_summary))
+        .route("/cronjobs/{namespace}/{cronjob}/cost/trend", get(K8sJobMetricsController::get_metric_k8s_cronjob_cost_trend))
+        .route("/cronjobs/{namespace}/{cronjob}/cost/runs", get(K8sJobMetricsController::get_metric_k8s_cronjob_cost_runs))
+
+        // Persistent volume claims
+        .route("/pvcs/raw", get(K8sPvcMetricsController::get_metric_k8s_pvcs_raw))
+        .route("/pvcs/cost", get(K8sPvcMetricsController::get_metric_k8s_pvcs_cost))
+        .route("/pvcs/{namespace}/{pvc}/raw", get(K8sPvcMetricsController::get_metric_k8s_pvc_raw))
+        .route("/pvcs/{namespace}/{pvc}/cost", get(K8sPvcMetricsController::get_metric_k8s_pvc_cost))
+
+        // Network flows
+        .route("/efficiency/scores", get(K8sEfficiencyMetricsController::get_metric_k8s_efficiency_scores))
+        .route("/network/flows", get(K8sNetworkMetricsController::get_metric_k8s_network_flows))
+        .route("/network/flows/{namespace}", get(K8sNetworkMetricsController::get_metric_k8s_namespace_network_flows))
+        .route("/network/cross-zone-cost", get(K8sNetworkMetricsController::get_metric_k8s_network_cross_zone_cost))
+
         // Cluster
         .route("/cluster/raw", get(K8sClusterMetricsController::get_metric_k8s_cluster_raw))
         .route("/cluster/raw/summary", get(K8sClusterMetricsController::get_metric_k8s_cluster_raw_summary))
         .route("/cluster/raw/efficiency", get(K8sClusterMetricsController::get_metric_k8s_cluster_raw_efficiency))
+        .route("/cluster/raw/efficiency/trend", get(K8sClusterMetricsController::get_metric_k8s_cluster_raw_efficiency_trend))
         .route("/cluster/cost", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost))
         .route("/cluster/cost/summary", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost_summary))
         .route("/cluster/cost/trend", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost_trend))
+        .route("/cluster/carbon", get(K8sCarbonMetricsController::get_metric_k8s_cluster_carbon))
+        .route("/cluster/cost/events", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost_events))
+        .route("/cluster/cost/compare", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost_compare))
+        .route("/cluster/cost/accounts", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost_by_account))
+        .route("/cluster/cost/billing", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost_billing_reconcile))
+        .route("/cost/reconcile", get(K8sClusterMetricsController::get_metric_k8s_cost_reconcile))
+
+        // Structured query (JSON body)
+        .route("/query", post(K8sMetricQueryController::post_metric_k8s_query))
+        // Reject explicit granularities too fine for the requested window
+        .layer(middleware::from_fn(guard_granularity))
 }
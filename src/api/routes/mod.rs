@@ -7,3 +7,4 @@ pub mod info_live_routes;
 pub mod system_routes;
 pub(crate) mod state_routes;
 pub mod llm_routes;
+pub mod alert_routes;
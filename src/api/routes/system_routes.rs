@@ -9,7 +9,19 @@ pub fn system_routes() -> Router<AppState> {
         .route("/status", get(SystemController::status))
         .route("/health", get(SystemController::health))
         .route("/backup", post(SystemController::backup))
+        .route("/backup/download", get(SystemController::download_backup))
+        .route("/restore", post(SystemController::restore))
         .route("/resync", post(SystemController::resync))
+        .route("/aggregate/backfill", post(SystemController::backfill_aggregates))
+        .route("/simulate/seed", post(SystemController::seed_simulation))
+        .route("/export", post(SystemController::export_cost_allocation))
+        .route("/cost-allocation", get(SystemController::get_cost_allocation))
+        .route(
+            "/close",
+            get(SystemController::list_closed_periods).post(SystemController::close_cost_period),
+        )
+        .route("/jobs", get(SystemController::get_job_history))
+        .route("/gc", get(SystemController::get_metric_gc_report))
 
         .route("/logs/{date}", get(SystemController::get_system_log_lines))
         .route("/logs", get(SystemController::get_system_log_file_list))
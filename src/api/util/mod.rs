@@ -1,2 +1,4 @@
 pub mod validation_ext;
-pub mod json;
\ No newline at end of file
+pub mod json;
+pub mod tenant_scope;
+pub mod ndjson;
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use axum::body::{Body, Bytes};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+use crate::core::persistence::repositories::{repositories, with_repositories_blocking};
+use crate::domain::metric::k8s::common::dto::metric_k8s_stream_dto::MetricSeriesStream;
+use crate::errors::{internal_error, AppError};
+
+/// Series buffered ahead of a slow client before the producer blocks.
+const STREAM_BUFFER: usize = 4;
+
+/// Streams a raw metric query as NDJSON: the header line first, then one series per line.
+///
+/// Series are read on a blocking thread while the client consumes earlier lines. Once
+/// the 200 status is sent an error can only be reported in-band, as a final
+/// `{"error": "..."}` line.
+pub fn to_ndjson(result: Result<MetricSeriesStream>) -> Result<Response, AppError> {
+    let stream = result.map_err(internal_error)?;
+    let header_line = ndjson_line(&stream.header).map_err(internal_error)?;
+
+    let (tx, rx) = mpsc::channel::<Result<Bytes, std::io::Error>>(STREAM_BUFFER);
+    let repos = repositories();
+    tokio::task::spawn_blocking(move || {
+        with_repositories_blocking(repos, || {
+            if tx.blocking_send(Ok(header_line)).is_err() {
+                return;
+            }
+            for item in stream.series {
+                let line = item.and_then(|series| ndjson_line(&series));
+                let failed = line.is_err();
+                let line = line.unwrap_or_else(|e| error_line(&e));
+                // A closed channel means the client went away; stop reading
+                if tx.blocking_send(Ok(line)).is_err() || failed {
+                    return;
+                }
+            }
+        })
+    });
+
+    let body = Body::from_stream(futures::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|chunk| (chunk, rx))
+    }));
+
+    Ok(([(header::CONTENT_TYPE, "application/x-ndjson")], body).into_response())
+}
+
+fn ndjson_line<T: Serialize>(value: &T) -> Result<Bytes> {
+    let mut line = serde_json::to_vec(value)?;
+    line.push(b'\n');
+    Ok(Bytes::from(line))
+}
+
+fn error_line(err: &anyhow::Error) -> Bytes {
+    let mut line = serde_json::to_vec(&serde_json::json!({ "error": err.to_string() }))
+        .unwrap_or_default();
+    line.push(b'\n');
+    Bytes::from(line)
+}
@@ -0,0 +1,330 @@
+//! Namespace/team scope attached to each API request by the auth middleware.
+//!
+//! A scoped key only sees pods in its namespaces or owned by its teams; namespace
+//! totals are limited to whole namespaces it owns, and node/cluster views (which mix
+//! every tenant's workloads) are refused.
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::api::dto::metrics_query_dto::MetricQueryScope;
+use crate::app_state::AppState;
+use tracing::warn;
+
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::errors::AppError;
+
+#[derive(Debug, Clone, Default)]
+pub struct TenantScope {
+    /// `None` means unrestricted.
+    allowed: Option<Allowed>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Allowed {
+    namespaces: HashSet<String>,
+    teams: HashSet<String>,
+}
+
+impl Allowed {
+    fn allows_pod(&self, pod: &InfoPodEntity) -> bool {
+        self.allows(pod.namespace.as_deref(), pod.team.as_deref())
+    }
+
+    /// Team is stored on pod info as a comma-separated list (same matching as the `team` filter).
+    fn allows(&self, namespace: Option<&str>, team: Option<&str>) -> bool {
+        namespace.is_some_and(|ns| self.namespaces.contains(ns))
+            || team.is_some_and(|t| t.split(',').any(|x| self.teams.contains(&x.trim().to_lowercase())))
+    }
+}
+
+impl FromStr for TenantScope {
+    type Err = anyhow::Error;
+
+    /// `payments|checkout|team:growth` — bare entries are namespaces.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut allowed = Allowed::default();
+        for item in s.split('|').map(str::trim).filter(|i| !i.is_empty()) {
+            match item.strip_prefix("team:") {
+                Some(team) => allowed.teams.insert(team.trim().to_lowercase()),
+                None => allowed.namespaces.insert(item.to_string()),
+            };
+        }
+        if allowed.namespaces.is_empty() && allowed.teams.is_empty() {
+            anyhow::bail!("Empty tenant scope '{}'", s);
+        }
+        Ok(Self { allowed: Some(allowed) })
+    }
+}
+
+impl TenantScope {
+    pub fn is_restricted(&self) -> bool {
+        self.allowed.is_some()
+    }
+
+    pub fn allows_namespace(&self, namespace: &str) -> bool {
+        match &self.allowed {
+            None => true,
+            Some(allowed) => allowed.namespaces.contains(namespace),
+        }
+    }
+
+    /// Whether a pod-level record of `namespace` owned by `team` is visible, e.g.
+    /// a cost allocation row.
+    pub fn allows_attribution(&self, namespace: &str, team: &str) -> bool {
+        match &self.allowed {
+            None => true,
+            Some(allowed) => allowed.allows(Some(namespace), Some(team)),
+        }
+    }
+
+    /// Node, cluster and LLM-context views aggregate every tenant, so they need an unscoped key.
+    pub fn require_unrestricted(&self) -> Result<(), AppError> {
+        if self.is_restricted() {
+            return Err(AppError::Forbidden(
+                "Cluster-wide data is not available to namespace-scoped keys".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn check_namespace(&self, namespace: &str) -> Result<(), AppError> {
+        if self.allows_namespace(namespace) {
+            Ok(())
+        } else {
+            Err(forbidden("namespace", namespace))
+        }
+    }
+
+    /// Namespaces the caller may see.
+    pub async fn namespaces(&self, state: &AppState) -> Vec<String> {
+        let mut names = state.k8s_state.get_namespaces().await;
+        names.retain(|ns| self.allows_namespace(ns));
+        names
+    }
+
+    /// Pod UIDs the caller may see.
+    pub async fn pod_uids(&self, state: &AppState) -> Vec<String> {
+        let Some(allowed) = &self.allowed else {
+            return state.k8s_state.get_pods().await;
+        };
+        visible_pods(allowed, state)
+            .await
+            .into_iter()
+            .filter_map(|p| p.pod_uid)
+            .collect()
+    }
+
+    pub async fn check_pod(&self, state: &AppState, pod_uid: &str) -> Result<(), AppError> {
+        if !self.is_restricted() || self.pod_uids(state).await.iter().any(|u| u == pod_uid) {
+            Ok(())
+        } else {
+            Err(forbidden("pod", pod_uid))
+        }
+    }
+
+    /// Like [`Self::check_pod`] for a whole selection, reading the visible pods once.
+    pub async fn check_pods(&self, state: &AppState, pod_uids: &[String]) -> Result<(), AppError> {
+        if !self.is_restricted() {
+            return Ok(());
+        }
+        let visible: HashSet<String> = self.pod_uids(state).await.into_iter().collect();
+        match pod_uids.iter().find(|uid| !visible.contains(*uid)) {
+            Some(uid) => Err(forbidden("pod", uid)),
+            None => Ok(()),
+        }
+    }
+
+    /// Container keys (`<pod_uid>-<container>`) the caller may see.
+    pub async fn container_keys(&self, state: &AppState) -> Vec<String> {
+        let Some(allowed) = &self.allowed else {
+            return state.k8s_state.get_container_keys().await;
+        };
+        visible_pods(allowed, state)
+            .await
+            .into_iter()
+            .filter_map(|p| {
+                let uid = p.pod_uid?;
+                let names = p.container_names.unwrap_or_default();
+                Some(names.into_iter().map(move |c| format!("{}-{}", uid, c)))
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub async fn check_container(&self, state: &AppState, id: &str) -> Result<(), AppError> {
+        if !self.is_restricted() || self.container_keys(state).await.iter().any(|k| k == id) {
+            Ok(())
+        } else {
+            Err(forbidden("container", id))
+        }
+    }
+
+    /// Like [`Self::check_container`] for a whole selection, reading the visible pods once.
+    pub async fn check_containers(&self, state: &AppState, ids: &[String]) -> Result<(), AppError> {
+        if !self.is_restricted() {
+            return Ok(());
+        }
+        let visible: HashSet<String> = self.container_keys(state).await.into_iter().collect();
+        match ids.iter().find(|id| !visible.contains(*id)) {
+            Some(id) => Err(forbidden("container", id)),
+            None => Ok(()),
+        }
+    }
+
+    /// Deployments whose pods are all visible to the caller.
+    pub async fn deployments(&self, state: &AppState) -> Vec<String> {
+        let all = state.k8s_state.get_deployments().await;
+        if !self.is_restricted() {
+            return all;
+        }
+
+        let visible: HashSet<String> = self.pod_uids(state).await.into_iter().collect();
+        let mut allowed = Vec::new();
+        for deployment in all {
+            let pods = state.k8s_state.get_pods_by_deployment(&deployment).await;
+            if !pods.is_empty() && pods.iter().all(|p| visible.contains(&p.uid)) {
+                allowed.push(deployment);
+            }
+        }
+        allowed
+    }
+
+    pub async fn check_deployment(&self, state: &AppState, deployment: &str) -> Result<(), AppError> {
+        if !self.is_restricted() || self.deployments(state).await.iter().any(|d| d == deployment) {
+            Ok(())
+        } else {
+            Err(forbidden("deployment", deployment))
+        }
+    }
+
+    /// Targets of a structured query: the requested keys (each checked against the
+    /// scope), or every object of `scope` the caller may see when none were given.
+    pub async fn query_targets(
+        &self,
+        state: &AppState,
+        scope: MetricQueryScope,
+        requested: Vec<String>,
+    ) -> Result<Vec<String>, AppError> {
+        match scope {
+            MetricQueryScope::Cluster => {
+                self.require_unrestricted()?;
+                Ok(state.k8s_state.get_nodes().await)
+            }
+            MetricQueryScope::Node => {
+                self.require_unrestricted()?;
+                if requested.is_empty() {
+                    Ok(state.k8s_state.get_nodes().await)
+                } else {
+                    Ok(requested)
+                }
+            }
+            MetricQueryScope::Namespace => {
+                if requested.is_empty() {
+                    return Ok(self.namespaces(state).await);
+                }
+                for ns in &requested {
+                    self.check_namespace(ns)?;
+                }
+                Ok(requested)
+            }
+            MetricQueryScope::Deployment => {
+                if requested.is_empty() {
+                    return Ok(self.deployments(state).await);
+                }
+                for deployment in &requested {
+                    self.check_deployment(state, deployment).await?;
+                }
+                Ok(requested)
+            }
+            MetricQueryScope::Pod => {
+                if requested.is_empty() {
+                    return Ok(self.pod_uids(state).await);
+                }
+                self.check_pods(state, &requested).await?;
+                Ok(requested)
+            }
+            MetricQueryScope::Container => {
+                if requested.is_empty() {
+                    return Ok(self.container_keys(state).await);
+                }
+                self.check_containers(state, &requested).await?;
+                Ok(requested)
+            }
+        }
+    }
+}
+
+/// Pods the caller may see, from pod info in one read. Deleted pods are
+/// included, so past windows stay queryable after a pod is gone; a failed read
+/// shows nothing rather than everything.
+async fn visible_pods(allowed: &Allowed, state: &AppState) -> Vec<InfoPodEntity> {
+    match state.repositories.info_pods.list().await {
+        Ok(pods) => pods.into_iter().filter(|p| allowed.allows_pod(p)).collect(),
+        Err(e) => {
+            warn!(?e, "failed to read pod info for tenant scope");
+            Vec::new()
+        }
+    }
+}
+
+fn forbidden(kind: &str, name: &str) -> AppError {
+    AppError::Forbidden(format!("{} '{}' is outside the caller's tenant scope", kind, name))
+}
+
+/// Parses `RUSTCOST_API_KEY_SCOPES`: `key=ns-a|team:x;key2=ns-b`.
+pub fn parse_key_scopes(raw: &str) -> anyhow::Result<HashMap<String, TenantScope>> {
+    raw.split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (key, scope) = entry
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Expected key=scope in RUSTCOST_API_KEY_SCOPES"))?;
+            Ok((key.trim().to_string(), scope.parse()?))
+        })
+        .collect()
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_namespaces_and_teams() {
+        let scopes = parse_key_scopes("k1=payments|checkout|team:Growth; k2=infra").unwrap();
+
+        let k1 = &scopes["k1"];
+        assert!(k1.is_restricted());
+        assert!(k1.allows_namespace("checkout"));
+        assert!(!k1.allows_namespace("infra"));
+        assert!(k1.allowed.as_ref().unwrap().teams.contains("growth"));
+
+        assert!(scopes["k2"].allows_namespace("infra"));
+        assert!(TenantScope::default().allows_namespace("anything"));
+        assert!(parse_key_scopes("k3=").is_err());
+    }
+
+    #[test]
+    fn allows_pods_by_namespace_or_team() {
+        let scope: TenantScope = "payments|team:growth".parse().unwrap();
+        let allowed = scope.allowed.as_ref().unwrap();
+        let pod = |ns: &str, team: Option<&str>| InfoPodEntity {
+            namespace: Some(ns.to_string()),
+            team: team.map(str::to_string),
+            ..Default::default()
+        };
+
+        assert!(allowed.allows_pod(&pod("payments", None)));
+        assert!(allowed.allows_pod(&pod("shared", Some("infra, Growth"))));
+        assert!(!allowed.allows_pod(&pod("shared", Some("infra"))));
+        assert!(!allowed.allows_pod(&pod("shared", None)));
+
+        // Allocation rows carry missing metadata as empty strings
+        assert!(scope.allows_attribution("shared", "growth"));
+        assert!(!scope.allows_attribution("shared", ""));
+        assert!(TenantScope::default().allows_attribution("", ""));
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 //
 // SHORT IMPORTS
@@ -7,12 +7,22 @@ use std::sync::Arc;
 // system
 use crate::domain::system::service::status_service::status_internal;
 use crate::domain::system::service::health_service::health;
-use crate::domain::system::service::backup_service::backup;
+use crate::domain::system::service::backup_service::{backup, backup_archive_stream, backup_file_name, restore};
 use crate::domain::system::service::resync_service::resync;
+use crate::domain::system::service::aggregate_service::backfill_aggregates;
+use crate::domain::system::service::simulate_service::seed_simulation;
+use crate::domain::system::service::metric_gc_service::get_metric_gc_report;
+use crate::domain::metric::k8s::common::util::k8s_metric_query_cache::{cached, QueryCacheArg, QueryCacheTtl};
+use crate::domain::system::service::job_service::get_job_history;
+use crate::api::dto::system_dto::{BackfillQuery, ClosePeriodQuery, CostAllocationQuery, ExportQuery, JobHistoryQuery, SimulateSeedQuery};
+use crate::api::util::tenant_scope::TenantScope;
+use crate::domain::export::cost_close_service::{close_cost_period, get_cost_allocation, list_closed_periods};
+use crate::domain::export::export_service::export_cost_allocation;
+use crate::core::persistence::jobs::job_run_entity::JobRunEntity;
 
 // info
 use crate::domain::info::service::info_unit_price_service::{
-    get_info_unit_prices, upsert_info_unit_prices,
+    get_info_unit_price_history, get_info_unit_prices, upsert_info_unit_prices,
 };
 use crate::domain::info::service::info_version_service::get_info_versions;
 use crate::domain::info::service::info_settings_service::{
@@ -21,14 +31,63 @@ use crate::domain::info::service::info_settings_service::{
 use crate::domain::info::service::info_alerts_service::{
     get_info_alerts, upsert_info_alerts,
 };
+use crate::domain::info::service::info_billing_service::{import_billing, list_billing};
+use crate::core::persistence::info::billing::info_billing_day_entity::InfoBillingDayEntity;
+use crate::domain::info::service::info_attribution_rule_service::{
+    create_attribution_rule, delete_attribution_rule, get_attribution_conflicts, list_attribution_rules,
+    preview_attribution_rules, update_attribution_rule,
+};
+use crate::core::persistence::info::fixed::attribution::attribution_rule_entity::{
+    AttributionRuleEntity, InfoAttributionRuleEntity,
+};
+use crate::domain::info::dto::info_attribution_rule_dto::{
+    InfoAttributionConflictReportDto, InfoAttributionPreviewDto, InfoAttributionPreviewRequest,
+    InfoAttributionRuleUpsertRequest,
+};
+use crate::domain::info::service::info_fixed_cost_service::{
+    create_fixed_cost, delete_fixed_cost, list_fixed_costs, update_fixed_cost,
+};
+use crate::core::persistence::info::fixed::fixed_cost::fixed_cost_entity::{
+    FixedCostEntity, InfoFixedCostEntity,
+};
+use crate::domain::info::dto::info_fixed_cost_dto::InfoFixedCostUpsertRequest;
+use crate::domain::info::service::info_carbon_service::{get_info_carbon, upsert_info_carbon};
+use crate::core::persistence::info::fixed::carbon::info_carbon_entity::InfoCarbonEntity;
+use crate::domain::info::dto::info_carbon_upsert_request::InfoCarbonUpsertRequest;
+use crate::domain::info::service::info_efficiency_target_service::{get_info_efficiency_targets, upsert_info_efficiency_targets};
+use crate::core::persistence::info::fixed::efficiency_target::info_efficiency_target_entity::InfoEfficiencyTargetEntity;
+use crate::domain::info::dto::info_efficiency_target_upsert_request::InfoEfficiencyTargetUpsertRequest;
+use crate::domain::info::service::info_virtual_node_price_service::{get_info_virtual_node_prices, upsert_info_virtual_node_prices};
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_entity::InfoVirtualNodePriceEntity;
+use crate::domain::info::dto::info_virtual_node_price_upsert_request::InfoVirtualNodePriceUpsertRequest;
+use crate::domain::info::service::info_commitment_service::{
+    create_commitment, delete_commitment, list_commitments, update_commitment,
+};
+use crate::core::persistence::info::fixed::commitment::commitment_entity::{
+    CommitmentEntity, InfoCommitmentEntity,
+};
+use crate::domain::info::dto::info_commitment_dto::InfoCommitmentUpsertRequest;
 use crate::domain::info::service::info_llm_service::{
     get_info_llm, upsert_info_llm,
 };
 use crate::domain::llm::service::llm_chat_service::chat as llm_chat;
 use crate::domain::llm::service::llm_chat_service::chat_with_context as llm_chat_with_context;
+use crate::domain::llm::service::llm_conversation_service::{
+    append_conversation_message, create_conversation, delete_conversation, get_conversation,
+    list_conversations,
+};
+use crate::domain::llm::dto::llm_conversation_dto::{
+    LlmConversationCreateRequest, LlmConversationMessageRequest, LlmConversationSummaryDto,
+};
+use crate::core::persistence::info::llm_conversation::llm_conversation_entity::LlmConversationEntity;
 
 // info k8s
-use crate::domain::info::service::info_namespace_service::get_k8s_namespaces;
+use crate::domain::info::service::info_namespace_service::{
+    delete_info_k8s_namespace, get_info_k8s_namespace, get_k8s_namespaces,
+    list_info_k8s_namespaces, patch_info_k8s_namespace,
+};
+use crate::core::persistence::info::k8s::namespace::info_namespace_entity::InfoNamespaceEntity;
+use crate::domain::info::dto::info_k8s_namespace_patch_request::InfoK8sNamespacePatchRequest;
 use crate::domain::info::service::info_k8s_deployment_service::{
     get_k8s_deployment, get_k8s_deployments, get_k8s_deployments_paginated,
 };
@@ -68,7 +127,7 @@ use crate::domain::info::service::info_k8s_node_service::{
     patch_info_k8s_node_price,
 };
 use crate::domain::info::service::info_k8s_pod_service::{
-    get_info_k8s_pod, list_k8s_pods, patch_info_k8s_pod,
+    bulk_patch_info_k8s_pods, get_info_k8s_pod, list_k8s_pods, patch_info_k8s_pod,
 };
 use crate::domain::info::service::info_k8s_container_service::{
     get_info_k8s_container, list_k8s_containers, patch_info_k8s_container,
@@ -89,13 +148,21 @@ use crate::domain::info::service::info_k8s_live_container_service::{
 // metrics
 use crate::domain::metric::k8s::pod::service::*;
 use crate::domain::metric::k8s::node::service::*;
+use crate::domain::metric::k8s::node_pool::service::*;
 use crate::domain::metric::k8s::namespace::service::*;
 use crate::domain::metric::k8s::deployment::service::*;
+use crate::domain::metric::k8s::job::service::*;
+use crate::domain::metric::k8s::pvc::service::*;
+use crate::domain::metric::k8s::network::service::*;
+use crate::domain::metric::k8s::carbon::service::*;
+use crate::domain::metric::k8s::efficiency::service::*;
 use crate::domain::metric::k8s::container::service::*;
 use crate::domain::metric::k8s::cluster::service::*;
+use crate::domain::metric::k8s::common::dto::metric_k8s_stream_dto::MetricSeriesStream;
 
 // entities
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_history_entity::InfoUnitPriceHistoryEntity;
 use crate::core::persistence::info::fixed::version::info_version_entity::InfoVersionEntity;
 use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
 use crate::core::persistence::info::fixed::alerts::info_alert_entity::InfoAlertEntity;
@@ -117,17 +184,25 @@ use crate::domain::info::dto::info_k8s_node_patch_request::{
     InfoK8sNodePricePatchRequest,
 };
 use crate::domain::info::dto::info_k8s_pod_patch_request::InfoK8sPodPatchRequest;
+use crate::domain::info::dto::info_k8s_pod_bulk_patch_request::{
+    InfoK8sPodBulkPatchRequest, InfoK8sPodBulkPatchResponseDto,
+};
 use crate::domain::info::dto::info_k8s_container_patch_request::InfoK8sContainerPatchRequest;
 
-use crate::api::dto::info_dto::{K8sListNodeQuery, K8sListQuery};
+use crate::api::dto::info_dto::{BillingImportQuery, BillingRangeQuery, K8sListNodeQuery, K8sListQuery};
 use crate::api::dto::k8s_pod_query_request_dto::K8sPodQueryRequestDto;
 use crate::api::dto::paginated_response::PaginatedResponse;
-use crate::api::dto::metrics_dto::RangeQuery;
+use crate::api::dto::metrics_dto::{CostCompareQuery, EfficiencyGroupBy, RangeQuery};
+use crate::domain::metric::k8s::common::service_helpers::build_cost_compare_value;
+use crate::api::dto::metrics_query_dto::MetricQueryScope;
+use crate::domain::metric::k8s::query::engine::{self as query_engine, MetricQuery};
+use crate::core::persistence::repositories::{with_repositories, Repositories};
 
 // logs
 use crate::core::persistence::logs::log_repository::LogRepositoryImpl;
 use crate::core::state::runtime::alerts::alert_runtime_state_manager::AlertRuntimeStateManager;
 use crate::core::state::runtime::alerts::alert_runtime_state_repository::AlertRuntimeStateRepository;
+use crate::domain::alert::alert_rule_evaluator::AlertRuleEvaluator;
 use crate::core::state::runtime::k8s::k8s_runtime_state_manager::K8sRuntimeStateManager;
 use crate::core::state::runtime::k8s::k8s_runtime_state_repository::K8sRuntimeStateRepository;
 use crate::domain::system::service::log_service::LogService;
@@ -138,6 +213,13 @@ use crate::domain::system::service::log_service::LogService;
 // ============================================================
 //
 macro_rules! delegate_async_service {
+    (scoped; $(fn $name:ident($($arg:ident : $typ:ty),*) -> $ret:ty => $path:path;)+) => {
+        $(
+            pub async fn $name(&self, $($arg: $typ),*) -> anyhow::Result<$ret> {
+                with_repositories(self.repositories.clone(), $path($($arg),*)).await
+            }
+        )+
+    };
     ($(fn $name:ident($($arg:ident : $typ:ty),*) -> $ret:ty => $path:path;)+) => {
         $(
             pub async fn $name(&self, $($arg: $typ),*) -> anyhow::Result<$ret> {
@@ -154,6 +236,23 @@ macro_rules! delegate_async_service {
     };
 }
 
+macro_rules! delegate_cached_service {
+    ($(fn $name:ident($($arg:ident : $typ:ty),*) => $path:path;)+) => {
+        $(
+            pub async fn $name(&self, $($arg: $typ),*) -> anyhow::Result<serde_json::Value> {
+                let key_parts = vec![$(QueryCacheArg::key_part(&$arg)),*];
+                let ttl = [$(QueryCacheArg::ttl(&$arg)),*]
+                    .into_iter()
+                    .flatten()
+                    .next()
+                    .unwrap_or(QueryCacheTtl::Live);
+                let compute = with_repositories(self.repositories.clone(), $path($($arg),*));
+                cached(stringify!($name), key_parts, ttl, compute).await
+            }
+        )+
+    };
+}
+
 //
 // ============================================================
 // APP STATE
@@ -167,13 +266,23 @@ pub struct AppState {
     pub llm_service: Arc<LlmService>,
     pub info_k8s_service: Arc<InfoK8sService>,
     pub metric_service: Arc<MetricService>,
+    /// Repositories the metric domain reads through; [`Self::scoped`] makes
+    /// them the active set for a request or scheduler loop.
+    pub repositories: Repositories,
 
     // runtime state managers
     pub k8s_state: Arc<K8sRuntimeStateManager<K8sRuntimeStateRepository>>,
-    pub alerts: Arc<AlertRuntimeStateManager<AlertRuntimeStateRepository>>
+    pub alerts: Arc<AlertRuntimeStateManager<AlertRuntimeStateRepository>>,
+    /// Per-node rule state carried between collection cycles
+    pub alert_rules: Arc<Mutex<AlertRuleEvaluator>>,
 }
 
 pub fn build_app_state() -> AppState {
+    build_app_state_with(Repositories::default())
+}
+
+/// App state whose metric domain runs against `repositories`, e.g. in-memory fakes.
+pub fn build_app_state_with(repositories: Repositories) -> AppState {
     // Create repositories
     let k8s_repo = K8sRuntimeStateRepository::new().shared();
     let alert_repo = AlertRuntimeStateRepository::new().shared();
@@ -188,10 +297,21 @@ pub fn build_app_state() -> AppState {
         info_service: Arc::new(InfoService::default()),
         llm_service: Arc::new(LlmService::default()),
         info_k8s_service: Arc::new(InfoK8sService::default()),
-        metric_service: Arc::new(MetricService::default()),
+        metric_service: Arc::new(MetricService::new(repositories.clone())),
+        repositories,
 
         k8s_state,
         alerts,
+        alert_rules: Arc::new(Mutex::new(AlertRuleEvaluator::default())),
+    }
+}
+
+impl AppState {
+    /// Runs `fut` with this state's repositories as the active set. Work
+    /// handed to `tokio::spawn` or `spawn_blocking` leaves the scope and must
+    /// enter it again.
+    pub fn scoped<F: std::future::Future>(&self, fut: F) -> impl std::future::Future<Output = F::Output> {
+        with_repositories(self.repositories.clone(), fut)
     }
 }
 
@@ -213,6 +333,14 @@ impl SystemService {
     delegate_async_service! {
         fn health() -> serde_json::Value => health;
         fn backup() -> serde_json::Value => backup;
+        fn backfill_aggregates(q: BackfillQuery) -> serde_json::Value => backfill_aggregates;
+        fn seed_simulation(q: SimulateSeedQuery) -> serde_json::Value => seed_simulation;
+        fn export_cost_allocation(q: ExportQuery) -> serde_json::Value => export_cost_allocation;
+        fn get_cost_allocation(q: CostAllocationQuery, scope: TenantScope) -> serde_json::Value => get_cost_allocation;
+        fn close_cost_period(q: ClosePeriodQuery) -> serde_json::Value => close_cost_period;
+        fn list_closed_periods() -> serde_json::Value => list_closed_periods;
+        fn get_metric_gc_report() -> serde_json::Value => get_metric_gc_report;
+        fn get_job_history(q: JobHistoryQuery) -> Vec<JobRunEntity> => get_job_history;
     }
     pub async fn status(&self) -> anyhow::Result<serde_json::Value> {
         status_internal(self.k8s_state.clone()).await
@@ -220,6 +348,15 @@ impl SystemService {
     pub async fn resync(&self) -> anyhow::Result<serde_json::Value> {
         resync(self.k8s_state.clone()).await
     }
+    /// Streaming `tar.zst` of info + metric data, with a suggested file name.
+    pub fn backup_archive(
+        &self,
+    ) -> (String, impl futures::Stream<Item = std::io::Result<axum::body::Bytes>>) {
+        (backup_file_name(), backup_archive_stream())
+    }
+    pub async fn restore(&self, body: axum::body::Body) -> anyhow::Result<serde_json::Value> {
+        restore(body.into_data_stream()).await
+    }
 }
 
 //
@@ -234,12 +371,40 @@ impl InfoService {
     delegate_async_service! {
         fn get_info_unit_prices() -> InfoUnitPriceEntity => get_info_unit_prices;
         fn upsert_info_unit_prices(req: InfoUnitPriceUpsertRequest) -> serde_json::Value => upsert_info_unit_prices;
+        fn get_info_unit_price_history() -> InfoUnitPriceHistoryEntity => get_info_unit_price_history;
 
         fn get_info_versions() -> InfoVersionEntity => get_info_versions;
 
         fn get_info_alerts() -> InfoAlertEntity => get_info_alerts;
         fn upsert_info_alerts(req: InfoAlertUpsertRequest) -> serde_json::Value => upsert_info_alerts;
 
+        fn list_attribution_rules() -> InfoAttributionRuleEntity => list_attribution_rules;
+        fn create_attribution_rule(req: InfoAttributionRuleUpsertRequest) -> AttributionRuleEntity => create_attribution_rule;
+        fn update_attribution_rule(id: String, req: InfoAttributionRuleUpsertRequest) -> AttributionRuleEntity => update_attribution_rule;
+        fn delete_attribution_rule(id: String) -> serde_json::Value => delete_attribution_rule;
+        fn preview_attribution_rules(state: AppState, req: InfoAttributionPreviewRequest) -> InfoAttributionPreviewDto => preview_attribution_rules;
+        fn get_attribution_conflicts(state: AppState) -> InfoAttributionConflictReportDto => get_attribution_conflicts;
+
+        fn list_fixed_costs() -> InfoFixedCostEntity => list_fixed_costs;
+        fn create_fixed_cost(req: InfoFixedCostUpsertRequest) -> FixedCostEntity => create_fixed_cost;
+        fn update_fixed_cost(id: String, req: InfoFixedCostUpsertRequest) -> FixedCostEntity => update_fixed_cost;
+        fn delete_fixed_cost(id: String) -> serde_json::Value => delete_fixed_cost;
+
+        fn get_info_carbon() -> InfoCarbonEntity => get_info_carbon;
+        fn upsert_info_carbon(req: InfoCarbonUpsertRequest) -> serde_json::Value => upsert_info_carbon;
+        fn get_info_efficiency_targets() -> InfoEfficiencyTargetEntity => get_info_efficiency_targets;
+        fn upsert_info_efficiency_targets(req: InfoEfficiencyTargetUpsertRequest) -> serde_json::Value => upsert_info_efficiency_targets;
+        fn get_info_virtual_node_prices() -> InfoVirtualNodePriceEntity => get_info_virtual_node_prices;
+        fn upsert_info_virtual_node_prices(req: InfoVirtualNodePriceUpsertRequest) -> serde_json::Value => upsert_info_virtual_node_prices;
+
+        fn list_commitments() -> InfoCommitmentEntity => list_commitments;
+        fn create_commitment(req: InfoCommitmentUpsertRequest) -> CommitmentEntity => create_commitment;
+        fn update_commitment(id: String, req: InfoCommitmentUpsertRequest) -> CommitmentEntity => update_commitment;
+        fn delete_commitment(id: String) -> serde_json::Value => delete_commitment;
+
+        fn import_billing(q: BillingImportQuery, body: String) -> serde_json::Value => import_billing;
+        fn list_billing(q: BillingRangeQuery) -> Vec<InfoBillingDayEntity> => list_billing;
+
         fn get_info_llm() -> InfoLlmEntity => get_info_llm;
         fn upsert_info_llm(req: InfoLlmUpsertRequest) -> serde_json::Value => upsert_info_llm;
 
@@ -259,7 +424,13 @@ pub struct LlmService;
 impl LlmService {
     delegate_async_service! {
         fn chat(payload: LlmChatRequest) -> serde_json::Value => llm_chat;
-        fn chat_with_context(payload: LlmChatWithContextRequest) -> serde_json::Value => llm_chat_with_context;
+        fn chat_with_context(state: AppState, payload: LlmChatWithContextRequest) -> serde_json::Value => llm_chat_with_context;
+
+        fn create_conversation(req: LlmConversationCreateRequest) -> LlmConversationEntity => create_conversation;
+        fn list_conversations() -> Vec<LlmConversationSummaryDto> => list_conversations;
+        fn get_conversation(id: String) -> LlmConversationEntity => get_conversation;
+        fn append_conversation_message(id: String, req: LlmConversationMessageRequest) -> LlmConversationEntity => append_conversation_message;
+        fn delete_conversation(id: String) -> serde_json::Value => delete_conversation;
     }
 }
 
@@ -274,6 +445,10 @@ pub struct InfoK8sService;
 impl InfoK8sService {
     delegate_async_service! {
         fn get_k8s_namespaces() -> serde_json::Value => get_k8s_namespaces;
+        fn list_info_k8s_namespaces() -> Vec<InfoNamespaceEntity> => list_info_k8s_namespaces;
+        fn get_info_k8s_namespace(name: String) -> InfoNamespaceEntity => get_info_k8s_namespace;
+        fn patch_info_k8s_namespace(name: String, payload: InfoK8sNamespacePatchRequest) -> InfoNamespaceEntity => patch_info_k8s_namespace;
+        fn delete_info_k8s_namespace(name: String) -> serde_json::Value => delete_info_k8s_namespace;
         fn get_k8s_deployments() -> crate::api::dto::paginated_response::PaginatedResponse<k8s_openapi::api::apps::v1::Deployment> => get_k8s_deployments;
         fn get_k8s_deployments_paginated(limit: Option<usize>, offset: Option<usize>) -> PaginatedResponse<k8s_openapi::api::apps::v1::Deployment> => get_k8s_deployments_paginated;
         fn get_k8s_deployment(namespace: String, name: String) -> k8s_openapi::api::apps::v1::Deployment => get_k8s_deployment;
@@ -328,6 +503,7 @@ impl InfoK8sService {
         fn get_info_k8s_pod(pod_uid: String) -> InfoPodEntity => get_info_k8s_pod;
         fn list_k8s_pods(state: AppState, filter: K8sPodQueryRequestDto) -> PaginatedResponse<InfoPodEntity> => list_k8s_pods;
         fn patch_info_k8s_pod(id: String, payload: InfoK8sPodPatchRequest) -> serde_json::Value => patch_info_k8s_pod;
+        fn bulk_patch_info_k8s_pods(payload: InfoK8sPodBulkPatchRequest) -> InfoK8sPodBulkPatchResponseDto => bulk_patch_info_k8s_pods;
 
         fn get_info_k8s_container(id: String) -> InfoContainerEntity => get_info_k8s_container;
         fn list_k8s_containers(filter: K8sListQuery) -> Vec<InfoContainerEntity> => list_k8s_containers;
@@ -341,11 +517,24 @@ impl InfoK8sService {
 // ============================================================
 //
 #[derive(Clone, Default)]
-pub struct MetricService;
+pub struct MetricService {
+    repositories: Repositories,
+}
 
 impl MetricService {
+    pub fn new(repositories: Repositories) -> Self {
+        Self { repositories }
+    }
+
+    /// Runs `fut` against this service's repositories.
+    async fn scoped<F: std::future::Future>(&self, fut: F) -> F::Output {
+        with_repositories(self.repositories.clone(), fut).await
+    }
+
     delegate_async_service! {
+        scoped;
         fn get_metric_k8s_pods_raw(q: RangeQuery, pod_uids: Vec<String>) -> serde_json::Value => get_metric_k8s_pods_raw;
+        fn stream_metric_k8s_pods_raw(q: RangeQuery, pod_uids: Vec<String>) -> MetricSeriesStream => stream_metric_k8s_pods_raw;
         fn get_metric_k8s_pods_raw_summary(q: RangeQuery, pod_uids: Vec<String>) -> serde_json::Value => get_metric_k8s_pods_raw_summary;
         fn get_metric_k8s_pods_raw_efficiency(q: RangeQuery, _pod_uids: Vec<String>) -> serde_json::Value => get_metric_k8s_pods_raw_efficiency;
 
@@ -354,14 +543,13 @@ impl MetricService {
         fn get_metric_k8s_pod_raw_efficiency(pod_uid: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_pod_raw_efficiency;
 
         fn get_metric_k8s_pods_cost(q: RangeQuery, _pod_uids: Vec<String>) -> serde_json::Value => get_metric_k8s_pods_cost;
-        fn get_metric_k8s_pods_cost_summary(q: RangeQuery, _pod_uids: Vec<String>) -> serde_json::Value => get_metric_k8s_pods_cost_summary;
-        fn get_metric_k8s_pods_cost_trend(q: RangeQuery, _pod_uids: Vec<String>) -> serde_json::Value => get_metric_k8s_pods_cost_trend;
 
         fn get_metric_k8s_pod_cost(pod_uid: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_pod_cost;
-        fn get_metric_k8s_pod_cost_summary(pod_uid: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_pod_cost_summary;
-        fn get_metric_k8s_pod_cost_trend(pod_uid: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_pod_cost_trend;
+        fn get_metric_k8s_pod_cost_breakdown(pod_uid: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_pod_cost_breakdown;
+        fn get_metric_k8s_pods_reliability(q: RangeQuery, pod_uids: Vec<String>) -> serde_json::Value => get_metric_k8s_pods_reliability;
 
         fn get_metric_k8s_nodes_raw(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodes_raw;
+        fn stream_metric_k8s_nodes_raw(q: RangeQuery, node_names: Vec<String>) -> MetricSeriesStream => stream_metric_k8s_nodes_raw;
         fn get_metric_k8s_nodes_raw_summary(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodes_raw_summary;
         fn get_metric_k8s_nodes_raw_efficiency(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodes_raw_efficiency;
 
@@ -370,12 +558,8 @@ impl MetricService {
         fn get_metric_k8s_node_raw_efficiency(node_name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_node_raw_efficiency;
 
         fn get_metric_k8s_nodes_cost(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodes_cost;
-        fn get_metric_k8s_nodes_cost_summary(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodes_cost_summary;
-        fn get_metric_k8s_nodes_cost_trend(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodes_cost_trend;
 
         fn get_metric_k8s_node_cost(node_name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_node_cost;
-        fn get_metric_k8s_node_cost_summary(node_name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_node_cost_summary;
-        fn get_metric_k8s_node_cost_trend(node_name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_node_cost_trend;
 
         fn get_metric_k8s_namespaces_raw(q: RangeQuery, namespaces: Vec<String>) -> serde_json::Value => get_metric_k8s_namespaces_raw;
         fn get_metric_k8s_namespaces_raw_summary(q: RangeQuery, namespaces: Vec<String>) -> serde_json::Value => get_metric_k8s_namespaces_raw_summary;
@@ -386,12 +570,8 @@ impl MetricService {
         fn get_metric_k8s_namespace_raw_efficiency(ns: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_namespace_raw_efficiency;
 
         fn get_metric_k8s_namespaces_cost(q: RangeQuery, namespaces: Vec<String>) -> serde_json::Value => get_metric_k8s_namespaces_cost;
-        fn get_metric_k8s_namespaces_cost_summary(q: RangeQuery, namespaces: Vec<String>) -> serde_json::Value => get_metric_k8s_namespaces_cost_summary;
-        fn get_metric_k8s_namespaces_cost_trend(q: RangeQuery, namespaces: Vec<String>) -> serde_json::Value => get_metric_k8s_namespaces_cost_trend;
 
         fn get_metric_k8s_namespace_cost(ns: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_namespace_cost;
-        fn get_metric_k8s_namespace_cost_summary(ns: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_namespace_cost_summary;
-        fn get_metric_k8s_namespace_cost_trend(ns: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_namespace_cost_trend;
 
         fn get_metric_k8s_deployments_raw(q: RangeQuery, deployments: Vec<String>) -> serde_json::Value => get_metric_k8s_deployments_raw;
         fn get_metric_k8s_deployments_raw_summary(q: RangeQuery, deployments: Vec<String>) -> serde_json::Value => get_metric_k8s_deployments_raw_summary;
@@ -402,14 +582,45 @@ impl MetricService {
         fn get_metric_k8s_deployment_raw_efficiency(name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_deployment_raw_efficiency;
 
         fn get_metric_k8s_deployments_cost(q: RangeQuery, deployments: Vec<String>) -> serde_json::Value => get_metric_k8s_deployments_cost;
-        fn get_metric_k8s_deployments_cost_summary(q: RangeQuery, deployments: Vec<String>) -> serde_json::Value => get_metric_k8s_deployments_cost_summary;
-        fn get_metric_k8s_deployments_cost_trend(q: RangeQuery, deployments: Vec<String>) -> serde_json::Value => get_metric_k8s_deployments_cost_trend;
 
         fn get_metric_k8s_deployment_cost(name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_deployment_cost;
-        fn get_metric_k8s_deployment_cost_summary(name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_deployment_cost_summary;
-        fn get_metric_k8s_deployment_cost_trend(name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_deployment_cost_trend;
+
+        fn get_metric_k8s_nodepools_raw(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodepools_raw;
+        fn get_metric_k8s_nodepools_raw_efficiency(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodepools_raw_efficiency;
+        fn get_metric_k8s_nodepools_cost(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodepools_cost;
+
+        fn get_metric_k8s_nodepool_raw(pool: String, q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodepool_raw;
+        fn get_metric_k8s_nodepool_raw_efficiency(pool: String, q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodepool_raw_efficiency;
+        fn get_metric_k8s_nodepool_cost(pool: String, q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodepool_cost;
+
+        fn get_metric_k8s_nodes_os_cost(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodes_os_cost;
+        fn get_metric_k8s_nodes_os_cost_summary(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodes_os_cost_summary;
+
+        fn get_metric_k8s_job_raw(namespace: String, name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_job_raw;
+        fn get_metric_k8s_job_cost(namespace: String, name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_job_cost;
+
+        fn get_metric_k8s_cronjob_raw(namespace: String, name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_cronjob_raw;
+        fn get_metric_k8s_cronjob_cost(namespace: String, name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_cronjob_cost;
+        fn get_metric_k8s_cronjob_cost_runs(namespace: String, name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_cronjob_cost_runs;
+
+        fn get_metric_k8s_pvcs_raw(q: RangeQuery, namespaces: Option<Vec<String>>) -> serde_json::Value => get_metric_k8s_pvcs_raw;
+        fn get_metric_k8s_pvcs_cost(q: RangeQuery, namespaces: Option<Vec<String>>) -> serde_json::Value => get_metric_k8s_pvcs_cost;
+        fn get_metric_k8s_pvc_raw(namespace: String, name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_pvc_raw;
+        fn get_metric_k8s_pvc_cost(namespace: String, name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_pvc_cost;
+
+        fn get_metric_k8s_network_flows(q: RangeQuery, namespaces: Option<Vec<String>>) -> serde_json::Value => get_metric_k8s_network_flows;
+        fn get_metric_k8s_namespace_network_flows(namespace: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_namespace_network_flows;
+        fn get_metric_k8s_network_cross_zone_cost(q: RangeQuery, namespaces: Option<Vec<String>>) -> serde_json::Value => get_metric_k8s_network_cross_zone_cost;
+
+        fn get_metric_k8s_cluster_carbon(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_cluster_carbon;
+        fn get_metric_k8s_nodes_carbon(q: RangeQuery, node_names: Vec<String>) -> serde_json::Value => get_metric_k8s_nodes_carbon;
+        fn get_metric_k8s_node_carbon(node_name: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_node_carbon;
+        fn get_metric_k8s_namespaces_carbon(q: RangeQuery, namespaces: Vec<String>) -> serde_json::Value => get_metric_k8s_namespaces_carbon;
+        fn get_metric_k8s_namespace_carbon(namespace: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_namespace_carbon;
+        fn get_metric_k8s_pod_carbon(pod_uid: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_pod_carbon;
 
         fn get_metric_k8s_containers_raw(q: RangeQuery, container_keys: Vec<String>) -> serde_json::Value => get_metric_k8s_containers_raw;
+        fn stream_metric_k8s_containers_raw(q: RangeQuery, container_keys: Vec<String>) -> MetricSeriesStream => stream_metric_k8s_containers_raw;
         fn get_metric_k8s_containers_raw_summary(q: RangeQuery, container_keys: Vec<String>) -> serde_json::Value => get_metric_k8s_containers_raw_summary;
         fn get_metric_k8s_containers_raw_efficiency(q: RangeQuery, container_keys: Vec<String>) -> serde_json::Value => get_metric_k8s_containers_raw_efficiency;
 
@@ -418,12 +629,63 @@ impl MetricService {
         fn get_metric_k8s_container_raw_efficiency(id: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_container_raw_efficiency;
 
         fn get_metric_k8s_containers_cost(q: RangeQuery, container_keys: Vec<String>) -> serde_json::Value => get_metric_k8s_containers_cost;
-        fn get_metric_k8s_containers_cost_summary(q: RangeQuery, container_keys: Vec<String>) -> serde_json::Value => get_metric_k8s_containers_cost_summary;
-        fn get_metric_k8s_containers_cost_trend(q: RangeQuery, container_keys: Vec<String>) -> serde_json::Value => get_metric_k8s_containers_cost_trend;
 
         fn get_metric_k8s_container_cost(id: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_container_cost;
-        fn get_metric_k8s_container_cost_summary(id: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_container_cost_summary;
-        fn get_metric_k8s_container_cost_trend(id: String, q: RangeQuery) -> serde_json::Value => get_metric_k8s_container_cost_trend;
+
+        fn explain_metric_k8s_efficiency_scores(scores: serde_json::Value) -> serde_json::Value => explain_metric_k8s_efficiency_scores;
+    }
+
+    // Dashboard summaries and trends, see `k8s_metric_query_cache`
+    delegate_cached_service! {
+        fn get_metric_k8s_pods_cost_summary(q: RangeQuery, _pod_uids: Vec<String>) => get_metric_k8s_pods_cost_summary;
+        fn get_metric_k8s_pods_cost_trend(q: RangeQuery, _pod_uids: Vec<String>) => get_metric_k8s_pods_cost_trend;
+        fn get_metric_k8s_pod_cost_summary(pod_uid: String, q: RangeQuery) => get_metric_k8s_pod_cost_summary;
+        fn get_metric_k8s_pod_cost_trend(pod_uid: String, q: RangeQuery) => get_metric_k8s_pod_cost_trend;
+
+        fn get_metric_k8s_nodes_cost_summary(q: RangeQuery, node_names: Vec<String>) => get_metric_k8s_nodes_cost_summary;
+        fn get_metric_k8s_nodes_cost_trend(q: RangeQuery, node_names: Vec<String>) => get_metric_k8s_nodes_cost_trend;
+        fn get_metric_k8s_node_cost_summary(node_name: String, q: RangeQuery) => get_metric_k8s_node_cost_summary;
+        fn get_metric_k8s_node_cost_trend(node_name: String, q: RangeQuery) => get_metric_k8s_node_cost_trend;
+
+        fn get_metric_k8s_namespaces_cost_summary(q: RangeQuery, namespaces: Vec<String>) => get_metric_k8s_namespaces_cost_summary;
+        fn get_metric_k8s_namespaces_cost_trend(q: RangeQuery, namespaces: Vec<String>) => get_metric_k8s_namespaces_cost_trend;
+        fn get_metric_k8s_namespace_cost_summary(ns: String, q: RangeQuery) => get_metric_k8s_namespace_cost_summary;
+        fn get_metric_k8s_namespace_cost_trend(ns: String, q: RangeQuery) => get_metric_k8s_namespace_cost_trend;
+
+        fn get_metric_k8s_deployments_cost_summary(q: RangeQuery, deployments: Vec<String>) => get_metric_k8s_deployments_cost_summary;
+        fn get_metric_k8s_deployments_cost_trend(q: RangeQuery, deployments: Vec<String>) => get_metric_k8s_deployments_cost_trend;
+        fn get_metric_k8s_deployment_cost_summary(name: String, q: RangeQuery) => get_metric_k8s_deployment_cost_summary;
+        fn get_metric_k8s_deployment_cost_trend(name: String, q: RangeQuery) => get_metric_k8s_deployment_cost_trend;
+
+        fn get_metric_k8s_nodepools_cost_summary(q: RangeQuery, node_names: Vec<String>) => get_metric_k8s_nodepools_cost_summary;
+        fn get_metric_k8s_nodepools_cost_trend(q: RangeQuery, node_names: Vec<String>) => get_metric_k8s_nodepools_cost_trend;
+        fn get_metric_k8s_nodepool_cost_summary(pool: String, q: RangeQuery, node_names: Vec<String>) => get_metric_k8s_nodepool_cost_summary;
+        fn get_metric_k8s_nodepool_cost_trend(pool: String, q: RangeQuery, node_names: Vec<String>) => get_metric_k8s_nodepool_cost_trend;
+
+        fn get_metric_k8s_job_cost_summary(namespace: String, name: String, q: RangeQuery) => get_metric_k8s_job_cost_summary;
+        fn get_metric_k8s_job_cost_trend(namespace: String, name: String, q: RangeQuery) => get_metric_k8s_job_cost_trend;
+
+        fn get_metric_k8s_cronjob_cost_summary(namespace: String, name: String, q: RangeQuery) => get_metric_k8s_cronjob_cost_summary;
+        fn get_metric_k8s_cronjob_cost_trend(namespace: String, name: String, q: RangeQuery) => get_metric_k8s_cronjob_cost_trend;
+
+        fn get_metric_k8s_efficiency_scores(q: RangeQuery, namespaces: Vec<String>, group_by: EfficiencyGroupBy) => get_metric_k8s_efficiency_scores;
+
+        fn get_metric_k8s_containers_cost_summary(q: RangeQuery, container_keys: Vec<String>) => get_metric_k8s_containers_cost_summary;
+        fn get_metric_k8s_containers_cost_trend(q: RangeQuery, container_keys: Vec<String>) => get_metric_k8s_containers_cost_trend;
+        fn get_metric_k8s_container_cost_summary(id: String, q: RangeQuery) => get_metric_k8s_container_cost_summary;
+        fn get_metric_k8s_container_cost_trend(id: String, q: RangeQuery) => get_metric_k8s_container_cost_trend;
+    }
+}
+
+//
+// ============================================================
+// METRIC QUERY ENGINE (manual)
+// ============================================================
+//
+impl MetricService {
+    /// Any scope's list view from one resolved query; see [`query_engine`].
+    pub async fn query(&self, query: MetricQuery) -> anyhow::Result<serde_json::Value> {
+        self.scoped(query_engine::run(self, query)).await
     }
 }
 
@@ -438,7 +700,7 @@ impl MetricService {
         q: RangeQuery,
         node_names: Vec<String>
     ) -> anyhow::Result<serde_json::Value> {
-        get_metric_k8s_cluster_raw(node_names, q).await
+        self.scoped(get_metric_k8s_cluster_raw(node_names, q)).await
     }
 
     pub async fn get_metric_k8s_cluster_raw_summary(
@@ -446,7 +708,7 @@ impl MetricService {
         q: RangeQuery,
         node_names: Vec<String>
     ) -> anyhow::Result<serde_json::Value> {
-        get_metric_k8s_cluster_raw_summary(node_names, q).await
+        self.scoped(get_metric_k8s_cluster_raw_summary(node_names, q)).await
     }
 
     pub async fn get_metric_k8s_cluster_raw_efficiency(
@@ -455,7 +717,16 @@ impl MetricService {
         node_names: Vec<String>
     ) -> anyhow::Result<serde_json::Value> {
         let nodes = list_k8s_nodes(K8sListNodeQuery::default()).await?;
-        get_metric_k8s_cluster_raw_efficiency(nodes, node_names, q).await
+        self.scoped(get_metric_k8s_cluster_raw_efficiency(nodes, node_names, q)).await
+    }
+
+    pub async fn get_metric_k8s_cluster_raw_efficiency_trend(
+        &self,
+        q: RangeQuery,
+        node_names: Vec<String>
+    ) -> anyhow::Result<serde_json::Value> {
+        let nodes = list_k8s_nodes(K8sListNodeQuery::default()).await?;
+        self.scoped(get_metric_k8s_cluster_raw_efficiency_trend(nodes, node_names, q)).await
     }
 
     pub async fn get_metric_k8s_cluster_cost(
@@ -464,7 +735,7 @@ impl MetricService {
         node_names: Vec<String>,
     ) -> anyhow::Result<serde_json::Value> {
         let costs = get_info_unit_prices().await?;
-        get_metric_k8s_cluster_cost(node_names, costs, q).await
+        self.scoped(get_metric_k8s_cluster_cost(node_names, costs, q)).await
     }
 
     pub async fn get_metric_k8s_cluster_cost_summary(
@@ -472,16 +743,134 @@ impl MetricService {
         q: RangeQuery,
         node_names: Vec<String>,
     ) -> anyhow::Result<serde_json::Value> {
-        let costs = get_info_unit_prices().await?;
-        get_metric_k8s_cluster_cost_summary(node_names, costs, q).await
+        let key_parts = vec![q.key_part(), node_names.key_part()];
+        let ttl = q.ttl().unwrap_or(QueryCacheTtl::Live);
+        let compute = self.scoped(async {
+            let costs = get_info_unit_prices().await?;
+            get_metric_k8s_cluster_cost_summary(node_names, costs, q).await
+        });
+        cached("get_metric_k8s_cluster_cost_summary", key_parts, ttl, compute).await
     }
 
     pub async fn get_metric_k8s_cluster_cost_trend(
         &self,
         q: RangeQuery,
         node_names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        let key_parts = vec![q.key_part(), node_names.key_part()];
+        let ttl = q.ttl().unwrap_or(QueryCacheTtl::Live);
+        let compute = self.scoped(async {
+            let costs = get_info_unit_prices().await?;
+            get_metric_k8s_cluster_cost_trend(node_names, costs, q).await
+        });
+        cached("get_metric_k8s_cluster_cost_trend", key_parts, ttl, compute).await
+    }
+
+    pub async fn get_metric_k8s_cluster_cost_events(
+        &self,
+        q: RangeQuery,
+        node_names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        let costs = get_info_unit_prices().await?;
+        self.scoped(get_metric_k8s_cluster_cost_events(node_names, costs, q)).await
+    }
+
+    pub async fn get_metric_k8s_cluster_cost_by_account(
+        &self,
+        q: RangeQuery,
+        node_names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        let costs = get_info_unit_prices().await?;
+        let settings = get_info_settings().await?;
+        self.scoped(get_metric_k8s_cluster_cost_by_account(node_names, costs, settings, q)).await
+    }
+
+    pub async fn get_metric_k8s_cost_reconcile(
+        &self,
+        q: RangeQuery,
+        node_names: Vec<String>,
     ) -> anyhow::Result<serde_json::Value> {
         let costs = get_info_unit_prices().await?;
-        get_metric_k8s_cluster_cost_trend(node_names, costs, q).await
+        self.scoped(get_metric_k8s_cost_reconcile(node_names, costs, q)).await
+    }
+
+    pub async fn get_metric_k8s_cluster_cost_billing_reconcile(
+        &self,
+        q: BillingRangeQuery,
+        node_names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        let costs = get_info_unit_prices().await?;
+        let settings = get_info_settings().await?;
+        self.scoped(get_metric_k8s_cluster_cost_billing_reconcile(node_names, costs, settings, q)).await
+    }
+}
+
+//
+// ============================================================
+// METRIC COST COMPARE (manual)
+// ============================================================
+//
+impl MetricService {
+    pub async fn get_metric_k8s_nodes_cost_compare(
+        &self,
+        q: RangeQuery,
+        cmp: CostCompareQuery,
+        node_names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        query_engine::cost_compare(self, MetricQueryScope::Node, q, cmp, node_names).await
+    }
+
+    pub async fn get_metric_k8s_pods_cost_compare(
+        &self,
+        q: RangeQuery,
+        cmp: CostCompareQuery,
+        pod_uids: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        query_engine::cost_compare(self, MetricQueryScope::Pod, q, cmp, pod_uids).await
+    }
+
+    pub async fn get_metric_k8s_containers_cost_compare(
+        &self,
+        q: RangeQuery,
+        cmp: CostCompareQuery,
+        container_keys: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        query_engine::cost_compare(self, MetricQueryScope::Container, q, cmp, container_keys).await
+    }
+
+    pub async fn get_metric_k8s_namespaces_cost_compare(
+        &self,
+        q: RangeQuery,
+        cmp: CostCompareQuery,
+        namespaces: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        query_engine::cost_compare(self, MetricQueryScope::Namespace, q, cmp, namespaces).await
+    }
+
+    pub async fn get_metric_k8s_deployments_cost_compare(
+        &self,
+        q: RangeQuery,
+        cmp: CostCompareQuery,
+        deployments: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        query_engine::cost_compare(self, MetricQueryScope::Deployment, q, cmp, deployments).await
+    }
+
+    pub async fn get_metric_k8s_nodepools_cost_compare(
+        &self,
+        q: RangeQuery,
+        cmp: CostCompareQuery,
+        node_names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        build_cost_compare_value(q, cmp, |q| self.get_metric_k8s_nodepools_cost_summary(q, node_names.clone())).await
+    }
+
+    pub async fn get_metric_k8s_cluster_cost_compare(
+        &self,
+        q: RangeQuery,
+        cmp: CostCompareQuery,
+        node_names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        query_engine::cost_compare(self, MetricQueryScope::Cluster, q, cmp, node_names).await
     }
 }
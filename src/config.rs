@@ -1,17 +1,70 @@
 use anyhow::Result;
 use dotenvy::dotenv;
 use std::env;
+use std::collections::HashMap;
 use tokio::sync::OnceCell;
+use crate::api::middleware::auth::Role;
+use crate::api::util::tenant_scope::{parse_key_scopes, TenantScope};
 
 #[derive(Debug)]
 pub struct ServerConfig {
     host: String,
     port: u16,
+    /// gRPC listener port from `GRPC_PORT`; the gRPC API is off when unset.
+    grpc_port: Option<u16>,
+}
+
+/// API authentication settings.
+///
+/// With no API keys and TokenReview disabled, the API stays open (legacy behavior).
+#[derive(Debug, Default)]
+pub struct AuthConfig {
+    /// Static API keys from `RUSTCOST_API_KEYS` (`key:role,key:role`).
+    pub api_keys: Vec<(String, Role)>,
+    /// Namespace/team scopes per API key from `RUSTCOST_API_KEY_SCOPES`; unlisted keys see everything.
+    pub key_scopes: HashMap<String, TenantScope>,
+    /// Validate bearer tokens against the Kubernetes TokenReview API.
+    pub token_review: bool,
+    /// Groups granted the admin role when authenticated via TokenReview.
+    pub admin_groups: Vec<String>,
+}
+
+impl AuthConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.api_keys.is_empty() || self.token_review
+    }
+}
+
+/// Lease-based leader election for running several replicas.
+///
+/// Only the leader scrapes and writes metrics; every replica serves the API.
+#[derive(Debug)]
+pub struct LeaderElectionConfig {
+    /// `RUSTCOST_LEADER_ELECTION`; a single replica is always the leader when off.
+    pub enabled: bool,
+    /// Lease object name (`RUSTCOST_LEADER_LEASE_NAME`).
+    pub lease_name: String,
+    /// Lease namespace (`RUSTCOST_LEADER_LEASE_NAMESPACE`, falling back to the pod's namespace).
+    pub lease_namespace: String,
+    /// Holder identity written to the lease, normally the pod name.
+    pub identity: String,
+}
+
+/// What this process does, from `ROLE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceRole {
+    /// Collects, aggregates, cleans up and serves the API (default).
+    Writer,
+    /// Only serves the API from a data directory another instance writes.
+    Reader,
 }
 
 #[derive(Debug)]
 pub struct Config {
     server: ServerConfig,
+    auth: AuthConfig,
+    leader_election: LeaderElectionConfig,
+    role: InstanceRole,
 }
 
 impl Config {
@@ -22,6 +75,22 @@ impl Config {
     pub fn server_port(&self) -> u16 {
         self.server.port
     }
+
+    pub fn grpc_port(&self) -> Option<u16> {
+        self.server.grpc_port
+    }
+
+    pub fn auth(&self) -> &AuthConfig {
+        &self.auth
+    }
+
+    pub fn leader_election(&self) -> &LeaderElectionConfig {
+        &self.leader_election
+    }
+
+    pub fn role(&self) -> InstanceRole {
+        self.role
+    }
 }
 
 pub static CONFIG: OnceCell<Config> = OnceCell::const_new();
@@ -35,9 +104,87 @@ async fn init_config() -> Result<Config> {
             .unwrap_or_else(|_| "3000".to_string())
             .parse::<u16>()
             .unwrap(),
+        grpc_port: match env::var("GRPC_PORT") {
+            Ok(v) if !v.trim().is_empty() => Some(v.trim().parse::<u16>()?),
+            _ => None,
+        },
+    };
+
+    let api_keys = parse_api_keys(&env::var("RUSTCOST_API_KEYS").unwrap_or_default())?;
+    let key_scopes = parse_key_scopes(&env::var("RUSTCOST_API_KEY_SCOPES").unwrap_or_default())?;
+    for key in key_scopes.keys() {
+        match api_keys.iter().find(|(k, _)| k == key) {
+            Some((_, Role::Viewer)) => {}
+            Some((_, Role::Admin)) => anyhow::bail!("Tenant-scoped API keys must be viewer keys"),
+            None => anyhow::bail!("RUSTCOST_API_KEY_SCOPES references a key missing from RUSTCOST_API_KEYS"),
+        }
+    }
+
+    let auth_config = AuthConfig {
+        api_keys,
+        key_scopes,
+        token_review: env::var("RUSTCOST_AUTH_TOKEN_REVIEW")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false),
+        admin_groups: env::var("RUSTCOST_AUTH_ADMIN_GROUPS")
+            .unwrap_or_else(|_| "system:masters".to_string())
+            .split(',')
+            .map(|g| g.trim().to_string())
+            .filter(|g| !g.is_empty())
+            .collect(),
+    };
+
+    let leader_election = LeaderElectionConfig {
+        enabled: env::var("RUSTCOST_LEADER_ELECTION")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false),
+        lease_name: env::var("RUSTCOST_LEADER_LEASE_NAME")
+            .unwrap_or_else(|_| "rustcost-collector".to_string()),
+        lease_namespace: env::var("RUSTCOST_LEADER_LEASE_NAMESPACE")
+            .ok()
+            .or_else(|| {
+                std::fs::read_to_string("/var/run/secrets/kubernetes.io/serviceaccount/namespace")
+                    .ok()
+                    .map(|ns| ns.trim().to_string())
+            })
+            .unwrap_or_else(|| "default".to_string()),
+        identity: env::var("POD_NAME")
+            .or_else(|_| env::var("HOSTNAME"))
+            .unwrap_or_else(|_| format!("rustcost-{}", std::process::id())),
     };
 
-    Ok(Config { server: server_config })
+    let role = match env::var("ROLE").unwrap_or_default().trim().to_ascii_lowercase().as_str() {
+        "" | "writer" => InstanceRole::Writer,
+        "reader" => InstanceRole::Reader,
+        other => anyhow::bail!("Unknown ROLE '{}' (expected writer or reader)", other),
+    };
+
+    Ok(Config { server: server_config, auth: auth_config, leader_election, role })
+}
+
+/// Parses `key:role` pairs; a key without a role is a viewer key.
+fn parse_api_keys(raw: &str) -> Result<Vec<(String, Role)>> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (key, role) = match entry.rsplit_once(':') {
+                Some((key, role)) => (key.trim(), role.parse()?),
+                None => (entry, Role::Viewer),
+            };
+            if key.is_empty() {
+                anyhow::bail!("Empty API key in RUSTCOST_API_KEYS");
+            }
+            Ok((key.to_string(), role))
+        })
+        .collect()
+}
+
+/// True on a `ROLE=reader` replica, which must never write to the data directory.
+///
+/// Sync so storage code can check it; false until the config is loaded.
+pub fn is_reader() -> bool {
+    CONFIG.get().is_some_and(|c| c.role == InstanceRole::Reader)
 }
 
 pub async fn config() -> &'static Config {
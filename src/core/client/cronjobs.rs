@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::CronJob;
 
 /// Fetch all cronjobs in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_cronjobs(client: &Client) -> Result<Vec<CronJob>> {
     let cronjobs: Api<CronJob> = Api::all(client.clone());
     let cj_list = cronjobs.list(&ListParams::default()).await?;
@@ -15,6 +16,7 @@ pub async fn fetch_cronjobs(client: &Client) -> Result<Vec<CronJob>> {
 }
 
 /// Fetch cronjobs in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_cronjobs_by_namespace(
     client: &Client,
     namespace: &str,
@@ -31,6 +33,7 @@ pub async fn fetch_cronjobs_by_namespace(
 }
 
 /// Fetch a single cronjob by name and namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_cronjob_by_name_and_namespace(
     client: &Client,
     namespace: &str,
@@ -44,6 +47,7 @@ pub async fn fetch_cronjob_by_name_and_namespace(
 }
 
 /// Fetch cronjobs filtered by label selector
+#[tracing::instrument(skip(client))]
 pub async fn fetch_cronjobs_by_label(
     client: &Client,
     label_selector: &str,
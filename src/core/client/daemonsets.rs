@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::DaemonSet;
 
 /// Fetch all daemonsets in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_daemonsets(client: &Client) -> Result<Vec<DaemonSet>> {
     let daemonsets: Api<DaemonSet> = Api::all(client.clone());
     let ds_list = daemonsets.list(&ListParams::default()).await?;
@@ -15,6 +16,7 @@ pub async fn fetch_daemonsets(client: &Client) -> Result<Vec<DaemonSet>> {
 }
 
 /// Fetch daemonsets in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_daemonsets_by_namespace(
     client: &Client,
     namespace: &str,
@@ -31,6 +33,7 @@ pub async fn fetch_daemonsets_by_namespace(
 }
 
 /// Fetch a single daemonset by name and namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_daemonset_by_name_and_namespace(
     client: &Client,
     namespace: &str,
@@ -44,6 +47,7 @@ pub async fn fetch_daemonset_by_name_and_namespace(
 }
 
 /// Fetch daemonsets filtered by label selector
+#[tracing::instrument(skip(client))]
 pub async fn fetch_daemonsets_by_label(
     client: &Client,
     label_selector: &str,
@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::Deployment;
 
 /// Fetch all deployments in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_deployments(client: &Client) -> Result<Vec<Deployment>> {
     let deployments: Api<Deployment> = Api::all(client.clone());
     let deployment_list = deployments.list(&ListParams::default()).await?;
@@ -15,6 +16,7 @@ pub async fn fetch_deployments(client: &Client) -> Result<Vec<Deployment>> {
 }
 
 /// Fetch deployments in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_deployments_by_namespace(
     client: &Client,
     namespace: &str,
@@ -31,6 +33,7 @@ pub async fn fetch_deployments_by_namespace(
 }
 
 /// Fetch a single deployment by name and namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_deployment_by_name_and_namespace(
     client: &Client,
     namespace: &str,
@@ -44,6 +47,7 @@ pub async fn fetch_deployment_by_name_and_namespace(
 }
 
 /// Fetch deployments filtered by label selector
+#[tracing::instrument(skip(client))]
 pub async fn fetch_deployments_by_label(
     client: &Client,
     label_selector: &str,
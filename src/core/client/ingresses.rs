@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::Ingress;
 
 /// Fetch all ingresses in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_ingresses(client: &Client) -> Result<Vec<Ingress>> {
     let ingresses: Api<Ingress> = Api::all(client.clone());
     let ingress_list = ingresses.list(&ListParams::default()).await?;
@@ -15,6 +16,7 @@ pub async fn fetch_ingresses(client: &Client) -> Result<Vec<Ingress>> {
 }
 
 /// Fetch ingresses in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_ingresses_by_namespace(
     client: &Client,
     namespace: &str,
@@ -31,6 +33,7 @@ pub async fn fetch_ingresses_by_namespace(
 }
 
 /// Fetch a single ingress by name and namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_ingress_by_name_and_namespace(
     client: &Client,
     namespace: &str,
@@ -44,6 +47,7 @@ pub async fn fetch_ingress_by_name_and_namespace(
 }
 
 /// Fetch ingresses filtered by label selector
+#[tracing::instrument(skip(client))]
 pub async fn fetch_ingresses_by_label(
     client: &Client,
     label_selector: &str,
@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::Job;
 
 /// Fetch all jobs in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_jobs(client: &Client) -> Result<Vec<Job>> {
     let jobs: Api<Job> = Api::all(client.clone());
     let job_list = jobs.list(&ListParams::default()).await?;
@@ -15,6 +16,7 @@ pub async fn fetch_jobs(client: &Client) -> Result<Vec<Job>> {
 }
 
 /// Fetch jobs in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_jobs_by_namespace(client: &Client, namespace: &str) -> Result<Vec<Job>> {
     let jobs: Api<Job> = Api::namespaced(client.clone(), namespace);
     let job_list = jobs.list(&ListParams::default()).await?;
@@ -28,6 +30,7 @@ pub async fn fetch_jobs_by_namespace(client: &Client, namespace: &str) -> Result
 }
 
 /// Fetch a single job by name and namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_job_by_name_and_namespace(
     client: &Client,
     namespace: &str,
@@ -41,6 +44,7 @@ pub async fn fetch_job_by_name_and_namespace(
 }
 
 /// Fetch jobs filtered by label selector
+#[tracing::instrument(skip(client))]
 pub async fn fetch_jobs_by_label(client: &Client, label_selector: &str) -> Result<Vec<Job>> {
     let jobs: Api<Job> = Api::all(client.clone());
     let lp = ListParams::default().labels(label_selector);
@@ -1,5 +1,5 @@
 /// Maps kube-rs / k8s-openapi types → internal domain models
-use crate::core::client::kube_resources::{Node, Pod, Deployment, Namespace};
+use crate::core::client::kube_resources::{Node, Pod, Deployment, Namespace, ResourceQuota};
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
 use crate::core::persistence::info::k8s::deployment::info_deployment_entity::InfoDeploymentEntity;
@@ -380,6 +380,7 @@ pub fn map_pod_to_info_entity(pod: &Pod) -> Result<InfoPodEntity> {
         pod_uid,
         creation_timestamp,
         start_time,
+        deleted_at: None,
         resource_version,
         last_updated_info_at: None,
         deleted: None,
@@ -394,6 +395,8 @@ pub fn map_pod_to_info_entity(pod: &Pod) -> Result<InfoPodEntity> {
         owner_kind,
         owner_name,
         owner_uid,
+        workload_kind: None,
+        workload_name: None,
         container_count,
         container_names,
         container_images,
@@ -415,6 +418,7 @@ pub fn map_pod_to_info_entity(pod: &Pod) -> Result<InfoPodEntity> {
         team: None,
         service: None,
         env: None,
+        attribution_source: None,
     })
 }
 
@@ -463,8 +467,104 @@ pub fn map_deployment_to_info_entity(_deployment: &Deployment) -> Result<InfoDep
     Ok(InfoDeploymentEntity::default())
 }
 
-/// Stub: Convert k8s-openapi Namespace to InfoNamespaceEntity
-/// TODO: Implement full mapping logic
-pub fn map_namespace_to_info_entity(_namespace: &Namespace) -> Result<InfoNamespaceEntity> {
-    Ok(InfoNamespaceEntity::default())
+/// Converts a Namespace plus the ResourceQuotas living in it into an
+/// InfoNamespaceEntity. Hard limits of several quotas are summed.
+pub fn map_namespace_to_info_entity(
+    namespace: &Namespace,
+    quotas: &[&ResourceQuota],
+    now: DateTime<Utc>,
+) -> Result<InfoNamespaceEntity> {
+    let metadata = &namespace.metadata;
+
+    let mut entity = InfoNamespaceEntity {
+        name: metadata.name.clone(),
+        uid: metadata.uid.clone(),
+        creation_timestamp: metadata.creation_timestamp.as_ref().map(|t| t.0),
+        resource_version: metadata.resource_version.clone(),
+        phase: namespace.status.as_ref().and_then(|s| s.phase.clone()),
+        last_updated_info_at: Some(now),
+        deleted: Some(false),
+        label: metadata.labels.as_ref().and_then(flatten_map),
+        annotation: metadata.annotations.as_ref().and_then(flatten_map),
+        quota_names: Some(quotas.iter().filter_map(|q| q.metadata.name.clone()).collect()),
+        ..Default::default()
+    };
+
+    for hard in quotas.iter().filter_map(|q| q.spec.as_ref().and_then(|s| s.hard.as_ref())) {
+        let get = |keys: &[&str]| keys.iter().find_map(|k| hard.get(*k)).map(|q| q.0.as_str());
+
+        add_to(&mut entity.quota_cpu_request_cores, get(&["requests.cpu", "cpu"]).and_then(quantity_to_cores));
+        add_to(&mut entity.quota_cpu_limit_cores, get(&["limits.cpu"]).and_then(quantity_to_cores));
+        add_to(&mut entity.quota_memory_request_bytes, get(&["requests.memory", "memory"]).and_then(quantity_to_bytes));
+        add_to(&mut entity.quota_memory_limit_bytes, get(&["limits.memory"]).and_then(quantity_to_bytes));
+        add_to(&mut entity.quota_storage_request_bytes, get(&["requests.storage"]).and_then(quantity_to_bytes));
+        add_to(&mut entity.quota_pods, get(&["pods", "count/pods"]).and_then(quantity_to_bytes));
+    }
+
+    Ok(entity)
+}
+
+fn add_to<T: std::ops::Add<Output = T> + Copy>(slot: &mut Option<T>, value: Option<T>) {
+    if let Some(v) = value {
+        *slot = Some(slot.map_or(v, |cur| cur + v));
+    }
+}
+
+/// Parses a CPU quantity (`2`, `500m`, `250000u`, `1e3n`) into cores.
+pub fn quantity_to_cores(raw: &str) -> Option<f64> {
+    let raw = raw.trim();
+    let (num, scale) = match raw.char_indices().last()? {
+        (i, 'm') => (&raw[..i], 1e-3),
+        (i, 'u') => (&raw[..i], 1e-6),
+        (i, 'n') => (&raw[..i], 1e-9),
+        _ => (raw, 1.0),
+    };
+    num.parse::<f64>().ok().map(|v| v * scale)
+}
+
+/// Parses a memory/storage/count quantity (`512Mi`, `1G`, `1.5Gi`, `100`) into
+/// a whole number; fractions are rounded up.
+pub fn quantity_to_bytes(raw: &str) -> Option<u64> {
+    const SUFFIXES: &[(&str, f64)] = &[
+        ("Ki", 1024.0),
+        ("Mi", 1_048_576.0),
+        ("Gi", 1_073_741_824.0),
+        ("Ti", 1_099_511_627_776.0),
+        ("Pi", 1_125_899_906_842_624.0),
+        ("Ei", 1_152_921_504_606_846_976.0),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+        ("E", 1e18),
+        ("m", 1e-3),
+    ];
+
+    let raw = raw.trim();
+    let (num, scale) = SUFFIXES
+        .iter()
+        .find_map(|(suffix, scale)| raw.strip_suffix(suffix).map(|n| (n, *scale)))
+        .unwrap_or((raw, 1.0));
+    let value = num.parse::<f64>().ok()? * scale;
+    (value >= 0.0).then(|| value.ceil() as u64)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quantities() {
+        assert_eq!(quantity_to_cores("2"), Some(2.0));
+        assert_eq!(quantity_to_cores("500m"), Some(0.5));
+        assert_eq!(quantity_to_bytes("512Mi"), Some(536_870_912));
+        assert_eq!(quantity_to_bytes("1G"), Some(1_000_000_000));
+        assert_eq!(quantity_to_bytes("1.5Ki"), Some(1536));
+        assert_eq!(quantity_to_bytes("10"), Some(10));
+        assert_eq!(quantity_to_bytes("1e3"), Some(1000));
+        assert_eq!(quantity_to_bytes("lots"), None);
+    }
 }
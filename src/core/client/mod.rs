@@ -10,6 +10,7 @@ pub mod kube_resources;
 pub mod nodes;
 pub mod pods;
 pub mod deployments;
+pub mod replicasets;
 pub mod statefulsets;
 pub mod daemonsets;
 pub mod jobs;
@@ -21,6 +22,7 @@ pub mod other_resources;
 pub mod watchers;
 pub mod store;
 pub mod mappers;
+pub mod owner_chain;
 
 // Other clients
 pub mod llm_client;
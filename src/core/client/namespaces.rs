@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::Namespace;
 
 /// Fetch all namespaces in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_namespaces(client: &Client) -> Result<Vec<Namespace>> {
     let namespaces: Api<Namespace> = Api::all(client.clone());
     let namespace_list = namespaces.list(&ListParams::default()).await?;
@@ -15,6 +16,7 @@ pub async fn fetch_namespaces(client: &Client) -> Result<Vec<Namespace>> {
 }
 
 /// Fetch a single namespace by name
+#[tracing::instrument(skip(client))]
 pub async fn fetch_namespace_by_name(client: &Client, name: &str) -> Result<Namespace> {
     let namespaces: Api<Namespace> = Api::all(client.clone());
     let namespace = namespaces.get(name).await?;
@@ -24,6 +26,7 @@ pub async fn fetch_namespace_by_name(client: &Client, name: &str) -> Result<Name
 }
 
 /// Fetch namespace names only
+#[tracing::instrument(skip(client))]
 pub async fn fetch_namespace_names(client: &Client) -> Result<Vec<String>> {
     let namespaces = fetch_namespaces(client).await?;
     let names = namespaces
@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::Node;
 
 /// Fetch all nodes in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_nodes(client: &Client) -> Result<Vec<Node>> {
     let nodes: Api<Node> = Api::all(client.clone());
     let node_list = nodes.list(&ListParams::default()).await?;
@@ -15,6 +16,7 @@ pub async fn fetch_nodes(client: &Client) -> Result<Vec<Node>> {
 }
 
 /// Fetch a single node by name
+#[tracing::instrument(skip(client))]
 pub async fn fetch_node_by_name(client: &Client, name: &str) -> Result<Node> {
     let nodes: Api<Node> = Api::all(client.clone());
     let node = nodes.get(name).await?;
@@ -24,6 +26,7 @@ pub async fn fetch_node_by_name(client: &Client, name: &str) -> Result<Node> {
 }
 
 /// Fetch node names only
+#[tracing::instrument(skip(client))]
 pub async fn fetch_node_names(client: &Client) -> Result<Vec<String>> {
     let nodes = fetch_nodes(client).await?;
     let names = nodes
@@ -36,6 +39,7 @@ pub async fn fetch_node_names(client: &Client) -> Result<Vec<String>> {
 
 /// Fetch node summary stats from kubelet /stats/summary endpoint
 /// This uses a direct proxy request to the kubelet through the API server
+#[tracing::instrument(skip(client))]
 pub async fn fetch_node_summary<T>(
     client: &Client,
     node_name: &str,
@@ -10,6 +10,7 @@ use crate::core::client::kube_resources::{
 // ==================== Persistent Volumes ====================
 
 /// Fetch all persistent volumes in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_persistent_volumes(client: &Client) -> Result<Vec<PersistentVolume>> {
     let pvs: Api<PersistentVolume> = Api::all(client.clone());
     let pv_list = pvs.list(&ListParams::default()).await?;
@@ -19,6 +20,7 @@ pub async fn fetch_persistent_volumes(client: &Client) -> Result<Vec<PersistentV
 }
 
 /// Fetch a single persistent volume by name
+#[tracing::instrument(skip(client))]
 pub async fn fetch_persistent_volume_by_name(
     client: &Client,
     name: &str,
@@ -33,6 +35,7 @@ pub async fn fetch_persistent_volume_by_name(
 // ==================== Persistent Volume Claims ====================
 
 /// Fetch all persistent volume claims in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_persistent_volume_claims(client: &Client) -> Result<Vec<PersistentVolumeClaim>> {
     let pvcs: Api<PersistentVolumeClaim> = Api::all(client.clone());
     let pvc_list = pvcs.list(&ListParams::default()).await?;
@@ -42,6 +45,7 @@ pub async fn fetch_persistent_volume_claims(client: &Client) -> Result<Vec<Persi
 }
 
 /// Fetch persistent volume claims in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_persistent_volume_claims_by_namespace(
     client: &Client,
     namespace: &str,
@@ -58,6 +62,7 @@ pub async fn fetch_persistent_volume_claims_by_namespace(
 }
 
 /// Fetch a single persistent volume claim by name and namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_persistent_volume_claim_by_name_and_namespace(
     client: &Client,
     namespace: &str,
@@ -73,6 +78,7 @@ pub async fn fetch_persistent_volume_claim_by_name_and_namespace(
 // ==================== Resource Quotas ====================
 
 /// Fetch all resource quotas in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_resource_quotas(client: &Client) -> Result<Vec<ResourceQuota>> {
     let quotas: Api<ResourceQuota> = Api::all(client.clone());
     let quota_list = quotas.list(&ListParams::default()).await?;
@@ -82,6 +88,7 @@ pub async fn fetch_resource_quotas(client: &Client) -> Result<Vec<ResourceQuota>
 }
 
 /// Fetch resource quotas in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_resource_quotas_by_namespace(
     client: &Client,
     namespace: &str,
@@ -100,6 +107,7 @@ pub async fn fetch_resource_quotas_by_namespace(
 // ==================== Limit Ranges ====================
 
 /// Fetch all limit ranges in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_limit_ranges(client: &Client) -> Result<Vec<LimitRange>> {
     let limits: Api<LimitRange> = Api::all(client.clone());
     let limit_list = limits.list(&ListParams::default()).await?;
@@ -109,6 +117,7 @@ pub async fn fetch_limit_ranges(client: &Client) -> Result<Vec<LimitRange>> {
 }
 
 /// Fetch limit ranges in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_limit_ranges_by_namespace(
     client: &Client,
     namespace: &str,
@@ -127,6 +136,7 @@ pub async fn fetch_limit_ranges_by_namespace(
 // ==================== Horizontal Pod Autoscalers ====================
 
 /// Fetch all HPAs in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_hpas(client: &Client) -> Result<Vec<HorizontalPodAutoscaler>> {
     let hpas: Api<HorizontalPodAutoscaler> = Api::all(client.clone());
     let hpa_list = hpas.list(&ListParams::default()).await?;
@@ -136,6 +146,7 @@ pub async fn fetch_hpas(client: &Client) -> Result<Vec<HorizontalPodAutoscaler>>
 }
 
 /// Fetch HPAs in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_hpas_by_namespace(
     client: &Client,
     namespace: &str,
@@ -0,0 +1,135 @@
+//! Resolves a pod's direct owner to the workload that actually manages it.
+//!
+//! Pods point at their immediate controller, which for Deployments is a
+//! ReplicaSet and for CronJobs is a Job. Walking one more hop gives the name
+//! users know the workload by.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use kube::Client;
+
+use crate::core::client::jobs::fetch_jobs;
+use crate::core::client::kube_resources::{Job, ObjectMeta, OwnerReference, ReplicaSet};
+use crate::core::client::replicasets::fetch_replicasets;
+
+/// Guards against malformed owner references that loop back on themselves.
+const MAX_DEPTH: usize = 4;
+
+/// Top-level controller of a pod.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workload {
+    pub kind: String,
+    pub name: String,
+}
+
+/// `(kind, namespace, name)` → parent `(kind, name)` for intermediate controllers.
+#[derive(Debug, Default, Clone)]
+pub struct OwnerChain {
+    parents: HashMap<(String, String, String), (String, String)>,
+}
+
+impl OwnerChain {
+    /// Lists ReplicaSets and Jobs cluster-wide and indexes their owners.
+    pub async fn load(client: &Client) -> Result<Self> {
+        let replicasets = fetch_replicasets(client).await?;
+        let jobs = fetch_jobs(client).await?;
+        Ok(Self::from_objects(&replicasets, &jobs))
+    }
+
+    pub fn from_objects(replicasets: &[ReplicaSet], jobs: &[Job]) -> Self {
+        let mut chain = Self::default();
+        for rs in replicasets {
+            chain.insert("ReplicaSet", &rs.metadata);
+        }
+        for job in jobs {
+            chain.insert("Job", &job.metadata);
+        }
+        chain
+    }
+
+    fn insert(&mut self, kind: &str, meta: &ObjectMeta) {
+        let (Some(ns), Some(name)) = (meta.namespace.as_ref(), meta.name.as_ref()) else {
+            return;
+        };
+        if let Some(owner) = controller_owner(meta.owner_references.as_deref()) {
+            self.parents.insert(
+                (kind.to_string(), ns.clone(), name.clone()),
+                (owner.kind.clone(), owner.name.clone()),
+            );
+        }
+    }
+
+    /// Walks up from a pod's direct owner until no further parent is known.
+    pub fn resolve(&self, namespace: &str, owner_kind: &str, owner_name: &str) -> Workload {
+        let mut kind = owner_kind.to_string();
+        let mut name = owner_name.to_string();
+        for _ in 0..MAX_DEPTH {
+            match self.parents.get(&(kind.clone(), namespace.to_string(), name.clone())) {
+                Some((parent_kind, parent_name)) => {
+                    kind = parent_kind.clone();
+                    name = parent_name.clone();
+                }
+                None => break,
+            }
+        }
+        Workload { kind, name }
+    }
+}
+
+/// Prefers the reference marked `controller: true`, falling back to the first one.
+pub fn controller_owner(owners: Option<&[OwnerReference]>) -> Option<&OwnerReference> {
+    let owners = owners?;
+    owners
+        .iter()
+        .find(|o| o.controller == Some(true))
+        .or_else(|| owners.first())
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(name: &str, owner: Option<(&str, &str)>) -> ObjectMeta {
+        ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some("default".to_string()),
+            owner_references: owner.map(|(kind, name)| {
+                vec![OwnerReference {
+                    kind: kind.to_string(),
+                    name: name.to_string(),
+                    controller: Some(true),
+                    ..Default::default()
+                }]
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn resolves_through_replicasets_and_jobs() {
+        let rs = ReplicaSet {
+            metadata: meta("api-7f9c", Some(("Deployment", "api"))),
+            ..Default::default()
+        };
+        let job = Job {
+            metadata: meta("backup-2891", Some(("CronJob", "backup"))),
+            ..Default::default()
+        };
+        let chain = OwnerChain::from_objects(&[rs], &[job]);
+
+        let w = chain.resolve("default", "ReplicaSet", "api-7f9c");
+        assert_eq!(w, Workload { kind: "Deployment".into(), name: "api".into() });
+
+        let w = chain.resolve("default", "Job", "backup-2891");
+        assert_eq!(w, Workload { kind: "CronJob".into(), name: "backup".into() });
+
+        // Unknown owners and other namespaces resolve to themselves
+        let w = chain.resolve("other", "ReplicaSet", "api-7f9c");
+        assert_eq!(w.kind, "ReplicaSet");
+        let w = chain.resolve("default", "StatefulSet", "db");
+        assert_eq!(w, Workload { kind: "StatefulSet".into(), name: "db".into() });
+    }
+}
@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::Pod;
 
 /// Fetch all pods in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pods(client: &Client) -> Result<Vec<Pod>> {
     let pods: Api<Pod> = Api::all(client.clone());
     let pod_list = pods.list(&ListParams::default()).await?;
@@ -15,6 +16,7 @@ pub async fn fetch_pods(client: &Client) -> Result<Vec<Pod>> {
 }
 
 /// Fetch pods in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pods_by_namespace(client: &Client, namespace: &str) -> Result<Vec<Pod>> {
     let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
     let pod_list = pods.list(&ListParams::default()).await?;
@@ -24,6 +26,7 @@ pub async fn fetch_pods_by_namespace(client: &Client, namespace: &str) -> Result
 }
 
 /// Fetch a single pod by name and namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pod_by_name_and_namespace(
     client: &Client,
     namespace: &str,
@@ -37,6 +40,7 @@ pub async fn fetch_pod_by_name_and_namespace(
 }
 
 /// Fetch pods filtered by label selector (e.g. "app=myservice")
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pods_by_label(
     client: &Client,
     label_selector: &str,
@@ -50,6 +54,7 @@ pub async fn fetch_pods_by_label(
 }
 
 /// Fetch pods scheduled on a specific node
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pods_by_node(client: &Client, node_name: &str) -> Result<Vec<Pod>> {
     let pods: Api<Pod> = Api::all(client.clone());
     let field_selector = format!("spec.nodeName={}", node_name);
@@ -61,6 +66,7 @@ pub async fn fetch_pods_by_node(client: &Client, node_name: &str) -> Result<Vec<
 }
 
 /// Fetch a pod by its UID
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pod_by_uid(client: &Client, pod_uid: &str) -> Result<Pod> {
     let pods: Api<Pod> = Api::all(client.clone());
     let field_selector = format!("metadata.uid={}", pod_uid);
@@ -74,6 +80,7 @@ pub async fn fetch_pod_by_uid(client: &Client, pod_uid: &str) -> Result<Pod> {
 }
 
 /// Fetch pod names only
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pod_names(client: &Client) -> Result<Vec<String>> {
     let pods = fetch_pods(client).await?;
     let names = pods
@@ -85,6 +92,7 @@ pub async fn fetch_pod_names(client: &Client) -> Result<Vec<String>> {
 }
 
 /// Fetch pod names in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pod_names_by_namespace(
     client: &Client,
     namespace: &str,
@@ -99,6 +107,7 @@ pub async fn fetch_pod_names_by_namespace(
 }
 
 /// Fetch pod names by label selector
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pod_names_by_label(
     client: &Client,
     label_selector: &str,
@@ -113,6 +122,7 @@ pub async fn fetch_pod_names_by_label(
 }
 
 /// Fetch pod names on a specific node
+#[tracing::instrument(skip(client))]
 pub async fn fetch_pod_names_by_node(client: &Client, node_name: &str) -> Result<Vec<String>> {
     let pods = fetch_pods_by_node(client, node_name).await?;
     let names = pods
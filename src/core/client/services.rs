@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::Service;
 
 /// Fetch all services in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_services(client: &Client) -> Result<Vec<Service>> {
     let services: Api<Service> = Api::all(client.clone());
     let svc_list = services.list(&ListParams::default()).await?;
@@ -15,6 +16,7 @@ pub async fn fetch_services(client: &Client) -> Result<Vec<Service>> {
 }
 
 /// Fetch services in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_services_by_namespace(
     client: &Client,
     namespace: &str,
@@ -31,6 +33,7 @@ pub async fn fetch_services_by_namespace(
 }
 
 /// Fetch a single service by name and namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_service_by_name_and_namespace(
     client: &Client,
     namespace: &str,
@@ -44,6 +47,7 @@ pub async fn fetch_service_by_name_and_namespace(
 }
 
 /// Fetch services filtered by label selector
+#[tracing::instrument(skip(client))]
 pub async fn fetch_services_by_label(
     client: &Client,
     label_selector: &str,
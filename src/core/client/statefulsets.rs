@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::core::client::kube_resources::StatefulSet;
 
 /// Fetch all statefulsets in the cluster
+#[tracing::instrument(skip(client))]
 pub async fn fetch_statefulsets(client: &Client) -> Result<Vec<StatefulSet>> {
     let statefulsets: Api<StatefulSet> = Api::all(client.clone());
     let statefulset_list = statefulsets.list(&ListParams::default()).await?;
@@ -18,6 +19,7 @@ pub async fn fetch_statefulsets(client: &Client) -> Result<Vec<StatefulSet>> {
 }
 
 /// Fetch statefulsets in a specific namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_statefulsets_by_namespace(
     client: &Client,
     namespace: &str,
@@ -34,6 +36,7 @@ pub async fn fetch_statefulsets_by_namespace(
 }
 
 /// Fetch a single statefulset by name and namespace
+#[tracing::instrument(skip(client))]
 pub async fn fetch_statefulset_by_name_and_namespace(
     client: &Client,
     namespace: &str,
@@ -47,6 +50,7 @@ pub async fn fetch_statefulset_by_name_and_namespace(
 }
 
 /// Fetch statefulsets filtered by label selector
+#[tracing::instrument(skip(client))]
 pub async fn fetch_statefulsets_by_label(
     client: &Client,
     label_selector: &str,
@@ -1,7 +1,11 @@
+use std::fmt::Debug;
+use std::future::Future;
+
 use anyhow::Result;
 use futures::StreamExt;
-use kube::{Api, Client};
+use kube::{Api, Client, Resource};
 use kube::runtime::{watcher, WatchStreamExt};
+use serde::de::DeserializeOwned;
 use tracing::{debug, error, info};
 
 use crate::core::client::kube_resources::{Node, Pod, Deployment};
@@ -145,6 +149,52 @@ where
     Ok(())
 }
 
+/// A watch event with deletions kept, unlike the `applied_objects` streams above.
+#[derive(Debug, Clone)]
+pub enum WatchEvent<K> {
+    /// Added or modified, including objects replayed when the watch (re)starts
+    Applied(K),
+    Deleted(K),
+}
+
+/// Watch every `K` in the cluster, awaiting `handler` for each add/update/delete.
+///
+/// Deletes that happen while the watch is disconnected are not replayed; callers
+/// should reconcile with a periodic full list.
+pub async fn watch_events<K, F, Fut>(client: &Client, mut handler: F) -> Result<()>
+where
+    K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + 'static,
+    F: FnMut(WatchEvent<K>) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let api: Api<K> = Api::all(client.clone());
+    let kind = K::kind(&()).to_string();
+
+    info!("Starting {} event watcher...", kind);
+
+    let mut stream = watcher(api, watcher::Config::default())
+        .default_backoff()
+        .boxed();
+
+    while let Some(result) = stream.next().await {
+        let event = match result {
+            Ok(watcher::Event::Apply(obj)) | Ok(watcher::Event::InitApply(obj)) => WatchEvent::Applied(obj),
+            Ok(watcher::Event::Delete(obj)) => WatchEvent::Deleted(obj),
+            Ok(watcher::Event::Init) | Ok(watcher::Event::InitDone) => continue,
+            Err(e) => {
+                error!("{} watcher error: {:?}", kind, e);
+                continue;
+            }
+        };
+
+        if let Err(e) = handler(event).await {
+            error!("Error handling {} event: {:?}", kind, e);
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
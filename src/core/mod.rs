@@ -12,3 +12,4 @@ pub mod persistence;
 pub mod client;
 pub mod state;
 pub mod util;
+pub mod self_metrics;
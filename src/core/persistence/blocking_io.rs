@@ -0,0 +1,40 @@
+//! Blocking file IO from synchronous adapter code.
+//!
+//! Adapters and repositories are synchronous but are called from async services
+//! and scheduler tasks. A month-long query reads thousands of partition files on
+//! the runtime worker that runs the request, stalling every task queued behind
+//! it. Adapters run their IO through [`blocking_io`] instead.
+
+use tokio::runtime::{Handle, RuntimeFlavor};
+use tokio::task::block_in_place;
+
+/// Runs `f`, which does blocking IO, without stalling the async executor.
+///
+/// On the multi-threaded runtime the worker first hands its queued tasks to
+/// another thread (`block_in_place`), like the remote metric client does.
+/// Elsewhere (current-thread runtimes in tests, `spawn_blocking` closures, plain
+/// threads) `f` just runs.
+pub fn blocking_io<R>(f: impl FnOnce() -> R) -> R {
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => block_in_place(f),
+        _ => f(),
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_inline_on_current_thread_runtime() {
+        // block_in_place would panic here
+        assert_eq!(blocking_io(|| 1 + 1), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn nests_on_multi_thread_runtime() {
+        assert_eq!(blocking_io(|| blocking_io(|| "read")), "read");
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// One UTC day of imported cloud billing, summed per service and account.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InfoBillingDayEntity {
+    pub date: NaiveDate,
+    /// `aws_cur` or `gcp`
+    pub source: String,
+    pub currency: String,
+    pub imported_at: DateTime<Utc>,
+    #[serde(default)]
+    pub items: Vec<InfoBillingItemEntity>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InfoBillingItemEntity {
+    /// Product code (`AmazonEC2`) or service description (`Compute Engine`)
+    pub service: String,
+    /// AWS usage account or GCP project
+    pub account: String,
+    pub cost: f64,
+}
+
+impl InfoBillingDayEntity {
+    /// Billed cost of the items matching `services` (any when empty) and `account`.
+    pub fn total_cost(&self, services: &[String], account: Option<&str>) -> f64 {
+        self.items
+            .iter()
+            .filter(|i| services.is_empty() || services.iter().any(|s| s.eq_ignore_ascii_case(&i.service)))
+            .filter(|i| account.is_none_or(|a| a == i.account))
+            .map(|i| i.cost)
+            .sum()
+    }
+}
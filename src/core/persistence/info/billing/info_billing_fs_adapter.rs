@@ -0,0 +1,68 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::fs;
+
+use crate::core::persistence::info::billing::info_billing_day_entity::InfoBillingDayEntity;
+use crate::core::persistence::info::path::{info_billing_dir_path, info_billing_file_path};
+
+/// One JSON file per day in `$RUSTCOST_BASE_PATH/info/billing/YYYY-MM-DD.json`.
+#[derive(Default)]
+pub struct InfoBillingFsAdapter;
+
+impl InfoBillingFsAdapter {
+    /// `None` when nothing was imported for `date`.
+    pub fn read(&self, date: NaiveDate) -> Result<Option<InfoBillingDayEntity>> {
+        let path = info_billing_file_path(&date.format("%Y-%m-%d").to_string());
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let raw = fs::read_to_string(&path).context("Failed to read billing file")?;
+        Ok(Some(serde_json::from_str(&raw).context("Failed to parse billing file")?))
+    }
+
+    /// Replaces the day; writes through a temp file like the other info files.
+    pub fn write(&self, day: &InfoBillingDayEntity) -> Result<()> {
+        let path = info_billing_file_path(&day.date.format("%Y-%m-%d").to_string());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("Failed to create billing directory")?;
+        }
+
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string(day)?).context("Failed to write billing file")?;
+        fs::rename(&tmp, &path).context("Failed to replace billing file")?;
+        Ok(())
+    }
+
+    /// Imported days in `[start, end]`, oldest first.
+    pub fn list_between(&self, start: NaiveDate, end: NaiveDate) -> Result<Vec<InfoBillingDayEntity>> {
+        let dir = info_billing_dir_path();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut dates = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let date = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+            if let Some(date) = date.filter(|d| *d >= start && *d <= end) {
+                dates.push(date);
+            }
+        }
+        dates.sort();
+
+        let mut out = Vec::with_capacity(dates.len());
+        for date in dates {
+            if let Some(day) = self.read(date)? {
+                out.push(day);
+            }
+        }
+        Ok(out)
+    }
+}
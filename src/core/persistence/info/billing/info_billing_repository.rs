@@ -0,0 +1,40 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+
+use crate::core::persistence::info::billing::info_billing_day_entity::InfoBillingDayEntity;
+use crate::core::persistence::info::billing::info_billing_fs_adapter::InfoBillingFsAdapter;
+
+pub trait InfoBillingRepository: Send + Sync {
+    fn fs(&self) -> &InfoBillingFsAdapter;
+
+    fn read(&self, date: NaiveDate) -> Result<Option<InfoBillingDayEntity>> {
+        self.fs().read(date)
+    }
+
+    fn write(&self, day: &InfoBillingDayEntity) -> Result<()> {
+        self.fs().write(day)
+    }
+
+    fn list_between(&self, start: NaiveDate, end: NaiveDate) -> Result<Vec<InfoBillingDayEntity>> {
+        self.fs().list_between(start, end)
+    }
+}
+
+#[derive(Default)]
+pub struct InfoBillingRepositoryImpl {
+    adapter: InfoBillingFsAdapter,
+}
+
+impl InfoBillingRepositoryImpl {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoBillingFsAdapter,
+        }
+    }
+}
+
+impl InfoBillingRepository for InfoBillingRepositoryImpl {
+    fn fs(&self) -> &InfoBillingFsAdapter {
+        &self.adapter
+    }
+}
@@ -0,0 +1,3 @@
+pub mod info_billing_day_entity;
+pub mod info_billing_fs_adapter;
+pub mod info_billing_repository;
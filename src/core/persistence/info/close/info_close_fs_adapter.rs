@@ -0,0 +1,73 @@
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+
+use crate::core::persistence::info::close::info_close_period_entity::InfoClosePeriodEntity;
+use crate::core::persistence::info::path::{info_close_dir_path, info_close_file_path};
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// One JSON file per closed month in `$RUSTCOST_BASE_PATH/info/close/YYYY-MM.json`.
+#[derive(Default)]
+pub struct InfoCloseFsAdapter;
+
+impl InfoCloseFsAdapter {
+    /// `None` while `period` is open.
+    pub fn read(&self, period: &str) -> Result<Option<InfoClosePeriodEntity>> {
+        blocking_io(|| {
+            let path = info_close_file_path(period);
+            if !path.exists() {
+                return Ok(None);
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read closed period file")?;
+            Ok(Some(serde_json::from_str(&raw).context("Failed to parse closed period file")?))
+        })
+    }
+
+    /// Writes a new snapshot; fails if the period is already closed, so a close
+    /// can never be overwritten.
+    pub fn create(&self, snapshot: &InfoClosePeriodEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = info_close_file_path(&snapshot.period);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create close directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string(snapshot)?).context("Failed to write closed period file")?;
+
+            // hard_link refuses an existing target, unlike rename
+            let linked = fs::hard_link(&tmp, &path);
+            let _ = fs::remove_file(&tmp);
+            match linked {
+                Ok(()) => Ok(()),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    Err(anyhow!("period {} is already closed", snapshot.period))
+                }
+                Err(e) => Err(e).context("Failed to finalize closed period file"),
+            }
+        })
+    }
+
+    /// Closed periods, oldest first.
+    pub fn list(&self) -> Result<Vec<String>> {
+        blocking_io(|| {
+            let dir = info_close_dir_path();
+            if !dir.exists() {
+                return Ok(Vec::new());
+            }
+
+            let mut periods = Vec::new();
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                    continue;
+                }
+                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                    periods.push(stem.to_string());
+                }
+            }
+            periods.sort();
+            Ok(periods)
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::export::cost_allocation_record::CostAllocationRecord;
+
+/// A month of daily cost allocation frozen by a finance close. Written once;
+/// allocation queries for the month are served from it from then on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfoClosePeriodEntity {
+    /// `YYYY-MM` (UTC)
+    pub period: String,
+    pub closed_at: DateTime<Utc>,
+    #[serde(default)]
+    pub note: Option<String>,
+    /// One record per pod and day, as exported at close time
+    pub records: Vec<CostAllocationRecord>,
+}
+
+impl InfoClosePeriodEntity {
+    pub fn total_cost_usd(&self) -> f64 {
+        self.records.iter().map(|r| r.total_cost_usd).sum()
+    }
+
+    /// Records of one `YYYY-MM-DD` day.
+    pub fn records_on(&self, date: &str) -> Vec<CostAllocationRecord> {
+        self.records.iter().filter(|r| r.date == date).cloned().collect()
+    }
+}
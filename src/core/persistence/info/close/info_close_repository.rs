@@ -0,0 +1,39 @@
+use anyhow::Result;
+
+use crate::core::persistence::info::close::info_close_fs_adapter::InfoCloseFsAdapter;
+use crate::core::persistence::info::close::info_close_period_entity::InfoClosePeriodEntity;
+
+pub trait InfoCloseRepository: Send + Sync {
+    fn fs(&self) -> &InfoCloseFsAdapter;
+
+    fn read(&self, period: &str) -> Result<Option<InfoClosePeriodEntity>> {
+        self.fs().read(period)
+    }
+
+    fn create(&self, snapshot: &InfoClosePeriodEntity) -> Result<()> {
+        self.fs().create(snapshot)
+    }
+
+    fn list(&self) -> Result<Vec<String>> {
+        self.fs().list()
+    }
+}
+
+#[derive(Default)]
+pub struct InfoCloseRepositoryImpl {
+    adapter: InfoCloseFsAdapter,
+}
+
+impl InfoCloseRepositoryImpl {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoCloseFsAdapter,
+        }
+    }
+}
+
+impl InfoCloseRepository for InfoCloseRepositoryImpl {
+    fn fs(&self) -> &InfoCloseFsAdapter {
+        &self.adapter
+    }
+}
@@ -0,0 +1,3 @@
+pub mod info_close_period_entity;
+pub mod info_close_fs_adapter;
+pub mod info_close_repository;
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Metrics that can be evaluated by alert rules.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 pub enum AlertMetricType {
     CpuUsagePercent,
     MemoryUsagePercent,
@@ -31,7 +32,7 @@ impl AlertMetricType {
 }
 
 /// Comparison operator for rule evaluation.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 pub enum AlertOperator {
     GreaterThan,
     LessThan,
@@ -61,7 +62,7 @@ impl AlertOperator {
 }
 
 /// Severity levels map to Discord embed colors.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 pub enum AlertSeverity {
     Info,
     Warning,
@@ -87,6 +88,45 @@ impl AlertSeverity {
     }
 }
 
+/// Where a firing rule is delivered. `All` sends to every configured webhook.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum AlertChannel {
+    #[default]
+    All,
+    Discord,
+    Slack,
+    Teams,
+    /// Recorded in runtime state only; nothing is sent.
+    None,
+}
+
+impl AlertChannel {
+    pub fn from_code<S: AsRef<str>>(code: S) -> Option<Self> {
+        match code.as_ref().to_uppercase().as_str() {
+            "ALL" => Some(Self::All),
+            "DISCORD" => Some(Self::Discord),
+            "SLACK" => Some(Self::Slack),
+            "TEAMS" => Some(Self::Teams),
+            "NONE" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            Self::All => "ALL",
+            Self::Discord => "DISCORD",
+            Self::Slack => "SLACK",
+            Self::Teams => "TEAMS",
+            Self::None => "NONE",
+        }
+    }
+
+    pub fn includes(&self, other: &AlertChannel) -> bool {
+        *self == AlertChannel::All || self == other
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AlertRuleEntity {
     pub id: String,
@@ -97,4 +137,60 @@ pub struct AlertRuleEntity {
     pub for_duration_sec: u64,
     pub severity: AlertSeverity,
     pub enabled: bool,
+    /// Node name pattern the rule applies to (`*` wildcards); `None` matches every node.
+    #[serde(default)]
+    pub scope_selector: Option<String>,
+    #[serde(default)]
+    pub channel: AlertChannel,
+}
+
+impl AlertRuleEntity {
+    pub fn matches_scope(&self, node: &str) -> bool {
+        selector_matches(self.scope_selector.as_deref(), node)
+    }
+}
+
+/// Matches `value` against a glob where `*` stands for any run of characters.
+pub fn selector_matches(selector: Option<&str>, value: &str) -> bool {
+    let Some(pattern) = selector.map(str::trim).filter(|p| !p.is_empty()) else {
+        return true;
+    };
+
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == value;
+    }
+
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if !value.starts_with(first) || value.len() < first.len() + last.len() || !value.ends_with(last) {
+        return false;
+    }
+
+    let mut rest = &value[first.len()..value.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selector_supports_wildcards() {
+        assert!(selector_matches(None, "node-a"));
+        assert!(selector_matches(Some(""), "node-a"));
+        assert!(selector_matches(Some("node-a"), "node-a"));
+        assert!(!selector_matches(Some("node-a"), "node-ab"));
+        assert!(selector_matches(Some("gpu-*"), "gpu-pool-1"));
+        assert!(selector_matches(Some("*-pool-*"), "gpu-pool-1"));
+        assert!(!selector_matches(Some("gpu-*-2"), "gpu-pool-1"));
+        assert!(!selector_matches(Some("ab*ba"), "aba"));
+    }
 }
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::alert_rule_entity::selector_matches;
+
+/// Window during which matching alerts keep firing but are not delivered.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AlertSilenceEntity {
+    pub id: String,
+    /// Silenced rule; `None` silences every rule.
+    pub rule_id: Option<String>,
+    /// Node name pattern (`*` wildcards), or the workload name for efficiency
+    /// rules; `None` matches everything.
+    pub scope_selector: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub comment: Option<String>,
+}
+
+impl AlertSilenceEntity {
+    pub fn covers(&self, rule_id: &str, target: &str, now: DateTime<Utc>) -> bool {
+        let rule_matches = match self.rule_id.as_deref() {
+            Some(id) => id == rule_id,
+            None => true,
+        };
+
+        rule_matches
+            && self.starts_at <= now
+            && now < self.ends_at
+            && selector_matches(self.scope_selector.as_deref(), target)
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.ends_at <= now
+    }
+}
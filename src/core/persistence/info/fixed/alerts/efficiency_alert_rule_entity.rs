@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::alert_rule_entity::{selector_matches, AlertChannel, AlertSeverity};
+
+/// Workload level an efficiency-drop rule watches.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum AlertEfficiencyScope {
+    Namespace,
+    Deployment,
+}
+
+impl AlertEfficiencyScope {
+    pub fn from_code<S: AsRef<str>>(code: S) -> Option<Self> {
+        match code.as_ref().to_uppercase().as_str() {
+            "NAMESPACE" => Some(Self::Namespace),
+            "DEPLOYMENT" => Some(Self::Deployment),
+            _ => None,
+        }
+    }
+
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            Self::Namespace => "NAMESPACE",
+            Self::Deployment => "DEPLOYMENT",
+        }
+    }
+}
+
+/// Fires when a workload's CPU/memory efficiency (usage over requests) stays
+/// below `threshold_percent` for `consecutive_hours` full hours.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EfficiencyAlertRuleEntity {
+    pub id: String,
+    pub name: String,
+    pub scope: AlertEfficiencyScope,
+    /// Namespace or deployment name pattern (`*` wildcards); `None` matches all.
+    pub target_selector: Option<String>,
+    pub threshold_percent: f64,
+    pub consecutive_hours: u32,
+    pub severity: AlertSeverity,
+    #[serde(default)]
+    pub channel: AlertChannel,
+    pub enabled: bool,
+}
+
+impl EfficiencyAlertRuleEntity {
+    pub fn matches_target(&self, target: &str) -> bool {
+        selector_matches(self.target_selector.as_deref(), target)
+    }
+}
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use crate::domain::info::dto::info_alert_upsert_request::{AlertRuleUpsertRequest, InfoAlertUpsertRequest};
 
 use super::alert_rule_entity::AlertRuleEntity;
+use super::alert_silence_entity::AlertSilenceEntity;
+use super::efficiency_alert_rule_entity::EfficiencyAlertRuleEntity;
 
 /// Alert delivery configuration extracted from the legacy settings file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +28,12 @@ pub struct InfoAlertEntity {
     pub discord_webhook_url: Option<String>,
     /// Declarative alert rules evaluated against metrics.
     pub rules: Vec<AlertRuleEntity>,
+    /// Workload efficiency-drop rules, evaluated hourly.
+    #[serde(default)]
+    pub efficiency_rules: Vec<EfficiencyAlertRuleEntity>,
+    /// Windows during which matching rules are not delivered.
+    #[serde(default)]
+    pub silences: Vec<AlertSilenceEntity>,
     /// Configuration creation timestamp (UTC).
     pub created_at: DateTime<Utc>,
     /// Last update timestamp (UTC).
@@ -47,6 +55,8 @@ impl Default for InfoAlertEntity {
             teams_webhook_url: None,
             discord_webhook_url: None,
             rules: Vec::new(),
+            efficiency_rules: Vec::new(),
+            silences: Vec::new(),
             created_at: now,
             updated_at: now,
             version: "1.0.0".into(),
@@ -85,8 +95,21 @@ impl InfoAlertEntity {
         if let Some(v) = req.rules {
             self.rules = v.into_iter().map(AlertRuleEntity::from).collect();
         }
+        if let Some(v) = req.efficiency_rules {
+            self.efficiency_rules = v.into_iter().map(EfficiencyAlertRuleEntity::from).collect();
+        }
+        if let Some(v) = req.silences {
+            self.silences = v.into_iter().map(AlertSilenceEntity::from).collect();
+        }
+
+        let now = Utc::now();
+        self.silences.retain(|s| !s.is_expired(now));
+        self.updated_at = now;
+    }
 
-        self.updated_at = Utc::now();
+    /// Whether any active silence covers `rule_id` on `target` (node or workload).
+    pub fn is_silenced(&self, rule_id: &str, target: &str, now: DateTime<Utc>) -> bool {
+        self.silences.iter().any(|s| s.covers(rule_id, target, now))
     }
 }
 
@@ -11,8 +11,13 @@ use chrono::{DateTime, Utc};
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
 use crate::core::persistence::storage_path::{info_alert_path, info_setting_path};
 
-use super::alert_rule_entity::{AlertMetricType, AlertOperator, AlertRuleEntity, AlertSeverity};
+use super::alert_rule_entity::{
+    AlertChannel, AlertMetricType, AlertOperator, AlertRuleEntity, AlertSeverity,
+};
+use super::alert_silence_entity::AlertSilenceEntity;
+use super::efficiency_alert_rule_entity::{AlertEfficiencyScope, EfficiencyAlertRuleEntity};
 use super::info_alert_entity::InfoAlertEntity;
+use crate::core::persistence::blocking_io::blocking_io;
 
 /// FS adapter for persisted alert settings.
 ///
@@ -27,19 +32,21 @@ impl InfoFixedFsAdapterTrait<InfoAlertEntity> for InfoAlertFsAdapter {
     }
 
     fn read(&self) -> Result<InfoAlertEntity> {
-        let path = info_alert_path();
-        if path.exists() {
-            return Self::read_from_path(&path);
-        }
+        blocking_io(|| {
+            let path = info_alert_path();
+            if path.exists() {
+                return Self::read_from_path(&path);
+            }
 
-        let legacy = info_setting_path();
-        if legacy.exists() {
-            if let Ok(entity) = Self::read_from_path(&legacy) {
-                return Ok(entity);
+            let legacy = info_setting_path();
+            if legacy.exists() {
+                if let Ok(entity) = Self::read_from_path(&legacy) {
+                    return Ok(entity);
+                }
             }
-        }
 
-        Ok(InfoAlertEntity::default())
+            Ok(InfoAlertEntity::default())
+        })
     }
 
     fn insert(&self, data: &InfoAlertEntity) -> Result<()> {
@@ -51,11 +58,13 @@ impl InfoFixedFsAdapterTrait<InfoAlertEntity> for InfoAlertFsAdapter {
     }
 
     fn delete(&self) -> Result<()> {
-        let path = info_alert_path();
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete alerts file")?;
-        }
-        Ok(())
+        blocking_io(|| {
+            let path = info_alert_path();
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete alerts file")?;
+            }
+            Ok(())
+        })
     }
 }
 
@@ -72,7 +81,7 @@ impl InfoAlertFsAdapter {
                 let key = key.trim().to_uppercase();
                 let val = val.trim();
 
-                if key.starts_with("ALERT_RULE_") {
+                if key.starts_with("ALERT_") {
                     raw_rules.insert(key.clone(), val.to_string());
                 }
 
@@ -136,6 +145,8 @@ impl InfoAlertFsAdapter {
         }
 
         s.rules = Self::parse_rules(&raw_rules);
+        s.efficiency_rules = Self::parse_efficiency_rules(&raw_rules);
+        s.silences = Self::parse_silences(&raw_rules);
         Ok(s)
     }
 
@@ -161,6 +172,31 @@ impl InfoAlertFsAdapter {
             writeln!(f, "ALERT_RULE_{}_FOR_SEC:{}", idx, rule.for_duration_sec)?;
             writeln!(f, "ALERT_RULE_{}_SEVERITY:{}", idx, rule.severity.as_code())?;
             writeln!(f, "ALERT_RULE_{}_ENABLED:{}", idx, rule.enabled)?;
+            writeln!(f, "ALERT_RULE_{}_SELECTOR:{}", idx, rule.scope_selector.clone().unwrap_or_default())?;
+            writeln!(f, "ALERT_RULE_{}_CHANNEL:{}", idx, rule.channel.as_code())?;
+        }
+
+        writeln!(f, "ALERT_EFF_RULE_COUNT:{}", data.efficiency_rules.len())?;
+        for (idx, rule) in data.efficiency_rules.iter().enumerate() {
+            writeln!(f, "ALERT_EFF_RULE_{}_ID:{}", idx, rule.id)?;
+            writeln!(f, "ALERT_EFF_RULE_{}_NAME:{}", idx, rule.name)?;
+            writeln!(f, "ALERT_EFF_RULE_{}_SCOPE:{}", idx, rule.scope.as_code())?;
+            writeln!(f, "ALERT_EFF_RULE_{}_SELECTOR:{}", idx, rule.target_selector.clone().unwrap_or_default())?;
+            writeln!(f, "ALERT_EFF_RULE_{}_THRESHOLD:{}", idx, rule.threshold_percent)?;
+            writeln!(f, "ALERT_EFF_RULE_{}_HOURS:{}", idx, rule.consecutive_hours)?;
+            writeln!(f, "ALERT_EFF_RULE_{}_SEVERITY:{}", idx, rule.severity.as_code())?;
+            writeln!(f, "ALERT_EFF_RULE_{}_CHANNEL:{}", idx, rule.channel.as_code())?;
+            writeln!(f, "ALERT_EFF_RULE_{}_ENABLED:{}", idx, rule.enabled)?;
+        }
+
+        writeln!(f, "ALERT_SILENCE_COUNT:{}", data.silences.len())?;
+        for (idx, silence) in data.silences.iter().enumerate() {
+            writeln!(f, "ALERT_SILENCE_{}_ID:{}", idx, silence.id)?;
+            writeln!(f, "ALERT_SILENCE_{}_RULE:{}", idx, silence.rule_id.clone().unwrap_or_default())?;
+            writeln!(f, "ALERT_SILENCE_{}_SELECTOR:{}", idx, silence.scope_selector.clone().unwrap_or_default())?;
+            writeln!(f, "ALERT_SILENCE_{}_STARTS_AT:{}", idx, silence.starts_at.to_rfc3339())?;
+            writeln!(f, "ALERT_SILENCE_{}_ENDS_AT:{}", idx, silence.ends_at.to_rfc3339())?;
+            writeln!(f, "ALERT_SILENCE_{}_COMMENT:{}", idx, silence.comment.clone().unwrap_or_default())?;
         }
 
         writeln!(f, "ENABLE_CLUSTER_HEALTH_ALERT:{}", data.enable_cluster_health_alert)?;
@@ -224,6 +260,10 @@ impl InfoAlertFsAdapter {
             let enabled = get("ENABLED")
                 .map(|v| v.eq_ignore_ascii_case("true"))
                 .unwrap_or(true);
+            let scope_selector = get("SELECTOR").filter(|v| !v.is_empty());
+            let channel = get("CHANNEL")
+                .and_then(AlertChannel::from_code)
+                .unwrap_or_default();
 
             rules.push(AlertRuleEntity {
                 id,
@@ -234,9 +274,92 @@ impl InfoAlertFsAdapter {
                 for_duration_sec,
                 severity,
                 enabled,
+                scope_selector,
+                channel,
             });
         }
 
         rules
     }
+
+    fn parse_efficiency_rules(raw: &HashMap<String, String>) -> Vec<EfficiencyAlertRuleEntity> {
+        let count = raw
+            .get("ALERT_EFF_RULE_COUNT")
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        let mut rules = Vec::with_capacity(count);
+
+        for idx in 0..count {
+            let prefix = format!("ALERT_EFF_RULE_{}_", idx);
+            let get = |suffix: &str| -> Option<String> {
+                raw.get(&(prefix.clone() + suffix))
+                    .filter(|v| !v.is_empty())
+                    .map(|v| v.to_string())
+            };
+
+            let id = get("ID").unwrap_or_else(|| format!("efficiency-rule-{}", idx));
+            rules.push(EfficiencyAlertRuleEntity {
+                name: get("NAME").unwrap_or_else(|| id.clone()),
+                id,
+                scope: get("SCOPE")
+                    .and_then(AlertEfficiencyScope::from_code)
+                    .unwrap_or(AlertEfficiencyScope::Namespace),
+                target_selector: get("SELECTOR"),
+                threshold_percent: get("THRESHOLD")
+                    .and_then(|v| v.parse::<f64>().ok())
+                    .unwrap_or(0.0),
+                consecutive_hours: get("HOURS")
+                    .and_then(|v| v.parse::<u32>().ok())
+                    .unwrap_or(1)
+                    .max(1),
+                severity: get("SEVERITY")
+                    .and_then(AlertSeverity::from_code)
+                    .unwrap_or(AlertSeverity::Warning),
+                channel: get("CHANNEL")
+                    .and_then(AlertChannel::from_code)
+                    .unwrap_or_default(),
+                enabled: get("ENABLED")
+                    .map(|v| v.eq_ignore_ascii_case("true"))
+                    .unwrap_or(true),
+            });
+        }
+
+        rules
+    }
+
+    fn parse_silences(raw: &HashMap<String, String>) -> Vec<AlertSilenceEntity> {
+        let count = raw
+            .get("ALERT_SILENCE_COUNT")
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        let mut silences = Vec::with_capacity(count);
+
+        for idx in 0..count {
+            let prefix = format!("ALERT_SILENCE_{}_", idx);
+            let get = |suffix: &str| -> Option<String> {
+                raw.get(&(prefix.clone() + suffix))
+                    .filter(|v| !v.is_empty())
+                    .map(|v| v.to_string())
+            };
+            let time = |suffix: &str| get(suffix).and_then(|v| v.parse::<DateTime<Utc>>().ok());
+
+            // A window without both bounds can't be evaluated; drop it.
+            let (Some(starts_at), Some(ends_at)) = (time("STARTS_AT"), time("ENDS_AT")) else {
+                continue;
+            };
+
+            silences.push(AlertSilenceEntity {
+                id: get("ID").unwrap_or_else(|| format!("silence-{}", idx)),
+                rule_id: get("RULE"),
+                scope_selector: get("SELECTOR"),
+                starts_at,
+                ends_at,
+                comment: get("COMMENT"),
+            });
+        }
+
+        silences
+    }
 }
@@ -3,3 +3,5 @@ pub mod info_alert_fs_adapter;
 pub mod info_alert_api_repository_trait;
 pub mod info_alert_repository;
 pub mod alert_rule_entity;
+pub mod alert_silence_entity;
+pub mod efficiency_alert_rule_entity;
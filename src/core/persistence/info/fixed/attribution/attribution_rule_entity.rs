@@ -0,0 +1,174 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::persistence::info::fixed::alerts::alert_rule_entity::selector_matches;
+
+/// Maps pods to team/service/env by namespace pattern and label selector.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct AttributionRuleEntity {
+    pub id: String,
+    pub name: String,
+    /// Higher wins when several rules set the same field.
+    #[serde(default)]
+    pub priority: i32,
+    pub enabled: bool,
+    /// Namespace pattern (`*` wildcards); `None` matches every namespace.
+    #[serde(default)]
+    pub namespace_selector: Option<String>,
+    /// Labels the pod must all carry (`key: value`); empty matches every pod.
+    #[serde(default)]
+    pub label_selector: BTreeMap<String, String>,
+    pub team: Option<String>,
+    pub service: Option<String>,
+    pub env: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl AttributionRuleEntity {
+    pub fn matches(&self, namespace: &str, labels: &HashMap<&str, &str>) -> bool {
+        self.enabled
+            && selector_matches(self.namespace_selector.as_deref(), namespace)
+            && self
+                .label_selector
+                .iter()
+                .all(|(k, v)| labels.get(k.as_str()) == Some(&v.as_str()))
+    }
+
+    /// Tie-breaker between equal priorities: more labels, then an exact namespace.
+    fn specificity(&self) -> (usize, bool) {
+        let exact_ns = self
+            .namespace_selector
+            .as_deref()
+            .is_some_and(|s| !s.trim().is_empty() && !s.contains('*'));
+        (self.label_selector.len(), exact_ns)
+    }
+}
+
+/// Stored rule set at `info/attribution_rules.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct InfoAttributionRuleEntity {
+    pub rules: Vec<AttributionRuleEntity>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Values a rule set resolves for one pod, with the rule that set each.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResolvedAttribution {
+    pub team: Option<(String, String)>,
+    pub service: Option<(String, String)>,
+    pub env: Option<(String, String)>,
+}
+
+impl ResolvedAttribution {
+    pub fn is_empty(&self) -> bool {
+        self.team.is_none() && self.service.is_none() && self.env.is_none()
+    }
+}
+
+impl InfoAttributionRuleEntity {
+    pub fn find(&self, id: &str) -> Option<&AttributionRuleEntity> {
+        self.rules.iter().find(|r| r.id == id)
+    }
+
+    /// Each field comes from the highest-precedence matching rule that sets it:
+    /// priority, then specificity, then rule id for a stable order.
+    pub fn resolve(&self, namespace: &str, label: Option<&str>) -> ResolvedAttribution {
+        let labels = parse_labels(label.unwrap_or_default());
+        let mut matching: Vec<&AttributionRuleEntity> = self
+            .rules
+            .iter()
+            .filter(|r| r.matches(namespace, &labels))
+            .collect();
+        matching.sort_by(|a, b| {
+            b.priority
+                .cmp(&a.priority)
+                .then_with(|| b.specificity().cmp(&a.specificity()))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        let pick = |field: fn(&AttributionRuleEntity) -> &Option<String>| {
+            matching
+                .iter()
+                .find_map(|r| field(r).clone().map(|v| (v, r.id.clone())))
+        };
+
+        ResolvedAttribution {
+            team: pick(|r| &r.team),
+            service: pick(|r| &r.service),
+            env: pick(|r| &r.env),
+        }
+    }
+}
+
+/// Whether the flattened pod `label` string carries every `selector` pair.
+pub fn label_selector_matches(selector: &BTreeMap<String, String>, label: Option<&str>) -> bool {
+    let labels = parse_labels(label.unwrap_or_default());
+    selector
+        .iter()
+        .all(|(k, v)| labels.get(k.as_str()) == Some(&v.as_str()))
+}
+
+/// Parses the flattened `key=value,...` label string stored on pods.
+fn parse_labels(label: &str) -> HashMap<&str, &str> {
+    label
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.trim(), v.trim()))
+        .collect()
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, priority: i32, ns: Option<&str>, labels: &[(&str, &str)]) -> AttributionRuleEntity {
+        AttributionRuleEntity {
+            id: id.into(),
+            name: id.into(),
+            priority,
+            enabled: true,
+            namespace_selector: ns.map(Into::into),
+            label_selector: labels.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            team: None,
+            service: None,
+            env: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn resolves_each_field_by_precedence() {
+        let broad = AttributionRuleEntity {
+            team: Some("platform".into()),
+            env: Some("prod".into()),
+            ..rule("a-broad", 0, Some("prod-*"), &[])
+        };
+        let specific = AttributionRuleEntity {
+            team: Some("payments".into()),
+            ..rule("b-specific", 0, Some("prod-*"), &[("app", "checkout")])
+        };
+        let disabled = AttributionRuleEntity {
+            enabled: false,
+            team: Some("ignored".into()),
+            ..rule("c-off", 10, None, &[])
+        };
+        let rules = InfoAttributionRuleEntity {
+            rules: vec![broad, specific, disabled],
+            updated_at: None,
+        };
+
+        let got = rules.resolve("prod-eu", Some("app=checkout,tier=web"));
+        assert_eq!(got.team, Some(("payments".into(), "b-specific".into())));
+        assert_eq!(got.env, Some(("prod".into(), "a-broad".into())));
+        assert_eq!(got.service, None);
+
+        let other = rules.resolve("prod-eu", Some("app=search"));
+        assert_eq!(other.team, Some(("platform".into(), "a-broad".into())));
+        assert!(rules.resolve("dev", None).is_empty());
+    }
+}
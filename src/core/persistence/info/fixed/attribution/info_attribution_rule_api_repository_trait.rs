@@ -0,0 +1,15 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use super::attribution_rule_entity::InfoAttributionRuleEntity;
+
+/// API-facing repository abstraction for attribution rules.
+pub trait InfoAttributionRuleApiRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoAttributionRuleEntity>;
+
+    fn read(&self) -> anyhow::Result<InfoAttributionRuleEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, rules: &InfoAttributionRuleEntity) -> anyhow::Result<()> {
+        self.fs_adapter().update(rules)
+    }
+}
@@ -0,0 +1,61 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::path::info_attribution_rule_path;
+
+use super::attribution_rule_entity::InfoAttributionRuleEntity;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// FS adapter for attribution rules, stored as JSON at `attribution_rules.json`.
+/// A missing file is an empty rule set.
+pub struct InfoAttributionRuleFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoAttributionRuleEntity> for InfoAttributionRuleFsAdapter {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn read(&self) -> Result<InfoAttributionRuleEntity> {
+        blocking_io(|| {
+            let path = info_attribution_rule_path();
+            if !path.exists() {
+                return Ok(InfoAttributionRuleEntity::default());
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read attribution rules file")?;
+            serde_json::from_str(&raw).context("Failed to parse attribution rules file")
+        })
+    }
+
+    fn insert(&self, data: &InfoAttributionRuleEntity) -> Result<()> {
+        self.update(data)
+    }
+
+    /// Writes through a temp file so pod sync never reads a half-written rule set.
+    fn update(&self, data: &InfoAttributionRuleEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = info_attribution_rule_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create info directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string_pretty(data)?)
+                .context("Failed to write attribution rules file")?;
+            fs::rename(&tmp, &path).context("Failed to replace attribution rules file")?;
+            Ok(())
+        })
+    }
+
+    fn delete(&self) -> Result<()> {
+        blocking_io(|| {
+            let path = info_attribution_rule_path();
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete attribution rules file")?;
+            }
+            Ok(())
+        })
+    }
+}
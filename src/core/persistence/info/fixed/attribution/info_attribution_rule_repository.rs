@@ -0,0 +1,29 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+
+use super::attribution_rule_entity::InfoAttributionRuleEntity;
+use super::info_attribution_rule_api_repository_trait::InfoAttributionRuleApiRepository;
+use super::info_attribution_rule_fs_adapter::InfoAttributionRuleFsAdapter;
+
+pub struct InfoAttributionRuleRepository {
+    adapter: InfoAttributionRuleFsAdapter,
+}
+
+impl InfoAttributionRuleRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoAttributionRuleFsAdapter::new(),
+        }
+    }
+}
+
+impl InfoAttributionRuleApiRepository for InfoAttributionRuleRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoAttributionRuleEntity> {
+        &self.adapter
+    }
+}
+
+impl Default for InfoAttributionRuleRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod attribution_rule_entity;
+pub mod info_attribution_rule_fs_adapter;
+pub mod info_attribution_rule_api_repository_trait;
+pub mod info_attribution_rule_repository;
+pub mod team_precedence;
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
+use crate::core::persistence::info::k8s::namespace::info_namespace_api_repository_trait::InfoNamespaceApiRepository;
+use crate::core::persistence::info::k8s::namespace::info_namespace_repository::InfoNamespaceRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+
+/// Which team a pod's cost goes to when its own team (patch or attribution
+/// rule) and its namespace's team disagree (the `team_precedence` setting).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TeamPrecedence {
+    /// The pod's team wins; the namespace team only fills pods without one (`pod`, default)
+    #[default]
+    Pod,
+    /// The namespace team wins over the pod's own team (`namespace`)
+    Namespace,
+}
+
+impl TeamPrecedence {
+    pub fn parse(v: &str) -> Option<Self> {
+        match v.trim().to_lowercase().as_str() {
+            "" | "pod" => Some(Self::Pod),
+            "namespace" => Some(Self::Namespace),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pod => "pod",
+            Self::Namespace => "namespace",
+        }
+    }
+
+    /// From the stored settings; unset or unparseable values fall back to `pod`.
+    pub fn configured() -> Self {
+        InfoSettingFsAdapter::new()
+            .read()
+            .ok()
+            .and_then(|s| Self::parse(&s.team_precedence))
+            .unwrap_or_default()
+    }
+
+    /// Team the pod's cost is attributed to.
+    pub fn effective_team(&self, pod_team: Option<&str>, namespace_team: Option<&str>) -> Option<String> {
+        let (pod_team, namespace_team) = (non_empty(pod_team), non_empty(namespace_team));
+        match self {
+            Self::Pod => pod_team.or(namespace_team),
+            Self::Namespace => namespace_team.or(pod_team),
+        }
+        .map(str::to_string)
+    }
+}
+
+/// Both teams are set and name different teams (case and spacing ignored).
+pub fn teams_conflict(pod_team: Option<&str>, namespace_team: Option<&str>) -> bool {
+    match (non_empty(pod_team), non_empty(namespace_team)) {
+        (Some(p), Some(n)) => !p.eq_ignore_ascii_case(n),
+        _ => false,
+    }
+}
+
+fn non_empty(v: Option<&str>) -> Option<&str> {
+    v.map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// Namespace teams with the configured precedence, for resolving pod teams.
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceTeams {
+    pub precedence: TeamPrecedence,
+    teams: HashMap<String, String>,
+}
+
+impl NamespaceTeams {
+    pub fn new(precedence: TeamPrecedence, teams: HashMap<String, String>) -> Self {
+        Self { precedence, teams }
+    }
+
+    /// Every stored namespace, deleted ones included (their pods still carry cost).
+    pub fn load() -> Self {
+        let teams = InfoNamespaceRepository::new()
+            .list()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|ns| Some((ns.name?, ns.team?)))
+            .collect();
+        Self::new(TeamPrecedence::configured(), teams)
+    }
+
+    /// Only the given namespace, for resolving a single pod.
+    pub fn load_one(namespace: &str) -> Self {
+        let teams = InfoNamespaceRepository::new()
+            .read(namespace)
+            .ok()
+            .and_then(|ns| ns.team)
+            .map(|team| HashMap::from([(namespace.to_string(), team)]))
+            .unwrap_or_default();
+        Self::new(TeamPrecedence::configured(), teams)
+    }
+
+    pub fn team_of(&self, namespace: Option<&str>) -> Option<&str> {
+        namespace.and_then(|ns| self.teams.get(ns)).map(String::as_str)
+    }
+
+    /// Replaces `pod.team` with the team its cost is attributed to.
+    pub fn resolve(&self, pod: &mut InfoPodEntity) {
+        let namespace_team = self.team_of(pod.namespace.as_deref());
+        pod.team = self.precedence.effective_team(pod.team.as_deref(), namespace_team);
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod(namespace: &str, team: Option<&str>) -> InfoPodEntity {
+        InfoPodEntity {
+            namespace: Some(namespace.into()),
+            team: team.map(Into::into),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn precedence_decides_disagreements_and_namespace_fills_gaps() {
+        let teams = HashMap::from([("payments".to_string(), "payments".to_string())]);
+
+        let by_pod = NamespaceTeams::new(TeamPrecedence::Pod, teams.clone());
+        let by_namespace = NamespaceTeams::new(TeamPrecedence::Namespace, teams);
+
+        for (resolver, expected) in [(&by_pod, "checkout"), (&by_namespace, "payments")] {
+            let mut p = pod("payments", Some("checkout"));
+            resolver.resolve(&mut p);
+            assert_eq!(p.team.as_deref(), Some(expected));
+
+            let mut unset = pod("payments", Some(" "));
+            resolver.resolve(&mut unset);
+            assert_eq!(unset.team.as_deref(), Some("payments"));
+
+            let mut elsewhere = pod("search", Some("search"));
+            resolver.resolve(&mut elsewhere);
+            assert_eq!(elsewhere.team.as_deref(), Some("search"));
+        }
+    }
+
+    #[test]
+    fn only_two_different_teams_conflict() {
+        assert!(teams_conflict(Some("checkout"), Some("payments")));
+        assert!(!teams_conflict(Some("Payments "), Some("payments")));
+        assert!(!teams_conflict(None, Some("payments")));
+        assert!(!teams_conflict(Some("checkout"), Some("")));
+        assert_eq!(TeamPrecedence::parse("Namespace"), Some(TeamPrecedence::Namespace));
+        assert_eq!(TeamPrecedence::parse("owner"), None);
+    }
+}
@@ -0,0 +1,15 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use super::info_carbon_entity::InfoCarbonEntity;
+
+/// API-facing repository abstraction for energy and carbon settings.
+pub trait InfoCarbonApiRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoCarbonEntity>;
+
+    fn read(&self) -> anyhow::Result<InfoCarbonEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, carbon: &InfoCarbonEntity) -> anyhow::Result<()> {
+        self.fs_adapter().update(carbon)
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::info::dto::info_carbon_upsert_request::InfoCarbonUpsertRequest;
+
+/// Power draw of one node type. Defaults are the averages published by the
+/// Cloud Carbon Footprint methodology.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct PowerProfileEntity {
+    /// Watts per vCPU while idle
+    pub cpu_idle_watts: f64,
+    /// Watts per vCPU at full load
+    pub cpu_max_watts: f64,
+    /// Watts per GB of memory
+    pub memory_watts_per_gb: f64,
+}
+
+impl Default for PowerProfileEntity {
+    fn default() -> Self {
+        Self {
+            cpu_idle_watts: 0.74,
+            cpu_max_watts: 3.5,
+            memory_watts_per_gb: 0.392,
+        }
+    }
+}
+
+/// Energy and carbon conversion settings at `info/carbon.json`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InfoCarbonEntity {
+    /// Grid carbon intensity in grams CO2e per kWh
+    pub grid_intensity_g_per_kwh: f64,
+    /// Power usage effectiveness of the data center (facility / IT energy)
+    pub pue: f64,
+    /// Profile for nodes whose instance type has no entry in `profiles`
+    pub default_profile: PowerProfileEntity,
+    /// Profiles keyed by the `node.kubernetes.io/instance-type` label
+    #[serde(default)]
+    pub profiles: BTreeMap<String, PowerProfileEntity>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for InfoCarbonEntity {
+    fn default() -> Self {
+        Self {
+            grid_intensity_g_per_kwh: 400.0,
+            pue: 1.135,
+            default_profile: PowerProfileEntity::default(),
+            profiles: BTreeMap::new(),
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+impl InfoCarbonEntity {
+    pub fn profile_for(&self, instance_type: Option<&str>) -> &PowerProfileEntity {
+        instance_type
+            .and_then(|t| self.profiles.get(t))
+            .unwrap_or(&self.default_profile)
+    }
+
+    pub fn apply_update(&mut self, req: InfoCarbonUpsertRequest) {
+        if let Some(v) = req.grid_intensity_g_per_kwh { self.grid_intensity_g_per_kwh = v; }
+        if let Some(v) = req.pue { self.pue = v; }
+        if let Some(v) = req.default_profile { self.default_profile = v; }
+        if let Some(v) = req.profiles { self.profiles = v; }
+        self.updated_at = Utc::now();
+    }
+}
@@ -0,0 +1,60 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::path::info_carbon_path;
+
+use super::info_carbon_entity::InfoCarbonEntity;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// FS adapter for the energy and carbon settings, stored as JSON at `carbon.json`.
+/// A missing file reads as the defaults.
+pub struct InfoCarbonFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoCarbonEntity> for InfoCarbonFsAdapter {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn read(&self) -> Result<InfoCarbonEntity> {
+        blocking_io(|| {
+            let path = info_carbon_path();
+            if !path.exists() {
+                return Ok(InfoCarbonEntity::default());
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read carbon settings file")?;
+            serde_json::from_str(&raw).context("Failed to parse carbon settings file")
+        })
+    }
+
+    fn insert(&self, data: &InfoCarbonEntity) -> Result<()> {
+        self.update(data)
+    }
+
+    fn update(&self, data: &InfoCarbonEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = info_carbon_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create info directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string_pretty(data)?)
+                .context("Failed to write carbon settings file")?;
+            fs::rename(&tmp, &path).context("Failed to replace carbon settings file")?;
+            Ok(())
+        })
+    }
+
+    fn delete(&self) -> Result<()> {
+        blocking_io(|| {
+            let path = info_carbon_path();
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete carbon settings file")?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+
+use super::info_carbon_api_repository_trait::InfoCarbonApiRepository;
+use super::info_carbon_entity::InfoCarbonEntity;
+use super::info_carbon_fs_adapter::InfoCarbonFsAdapter;
+
+pub struct InfoCarbonRepository {
+    adapter: InfoCarbonFsAdapter,
+}
+
+impl InfoCarbonRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoCarbonFsAdapter::new(),
+        }
+    }
+}
+
+impl InfoCarbonApiRepository for InfoCarbonRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoCarbonEntity> {
+        &self.adapter
+    }
+}
+
+impl Default for InfoCarbonRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
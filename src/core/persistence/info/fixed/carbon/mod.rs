@@ -0,0 +1,4 @@
+pub mod info_carbon_entity;
+pub mod info_carbon_fs_adapter;
+pub mod info_carbon_api_repository_trait;
+pub mod info_carbon_repository;
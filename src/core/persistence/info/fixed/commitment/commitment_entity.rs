@@ -0,0 +1,119 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum CommitmentKind {
+    SavingsPlan,
+    ReservedInstance,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum CommitmentTerm {
+    OneYear,
+    ThreeYear,
+}
+
+impl CommitmentTerm {
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::OneYear => Duration::days(365),
+            Self::ThreeYear => Duration::days(3 * 365),
+        }
+    }
+}
+
+/// A savings plan or reserved instance: a fixed hourly spend paid whether or not
+/// matching nodes run.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct CommitmentEntity {
+    pub id: String,
+    pub name: String,
+    pub kind: CommitmentKind,
+    /// Spend committed per hour, in USD
+    pub hourly_commitment_usd: f64,
+    pub term: CommitmentTerm,
+    pub starts_at: DateTime<Utc>,
+    /// Instance families covered (`m5`, `n2`); empty covers every node
+    #[serde(default)]
+    pub instance_families: Vec<String>,
+    pub enabled: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CommitmentEntity {
+    pub fn ends_at(&self) -> DateTime<Utc> {
+        self.starts_at + self.term.duration()
+    }
+
+    /// Hours of `[start, end)` within the term.
+    pub fn active_hours_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        if !self.enabled {
+            return 0.0;
+        }
+        let from = self.starts_at.max(start);
+        let to = self.ends_at().min(end);
+        if to <= from {
+            return 0.0;
+        }
+        (to - from).num_seconds() as f64 / 3600.0
+    }
+
+    pub fn covers_family(&self, family: Option<&str>) -> bool {
+        self.instance_families.is_empty()
+            || family.is_some_and(|f| self.instance_families.iter().any(|c| c.eq_ignore_ascii_case(f)))
+    }
+}
+
+/// Stored commitments at `info/commitments.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct InfoCommitmentEntity {
+    pub items: Vec<CommitmentEntity>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl InfoCommitmentEntity {
+    pub fn find(&self, id: &str) -> Option<&CommitmentEntity> {
+        self.items.iter().find(|c| c.id == id)
+    }
+}
+
+/// Family of a cloud instance type: `m5.large` -> `m5`, `n2-standard-4` -> `n2`.
+pub fn instance_family(instance_type: &str) -> &str {
+    instance_type
+        .split_once('.')
+        .or_else(|| instance_type.split_once('-'))
+        .map_or(instance_type, |(family, _)| family)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn term_bounds_active_hours_and_families_match() {
+        let at = |y: i32, d: u32| Utc.with_ymd_and_hms(y, 3, d, 0, 0, 0).unwrap();
+        let sp = CommitmentEntity {
+            id: "cm".into(),
+            name: "compute SP".into(),
+            kind: CommitmentKind::SavingsPlan,
+            hourly_commitment_usd: 2.0,
+            term: CommitmentTerm::OneYear,
+            starts_at: at(2025, 2),
+            instance_families: vec!["M5".into()],
+            enabled: true,
+            updated_at: at(2025, 1),
+        };
+        assert_eq!(sp.active_hours_between(at(2025, 1), at(2025, 3)), 24.0);
+        assert_eq!(sp.active_hours_between(at(2026, 3), at(2026, 4)), 0.0);
+
+        assert_eq!(instance_family("m5.large"), "m5");
+        assert_eq!(instance_family("n2-standard-4"), "n2");
+        assert!(sp.covers_family(Some("m5")));
+        assert!(!sp.covers_family(Some("c5")));
+        assert!(!sp.covers_family(None));
+    }
+}
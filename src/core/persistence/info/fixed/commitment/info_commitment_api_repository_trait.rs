@@ -0,0 +1,15 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use super::commitment_entity::InfoCommitmentEntity;
+
+/// API-facing repository abstraction for savings plans and reserved instances.
+pub trait InfoCommitmentApiRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoCommitmentEntity>;
+
+    fn read(&self) -> anyhow::Result<InfoCommitmentEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, commitments: &InfoCommitmentEntity) -> anyhow::Result<()> {
+        self.fs_adapter().update(commitments)
+    }
+}
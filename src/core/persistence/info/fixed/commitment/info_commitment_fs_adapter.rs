@@ -0,0 +1,60 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::path::info_commitment_path;
+
+use super::commitment_entity::InfoCommitmentEntity;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// FS adapter for commitments, stored as JSON at `commitments.json`.
+/// A missing file is an empty list.
+pub struct InfoCommitmentFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoCommitmentEntity> for InfoCommitmentFsAdapter {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn read(&self) -> Result<InfoCommitmentEntity> {
+        blocking_io(|| {
+            let path = info_commitment_path();
+            if !path.exists() {
+                return Ok(InfoCommitmentEntity::default());
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read commitments file")?;
+            serde_json::from_str(&raw).context("Failed to parse commitments file")
+        })
+    }
+
+    fn insert(&self, data: &InfoCommitmentEntity) -> Result<()> {
+        self.update(data)
+    }
+
+    fn update(&self, data: &InfoCommitmentEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = info_commitment_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create info directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string_pretty(data)?)
+                .context("Failed to write commitments file")?;
+            fs::rename(&tmp, &path).context("Failed to replace commitments file")?;
+            Ok(())
+        })
+    }
+
+    fn delete(&self) -> Result<()> {
+        blocking_io(|| {
+            let path = info_commitment_path();
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete commitments file")?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+
+use super::commitment_entity::InfoCommitmentEntity;
+use super::info_commitment_api_repository_trait::InfoCommitmentApiRepository;
+use super::info_commitment_fs_adapter::InfoCommitmentFsAdapter;
+
+pub struct InfoCommitmentRepository {
+    adapter: InfoCommitmentFsAdapter,
+}
+
+impl InfoCommitmentRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoCommitmentFsAdapter::new(),
+        }
+    }
+}
+
+impl InfoCommitmentApiRepository for InfoCommitmentRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoCommitmentEntity> {
+        &self.adapter
+    }
+}
+
+impl Default for InfoCommitmentRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,4 @@
+pub mod commitment_entity;
+pub mod info_commitment_fs_adapter;
+pub mod info_commitment_api_repository_trait;
+pub mod info_commitment_repository;
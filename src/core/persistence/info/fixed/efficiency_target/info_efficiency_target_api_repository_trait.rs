@@ -0,0 +1,15 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use super::info_efficiency_target_entity::InfoEfficiencyTargetEntity;
+
+/// API-facing repository abstraction for team / namespace efficiency targets.
+pub trait InfoEfficiencyTargetApiRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoEfficiencyTargetEntity>;
+
+    fn read(&self) -> anyhow::Result<InfoEfficiencyTargetEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, targets: &InfoEfficiencyTargetEntity) -> anyhow::Result<()> {
+        self.fs_adapter().update(targets)
+    }
+}
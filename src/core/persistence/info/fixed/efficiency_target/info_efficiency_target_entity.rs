@@ -0,0 +1,48 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::info::dto::info_efficiency_target_upsert_request::InfoEfficiencyTargetUpsertRequest;
+
+/// Efficiency each team / namespace is expected to reach, at
+/// `info/efficiency_targets.json`. Percentages of requested resources used.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InfoEfficiencyTargetEntity {
+    /// Target for groups without their own entry
+    pub default_target_percent: f64,
+    #[serde(default)]
+    pub teams: BTreeMap<String, f64>,
+    #[serde(default)]
+    pub namespaces: BTreeMap<String, f64>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for InfoEfficiencyTargetEntity {
+    fn default() -> Self {
+        Self {
+            default_target_percent: 60.0,
+            teams: BTreeMap::new(),
+            namespaces: BTreeMap::new(),
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+impl InfoEfficiencyTargetEntity {
+    pub fn team_target(&self, team: &str) -> f64 {
+        self.teams.get(team).copied().unwrap_or(self.default_target_percent)
+    }
+
+    pub fn namespace_target(&self, namespace: &str) -> f64 {
+        self.namespaces.get(namespace).copied().unwrap_or(self.default_target_percent)
+    }
+
+    pub fn apply_update(&mut self, req: InfoEfficiencyTargetUpsertRequest) {
+        if let Some(v) = req.default_target_percent { self.default_target_percent = v; }
+        if let Some(v) = req.teams { self.teams = v; }
+        if let Some(v) = req.namespaces { self.namespaces = v; }
+        self.updated_at = Utc::now();
+    }
+}
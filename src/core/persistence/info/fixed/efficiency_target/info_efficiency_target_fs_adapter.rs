@@ -0,0 +1,60 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::path::info_efficiency_target_path;
+
+use super::info_efficiency_target_entity::InfoEfficiencyTargetEntity;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// FS adapter for efficiency targets, stored as JSON at `efficiency_targets.json`.
+/// A missing file reads as the defaults.
+pub struct InfoEfficiencyTargetFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoEfficiencyTargetEntity> for InfoEfficiencyTargetFsAdapter {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn read(&self) -> Result<InfoEfficiencyTargetEntity> {
+        blocking_io(|| {
+            let path = info_efficiency_target_path();
+            if !path.exists() {
+                return Ok(InfoEfficiencyTargetEntity::default());
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read efficiency targets file")?;
+            serde_json::from_str(&raw).context("Failed to parse efficiency targets file")
+        })
+    }
+
+    fn insert(&self, data: &InfoEfficiencyTargetEntity) -> Result<()> {
+        self.update(data)
+    }
+
+    fn update(&self, data: &InfoEfficiencyTargetEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = info_efficiency_target_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create info directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string_pretty(data)?)
+                .context("Failed to write efficiency targets file")?;
+            fs::rename(&tmp, &path).context("Failed to replace efficiency targets file")?;
+            Ok(())
+        })
+    }
+
+    fn delete(&self) -> Result<()> {
+        blocking_io(|| {
+            let path = info_efficiency_target_path();
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete efficiency targets file")?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+
+use super::info_efficiency_target_api_repository_trait::InfoEfficiencyTargetApiRepository;
+use super::info_efficiency_target_entity::InfoEfficiencyTargetEntity;
+use super::info_efficiency_target_fs_adapter::InfoEfficiencyTargetFsAdapter;
+
+pub struct InfoEfficiencyTargetRepository {
+    adapter: InfoEfficiencyTargetFsAdapter,
+}
+
+impl InfoEfficiencyTargetRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoEfficiencyTargetFsAdapter::new(),
+        }
+    }
+}
+
+impl InfoEfficiencyTargetApiRepository for InfoEfficiencyTargetRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoEfficiencyTargetEntity> {
+        &self.adapter
+    }
+}
+
+impl Default for InfoEfficiencyTargetRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,4 @@
+pub mod info_efficiency_target_entity;
+pub mod info_efficiency_target_fs_adapter;
+pub mod info_efficiency_target_api_repository_trait;
+pub mod info_efficiency_target_repository;
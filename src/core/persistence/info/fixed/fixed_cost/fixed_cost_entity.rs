@@ -0,0 +1,135 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Billing period of a fixed cost's `amount_usd`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum FixedCostPeriod {
+    Hour,
+    Day,
+    /// 730 hours, the average month cloud providers bill hourly fees on
+    Month,
+    Year,
+}
+
+impl FixedCostPeriod {
+    pub fn hours(&self) -> f64 {
+        match self {
+            Self::Hour => 1.0,
+            Self::Day => 24.0,
+            Self::Month => 730.0,
+            Self::Year => 8760.0,
+        }
+    }
+}
+
+/// How namespace cost summaries share a fixed cost. Cluster summaries always
+/// carry the full amount.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum FixedCostAllocation {
+    /// Not allocated; cluster summaries only.
+    #[default]
+    Cluster,
+    /// Across all namespaces in proportion to their pod cost in the window.
+    Proportional,
+    /// Evenly across the listed `namespaces`.
+    Namespaces,
+}
+
+/// A recurring cost outside node pricing, such as a managed control plane fee,
+/// a support contract or a monitoring subscription.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct FixedCostEntity {
+    pub id: String,
+    pub name: String,
+    /// Free-form grouping, e.g. `control_plane`, `support`
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Amount billed per `period`
+    pub amount_usd: f64,
+    pub period: FixedCostPeriod,
+    /// Accrues from this time; `None` for always.
+    #[serde(default)]
+    pub starts_at: Option<DateTime<Utc>>,
+    /// Stops accruing at this time; `None` while still billed.
+    #[serde(default)]
+    pub ends_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub allocation: FixedCostAllocation,
+    /// Namespaces sharing the cost under [`FixedCostAllocation::Namespaces`]
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+    pub enabled: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl FixedCostEntity {
+    pub fn hourly_usd(&self) -> f64 {
+        self.amount_usd / self.period.hours()
+    }
+
+    /// Amortized cost over the part of `[start, end)` the item was billed in.
+    pub fn cost_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        if !self.enabled {
+            return 0.0;
+        }
+        let from = self.starts_at.map_or(start, |s| s.max(start));
+        let to = self.ends_at.map_or(end, |e| e.min(end));
+        if to <= from {
+            return 0.0;
+        }
+        (to - from).num_seconds() as f64 / 3600.0 * self.hourly_usd()
+    }
+}
+
+/// Stored line items at `info/fixed_costs.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct InfoFixedCostEntity {
+    pub items: Vec<FixedCostEntity>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl InfoFixedCostEntity {
+    pub fn find(&self, id: &str) -> Option<&FixedCostEntity> {
+        self.items.iter().find(|c| c.id == id)
+    }
+
+    /// Sum of every item over the window, whatever its allocation.
+    pub fn total_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        self.items.iter().map(|c| c.cost_between(start, end)).sum()
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn cost_is_amortized_over_the_billed_part_of_the_window() {
+        let at = |d: u32| Utc.with_ymd_and_hms(2025, 3, d, 0, 0, 0).unwrap();
+        let mut fee = FixedCostEntity {
+            id: "fc".into(),
+            name: "EKS control plane".into(),
+            category: None,
+            amount_usd: 73.0,
+            period: FixedCostPeriod::Month,
+            starts_at: Some(at(2)),
+            ends_at: None,
+            allocation: FixedCostAllocation::Cluster,
+            namespaces: Vec::new(),
+            enabled: true,
+            updated_at: at(1),
+        };
+
+        assert!((fee.hourly_usd() - 0.1).abs() < 1e-9);
+        assert!((fee.cost_between(at(1), at(3)) - 2.4).abs() < 1e-9);
+        fee.ends_at = Some(at(2));
+        assert_eq!(fee.cost_between(at(1), at(3)), 0.0);
+        fee.ends_at = None;
+        fee.enabled = false;
+        assert_eq!(fee.cost_between(at(1), at(3)), 0.0);
+    }
+}
@@ -0,0 +1,15 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use super::fixed_cost_entity::InfoFixedCostEntity;
+
+/// API-facing repository abstraction for fixed cost line items.
+pub trait InfoFixedCostApiRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoFixedCostEntity>;
+
+    fn read(&self) -> anyhow::Result<InfoFixedCostEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, costs: &InfoFixedCostEntity) -> anyhow::Result<()> {
+        self.fs_adapter().update(costs)
+    }
+}
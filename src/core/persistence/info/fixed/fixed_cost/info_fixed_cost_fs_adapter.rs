@@ -0,0 +1,60 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::path::info_fixed_cost_path;
+
+use super::fixed_cost_entity::InfoFixedCostEntity;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// FS adapter for fixed cost line items, stored as JSON at `fixed_costs.json`.
+/// A missing file is an empty list.
+pub struct InfoFixedCostFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoFixedCostEntity> for InfoFixedCostFsAdapter {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn read(&self) -> Result<InfoFixedCostEntity> {
+        blocking_io(|| {
+            let path = info_fixed_cost_path();
+            if !path.exists() {
+                return Ok(InfoFixedCostEntity::default());
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read fixed costs file")?;
+            serde_json::from_str(&raw).context("Failed to parse fixed costs file")
+        })
+    }
+
+    fn insert(&self, data: &InfoFixedCostEntity) -> Result<()> {
+        self.update(data)
+    }
+
+    fn update(&self, data: &InfoFixedCostEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = info_fixed_cost_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create info directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string_pretty(data)?)
+                .context("Failed to write fixed costs file")?;
+            fs::rename(&tmp, &path).context("Failed to replace fixed costs file")?;
+            Ok(())
+        })
+    }
+
+    fn delete(&self) -> Result<()> {
+        blocking_io(|| {
+            let path = info_fixed_cost_path();
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete fixed costs file")?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+
+use super::fixed_cost_entity::InfoFixedCostEntity;
+use super::info_fixed_cost_api_repository_trait::InfoFixedCostApiRepository;
+use super::info_fixed_cost_fs_adapter::InfoFixedCostFsAdapter;
+
+pub struct InfoFixedCostRepository {
+    adapter: InfoFixedCostFsAdapter,
+}
+
+impl InfoFixedCostRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoFixedCostFsAdapter::new(),
+        }
+    }
+}
+
+impl InfoFixedCostApiRepository for InfoFixedCostRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoFixedCostEntity> {
+        &self.adapter
+    }
+}
+
+impl Default for InfoFixedCostRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
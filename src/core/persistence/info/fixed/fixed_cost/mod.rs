@@ -0,0 +1,4 @@
+pub mod fixed_cost_entity;
+pub mod info_fixed_cost_fs_adapter;
+pub mod info_fixed_cost_api_repository_trait;
+pub mod info_fixed_cost_repository;
@@ -11,6 +11,7 @@ use crate::core::persistence::storage_path::info_llm_path;
 
 use super::info_llm_entity::InfoLlmEntity;
 use super::llm_provider::LlmProvider;
+use crate::core::persistence::blocking_io::blocking_io;
 
 /// FS adapter for persisted LLM configuration.
 ///
@@ -23,67 +24,69 @@ impl InfoFixedFsAdapterTrait<InfoLlmEntity> for InfoLlmFsAdapter {
     }
 
     fn read(&self) -> Result<InfoLlmEntity> {
-        let path = info_llm_path();
-        if !path.exists() {
-            return Ok(InfoLlmEntity::default());
-        }
-
-        let file = File::open(&path).context("Failed to open llm file")?;
-        let reader = BufReader::new(file);
-        let mut s = InfoLlmEntity::default();
-
-        for line in reader.lines() {
-            let line = line?;
-            if let Some((key, val)) = line.split_once(':') {
-                let key = key.trim().to_uppercase();
-                let val = val.trim();
+        blocking_io(|| {
+            let path = info_llm_path();
+            if !path.exists() {
+                return Ok(InfoLlmEntity::default());
+            }
 
-                match key.as_str() {
-                    "PROVIDER" => {
-                        if let Some(p) = LlmProvider::from_code(val) {
-                            s.provider = p;
+            let file = File::open(&path).context("Failed to open llm file")?;
+            let reader = BufReader::new(file);
+            let mut s = InfoLlmEntity::default();
+
+            for line in reader.lines() {
+                let line = line?;
+                if let Some((key, val)) = line.split_once(':') {
+                    let key = key.trim().to_uppercase();
+                    let val = val.trim();
+
+                    match key.as_str() {
+                        "PROVIDER" => {
+                            if let Some(p) = LlmProvider::from_code(val) {
+                                s.provider = p;
+                            }
                         }
-                    }
-                    "BASE_URL" => s.base_url = if val.is_empty() { None } else { Some(val.to_string()) },
-                    "TOKEN" => s.token = if val.is_empty() { None } else { Some(val.to_string()) },
-                    "MODEL" => s.model = if val.is_empty() { None } else { Some(val.to_string()) },
-                    "MAX_OUTPUT_TOKENS" => {
-                        s.max_output_tokens = val.parse().ok();
-                    }
-                    "TEMPERATURE" => s.temperature = val.parse().ok(),
-                    "TOP_P" => s.top_p = val.parse().ok(),
-                    "TOP_K" => s.top_k = val.parse().ok(),
-                    "PRESENCE_PENALTY" => s.presence_penalty = val.parse().ok(),
-                    "FREQUENCY_PENALTY" => s.frequency_penalty = val.parse().ok(),
-                    "TIMEOUT_MS" => s.timeout_ms = val.parse().ok(),
-                    "STREAM" => s.stream = val.eq_ignore_ascii_case("true"),
-                    "STOP_SEQUENCES" => {
-                        let seq: Vec<String> = val
-                            .split(',')
-                            .map(|v| v.trim().to_string())
-                            .filter(|v| !v.is_empty())
-                            .collect();
-                        s.stop_sequences = if seq.is_empty() { None } else { Some(seq) };
-                    }
-                    "ORGANIZATION" => s.organization = if val.is_empty() { None } else { Some(val.to_string()) },
-                    "USER" => s.user = if val.is_empty() { None } else { Some(val.to_string()) },
-                    "CREATED_AT" => {
-                        if let Ok(dt) = val.parse::<DateTime<Utc>>() {
-                            s.created_at = dt;
+                        "BASE_URL" => s.base_url = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "TOKEN" => s.token = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "MODEL" => s.model = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "MAX_OUTPUT_TOKENS" => {
+                            s.max_output_tokens = val.parse().ok();
                         }
-                    }
-                    "UPDATED_AT" => {
-                        if let Ok(dt) = val.parse::<DateTime<Utc>>() {
-                            s.updated_at = dt;
+                        "TEMPERATURE" => s.temperature = val.parse().ok(),
+                        "TOP_P" => s.top_p = val.parse().ok(),
+                        "TOP_K" => s.top_k = val.parse().ok(),
+                        "PRESENCE_PENALTY" => s.presence_penalty = val.parse().ok(),
+                        "FREQUENCY_PENALTY" => s.frequency_penalty = val.parse().ok(),
+                        "TIMEOUT_MS" => s.timeout_ms = val.parse().ok(),
+                        "STREAM" => s.stream = val.eq_ignore_ascii_case("true"),
+                        "STOP_SEQUENCES" => {
+                            let seq: Vec<String> = val
+                                .split(',')
+                                .map(|v| v.trim().to_string())
+                                .filter(|v| !v.is_empty())
+                                .collect();
+                            s.stop_sequences = if seq.is_empty() { None } else { Some(seq) };
+                        }
+                        "ORGANIZATION" => s.organization = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "USER" => s.user = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "CREATED_AT" => {
+                            if let Ok(dt) = val.parse::<DateTime<Utc>>() {
+                                s.created_at = dt;
+                            }
                         }
+                        "UPDATED_AT" => {
+                            if let Ok(dt) = val.parse::<DateTime<Utc>>() {
+                                s.updated_at = dt;
+                            }
+                        }
+                        "VERSION" => s.version = val.to_string(),
+                        _ => {}
                     }
-                    "VERSION" => s.version = val.to_string(),
-                    _ => {}
                 }
             }
-        }
 
-        Ok(s)
+            Ok(s)
+        })
     }
 
     fn insert(&self, data: &InfoLlmEntity) -> Result<()> {
@@ -95,11 +98,13 @@ impl InfoFixedFsAdapterTrait<InfoLlmEntity> for InfoLlmFsAdapter {
     }
 
     fn delete(&self) -> Result<()> {
-        let path = info_llm_path();
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete llm file")?;
-        }
-        Ok(())
+        blocking_io(|| {
+            let path = info_llm_path();
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete llm file")?;
+            }
+            Ok(())
+        })
     }
 }
 
@@ -3,4 +3,10 @@ pub mod setting;
 pub mod info_fixed_fs_adapter_trait;
 pub mod unit_price;
 pub mod alerts;
+pub mod attribution;
 pub mod llm;
+pub mod fixed_cost;
+pub mod carbon;
+pub mod efficiency_target;
+pub mod commitment;
+pub mod virtual_node_price;
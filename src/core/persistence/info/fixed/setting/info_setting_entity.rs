@@ -20,14 +20,14 @@ pub struct InfoSettingEntity {
     /// Display language (e.g. `"en"`, `"ko"`).
     pub language: String,
 
-    /// Number of months to retain metric data before applying retention policy.
-    /// Minute data (files named YYYY-MM-DD)
+    /// Retention applied by the daily cleanup task; `0` keeps that granularity forever.
+    /// Minute data, in days (files named YYYY-MM-DD)
     pub minute_retention_days: u32,
 
-    /// Hour data (files named YYYY-MM)
+    /// Hour data, in calendar months (files named YYYY-MM)
     pub hour_retention_months: u32,
 
-    /// Day data (files named YYYY)
+    /// Day data, in calendar years (files named YYYY)
     pub day_retention_years: u32,
 
     /// Retention behavior: `"delete"` or `"archive"`.
@@ -46,13 +46,86 @@ pub struct InfoSettingEntity {
     /// Enables on-disk compression (gzip or zstd).
     pub compression_enabled: bool,
 
+    /// Metric storage backend: `"fs"` (one directory per object), `"sqlite"` or `"influx"`.
+    /// Read at startup; a change takes effect after restart.
+    pub storage_backend: String,
+
+    /// Base URL of the InfluxDB-compatible TSDB used by the `"influx"` backend.
+    pub remote_store_url: Option<String>,
+
+    /// Database (bucket) the `"influx"` backend writes to.
+    pub remote_store_database: String,
+
+    /// API token for the remote TSDB, sent as `Authorization: Token ...`.
+    pub remote_store_token: Option<String>,
+
     // ===== Metrics Collection =====
-    /// Scrape interval in seconds (e.g. 60 = every minute).
+    /// Scrape interval in seconds (e.g. 30, 60, 300).
+    /// Must divide an hour evenly so hour windows hold whole samples.
     pub scrape_interval_sec: u32,
 
+    /// Upper bound of the per-node delay (seconds) applied within each scrape,
+    /// spreading kubelet summary calls. `0` disables jitter; must stay below the interval.
+    pub scrape_jitter_sec: u32,
+
+    /// Maximum number of kubelet summaries fetched at the same time.
+    pub scrape_concurrency: u32,
+
+    /// Per-attempt timeout (seconds) for one node's kubelet summary call.
+    pub scrape_timeout_sec: u32,
+
+    /// Retries after a failed or timed-out summary call, with exponential backoff.
+    pub scrape_retries: u32,
+
+    /// Where usage is collected from: "kubelet" (default), "metrics-server" or
+    /// "prometheus", for clusters where the kubelet summary API is not reachable.
+    pub metrics_source: String,
+
+    /// Prometheus base URL queried when `metrics_source` is "prometheus".
+    pub metrics_source_url: Option<String>,
+
     /// Number of metrics batched together when written to disk.
     pub metrics_batch_size: u32,
 
+    // ===== Cost Attribution =====
+    /// Node label holding the cloud account (AWS account, Azure subscription, ...).
+    pub cloud_account_label: String,
+
+    /// Node label holding the cloud project (GCP project, resource group, ...).
+    pub cloud_project_label: String,
+
+    /// Account this cluster is billed to, used for nodes without the label.
+    pub cloud_account: Option<String>,
+
+    /// Which team wins when a pod's own team and its namespace's team disagree:
+    /// `"pod"` (the namespace team only fills pods without one) or `"namespace"`.
+    pub team_precedence: String,
+
+    // ===== Cost Export =====
+    /// Where the daily cost allocation export goes: `s3://bucket/prefix`,
+    /// `gs://bucket/prefix`, `http(s)://...` or `file:///dir`. `None` disables it.
+    pub export_sink_url: Option<String>,
+
+    /// File format of the export: `"parquet"`, `"csv"` or `"ndjson"`.
+    pub export_format: String,
+
+    /// Bearer token for GCS and HTTP sinks. GCS falls back to the metadata server.
+    pub export_token: Option<String>,
+
+    // ===== Network Flows =====
+    /// Prometheus HTTP API holding per-destination egress counters (e.g. scraped from
+    /// a flow exporter daemonset). `None` disables the flow breakdown.
+    pub network_flow_url: Option<String>,
+
+    /// PromQL override for the hourly flow query. Must return per-namespace byte
+    /// counts labelled `namespace`, `internet` and `same_zone`.
+    pub network_flow_query: Option<String>,
+
+    // ===== Efficiency =====
+    /// How `overall_efficiency` weighs CPU, memory and storage: `"cost"` (share of
+    /// capacity cost), `"equal"`, or fixed weights like `"cpu=2,memory=1,storage=0"`.
+    pub efficiency_weights: String,
+
     // ===== LLM Integration =====
     /// Endpoint for an external LLM API (e.g., OpenAI, Anthropic).
     pub llm_url: Option<String>,
@@ -104,11 +177,39 @@ impl Default for InfoSettingEntity {
             enable_index_file: true,
             max_storage_gb: 5,
             compression_enabled: true,
+            storage_backend: "fs".into(),
+            remote_store_url: None,
+            remote_store_database: "rustcost".into(),
+            remote_store_token: None,
 
             // --- Metrics ---
             scrape_interval_sec: 60,
+            scrape_jitter_sec: 0,
+            scrape_concurrency: 8,
+            scrape_timeout_sec: 10,
+            scrape_retries: 2,
+            metrics_source: "kubelet".into(),
+            metrics_source_url: None,
             metrics_batch_size: 500,
 
+            // --- Cost Attribution ---
+            cloud_account_label: "rustcost.io/cloud-account".into(),
+            cloud_project_label: "rustcost.io/cloud-project".into(),
+            cloud_account: None,
+            team_precedence: "pod".into(),
+
+            // --- Cost Export ---
+            export_sink_url: None,
+            export_format: "parquet".into(),
+            export_token: None,
+
+            // --- Network Flows ---
+            network_flow_url: None,
+            network_flow_query: None,
+
+            // --- Efficiency ---
+            efficiency_weights: "cost".into(),
+
             // --- LLM ---
             llm_url: None,
             llm_token: None,
@@ -185,15 +286,79 @@ impl InfoSettingEntity {
         if let Some(v) = req.compression_enabled {
             self.compression_enabled = v;
         }
+        if let Some(v) = req.storage_backend {
+            self.storage_backend = v.to_lowercase();
+        }
+        if let Some(v) = req.remote_store_database {
+            self.remote_store_database = v;
+        }
 
         // === Metrics ===
         if let Some(v) = req.scrape_interval_sec {
             self.scrape_interval_sec = v;
         }
+        if let Some(v) = req.scrape_jitter_sec {
+            self.scrape_jitter_sec = v;
+        }
+        if let Some(v) = req.scrape_concurrency {
+            self.scrape_concurrency = v;
+        }
+        if let Some(v) = req.scrape_timeout_sec {
+            self.scrape_timeout_sec = v;
+        }
+        if let Some(v) = req.scrape_retries {
+            self.scrape_retries = v;
+        }
+        if let Some(v) = req.metrics_source {
+            self.metrics_source = v.to_lowercase();
+        }
+        if let Some(v) = normalize_string_opt(req.metrics_source_url) {
+            self.metrics_source_url = v;
+        }
         if let Some(v) = req.metrics_batch_size {
             self.metrics_batch_size = v;
         }
 
+        // === Cost Attribution ===
+        if let Some(v) = req.cloud_account_label {
+            self.cloud_account_label = v;
+        }
+        if let Some(v) = req.cloud_project_label {
+            self.cloud_project_label = v;
+        }
+        if let Some(v) = normalize_string_opt(req.cloud_account) {
+            self.cloud_account = v;
+        }
+        if let Some(v) = req.team_precedence {
+            self.team_precedence = v.trim().to_lowercase();
+        }
+
+        // === Cost Export ===
+        if let Some(v) = normalize_string_opt(req.export_sink_url) {
+            self.export_sink_url = v;
+        }
+        if let Some(v) = req.export_format {
+            self.export_format = v.to_lowercase();
+        }
+        if let Some(v) = normalize_string_opt(req.export_token) {
+            self.export_token = v;
+        }
+
+        // === Network Flows ===
+        if let Some(v) = normalize_string_opt(req.network_flow_url) {
+            self.network_flow_url = v;
+        }
+        // One line in the settings file
+        let query = req.network_flow_query.map(|q| q.split_whitespace().collect::<Vec<_>>().join(" "));
+        if let Some(v) = normalize_string_opt(query) {
+            self.network_flow_query = v;
+        }
+
+        // === Efficiency ===
+        if let Some(v) = req.efficiency_weights {
+            self.efficiency_weights = v.trim().to_lowercase();
+        }
+
 
         // Optional URLs and tokens (normalize empty strings → None)
         if let Some(v) = normalize_string_opt(req.llm_url) {
@@ -208,6 +373,12 @@ impl InfoSettingEntity {
         if let Some(v) = normalize_string_opt(req.k8s_api_url) {
             self.k8s_api_url = v;
         }
+        if let Some(v) = normalize_string_opt(req.remote_store_url) {
+            self.remote_store_url = v;
+        }
+        if let Some(v) = normalize_string_opt(req.remote_store_token) {
+            self.remote_store_token = v;
+        }
 
         // === Runtime ===
         if let Some(v) = req.runtime_type {
@@ -237,6 +408,38 @@ impl InfoSettingEntity {
         // === Update timestamp ===
         self.updated_at = Utc::now();
     }
+
+    /// Scrape interval used by the collector; unusable stored values fall back to 60s.
+    pub fn effective_scrape_interval_sec(&self) -> u32 {
+        if is_valid_scrape_interval(self.scrape_interval_sec) {
+            self.scrape_interval_sec
+        } else {
+            60
+        }
+    }
+
+    /// Per-node jitter, kept strictly below the effective interval.
+    pub fn effective_scrape_jitter_sec(&self) -> u32 {
+        self.scrape_jitter_sec
+            .min(self.effective_scrape_interval_sec().saturating_sub(1))
+    }
+
+    /// Concurrent node scrapes; at least one.
+    pub fn effective_scrape_concurrency(&self) -> usize {
+        self.scrape_concurrency.max(1) as usize
+    }
+
+    /// Per-attempt node timeout, at least one second and never longer than the interval.
+    pub fn effective_scrape_timeout_sec(&self) -> u32 {
+        self.scrape_timeout_sec
+            .clamp(1, self.effective_scrape_interval_sec())
+    }
+}
+
+/// Intervals must divide an hour evenly so every hour window holds the same
+/// number of samples and minute→hour aggregation stays aligned.
+pub fn is_valid_scrape_interval(sec: u32) -> bool {
+    (10..=3600).contains(&sec) && 3600 % sec == 0
 }
 
 fn normalize_string_opt(v: Option<String>) -> Option<Option<String>> {
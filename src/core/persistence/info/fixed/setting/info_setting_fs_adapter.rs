@@ -7,6 +7,7 @@ use std::{
     io::{BufRead, BufReader},
 };
 use crate::core::persistence::storage_path::info_setting_path;
+use crate::core::persistence::blocking_io::blocking_io;
 
 /// File-based FS_ADAPTER implementation for the `Settings` entity.
 ///
@@ -27,96 +28,126 @@ impl InfoFixedFsAdapterTrait<InfoSettingEntity> for InfoSettingFsAdapter {
     /// Reads the settings file into memory.
     /// Returns default values if the file does not exist.
     fn read(&self) -> Result<InfoSettingEntity> {
-        let path = info_setting_path();
+        blocking_io(|| {
+            let path = info_setting_path();
 
-        if !path.exists() {
-            return Ok(InfoSettingEntity::default());
-        }
+            if !path.exists() {
+                return Ok(InfoSettingEntity::default());
+            }
+
+            let file = File::open(&path).context("Failed to open settings file")?;
+            let reader = BufReader::new(file);
+            let mut s = InfoSettingEntity::default();
+
+            for line in reader.lines() {
+                let line = line?;
+                if let Some((key, val)) = line.split_once(':') {
+                    let key = key.trim().to_uppercase();
+                    let val = val.trim();
+
+                    match key.as_str() {
+                        // === General & UI ===
+                        "IS_DARK_MODE" => s.is_dark_mode = val.eq_ignore_ascii_case("true"),
+                        "LANGUAGE" => s.language = val.to_string(),
+
+                        "MINUTE_RETENTION_DAY" => s.minute_retention_days = val.parse().unwrap_or(s.minute_retention_days),
+                        "HOUR_RETENTION_MONTH" => s.hour_retention_months = val.parse().unwrap_or(s.hour_retention_months),
+                        "DAY_RETENTION_YEAR" => s.day_retention_years = val.parse().unwrap_or(s.day_retention_years),                    "RETENTION_POLICY" => s.retention_policy = val.to_string(),
+
+                        // === TSDB Options ===
+                        "ENABLE_LINE_NUM_TRACKING" => s.enable_line_num_tracking = val.eq_ignore_ascii_case("true"),
+                        "ENABLE_INDEX_FILE" => s.enable_index_file = val.eq_ignore_ascii_case("true"),
+                        "MAX_STORAGE_GB" => s.max_storage_gb = val.parse().unwrap_or(s.max_storage_gb),
+                        "COMPRESSION_ENABLED" => s.compression_enabled = val.eq_ignore_ascii_case("true"),
+                        "STORAGE_BACKEND" => s.storage_backend = val.to_lowercase(),
+                        "REMOTE_STORE_URL" => s.remote_store_url = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "REMOTE_STORE_DATABASE" => s.remote_store_database = val.to_string(),
+                        "REMOTE_STORE_TOKEN" => s.remote_store_token = if val.is_empty() { None } else { Some(val.to_string()) },
+
+                        // === Metrics ===
+                        "SCRAPE_INTERVAL_SEC" => s.scrape_interval_sec = val.parse().unwrap_or(s.scrape_interval_sec),
+                        "SCRAPE_JITTER_SEC" => s.scrape_jitter_sec = val.parse().unwrap_or(s.scrape_jitter_sec),
+                        "SCRAPE_CONCURRENCY" => s.scrape_concurrency = val.parse().unwrap_or(s.scrape_concurrency),
+                        "SCRAPE_TIMEOUT_SEC" => s.scrape_timeout_sec = val.parse().unwrap_or(s.scrape_timeout_sec),
+                        "SCRAPE_RETRIES" => s.scrape_retries = val.parse().unwrap_or(s.scrape_retries),
+                        "METRICS_SOURCE" => s.metrics_source = val.to_lowercase(),
+                        "METRICS_SOURCE_URL" => s.metrics_source_url = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "METRICS_BATCH_SIZE" => s.metrics_batch_size = val.parse().unwrap_or(s.metrics_batch_size),
+
+                        // === Cost Attribution ===
+                        "CLOUD_ACCOUNT_LABEL" => s.cloud_account_label = val.to_string(),
+                        "CLOUD_PROJECT_LABEL" => s.cloud_project_label = val.to_string(),
+                        "CLOUD_ACCOUNT" => s.cloud_account = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "TEAM_PRECEDENCE" => s.team_precedence = val.to_lowercase(),
+
+                        // === Cost Export ===
+                        "EXPORT_SINK_URL" => s.export_sink_url = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "EXPORT_FORMAT" => s.export_format = val.to_lowercase(),
+                        "EXPORT_TOKEN" => s.export_token = if val.is_empty() { None } else { Some(val.to_string()) },
+
+                        // === Network Flows ===
+                        "NETWORK_FLOW_URL" => s.network_flow_url = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "NETWORK_FLOW_QUERY" => s.network_flow_query = if val.is_empty() { None } else { Some(val.to_string()) },
+
+                        // === Efficiency ===
+                        "EFFICIENCY_WEIGHTS" => s.efficiency_weights = val.to_lowercase(),
 
-        let file = File::open(&path).context("Failed to open settings file")?;
-        let reader = BufReader::new(file);
-        let mut s = InfoSettingEntity::default();
-
-        for line in reader.lines() {
-            let line = line?;
-            if let Some((key, val)) = line.split_once(':') {
-                let key = key.trim().to_uppercase();
-                let val = val.trim();
-
-                match key.as_str() {
-                    // === General & UI ===
-                    "IS_DARK_MODE" => s.is_dark_mode = val.eq_ignore_ascii_case("true"),
-                    "LANGUAGE" => s.language = val.to_string(),
-
-                    "MINUTE_RETENTION_DAY" => s.minute_retention_days = val.parse().unwrap_or(s.minute_retention_days),
-                    "HOUR_RETENTION_MONTH" => s.hour_retention_months = val.parse().unwrap_or(s.hour_retention_months),
-                    "DAY_RETENTION_YEAR" => s.day_retention_years = val.parse().unwrap_or(s.day_retention_years),                    "RETENTION_POLICY" => s.retention_policy = val.to_string(),
-
-                    // === TSDB Options ===
-                    "ENABLE_LINE_NUM_TRACKING" => s.enable_line_num_tracking = val.eq_ignore_ascii_case("true"),
-                    "ENABLE_INDEX_FILE" => s.enable_index_file = val.eq_ignore_ascii_case("true"),
-                    "MAX_STORAGE_GB" => s.max_storage_gb = val.parse().unwrap_or(s.max_storage_gb),
-                    "COMPRESSION_ENABLED" => s.compression_enabled = val.eq_ignore_ascii_case("true"),
-
-                    // === Metrics ===
-                    "SCRAPE_INTERVAL_SEC" => s.scrape_interval_sec = val.parse().unwrap_or(s.scrape_interval_sec),
-                    "METRICS_BATCH_SIZE" => s.metrics_batch_size = val.parse().unwrap_or(s.metrics_batch_size),
-
-                    // === LLM ===
-                    "LLM_URL" => s.llm_url = if val.is_empty() { None } else { Some(val.to_string()) },
-                    "LLM_TOKEN" => s.llm_token = if val.is_empty() { None } else { Some(val.to_string()) },
-                    "LLM_MODEL" => s.llm_model = if val.is_empty() { None } else { Some(val.to_string()) },
-
-                    // === Metadata ===
-                    "CREATED_AT" => {
-                        if let Ok(dt) = val.parse::<DateTime<Utc>>() {
-                            s.created_at = dt;
+                        // === LLM ===
+                        "LLM_URL" => s.llm_url = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "LLM_TOKEN" => s.llm_token = if val.is_empty() { None } else { Some(val.to_string()) },
+                        "LLM_MODEL" => s.llm_model = if val.is_empty() { None } else { Some(val.to_string()) },
+
+                        // === Metadata ===
+                        "CREATED_AT" => {
+                            if let Ok(dt) = val.parse::<DateTime<Utc>>() {
+                                s.created_at = dt;
+                            }
                         }
-                    }
-                    "UPDATED_AT" => {
-                        if let Ok(dt) = val.parse::<DateTime<Utc>>() {
-                            s.updated_at = dt;
+                        "UPDATED_AT" => {
+                            if let Ok(dt) = val.parse::<DateTime<Utc>>() {
+                                s.updated_at = dt;
+                            }
                         }
+                        "VERSION" => s.version = val.to_string(),
+
+                            "RUNTIME_TYPE" => s.runtime_type = match val.to_lowercase().as_str() {
+                            "docker" => RuntimeType::Docker,
+                            "containerd" => RuntimeType::Containerd,
+                            "baremetal" => RuntimeType::BareMetal,
+                            _ => RuntimeType::K8s,
+                            },
+                            "ENABLE_K8S_API" => s.enable_k8s_api = val == "true",
+                            "ENABLE_CONTAINER_EXPORTER" => s.enable_container_exporter = val == "true",
+                            "ENABLE_GPU_EXPORTER" => s.enable_gpu_exporter = val == "true",
+
+                            "GPU_EXPORTER_URLS" => {
+                            s.gpu_exporter_urls = val
+                            .split(',')
+                            .map(|v| v.trim().to_string())
+                            .filter(|v| !v.is_empty())
+                            .collect();
+                            }
+                            "CONTAINER_EXPORTER_URLS" => {
+                            s.container_exporter_urls = val
+                            .split(',')
+                            .map(|v| v.trim().to_string())
+                            .filter(|v| !v.is_empty())
+                            .collect();
+                            }
+                            "K8S_API_URL" => {
+                            s.k8s_api_url = if val.trim().is_empty() {
+                            None
+                            } else {
+                            Some(val.to_string())
+                            };
+                            }
+                        _ => {}
                     }
-                    "VERSION" => s.version = val.to_string(),
-
-                        "RUNTIME_TYPE" => s.runtime_type = match val.to_lowercase().as_str() {
-                        "docker" => RuntimeType::Docker,
-                        "containerd" => RuntimeType::Containerd,
-                        "baremetal" => RuntimeType::BareMetal,
-                        _ => RuntimeType::K8s,
-                        },
-                        "ENABLE_K8S_API" => s.enable_k8s_api = val == "true",
-                        "ENABLE_CONTAINER_EXPORTER" => s.enable_container_exporter = val == "true",
-                        "ENABLE_GPU_EXPORTER" => s.enable_gpu_exporter = val == "true",
-
-                        "GPU_EXPORTER_URLS" => {
-                        s.gpu_exporter_urls = val
-                        .split(',')
-                        .map(|v| v.trim().to_string())
-                        .filter(|v| !v.is_empty())
-                        .collect();
-                        }
-                        "CONTAINER_EXPORTER_URLS" => {
-                        s.container_exporter_urls = val
-                        .split(',')
-                        .map(|v| v.trim().to_string())
-                        .filter(|v| !v.is_empty())
-                        .collect();
-                        }
-                        "K8S_API_URL" => {
-                        s.k8s_api_url = if val.trim().is_empty() {
-                        None
-                        } else {
-                        Some(val.to_string())
-                        };
-                        }
-                    _ => {}
                 }
             }
-        }
 
-        Ok(s)
+            Ok(s)
+        })
     }
 
     fn insert(&self, data: &InfoSettingEntity) -> Result<()> {
@@ -128,12 +159,14 @@ impl InfoFixedFsAdapterTrait<InfoSettingEntity> for InfoSettingFsAdapter {
     }
 
     fn delete(&self) -> Result<()> {
-        let path = info_setting_path();
+        blocking_io(|| {
+            let path = info_setting_path();
 
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete settings file")?;
-        }
-        Ok(())
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete settings file")?;
+            }
+            Ok(())
+        })
     }
 }
 
@@ -166,8 +199,28 @@ impl InfoSettingFsAdapter {
         writeln!(f, "ENABLE_INDEX_FILE:{}", data.enable_index_file)?;
         writeln!(f, "MAX_STORAGE_GB:{}", data.max_storage_gb)?;
         writeln!(f, "COMPRESSION_ENABLED:{}", data.compression_enabled)?;
+        writeln!(f, "STORAGE_BACKEND:{}", data.storage_backend)?;
+        writeln!(f, "REMOTE_STORE_URL:{}", data.remote_store_url.clone().unwrap_or_default())?;
+        writeln!(f, "REMOTE_STORE_DATABASE:{}", data.remote_store_database)?;
+        writeln!(f, "REMOTE_STORE_TOKEN:{}", data.remote_store_token.clone().unwrap_or_default())?;
         writeln!(f, "SCRAPE_INTERVAL_SEC:{}", data.scrape_interval_sec)?;
+        writeln!(f, "SCRAPE_JITTER_SEC:{}", data.scrape_jitter_sec)?;
+        writeln!(f, "SCRAPE_CONCURRENCY:{}", data.scrape_concurrency)?;
+        writeln!(f, "SCRAPE_TIMEOUT_SEC:{}", data.scrape_timeout_sec)?;
+        writeln!(f, "SCRAPE_RETRIES:{}", data.scrape_retries)?;
+        writeln!(f, "METRICS_SOURCE:{}", data.metrics_source)?;
+        writeln!(f, "METRICS_SOURCE_URL:{}", data.metrics_source_url.clone().unwrap_or_default())?;
         writeln!(f, "METRICS_BATCH_SIZE:{}", data.metrics_batch_size)?;
+        writeln!(f, "CLOUD_ACCOUNT_LABEL:{}", data.cloud_account_label)?;
+        writeln!(f, "CLOUD_PROJECT_LABEL:{}", data.cloud_project_label)?;
+        writeln!(f, "CLOUD_ACCOUNT:{}", data.cloud_account.clone().unwrap_or_default())?;
+        writeln!(f, "TEAM_PRECEDENCE:{}", data.team_precedence)?;
+        writeln!(f, "EXPORT_SINK_URL:{}", data.export_sink_url.clone().unwrap_or_default())?;
+        writeln!(f, "EXPORT_FORMAT:{}", data.export_format)?;
+        writeln!(f, "EXPORT_TOKEN:{}", data.export_token.clone().unwrap_or_default())?;
+        writeln!(f, "NETWORK_FLOW_URL:{}", data.network_flow_url.clone().unwrap_or_default())?;
+        writeln!(f, "NETWORK_FLOW_QUERY:{}", data.network_flow_query.clone().unwrap_or_default())?;
+        writeln!(f, "EFFICIENCY_WEIGHTS:{}", data.efficiency_weights)?;
         writeln!(f, "LLM_URL:{}", data.llm_url.clone().unwrap_or_default())?;
         writeln!(f, "LLM_TOKEN:{}", data.llm_token.clone().unwrap_or_default())?;
         writeln!(f, "LLM_MODEL:{}", data.llm_model.clone().unwrap_or_default())?;
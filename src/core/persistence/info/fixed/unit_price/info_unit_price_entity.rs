@@ -1,5 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use std::sync::Arc;
+use super::info_unit_price_history_entity::InfoUnitPriceHistoryEntity;
 use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
 
 /// Represents per-unit pricing configuration for system resource usage.
@@ -49,14 +51,31 @@ pub struct InfoUnitPriceEntity {
     /// Price per GB transferred to external networks (internet egress)
     pub network_external_gb: f64,
 
+    // --- Load balancers ---
+    /// Price per hour of each `LoadBalancer` Service (the cloud LB it provisions)
+    pub load_balancer_hour: f64,
+
     // = always USD
     pub currency: Currency,
 
     /// Last update timestamp (UTC).
     pub updated_at: DateTime<Utc>,
+
+    /// Effective-dated versions, attached when loaded through the unit price
+    /// service so [`Self::at`] can price past points; never stored with the record.
+    #[serde(skip)]
+    pub history: Option<Arc<InfoUnitPriceHistoryEntity>>,
 }
 
 impl InfoUnitPriceEntity {
+    /// Prices in effect at `time`: the history version covering it, else these.
+    pub fn at(&self, time: DateTime<Utc>) -> &InfoUnitPriceEntity {
+        self.history
+            .as_deref()
+            .and_then(|h| h.price_at(time))
+            .unwrap_or(self)
+    }
+
     pub fn apply_update(&mut self, req: InfoUnitPriceUpsertRequest) {
         if let Some(v) = req.cpu_core_hour { self.cpu_core_hour = v; }
         if let Some(v) = req.cpu_spot_core_hour { self.cpu_spot_core_hour = v; }
@@ -68,6 +87,7 @@ impl InfoUnitPriceEntity {
         if let Some(v) = req.network_local_gb { self.network_local_gb = v; }
         if let Some(v) = req.network_regional_gb { self.network_regional_gb = v; }
         if let Some(v) = req.network_external_gb { self.network_external_gb = v; }
+        if let Some(v) = req.load_balancer_hour { self.load_balancer_hour = v; }
         self.updated_at = Utc::now();
     }
 }
@@ -86,8 +106,10 @@ impl Default for InfoUnitPriceEntity {
             network_local_gb: 0.01,
             network_regional_gb: 0.01,
             network_external_gb: 0.12,
+            load_balancer_hour: 0.0225,
             currency: Currency::USD,
             updated_at: now,
+            history: None,
         }
     }
 }
\ No newline at end of file
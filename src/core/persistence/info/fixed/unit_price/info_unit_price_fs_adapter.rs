@@ -7,6 +7,7 @@ use std::{
     fs::{self, File},
     io::{BufRead, BufReader},
 };
+use crate::core::persistence::blocking_io::blocking_io;
 
 /// File-based adapter for reading and writing [`InfoUnitPriceEntity`] data.
 ///
@@ -23,63 +24,68 @@ impl InfoFixedFsAdapterTrait<InfoUnitPriceEntity> for InfoUnitPriceFsAdapter {
     /// Reads the unit price configuration from disk.
     /// Returns default values if the file does not exist.
     fn read(&self) -> Result<InfoUnitPriceEntity> {
-        let path = info_unit_price_path();
+        blocking_io(|| {
+            let path = info_unit_price_path();
 
-        if !path.exists() {
-            return Ok(InfoUnitPriceEntity::default());
-        }
+            if !path.exists() {
+                return Ok(InfoUnitPriceEntity::default());
+            }
 
-        let file = File::open(&path).context("Failed to open unit price file")?;
-        let reader = BufReader::new(file);
-        let mut entity = InfoUnitPriceEntity::default();
-
-        for line in reader.lines() {
-            let line = line?;
-            if let Some((key, val)) = line.split_once(':') {
-                let key = key.trim().to_lowercase(); // normalize key
-                let val = val.trim();
-
-                match key.as_str() {
-                    // CPU
-                    "cpu_core_hour" => entity.cpu_core_hour = val.parse().unwrap_or_default(),
-                    "cpu_spot_core_hour" => entity.cpu_spot_core_hour = val.parse().unwrap_or_default(),
-
-                    // Memory
-                    "memory_gb_hour" => entity.memory_gb_hour = val.parse().unwrap_or_default(),
-                    "memory_spot_gb_hour" => entity.memory_spot_gb_hour = val.parse().unwrap_or_default(),
-
-                    // GPU
-                    "gpu_hour" => entity.gpu_hour = val.parse().unwrap_or_default(),
-                    "gpu_spot_hour" => entity.gpu_spot_hour = val.parse().unwrap_or_default(),
-
-                    // Storage
-                    "storage_gb_hour" => entity.storage_gb_hour = val.parse().unwrap_or_default(),
-
-                    // Network
-                    "network_local_gb" => entity.network_local_gb = val.parse().unwrap_or_default(),
-                    "network_regional_gb" => entity.network_regional_gb = val.parse().unwrap_or_default(),
-                    "network_external_gb" => entity.network_external_gb = val.parse().unwrap_or_default(),
-
-                    "currency" => {
-                        match val.to_uppercase().as_str() {
-                            "USD" => entity.currency = Currency::USD,
-                            _ => {} // ignore or fallback
+            let file = File::open(&path).context("Failed to open unit price file")?;
+            let reader = BufReader::new(file);
+            let mut entity = InfoUnitPriceEntity::default();
+
+            for line in reader.lines() {
+                let line = line?;
+                if let Some((key, val)) = line.split_once(':') {
+                    let key = key.trim().to_lowercase(); // normalize key
+                    let val = val.trim();
+
+                    match key.as_str() {
+                        // CPU
+                        "cpu_core_hour" => entity.cpu_core_hour = val.parse().unwrap_or_default(),
+                        "cpu_spot_core_hour" => entity.cpu_spot_core_hour = val.parse().unwrap_or_default(),
+
+                        // Memory
+                        "memory_gb_hour" => entity.memory_gb_hour = val.parse().unwrap_or_default(),
+                        "memory_spot_gb_hour" => entity.memory_spot_gb_hour = val.parse().unwrap_or_default(),
+
+                        // GPU
+                        "gpu_hour" => entity.gpu_hour = val.parse().unwrap_or_default(),
+                        "gpu_spot_hour" => entity.gpu_spot_hour = val.parse().unwrap_or_default(),
+
+                        // Storage
+                        "storage_gb_hour" => entity.storage_gb_hour = val.parse().unwrap_or_default(),
+
+                        // Network
+                        "network_local_gb" => entity.network_local_gb = val.parse().unwrap_or_default(),
+                        "network_regional_gb" => entity.network_regional_gb = val.parse().unwrap_or_default(),
+                        "network_external_gb" => entity.network_external_gb = val.parse().unwrap_or_default(),
+
+                        // Load balancers
+                        "load_balancer_hour" => entity.load_balancer_hour = val.parse().unwrap_or_default(),
+
+                        "currency" => {
+                            match val.to_uppercase().as_str() {
+                                "USD" => entity.currency = Currency::USD,
+                                _ => {} // ignore or fallback
+                            }
                         }
-                    }
 
-                    // Updated timestamp
-                    "updated_at" => {
-                        if let Ok(parsed) = DateTime::parse_from_rfc3339(val) {
-                            entity.updated_at = parsed.with_timezone(&Utc);
+                        // Updated timestamp
+                        "updated_at" => {
+                            if let Ok(parsed) = DateTime::parse_from_rfc3339(val) {
+                                entity.updated_at = parsed.with_timezone(&Utc);
+                            }
                         }
-                    }
 
-                    _ => {}
+                        _ => {}
+                    }
                 }
             }
-        }
 
-        Ok(entity)
+            Ok(entity)
+        })
     }
 
     fn insert(&self, data: &InfoUnitPriceEntity) -> Result<()> {
@@ -91,13 +97,15 @@ impl InfoFixedFsAdapterTrait<InfoUnitPriceEntity> for InfoUnitPriceFsAdapter {
     }
 
     fn delete(&self) -> Result<()> {
-        let path = info_unit_price_path();
+        blocking_io(|| {
+            let path = info_unit_price_path();
 
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete unit price file")?;
-        }
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete unit price file")?;
+            }
 
-        Ok(())
+            Ok(())
+        })
     }
 }
 
@@ -136,6 +144,7 @@ impl InfoUnitPriceFsAdapter {
         writeln!(f, "network_local_gb:{}", data.network_local_gb)?;
         writeln!(f, "network_regional_gb:{}", data.network_regional_gb)?;
         writeln!(f, "network_external_gb:{}", data.network_external_gb)?;
+        writeln!(f, "load_balancer_hour:{}", data.load_balancer_hour)?;
         writeln!(f, "currency:{:?}", data.currency)?;
         writeln!(f, "updated_at:{}", data.updated_at.to_rfc3339())?;
 
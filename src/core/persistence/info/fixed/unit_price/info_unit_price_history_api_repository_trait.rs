@@ -0,0 +1,16 @@
+use super::info_unit_price_history_entity::InfoUnitPriceHistoryEntity;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use anyhow::Result;
+
+/// API repository trait for effective-dated unit prices.
+pub trait InfoUnitPriceHistoryApiRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoUnitPriceHistoryEntity>;
+
+    fn read(&self) -> Result<InfoUnitPriceHistoryEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, data: &InfoUnitPriceHistoryEntity) -> Result<()> {
+        self.fs_adapter().update(data)
+    }
+}
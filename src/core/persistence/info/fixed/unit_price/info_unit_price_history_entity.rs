@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::info_unit_price_entity::InfoUnitPriceEntity;
+
+/// Unit prices in effect from `effective_from` until the next version starts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitPriceVersionEntity {
+    pub effective_from: DateTime<Utc>,
+    pub prices: InfoUnitPriceEntity,
+}
+
+/// Effective-dated unit prices at `info/unit_price_history.json`, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InfoUnitPriceHistoryEntity {
+    pub versions: Vec<UnitPriceVersionEntity>,
+}
+
+impl InfoUnitPriceHistoryEntity {
+    /// Prices in effect at `time`; `None` before the first version.
+    pub fn price_at(&self, time: DateTime<Utc>) -> Option<&InfoUnitPriceEntity> {
+        let idx = self.versions.partition_point(|v| v.effective_from <= time);
+        idx.checked_sub(1).map(|i| &self.versions[i].prices)
+    }
+
+    /// Adds a version, replacing one starting at the same time.
+    pub fn upsert(&mut self, version: UnitPriceVersionEntity) {
+        match self
+            .versions
+            .binary_search_by(|v| v.effective_from.cmp(&version.effective_from))
+        {
+            Ok(i) => self.versions[i] = version,
+            Err(i) => self.versions.insert(i, version),
+        }
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn picks_the_latest_version_started_by_the_given_time() {
+        let at = |d: u32| Utc.with_ymd_and_hms(2025, 3, d, 0, 0, 0).unwrap();
+        let priced = |cpu: f64| InfoUnitPriceEntity { cpu_core_hour: cpu, ..Default::default() };
+
+        let mut history = InfoUnitPriceHistoryEntity::default();
+        history.upsert(UnitPriceVersionEntity { effective_from: at(10), prices: priced(2.0) });
+        history.upsert(UnitPriceVersionEntity { effective_from: at(1), prices: priced(1.0) });
+
+        assert!(history.price_at(Utc.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap()).is_none());
+        assert_eq!(history.price_at(at(1)).unwrap().cpu_core_hour, 1.0);
+        assert_eq!(history.price_at(at(9)).unwrap().cpu_core_hour, 1.0);
+        assert_eq!(history.price_at(at(10)).unwrap().cpu_core_hour, 2.0);
+
+        history.upsert(UnitPriceVersionEntity { effective_from: at(10), prices: priced(3.0) });
+        assert_eq!(history.versions.len(), 2);
+        assert_eq!(history.price_at(at(20)).unwrap().cpu_core_hour, 3.0);
+    }
+}
@@ -0,0 +1,60 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::path::info_unit_price_history_path;
+
+use super::info_unit_price_history_entity::InfoUnitPriceHistoryEntity;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// FS adapter for effective-dated unit prices, stored as JSON at
+/// `unit_price_history.json`. A missing file reads as no history.
+pub struct InfoUnitPriceHistoryFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoUnitPriceHistoryEntity> for InfoUnitPriceHistoryFsAdapter {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn read(&self) -> Result<InfoUnitPriceHistoryEntity> {
+        blocking_io(|| {
+            let path = info_unit_price_history_path();
+            if !path.exists() {
+                return Ok(InfoUnitPriceHistoryEntity::default());
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read unit price history file")?;
+            serde_json::from_str(&raw).context("Failed to parse unit price history file")
+        })
+    }
+
+    fn insert(&self, data: &InfoUnitPriceHistoryEntity) -> Result<()> {
+        self.update(data)
+    }
+
+    fn update(&self, data: &InfoUnitPriceHistoryEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = info_unit_price_history_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create info directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string_pretty(data)?)
+                .context("Failed to write unit price history file")?;
+            fs::rename(&tmp, &path).context("Failed to replace unit price history file")?;
+            Ok(())
+        })
+    }
+
+    fn delete(&self) -> Result<()> {
+        blocking_io(|| {
+            let path = info_unit_price_history_path();
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete unit price history file")?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_history_api_repository_trait::InfoUnitPriceHistoryApiRepository;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_history_entity::InfoUnitPriceHistoryEntity;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_history_fs_adapter::InfoUnitPriceHistoryFsAdapter;
+
+pub struct InfoUnitPriceHistoryRepository {
+    adapter: InfoUnitPriceHistoryFsAdapter,
+}
+
+impl InfoUnitPriceHistoryRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoUnitPriceHistoryFsAdapter,
+        }
+    }
+}
+
+impl Default for InfoUnitPriceHistoryRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InfoUnitPriceHistoryApiRepository for InfoUnitPriceHistoryRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoUnitPriceHistoryEntity> {
+        &self.adapter
+    }
+}
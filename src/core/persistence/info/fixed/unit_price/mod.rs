@@ -3,3 +3,7 @@ pub mod info_unit_price_fs_adapter;
 pub mod info_unit_price_collector_repository_trait;
 pub mod info_unit_price_api_repository_trait;
 pub mod info_unit_price_repository;
+pub mod info_unit_price_history_entity;
+pub mod info_unit_price_history_fs_adapter;
+pub mod info_unit_price_history_api_repository_trait;
+pub mod info_unit_price_history_repository;
@@ -6,6 +6,7 @@ use std::{
     io::{BufRead, BufReader},
 };
 use crate::core::persistence::storage_path::{info_version_path};
+use crate::core::persistence::blocking_io::blocking_io;
 
 /// File-based FS_ADAPTER implementation for the `VersionInfo` entity.
 ///
@@ -25,38 +26,40 @@ impl InfoFixedFsAdapterTrait<InfoVersionEntity> for InfoVersionFsAdapter {
     /// Reads the version information file into memory.
     /// Returns default values if the file does not exist.
     fn read(&self) -> Result<InfoVersionEntity> {
-        let path = info_version_path();
+        blocking_io(|| {
+            let path = info_version_path();
 
-        if !path.exists() {
-            return Ok(InfoVersionEntity::default());
-        }
+            if !path.exists() {
+                return Ok(InfoVersionEntity::default());
+            }
 
-        let file = File::open(&path).context("Failed to open version file")?;
-        let reader = BufReader::new(file);
-        let mut v = InfoVersionEntity::default();
-
-        for line in reader.lines() {
-            let line = line?;
-            if let Some((key, val)) = line.split_once(':') {
-                let key = key.trim().to_uppercase();
-                let val = val.trim();
-
-                match key.as_str() {
-                    "DATE" => v.date = val.to_string(),
-                    "MAJOR" => v.major = val.to_string(),
-                    "MINOR" => v.minor = val.to_string(),
-                    "GIT_VERSION" => v.git_version = val.to_string(),
-                    "GIT_COMMIT" => v.git_commit = val.to_string(),
-                    "BUILD_DATE" => v.build_date = val.to_string(),
-                    "GO_VERSION" => v.go_version = val.to_string(),
-                    "COMPILER" => v.compiler = val.to_string(),
-                    "PLATFORM" => v.platform = val.to_string(),
-                    _ => {}
+            let file = File::open(&path).context("Failed to open version file")?;
+            let reader = BufReader::new(file);
+            let mut v = InfoVersionEntity::default();
+
+            for line in reader.lines() {
+                let line = line?;
+                if let Some((key, val)) = line.split_once(':') {
+                    let key = key.trim().to_uppercase();
+                    let val = val.trim();
+
+                    match key.as_str() {
+                        "DATE" => v.date = val.to_string(),
+                        "MAJOR" => v.major = val.to_string(),
+                        "MINOR" => v.minor = val.to_string(),
+                        "GIT_VERSION" => v.git_version = val.to_string(),
+                        "GIT_COMMIT" => v.git_commit = val.to_string(),
+                        "BUILD_DATE" => v.build_date = val.to_string(),
+                        "GO_VERSION" => v.go_version = val.to_string(),
+                        "COMPILER" => v.compiler = val.to_string(),
+                        "PLATFORM" => v.platform = val.to_string(),
+                        _ => {}
+                    }
                 }
             }
-        }
 
-        Ok(v)
+            Ok(v)
+        })
     }
 
     fn insert(&self, data: &InfoVersionEntity) -> Result<()> {
@@ -68,12 +71,14 @@ impl InfoFixedFsAdapterTrait<InfoVersionEntity> for InfoVersionFsAdapter {
     }
 
     fn delete(&self) -> Result<()> {
-        let path = info_version_path();
+        blocking_io(|| {
+            let path = info_version_path();
 
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete version file")?;
-        }
-        Ok(())
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete version file")?;
+            }
+            Ok(())
+        })
     }
 }
 
@@ -0,0 +1,15 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use super::info_virtual_node_price_entity::InfoVirtualNodePriceEntity;
+
+/// API-facing repository abstraction for virtual node (Fargate, ACI) rates.
+pub trait InfoVirtualNodePriceApiRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoVirtualNodePriceEntity>;
+
+    fn read(&self) -> anyhow::Result<InfoVirtualNodePriceEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, prices: &InfoVirtualNodePriceEntity) -> anyhow::Result<()> {
+        self.fs_adapter().update(prices)
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::info::dto::info_virtual_node_price_upsert_request::InfoVirtualNodePriceUpsertRequest;
+
+/// Request-based rates of one virtual node provider.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct VirtualNodeRate {
+    /// Price per requested vCPU-hour
+    pub vcpu_hour: f64,
+    /// Price per requested GB-hour of memory
+    pub memory_gb_hour: f64,
+}
+
+/// Rates for pods on virtual nodes (EKS Fargate, AKS virtual nodes / ACI), at
+/// `info/virtual_node_prices.json`. These nodes have no capacity of their own,
+/// so their pods are billed on requests instead of a share of the node.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InfoVirtualNodePriceEntity {
+    /// Rates for providers without their own entry
+    pub default_rate: VirtualNodeRate,
+    /// Rates keyed by provider (`fargate`, `aci`, ...)
+    #[serde(default)]
+    pub providers: BTreeMap<String, VirtualNodeRate>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for InfoVirtualNodePriceEntity {
+    fn default() -> Self {
+        // us-east-1 / eastus list prices
+        let fargate = VirtualNodeRate { vcpu_hour: 0.04048, memory_gb_hour: 0.004445 };
+        let aci = VirtualNodeRate { vcpu_hour: 0.0486, memory_gb_hour: 0.00533 };
+        Self {
+            default_rate: fargate,
+            providers: BTreeMap::from([("fargate".to_string(), fargate), ("aci".to_string(), aci)]),
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+impl InfoVirtualNodePriceEntity {
+    pub fn rate_for(&self, provider: &str) -> VirtualNodeRate {
+        self.providers.get(provider).copied().unwrap_or(self.default_rate)
+    }
+
+    pub fn apply_update(&mut self, req: InfoVirtualNodePriceUpsertRequest) {
+        if let Some(v) = req.default_rate { self.default_rate = v; }
+        if let Some(v) = req.providers { self.providers = v; }
+        self.updated_at = Utc::now();
+    }
+}
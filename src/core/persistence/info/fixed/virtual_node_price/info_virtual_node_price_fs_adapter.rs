@@ -0,0 +1,60 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::path::info_virtual_node_price_path;
+
+use super::info_virtual_node_price_entity::InfoVirtualNodePriceEntity;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// FS adapter for virtual node rates, stored as JSON at `virtual_node_prices.json`.
+/// A missing file reads as the defaults.
+pub struct InfoVirtualNodePriceFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoVirtualNodePriceEntity> for InfoVirtualNodePriceFsAdapter {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn read(&self) -> Result<InfoVirtualNodePriceEntity> {
+        blocking_io(|| {
+            let path = info_virtual_node_price_path();
+            if !path.exists() {
+                return Ok(InfoVirtualNodePriceEntity::default());
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read virtual node prices file")?;
+            serde_json::from_str(&raw).context("Failed to parse virtual node prices file")
+        })
+    }
+
+    fn insert(&self, data: &InfoVirtualNodePriceEntity) -> Result<()> {
+        self.update(data)
+    }
+
+    fn update(&self, data: &InfoVirtualNodePriceEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = info_virtual_node_price_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create info directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string_pretty(data)?)
+                .context("Failed to write virtual node prices file")?;
+            fs::rename(&tmp, &path).context("Failed to replace virtual node prices file")?;
+            Ok(())
+        })
+    }
+
+    fn delete(&self) -> Result<()> {
+        blocking_io(|| {
+            let path = info_virtual_node_price_path();
+            if path.exists() {
+                fs::remove_file(&path).context("Failed to delete virtual node prices file")?;
+            }
+            Ok(())
+        })
+    }
+}
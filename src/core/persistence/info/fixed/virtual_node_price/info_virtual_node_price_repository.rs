@@ -0,0 +1,29 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+
+use super::info_virtual_node_price_api_repository_trait::InfoVirtualNodePriceApiRepository;
+use super::info_virtual_node_price_entity::InfoVirtualNodePriceEntity;
+use super::info_virtual_node_price_fs_adapter::InfoVirtualNodePriceFsAdapter;
+
+pub struct InfoVirtualNodePriceRepository {
+    adapter: InfoVirtualNodePriceFsAdapter,
+}
+
+impl InfoVirtualNodePriceRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoVirtualNodePriceFsAdapter::new(),
+        }
+    }
+}
+
+impl InfoVirtualNodePriceApiRepository for InfoVirtualNodePriceRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoVirtualNodePriceEntity> {
+        &self.adapter
+    }
+}
+
+impl Default for InfoVirtualNodePriceRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
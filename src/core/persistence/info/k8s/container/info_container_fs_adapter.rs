@@ -7,6 +7,7 @@ use std::{
     path::Path,
 };
 use crate::core::persistence::info::path::{info_k8s_container_key_dir_path, info_k8s_container_file_path};
+use crate::core::persistence::blocking_io::blocking_io;
 
 /// File-based FS adapter for `InfoContainerEntity`.
 ///
@@ -17,106 +18,116 @@ pub struct InfoContainerFsAdapter;
 impl InfoDynamicFsAdapterTrait<InfoContainerEntity> for InfoContainerFsAdapter {
     /// Reads the container info file into memory.
     fn read(&self, container_key: &str) -> Result<InfoContainerEntity> {
-        let path = info_k8s_container_file_path(container_key);
-        if !Path::new(&path).exists() {
-            return Err(anyhow!("Missing container info file '{}'", path.display()));
-        }
+        blocking_io(|| {
+            let path = info_k8s_container_file_path(container_key);
+            if !Path::new(&path).exists() {
+                return Err(anyhow!("Missing container info file '{}'", path.display()));
+            }
 
-        let file = File::open(&path).context("Failed to open container info file")?;
-        let reader = BufReader::new(file);
-        let mut v = InfoContainerEntity::default();
-
-        for line in reader.lines() {
-            let line = line?;
-            if let Some((key, val)) = line.split_once(':') {
-                let key = key.trim().to_uppercase();
-                let val = val.trim().to_string();
-
-                match key.as_str() {
-                    // Identity
-                    "POD_UID" => v.pod_uid = Some(val),
-                    "POD_NAME" => v.pod_name = Some(val),
-                    "CONTAINER_NAME" => v.container_name = Some(val),
-                    "NAMESPACE" => v.namespace = Some(val),
-
-                    // Lifecycle
-                    "CREATION_TIMESTAMP" => v.creation_timestamp = val.parse().ok(),
-                    "START_TIME" => v.start_time = val.parse().ok(),
-                    "CONTAINER_ID" => v.container_id = Some(val),
-                    "IMAGE" => v.image = Some(val),
-                    "IMAGE_ID" => v.image_id = Some(val),
-
-                    // Status
-                    "STATE" => v.state = Some(val),
-                    "REASON" => v.reason = Some(val),
-                    "MESSAGE" => v.message = Some(val),
-                    "EXIT_CODE" => v.exit_code = val.parse().ok(),
-                    "RESTART_COUNT" => v.restart_count = val.parse().ok(),
-                    "READY" => v.ready = Some(val == "true"),
-
-                    // Node association
-                    "NODE_NAME" => v.node_name = Some(val),
-                    "HOST_IP" => v.host_ip = Some(val),
-                    "POD_IP" => v.pod_ip = Some(val),
-
-                    // Resources
-                    "CPU_REQUEST_MILLICORES" => v.cpu_request_millicores = val.parse().ok(),
-                    "MEMORY_REQUEST_BYTES" => v.memory_request_bytes = val.parse().ok(),
-                    "CPU_LIMIT_MILLICORES" => v.cpu_limit_millicores = val.parse().ok(),
-                    "MEMORY_LIMIT_BYTES" => v.memory_limit_bytes = val.parse().ok(),
-
-                    // Volumes
-                    "VOLUME_MOUNTS" => v.volume_mounts = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-                    "VOLUME_DEVICES" => v.volume_devices = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-
-                    // Metadata
-                    "LABELS" => v.labels = Some(val),
-                    "ANNOTATIONS" => v.annotations = Some(val),
-
-                    // Team / Service / Env
-                    "TEAM" => v.team = Some(val),
-                    "SERVICE" => v.service = Some(val),
-                    "ENV" => v.env = Some(val),
-
-                    // Bookkeeping
-                    "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = val.parse().ok(),
-                    "DELETED" => v.deleted = Some(val == "true"),
-                    "LAST_CHECK_DELETED_COUNT" => v.last_check_deleted_count = val.parse().ok(),
-
-                    _ => {}
+            let file = File::open(&path).context("Failed to open container info file")?;
+            let reader = BufReader::new(file);
+            let mut v = InfoContainerEntity::default();
+
+            for line in reader.lines() {
+                let line = line?;
+                if let Some((key, val)) = line.split_once(':') {
+                    let key = key.trim().to_uppercase();
+                    let val = val.trim().to_string();
+
+                    match key.as_str() {
+                        // Identity
+                        "POD_UID" => v.pod_uid = Some(val),
+                        "POD_NAME" => v.pod_name = Some(val),
+                        "CONTAINER_NAME" => v.container_name = Some(val),
+                        "NAMESPACE" => v.namespace = Some(val),
+
+                        // Lifecycle
+                        "CREATION_TIMESTAMP" => v.creation_timestamp = val.parse().ok(),
+                        "START_TIME" => v.start_time = val.parse().ok(),
+                        "CONTAINER_ID" => v.container_id = Some(val),
+                        "IMAGE" => v.image = Some(val),
+                        "IMAGE_ID" => v.image_id = Some(val),
+
+                        // Status
+                        "STATE" => v.state = Some(val),
+                        "REASON" => v.reason = Some(val),
+                        "MESSAGE" => v.message = Some(val),
+                        "EXIT_CODE" => v.exit_code = val.parse().ok(),
+                        "RESTART_COUNT" => v.restart_count = val.parse().ok(),
+                        "READY" => v.ready = Some(val == "true"),
+
+                        // Node association
+                        "NODE_NAME" => v.node_name = Some(val),
+                        "HOST_IP" => v.host_ip = Some(val),
+                        "POD_IP" => v.pod_ip = Some(val),
+
+                        // Resources
+                        "CPU_REQUEST_MILLICORES" => v.cpu_request_millicores = val.parse().ok(),
+                        "MEMORY_REQUEST_BYTES" => v.memory_request_bytes = val.parse().ok(),
+                        "CPU_LIMIT_MILLICORES" => v.cpu_limit_millicores = val.parse().ok(),
+                        "MEMORY_LIMIT_BYTES" => v.memory_limit_bytes = val.parse().ok(),
+
+                        // Volumes
+                        "VOLUME_MOUNTS" => v.volume_mounts = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+                        "VOLUME_DEVICES" => v.volume_devices = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+
+                        // Metadata
+                        "LABELS" => v.labels = Some(val),
+                        "ANNOTATIONS" => v.annotations = Some(val),
+
+                        // Team / Service / Env
+                        "TEAM" => v.team = Some(val),
+                        "SERVICE" => v.service = Some(val),
+                        "ENV" => v.env = Some(val),
+
+                        // Bookkeeping
+                        "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = val.parse().ok(),
+                        "DELETED" => v.deleted = Some(val == "true"),
+                        "LAST_CHECK_DELETED_COUNT" => v.last_check_deleted_count = val.parse().ok(),
+
+                        _ => {}
+                    }
                 }
             }
-        }
 
-        Ok(v)
+            Ok(v)
+        })
     }
 
     /// Inserts (creates) a container info file.
     fn insert(&self, data: &InfoContainerEntity) -> Result<()> {
-        let key = Self::container_key(data)?;
-        Self::create_container_dir_if_missing(&key)?;
-        self.write(&key, data)
+        blocking_io(|| {
+            let key = Self::container_key(data)?;
+            Self::create_container_dir_if_missing(&key)?;
+            self.write(&key, data)
+        })
     }
 
     /// Updates a container info file.
     fn update(&self, data: &InfoContainerEntity) -> Result<()> {
-        let container_key = Self::container_key(data)?;
-        Self::create_container_dir_if_missing(&container_key)?;
-        self.write(&container_key, data)
+        blocking_io(|| {
+            let container_key = Self::container_key(data)?;
+            Self::create_container_dir_if_missing(&container_key)?;
+            self.write(&container_key, data)
+        })
     }
 
     /// Deletes the container info file.
     fn delete(&self, key: &str) -> Result<()> {
-        let path = info_k8s_container_file_path(key);
-        if Path::new(&path).exists() {
-            fs::remove_file(&path).context("Failed to delete container info file")?;
-        }
-        Ok(())
+        blocking_io(|| {
+            let path = info_k8s_container_file_path(key);
+            if Path::new(&path).exists() {
+                fs::remove_file(&path).context("Failed to delete container info file")?;
+            }
+            Ok(())
+        })
     }
 
     fn exists(&self, container_key: &str) -> Result<bool> {
-        let path = info_k8s_container_file_path(container_key);
-        Ok(Path::new(&path).exists())
+        blocking_io(|| {
+            let path = info_k8s_container_file_path(container_key);
+            Ok(Path::new(&path).exists())
+        })
     }
 }
 
@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A HorizontalPodAutoscaler and the workload it scales, as of the last discovery.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InfoHpaEntity {
+    pub namespace: String,
+    pub name: String,
+    /// `spec.scaleTargetRef.kind`, e.g. `Deployment`
+    pub target_kind: String,
+    pub target_name: String,
+    pub min_replicas: Option<i32>,
+    pub max_replicas: i32,
+    /// Scales on CPU, by utilization or by value, pod-wide or per container
+    pub scales_on_cpu: bool,
+    /// Average CPU utilization target in percent, when CPU is targeted by utilization
+    pub cpu_target_utilization: Option<i32>,
+    /// Average memory utilization target in percent, when memory is targeted by utilization
+    pub memory_target_utilization: Option<i32>,
+    pub observed_at: DateTime<Utc>,
+}
+
+impl InfoHpaEntity {
+    /// Whether this HPA scales the `kind` workload `namespace/name`.
+    pub fn scales(&self, namespace: &str, kind: &str, name: &str) -> bool {
+        self.namespace == namespace && self.target_kind == kind && self.target_name == name
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_matches_namespace_kind_and_name() {
+        let hpa = InfoHpaEntity {
+            namespace: "shop".into(),
+            name: "web".into(),
+            target_kind: "Deployment".into(),
+            target_name: "web".into(),
+            min_replicas: Some(2),
+            max_replicas: 10,
+            scales_on_cpu: true,
+            cpu_target_utilization: Some(70),
+            memory_target_utilization: None,
+            observed_at: Utc::now(),
+        };
+
+        assert!(hpa.scales("shop", "Deployment", "web"));
+        assert!(!hpa.scales("shop", "StatefulSet", "web"));
+        assert!(!hpa.scales("infra", "Deployment", "web"));
+    }
+}
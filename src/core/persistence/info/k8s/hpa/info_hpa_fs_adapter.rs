@@ -0,0 +1,44 @@
+use anyhow::{Context, Result};
+use std::fs;
+
+use crate::core::persistence::info::k8s::hpa::info_hpa_entity::InfoHpaEntity;
+use crate::core::persistence::info::path::info_k8s_hpa_path;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// All HPAs in one JSON file, `$RUSTCOST_BASE_PATH/info/k8s/hpas.json`.
+/// Discovery replaces the list each cycle; only current autoscalers matter.
+#[derive(Default)]
+pub struct InfoHpaFsAdapter;
+
+impl InfoHpaFsAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Empty until discovery has run.
+    pub fn list(&self) -> Result<Vec<InfoHpaEntity>> {
+        blocking_io(|| {
+            let path = info_k8s_hpa_path();
+            if !path.exists() {
+                return Ok(Vec::new());
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read HPA file")?;
+            serde_json::from_str(&raw).context("Failed to parse HPA file")
+        })
+    }
+
+    pub fn write(&self, hpas: &[InfoHpaEntity]) -> Result<()> {
+        blocking_io(|| {
+            let path = info_k8s_hpa_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create info directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string(hpas)?).context("Failed to write HPA file")?;
+            fs::rename(&tmp, &path).context("Failed to replace HPA file")?;
+            Ok(())
+        })
+    }
+}
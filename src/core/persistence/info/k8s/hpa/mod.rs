@@ -0,0 +1,2 @@
+pub mod info_hpa_entity;
+pub mod info_hpa_fs_adapter;
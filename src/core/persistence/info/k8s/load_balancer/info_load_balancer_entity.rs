@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Service of type `LoadBalancer`, kept after deletion so past windows still
+/// carry its cost.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InfoLoadBalancerEntity {
+    pub uid: String,
+    pub namespace: String,
+    pub name: String,
+    /// Service `creationTimestamp`
+    pub created_at: Option<DateTime<Utc>>,
+    pub first_seen_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// First discovery that no longer found the Service
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// `spec.loadBalancerClass`, when set
+    pub class: Option<String>,
+    /// Provisioned hostname or IP from `status.loadBalancer.ingress`
+    pub ingress: Option<String>,
+}
+
+impl InfoLoadBalancerEntity {
+    /// Hours the load balancer existed within `[start, end)`.
+    pub fn active_hours(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        let from = self.created_at.unwrap_or(self.first_seen_at).max(start);
+        let to = self.deleted_at.map_or(end, |d| d.min(end));
+        if to <= from {
+            return 0.0;
+        }
+        (to - from).num_seconds() as f64 / 3600.0
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn active_hours_clip_to_window_and_lifetime() {
+        let at = |h: u32| Utc.with_ymd_and_hms(2025, 3, 1, h, 0, 0).unwrap();
+        let mut lb = InfoLoadBalancerEntity {
+            uid: "u".into(),
+            namespace: "shop".into(),
+            name: "web".into(),
+            created_at: Some(at(2)),
+            first_seen_at: at(3),
+            last_seen_at: at(8),
+            deleted_at: None,
+            class: None,
+            ingress: None,
+        };
+
+        assert_eq!(lb.active_hours(at(0), at(10)), 8.0);
+        lb.deleted_at = Some(at(5));
+        assert_eq!(lb.active_hours(at(0), at(10)), 3.0);
+        assert_eq!(lb.active_hours(at(6), at(10)), 0.0);
+    }
+}
@@ -0,0 +1,44 @@
+use anyhow::{Context, Result};
+use std::fs;
+
+use crate::core::persistence::info::k8s::load_balancer::info_load_balancer_entity::InfoLoadBalancerEntity;
+use crate::core::persistence::info::path::info_k8s_load_balancer_path;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// All load balancers in one JSON file, `$RUSTCOST_BASE_PATH/info/k8s/load_balancers.json`.
+/// Clusters have few of them, and discovery rewrites the list each cycle.
+#[derive(Default)]
+pub struct InfoLoadBalancerFsAdapter;
+
+impl InfoLoadBalancerFsAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Empty until discovery has run.
+    pub fn list(&self) -> Result<Vec<InfoLoadBalancerEntity>> {
+        blocking_io(|| {
+            let path = info_k8s_load_balancer_path();
+            if !path.exists() {
+                return Ok(Vec::new());
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read load balancer file")?;
+            serde_json::from_str(&raw).context("Failed to parse load balancer file")
+        })
+    }
+
+    pub fn write(&self, load_balancers: &[InfoLoadBalancerEntity]) -> Result<()> {
+        blocking_io(|| {
+            let path = info_k8s_load_balancer_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create info directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string(load_balancers)?).context("Failed to write load balancer file")?;
+            fs::rename(&tmp, &path).context("Failed to replace load balancer file")?;
+            Ok(())
+        })
+    }
+}
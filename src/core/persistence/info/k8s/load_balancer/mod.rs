@@ -0,0 +1,2 @@
+pub mod info_load_balancer_entity;
+pub mod info_load_balancer_fs_adapter;
@@ -3,4 +3,6 @@ pub mod node;
 pub mod pod;
 pub mod deployment;
 pub mod namespace;
+pub mod load_balancer;
+pub mod hpa;
 pub mod info_dynamic_fs_adapter_trait;
@@ -0,0 +1,25 @@
+use super::info_namespace_entity::InfoNamespaceEntity;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use anyhow::Result;
+
+/// API repository trait for namespaces.
+///
+/// Namespaces are written by discovery; the API reads them, patches local
+/// annotations and removes stale records.
+pub trait InfoNamespaceApiRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoNamespaceEntity>;
+
+    /// Reads namespace info for the given namespace name.
+    fn read(&self, name: &str) -> Result<InfoNamespaceEntity> {
+        self.fs_adapter().read(name)
+    }
+
+    /// Creates or replaces namespace info.
+    fn update(&self, data: &InfoNamespaceEntity) -> Result<()> {
+        self.fs_adapter().update(data)
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        self.fs_adapter().delete(name)
+    }
+}
@@ -1,8 +1,68 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// Stored information for a Kubernetes Namespace, including the hard limits of
+/// its ResourceQuotas (summed when there are several).
+///
+/// Stored at: `data/info/k8s/namespace/{name}/info.rci`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct InfoNamespaceEntity {
+    // --- Identity ---
     pub name: Option<String>,
     pub uid: Option<String>,
-    // TODO: Add fields needed for cost tracking
+
+    // --- Lifecycle ---
+    pub creation_timestamp: Option<DateTime<Utc>>,
+    pub resource_version: Option<String>,
+    pub phase: Option<String>,
+    pub last_updated_info_at: Option<DateTime<Utc>>,
+    pub deleted: Option<bool>,
+
+    // --- Metadata ---
+    pub label: Option<String>,        // flattened "key=value,..."
+    pub annotation: Option<String>,   // flattened "key=value,..."
+
+    // --- ResourceQuota hard limits ---
+    pub quota_names: Option<Vec<String>>,
+    pub quota_cpu_request_cores: Option<f64>,
+    pub quota_cpu_limit_cores: Option<f64>,
+    pub quota_memory_request_bytes: Option<u64>,
+    pub quota_memory_limit_bytes: Option<u64>,
+    pub quota_storage_request_bytes: Option<u64>,
+    pub quota_pods: Option<u64>,
+
+    pub team: Option<String>,
+    pub service: Option<String>,
+    pub env: Option<String>,
+}
+
+impl InfoNamespaceEntity {
+    /// Merge data from the API (`newer`), preserving user-managed fields.
+    /// Quota fields are replaced as a whole so removed quotas don't linger.
+    pub fn merge_from(&mut self, newer: InfoNamespaceEntity) {
+        self.name = newer.name.or(self.name.take());
+        self.uid = newer.uid.or(self.uid.take());
+        self.creation_timestamp = newer.creation_timestamp.or(self.creation_timestamp.take());
+        self.resource_version = newer.resource_version.or(self.resource_version.take());
+        self.phase = newer.phase.or(self.phase.take());
+        self.last_updated_info_at = newer.last_updated_info_at.or(self.last_updated_info_at.take());
+        self.deleted = newer.deleted.or(self.deleted.take());
+        self.label = newer.label.or(self.label.take());
+        self.annotation = newer.annotation.or(self.annotation.take());
+
+        self.quota_names = newer.quota_names;
+        self.quota_cpu_request_cores = newer.quota_cpu_request_cores;
+        self.quota_cpu_limit_cores = newer.quota_cpu_limit_cores;
+        self.quota_memory_request_bytes = newer.quota_memory_request_bytes;
+        self.quota_memory_limit_bytes = newer.quota_memory_limit_bytes;
+        self.quota_storage_request_bytes = newer.quota_storage_request_bytes;
+        self.quota_pods = newer.quota_pods;
+        // team/service/env are local annotations and never come from the API
+    }
+
+    pub fn has_quota(&self) -> bool {
+        self.quota_names.as_ref().is_some_and(|q| !q.is_empty())
+    }
 }
@@ -0,0 +1,161 @@
+use super::info_namespace_entity::InfoNamespaceEntity;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::path::{
+    info_k8s_namespace_file_path, info_k8s_namespace_key_dir_path,
+};
+use anyhow::{anyhow, Context, Result};
+use std::{fs::{self, File}, io::{BufRead, BufReader}, path::Path};
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// File-based FS adapter for the `InfoNamespaceEntity`.
+///
+/// Each namespace has its own file at `data/info/k8s/namespace/{name}/info.rci`,
+/// in the same key–value format as nodes and pods.
+pub struct InfoNamespaceFsAdapter;
+
+impl InfoDynamicFsAdapterTrait<InfoNamespaceEntity> for InfoNamespaceFsAdapter {
+    fn read(&self, name: &str) -> Result<InfoNamespaceEntity> {
+        blocking_io(|| {
+            let path = info_k8s_namespace_file_path(name);
+            if !Path::new(&path).exists() {
+                return Err(anyhow!("Missing Namespace info file '{}'", path.display()));
+            }
+
+            let file = File::open(&path).context("Failed to open namespace info file")?;
+            let reader = BufReader::new(file);
+            let mut v = InfoNamespaceEntity::default();
+
+            let non_empty = |val: String| Some(val).filter(|s| !s.is_empty());
+            let list = |val: &str| {
+                Some(val.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+            };
+
+            for line in reader.lines() {
+                let line = line?;
+                if let Some((key, val)) = line.split_once(':') {
+                    let key = key.trim().to_uppercase();
+                    let val = val.trim().to_string();
+
+                    match key.as_str() {
+                        "NAME" => v.name = non_empty(val),
+                        "UID" => v.uid = non_empty(val),
+                        "CREATION_TIMESTAMP" => v.creation_timestamp = val.parse().ok(),
+                        "RESOURCE_VERSION" => v.resource_version = non_empty(val),
+                        "PHASE" => v.phase = non_empty(val),
+                        "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = val.parse().ok(),
+                        "DELETED" => v.deleted = val.parse().ok(),
+                        "LABEL" => v.label = non_empty(val),
+                        "ANNOTATION" => v.annotation = non_empty(val),
+                        "QUOTA_NAMES" => v.quota_names = list(&val),
+                        "QUOTA_CPU_REQUEST_CORES" => v.quota_cpu_request_cores = val.parse().ok(),
+                        "QUOTA_CPU_LIMIT_CORES" => v.quota_cpu_limit_cores = val.parse().ok(),
+                        "QUOTA_MEMORY_REQUEST_BYTES" => v.quota_memory_request_bytes = val.parse().ok(),
+                        "QUOTA_MEMORY_LIMIT_BYTES" => v.quota_memory_limit_bytes = val.parse().ok(),
+                        "QUOTA_STORAGE_REQUEST_BYTES" => v.quota_storage_request_bytes = val.parse().ok(),
+                        "QUOTA_PODS" => v.quota_pods = val.parse().ok(),
+                        "TEAM" => v.team = non_empty(val),
+                        "SERVICE" => v.service = non_empty(val),
+                        "ENV" => v.env = non_empty(val),
+                        _ => {}
+                    }
+                }
+            }
+
+            Ok(v)
+        })
+    }
+
+    fn insert(&self, data: &InfoNamespaceEntity) -> Result<()> {
+        self.update(data)
+    }
+
+    fn update(&self, data: &InfoNamespaceEntity) -> Result<()> {
+        blocking_io(|| {
+            let name = data
+                .name
+                .as_ref()
+                .ok_or_else(|| anyhow!("Missing name in InfoNamespaceEntity"))?;
+            self.write(name, data)
+        })
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        blocking_io(|| {
+            let dir = info_k8s_namespace_key_dir_path(name);
+            if dir.exists() {
+                fs::remove_dir_all(&dir).context("Failed to delete namespace info directory")?;
+            }
+            Ok(())
+        })
+    }
+
+    fn exists(&self, name: &str) -> Result<bool> {
+        blocking_io(|| {
+            Ok(info_k8s_namespace_file_path(name).exists())
+        })
+    }
+}
+
+impl InfoNamespaceFsAdapter {
+    fn write(&self, name: &str, data: &InfoNamespaceEntity) -> Result<()> {
+        use std::io::Write;
+
+        let dir = info_k8s_namespace_key_dir_path(name);
+        fs::create_dir_all(&dir).context("Failed to create namespace info directory")?;
+
+        let tmp_path = dir.join("info.rci.tmp");
+        let final_path = dir.join("info.rci");
+
+        let mut f = File::create(&tmp_path)
+            .context("Failed to create temporary namespace info file")?;
+
+        macro_rules! write_field {
+            ($key:expr, $val:expr) => {
+                match &$val {
+                    Some(v) => writeln!(f, "{}:{}", $key, v)?,
+                    None => writeln!(f, "{}:", $key)?,
+                }
+            };
+        }
+
+        // ---- Identity / lifecycle ----
+        write_field!("NAME", data.name);
+        write_field!("UID", data.uid);
+        write_field!("CREATION_TIMESTAMP", data.creation_timestamp.map(|t| t.to_rfc3339()));
+        write_field!("RESOURCE_VERSION", data.resource_version);
+        write_field!("PHASE", data.phase);
+        write_field!("LAST_UPDATED_INFO_AT", data.last_updated_info_at.map(|t| t.to_rfc3339()));
+        write_field!("DELETED", data.deleted.map(|v| v.to_string()));
+
+        // ---- Metadata ----
+        write_field!("LABEL", data.label);
+        write_field!("ANNOTATION", data.annotation);
+
+        // ---- Quota ----
+        write_field!("QUOTA_NAMES", data.quota_names.as_ref().map(|v| v.join(",")));
+        write_field!("QUOTA_CPU_REQUEST_CORES", data.quota_cpu_request_cores.map(|v| v.to_string()));
+        write_field!("QUOTA_CPU_LIMIT_CORES", data.quota_cpu_limit_cores.map(|v| v.to_string()));
+        write_field!("QUOTA_MEMORY_REQUEST_BYTES", data.quota_memory_request_bytes.map(|v| v.to_string()));
+        write_field!("QUOTA_MEMORY_LIMIT_BYTES", data.quota_memory_limit_bytes.map(|v| v.to_string()));
+        write_field!("QUOTA_STORAGE_REQUEST_BYTES", data.quota_storage_request_bytes.map(|v| v.to_string()));
+        write_field!("QUOTA_PODS", data.quota_pods.map(|v| v.to_string()));
+
+        // ---- Custom fields ----
+        write_field!("TEAM", data.team);
+        write_field!("SERVICE", data.service);
+        write_field!("ENV", data.env);
+
+        f.flush()?;
+
+        #[cfg(windows)]
+        if final_path.exists() {
+            fs::remove_file(&final_path)
+                .context("Failed to remove old info.rci before rename")?;
+        }
+
+        fs::rename(&tmp_path, &final_path)
+            .context("Failed to atomically replace namespace info file")?;
+
+        Ok(())
+    }
+}
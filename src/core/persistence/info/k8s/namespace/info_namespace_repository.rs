@@ -0,0 +1,53 @@
+use std::fs;
+
+use anyhow::Result;
+
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::path::info_k8s_namespace_dir_path;
+
+use super::info_namespace_api_repository_trait::InfoNamespaceApiRepository;
+use super::info_namespace_entity::InfoNamespaceEntity;
+use super::info_namespace_fs_adapter::InfoNamespaceFsAdapter;
+
+/// Repository for namespace info that delegates to the filesystem adapter.
+pub struct InfoNamespaceRepository {
+    adapter: InfoNamespaceFsAdapter,
+}
+
+impl InfoNamespaceRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoNamespaceFsAdapter,
+        }
+    }
+
+    /// Every stored namespace; unreadable records are skipped.
+    pub fn list(&self) -> Result<Vec<InfoNamespaceEntity>> {
+        let dir = info_k8s_namespace_dir_path();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut out = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let name = entry?.file_name().to_string_lossy().to_string();
+            if let Ok(ns) = self.adapter.read(&name) {
+                out.push(ns);
+            }
+        }
+        out.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(out)
+    }
+}
+
+impl Default for InfoNamespaceRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InfoNamespaceApiRepository for InfoNamespaceRepository {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoNamespaceEntity> {
+        &self.adapter
+    }
+}
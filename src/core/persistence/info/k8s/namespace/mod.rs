@@ -1 +1,4 @@
 pub mod info_namespace_entity;
+pub mod info_namespace_fs_adapter;
+pub mod info_namespace_api_repository_trait;
+pub mod info_namespace_repository;
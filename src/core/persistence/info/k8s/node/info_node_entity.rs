@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Represents static and runtime information for a Kubernetes node.
 ///
@@ -16,6 +17,8 @@ pub struct InfoNodeEntity {
     // --- Lifecycle ---
     pub last_updated_info_at: Option<DateTime<Utc>>,
     pub deleted: Option<bool>,
+    /// When the node was removed from the cluster, as seen by the watcher
+    pub deleted_at: Option<DateTime<Utc>>,
     pub last_check_deleted_count: Option<u64>,
 
     // --- Host Info ---
@@ -64,7 +67,7 @@ pub struct InfoNodeEntity {
 
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub enum NodePricePeriod {
     /// Unit-based pricing (CPU-hour, GB-hour, etc.)
     Unit,
@@ -90,6 +93,7 @@ impl InfoNodeEntity {
         self.last_updated_info_at =
             newer.last_updated_info_at.or(self.last_updated_info_at.take());
         self.deleted = newer.deleted.or(self.deleted.take());
+        self.deleted_at = newer.deleted_at.or(self.deleted_at.take());
         self.last_check_deleted_count =
             newer.last_check_deleted_count.or(self.last_check_deleted_count.take());
 
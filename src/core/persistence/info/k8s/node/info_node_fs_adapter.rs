@@ -3,6 +3,7 @@ use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDyna
 use anyhow::{anyhow, Context, Result};
 use std::{fs::{self, File}, io::{BufRead, BufReader}, path::Path};
 use crate::core::persistence::info::path::{info_k8s_node_key_dir_path, info_k8s_node_file_path};
+use crate::core::persistence::blocking_io::blocking_io;
 
 /// File-based FS adapter for the `InfoNodeEntity`.
 ///
@@ -16,113 +17,123 @@ impl InfoDynamicFsAdapterTrait<InfoNodeEntity> for InfoNodeFsAdapter {
     /// Reads the node info file into memory.
     /// Returns a default entity if the file does not exist.
     fn read(&self, node_name: &str) -> Result<InfoNodeEntity> {
+        blocking_io(|| {
+            let path = info_k8s_node_file_path(node_name);
+            if !Path::new(&path).exists() {
+                return Err(anyhow!("Missing Node info file '{}'", path.display()));
+            }
 
-        let path = info_k8s_node_file_path(node_name);
-        if !Path::new(&path).exists() {
-            return Err(anyhow!("Missing Node info file '{}'", path.display()));
-        }
-
-        let file = File::open(&path).context("Failed to open node info file")?;
-        let reader = BufReader::new(file);
-        let mut v = InfoNodeEntity::default();
-
-        for line in reader.lines() {
-            let line = line?;
-            if let Some((key, val)) = line.split_once(':') {
-                let key = key.trim().to_uppercase();
-                let val = val.trim().to_string();
-
-                match key.as_str() {
-                    "NODE_NAME" => v.node_name = Some(val),
-                    "NODE_UID" => v.node_uid = Some(val),
-                    "CREATION_TIMESTAMP" => v.creation_timestamp = Some(val.parse().unwrap_or_default()),
-                    "RESOURCE_VERSION" => v.resource_version = Some(val),
-                    "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = Some(val.parse().unwrap_or_default()),
-                    "DELETED" => v.deleted = Some(val == "true"),
-                    "LAST_CHECK_DELETED_COUNT" => v.last_check_deleted_count = val.parse().ok(),
-                    "HOSTNAME" => v.hostname = Some(val),
-                    "INTERNAL_IP" => v.internal_ip = Some(val),
-                    "ARCHITECTURE" => v.architecture = Some(val),
-                    "OS_IMAGE" => v.os_image = Some(val),
-                    "KERNEL_VERSION" => v.kernel_version = Some(val),
-                    "KUBELET_VERSION" => v.kubelet_version = Some(val),
-                    "CONTAINER_RUNTIME" => v.container_runtime = Some(val),
-                    "OPERATING_SYSTEM" => v.operating_system = Some(val),
-                    "CPU_CAPACITY_CORES" => v.cpu_capacity_cores = val.parse().ok(),
-                    "MEMORY_CAPACITY_BYTES" => v.memory_capacity_bytes = val.parse().ok(),
-                    "POD_CAPACITY" => v.pod_capacity = val.parse().ok(),
-                    "EPHEMERAL_STORAGE_CAPACITY_BYTES" => v.ephemeral_storage_capacity_bytes = val.parse().ok(),
-                    "CPU_ALLOCATABLE_CORES" => v.cpu_allocatable_cores = val.parse().ok(),
-                    "MEMORY_ALLOCATABLE_BYTES" => v.memory_allocatable_bytes = val.parse().ok(),
-                    "EPHEMERAL_STORAGE_ALLOCATABLE_BYTES" => v.ephemeral_storage_allocatable_bytes = val.parse().ok(),
-                    "POD_ALLOCATABLE" => v.pod_allocatable = val.parse().ok(),
-                    "READY" => v.ready = Some(val == "true"),
-                    "TAINTS" => v.taints = Some(val),
-                    "LABEL" => v.label = Some(val),
-                    "ANNOTATION" => v.annotation = Some(val),
-                    "IMAGE_COUNT" => v.image_count = val.parse().ok(),
-                    "IMAGE_NAMES" => v.image_names = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-                    "IMAGE_TOTAL_SIZE_BYTES" => v.image_total_size_bytes = val.parse().ok(),
-
-                    "FIXED_INSTANCE_USD" => v.fixed_instance_usd = val.parse().ok(),
-                    "PRICE_PERIOD" => v.price_period = match val.to_lowercase().as_str() {
-                        "unit" => Some(NodePricePeriod::Unit),
-                        "hour" => Some(NodePricePeriod::Hour),
-                        "day" => Some(NodePricePeriod::Day),
-                        "month" => Some(NodePricePeriod::Month),
-                        _ => None,
-                    },
-
-                    "TEAM" => v.team = Some(val),
-                    "SERVICE" => v.service = Some(val),
-                    "ENV" => v.env = Some(val),
-                    _ => {}
+            let file = File::open(&path).context("Failed to open node info file")?;
+            let reader = BufReader::new(file);
+            let mut v = InfoNodeEntity::default();
+
+            for line in reader.lines() {
+                let line = line?;
+                if let Some((key, val)) = line.split_once(':') {
+                    let key = key.trim().to_uppercase();
+                    let val = val.trim().to_string();
+
+                    match key.as_str() {
+                        "NODE_NAME" => v.node_name = Some(val),
+                        "NODE_UID" => v.node_uid = Some(val),
+                        "CREATION_TIMESTAMP" => v.creation_timestamp = Some(val.parse().unwrap_or_default()),
+                        "RESOURCE_VERSION" => v.resource_version = Some(val),
+                        "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = Some(val.parse().unwrap_or_default()),
+                        "DELETED" => v.deleted = Some(val == "true"),
+                        "DELETED_AT" => v.deleted_at = val.parse().ok(),
+                        "LAST_CHECK_DELETED_COUNT" => v.last_check_deleted_count = val.parse().ok(),
+                        "HOSTNAME" => v.hostname = Some(val),
+                        "INTERNAL_IP" => v.internal_ip = Some(val),
+                        "ARCHITECTURE" => v.architecture = Some(val),
+                        "OS_IMAGE" => v.os_image = Some(val),
+                        "KERNEL_VERSION" => v.kernel_version = Some(val),
+                        "KUBELET_VERSION" => v.kubelet_version = Some(val),
+                        "CONTAINER_RUNTIME" => v.container_runtime = Some(val),
+                        "OPERATING_SYSTEM" => v.operating_system = Some(val),
+                        "CPU_CAPACITY_CORES" => v.cpu_capacity_cores = val.parse().ok(),
+                        "MEMORY_CAPACITY_BYTES" => v.memory_capacity_bytes = val.parse().ok(),
+                        "POD_CAPACITY" => v.pod_capacity = val.parse().ok(),
+                        "EPHEMERAL_STORAGE_CAPACITY_BYTES" => v.ephemeral_storage_capacity_bytes = val.parse().ok(),
+                        "CPU_ALLOCATABLE_CORES" => v.cpu_allocatable_cores = val.parse().ok(),
+                        "MEMORY_ALLOCATABLE_BYTES" => v.memory_allocatable_bytes = val.parse().ok(),
+                        "EPHEMERAL_STORAGE_ALLOCATABLE_BYTES" => v.ephemeral_storage_allocatable_bytes = val.parse().ok(),
+                        "POD_ALLOCATABLE" => v.pod_allocatable = val.parse().ok(),
+                        "READY" => v.ready = Some(val == "true"),
+                        "TAINTS" => v.taints = Some(val),
+                        "LABEL" => v.label = Some(val),
+                        "ANNOTATION" => v.annotation = Some(val),
+                        "IMAGE_COUNT" => v.image_count = val.parse().ok(),
+                        "IMAGE_NAMES" => v.image_names = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+                        "IMAGE_TOTAL_SIZE_BYTES" => v.image_total_size_bytes = val.parse().ok(),
+
+                        "FIXED_INSTANCE_USD" => v.fixed_instance_usd = val.parse().ok(),
+                        "PRICE_PERIOD" => v.price_period = match val.to_lowercase().as_str() {
+                            "unit" => Some(NodePricePeriod::Unit),
+                            "hour" => Some(NodePricePeriod::Hour),
+                            "day" => Some(NodePricePeriod::Day),
+                            "month" => Some(NodePricePeriod::Month),
+                            _ => None,
+                        },
+
+                        "TEAM" => v.team = Some(val),
+                        "SERVICE" => v.service = Some(val),
+                        "ENV" => v.env = Some(val),
+                        _ => {}
+                    }
                 }
             }
-        }
 
-        Ok(v)
+            Ok(v)
+        })
     }
 
     /// Creates the node info file.
     fn insert(&self, data: &InfoNodeEntity) -> Result<()> {
-        // Safely get the node name or return an error if missing
-        let node_name = data
-            .node_name
-            .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Missing node_name in InfoNodeEntity"))?;
-
-        Self::create_node_dir_if_missing(node_name)?;
-        self.write(node_name, data)
+        blocking_io(|| {
+            // Safely get the node name or return an error if missing
+            let node_name = data
+                .node_name
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("Missing node_name in InfoNodeEntity"))?;
+
+            Self::create_node_dir_if_missing(node_name)?;
+            self.write(node_name, data)
+        })
     }
 
     /// Updates the node info file.
     fn update(&self, data: &InfoNodeEntity) -> Result<()> {
-        // Safely get the node name or return an error if missing
-        let node_name = data
-            .node_name
-            .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Missing node_name in InfoNodeEntity"))?;
+        blocking_io(|| {
+            // Safely get the node name or return an error if missing
+            let node_name = data
+                .node_name
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("Missing node_name in InfoNodeEntity"))?;
 
-        // Create directory if missing
-        Self::create_node_dir_if_missing(node_name)?;
+            // Create directory if missing
+            Self::create_node_dir_if_missing(node_name)?;
 
-        // Write node info
-        self.write(node_name, data)
+            // Write node info
+            self.write(node_name, data)
+        })
     }
 
     /// Deletes the node info file if present.
     fn delete(&self, node_name: &str) -> Result<()> {
-        let path = info_k8s_node_file_path(node_name);
-        if Path::new(&path).exists() {
-            fs::remove_file(&path).context("Failed to delete node info file")?;
-        }
-        Ok(())
+        blocking_io(|| {
+            let path = info_k8s_node_file_path(node_name);
+            if Path::new(&path).exists() {
+                fs::remove_file(&path).context("Failed to delete node info file")?;
+            }
+            Ok(())
+        })
     }
 
     fn exists(&self, node_name: &str) -> Result<bool> {
-        let path = info_k8s_node_file_path(node_name);
-        Ok(Path::new(&path).exists())
+        blocking_io(|| {
+            let path = info_k8s_node_file_path(node_name);
+            Ok(Path::new(&path).exists())
+        })
     }
 
 }
@@ -167,6 +178,7 @@ impl InfoNodeFsAdapter {
         write_field!("RESOURCE_VERSION", data.resource_version);
         write_field!("LAST_UPDATED_INFO_AT", data.last_updated_info_at.map(|t| t.to_string()));
         write_field!("DELETED", data.deleted.map(|v| v.to_string()));
+        write_field!("DELETED_AT", data.deleted_at.map(|t| t.to_string()));
         write_field!("LAST_CHECK_DELETED_COUNT", data.last_check_deleted_count.map(|v| v.to_string()));
 
         // ---- Basic Info ----
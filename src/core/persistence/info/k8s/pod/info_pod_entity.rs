@@ -16,6 +16,8 @@ pub struct InfoPodEntity {
     // --- Lifecycle ---
     pub creation_timestamp: Option<DateTime<Utc>>,
     pub start_time: Option<DateTime<Utc>>,
+    /// When the pod was seen deleted (watch event); never set by the API mapper.
+    pub deleted_at: Option<DateTime<Utc>>,
     pub resource_version: Option<String>,
 
     pub last_updated_info_at: Option<DateTime<Utc>>,
@@ -37,6 +39,10 @@ pub struct InfoPodEntity {
     pub owner_kind: Option<String>,
     pub owner_name: Option<String>,
     pub owner_uid: Option<String>,
+    /// Top-level controller reached by walking the owner chain
+    /// (e.g. ReplicaSet → Deployment, Job → CronJob).
+    pub workload_kind: Option<String>,
+    pub workload_name: Option<String>,
 
     // --- Containers ---
     pub container_count: Option<u32>,
@@ -65,6 +71,9 @@ pub struct InfoPodEntity {
     pub team: Option<String>,
     pub service: Option<String>,
     pub env: Option<String>, // "dev", "stage", "prod"
+    /// Who last set team/service/env: `manual` (PATCH, never overridden by rules)
+    /// or `rule:<id>,...` for the attribution rules that did.
+    pub attribution_source: Option<String>,
 }
 
 impl InfoPodEntity {
@@ -77,6 +86,7 @@ impl InfoPodEntity {
 
         self.creation_timestamp = newer.creation_timestamp.or(self.creation_timestamp.take());
         self.start_time = newer.start_time.or(self.start_time.take());
+        self.deleted_at = newer.deleted_at.or(self.deleted_at.take());
         self.resource_version = newer.resource_version.or(self.resource_version.take());
         self.last_updated_info_at = newer.last_updated_info_at.or(self.last_updated_info_at.take());
         self.deleted = newer.deleted.or(self.deleted.take());
@@ -94,6 +104,8 @@ impl InfoPodEntity {
         self.owner_kind = newer.owner_kind.or(self.owner_kind.take());
         self.owner_name = newer.owner_name.or(self.owner_name.take());
         self.owner_uid = newer.owner_uid.or(self.owner_uid.take());
+        self.workload_kind = newer.workload_kind.or(self.workload_kind.take());
+        self.workload_name = newer.workload_name.or(self.workload_name.take());
 
         self.container_count = newer.container_count.or(self.container_count.take());
         self.container_names = newer.container_names.or(self.container_names.take());
@@ -116,5 +128,64 @@ impl InfoPodEntity {
         if newer.team.is_some() { self.team = newer.team; }
         if newer.service.is_some() { self.service = newer.service; }
         if newer.env.is_some() { self.env = newer.env; }
+        if newer.attribution_source.is_some() { self.attribution_source = newer.attribution_source; }
+    }
+
+    /// Hours the pod was alive within `[start, end)`: from `start_time` (or
+    /// creation) until `deleted_at`, or `now` while it still runs.
+    ///
+    /// `None` when the lifecycle is unknown — no start recorded, deleted without a
+    /// recorded time, or completed (the end isn't kept) — so callers can fall back
+    /// to metric rows.
+    pub fn running_hours_within(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Option<f64> {
+        let started = self.start_time.or(self.creation_timestamp)?;
+        let stopped = match self.deleted_at {
+            Some(at) => at,
+            None if self.deleted == Some(true) => return None,
+            None if matches!(self.phase.as_deref(), Some("Succeeded" | "Failed")) => return None,
+            None => now,
+        };
+
+        let from = started.max(start);
+        let to = stopped.min(end);
+        if to <= from {
+            return Some(0.0);
+        }
+        Some((to - from).num_seconds() as f64 / 3600.0)
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn running_hours_intersect_lifecycle_with_window() {
+        let pod = InfoPodEntity {
+            start_time: Some(at(2)),
+            deleted_at: Some(at(5)),
+            ..Default::default()
+        };
+        assert_eq!(pod.running_hours_within(at(0), at(10), at(12)), Some(3.0));
+        assert_eq!(pod.running_hours_within(at(4), at(10), at(12)), Some(1.0));
+        assert_eq!(pod.running_hours_within(at(6), at(10), at(12)), Some(0.0));
+
+        let running = InfoPodEntity { start_time: Some(at(2)), ..Default::default() };
+        assert_eq!(running.running_hours_within(at(0), at(10), at(4)), Some(2.0));
+
+        let unknown = InfoPodEntity { deleted: Some(true), ..running };
+        assert_eq!(unknown.running_hours_within(at(0), at(10), at(4)), None);
     }
 }
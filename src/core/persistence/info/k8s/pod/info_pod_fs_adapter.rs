@@ -8,6 +8,7 @@ use std::{
 };
 use tracing::log::debug;
 use crate::core::persistence::info::path::{info_k8s_pod_key_dir_path, info_k8s_pod_file_path};
+use crate::core::persistence::blocking_io::blocking_io;
 
 /// File-based FS adapter for `InfoPodEntity`.
 ///
@@ -19,144 +20,157 @@ impl InfoDynamicFsAdapterTrait<InfoPodEntity> for InfoPodFsAdapter {
     /// Reads the pod info file into memory.
     /// Returns a default entity if the file does not exist.
     fn read(&self, pod_uid: &str) -> Result<InfoPodEntity> {
+        blocking_io(|| {
+            let path = info_k8s_pod_file_path(pod_uid);
+            if !Path::new(&path).exists() {
+                return Err(anyhow!("Missing pod info file '{}'", path.display()));
+            }
 
-        let path = info_k8s_pod_file_path(pod_uid);
-        if !Path::new(&path).exists() {
-            return Err(anyhow!("Missing pod info file '{}'", path.display()));
-        }
-
-        let file = File::open(&path).context("Failed to open pod info file")?;
-        let reader = BufReader::new(file);
-        let mut v = InfoPodEntity::default();
-
-        for line in reader.lines() {
-            let line = line?;
-            if let Some((key, val)) = line.split_once(':') {
-                let key = key.trim().to_uppercase();
-                let val = val.trim().to_string();
-
-                match key.as_str() {
-                    // Identity
-                    "POD_NAME" => v.pod_name = Some(val),
-                    "NAMESPACE" => v.namespace = Some(val),
-                    "POD_UID" => v.pod_uid = Some(val),
-
-                    // Lifecycle
-                    "CREATION_TIMESTAMP" => v.creation_timestamp = val.parse().ok(),
-                    "START_TIME" => v.start_time = val.parse().ok(),
-                    "RESOURCE_VERSION" => v.resource_version = Some(val),
-                    "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = val.parse().ok(),
-                    "DELETED" => v.deleted = Some(val == "true"),
-                    "LAST_CHECK_DELETED_COUNT" => v.last_check_deleted_count = val.parse().ok(),
-
-                    // Node association
-                    "NODE_NAME" => v.node_name = Some(val),
-                    "HOST_IP" => v.host_ip = Some(val),
-                    "POD_IP" => v.pod_ip = Some(val),
-
-                    // Status
-                    "QOS_CLASS" => v.qos_class = Some(val),
-                    "PHASE" => v.phase = Some(val),
-                    "READY" => v.ready = Some(val == "true"),
-                    "RESTART_COUNT" => v.restart_count = val.parse().ok(),
-
-                    // Owner
-                    "OWNER_KIND" => v.owner_kind = Some(val),
-                    "OWNER_NAME" => v.owner_name = Some(val),
-                    "OWNER_UID" => v.owner_uid = Some(val),
-
-                    // Containers
-                    "CONTAINER_COUNT" => v.container_count = val.parse().ok(),
-                    "CONTAINER_NAMES" => v.container_names = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-                    "CONTAINER_IMAGES" => v.container_images = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-                    "CONTAINER_IDS" => v.container_ids = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-                    "IMAGE_IDS" => v.image_ids = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-                    "CONTAINER_PORTS" => {
-                        v.container_ports = Some(
-                            val.split(',')
-                                .filter_map(|s| s.trim().parse::<u16>().ok())
-                                .collect(),
-                        )
+            let file = File::open(&path).context("Failed to open pod info file")?;
+            let reader = BufReader::new(file);
+            let mut v = InfoPodEntity::default();
+
+            for line in reader.lines() {
+                let line = line?;
+                if let Some((key, val)) = line.split_once(':') {
+                    let key = key.trim().to_uppercase();
+                    let val = val.trim().to_string();
+
+                    match key.as_str() {
+                        // Identity
+                        "POD_NAME" => v.pod_name = Some(val),
+                        "NAMESPACE" => v.namespace = Some(val),
+                        "POD_UID" => v.pod_uid = Some(val),
+
+                        // Lifecycle
+                        "CREATION_TIMESTAMP" => v.creation_timestamp = val.parse().ok(),
+                        "START_TIME" => v.start_time = val.parse().ok(),
+                        "DELETED_AT" => v.deleted_at = val.parse().ok(),
+                        "RESOURCE_VERSION" => v.resource_version = Some(val),
+                        "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = val.parse().ok(),
+                        "DELETED" => v.deleted = Some(val == "true"),
+                        "LAST_CHECK_DELETED_COUNT" => v.last_check_deleted_count = val.parse().ok(),
+
+                        // Node association
+                        "NODE_NAME" => v.node_name = Some(val),
+                        "HOST_IP" => v.host_ip = Some(val),
+                        "POD_IP" => v.pod_ip = Some(val),
+
+                        // Status
+                        "QOS_CLASS" => v.qos_class = Some(val),
+                        "PHASE" => v.phase = Some(val),
+                        "READY" => v.ready = Some(val == "true"),
+                        "RESTART_COUNT" => v.restart_count = val.parse().ok(),
+
+                        // Owner
+                        "OWNER_KIND" => v.owner_kind = Some(val),
+                        "OWNER_NAME" => v.owner_name = Some(val),
+                        "OWNER_UID" => v.owner_uid = Some(val),
+                        "WORKLOAD_KIND" => v.workload_kind = Some(val),
+                        "WORKLOAD_NAME" => v.workload_name = Some(val),
+
+                        // Containers
+                        "CONTAINER_COUNT" => v.container_count = val.parse().ok(),
+                        "CONTAINER_NAMES" => v.container_names = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+                        "CONTAINER_IMAGES" => v.container_images = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+                        "CONTAINER_IDS" => v.container_ids = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+                        "IMAGE_IDS" => v.image_ids = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+                        "CONTAINER_PORTS" => {
+                            v.container_ports = Some(
+                                val.split(',')
+                                    .filter_map(|s| s.trim().parse::<u16>().ok())
+                                    .collect(),
+                            )
+                        }
+                        "RESTART_POLICY" => v.restart_policy = Some(val),
+                        "SCHEDULER_NAME" => v.scheduler_name = Some(val),
+                        "SERVICE_ACCOUNT" => v.service_account = Some(val),
+
+                        // Volumes
+                        "VOLUME_COUNT" => v.volume_count = val.parse().ok(),
+                        "VOLUME_NAMES" => v.volume_names = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+                        "PVC_NAMES" => v.pvc_names = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+                        "MOUNT_PATHS" => v.mount_paths = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+                        "TERMINATION_GRACE_PERIOD_SECONDS" => v.termination_grace_period_seconds = val.parse().ok(),
+                        "TOLERATIONS" => v.tolerations = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
+
+                        // Metadata
+                        "LABEL" => v.label = Some(val),
+                        "ANNOTATION" => v.annotation = Some(val),
+
+                        // Team / Service / Env
+                        "TEAM" => v.team = Some(val),
+                        "SERVICE" => v.service = Some(val),
+                        "ENV" => v.env = Some(val),
+                        "ATTRIBUTION_SOURCE" => v.attribution_source = Some(val).filter(|s| !s.is_empty()),
+                        _ => {}
                     }
-                    "RESTART_POLICY" => v.restart_policy = Some(val),
-                    "SCHEDULER_NAME" => v.scheduler_name = Some(val),
-                    "SERVICE_ACCOUNT" => v.service_account = Some(val),
-
-                    // Volumes
-                    "VOLUME_COUNT" => v.volume_count = val.parse().ok(),
-                    "VOLUME_NAMES" => v.volume_names = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-                    "PVC_NAMES" => v.pvc_names = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-                    "MOUNT_PATHS" => v.mount_paths = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-                    "TERMINATION_GRACE_PERIOD_SECONDS" => v.termination_grace_period_seconds = val.parse().ok(),
-                    "TOLERATIONS" => v.tolerations = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
-
-                    // Metadata
-                    "LABEL" => v.label = Some(val),
-                    "ANNOTATION" => v.annotation = Some(val),
-
-                    // Team / Service / Env
-                    "TEAM" => v.team = Some(val),
-                    "SERVICE" => v.service = Some(val),
-                    "ENV" => v.env = Some(val),
-                    _ => {}
                 }
             }
-        }
 
-        Ok(v)
+            Ok(v)
+        })
     }
 
     /// Creates the pod info file.
     fn insert(&self, data: &InfoPodEntity) -> Result<()> {
-        let pod_uid = data
-            .pod_uid
-            .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Missing pod_uid in InfoPodEntity"))?;
-
-        Self::create_pod_dir_if_missing(pod_uid)?;
-        self.write(pod_uid, data)
+        blocking_io(|| {
+            let pod_uid = data
+                .pod_uid
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("Missing pod_uid in InfoPodEntity"))?;
+
+            Self::create_pod_dir_if_missing(pod_uid)?;
+            self.write(pod_uid, data)
+        })
     }
 
     /// Updates the pod info file.
     fn update(&self, data: &InfoPodEntity) -> Result<()> {
-        // 1️⃣ Ensure we have a Pod UID
-        let pod_uid = data
-            .pod_uid
-            .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Missing pod_uid in InfoPodEntity"))?;
-
-        // 2️⃣ Ensure the directory exists
-        Self::create_pod_dir_if_missing(pod_uid)
-            .with_context(|| format!("Failed to prepare pod directory for '{}'", pod_uid))?;
-
-        // 3️⃣ Log what we’re about to persist (optional but strongly recommended)
-        debug!(
-            "📝 Updating InfoPodEntity '{}': {}",
-            pod_uid,
-            serde_json::to_string_pretty(data).unwrap_or_default()
-        );
-
-        // 4️⃣ Perform atomic write
-        self.write(pod_uid, data)
-            .with_context(|| format!("Failed to write pod info for '{}'", pod_uid))?;
-
-        // 5️⃣ Confirm
-        debug!("✅ Successfully updated InfoPodEntity '{}'", pod_uid);
-        Ok(())
+        blocking_io(|| {
+            // 1️⃣ Ensure we have a Pod UID
+            let pod_uid = data
+                .pod_uid
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("Missing pod_uid in InfoPodEntity"))?;
+
+            // 2️⃣ Ensure the directory exists
+            Self::create_pod_dir_if_missing(pod_uid)
+                .with_context(|| format!("Failed to prepare pod directory for '{}'", pod_uid))?;
+
+            // 3️⃣ Log what we’re about to persist (optional but strongly recommended)
+            debug!(
+                "📝 Updating InfoPodEntity '{}': {}",
+                pod_uid,
+                serde_json::to_string_pretty(data).unwrap_or_default()
+            );
+
+            // 4️⃣ Perform atomic write
+            self.write(pod_uid, data)
+                .with_context(|| format!("Failed to write pod info for '{}'", pod_uid))?;
+
+            // 5️⃣ Confirm
+            debug!("✅ Successfully updated InfoPodEntity '{}'", pod_uid);
+            Ok(())
+        })
     }
 
     /// Deletes the pod info file if present.
     fn delete(&self, pod_uid: &str) -> Result<()> {
-        let path = info_k8s_pod_file_path(pod_uid);
-        if Path::new(&path).exists() {
-            fs::remove_file(&path).context("Failed to delete pod info file")?;
-        }
-        Ok(())
+        blocking_io(|| {
+            let path = info_k8s_pod_file_path(pod_uid);
+            if Path::new(&path).exists() {
+                fs::remove_file(&path).context("Failed to delete pod info file")?;
+            }
+            Ok(())
+        })
     }
 
     fn exists(&self, pod_uid: &str) -> Result<bool> {
-        let path = info_k8s_pod_file_path(pod_uid);
-        Ok(Path::new(&path).exists())
+        blocking_io(|| {
+            let path = info_k8s_pod_file_path(pod_uid);
+            Ok(Path::new(&path).exists())
+        })
     }
 }
 
@@ -217,6 +231,7 @@ impl InfoPodFsAdapter {
         // --- Lifecycle ---
         write_datetime!("CREATION_TIMESTAMP", data.creation_timestamp);
         write_datetime!("START_TIME", data.start_time);
+        write_datetime!("DELETED_AT", data.deleted_at);
         write_field!("RESOURCE_VERSION", data.resource_version);
         write_datetime!("LAST_UPDATED_INFO_AT", data.last_updated_info_at);
         write_field!("DELETED", data.deleted.map(|v| v.to_string()));
@@ -237,6 +252,8 @@ impl InfoPodFsAdapter {
         write_field!("OWNER_KIND", data.owner_kind);
         write_field!("OWNER_NAME", data.owner_name);
         write_field!("OWNER_UID", data.owner_uid);
+        write_field!("WORKLOAD_KIND", data.workload_kind);
+        write_field!("WORKLOAD_NAME", data.workload_name);
 
         // --- Containers ---
         write_field!("CONTAINER_COUNT", data.container_count.map(|v| v.to_string()));
@@ -275,6 +292,7 @@ impl InfoPodFsAdapter {
         write_field!("TEAM", data.team);
         write_field!("SERVICE", data.service);
         write_field!("ENV", data.env);
+        write_field!("ATTRIBUTION_SOURCE", data.attribution_source);
 
         // --- finalize atomic write (NO fsync) ------------------------
 
@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A persisted LLM chat: its turns in order, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LlmConversationEntity {
+    pub id: String,
+    pub title: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub messages: Vec<LlmConversationMessageEntity>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LlmConversationMessageEntity {
+    /// `user`, `assistant` or `system`
+    pub role: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LlmConversationEntity {
+    pub fn new(id: String, title: Option<String>, now: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            title,
+            created_at: now,
+            updated_at: now,
+            messages: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, role: &str, content: &str, now: DateTime<Utc>) {
+        self.messages.push(LlmConversationMessageEntity {
+            role: role.to_string(),
+            content: content.to_string(),
+            created_at: now,
+        });
+        self.updated_at = now;
+    }
+}
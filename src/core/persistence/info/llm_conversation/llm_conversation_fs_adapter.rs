@@ -0,0 +1,71 @@
+use anyhow::{anyhow, Result};
+use std::fs;
+
+use crate::core::persistence::info::llm_conversation::llm_conversation_entity::LlmConversationEntity;
+use crate::core::persistence::info::path::{
+    info_llm_conversation_dir_path, info_llm_conversation_file_path,
+};
+
+/// One JSON file per conversation in `$RUSTCOST_BASE_PATH/info/llm_conversation/`.
+#[derive(Default)]
+pub struct LlmConversationFsAdapter;
+
+impl LlmConversationFsAdapter {
+    pub fn exists(&self, id: &str) -> Result<bool> {
+        Ok(info_llm_conversation_file_path(checked_id(id)?).exists())
+    }
+
+    pub fn read(&self, id: &str) -> Result<LlmConversationEntity> {
+        let path = info_llm_conversation_file_path(checked_id(id)?);
+        let raw = fs::read_to_string(&path)
+            .map_err(|_| anyhow!("conversation '{}' not found", id))?;
+        Ok(serde_json::from_str(&raw)?)
+    }
+
+    /// Writes through a temp file so a crash never leaves a half-written conversation.
+    pub fn write(&self, conversation: &LlmConversationEntity) -> Result<()> {
+        let path = info_llm_conversation_file_path(checked_id(&conversation.id)?);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string(conversation)?)?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+
+    pub fn delete(&self, id: &str) -> Result<()> {
+        let path = info_llm_conversation_file_path(checked_id(id)?);
+        fs::remove_file(&path).map_err(|_| anyhow!("conversation '{}' not found", id))
+    }
+
+    /// Every stored conversation; unreadable files are skipped.
+    pub fn list(&self) -> Result<Vec<LlmConversationEntity>> {
+        let dir = info_llm_conversation_dir_path();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut out = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            if let Some(c) = fs::read_to_string(&path).ok().and_then(|raw| serde_json::from_str(&raw).ok()) {
+                out.push(c);
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Ids become file names, so only `[A-Za-z0-9_-]` is accepted.
+fn checked_id(id: &str) -> Result<&str> {
+    if !id.is_empty() && id.len() <= 64 && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        Ok(id)
+    } else {
+        Err(anyhow!("invalid conversation id '{}'", id))
+    }
+}
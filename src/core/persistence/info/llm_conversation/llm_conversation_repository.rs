@@ -0,0 +1,46 @@
+use anyhow::Result;
+use crate::core::persistence::info::llm_conversation::llm_conversation_entity::LlmConversationEntity;
+use crate::core::persistence::info::llm_conversation::llm_conversation_fs_adapter::LlmConversationFsAdapter;
+
+pub trait LlmConversationRepository: Send + Sync {
+    fn fs(&self) -> &LlmConversationFsAdapter;
+
+    fn exists(&self, id: &str) -> Result<bool> {
+        self.fs().exists(id)
+    }
+
+    fn read(&self, id: &str) -> Result<LlmConversationEntity> {
+        self.fs().read(id)
+    }
+
+    fn write(&self, conversation: &LlmConversationEntity) -> Result<()> {
+        self.fs().write(conversation)
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        self.fs().delete(id)
+    }
+
+    fn list(&self) -> Result<Vec<LlmConversationEntity>> {
+        self.fs().list()
+    }
+}
+
+#[derive(Default)]
+pub struct LlmConversationRepositoryImpl {
+    adapter: LlmConversationFsAdapter,
+}
+
+impl LlmConversationRepositoryImpl {
+    pub fn new() -> Self {
+        Self {
+            adapter: LlmConversationFsAdapter,
+        }
+    }
+}
+
+impl LlmConversationRepository for LlmConversationRepositoryImpl {
+    fn fs(&self) -> &LlmConversationFsAdapter {
+        &self.adapter
+    }
+}
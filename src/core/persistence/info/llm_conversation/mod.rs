@@ -0,0 +1,3 @@
+pub mod llm_conversation_entity;
+pub mod llm_conversation_fs_adapter;
+pub mod llm_conversation_repository;
@@ -1,4 +1,7 @@
 pub mod k8s;
 pub mod fixed;
+pub mod llm_conversation;
+pub mod billing;
+pub mod close;
 pub mod path;
 
@@ -19,6 +19,10 @@ pub fn info_unit_price_path() -> PathBuf {
     info_path("unit_price.rci")
 }
 
+pub fn info_unit_price_history_path() -> PathBuf {
+    info_path("unit_price_history.json")
+}
+
 pub fn info_alert_path() -> PathBuf {
     info_path("alerts.rci")
 }
@@ -31,6 +35,57 @@ pub fn info_setting_path() -> PathBuf {
     info_path("settings.rci")
 }
 
+pub fn info_attribution_rule_path() -> PathBuf {
+    info_path("attribution_rules.json")
+}
+
+pub fn info_fixed_cost_path() -> PathBuf {
+    info_path("fixed_costs.json")
+}
+
+pub fn info_carbon_path() -> PathBuf {
+    info_path("carbon.json")
+}
+
+pub fn info_efficiency_target_path() -> PathBuf {
+    info_path("efficiency_targets.json")
+}
+
+pub fn info_commitment_path() -> PathBuf {
+    info_path("commitments.json")
+}
+
+pub fn info_virtual_node_price_path() -> PathBuf {
+    info_path("virtual_node_prices.json")
+}
+
+// LLM conversations
+pub fn info_llm_conversation_dir_path() -> PathBuf {
+    info_path("llm_conversation")
+}
+
+pub fn info_llm_conversation_file_path(conversation_id: &str) -> PathBuf {
+    info_path(format!("llm_conversation/{}.json", conversation_id))
+}
+
+// Imported cloud billing, one file per UTC day
+pub fn info_billing_dir_path() -> PathBuf {
+    info_path("billing")
+}
+
+pub fn info_billing_file_path(date: &str) -> PathBuf {
+    info_path(format!("billing/{}.json", date))
+}
+
+// Closed finance periods, one immutable file per month
+pub fn info_close_dir_path() -> PathBuf {
+    info_path("close")
+}
+
+pub fn info_close_file_path(period: &str) -> PathBuf {
+    info_path(format!("close/{}.json", period))
+}
+
 // Dynamic info: container
 pub fn info_k8s_container_dir_path() -> PathBuf {
     info_k8s_path("container".to_string())
@@ -67,3 +122,28 @@ pub fn info_k8s_node_key_dir_path(node_key: &str) -> PathBuf {
 pub fn info_k8s_node_file_path(node_key: &str) -> PathBuf {
     info_k8s_path(format!("node/{}/info.rci", node_key))
 }
+
+// Dynamic info: namespace
+pub fn info_k8s_namespace_dir_path() -> PathBuf {
+    info_k8s_path("namespace".to_string())
+}
+
+pub fn info_k8s_namespace_key_dir_path(namespace: &str) -> PathBuf {
+    info_k8s_path(format!("namespace/{}", namespace))
+}
+
+pub fn info_k8s_namespace_file_path(namespace: &str) -> PathBuf {
+    info_k8s_path(format!("namespace/{}/info.rci", namespace))
+}
+
+// Dynamic info: LoadBalancer Services
+/// Every `LoadBalancer` Service discovery has seen, with its lifetime.
+pub fn info_k8s_load_balancer_path() -> PathBuf {
+    info_k8s_path("load_balancers.json")
+}
+
+// Dynamic info: HorizontalPodAutoscalers
+/// Every HPA of the last discovery, with the workload it scales.
+pub fn info_k8s_hpa_path() -> PathBuf {
+    info_k8s_path("hpas.json")
+}
@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Last successfully aggregated window per scope (`node`, `pod`, `container`).
+///
+/// Values are window *ends*, the same timestamp the processors take as `now`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AggregationWatermarkEntity {
+    /// minute → hour
+    #[serde(default)]
+    pub hour: BTreeMap<String, DateTime<Utc>>,
+    /// hour → day
+    #[serde(default)]
+    pub day: BTreeMap<String, DateTime<Utc>>,
+}
+
+/// Which aggregation a watermark belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregationLevel {
+    Hour,
+    Day,
+}
+
+impl AggregationWatermarkEntity {
+    pub fn get(&self, level: AggregationLevel, scope: &str) -> Option<DateTime<Utc>> {
+        self.map(level).get(scope).copied()
+    }
+
+    /// Moves the watermark forward; never moves it back (backfills of older
+    /// windows must not hide a gap after them).
+    pub fn advance(&mut self, level: AggregationLevel, scope: &str, window_end: DateTime<Utc>) {
+        let map = match level {
+            AggregationLevel::Hour => &mut self.hour,
+            AggregationLevel::Day => &mut self.day,
+        };
+        let entry = map.entry(scope.to_string()).or_insert(window_end);
+        if window_end > *entry {
+            *entry = window_end;
+        }
+    }
+
+    fn map(&self, level: AggregationLevel) -> &BTreeMap<String, DateTime<Utc>> {
+        match level {
+            AggregationLevel::Hour => &self.hour,
+            AggregationLevel::Day => &self.day,
+        }
+    }
+}
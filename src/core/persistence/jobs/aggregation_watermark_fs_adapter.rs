@@ -0,0 +1,36 @@
+use anyhow::Result;
+use std::{fs, path::PathBuf};
+use crate::core::persistence::jobs::aggregation_watermark_entity::AggregationWatermarkEntity;
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+/// Aggregation watermarks stored in `$RUSTCOST_BASE_PATH/jobs/watermarks.json`.
+#[derive(Default)]
+pub struct AggregationWatermarkFsAdapter;
+
+impl AggregationWatermarkFsAdapter {
+    fn path() -> PathBuf {
+        get_rustcost_base_path().join("jobs").join("watermarks.json")
+    }
+
+    /// Stored watermarks; empty when nothing was recorded yet.
+    pub fn read(&self) -> Result<AggregationWatermarkEntity> {
+        match fs::read_to_string(Self::path()) {
+            Ok(s) => Ok(serde_json::from_str(&s)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Default::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn write(&self, data: &AggregationWatermarkEntity) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // Write-then-rename so a crash never leaves a truncated file
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(data)?)?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+}
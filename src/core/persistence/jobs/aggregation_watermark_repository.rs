@@ -0,0 +1,34 @@
+use anyhow::Result;
+use crate::core::persistence::jobs::aggregation_watermark_entity::AggregationWatermarkEntity;
+use crate::core::persistence::jobs::aggregation_watermark_fs_adapter::AggregationWatermarkFsAdapter;
+
+pub trait AggregationWatermarkRepository: Send + Sync {
+    fn fs(&self) -> &AggregationWatermarkFsAdapter;
+
+    fn read(&self) -> Result<AggregationWatermarkEntity> {
+        self.fs().read()
+    }
+
+    fn write(&self, data: &AggregationWatermarkEntity) -> Result<()> {
+        self.fs().write(data)
+    }
+}
+
+#[derive(Default)]
+pub struct AggregationWatermarkRepositoryImpl {
+    adapter: AggregationWatermarkFsAdapter,
+}
+
+impl AggregationWatermarkRepositoryImpl {
+    pub fn new() -> Self {
+        Self {
+            adapter: AggregationWatermarkFsAdapter,
+        }
+    }
+}
+
+impl AggregationWatermarkRepository for AggregationWatermarkRepositoryImpl {
+    fn fs(&self) -> &AggregationWatermarkFsAdapter {
+        &self.adapter
+    }
+}
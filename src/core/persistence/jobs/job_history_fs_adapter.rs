@@ -0,0 +1,62 @@
+use anyhow::Result;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+use crate::core::persistence::jobs::job_run_entity::JobRunEntity;
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+/// Number of runs kept on disk; older entries are dropped on append.
+const MAX_ENTRIES: usize = 2000;
+
+/// Job history stored as JSON lines in `$RUSTCOST_BASE_PATH/jobs/history.jsonl`.
+#[derive(Default)]
+pub struct JobHistoryFsAdapter;
+
+impl JobHistoryFsAdapter {
+    fn path() -> PathBuf {
+        get_rustcost_base_path().join("jobs").join("history.jsonl")
+    }
+
+    pub fn append(&self, run: &JobRunEntity) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        file.write_all(format!("{}\n", serde_json::to_string(run)?).as_bytes())?;
+        drop(file);
+
+        // Trim with some slack so we don't rewrite on every append
+        let lines = Self::read_lines(&path)?;
+        if lines.len() > MAX_ENTRIES + MAX_ENTRIES / 10 {
+            let keep = &lines[lines.len() - MAX_ENTRIES..];
+            let tmp = path.with_extension("jsonl.tmp");
+            fs::write(&tmp, keep.iter().map(|l| format!("{}\n", l)).collect::<String>())?;
+            fs::rename(&tmp, &path)?;
+        }
+        Ok(())
+    }
+
+    /// All stored runs, oldest first. Unparseable lines are skipped.
+    pub fn list(&self) -> Result<Vec<JobRunEntity>> {
+        Ok(Self::read_lines(&Self::path())?
+            .iter()
+            .filter_map(|l| serde_json::from_str(l).ok())
+            .collect())
+    }
+
+    fn read_lines(path: &Path) -> Result<Vec<String>> {
+        let file = match File::open(path) {
+            Ok(f) => f,
+            Err(_) => return Ok(vec![]),
+        };
+        Ok(BufReader::new(file)
+            .lines()
+            .map_while(|l| l.ok())
+            .filter(|l| !l.trim().is_empty())
+            .collect())
+    }
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use crate::core::persistence::jobs::job_history_fs_adapter::JobHistoryFsAdapter;
+use crate::core::persistence::jobs::job_run_entity::JobRunEntity;
+
+pub trait JobHistoryRepository: Send + Sync {
+    fn fs(&self) -> &JobHistoryFsAdapter;
+
+    fn append(&self, run: &JobRunEntity) -> Result<()> {
+        self.fs().append(run)
+    }
+
+    fn list(&self) -> Result<Vec<JobRunEntity>> {
+        self.fs().list()
+    }
+}
+
+#[derive(Default)]
+pub struct JobHistoryRepositoryImpl {
+    adapter: JobHistoryFsAdapter,
+}
+
+impl JobHistoryRepositoryImpl {
+    pub fn new() -> Self {
+        Self {
+            adapter: JobHistoryFsAdapter,
+        }
+    }
+}
+
+impl JobHistoryRepository for JobHistoryRepositoryImpl {
+    fn fs(&self) -> &JobHistoryFsAdapter {
+        &self.adapter
+    }
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// One run of a background job (collection, aggregation, retention, ...).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRunEntity {
+    pub job: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub duration_ms: i64,
+    /// `success`, `partial` (some objects failed) or `failed`
+    pub status: String,
+    pub objects_processed: u64,
+    pub objects_failed: u64,
+    /// First few per-object failures, for quick triage.
+    #[serde(default)]
+    pub failures: Vec<String>,
+    /// Error that aborted the whole run, if any.
+    pub error: Option<String>,
+}
@@ -0,0 +1,6 @@
+pub mod job_run_entity;
+pub mod job_history_fs_adapter;
+pub mod job_history_repository;
+pub mod aggregation_watermark_entity;
+pub mod aggregation_watermark_fs_adapter;
+pub mod aggregation_watermark_repository;
@@ -0,0 +1,214 @@
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+
+/// Severity of a log line, ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "trace" => Some(Self::Trace),
+            "debug" => Some(Self::Debug),
+            "info" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// Server-side filter for `/system/logs/{date}`.
+///
+/// `min_level` keeps lines at or above that severity (`warn` also returns
+/// errors). `from`/`to` are inclusive. Lines without their own header
+/// (e.g. the rest of a multi-line panic message) inherit the level and
+/// timestamp of the line above them.
+#[derive(Debug, Default)]
+pub struct LogLineFilter {
+    pub min_level: Option<LogLevel>,
+    pub contains: Option<String>,
+    pub regex: Option<Regex>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Level and timestamp read from the start of a log line.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogLineHeader {
+    pub level: Option<LogLevel>,
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl LogLineFilter {
+    pub fn new(
+        level: Option<&str>,
+        contains: Option<String>,
+        regex: Option<&str>,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
+        let min_level = level
+            .map(|l| {
+                LogLevel::parse(l)
+                    .ok_or_else(|| anyhow!("unknown log level '{}', expected error|warn|info", l))
+            })
+            .transpose()?;
+
+        let regex = regex
+            .map(|r| Regex::new(r).map_err(|e| anyhow!("invalid regex: {}", e)))
+            .transpose()?;
+
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                bail!("'from' must not be after 'to'");
+            }
+        }
+
+        Ok(Self {
+            min_level,
+            contains: contains.filter(|s| !s.is_empty()),
+            regex,
+            from,
+            to,
+        })
+    }
+
+    /// True when no criteria are set, so every line matches.
+    pub fn is_empty(&self) -> bool {
+        self.min_level.is_none()
+            && self.contains.is_none()
+            && self.regex.is_none()
+            && self.from.is_none()
+            && self.to.is_none()
+    }
+
+    /// Checks one line; `header` is what [`parse_header`] returned for it,
+    /// or the inherited header of a continuation line.
+    pub fn matches(&self, line: &str, header: &LogLineHeader) -> bool {
+        if let Some(min) = self.min_level {
+            match header.level {
+                Some(level) if level >= min => {}
+                _ => return false,
+            }
+        }
+
+        if self.from.is_some() || self.to.is_some() {
+            let Some(time) = header.time else {
+                return false;
+            };
+            if self.from.is_some_and(|from| time < from) || self.to.is_some_and(|to| time > to) {
+                return false;
+            }
+        }
+
+        if let Some(needle) = &self.contains {
+            if !line.contains(needle.as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(re) = &self.regex {
+            if !re.is_match(line) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Reads the level and timestamp from a line in either log format:
+/// text (`2025-01-01T00:00:00.000000Z  INFO message`) or JSON
+/// (`{"timestamp":"...","level":"INFO",...}`). Returns `None` when the line
+/// has no header of its own.
+pub fn parse_header(line: &str) -> Option<LogLineHeader> {
+    let trimmed = line.trim_start();
+
+    if trimmed.starts_with('{') {
+        let value: serde_json::Value = serde_json::from_str(trimmed).ok()?;
+        let time = value
+            .get("timestamp")
+            .and_then(|v| v.as_str())
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|t| t.with_timezone(&Utc));
+        let level = value
+            .get("level")
+            .and_then(|v| v.as_str())
+            .and_then(LogLevel::parse);
+        return Some(LogLineHeader { level, time });
+    }
+
+    let mut parts = trimmed.split_whitespace();
+    let time = DateTime::parse_from_rfc3339(parts.next()?)
+        .ok()?
+        .with_timezone(&Utc);
+    let level = parts.next().and_then(LogLevel::parse);
+
+    Some(LogLineHeader {
+        level,
+        time: Some(time),
+    })
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn parses_text_and_json_headers() {
+        let text = parse_header("2025-03-01T10:00:00.123456Z  WARN node scrape slow").unwrap();
+        assert_eq!(text.level, Some(LogLevel::Warn));
+        assert_eq!(text.time.unwrap().timestamp(), Utc.with_ymd_and_hms(2025, 3, 1, 10, 0, 0).unwrap().timestamp());
+
+        let json = parse_header(r#"{"timestamp":"2025-03-01T10:00:00Z","level":"ERROR","fields":{}}"#).unwrap();
+        assert_eq!(json.level, Some(LogLevel::Error));
+
+        assert!(parse_header("   at src/main.rs:10").is_none());
+    }
+
+    #[test]
+    fn level_filter_keeps_more_severe_lines() {
+        let filter = LogLineFilter::new(Some("warn"), None, None, None, None).unwrap();
+        let line = "2025-03-01T10:00:00Z ERROR boom";
+        let header = parse_header(line).unwrap();
+        assert!(filter.matches(line, &header));
+
+        let line = "2025-03-01T10:00:00Z  INFO fine";
+        let header = parse_header(line).unwrap();
+        assert!(!filter.matches(line, &header));
+    }
+
+    #[test]
+    fn time_range_and_text_filters_combine() {
+        let from = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2025, 3, 1, 11, 0, 0).unwrap();
+        let filter = LogLineFilter::new(None, Some("scrape".into()), Some(r"node-\d+"), Some(from), Some(to))
+            .unwrap();
+
+        let line = "2025-03-01T10:00:00Z  INFO scrape failed for node-3";
+        assert!(filter.matches(line, &parse_header(line).unwrap()));
+
+        let line = "2025-03-01T12:00:00Z  INFO scrape failed for node-3";
+        assert!(!filter.matches(line, &parse_header(line).unwrap()));
+
+        let line = "2025-03-01T10:00:00Z  INFO scrape failed for node-x";
+        assert!(!filter.matches(line, &parse_header(line).unwrap()));
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert!(LogLineFilter::new(Some("loud"), None, None, None, None).is_err());
+        assert!(LogLineFilter::new(None, None, Some("("), None, None).is_err());
+    }
+}
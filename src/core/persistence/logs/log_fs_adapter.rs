@@ -5,6 +5,7 @@ use std::{
     io::{BufRead, BufReader},
     path::PathBuf,
 };
+use crate::core::persistence::logs::log_filter::{parse_header, LogLineFilter, LogLineHeader};
 use crate::core::persistence::storage_path::get_rustcost_base_path;
 use tokio::task;
 const LOG_PREFIX: &str = "app.log.";
@@ -60,11 +61,16 @@ impl LogFsAdapter {
     }
 
 
+    /// Reads up to `limit` lines matching `filter`, starting at raw line `cursor`.
+    ///
+    /// The returned cursor is the raw line number to resume from, so paging
+    /// stays stable even when most lines are filtered out.
     pub async fn get_system_log_lines(
         &self,
         date: &str,
         cursor: usize,
         limit: usize,
+        filter: LogLineFilter,
     ) -> anyhow::Result<(Vec<String>, Option<usize>)> {
         let path = Self::log_path(&date);
 
@@ -76,17 +82,34 @@ impl LogFsAdapter {
             let file = File::open(path)?;
             let reader = BufReader::new(file);
 
-            let lines = reader
-                .lines()
-                .skip(cursor)
-                .take(limit)
-                .collect::<Result<Vec<_>, _>>()?;
-
-            let next_cursor = if lines.len() < limit {
-                None
-            } else {
-                Some(cursor + lines.len())
-            };
+            let mut lines = Vec::new();
+            let mut header = LogLineHeader::default();
+            let mut next_cursor = None;
+
+            for (idx, line) in reader.lines().enumerate() {
+                let line = line?;
+
+                // Continuation lines inherit the header of the line above,
+                // so it has to be tracked across the skipped prefix too.
+                if !filter.is_empty() {
+                    if let Some(parsed) = parse_header(&line) {
+                        header = parsed;
+                    }
+                }
+
+                if idx < cursor {
+                    continue;
+                }
+
+                if lines.len() == limit {
+                    next_cursor = Some(idx);
+                    break;
+                }
+
+                if filter.matches(&line, &header) {
+                    lines.push(line);
+                }
+            }
 
             Ok((lines, next_cursor))
         })
@@ -1,5 +1,6 @@
 
 use anyhow::Result;
+use crate::core::persistence::logs::log_filter::LogLineFilter;
 use crate::core::persistence::logs::log_fs_adapter::LogFsAdapter;
 
 pub trait LogRepository: Send + Sync {
@@ -15,9 +16,10 @@ pub trait LogRepository: Send + Sync {
         date: &str,
         cursor: usize,
         limit: usize,
+        filter: LogLineFilter,
     ) -> Result<(Vec<String>, Option<usize>)> {
         self.fs()
-            .get_system_log_lines(date, cursor, limit)
+            .get_system_log_lines(date, cursor, limit, filter)
             .await
     }
 }
@@ -1,2 +1,3 @@
+pub mod log_filter;
 pub mod log_fs_adapter;
 pub mod log_repository;
\ No newline at end of file
@@ -0,0 +1,36 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryDto;
+
+/// One pod's cost for one UTC day, priced on the usage basis. Carries the
+/// attribution fields the summary filters match on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPodDayCostEntity {
+    pub pod_uid: String,
+    #[serde(default)]
+    pub pod_name: Option<String>,
+    #[serde(default)]
+    pub namespace: Option<String>,
+    #[serde(default)]
+    pub workload_kind: Option<String>,
+    #[serde(default)]
+    pub workload_name: Option<String>,
+    #[serde(default)]
+    pub team: Option<String>,
+    #[serde(default)]
+    pub service: Option<String>,
+    #[serde(default)]
+    pub env: Option<String>,
+    pub running_hours: f64,
+    pub cost: MetricCostSummaryDto,
+}
+
+/// Every pod cost of one UTC day. Rewritten whole when the day is materialized
+/// again (backfill, back-dated price change).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCostAllocationDayEntity {
+    pub date: NaiveDate,
+    pub materialized_at: DateTime<Utc>,
+    pub pods: Vec<MetricPodDayCostEntity>,
+}
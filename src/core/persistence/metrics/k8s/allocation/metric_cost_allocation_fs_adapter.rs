@@ -0,0 +1,75 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::fs;
+
+use crate::core::persistence::metrics::k8s::allocation::metric_cost_allocation_entity::MetricCostAllocationDayEntity;
+use crate::core::persistence::metrics::k8s::path::{metric_k8s_allocation_dir_path, metric_k8s_allocation_file_path};
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// One JSON file per day in `metric/k8s/allocation/YYYY-MM-DD.json`.
+#[derive(Default)]
+pub struct MetricCostAllocationFsAdapter;
+
+impl MetricCostAllocationFsAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// `None` while the day hasn't been materialized.
+    pub fn read(&self, date: NaiveDate) -> Result<Option<MetricCostAllocationDayEntity>> {
+        blocking_io(|| {
+            let path = metric_k8s_allocation_file_path(&date.format("%Y-%m-%d").to_string());
+            if !path.exists() {
+                return Ok(None);
+            }
+
+            let raw = fs::read_to_string(&path).context("Failed to read cost allocation file")?;
+            Ok(Some(serde_json::from_str(&raw).context("Failed to parse cost allocation file")?))
+        })
+    }
+
+    pub fn exists(&self, date: NaiveDate) -> bool {
+        metric_k8s_allocation_file_path(&date.format("%Y-%m-%d").to_string()).exists()
+    }
+
+    /// Replaces the day's file atomically.
+    pub fn write(&self, day: &MetricCostAllocationDayEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = metric_k8s_allocation_file_path(&day.date.format("%Y-%m-%d").to_string());
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create cost allocation directory")?;
+            }
+
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string(day)?).context("Failed to write cost allocation file")?;
+            fs::rename(&tmp, &path).context("Failed to finalize cost allocation file")?;
+            Ok(())
+        })
+    }
+
+    /// Drops the days from `from` on, so they are priced from raw points again
+    /// until re-materialized. Returns the number of files removed.
+    pub fn remove_from(&self, from: NaiveDate) -> Result<usize> {
+        blocking_io(|| {
+            let dir = metric_k8s_allocation_dir_path();
+            if !dir.exists() {
+                return Ok(0);
+            }
+
+            let from = from.format("%Y-%m-%d").to_string();
+            let mut removed = 0;
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                    continue;
+                }
+                let stale = path.file_stem().and_then(|s| s.to_str()).is_some_and(|s| s >= from.as_str());
+                if stale {
+                    fs::remove_file(&path)?;
+                    removed += 1;
+                }
+            }
+            Ok(removed)
+        })
+    }
+}
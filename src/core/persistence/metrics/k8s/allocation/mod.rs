@@ -0,0 +1,5 @@
+//! Materialized cost allocation: every pod's cost per closed UTC day, written
+//! nightly so summaries of past days don't re-price raw points.
+
+pub mod metric_cost_allocation_entity;
+pub mod metric_cost_allocation_fs_adapter;
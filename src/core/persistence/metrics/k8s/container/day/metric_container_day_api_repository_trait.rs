@@ -1,11 +1,11 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 
 /// Repository trait for reading container day metrics (API layer).
 pub trait MetricContainerDayApiRepository: Send + Sync {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity>;
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity>;
 
     fn get_column_between(
         &self,
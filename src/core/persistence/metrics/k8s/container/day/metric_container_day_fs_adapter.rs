@@ -1,12 +1,14 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+use crate::core::persistence::metrics::metric_column::{
+    format_metric_row, metric_header, parse_column, parse_metric_row, MetricColumn,
+};
+use crate::core::persistence::metrics::metric_partition_writer::MetricPartitionWriter;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, NaiveDate, Utc, Datelike};
-use std::io::BufWriter;
 use std::{
     fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
+    fs,
     io::{BufRead, BufReader},
     path::Path,
 };
@@ -35,104 +37,13 @@ impl MetricContainerDayFsAdapter {
         metric_k8s_container_key_day_file_path(node_key, &year_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricContainerEntity> {
-        let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
-            return None;
-        }
-
-        // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
-        let time = parts[0].parse::<DateTime<Utc>>().ok()?;
-        Some(MetricContainerEntity {
-            time,
-            cpu_usage_nano_cores: parts[1].parse().ok(),
-            cpu_usage_core_nano_seconds: parts[2].parse().ok(),
-            memory_usage_bytes: parts[3].parse().ok(),
-            memory_working_set_bytes: parts[4].parse().ok(),
-            memory_rss_bytes: parts[5].parse().ok(),
-            memory_page_faults: parts[6].parse().ok(),
-            fs_used_bytes: parts[7].parse().ok(),
-            fs_capacity_bytes: parts[8].parse().ok(),
-            fs_inodes_used: parts[9].parse().ok(),
-            fs_inodes: parts[10].parse().ok(),
-        })
-    }
-
-    // fn ensure_header(file: &mut File) -> Result<()> {
-    //     if file.metadata()?.len() == 0 {
-    //         let header = "TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|MEMORY_USAGE_BYTES|MEMORY_WORKING_SET_BYTES|MEMORY_RSS_BYTES|MEMORY_PAGE_FAULTS|NETWORK_PHYSICAL_RX_BYTES|NETWORK_PHYSICAL_TX_BYTES|NETWORK_PHYSICAL_RX_ERRORS|NETWORK_PHYSICAL_TX_ERRORS|ES_USED_BYTES|ES_CAPACITY_BYTES|ES_INODES_USED|ES_INODES|PV_USED_BYTES|PV_CAPACITY_BYTES|PV_INODES_USED|PV_INODES\n";
-    //         file.write_all(header.as_bytes())?;
-    //     }
-    //     Ok(())
-    // }
-
-
-    fn opt(v: Option<u64>) -> String {
-        v.map(|x| x.to_string()).unwrap_or_default()
-    }
-}
-
-impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter {
-    fn append_row(&self, container: &str, dto: &MetricContainerEntity, now: DateTime<Utc>) -> Result<()> {
-        let now_date = now.date_naive();
-        let path_str = self.build_path_for(container, now_date);
-        let path = Path::new(&path_str);
-
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
-        // Write header if file newly created
-        // if new {
-        //     self.ensure_header(path, &mut writer)?;
-        // }
-
-        // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            // --- FS fields (rootfs + logs) ---
-            Self::opt(dto.fs_used_bytes),
-            Self::opt(dto.fs_capacity_bytes),
-            Self::opt(dto.fs_inodes_used),
-            Self::opt(dto.fs_inodes),
-        );
-
-
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
-        Ok(())
-    }
-
-    /// Aggregate hour-level metrics into an dayly sample and append to day file.
-    fn append_row_aggregated(
-        &self,
-        container_uid: &str,
-        start: DateTime<Utc>,
+    /// Aggregate hour-level rows into a single day row (timestamp = end of window).
+    /// Storage-independent so every backend produces the same aggregates.
+    pub fn aggregate_rows(
+        rows: Vec<MetricContainerEntity>,
+        _start: DateTime<Utc>,
         end: DateTime<Utc>,
-        now: DateTime<Utc>
-    ) -> Result<()> {
-        let hour_adapter = MetricContainerHourFsAdapter;
-        let rows = hour_adapter.get_row_between(start, end, container_uid, None, None)?;
-
+    ) -> Result<MetricContainerEntity> {
         if rows.is_empty() {
             return Err(anyhow!("no hour data found for aggregation"));
         }
@@ -196,13 +107,101 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
             fs_inodes:                       last.fs_inodes,
         };
 
+        Ok(aggregated)
+    }
+
+    /// Rows of `object_name` between `start` and `end`, each line parsed by `parse`.
+    fn read_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+        parse: &dyn Fn(&[&str], &str) -> Option<MetricContainerEntity>,
+    ) -> Result<Vec<MetricContainerEntity>> {
+        let header = metric_header::<MetricContainerEntity>();
+
+        let mut data = Vec::new();
+        let mut current_date = start.naive_utc().date();
+        let end_date = end.naive_utc().date();
+
+        // ✅ Iterate over each *year* that overlaps the range
+        while current_date.year() <= end_date.year() {
+            let path = self.build_path_for(object_name, current_date);
+            let path_obj = Path::new(&path);
+
+            if !path_obj.exists() {
+                current_date = NaiveDate::from_ymd_opt(current_date.year() + 1, 1, 1)
+                    .unwrap_or(current_date);
+                continue;
+            }
+
+            if let Ok(file) = File::open(&path_obj) {
+                let reader = BufReader::new(file);
+                for line_result in reader.lines() {
+                    let line = match line_result {
+                        Ok(ref l) if !l.trim().is_empty() => l,
+                        _ => continue,
+                    };
+                    if let Some(row) = parse(&header, line) {
+                        if row.time < start {
+                            continue;
+                        }
+                        if row.time > end {
+                            break;
+                        }
+                        data.push(row);
+                    }
+                }
+            }
+
+            // move to next year
+            current_date = NaiveDate::from_ymd_opt(current_date.year() + 1, 1, 1)
+                .unwrap_or(current_date);
+        }
+
+        // ✅ Sort and paginate
+        data.sort_by_key(|r| r.time);
+        let start_idx = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(data.len());
+        let paginated: Vec<_> = data.into_iter().skip(start_idx).take(limit).collect();
+
+        Ok(paginated)
+    }
+}
+
+impl MetricStore<MetricContainerEntity> for MetricContainerDayFsAdapter {
+    fn append_row(&self, container: &str, dto: &MetricContainerEntity, now: DateTime<Utc>) -> Result<()> {
+        let now_date = now.date_naive();
+        let path_str = self.build_path_for(container, now_date);
+        let path = Path::new(&path_str);
+
+        let row = format_metric_row(dto);
+
+        // Replaces an existing row with the same timestamp, so aggregation replays stay idempotent
+        MetricPartitionWriter::upsert_row(path, dto.time, &row, None)
+    }
+
+    /// Aggregate hour-level metrics into an dayly sample and append to day file.
+    fn append_row_aggregated(
+        &self,
+        container_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>
+    ) -> Result<()> {
+        let hour_adapter = MetricContainerHourFsAdapter;
+        let rows = hour_adapter.get_row_between(start, end, container_uid, None, None)?;
+
+        let aggregated = Self::aggregate_rows(rows, start, end)?;
+
         // ---- 4️⃣ append row into correct day file
         self.append_row(container_uid, &aggregated, now)?;
 
         Ok(())
     }
 
-
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
         const BATCH_SIZE: usize = 200;
 
@@ -260,8 +259,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         Ok(())
     }
 
-
-
     fn get_column_between(
         &self,
         column_name: &str,
@@ -271,148 +268,10 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<MetricContainerEntity>> {
-        let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricContainerEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        let Some(column) = MetricColumn::of::<MetricContainerEntity>(column_name) else {
+            return self.get_row_between(start, end, object_name, limit, offset);
+        };
+        self.read_between(start, end, object_name, limit, offset, &|header, line| parse_column(header, line, column))
     }
 
     fn get_row_between(
@@ -423,66 +282,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<MetricContainerEntity>> {
-        const HEADER: [&str; 11] = [
-            "TIME",
-            "CPU_USAGE_NANO_CORES",
-            "CPU_USAGE_CORE_NANO_SECONDS",
-            "MEMORY_USAGE_BYTES",
-            "MEMORY_WORKING_SET_BYTES",
-            "MEMORY_RSS_BYTES",
-            "MEMORY_PAGE_FAULTS",
-            "FS_USED_BYTES",
-            "FS_CAPACITY_BYTES",
-            "FS_INODES_USED",
-            "FS_INODES",
-        ];
-
-        let mut data = Vec::new();
-        let mut current_date = start.naive_utc().date();
-        let end_date = end.naive_utc().date();
-
-        // ✅ Iterate over each *year* that overlaps the range
-        while current_date.year() <= end_date.year() {
-            let path = self.build_path_for(object_name, current_date);
-            let path_obj = Path::new(&path);
-
-            if !path_obj.exists() {
-                current_date = NaiveDate::from_ymd_opt(current_date.year() + 1, 1, 1)
-                    .unwrap_or(current_date);
-                continue;
-            }
-
-            if let Ok(file) = File::open(&path_obj) {
-                let reader = BufReader::new(file);
-                for line_result in reader.lines() {
-                    let line = match line_result {
-                        Ok(ref l) if !l.trim().is_empty() => l,
-                        _ => continue,
-                    };
-                    if let Some(row) = Self::parse_line(&HEADER, line) {
-                        if row.time < start {
-                            continue;
-                        }
-                        if row.time > end {
-                            break;
-                        }
-                        data.push(row);
-                    }
-                }
-            }
-
-            // move to next year
-            current_date = NaiveDate::from_ymd_opt(current_date.year() + 1, 1, 1)
-                .unwrap_or(current_date);
-        }
-
-        // ✅ Sort and paginate
-        data.sort_by_key(|r| r.time);
-        let start_idx = offset.unwrap_or(0);
-        let limit = limit.unwrap_or(data.len());
-        let paginated: Vec<_> = data.into_iter().skip(start_idx).take(limit).collect();
-
-        Ok(paginated)
+        self.read_between(start, end, object_name, limit, offset, &parse_metric_row::<MetricContainerEntity>)
     }
 
 }
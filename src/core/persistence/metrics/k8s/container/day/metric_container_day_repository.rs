@@ -1,25 +1,37 @@
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_api_repository_trait::MetricContainerDayApiRepository;
-use crate::core::persistence::metrics::k8s::container::day::metric_container_day_fs_adapter::MetricContainerDayFsAdapter;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_retention_repository_traits::MetricContainerDayRetentionRepository;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
+use crate::domain::common::service::MetricRowRepository;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_processor_repository_trait::MetricContainerDayProcessorRepository;
+use crate::core::persistence::repositories::{repositories, MetricTier};
 
 pub struct MetricContainerDayRepository {
-    adapter: MetricContainerDayFsAdapter,
+    adapter: Box<dyn MetricStore<MetricContainerEntity>>,
 }
 
 impl MetricContainerDayRepository {
     pub fn new() -> Self {
         Self {
-            adapter: MetricContainerDayFsAdapter,
+            adapter: repositories().metric_stores.container(MetricTier::Day),
         }
     }
 }
 
+impl MetricRowRepository<MetricContainerEntity> for MetricContainerDayRepository {
+    fn get_row_between(
+        &self,
+        object_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<MetricContainerEntity>> {
+        MetricContainerDayApiRepository::get_row_between(self, start, end, object_name, None, None)
+    }
+}
+
 impl Default for MetricContainerDayRepository {
     fn default() -> Self {
         Self::new()
@@ -27,8 +39,8 @@ impl Default for MetricContainerDayRepository {
 }
 
 impl MetricContainerDayApiRepository for MetricContainerDayRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 
     fn get_row_between(
@@ -49,8 +61,8 @@ impl MetricContainerDayApiRepository for MetricContainerDayRepository {
 }
 
 impl MetricContainerDayRetentionRepository for MetricContainerDayRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
@@ -62,8 +74,8 @@ impl MetricContainerDayRetentionRepository for MetricContainerDayRepository {
 }
 
 impl MetricContainerDayProcessorRepository for MetricContainerDayRepository  {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 
     fn append_row_aggregated(&self, container_uid: &str, start: DateTime<Utc>, end: DateTime<Utc>, now: DateTime<Utc>) -> anyhow::Result<()> {
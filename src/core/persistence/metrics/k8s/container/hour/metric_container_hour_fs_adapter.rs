@@ -1,13 +1,16 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+use crate::core::persistence::metrics::metric_column::{
+    format_metric_row, metric_header, parse_column, parse_metric_row, MetricColumn,
+};
+use crate::core::persistence::metrics::metric_partition_writer::MetricPartitionWriter;
+use crate::core::persistence::metrics::metric_partition_index::MetricPartitionIndex;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, NaiveDate, Datelike, Utc};
-use std::io::BufWriter;
 use std::{
     fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    fs,
+    io::{BufRead, BufReader, Seek, SeekFrom},
     path::Path,
 };
 use std::path::PathBuf;
@@ -39,7 +42,10 @@ impl MetricContainerHourFsAdapter {
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
             match fs::remove_file(path) {
-                Ok(_) => tracing::info!("Deleted old metric file {:?}", path),
+                Ok(_) => {
+                    tracing::info!("Deleted old metric file {:?}", path);
+                    MetricPartitionIndex::remove(path);
+                }
                 Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
             }
         }
@@ -51,105 +57,13 @@ impl MetricContainerHourFsAdapter {
         metric_k8s_container_key_hour_file_path(container_key, &month_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricContainerEntity> {
-        let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
-            return None;
-        }
-
-        // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
-        let time = parts[0].parse::<DateTime<Utc>>().ok()?;
-        Some(MetricContainerEntity {
-            time,
-            cpu_usage_nano_cores: parts[1].parse().ok(),
-            cpu_usage_core_nano_seconds: parts[2].parse().ok(),
-            memory_usage_bytes: parts[3].parse().ok(),
-            memory_working_set_bytes: parts[4].parse().ok(),
-            memory_rss_bytes: parts[5].parse().ok(),
-            memory_page_faults: parts[6].parse().ok(),
-            fs_used_bytes: parts[7].parse().ok(),
-            fs_capacity_bytes: parts[8].parse().ok(),
-            fs_inodes_used: parts[9].parse().ok(),
-            fs_inodes: parts[10].parse().ok(),
-        })
-    }
-
-    // fn ensure_header(file: &mut File) -> Result<()> {
-    //     if file.metadata()?.len() == 0 {
-    //         let header = "TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|MEMORY_USAGE_BYTES|MEMORY_WORKING_SET_BYTES|MEMORY_RSS_BYTES|MEMORY_PAGE_FAULTS|NETWORK_PHYSICAL_RX_BYTES|NETWORK_PHYSICAL_TX_BYTES|NETWORK_PHYSICAL_RX_ERRORS|NETWORK_PHYSICAL_TX_ERRORS|ES_USED_BYTES|ES_CAPACITY_BYTES|ES_INODES_USED|ES_INODES|PV_USED_BYTES|PV_CAPACITY_BYTES|PV_INODES_USED|PV_INODES\n";
-    //         file.write_all(header.as_bytes())?;
-    //     }
-    //     Ok(())
-    // }
-
-
-    fn opt(v: Option<u64>) -> String {
-        v.map(|x| x.to_string()).unwrap_or_default()
-    }
-}
-
-impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter {
-    fn append_row(&self, container: &str, dto: &MetricContainerEntity, now: DateTime<Utc>) -> Result<()> {
-        let now_date = now.date_naive();
-        let path_str = self.build_path_for(container, now_date);
-        let path = Path::new(&path_str);
-
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
-        // Write header if file newly created
-        // if new {
-        //     self.ensure_header(path, &mut writer)?;
-        // }
-
-        // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            // --- FS fields (rootfs + logs) ---
-            Self::opt(dto.fs_used_bytes),
-            Self::opt(dto.fs_capacity_bytes),
-            Self::opt(dto.fs_inodes_used),
-            Self::opt(dto.fs_inodes),
-        );
-
-
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
-        Ok(())
-    }
-
-    /// Aggregate minute-level metrics into an hour sample and append to hour file.
-    fn append_row_aggregated(
-        &self,
-        container_uid: &str,
-        start: DateTime<Utc>,
+    /// Aggregate minute-level rows into a single hour row (timestamp = end of window).
+    /// Storage-independent so every backend produces the same aggregates.
+    pub fn aggregate_rows(
+        rows: Vec<MetricContainerEntity>,
+        _start: DateTime<Utc>,
         end: DateTime<Utc>,
-        now: DateTime<Utc>
-    ) -> Result<()> {
-        // --- 1️⃣ Load minute data
-        let minute_adapter = MetricContainerMinuteFsAdapter;
-        let rows = minute_adapter.get_row_between(start, end, container_uid, None, None)?;
-
+    ) -> Result<MetricContainerEntity> {
         if rows.is_empty() {
             return Err(anyhow!("no minute data found for aggregation"));
         }
@@ -195,235 +109,18 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
             fs_inodes: last.fs_inodes,
         };
 
-        // --- 3️⃣ Append the aggregated row into the hour-level file
-        self.append_row(container_uid, &aggregated, now)?;
-
-        Ok(())
-    }
-
-    fn cleanup_old(&self, container_uid: &str, before: DateTime<Utc>) -> Result<()> {
-        const BATCH_SIZE: usize = 200;
-
-        let dir = metric_k8s_container_key_hour_dir_path(container_uid);
-        if !dir.exists() {
-            return Ok(());
-        }
-
-        // Normalize cutoff to YYYY-MM-01
-        let before_month = NaiveDate::from_ymd_opt(before.year(), before.month(), 1)
-            .ok_or_else(|| anyhow!("invalid 'before' month ({}-{})", before.year(), before.month()))?;
-
-        let mut batch = Vec::with_capacity(BATCH_SIZE);
-
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Only process `.rcd` files
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
-                continue;
-            }
-
-            // Extract stem safely
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
-                Some(s) => s.trim(),
-                None => {
-                    tracing::warn!("Skipping file with invalid UTF-8 filename: {:?}", path);
-                    continue;
-                }
-            };
-
-            // Parse YYYY-MM
-            let file_month = match Self::parse_year_month(stem) {
-                Some(date) => date,
-                None => {
-                    tracing::warn!("Skipping invalid hour filename '{}'", stem);
-                    continue;
-                }
-            };
-
-            // Apply retention
-            if file_month < before_month {
-                batch.push(path);
-
-                // Process batch
-                if batch.len() >= BATCH_SIZE {
-                    Self::delete_batch(&batch)?;
-                    batch.clear();
-                }
-            }
-        }
-
-        // Flush remaining items
-        if !batch.is_empty() {
-            Self::delete_batch(&batch)?;
-        }
-
-        Ok(())
-    }
-
-
-
-    fn get_column_between(
-        &self,
-        column_name: &str,
-        start: DateTime<Utc>,
-        end: DateTime<Utc>,
-        object_name: &str,
-        limit: Option<usize>,
-        offset: Option<usize>,
-    ) -> Result<Vec<MetricContainerEntity>> {
-        let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricContainerEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        Ok(aggregated)
     }
 
-    fn get_row_between(
+    /// Rows of `object_name` between `start` and `end`, each line parsed by `parse`.
+    fn read_between(
         &self,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
         object_name: &str,
         limit: Option<usize>,
         offset: Option<usize>,
+        parse: &dyn Fn(&[&str], &str) -> Option<MetricContainerEntity>,
     ) -> Result<Vec<MetricContainerEntity>> {
         use chrono::Months;
 
@@ -451,7 +148,13 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
                 }
             };
 
-            let reader = BufReader::new(file);
+            let mut reader = BufReader::new(file);
+
+            // Skip rows before the requested day when the partition has an index
+            let start_bucket = start.format("%Y-%m-%d").to_string();
+            if let Some(pos) = MetricPartitionIndex::seek_offset(&path_obj, &start_bucket) {
+                reader.seek(SeekFrom::Start(pos))?;
+            }
             let mut lines = reader.lines();
 
             // Handle empty files
@@ -470,14 +173,9 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
             // 2️⃣ Handle header or first data line
             if first_line.starts_with("20") {
                 // Default header assumption (timestamp-first)
-                header = vec![
-                    "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
-                    "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
-                    "MEMORY_PAGE_FAULTS", "FS_USED_BYTES", "FS_CAPACITY_BYTES",
-                    "FS_INODES_USED", "FS_INODES",
-                ];
-
-                if let Some(row) = Self::parse_line(&header, &first_line) {
+                header = metric_header::<MetricContainerEntity>();
+
+                if let Some(row) = parse(&header, &first_line) {
                     if row.time >= start && row.time <= end {
                         rows.push(row);
                     }
@@ -493,7 +191,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
                     _ => continue,
                 };
 
-                if let Some(row) = Self::parse_line(&header, &line) {
+                if let Some(row) = parse(&header, &line) {
                     if row.time < start {
                         continue;
                     }
@@ -518,5 +216,125 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
 
         Ok(slice)
     }
+}
+
+impl MetricStore<MetricContainerEntity> for MetricContainerHourFsAdapter {
+    fn append_row(&self, container: &str, dto: &MetricContainerEntity, now: DateTime<Utc>) -> Result<()> {
+        let now_date = now.date_naive();
+        let path_str = self.build_path_for(container, now_date);
+        let path = Path::new(&path_str);
+
+        let row = format_metric_row(dto);
+
+        // Replaces an existing row with the same timestamp, so aggregation replays stay idempotent
+        MetricPartitionWriter::upsert_row(path, dto.time, &row, Some("%Y-%m-%d"))
+    }
+
+    /// Aggregate minute-level metrics into an hour sample and append to hour file.
+    fn append_row_aggregated(
+        &self,
+        container_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>
+    ) -> Result<()> {
+        // --- 1️⃣ Load minute data
+        let minute_adapter = MetricContainerMinuteFsAdapter;
+        let rows = minute_adapter.get_row_between(start, end, container_uid, None, None)?;
+
+        let aggregated = Self::aggregate_rows(rows, start, end)?;
+
+        // --- 3️⃣ Append the aggregated row into the hour-level file
+        self.append_row(container_uid, &aggregated, now)?;
+
+        Ok(())
+    }
+
+    fn cleanup_old(&self, container_uid: &str, before: DateTime<Utc>) -> Result<()> {
+        const BATCH_SIZE: usize = 200;
+
+        let dir = metric_k8s_container_key_hour_dir_path(container_uid);
+        if !dir.exists() {
+            return Ok(());
+        }
+
+        // Normalize cutoff to YYYY-MM-01
+        let before_month = NaiveDate::from_ymd_opt(before.year(), before.month(), 1)
+            .ok_or_else(|| anyhow!("invalid 'before' month ({}-{})", before.year(), before.month()))?;
+
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            // Only process `.rcd` files
+            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+                continue;
+            }
+
+            // Extract stem safely
+            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+                Some(s) => s.trim(),
+                None => {
+                    tracing::warn!("Skipping file with invalid UTF-8 filename: {:?}", path);
+                    continue;
+                }
+            };
+
+            // Parse YYYY-MM
+            let file_month = match Self::parse_year_month(stem) {
+                Some(date) => date,
+                None => {
+                    tracing::warn!("Skipping invalid hour filename '{}'", stem);
+                    continue;
+                }
+            };
+
+            // Apply retention
+            if file_month < before_month {
+                batch.push(path);
+
+                // Process batch
+                if batch.len() >= BATCH_SIZE {
+                    Self::delete_batch(&batch)?;
+                    batch.clear();
+                }
+            }
+        }
+
+        // Flush remaining items
+        if !batch.is_empty() {
+            Self::delete_batch(&batch)?;
+        }
+
+        Ok(())
+    }
+
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricContainerEntity>> {
+        let Some(column) = MetricColumn::of::<MetricContainerEntity>(column_name) else {
+            return self.get_row_between(start, end, object_name, limit, offset);
+        };
+        self.read_between(start, end, object_name, limit, offset, &|header, line| parse_column(header, line, column))
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricContainerEntity>> {
+        self.read_between(start, end, object_name, limit, offset, &parse_metric_row::<MetricContainerEntity>)
+    }
 
 }
@@ -1,24 +1,36 @@
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_api_repository_trait::MetricContainerHourApiRepository;
-use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_fs_adapter::MetricContainerHourFsAdapter;
 use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_retention_repository_traits::MetricContainerHourRetentionRepository;
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
+use crate::domain::common::service::MetricRowRepository;
+use crate::core::persistence::repositories::{repositories, MetricTier};
 
 pub struct MetricContainerHourRepository {
-    adapter: MetricContainerHourFsAdapter,
+    adapter: Box<dyn MetricStore<MetricContainerEntity>>,
 }
 
 impl MetricContainerHourRepository {
     pub fn new() -> Self {
         Self {
-            adapter: MetricContainerHourFsAdapter,
+            adapter: repositories().metric_stores.container(MetricTier::Hour),
         }
     }
 }
 
+impl MetricRowRepository<MetricContainerEntity> for MetricContainerHourRepository {
+    fn get_row_between(
+        &self,
+        object_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<MetricContainerEntity>> {
+        MetricContainerHourApiRepository::get_row_between(self, start, end, object_name, None, None)
+    }
+}
+
 impl Default for MetricContainerHourRepository {
     fn default() -> Self {
         Self::new()
@@ -26,8 +38,8 @@ impl Default for MetricContainerHourRepository {
 }
 
 impl MetricContainerHourApiRepository for MetricContainerHourRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 
     fn get_row_between(
@@ -48,8 +60,8 @@ impl MetricContainerHourApiRepository for MetricContainerHourRepository {
 }
 
 impl MetricContainerHourRetentionRepository for MetricContainerHourRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
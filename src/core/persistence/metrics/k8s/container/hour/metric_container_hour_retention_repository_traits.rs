@@ -1,11 +1,11 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 
 /// Repository trait for reading container minute metrics (API layer).
 pub trait MetricContainerHourRetentionRepository: Send + Sync {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity>;
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity>;
 
     /// Deletes old metric files for the given container before the cutoff timestamp.
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
@@ -1,4 +1,7 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+use crate::core::persistence::metrics::metric_column::{
+    format_metric_row, metric_header, parse_column, parse_metric_row, MetricColumn,
+};
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::{Result};
 use chrono::{DateTime, NaiveDate, Utc};
@@ -37,43 +40,115 @@ impl MetricContainerMinuteFsAdapter {
         metric_k8s_container_key_minute_file_path(container_key, &date_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricContainerEntity> {
-        let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
-            return None;
+    /// Rows of `object_name` between `start` and `end`, each line parsed by `parse`.
+    fn read_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+        parse: &dyn Fn(&[&str], &str) -> Option<MetricContainerEntity>,
+    ) -> Result<Vec<MetricContainerEntity>> {
+        let mut all_rows = Vec::new();
+
+        // 1️⃣ Iterate day-by-day across the requested range
+        let mut current_date = start.date_naive();
+        let end_date = end.date_naive();
+
+        while current_date <= end_date {
+            let path = self.build_path_for(object_name, current_date);
+            let path_obj = Path::new(&path);
+
+            if !path_obj.exists() {
+                tracing::debug!("Minute metrics file missing for {} on {}", object_name, current_date);
+                current_date = current_date.succ_opt().unwrap_or(current_date);
+                continue;
+            }
+
+            // Safely open file
+            let file = match File::open(&path_obj) {
+                Ok(f) => f,
+                Err(e) => {
+                    tracing::warn!("Cannot open {:?}: {}", path_obj, e);
+                    current_date = current_date.succ_opt().unwrap_or(current_date);
+                    continue;
+                }
+            };
+
+            let reader = BufReader::new(file);
+            let mut lines = reader.lines();
+
+            // Skip empty files
+            let first_line = match lines.next() {
+                Some(Ok(line)) => line,
+                _ => {
+                    tracing::debug!("Empty metric file for {} on {}", object_name, current_date);
+                    current_date = current_date.succ_opt().unwrap_or(current_date);
+                    continue;
+                }
+            };
+
+            // 2️⃣ Handle header vs. data
+            let header: Vec<&str>;
+            let mut rows: Vec<MetricContainerEntity> = vec![];
+
+            if first_line.starts_with("20") {
+                // Treat as data (no header)
+                header = metric_header::<MetricContainerEntity>();
+
+                if let Some(row) = parse(&header, &first_line) {
+                    if row.time >= start && row.time <= end {
+                        rows.push(row);
+                    }
+                }
+            } else {
+                header = first_line.split('|').collect();
+            }
+
+            // 3️⃣ Process remaining lines safely
+            for line_result in lines {
+                let line = match line_result {
+                    Ok(l) if !l.trim().is_empty() => l,
+                    _ => continue,
+                };
+
+                if let Some(row) = parse(&header, &line) {
+                    if row.time < start {
+                        continue;
+                    }
+                    if row.time > end {
+                        break;
+                    }
+                    rows.push(row);
+                } else {
+                    tracing::warn!("Malformed line skipped in {:?}: {}", path_obj, line);
+                }
+            }
+
+            all_rows.extend(rows);
+            current_date = current_date.succ_opt().unwrap_or(current_date);
         }
 
-        // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
-        let time = parts[0].parse::<DateTime<Utc>>().ok()?;
-        Some(MetricContainerEntity {
-            time,
-            cpu_usage_nano_cores: parts[1].parse().ok(),
-            cpu_usage_core_nano_seconds: parts[2].parse().ok(),
-            memory_usage_bytes: parts[3].parse().ok(),
-            memory_working_set_bytes: parts[4].parse().ok(),
-            memory_rss_bytes: parts[5].parse().ok(),
-            memory_page_faults: parts[6].parse().ok(),
-            fs_used_bytes: parts[7].parse().ok(),
-            fs_capacity_bytes: parts[8].parse().ok(),
-            fs_inodes_used: parts[9].parse().ok(),
-            fs_inodes: parts[10].parse().ok(),
-        })
-    }
+        // 4️⃣ Sort and paginate
+        all_rows.sort_by_key(|r| r.time);
+        let start_idx = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(all_rows.len());
+        let paginated = all_rows.into_iter().skip(start_idx).take(limit).collect::<Vec<_>>();
 
-    // fn ensure_header(&self, path: &Path, file: &mut std::fs::File) -> Result<()> {
-    //     if !path.exists() {
-    //         let header = "TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|MEMORY_USAGE_BYTES|MEMORY_WORKING_SET_BYTES|MEMORY_RSS_BYTES|MEMORY_PAGE_FAULTS|NETWORK_PHYSICAL_RX_BYTES|NETWORK_PHYSICAL_TX_BYTES|NETWORK_PHYSICAL_RX_ERRORS|NETWORK_PHYSICAL_TX_ERRORS|FS_USED_BYTES|FS_CAPACITY_BYTES|FS_ICONTAINERS_USED|FS_ICONTAINERS\n";
-    //         file.write_all(header.as_bytes())?;
-    //     }
-    //     Ok(())
-    // }
+        tracing::debug!(
+        "Returning {} rows for {} between {} and {}",
+        paginated.len(),
+        object_name,
+        start,
+        end
+    );
 
-    fn opt(v: Option<u64>) -> String {
-        v.map(|x| x.to_string()).unwrap_or_default()
+        Ok(paginated)
     }
 }
 
-impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapter {
+impl MetricStore<MetricContainerEntity> for MetricContainerMinuteFsAdapter {
     fn append_row(&self, container: &str, dto: &MetricContainerEntity, now: DateTime<Utc>) -> Result<()> {
         let now_date = now.date_naive();
         let path_str = self.build_path_for(container, now_date);
@@ -83,8 +158,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
             fs::create_dir_all(parent)?;
         }
 
-        // let new = !path.exists();
-
         // ✅ open file and wrap in BufWriter
         let file = OpenOptions::new()
             .create(true)
@@ -92,27 +165,8 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
             .open(&path)?;
         let mut writer = BufWriter::new(file);
 
-        // Write header if file newly created
-        // if new {
-        //     self.ensure_header(path, &mut writer)?;
-        // }
-
         // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            // --- FS fields (rootfs + logs) ---
-            Self::opt(dto.fs_used_bytes),
-            Self::opt(dto.fs_capacity_bytes),
-            Self::opt(dto.fs_inodes_used),
-            Self::opt(dto.fs_inodes),
-        );
+        let row = format_metric_row(dto);
 
         // ✅ write to buffer
         writer.write_all(row.as_bytes())?;
@@ -183,7 +237,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
         Ok(())
     }
 
-
     fn get_row_between(
         &self,
         start: DateTime<Utc>,
@@ -192,106 +245,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<MetricContainerEntity>> {
-        let mut all_rows = Vec::new();
-
-        // 1️⃣ Iterate day-by-day across the requested range
-        let mut current_date = start.date_naive();
-        let end_date = end.date_naive();
-
-        while current_date <= end_date {
-            let path = self.build_path_for(object_name, current_date);
-            let path_obj = Path::new(&path);
-
-            if !path_obj.exists() {
-                tracing::debug!("Minute metrics file missing for {} on {}", object_name, current_date);
-                current_date = current_date.succ_opt().unwrap_or(current_date);
-                continue;
-            }
-
-            // Safely open file
-            let file = match File::open(&path_obj) {
-                Ok(f) => f,
-                Err(e) => {
-                    tracing::warn!("Cannot open {:?}: {}", path_obj, e);
-                    current_date = current_date.succ_opt().unwrap_or(current_date);
-                    continue;
-                }
-            };
-
-            let reader = BufReader::new(file);
-            let mut lines = reader.lines();
-
-            // Skip empty files
-            let first_line = match lines.next() {
-                Some(Ok(line)) => line,
-                _ => {
-                    tracing::debug!("Empty metric file for {} on {}", object_name, current_date);
-                    current_date = current_date.succ_opt().unwrap_or(current_date);
-                    continue;
-                }
-            };
-
-            // 2️⃣ Handle header vs. data
-            let header: Vec<&str>;
-            let mut rows: Vec<MetricContainerEntity> = vec![];
-
-            if first_line.starts_with("20") {
-                // Treat as data (no header)
-                header = vec![
-                    "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
-                    "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
-                    "MEMORY_PAGE_FAULTS", "FS_USED_BYTES", "FS_CAPACITY_BYTES",
-                    "FS_INODES_USED", "FS_INODES"
-                ];
-
-                if let Some(row) = Self::parse_line(&header, &first_line) {
-                    if row.time >= start && row.time <= end {
-                        rows.push(row);
-                    }
-                }
-            } else {
-                header = first_line.split('|').collect();
-            }
-
-            // 3️⃣ Process remaining lines safely
-            for line_result in lines {
-                let line = match line_result {
-                    Ok(l) if !l.trim().is_empty() => l,
-                    _ => continue,
-                };
-
-                if let Some(row) = Self::parse_line(&header, &line) {
-                    if row.time < start {
-                        continue;
-                    }
-                    if row.time > end {
-                        break;
-                    }
-                    rows.push(row);
-                } else {
-                    tracing::warn!("Malformed line skipped in {:?}: {}", path_obj, line);
-                }
-            }
-
-            all_rows.extend(rows);
-            current_date = current_date.succ_opt().unwrap_or(current_date);
-        }
-
-        // 4️⃣ Sort and paginate
-        all_rows.sort_by_key(|r| r.time);
-        let start_idx = offset.unwrap_or(0);
-        let limit = limit.unwrap_or(all_rows.len());
-        let paginated = all_rows.into_iter().skip(start_idx).take(limit).collect::<Vec<_>>();
-
-        tracing::debug!(
-        "Returning {} rows for {} between {} and {}",
-        paginated.len(),
-        object_name,
-        start,
-        end
-    );
-
-        Ok(paginated)
+        self.read_between(start, end, object_name, limit, offset, &parse_metric_row::<MetricContainerEntity>)
     }
 
     fn get_column_between(
@@ -303,147 +257,9 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<MetricContainerEntity>> {
-        let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricContainerEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        let Some(column) = MetricColumn::of::<MetricContainerEntity>(column_name) else {
+            return self.get_row_between(start, end, object_name, limit, offset);
+        };
+        self.read_between(start, end, object_name, limit, offset, &|header, line| parse_column(header, line, column))
     }
 }
@@ -1,23 +1,23 @@
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_api_repository_trait::MetricContainerMinuteApiRepository;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_collector_repository_trait::MetricContainerMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_processor_repository_trait::MetricContainerMinuteProcessorRepository;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_retention_repository_traits::MetricContainerMinuteRetentionRepository;
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
+use crate::core::persistence::repositories::{repositories, MetricTier};
 
 /// Repository for container minute metrics that bridges the traits and FS adapter.
 pub struct MetricContainerMinuteRepository {
-    adapter: MetricContainerMinuteFsAdapter,
+    adapter: Box<dyn MetricStore<MetricContainerEntity>>,
 }
 
 impl MetricContainerMinuteRepository {
     pub fn new() -> Self {
         Self {
-            adapter: MetricContainerMinuteFsAdapter,
+            adapter: repositories().metric_stores.container(MetricTier::Minute),
         }
     }
 }
@@ -29,8 +29,8 @@ impl Default for MetricContainerMinuteRepository {
 }
 
 impl MetricContainerMinuteApiRepository for MetricContainerMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 
     fn get_row_between(
@@ -51,8 +51,8 @@ impl MetricContainerMinuteApiRepository for MetricContainerMinuteRepository {
 }
 
 impl MetricContainerMinuteCollectorRepository for MetricContainerMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 
     fn append_row(&self, container_key: &str, data: &MetricContainerEntity, now: DateTime<Utc>) -> Result<()> {
@@ -64,14 +64,14 @@ impl MetricContainerMinuteCollectorRepository for MetricContainerMinuteRepositor
 }
 
 impl MetricContainerMinuteProcessorRepository for MetricContainerMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 }
 
 impl MetricContainerMinuteRetentionRepository for MetricContainerMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
@@ -1,16 +1,15 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_retention_repository_traits::MetricContainerMinuteRetentionRepository;
 
 pub struct MetricContainerMinuteRetentionRepositoryImpl {
-    pub adapter: MetricContainerMinuteFsAdapter,
+    pub adapter: Box<dyn MetricStore<MetricContainerEntity>>,
 }
 
 impl MetricContainerMinuteRetentionRepository for MetricContainerMinuteRetentionRepositoryImpl  {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
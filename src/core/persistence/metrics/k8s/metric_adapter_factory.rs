@@ -0,0 +1,248 @@
+//! Metric adapter constructors honoring the configured storage backend.
+//!
+//! Repositories and scheduler tasks build their adapters through these functions
+//! instead of naming a concrete FS adapter, so the `storage_backend` setting
+//! switches every reader and writer at once.
+
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+use crate::core::persistence::metrics::metric_column::format_metric_row;
+use crate::core::persistence::metrics::metric_store_traced::traced;
+use crate::core::persistence::metrics::metric_storage_backend::{metric_storage_backend, MetricStorageBackend};
+use crate::core::persistence::metrics::remote::metric_influx_adapter::MetricInfluxAdapter;
+use crate::core::persistence::metrics::sqlite::metric_sqlite_adapter::MetricSqliteAdapter;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_fs_adapter::MetricPodHourFsAdapter;
+use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_fs_adapter::MetricPodDayFsAdapter;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_adapter::MetricNodeMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_fs_adapter::MetricNodeHourFsAdapter;
+use crate::core::persistence::metrics::k8s::node::day::metric_node_day_fs_adapter::MetricNodeDayFsAdapter;
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_fs_adapter::MetricContainerHourFsAdapter;
+use crate::core::persistence::metrics::k8s::container::day::metric_container_day_fs_adapter::MetricContainerDayFsAdapter;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::k8s::pvc::hour::metric_pvc_hour_fs_adapter::MetricPvcHourFsAdapter;
+use crate::core::persistence::metrics::k8s::network_flow::metric_network_flow_entity::MetricNetworkFlowEntity;
+use crate::core::persistence::metrics::k8s::network_flow::hour::metric_network_flow_hour_fs_adapter::MetricNetworkFlowHourFsAdapter;
+use crate::core::persistence::metrics::k8s::month::metric_month_aggregate::{
+    aggregate_container_rows, aggregate_node_rows, aggregate_pod_rows,
+};
+use crate::core::persistence::metrics::k8s::month::metric_month_fs_adapter::{
+    parse_container_month_line, parse_node_month_line, parse_pod_month_line, MetricMonthFsAdapter,
+};
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_key_month_dir_path, metric_k8s_node_key_month_dir_path, metric_k8s_pod_key_month_dir_path,
+};
+
+// --- Pod ---
+pub fn metric_pod_minute_adapter() -> Box<dyn MetricStore<MetricPodEntity>> {
+    traced::<MetricPodEntity>("pod", "minute", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricPodMinuteFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricPodEntity>::new("pod", "minute"),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricPodEntity>::new("pod", "minute"),
+        ),
+    })
+}
+
+pub fn metric_pod_hour_adapter() -> Box<dyn MetricStore<MetricPodEntity>> {
+    traced::<MetricPodEntity>("pod", "hour", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricPodHourFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricPodEntity>::new("pod", "hour")
+                .aggregating("minute", MetricPodHourFsAdapter::aggregate_rows),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricPodEntity>::new("pod", "hour")
+                .aggregating("minute", MetricPodHourFsAdapter::aggregate_rows),
+        ),
+    })
+}
+
+pub fn metric_pod_day_adapter() -> Box<dyn MetricStore<MetricPodEntity>> {
+    traced::<MetricPodEntity>("pod", "day", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricPodDayFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricPodEntity>::new("pod", "day")
+                .aggregating("hour", MetricPodDayFsAdapter::aggregate_rows),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricPodEntity>::new("pod", "day")
+                .aggregating("hour", MetricPodDayFsAdapter::aggregate_rows),
+        ),
+    })
+}
+
+pub fn metric_pod_month_adapter() -> Box<dyn MetricStore<MetricPodEntity>> {
+    traced::<MetricPodEntity>("pod", "month", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricMonthFsAdapter::new(
+            metric_k8s_pod_key_month_dir_path,
+            format_metric_row::<MetricPodEntity>,
+            parse_pod_month_line,
+            metric_pod_day_adapter,
+            aggregate_pod_rows,
+        )),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricPodEntity>::new("pod", "month")
+                .aggregating("day", aggregate_pod_rows),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricPodEntity>::new("pod", "month")
+                .aggregating("day", aggregate_pod_rows),
+        ),
+    })
+}
+
+// --- Node ---
+pub fn metric_node_minute_adapter() -> Box<dyn MetricStore<MetricNodeEntity>> {
+    traced::<MetricNodeEntity>("node", "minute", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricNodeMinuteFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricNodeEntity>::new("node", "minute"),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricNodeEntity>::new("node", "minute"),
+        ),
+    })
+}
+
+pub fn metric_node_hour_adapter() -> Box<dyn MetricStore<MetricNodeEntity>> {
+    traced::<MetricNodeEntity>("node", "hour", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricNodeHourFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricNodeEntity>::new("node", "hour")
+                .aggregating("minute", MetricNodeHourFsAdapter::aggregate_rows),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricNodeEntity>::new("node", "hour")
+                .aggregating("minute", MetricNodeHourFsAdapter::aggregate_rows),
+        ),
+    })
+}
+
+pub fn metric_node_day_adapter() -> Box<dyn MetricStore<MetricNodeEntity>> {
+    traced::<MetricNodeEntity>("node", "day", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricNodeDayFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricNodeEntity>::new("node", "day")
+                .aggregating("hour", MetricNodeDayFsAdapter::aggregate_rows),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricNodeEntity>::new("node", "day")
+                .aggregating("hour", MetricNodeDayFsAdapter::aggregate_rows),
+        ),
+    })
+}
+
+pub fn metric_node_month_adapter() -> Box<dyn MetricStore<MetricNodeEntity>> {
+    traced::<MetricNodeEntity>("node", "month", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricMonthFsAdapter::new(
+            metric_k8s_node_key_month_dir_path,
+            format_metric_row::<MetricNodeEntity>,
+            parse_node_month_line,
+            metric_node_day_adapter,
+            aggregate_node_rows,
+        )),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricNodeEntity>::new("node", "month")
+                .aggregating("day", aggregate_node_rows),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricNodeEntity>::new("node", "month")
+                .aggregating("day", aggregate_node_rows),
+        ),
+    })
+}
+
+// --- Container ---
+pub fn metric_container_minute_adapter() -> Box<dyn MetricStore<MetricContainerEntity>> {
+    traced::<MetricContainerEntity>("container", "minute", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricContainerMinuteFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricContainerEntity>::new("container", "minute"),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricContainerEntity>::new("container", "minute"),
+        ),
+    })
+}
+
+pub fn metric_container_hour_adapter() -> Box<dyn MetricStore<MetricContainerEntity>> {
+    traced::<MetricContainerEntity>("container", "hour", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricContainerHourFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricContainerEntity>::new("container", "hour")
+                .aggregating("minute", MetricContainerHourFsAdapter::aggregate_rows),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricContainerEntity>::new("container", "hour")
+                .aggregating("minute", MetricContainerHourFsAdapter::aggregate_rows),
+        ),
+    })
+}
+
+pub fn metric_container_day_adapter() -> Box<dyn MetricStore<MetricContainerEntity>> {
+    traced::<MetricContainerEntity>("container", "day", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricContainerDayFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricContainerEntity>::new("container", "day")
+                .aggregating("hour", MetricContainerDayFsAdapter::aggregate_rows),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricContainerEntity>::new("container", "day")
+                .aggregating("hour", MetricContainerDayFsAdapter::aggregate_rows),
+        ),
+    })
+}
+
+pub fn metric_container_month_adapter() -> Box<dyn MetricStore<MetricContainerEntity>> {
+    traced::<MetricContainerEntity>("container", "month", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricMonthFsAdapter::new(
+            metric_k8s_container_key_month_dir_path,
+            format_metric_row::<MetricContainerEntity>,
+            parse_container_month_line,
+            metric_container_day_adapter,
+            aggregate_container_rows,
+        )),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricContainerEntity>::new("container", "month")
+                .aggregating("day", aggregate_container_rows),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricContainerEntity>::new("container", "month")
+                .aggregating("day", aggregate_container_rows),
+        ),
+    })
+}
+
+// --- PVC ---
+/// Claims are sampled hourly by the PVC collector, so there is no minute tier to aggregate.
+pub fn metric_pvc_hour_adapter() -> Box<dyn MetricStore<MetricPvcEntity>> {
+    traced::<MetricPvcEntity>("pvc", "hour", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricPvcHourFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricPvcEntity>::new("pvc", "hour"),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricPvcEntity>::new("pvc", "hour"),
+        ),
+    })
+}
+
+// --- Network flows ---
+/// Flows are queried hourly from the flow source, so there is no minute tier either.
+pub fn metric_network_flow_hour_adapter() -> Box<dyn MetricStore<MetricNetworkFlowEntity>> {
+    traced::<MetricNetworkFlowEntity>("network_flow", "hour", match metric_storage_backend() {
+        MetricStorageBackend::Fs => Box::new(MetricNetworkFlowHourFsAdapter),
+        MetricStorageBackend::Sqlite => Box::new(
+            MetricSqliteAdapter::<MetricNetworkFlowEntity>::new("network_flow", "hour"),
+        ),
+        MetricStorageBackend::Influx => Box::new(
+            MetricInfluxAdapter::<MetricNetworkFlowEntity>::new("network_flow", "hour"),
+        ),
+    })
+}
@@ -1,4 +1,9 @@
 pub mod container;
 pub mod node;
 pub mod pod;
+pub mod pvc;
+pub mod network_flow;
+pub mod month;
+pub mod allocation;
 pub mod path;
+pub mod metric_adapter_factory;
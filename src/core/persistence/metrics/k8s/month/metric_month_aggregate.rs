@@ -0,0 +1,199 @@
+//! Day → month aggregation.
+//!
+//! Follows the pod day rule set (see `MetricPodDayFsAdapter::aggregate_rows`,
+//! which the pod month tier reuses): time-weighted average for gauges, sum
+//! for per-window usage, max for capacity. Day rows already carry usage for
+//! their own day, so summing them yields the month's usage.
+//!
+//! Day rows are stamped with the end of their day, so the row at `start`
+//! belongs to the previous month and is dropped before aggregating.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_fs_adapter::MetricPodDayFsAdapter;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::metric_store_trait::MetricStoreRow;
+
+/// Time-weighted average of `f` over `[start, end]`; each sample holds until the next one.
+fn twa<T: MetricStoreRow>(
+    rows: &[T],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    f: impl Fn(&T) -> Option<u64>,
+) -> Option<u64> {
+    let pts: Vec<(DateTime<Utc>, u64)> = rows.iter().filter_map(|r| f(r).map(|v| (r.time(), v))).collect();
+    if pts.is_empty() {
+        return None;
+    }
+
+    let window_ns = (end - start).num_nanoseconds()? as f64;
+    if window_ns <= 0.0 {
+        return Some(pts.last().unwrap().1);
+    }
+
+    let mut area: f64 = 0.0;
+    for i in 0..pts.len() {
+        let (t_i, v_i) = pts[i];
+        let seg_end = if i + 1 < pts.len() { pts[i + 1].0 } else { end };
+
+        let seg_start = std::cmp::max(t_i, start);
+        let seg_end = std::cmp::min(seg_end, end);
+
+        if seg_end > seg_start {
+            area += (v_i as f64) * (seg_end - seg_start).num_nanoseconds()? as f64;
+        }
+    }
+
+    Some((area / window_ns).round() as u64)
+}
+
+fn sum<T>(rows: &[T], f: impl Fn(&T) -> Option<u64>) -> Option<u64> {
+    rows.iter()
+        .filter_map(f)
+        .fold(None, |acc, v| Some(acc.unwrap_or(0u64).saturating_add(v)))
+}
+
+fn max<T>(rows: &[T], f: impl Fn(&T) -> Option<u64>) -> Option<u64> {
+    rows.iter().filter_map(f).max()
+}
+
+fn within_month<T: MetricStoreRow>(rows: Vec<T>, start: DateTime<Utc>) -> Vec<T> {
+    let mut rows: Vec<T> = rows.into_iter().filter(|r| r.time() > start).collect();
+    rows.sort_by_key(|r| r.time());
+    rows
+}
+
+/// Aggregate day-level pod rows into one month row (timestamp = end of window).
+pub fn aggregate_pod_rows(
+    rows: Vec<MetricPodEntity>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<MetricPodEntity> {
+    MetricPodDayFsAdapter::aggregate_rows(within_month(rows, start), start, end)
+}
+
+/// Aggregate day-level node rows into one month row (timestamp = end of window).
+pub fn aggregate_node_rows(
+    rows: Vec<MetricNodeEntity>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<MetricNodeEntity> {
+    let rows = within_month(rows, start);
+    if rows.is_empty() {
+        return Err(anyhow!("no day data found for aggregation"));
+    }
+    let r = rows.as_slice();
+
+    Ok(MetricNodeEntity {
+        time: end,
+
+        // CPU
+        cpu_usage_nano_cores: twa(r, start, end, |r| r.cpu_usage_nano_cores),
+        cpu_usage_core_nano_seconds: sum(r, |r| r.cpu_usage_core_nano_seconds),
+
+        // Memory
+        memory_usage_bytes: twa(r, start, end, |r| r.memory_usage_bytes),
+        memory_working_set_bytes: twa(r, start, end, |r| r.memory_working_set_bytes),
+        memory_rss_bytes: twa(r, start, end, |r| r.memory_rss_bytes),
+        memory_page_faults: sum(r, |r| r.memory_page_faults),
+
+        // Network
+        network_physical_rx_bytes: sum(r, |r| r.network_physical_rx_bytes),
+        network_physical_tx_bytes: sum(r, |r| r.network_physical_tx_bytes),
+        network_physical_rx_errors: sum(r, |r| r.network_physical_rx_errors),
+        network_physical_tx_errors: sum(r, |r| r.network_physical_tx_errors),
+
+        // Filesystem
+        fs_used_bytes: twa(r, start, end, |r| r.fs_used_bytes),
+        fs_capacity_bytes: max(r, |r| r.fs_capacity_bytes),
+        fs_inodes_used: twa(r, start, end, |r| r.fs_inodes_used),
+        fs_inodes: max(r, |r| r.fs_inodes),
+
+        // Saturation
+        cpu_allocatable_nano_cores: max(r, |r| r.cpu_allocatable_nano_cores),
+        memory_allocatable_bytes: max(r, |r| r.memory_allocatable_bytes),
+        memory_pressure_percent: twa(r, start, end, |r| r.memory_pressure_percent),
+        disk_pressure_percent: twa(r, start, end, |r| r.disk_pressure_percent),
+    })
+}
+
+/// Aggregate day-level container rows into one month row (timestamp = end of window).
+pub fn aggregate_container_rows(
+    rows: Vec<MetricContainerEntity>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<MetricContainerEntity> {
+    let rows = within_month(rows, start);
+    if rows.is_empty() {
+        return Err(anyhow!("no day data found for aggregation"));
+    }
+    let r = rows.as_slice();
+
+    Ok(MetricContainerEntity {
+        time: end,
+
+        // CPU
+        cpu_usage_nano_cores: twa(r, start, end, |r| r.cpu_usage_nano_cores),
+        cpu_usage_core_nano_seconds: sum(r, |r| r.cpu_usage_core_nano_seconds),
+
+        // Memory
+        memory_usage_bytes: twa(r, start, end, |r| r.memory_usage_bytes),
+        memory_working_set_bytes: twa(r, start, end, |r| r.memory_working_set_bytes),
+        memory_rss_bytes: twa(r, start, end, |r| r.memory_rss_bytes),
+        memory_page_faults: sum(r, |r| r.memory_page_faults),
+
+        // Filesystem
+        fs_used_bytes: twa(r, start, end, |r| r.fs_used_bytes),
+        fs_capacity_bytes: max(r, |r| r.fs_capacity_bytes),
+        fs_inodes_used: twa(r, start, end, |r| r.fs_inodes_used),
+        fs_inodes: max(r, |r| r.fs_inodes),
+    })
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn node_day(day: u32, cpu: u64, usage: u64, capacity: u64) -> MetricNodeEntity {
+        MetricNodeEntity {
+            time: Utc.with_ymd_and_hms(2025, 2, day, 0, 0, 0).unwrap(),
+            cpu_usage_nano_cores: Some(cpu),
+            cpu_usage_core_nano_seconds: Some(usage),
+            fs_capacity_bytes: Some(capacity),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn node_month_sums_usage_and_weights_gauges_by_time() {
+        let start = Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+
+        // Feb 1 00:00 closes January and is dropped. 100 cores for 13 days, 200 for 14: 4100 / 28
+        let rows = vec![
+            node_day(1, 999, 999, 999),
+            node_day(2, 100, 10, 500),
+            node_day(15, 200, 20, 800),
+        ];
+        let month = aggregate_node_rows(rows, start, end).unwrap();
+
+        assert_eq!(month.time, end);
+        assert_eq!(month.cpu_usage_nano_cores, Some(146));
+        assert_eq!(month.cpu_usage_core_nano_seconds, Some(30));
+        assert_eq!(month.fs_capacity_bytes, Some(800));
+        assert_eq!(month.memory_usage_bytes, None);
+    }
+
+    #[test]
+    fn empty_month_is_an_error() {
+        let start = Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        assert!(aggregate_container_rows(vec![], start, end).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Datelike, Utc};
+use std::{
+    fs::{self, File},
+    io::{BufRead, BufReader},
+    path::PathBuf,
+};
+
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::metric_column::{metric_header, parse_metric_row};
+use crate::core::persistence::metrics::metric_partition_writer::MetricPartitionWriter;
+use crate::core::persistence::metrics::metric_store_trait::{MetricAggregateFn, MetricStore, MetricStoreRow};
+
+/// File adapter for month rows, shared by node, pod and container.
+///
+/// Rows use the day tier's `.rcd` line format and live in yearly partitions
+/// (`<object>/mo/YYYY.rcd`). Like every other tier a row is stamped with the
+/// *end* of its window, i.e. 00:00 UTC on the first of the next month.
+pub struct MetricMonthFsAdapter<T: 'static> {
+    dir: fn(&str) -> PathBuf,
+    format_row: fn(&T) -> String,
+    parse_line: fn(&str) -> Option<T>,
+    /// Day-tier store the month rows are rolled up from
+    source: fn() -> Box<dyn MetricStore<T>>,
+    aggregate: MetricAggregateFn<T>,
+}
+
+impl<T: MetricStoreRow + 'static> MetricMonthFsAdapter<T> {
+    pub fn new(
+        dir: fn(&str) -> PathBuf,
+        format_row: fn(&T) -> String,
+        parse_line: fn(&str) -> Option<T>,
+        source: fn() -> Box<dyn MetricStore<T>>,
+        aggregate: MetricAggregateFn<T>,
+    ) -> Self {
+        Self { dir, format_row, parse_line, source, aggregate }
+    }
+
+    fn file_path(&self, key: &str, year: i32) -> PathBuf {
+        (self.dir)(key).join(format!("{}.rcd", year))
+    }
+}
+
+impl<T: MetricStoreRow + 'static> MetricStore<T> for MetricMonthFsAdapter<T> {
+    fn append_row(&self, key: &str, dto: &T, _now: DateTime<Utc>) -> Result<()> {
+        let path = self.file_path(key, dto.time().year());
+        // Same timestamp replaces, so re-running a month is idempotent
+        MetricPartitionWriter::upsert_row(&path, dto.time(), &(self.format_row)(dto), None)
+    }
+
+    /// Rolls the day rows of `(start, end]` into one month row.
+    fn append_row_aggregated(
+        &self,
+        key: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let rows = (self.source)().get_row_between(start, end, key, None, None)?;
+
+        let aggregated = (self.aggregate)(rows, start, end)?;
+        self.append_row(key, &aggregated, now)
+    }
+
+    fn cleanup_old(&self, key: &str, before: DateTime<Utc>) -> Result<()> {
+        let dir = (self.dir)(key);
+        if !dir.exists() {
+            return Ok(());
+        }
+
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+                continue;
+            }
+            let Some(year) = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.trim().parse::<i32>().ok())
+            else {
+                continue;
+            };
+            if year < before.year() {
+                match fs::remove_file(&path) {
+                    Ok(_) => tracing::info!("Deleted old metric file {:?}", path),
+                    Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Month rows are tiny, so column reads return whole rows.
+    fn get_column_between(
+        &self,
+        _column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.get_row_between(start, end, object_name, limit, offset)
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        if end < start {
+            return Ok(vec![]);
+        }
+        if end.year() - start.year() > 10_000 {
+            return Err(anyhow!("year range too large"));
+        }
+
+        let mut data: Vec<T> = Vec::new();
+        for year in start.year()..=end.year() {
+            let path = self.file_path(object_name, year);
+            let file = match File::open(&path) {
+                Ok(f) => f,
+                Err(_) => continue,
+            };
+
+            for line in BufReader::new(file).lines().map_while(|l| l.ok()) {
+                let Some(row) = (self.parse_line)(&line) else {
+                    continue;
+                };
+                if row.time() < start {
+                    continue;
+                }
+                if row.time() > end {
+                    break;
+                }
+                data.push(row);
+            }
+        }
+
+        data.sort_by_key(|r| r.time());
+        data.dedup_by_key(|r| r.time());
+
+        Ok(data
+            .into_iter()
+            .skip(offset.unwrap_or(0))
+            .take(limit.unwrap_or(usize::MAX))
+            .collect())
+    }
+}
+
+/// Month files reuse the day `.rcd` layout. Node rows were always read without
+/// a field count check; pod and container rows must be complete.
+pub(crate) fn parse_node_month_line(line: &str) -> Option<MetricNodeEntity> {
+    parse_metric_row(&[], line)
+}
+
+pub(crate) fn parse_pod_month_line(line: &str) -> Option<MetricPodEntity> {
+    parse_metric_row(&metric_header::<MetricPodEntity>(), line)
+}
+
+pub(crate) fn parse_container_month_line(line: &str) -> Option<MetricContainerEntity> {
+    parse_metric_row(&metric_header::<MetricContainerEntity>(), line)
+}
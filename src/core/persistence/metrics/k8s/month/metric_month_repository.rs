@@ -0,0 +1,78 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::error;
+
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+use crate::core::persistence::repositories::{repositories, MetricTier};
+use crate::domain::common::service::MetricRowRepository;
+
+/// Month-tier repository; one type for all scopes since month rows share
+/// the day layout and adapter.
+pub struct MetricMonthRepository<T: 'static> {
+    scope: &'static str,
+    adapter: Box<dyn MetricStore<T>>,
+}
+
+impl MetricMonthRepository<MetricNodeEntity> {
+    pub fn node() -> Self {
+        Self { scope: "node", adapter: repositories().metric_stores.node(MetricTier::Month) }
+    }
+}
+
+impl MetricMonthRepository<MetricPodEntity> {
+    pub fn pod() -> Self {
+        Self { scope: "pod", adapter: repositories().metric_stores.pod(MetricTier::Month) }
+    }
+}
+
+impl MetricMonthRepository<MetricContainerEntity> {
+    pub fn container() -> Self {
+        Self { scope: "container", adapter: repositories().metric_stores.container(MetricTier::Month) }
+    }
+}
+
+impl<T: 'static> MetricMonthRepository<T> {
+    /// Rolls the day rows of `(start, end]` into the month row stamped `end`.
+    pub fn append_row_aggregated(
+        &self,
+        object_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        self.adapter
+            .append_row_aggregated(object_name, start, end, now)
+            .map_err(|err| {
+                error!(error = %err, scope = self.scope, object_name, "Failed to aggregate month rows");
+                err
+            })
+    }
+
+    pub fn get_row_between(
+        &self,
+        object_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<T>> {
+        self.adapter
+            .get_row_between(start, end, object_name, None, None)
+            .map_err(|err| {
+                error!(error = %err, scope = self.scope, object_name, "Failed to read month rows");
+                err
+            })
+    }
+}
+
+impl<T: 'static> MetricRowRepository<T> for MetricMonthRepository<T> {
+    fn get_row_between(
+        &self,
+        object_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<T>> {
+        MetricMonthRepository::get_row_between(self, object_name, start, end)
+    }
+}
@@ -0,0 +1,6 @@
+//! Month tier: one row per object and calendar month, rolled up from day rows
+//! so multi-year history can be read without scanning every day partition.
+
+pub mod metric_month_aggregate;
+pub mod metric_month_fs_adapter;
+pub mod metric_month_repository;
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::metric_network_flow_hour_adapter;
+use crate::core::persistence::metrics::k8s::network_flow::metric_network_flow_entity::MetricNetworkFlowEntity;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+
+/// Namespace flow hour rows for the collector, the retention task and the API.
+pub struct MetricNetworkFlowHourRepository {
+    adapter: Box<dyn MetricStore<MetricNetworkFlowEntity>>,
+}
+
+impl MetricNetworkFlowHourRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: metric_network_flow_hour_adapter(),
+        }
+    }
+
+    pub fn append_row(&self, namespace: &str, row: &MetricNetworkFlowEntity, now: DateTime<Utc>) -> Result<()> {
+        self.adapter.append_row(namespace, row, now)
+    }
+
+    pub fn get_row_between(
+        &self,
+        namespace: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<MetricNetworkFlowEntity>> {
+        self.adapter.get_row_between(start, end, namespace, None, None)
+    }
+
+    pub fn cleanup_old(&self, namespace: &str, before: DateTime<Utc>) -> Result<()> {
+        self.adapter.cleanup_old(namespace, before)
+    }
+}
+
+impl Default for MetricNetworkFlowHourRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,2 @@
+pub mod metric_network_flow_hour_fs_adapter;
+pub mod metric_network_flow_hour_repository;
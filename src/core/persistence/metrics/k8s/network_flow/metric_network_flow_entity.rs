@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Egress of one namespace over one hour, split by where the traffic went.
+///
+/// Comes from the optional network flow source; pod network counters can't tell
+/// destinations apart.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct MetricNetworkFlowEntity {
+    pub time: DateTime<Utc>,
+    /// To pods and nodes in the same availability zone
+    pub intra_zone_bytes: u64,
+    /// To another availability zone of the region
+    pub cross_zone_bytes: u64,
+    /// Leaving the cloud network
+    pub internet_bytes: u64,
+}
+
+impl MetricNetworkFlowEntity {
+    pub fn total_bytes(&self) -> u64 {
+        self.intra_zone_bytes + self.cross_zone_bytes + self.internet_bytes
+    }
+}
@@ -0,0 +1,2 @@
+pub mod hour;
+pub mod metric_network_flow_entity;
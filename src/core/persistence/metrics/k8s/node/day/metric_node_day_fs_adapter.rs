@@ -1,12 +1,14 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+use crate::core::persistence::metrics::metric_column::{
+    format_metric_row, parse_column, parse_metric_row, MetricColumn,
+};
+use crate::core::persistence::metrics::metric_partition_writer::MetricPartitionWriter;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Datelike, NaiveDate, Utc};
-use std::io::BufWriter;
 use std::{
     fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
+    fs,
     io::{BufRead, BufReader},
     path::Path,
 };
@@ -38,116 +40,13 @@ impl MetricNodeDayFsAdapter {
         metric_k8s_node_key_day_file_path(node_key, &year_str)
     }
 
-    fn parse_line(_header: &[&str], line: &str) -> Option<MetricNodeEntity> {
-        use chrono::{DateTime, Utc};
-
-        let parts: Vec<&str> = line.split('|').collect();
-        if parts.is_empty() {
-            return None;
-        }
-
-        let time = DateTime::parse_from_rfc3339(parts[0])
-            .map(|dt| dt.with_timezone(&Utc))
-            .ok()?;
-
-        Some(MetricNodeEntity {
-            time,
-            cpu_usage_nano_cores: parts.get(1).and_then(|s| s.parse::<u64>().ok()),
-            cpu_usage_core_nano_seconds: parts.get(2).and_then(|s| s.parse::<u64>().ok()),
-            memory_usage_bytes: parts.get(3).and_then(|s| s.parse::<u64>().ok()),
-            memory_working_set_bytes: parts.get(4).and_then(|s| s.parse::<u64>().ok()),
-            memory_rss_bytes: parts.get(5).and_then(|s| s.parse::<u64>().ok()),
-            memory_page_faults: parts.get(6).and_then(|s| s.parse::<u64>().ok()),
-            network_physical_rx_bytes: parts.get(7).and_then(|s| s.parse::<u64>().ok()),
-            network_physical_tx_bytes: parts.get(8).and_then(|s| s.parse::<u64>().ok()),
-            network_physical_rx_errors: parts.get(9).and_then(|s| s.parse::<u64>().ok()),
-            network_physical_tx_errors: parts.get(10).and_then(|s| s.parse::<u64>().ok()),
-            fs_used_bytes: parts.get(11).and_then(|s| s.parse::<u64>().ok()),
-            fs_capacity_bytes: parts.get(12).and_then(|s| s.parse::<u64>().ok()),
-            fs_inodes_used: parts.get(13).and_then(|s| s.parse::<u64>().ok()),
-            fs_inodes: parts.get(14).and_then(|s| s.parse::<u64>().ok()),
-        })
-    }
-
-    // fn ensure_header(file: &mut File) -> Result<()> {
-    //     if file.metadata()?.len() == 0 {
-    //         let header = "TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|MEMORY_USAGE_BYTES|MEMORY_WORKING_SET_BYTES|MEMORY_RSS_BYTES|MEMORY_PAGE_FAULTS|NETWORK_PHYSICAL_RX_BYTES|NETWORK_PHYSICAL_TX_BYTES|NETWORK_PHYSICAL_RX_ERRORS|NETWORK_PHYSICAL_TX_ERRORS|ES_USED_BYTES|ES_CAPACITY_BYTES|ES_INODES_USED|ES_INODES|PV_USED_BYTES|PV_CAPACITY_BYTES|PV_INODES_USED|PV_INODES\n";
-    //         file.write_all(header.as_bytes())?;
-    //     }
-    //     Ok(())
-    // }
-
-
-    fn opt(v: Option<u64>) -> String {
-        v.map(|x| x.to_string()).unwrap_or_default()
-    }
-}
-
-impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
-    fn append_row(&self, node: &str, dto: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
-        let now_date = now.date_naive();
-        let path_str = self.build_path_for(node, now_date);
-        let path = Path::new(&path_str);
-
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
-        // Write header if file newly created
-        // if new {
-        //     self.ensure_header(path, &mut writer)?;
-        // }
-
-        // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            Self::opt(dto.network_physical_rx_bytes),
-            Self::opt(dto.network_physical_tx_bytes),
-            Self::opt(dto.network_physical_rx_errors),
-            Self::opt(dto.network_physical_tx_errors),
-            Self::opt(dto.fs_used_bytes),
-            Self::opt(dto.fs_capacity_bytes),
-            Self::opt(dto.fs_inodes_used),
-            Self::opt(dto.fs_inodes),
-        );
-
-
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
-        Ok(())
-    }
-
-    /// Aggregate hour-level metrics into an dayly sample and append to day file.
-    fn append_row_aggregated(
-        &self,
-        node_uid: &str,
-        start: DateTime<Utc>,
+    /// Aggregate hour-level rows into a single day row (timestamp = end of window).
+    /// Storage-independent so every backend produces the same aggregates.
+    pub fn aggregate_rows(
+        rows: Vec<MetricNodeEntity>,
+        _start: DateTime<Utc>,
         end: DateTime<Utc>,
-        now: DateTime<Utc>
-    ) -> Result<()> {
-        // --- 1️⃣ Load hour data
-        let hour_adapter = MetricNodeHourFsAdapter;
-        let rows = hour_adapter.get_row_between(start, end, node_uid, None, None)?;
-
+    ) -> Result<MetricNodeEntity> {
         if rows.is_empty() {
             return Err(anyhow!("no hour data found for aggregation"));
         }
@@ -197,341 +96,26 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
             fs_capacity_bytes: last.fs_capacity_bytes,
             fs_inodes_used: avg(|r| r.fs_inodes_used),
             fs_inodes: last.fs_inodes,
-        };
-
-        // --- 3️⃣ Append the aggregated row into the day-level file
-        self.append_row(node_uid, &aggregated, now)?;
-
-        Ok(())
-    }
-
-
-    fn cleanup_old(&self, node_uid: &str, before: DateTime<Utc>) -> Result<()> {
-        const BATCH_SIZE: usize = 200;
-
-        let dir = metric_k8s_node_key_day_dir_path(node_uid);
-        if !dir.exists() {
-            return Ok(());
-        }
-
-        let cutoff_year = before.year();
-        let mut batch = Vec::with_capacity(BATCH_SIZE);
-
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Must be .rcd
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
-                continue;
-            }
-
-            // Extract filename stem as UTF-8
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
-                Some(s) => s.trim(),
-                None => {
-                    tracing::warn!("Skipping file with invalid UTF-8 name: {:?}", path);
-                    continue;
-                }
-            };
-
-            // Parse year (e.g., "2023")
-            let file_year: i32 = match stem.parse() {
-                Ok(y) => y,
-                Err(_) => {
-                    tracing::warn!("Skipping invalid year filename '{}'", stem);
-                    continue;
-                }
-            };
-
-            // Check retention policy
-            if file_year < cutoff_year {
-                batch.push(path);
-
-                if batch.len() >= BATCH_SIZE {
-                    Self::delete_batch(&batch)?;
-                    batch.clear();
-                }
-            }
-        }
 
-        // Flush remaining batch
-        if !batch.is_empty() {
-            Self::delete_batch(&batch)?;
-        }
-
-        Ok(())
-    }
-
-
-    fn get_column_between(
-        &self,
-        column_name: &str,
-        start: DateTime<Utc>,
-        end: DateTime<Utc>,
-        object_name: &str,
-        limit: Option<usize>,
-        offset: Option<usize>,
-    ) -> Result<Vec<MetricNodeEntity>> {
-        let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricNodeEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_BYTES" => {
-                        let keep = row.network_physical_rx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_rx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_BYTES" => {
-                        let keep = row.network_physical_tx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_tx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_ERRORS" => {
-                        let keep = row.network_physical_rx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_rx_errors = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_ERRORS" => {
-                        let keep = row.network_physical_tx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_tx_errors = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
+            // Saturation
+            cpu_allocatable_nano_cores: last.cpu_allocatable_nano_cores,
+            memory_allocatable_bytes: last.memory_allocatable_bytes,
+            memory_pressure_percent: avg(|r| r.memory_pressure_percent),
+            disk_pressure_percent: avg(|r| r.disk_pressure_percent),
+        };
 
-        Ok(filtered)
+        Ok(aggregated)
     }
 
-    fn get_row_between(
+    /// Rows of `object_name` between `start` and `end`, each line parsed by `parse`.
+    fn read_between(
         &self,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
         object_name: &str,
         limit: Option<usize>,
         offset: Option<usize>,
+        parse: &dyn Fn(&[&str], &str) -> Option<MetricNodeEntity>,
     ) -> Result<Vec<MetricNodeEntity>> {
 
         // Collected result rows
@@ -584,7 +168,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
             // Assumption: rows are written in chronological order
             for line in reader.lines().flatten() {
                 // Parse a single metric row
-                let Some(row) = Self::parse_line(&[], &line) else {
+                let Some(row) = parse(&[], &line) else {
                     continue;
                 };
 
@@ -619,6 +203,121 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
                 .collect()
         )
     }
+}
+
+impl MetricStore<MetricNodeEntity> for MetricNodeDayFsAdapter {
+    fn append_row(&self, node: &str, dto: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
+        let now_date = now.date_naive();
+        let path_str = self.build_path_for(node, now_date);
+        let path = Path::new(&path_str);
+
+        let row = format_metric_row(dto);
+
+        // Replaces an existing row with the same timestamp, so aggregation replays stay idempotent
+        MetricPartitionWriter::upsert_row(path, dto.time, &row, None)
+    }
+
+    /// Aggregate hour-level metrics into an dayly sample and append to day file.
+    fn append_row_aggregated(
+        &self,
+        node_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>
+    ) -> Result<()> {
+        // --- 1️⃣ Load hour data
+        let hour_adapter = MetricNodeHourFsAdapter;
+        let rows = hour_adapter.get_row_between(start, end, node_uid, None, None)?;
+
+        let aggregated = Self::aggregate_rows(rows, start, end)?;
+
+        // --- 3️⃣ Append the aggregated row into the day-level file
+        self.append_row(node_uid, &aggregated, now)?;
+
+        Ok(())
+    }
+
+    fn cleanup_old(&self, node_uid: &str, before: DateTime<Utc>) -> Result<()> {
+        const BATCH_SIZE: usize = 200;
+
+        let dir = metric_k8s_node_key_day_dir_path(node_uid);
+        if !dir.exists() {
+            return Ok(());
+        }
+
+        let cutoff_year = before.year();
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            // Must be .rcd
+            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+                continue;
+            }
+
+            // Extract filename stem as UTF-8
+            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+                Some(s) => s.trim(),
+                None => {
+                    tracing::warn!("Skipping file with invalid UTF-8 name: {:?}", path);
+                    continue;
+                }
+            };
+
+            // Parse year (e.g., "2023")
+            let file_year: i32 = match stem.parse() {
+                Ok(y) => y,
+                Err(_) => {
+                    tracing::warn!("Skipping invalid year filename '{}'", stem);
+                    continue;
+                }
+            };
+
+            // Check retention policy
+            if file_year < cutoff_year {
+                batch.push(path);
+
+                if batch.len() >= BATCH_SIZE {
+                    Self::delete_batch(&batch)?;
+                    batch.clear();
+                }
+            }
+        }
+
+        // Flush remaining batch
+        if !batch.is_empty() {
+            Self::delete_batch(&batch)?;
+        }
+
+        Ok(())
+    }
 
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeEntity>> {
+        let Some(column) = MetricColumn::of::<MetricNodeEntity>(column_name) else {
+            return self.get_row_between(start, end, object_name, limit, offset);
+        };
+        self.read_between(start, end, object_name, limit, offset, &|header, line| parse_column(header, line, column))
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeEntity>> {
+        self.read_between(start, end, object_name, limit, offset, &parse_metric_row::<MetricNodeEntity>)
+    }
 
 }
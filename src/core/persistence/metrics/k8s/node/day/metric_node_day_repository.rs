@@ -1,22 +1,22 @@
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_api_repository_trait::MetricNodeDayApiRepository;
-use crate::core::persistence::metrics::k8s::node::day::metric_node_day_fs_adapter::MetricNodeDayFsAdapter;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_processor_repository_trait::MetricNodeDayProcessorRepository;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_retention_repository_traits::MetricNodeDayRetentionRepository;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::domain::common::service::MetricRowRepository;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
+use crate::core::persistence::repositories::{repositories, MetricTier};
 
 pub struct MetricNodeDayRepository {
-    adapter: MetricNodeDayFsAdapter,
+    adapter: Box<dyn MetricStore<MetricNodeEntity>>,
 }
 
 impl MetricNodeDayRepository {
     pub fn new() -> Self {
         Self {
-            adapter: MetricNodeDayFsAdapter,
+            adapter: repositories().metric_stores.node(MetricTier::Day),
         }
     }
 }
@@ -39,8 +39,8 @@ impl Default for MetricNodeDayRepository {
 }
 
 impl MetricNodeDayApiRepository for MetricNodeDayRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 
     fn get_row_between(&self, node_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<MetricNodeEntity>> {
@@ -52,8 +52,8 @@ impl MetricNodeDayApiRepository for MetricNodeDayRepository {
 }
 
 impl MetricNodeDayRetentionRepository for MetricNodeDayRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, node_name: &str, before: DateTime<Utc>) -> Result<()> {
@@ -64,8 +64,8 @@ impl MetricNodeDayRetentionRepository for MetricNodeDayRepository {
     }
 }
 impl MetricNodeDayProcessorRepository for MetricNodeDayRepository  {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 
     fn append_row_aggregated(&self, node_uid: &str, start: DateTime<Utc>, end: DateTime<Utc>, now: DateTime<Utc>) -> anyhow::Result<()> {
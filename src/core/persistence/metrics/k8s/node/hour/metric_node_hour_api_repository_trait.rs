@@ -1,11 +1,11 @@
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 
 /// Repository trait for reading node minute metrics (API layer).
 pub trait MetricNodeHourApiRepository: Send + Sync {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity>;
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity>;
 
     fn get_column_between(
         &self,
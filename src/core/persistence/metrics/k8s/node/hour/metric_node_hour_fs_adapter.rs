@@ -1,13 +1,16 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+use crate::core::persistence::metrics::metric_column::{
+    format_metric_row, metric_header, parse_column, parse_metric_row, MetricColumn,
+};
+use crate::core::persistence::metrics::metric_partition_writer::MetricPartitionWriter;
+use crate::core::persistence::metrics::metric_partition_index::MetricPartitionIndex;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use anyhow::{anyhow, Error, Result};
 use chrono::{DateTime, NaiveDate, Datelike, Utc};
-use std::io::BufWriter;
 use std::{
     fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    fs,
+    io::{BufRead, BufReader, Seek, SeekFrom},
     path::Path,
 };
 use std::path::PathBuf;
@@ -27,14 +30,16 @@ impl MetricNodeHourFsAdapter {
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
             match fs::remove_file(path) {
-                Ok(_) => tracing::info!("Deleted old metric file {:?}", path),
+                Ok(_) => {
+                    tracing::info!("Deleted old metric file {:?}", path);
+                    MetricPartitionIndex::remove(path);
+                }
                 Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
             }
         }
         Ok(())
     }
 
-
     fn parse_year_month(stem: &str) -> Option<NaiveDate> {
         let mut parts = stem.split('-');
 
@@ -53,46 +58,6 @@ impl MetricNodeHourFsAdapter {
         metric_k8s_node_key_hour_file_path(node_name, &month_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricNodeEntity> {
-        let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
-            return None;
-        }
-
-        // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
-        let time = parts[0].parse::<DateTime<Utc>>().ok()?;
-        Some(MetricNodeEntity {
-            time,
-            cpu_usage_nano_cores: parts[1].parse().ok(),
-            cpu_usage_core_nano_seconds: parts[2].parse().ok(),
-            memory_usage_bytes: parts[3].parse().ok(),
-            memory_working_set_bytes: parts[4].parse().ok(),
-            memory_rss_bytes: parts[5].parse().ok(),
-            memory_page_faults: parts[6].parse().ok(),
-            network_physical_rx_bytes: parts[7].parse().ok(),
-            network_physical_tx_bytes: parts[8].parse().ok(),
-            network_physical_rx_errors: parts[9].parse().ok(),
-            network_physical_tx_errors: parts[10].parse().ok(),
-            fs_used_bytes: parts[11].parse().ok(),
-            fs_capacity_bytes: parts[12].parse().ok(),
-            fs_inodes_used: parts[13].parse().ok(),
-            fs_inodes: parts[14].parse().ok(),
-        })
-    }
-
-    // fn ensure_header(file: &mut File) -> Result<()> {
-    //     if file.metadata()?.len() == 0 {
-    //         let header = "TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|MEMORY_USAGE_BYTES|MEMORY_WORKING_SET_BYTES|MEMORY_RSS_BYTES|MEMORY_PAGE_FAULTS|NETWORK_PHYSICAL_RX_BYTES|NETWORK_PHYSICAL_TX_BYTES|NETWORK_PHYSICAL_RX_ERRORS|NETWORK_PHYSICAL_TX_ERRORS|ES_USED_BYTES|ES_CAPACITY_BYTES|ES_INODES_USED|ES_INODES|PV_USED_BYTES|PV_CAPACITY_BYTES|PV_INODES_USED|PV_INODES\n";
-    //         file.write_all(header.as_bytes())?;
-    //     }
-    //     Ok(())
-    // }
-
-
-    fn opt(v: Option<u64>) -> String {
-        v.map(|x| x.to_string()).unwrap_or_default()
-    }
-
     /// Generates a list of monthly metric file names (`YYYY-MM.rcd`)
     /// covering the inclusive range between `start` and `end`.
     ///
@@ -146,75 +111,13 @@ impl MetricNodeHourFsAdapter {
         Ok(files)
     }
 
-
-}
-
-impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
-    fn append_row(&self, node: &str, dto: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
-
-        let now_date = now.date_naive();
-        let path_str = self.build_path(node, now_date);
-        let path = Path::new(&path_str);
-
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
-        // Write header if file newly created
-        // if new {
-        //     self.ensure_header(path, &mut writer)?;
-        // }
-
-        // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            Self::opt(dto.network_physical_rx_bytes),
-            Self::opt(dto.network_physical_tx_bytes),
-            Self::opt(dto.network_physical_rx_errors),
-            Self::opt(dto.network_physical_tx_errors),
-            Self::opt(dto.fs_used_bytes),
-            Self::opt(dto.fs_capacity_bytes),
-            Self::opt(dto.fs_inodes_used),
-            Self::opt(dto.fs_inodes),
-        );
-
-
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
-        Ok(())
-    }
-
-    /// Aggregate minute-level metrics into an hour sample and append to hour file.
-    fn append_row_aggregated(
-        &self,
-        node_uid: &str,
-        start: DateTime<Utc>,
+    /// Aggregate minute-level rows into a single hour row (timestamp = end of window).
+    /// Storage-independent so every backend produces the same aggregates.
+    pub fn aggregate_rows(
+        rows: Vec<MetricNodeEntity>,
+        _start: DateTime<Utc>,
         end: DateTime<Utc>,
-        now: DateTime<Utc>
-    ) -> Result<()> {
-        // --- 1️⃣ Load minute data
-        let minute_adapter = MetricNodeMinuteFsAdapter;
-        let rows = minute_adapter.get_row_between(start, end, node_uid, None, None)?;
-
+    ) -> Result<MetricNodeEntity> {
         if rows.is_empty() {
             return Err(anyhow!("no minute data found for aggregation"));
         }
@@ -264,8 +167,129 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
             fs_capacity_bytes: last.fs_capacity_bytes,
             fs_inodes_used: avg(|r| r.fs_inodes_used),
             fs_inodes: last.fs_inodes,
+
+            // Saturation
+            cpu_allocatable_nano_cores: last.cpu_allocatable_nano_cores,
+            memory_allocatable_bytes: last.memory_allocatable_bytes,
+            memory_pressure_percent: avg(|r| r.memory_pressure_percent),
+            disk_pressure_percent: avg(|r| r.disk_pressure_percent),
         };
 
+        Ok(aggregated)
+    }
+
+    /// Rows of `object_name` between `start` and `end`, each line parsed by `parse`.
+    fn read_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+        parse: &dyn Fn(&[&str], &str) -> Option<MetricNodeEntity>,
+    ) -> Result<Vec<MetricNodeEntity>> {
+
+        let mut data: Vec<MetricNodeEntity> = vec![];
+
+        // Calculate month iteration range
+        let mut current_date = start.date_naive();
+        let end_date = end.date_naive();
+
+        let header: Vec<&str> = metric_header::<MetricNodeEntity>();
+
+        let file_names =
+            MetricNodeHourFsAdapter::monthly_file_names(start, end)
+                .map_err(|e| anyhow!(e))?;
+
+        for file_name in file_names {
+            let path = metric_k8s_node_key_hour_dir_path(object_name).join(file_name);
+            let path_obj = Path::new(&path);
+
+            if path_obj.exists() {
+                let file = File::open(&path_obj)?;
+                let mut reader = BufReader::new(file);
+
+                // Skip rows before the requested day when the partition has an index
+                let start_bucket = start.format("%Y-%m-%d").to_string();
+                if let Some(pos) = MetricPartitionIndex::seek_offset(&path_obj, &start_bucket) {
+                    reader.seek(SeekFrom::Start(pos))?;
+                }
+                let mut lines = reader.lines();
+
+                if let Some(first_line_res) = lines.next() {
+                    let first_line = first_line_res?;
+
+                    if let Some(row) = parse(&header, &first_line) {
+                        if row.time >= start && row.time <= end {
+                            data.push(row);
+                        }
+                    }
+
+                    for line in lines.flatten() {
+                        if let Some(row) = parse(&header, &line) {
+                            if row.time < start {
+                                continue;
+                            }
+                            if row.time > end {
+                                break;
+                            }
+                            data.push(row);
+                        }
+                    }
+                }
+            }
+
+            // month progression preserved
+            let next_month = if current_date.month() == 12 {
+                NaiveDate::from_ymd_opt(current_date.year() + 1, 1, 1)
+            } else {
+                NaiveDate::from_ymd_opt(current_date.year(), current_date.month() + 1, 1)
+            };
+
+            current_date = match next_month {
+                Some(next) if next <= end_date => next,
+                _ => break,
+            };
+        }
+
+        // Sort and paginate
+        data.sort_by_key(|r| r.time);
+
+        let start_idx = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(data.len());
+        let slice: Vec<_> = data.into_iter().skip(start_idx).take(limit).collect();
+
+        Ok(slice)
+    }
+}
+
+impl MetricStore<MetricNodeEntity> for MetricNodeHourFsAdapter {
+    fn append_row(&self, node: &str, dto: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
+
+        let now_date = now.date_naive();
+        let path_str = self.build_path(node, now_date);
+        let path = Path::new(&path_str);
+
+        let row = format_metric_row(dto);
+
+        // Replaces an existing row with the same timestamp, so aggregation replays stay idempotent
+        MetricPartitionWriter::upsert_row(path, dto.time, &row, Some("%Y-%m-%d"))
+    }
+
+    /// Aggregate minute-level metrics into an hour sample and append to hour file.
+    fn append_row_aggregated(
+        &self,
+        node_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>
+    ) -> Result<()> {
+        // --- 1️⃣ Load minute data
+        let minute_adapter = MetricNodeMinuteFsAdapter;
+        let rows = minute_adapter.get_row_between(start, end, node_uid, None, None)?;
+
+        let aggregated = Self::aggregate_rows(rows, start, end)?;
+
         // --- 3️⃣ Append the aggregated row into the hour-level file
         self.append_row(node_uid, &aggregated, now)?;
 
@@ -338,79 +362,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<MetricNodeEntity>> {
-
-        let mut data: Vec<MetricNodeEntity> = vec![];
-
-        // Calculate month iteration range
-        let mut current_date = start.date_naive();
-        let end_date = end.date_naive();
-
-
-        let header: Vec<&str> = vec![
-            "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
-            "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
-            "MEMORY_PAGE_FAULTS", "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES",
-            "NETWORK_PHYSICAL_RX_ERRORS", "NETWORK_PHYSICAL_TX_ERRORS",
-            "FS_USED_BYTES", "FS_CAPACITY_BYTES", "FS_INODES_USED", "FS_INODES",
-        ];
-
-        let file_names =
-            MetricNodeHourFsAdapter::monthly_file_names(start, end)
-                .map_err(|e| anyhow!(e))?;
-
-        for file_name in file_names {
-            let path = metric_k8s_node_key_hour_dir_path(object_name).join(file_name);
-            let path_obj = Path::new(&path);
-
-            if path_obj.exists() {
-                let file = File::open(&path_obj)?;
-                let reader = BufReader::new(file);
-                let mut lines = reader.lines();
-
-                if let Some(first_line_res) = lines.next() {
-                    let first_line = first_line_res?;
-
-                    if let Some(row) = Self::parse_line(&header, &first_line) {
-                        if row.time >= start && row.time <= end {
-                            data.push(row);
-                        }
-                    }
-
-                    for line in lines.flatten() {
-                        if let Some(row) = Self::parse_line(&header, &line) {
-                            if row.time < start {
-                                continue;
-                            }
-                            if row.time > end {
-                                break;
-                            }
-                            data.push(row);
-                        }
-                    }
-                }
-            }
-
-            // month progression preserved
-            let next_month = if current_date.month() == 12 {
-                NaiveDate::from_ymd_opt(current_date.year() + 1, 1, 1)
-            } else {
-                NaiveDate::from_ymd_opt(current_date.year(), current_date.month() + 1, 1)
-            };
-
-            current_date = match next_month {
-                Some(next) if next <= end_date => next,
-                _ => break,
-            };
-        }
-
-        // Sort and paginate
-        data.sort_by_key(|r| r.time);
-
-        let start_idx = offset.unwrap_or(0);
-        let limit = limit.unwrap_or(data.len());
-        let slice: Vec<_> = data.into_iter().skip(start_idx).take(limit).collect();
-
-        Ok(slice)
+        self.read_between(start, end, object_name, limit, offset, &parse_metric_row::<MetricNodeEntity>)
     }
 
     fn get_column_between(
@@ -422,255 +374,9 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<MetricNodeEntity>> {
-        let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricNodeEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_BYTES" => {
-                        let keep = row.network_physical_rx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_rx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_BYTES" => {
-                        let keep = row.network_physical_tx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_tx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_ERRORS" => {
-                        let keep = row.network_physical_rx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_rx_errors = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_ERRORS" => {
-                        let keep = row.network_physical_tx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_tx_errors = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        let Some(column) = MetricColumn::of::<MetricNodeEntity>(column_name) else {
+            return self.get_row_between(start, end, object_name, limit, offset);
+        };
+        self.read_between(start, end, object_name, limit, offset, &|header, line| parse_column(header, line, column))
     }
 }
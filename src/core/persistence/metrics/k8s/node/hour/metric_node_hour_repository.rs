@@ -1,21 +1,21 @@
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_api_repository_trait::MetricNodeHourApiRepository;
-use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_fs_adapter::MetricNodeHourFsAdapter;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_retention_repository_traits::MetricNodeHourRetentionRepository;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::domain::common::service::MetricRowRepository;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
+use crate::core::persistence::repositories::{repositories, MetricTier};
 
 pub struct MetricNodeHourRepository {
-    adapter: MetricNodeHourFsAdapter,
+    adapter: Box<dyn MetricStore<MetricNodeEntity>>,
 }
 
 impl MetricNodeHourRepository {
     pub fn new() -> Self {
         Self {
-            adapter: MetricNodeHourFsAdapter,
+            adapter: repositories().metric_stores.node(MetricTier::Hour),
         }
     }
 }
@@ -38,8 +38,8 @@ impl Default for MetricNodeHourRepository {
 }
 
 impl MetricNodeHourApiRepository for MetricNodeHourRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 
     fn get_row_between(&self, node_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<MetricNodeEntity>> {
@@ -51,8 +51,8 @@ impl MetricNodeHourApiRepository for MetricNodeHourRepository {
 }
 
 impl MetricNodeHourRetentionRepository for MetricNodeHourRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, node_name: &str, before: DateTime<Utc>) -> Result<()> {
@@ -1,16 +1,15 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_fs_adapter::MetricNodeHourFsAdapter;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_retention_repository_traits::MetricNodeHourRetentionRepository;
 
 pub struct MetricNodeHourRetentionRepositoryImpl {
-    pub adapter: MetricNodeHourFsAdapter,
+    pub adapter: Box<dyn MetricStore<MetricNodeEntity>>,
 }
 
 impl MetricNodeHourRetentionRepository for MetricNodeHourRetentionRepositoryImpl  {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, node_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
@@ -26,4 +26,14 @@ pub struct MetricNodeEntity {
     pub fs_capacity_bytes: Option<u64>,
     pub fs_inodes_used: Option<u64>,
     pub fs_inodes: Option<u64>,
+
+    /// Node status at scrape time (allocatable can change when the kubelet
+    /// reserves more).
+    pub cpu_allocatable_nano_cores: Option<u64>,
+    pub memory_allocatable_bytes: Option<u64>,
+
+    /// Share of the window the `MemoryPressure` / `DiskPressure` condition was
+    /// `True`: 0 or 100 per minute sample, averaged by the rollups.
+    pub memory_pressure_percent: Option<u64>,
+    pub disk_pressure_percent: Option<u64>,
 }
\ No newline at end of file
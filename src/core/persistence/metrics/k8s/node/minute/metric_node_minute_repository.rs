@@ -1,22 +1,22 @@
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_api_repository_trait::MetricNodeMinuteApiRepository;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_collector_repository_trait::MetricNodeMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_adapter::MetricNodeMinuteFsAdapter;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_processor_repository_trait::MetricNodeMinuteProcessorRepository;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_retention_repository_traits::MetricNodeMinuteRetentionRepository;
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
+use crate::core::persistence::repositories::{repositories, MetricTier};
 
 pub struct MetricNodeMinuteRepository {
-    adapter: MetricNodeMinuteFsAdapter,
+    adapter: Box<dyn MetricStore<MetricNodeEntity>>,
 }
 
 impl MetricNodeMinuteRepository {
     pub fn new() -> Self {
         Self {
-            adapter: MetricNodeMinuteFsAdapter,
+            adapter: repositories().metric_stores.node(MetricTier::Minute),
         }
     }
 }
@@ -28,8 +28,8 @@ impl Default for MetricNodeMinuteRepository {
 }
 
 impl MetricNodeMinuteApiRepository for MetricNodeMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 
     fn get_row_between(&self, node_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<MetricNodeEntity>> {
@@ -41,8 +41,8 @@ impl MetricNodeMinuteApiRepository for MetricNodeMinuteRepository {
 }
 
 impl MetricNodeMinuteCollectorRepository for MetricNodeMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 
     fn append_row(&self, node_name: &str, data: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
@@ -54,14 +54,14 @@ impl MetricNodeMinuteCollectorRepository for MetricNodeMinuteRepository {
 }
 
 impl MetricNodeMinuteProcessorRepository for MetricNodeMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 }
 
 impl MetricNodeMinuteRetentionRepository for MetricNodeMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, node_name: &str, before: DateTime<Utc>) -> Result<()> {
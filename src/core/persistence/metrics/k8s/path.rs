@@ -27,6 +27,11 @@ pub fn metric_k8s_node_key_minute_dir_path(key: &str) -> PathBuf {
     metric_k8s_node_key_dir_path(key).join("m")
 }
 
+/// Month rows in yearly files (`mo/YYYY.rcd`), see `MetricMonthFsAdapter`.
+pub fn metric_k8s_node_key_month_dir_path(key: &str) -> PathBuf {
+    metric_k8s_node_key_dir_path(key).join("mo")
+}
+
 pub fn metric_k8s_node_key_day_file_path(key: &str, yyyy: &str) -> PathBuf {
     metric_k8s_node_key_day_dir_path(key).join(format!("{}.rcd", yyyy))
 }
@@ -60,6 +65,11 @@ pub fn metric_k8s_pod_key_minute_dir_path(key: &str) -> PathBuf {
     metric_k8s_pod_key_dir_path(key).join("m")
 }
 
+/// Month rows in yearly files (`mo/YYYY.rcd`), see `MetricMonthFsAdapter`.
+pub fn metric_k8s_pod_key_month_dir_path(key: &str) -> PathBuf {
+    metric_k8s_pod_key_dir_path(key).join("mo")
+}
+
 pub fn metric_k8s_pod_key_day_file_path(key: &str, yyyy: &str) -> PathBuf {
     metric_k8s_pod_key_day_dir_path(key).join(format!("{}.rcd", yyyy))
 }
@@ -72,6 +82,11 @@ pub fn metric_k8s_pod_key_minute_file_path(key: &str, yyyy_mm_dd: &str) -> PathB
     metric_k8s_pod_key_minute_dir_path(key).join(format!("{}.rcd", yyyy_mm_dd))
 }
 
+/// Container restart events of the pod, see `MetricPodRestartFsAdapter`.
+pub fn metric_k8s_pod_key_restart_file_path(key: &str) -> PathBuf {
+    metric_k8s_pod_key_dir_path(key).join("restarts.jsonl")
+}
+
 // --- Container ---
 pub fn metric_k8s_container_dir_path() -> PathBuf {
     k8s_root().join("container")
@@ -93,6 +108,11 @@ pub fn metric_k8s_container_key_minute_dir_path(key: &str) -> PathBuf {
     metric_k8s_container_key_dir_path(key).join("m")
 }
 
+/// Month rows in yearly files (`mo/YYYY.rcd`), see `MetricMonthFsAdapter`.
+pub fn metric_k8s_container_key_month_dir_path(key: &str) -> PathBuf {
+    metric_k8s_container_key_dir_path(key).join("mo")
+}
+
 pub fn metric_k8s_container_key_day_file_path(key: &str, yyyy: &str) -> PathBuf {
     metric_k8s_container_key_day_dir_path(key).join(format!("{}.rcd", yyyy))
 }
@@ -105,3 +125,72 @@ pub fn metric_k8s_container_key_minute_file_path(key: &str, yyyy_mm_dd: &str) ->
     metric_k8s_container_key_minute_dir_path(key).join(format!("{}.rcd", yyyy_mm_dd))
 }
 
+// --- PVC ---
+/// Volume claims keyed by `{namespace}_{claim}`; only hour rows are kept.
+pub fn metric_k8s_pvc_dir_path() -> PathBuf {
+    k8s_root().join("pvc")
+}
+
+pub fn metric_k8s_pvc_key_dir_path(key: &str) -> PathBuf {
+    metric_k8s_pvc_dir_path().join(key)
+}
+
+pub fn metric_k8s_pvc_key_hour_dir_path(key: &str) -> PathBuf {
+    metric_k8s_pvc_key_dir_path(key).join("h")
+}
+
+pub fn metric_k8s_pvc_key_hour_file_path(key: &str, yyyy_mm: &str) -> PathBuf {
+    metric_k8s_pvc_key_hour_dir_path(key).join(format!("{}.rcd", yyyy_mm))
+}
+
+// --- Network flows ---
+/// Namespace egress split by destination, keyed by namespace; only hour rows are kept.
+pub fn metric_k8s_network_flow_dir_path() -> PathBuf {
+    k8s_root().join("network_flow")
+}
+
+pub fn metric_k8s_network_flow_key_dir_path(namespace: &str) -> PathBuf {
+    metric_k8s_network_flow_dir_path().join(namespace)
+}
+
+pub fn metric_k8s_network_flow_key_hour_dir_path(namespace: &str) -> PathBuf {
+    metric_k8s_network_flow_key_dir_path(namespace).join("h")
+}
+
+pub fn metric_k8s_network_flow_key_hour_file_path(namespace: &str, yyyy_mm: &str) -> PathBuf {
+    metric_k8s_network_flow_key_hour_dir_path(namespace).join(format!("{}.rcd", yyyy_mm))
+}
+
+// --- Compacted archives ---
+/// Small closed partitions merged per namespace, see `MetricPartitionArchive`.
+pub fn metric_k8s_archive_dir_path() -> PathBuf {
+    k8s_root().join("archive")
+}
+
+pub fn metric_k8s_archive_index_path() -> PathBuf {
+    metric_k8s_archive_dir_path().join("index")
+}
+
+/// `partition` is relative to the object directory, e.g. `h/2025-02`.
+pub fn metric_k8s_archive_file_path(scope: &str, namespace: &str, partition: &str) -> PathBuf {
+    metric_k8s_archive_dir_path()
+        .join(scope)
+        .join(namespace)
+        .join(format!("{}.rca", partition))
+}
+
+// --- Materialized cost allocation ---
+/// Per-pod cost of each closed UTC day, one JSON file per day.
+pub fn metric_k8s_allocation_dir_path() -> PathBuf {
+    k8s_root().join("allocation")
+}
+
+pub fn metric_k8s_allocation_file_path(yyyy_mm_dd: &str) -> PathBuf {
+    metric_k8s_allocation_dir_path().join(format!("{}.json", yyyy_mm_dd))
+}
+
+// --- SQLite backend ---
+/// Single database holding every metric row of this cluster when the SQLite backend is selected.
+pub fn metric_k8s_sqlite_db_path() -> PathBuf {
+    k8s_root().join("metrics.db")
+}
@@ -1,11 +1,11 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 
 /// Repository trait for reading pod day metrics (API layer).
 pub trait MetricPodDayApiRepository: Send + Sync {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity>;
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricPodEntity>;
 
     fn get_column_between(
         &self,
@@ -1,8 +1,7 @@
 use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_api_repository_trait::MetricPodDayApiRepository;
-use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_fs_adapter::MetricPodDayFsAdapter;
 use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_retention_repository_traits::MetricPodDayRetentionRepository;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
@@ -10,15 +9,16 @@ use crate::core::persistence::metrics::k8s::node::day::metric_node_day_api_repos
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repository::MetricNodeDayRepository;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use crate::domain::common::service::MetricRowRepository;
+use crate::core::persistence::repositories::{repositories, MetricTier};
 
 pub struct MetricPodDayRepository {
-    adapter: MetricPodDayFsAdapter,
+    adapter: Box<dyn MetricStore<MetricPodEntity>>,
 }
 
 impl MetricPodDayRepository {
     pub fn new() -> Self {
         Self {
-            adapter: MetricPodDayFsAdapter,
+            adapter: repositories().metric_stores.pod(MetricTier::Day),
         }
     }
 }
@@ -41,8 +41,8 @@ impl Default for MetricPodDayRepository {
 }
 
 impl MetricPodDayApiRepository for MetricPodDayRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricPodEntity> {
+        self.adapter.as_ref()
     }
 
     fn get_row_between(
@@ -63,8 +63,8 @@ impl MetricPodDayApiRepository for MetricPodDayRepository {
 }
 
 impl MetricPodDayRetentionRepository for MetricPodDayRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricPodEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
@@ -1,11 +1,11 @@
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 
 /// Repository trait for reading pod minute metrics (API layer).
 pub trait MetricPodDayRetentionRepository: Send + Sync {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity>;
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricPodEntity>;
 
     /// Deletes old metric files for the given pod before the cutoff timestamp.
     fn cleanup_old(&self, pod_key: &str, before: DateTime<Utc>) -> Result<()> {
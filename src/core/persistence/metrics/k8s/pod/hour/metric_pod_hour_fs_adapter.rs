@@ -1,13 +1,17 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+use crate::core::persistence::metrics::metric_column::{
+    format_metric_row, metric_header, parse_column, parse_metric_row, MetricColumn,
+};
+use crate::core::persistence::metrics::metric_partition_writer::MetricPartitionWriter;
+use crate::core::persistence::metrics::metric_partition_index::MetricPartitionIndex;
+use crate::core::persistence::metrics::metric_partition_archive::MetricPartitionArchive;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use anyhow::{anyhow,  Result};
 use chrono::{DateTime, NaiveDate, Datelike, Utc};
-use std::io::BufWriter;
 use std::{
     fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    fs,
+    io::{BufRead, BufReader, Seek, SeekFrom},
     path::Path,
 };
 use std::path::PathBuf;
@@ -32,6 +36,7 @@ impl MetricPodHourFsAdapter {
                 tracing::error!("Failed to delete {:?}: {}", path, e);
             } else {
                 tracing::info!("Deleted old metric file {:?}", path);
+                MetricPartitionIndex::remove(path);
             }
         }
         Ok(())
@@ -41,122 +46,13 @@ impl MetricPodHourFsAdapter {
         metric_k8s_pod_key_hour_file_path(pod_uid, &month_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricPodEntity> {
-        let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
-            return None;
-        }
-
-        // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
-        let time = parts[0].parse::<DateTime<Utc>>().ok()?;
-        Some(MetricPodEntity {
-            time,
-            cpu_usage_nano_cores: parts[1].parse().ok(),
-            cpu_usage_core_nano_seconds: parts[2].parse().ok(),
-            memory_usage_bytes: parts[3].parse().ok(),
-            memory_working_set_bytes: parts[4].parse().ok(),
-            memory_rss_bytes: parts[5].parse().ok(),
-            memory_page_faults: parts[6].parse().ok(),
-            network_physical_rx_bytes: parts[7].parse().ok(),
-            network_physical_tx_bytes: parts[8].parse().ok(),
-            network_physical_rx_errors: parts[9].parse().ok(),
-            network_physical_tx_errors: parts[10].parse().ok(),
-            es_used_bytes: parts[11].parse().ok(),
-            es_capacity_bytes: parts[12].parse().ok(),
-            es_inodes_used: parts[13].parse().ok(),
-            es_inodes: parts[14].parse().ok(),
-            pv_used_bytes: parts[15].parse().ok(),
-            pv_capacity_bytes: parts[16].parse().ok(),
-            pv_inodes_used: parts[17].parse().ok(),
-            pv_inodes: parts[18].parse().ok(),
-        })
-    }
-
-    // fn ensure_header(file: &mut File) -> Result<()> {
-    //     if file.metadata()?.len() == 0 {
-    //         let header = "TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|MEMORY_USAGE_BYTES|MEMORY_WORKING_SET_BYTES|MEMORY_RSS_BYTES|MEMORY_PAGE_FAULTS|NETWORK_PHYSICAL_RX_BYTES|NETWORK_PHYSICAL_TX_BYTES|NETWORK_PHYSICAL_RX_ERRORS|NETWORK_PHYSICAL_TX_ERRORS|ES_USED_BYTES|ES_CAPACITY_BYTES|ES_INODES_USED|ES_INODES|PV_USED_BYTES|PV_CAPACITY_BYTES|PV_INODES_USED|PV_INODES\n";
-    //         file.write_all(header.as_bytes())?;
-    //     }
-    //     Ok(())
-    // }
-
-
-    fn opt(v: Option<u64>) -> String {
-        v.map(|x| x.to_string()).unwrap_or_default()
-    }
-}
-
-impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
-    fn append_row(&self, pod: &str, dto: &MetricPodEntity, _now: DateTime<Utc>) -> Result<()> {
-        // IMPORTANT: partition by the metric timestamp, not "now".
-        // This prevents late aggregation/backfill data from being written into the wrong file.
-        let dto_date = dto.time.date_naive();
-        let path_str = self.build_path_for(pod, dto_date);
-        let path = Path::new(&path_str);
-
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
-        // Write header if file newly created
-        // if new {
-        //     self.ensure_header(path, &mut writer)?;
-        // }
-
-        // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            Self::opt(dto.network_physical_rx_bytes),
-            Self::opt(dto.network_physical_tx_bytes),
-            Self::opt(dto.network_physical_rx_errors),
-            Self::opt(dto.network_physical_tx_errors),
-            Self::opt(dto.es_used_bytes),
-            Self::opt(dto.es_capacity_bytes),
-            Self::opt(dto.es_inodes_used),
-            Self::opt(dto.es_inodes),
-            Self::opt(dto.pv_used_bytes),
-            Self::opt(dto.pv_capacity_bytes),
-            Self::opt(dto.pv_inodes_used),
-            Self::opt(dto.pv_inodes),
-        );
-
-
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
-        Ok(())
-    }
-
-    /// Aggregate minute-level metrics into an hour sample and append to hour file.
-    fn append_row_aggregated(
-        &self,
-        pod_uid: &str,
+    /// Aggregate minute-level rows into a single hour row (timestamp = end of window).
+    /// Storage-independent so every backend produces the same aggregates.
+    pub fn aggregate_rows(
+        mut rows: Vec<MetricPodEntity>,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
-        now: DateTime<Utc>
-    ) -> Result<()> {
-        // 1) Load minute-level samples in [start, end].
-        let minute_adapter = MetricPodMinuteFsAdapter;
-        let mut rows = minute_adapter.get_row_between(start, end, pod_uid, None, None)?;
-
+    ) -> Result<MetricPodEntity> {
         if rows.is_empty() {
             return Err(anyhow!("no minute data found for aggregation"));
         }
@@ -271,107 +167,24 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
             pv_inodes: max_u64(|r| r.pv_inodes).or(last.pv_inodes),
         };
 
-        // 3) Append the aggregated sample (storage partitioning uses aggregated.time internally).
-        self.append_row(pod_uid, &aggregated, now)?;
-
-        Ok(())
+        Ok(aggregated)
     }
 
-
-    fn cleanup_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
-        const BATCH_SIZE: usize = 200;
-        let dir = metric_k8s_pod_key_hour_dir_path(pod_uid);
-        if !dir.exists() {
-            return Ok(());
-        }
-
-        // Normalize cutoff to YYYY-MM-01
-        let before_month = NaiveDate::from_ymd_opt(before.year(), before.month(), 1)
-            .ok_or_else(|| anyhow!("Invalid 'before' month {}-{}", before.year(), before.month()))?;
-
-        let mut batch: Vec<PathBuf> = Vec::with_capacity(BATCH_SIZE);
-
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Only process *.rcd
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
-                continue;
-            }
-
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
-                Some(s) => s.trim(),
-                None => {
-                    tracing::warn!(
-                    "Skipping file with non-UTF8 name in {:?}",
-                    path
-                );
-                    continue;
-                }
-            };
-
-            // Expect filename like "2025-02"
-            let parts: Vec<&str> = stem.split('-').collect();
-            if parts.len() != 2 {
-                tracing::warn!("Skipping unexpected filename '{}'", stem);
-                continue;
-            }
-
-            let year: i32 = match parts[0].parse() {
-                Ok(v) => v,
-                Err(_) => {
-                    tracing::warn!("Invalid year '{}' in '{}'", parts[0], stem);
-                    continue;
-                }
-            };
-
-            let month: u32 = match parts[1].parse() {
-                Ok(v) if (1..=12).contains(&v) => v,
-                _ => {
-                    tracing::warn!("Invalid month '{}' in '{}'", parts[1], stem);
-                    continue;
-                }
-            };
-
-            let file_month = match NaiveDate::from_ymd_opt(year, month, 1) {
-                Some(d) => d,
-                None => {
-                    tracing::warn!("Invalid date '{}-{}' in '{}'", year, month, stem);
-                    continue;
-                }
-            };
-
-            // Compare normalized YYYY-MM-01
-            if file_month < before_month {
-                batch.push(path);
-
-                // Batching to avoid blocking for too long
-                if batch.len() >= BATCH_SIZE {
-                    Self::delete_batch(&batch)?;
-                    batch.clear();
-                }
-            }
-        }
-
-        // Delete remaining
-        if !batch.is_empty() {
-            Self::delete_batch(&batch)?;
-        }
-
-        Ok(())
-    }
-
-
-    fn get_row_between(
+    /// Rows of `object_name` between `start` and `end`, each line parsed by `parse`.
+    fn read_between(
         &self,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
         object_name: &str,
         limit: Option<usize>,
         offset: Option<usize>,
+        parse: &dyn Fn(&[&str], &str) -> Option<MetricPodEntity>,
     ) -> Result<Vec<MetricPodEntity>> {
+        // Column order of header-less hour partitions and of their archived rows
+        let default_header = metric_header::<MetricPodEntity>();
         let mut data: Vec<MetricPodEntity> = vec![];
+        // Rows of compacted months; per-object files win on the same timestamp
+        let mut archived: Vec<MetricPodEntity> = vec![];
 
         // 1️⃣ Iterate month by month between start and end
         let mut current_date = NaiveDate::from_ymd_opt(start.year(), start.month() as u32, 1)
@@ -380,6 +193,15 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
             .expect("valid end date");
 
         while current_date <= end_date {
+            let partition = format!("h/{}", current_date.format("%Y-%m"));
+            for line in MetricPartitionArchive::rows("pod", object_name, &partition)? {
+                if let Some(row) = parse(&default_header, &line) {
+                    if row.time >= start && row.time <= end {
+                        archived.push(row);
+                    }
+                }
+            }
+
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
@@ -411,7 +233,13 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
                 }
             };
 
-            let reader = BufReader::new(file);
+            let mut reader = BufReader::new(file);
+
+            // Skip rows before the requested day when the partition has an index
+            let start_bucket = start.format("%Y-%m-%d").to_string();
+            if let Some(pos) = MetricPartitionIndex::seek_offset(&path_obj, &start_bucket) {
+                reader.seek(SeekFrom::Start(pos))?;
+            }
             let mut lines = reader.lines();
 
             // 2️⃣ Try to read the first line (header or data)
@@ -431,16 +259,9 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
 
             // Handle header or first data line
             if first_line.starts_with("20") {
-                header = vec![
-                    "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
-                    "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
-                    "MEMORY_PAGE_FAULTS", "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES",
-                    "NETWORK_PHYSICAL_RX_ERRORS", "NETWORK_PHYSICAL_TX_ERRORS",
-                    "ES_USED_BYTES", "ES_CAPACITY_BYTES", "ES_INODES_USED", "ES_INODES",
-                    "PV_USED_BYTES", "PV_CAPACITY_BYTES", "PV_INODES_USED", "PV_INODES"
-                ];
-
-                if let Some(row) = Self::parse_line(&header, &first_line) {
+                header = default_header.clone();
+
+                if let Some(row) = parse(&header, &first_line) {
                     if row.time >= start && row.time <= end {
                         rows.push(row);
                     }
@@ -451,7 +272,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
 
             // 3️⃣ Process the rest of the lines
             for line in lines.flatten() {
-                if let Some(row) = Self::parse_line(&header, &line) {
+                if let Some(row) = parse(&header, &line) {
                     if row.time < start {
                         continue;
                     }
@@ -472,8 +293,10 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
             };
         }
 
-        // 4️⃣ Sort and paginate
+        // 4️⃣ Sort and paginate; the stable sort keeps file rows ahead of archived ones
+        data.append(&mut archived);
         data.sort_by_key(|r| r.time);
+        data.dedup_by_key(|r| r.time);
 
         let start_idx = offset.unwrap_or(0);
         let limit = limit.unwrap_or(data.len());
@@ -489,7 +312,136 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
 
         Ok(slice)
     }
+}
 
+impl MetricStore<MetricPodEntity> for MetricPodHourFsAdapter {
+    fn append_row(&self, pod: &str, dto: &MetricPodEntity, _now: DateTime<Utc>) -> Result<()> {
+        // IMPORTANT: partition by the metric timestamp, not "now".
+        // This prevents late aggregation/backfill data from being written into the wrong file.
+        let dto_date = dto.time.date_naive();
+        let path_str = self.build_path_for(pod, dto_date);
+        let path = Path::new(&path_str);
+
+        let row = format_metric_row(dto);
+
+        // Replaces an existing row with the same timestamp, so aggregation replays stay idempotent
+        MetricPartitionWriter::upsert_row(path, dto.time, &row, Some("%Y-%m-%d"))
+    }
+
+    /// Aggregate minute-level metrics into an hour sample and append to hour file.
+    fn append_row_aggregated(
+        &self,
+        pod_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>
+    ) -> Result<()> {
+        // 1) Load minute-level samples in [start, end].
+        let minute_adapter = MetricPodMinuteFsAdapter;
+        let rows = minute_adapter.get_row_between(start, end, pod_uid, None, None)?;
+
+        let aggregated = Self::aggregate_rows(rows, start, end)?;
+
+        // 3) Append the aggregated sample (storage partitioning uses aggregated.time internally).
+        self.append_row(pod_uid, &aggregated, now)?;
+
+        Ok(())
+    }
+
+    fn cleanup_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
+        const BATCH_SIZE: usize = 200;
+        let dir = metric_k8s_pod_key_hour_dir_path(pod_uid);
+        if !dir.exists() {
+            return Ok(());
+        }
+
+        // Normalize cutoff to YYYY-MM-01
+        let before_month = NaiveDate::from_ymd_opt(before.year(), before.month(), 1)
+            .ok_or_else(|| anyhow!("Invalid 'before' month {}-{}", before.year(), before.month()))?;
+
+        let mut batch: Vec<PathBuf> = Vec::with_capacity(BATCH_SIZE);
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            // Only process *.rcd
+            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+                continue;
+            }
+
+            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+                Some(s) => s.trim(),
+                None => {
+                    tracing::warn!(
+                    "Skipping file with non-UTF8 name in {:?}",
+                    path
+                );
+                    continue;
+                }
+            };
+
+            // Expect filename like "2025-02"
+            let parts: Vec<&str> = stem.split('-').collect();
+            if parts.len() != 2 {
+                tracing::warn!("Skipping unexpected filename '{}'", stem);
+                continue;
+            }
+
+            let year: i32 = match parts[0].parse() {
+                Ok(v) => v,
+                Err(_) => {
+                    tracing::warn!("Invalid year '{}' in '{}'", parts[0], stem);
+                    continue;
+                }
+            };
+
+            let month: u32 = match parts[1].parse() {
+                Ok(v) if (1..=12).contains(&v) => v,
+                _ => {
+                    tracing::warn!("Invalid month '{}' in '{}'", parts[1], stem);
+                    continue;
+                }
+            };
+
+            let file_month = match NaiveDate::from_ymd_opt(year, month, 1) {
+                Some(d) => d,
+                None => {
+                    tracing::warn!("Invalid date '{}-{}' in '{}'", year, month, stem);
+                    continue;
+                }
+            };
+
+            // Compare normalized YYYY-MM-01
+            if file_month < before_month {
+                batch.push(path);
+
+                // Batching to avoid blocking for too long
+                if batch.len() >= BATCH_SIZE {
+                    Self::delete_batch(&batch)?;
+                    batch.clear();
+                }
+            }
+        }
+
+        // Delete remaining
+        if !batch.is_empty() {
+            Self::delete_batch(&batch)?;
+        }
+
+        Ok(())
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricPodEntity>> {
+        self.read_between(start, end, object_name, limit, offset, &parse_metric_row::<MetricPodEntity>)
+    }
 
     fn get_column_between(
         &self,
@@ -500,395 +452,9 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<MetricPodEntity>> {
-        let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricPodEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_BYTES" => {
-                        let keep = row.network_physical_rx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.network_physical_rx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_BYTES" => {
-                        let keep = row.network_physical_tx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.network_physical_tx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_ERRORS" => {
-                        let keep = row.network_physical_rx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.network_physical_rx_errors = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_ERRORS" => {
-                        let keep = row.network_physical_tx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.network_physical_tx_errors = keep;
-                    }
-                    "ES_USED_BYTES" => {
-                        let keep = row.es_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.es_used_bytes = keep;
-                    }
-                    "ES_CAPACITY_BYTES" => {
-                        let keep = row.es_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.es_capacity_bytes = keep;
-                    }
-                    "ES_INODES_USED" => {
-                        let keep = row.es_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.es_inodes_used = keep;
-                    }
-                    "ES_INODES" => {
-                        let keep = row.es_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.es_inodes = keep;
-                    }
-                    "PV_USED_BYTES" => {
-                        let keep = row.pv_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.pv_used_bytes = keep;
-                    }
-                    "PV_CAPACITY_BYTES" => {
-                        let keep = row.pv_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.pv_capacity_bytes = keep;
-                    }
-                    "PV_INODES_USED" => {
-                        let keep = row.pv_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes = None;
-                        row.pv_inodes_used = keep;
-                    }
-                    "PV_INODES" => {
-                        let keep = row.pv_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        let Some(column) = MetricColumn::of::<MetricPodEntity>(column_name) else {
+            return self.get_row_between(start, end, object_name, limit, offset);
+        };
+        self.read_between(start, end, object_name, limit, offset, &|header, line| parse_column(header, line, column))
     }
 }
@@ -1,16 +1,15 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_processor_repository_trait::MetricPodHourProcessorRepository;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_fs_adapter::MetricPodHourFsAdapter;
 
 pub struct MetricPodHourProcessorRepositoryImpl {
-    pub adapter: MetricPodHourFsAdapter,
+    pub adapter: Box<dyn MetricStore<MetricPodEntity>>,
 }
 
 impl MetricPodHourProcessorRepository for MetricPodHourProcessorRepositoryImpl  {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricPodEntity> {
+        self.adapter.as_ref()
     }
 
     fn append_row_aggregated(&self, pod_uid: &str, start: DateTime<Utc>, end: DateTime<Utc>, now: DateTime<Utc>) -> anyhow::Result<()> {
@@ -1,10 +1,9 @@
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_api_repository_trait::MetricPodMinuteApiRepository;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_collector_repository_trait::MetricPodMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_processor_repository_trait::MetricPodMinuteProcessorRepository;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_retention_repository_traits::MetricPodMinuteRetentionRepository;
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
@@ -12,15 +11,16 @@ use crate::core::persistence::metrics::k8s::node::day::metric_node_day_api_repos
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repository::MetricNodeDayRepository;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use crate::domain::common::service::MetricRowRepository;
+use crate::core::persistence::repositories::{repositories, MetricTier};
 
 pub struct MetricPodMinuteRepository {
-    adapter: MetricPodMinuteFsAdapter,
+    adapter: Box<dyn MetricStore<MetricPodEntity>>,
 }
 
 impl MetricPodMinuteRepository {
     pub fn new() -> Self {
         Self {
-            adapter: MetricPodMinuteFsAdapter,
+            adapter: repositories().metric_stores.pod(MetricTier::Minute),
         }
     }
 }
@@ -43,8 +43,8 @@ impl Default for MetricPodMinuteRepository {
 }
 
 impl MetricPodMinuteApiRepository for MetricPodMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricPodEntity> {
+        self.adapter.as_ref()
     }
 
     fn get_row_between(
@@ -65,8 +65,8 @@ impl MetricPodMinuteApiRepository for MetricPodMinuteRepository {
 }
 
 impl MetricPodMinuteCollectorRepository for MetricPodMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricPodEntity> {
+        self.adapter.as_ref()
     }
 
     fn append_row(&self, pod_uid: &str, data: &MetricPodEntity, now: DateTime<Utc>) -> Result<()> {
@@ -78,14 +78,14 @@ impl MetricPodMinuteCollectorRepository for MetricPodMinuteRepository {
 }
 
 impl MetricPodMinuteProcessorRepository for MetricPodMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricPodEntity> {
+        self.adapter.as_ref()
     }
 }
 
 impl MetricPodMinuteRetentionRepository for MetricPodMinuteRepository {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricPodEntity> {
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
@@ -2,3 +2,4 @@ pub mod minute;
 pub mod hour;
 pub mod day;
 pub mod metric_pod_entity;
+pub mod restart;
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Container restarts noticed by one discovery cycle, taken from the pod's
+/// `containerStatuses[].lastState.terminated`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MetricPodRestartEntity {
+    pub container: String,
+    /// Container restart count after these restarts
+    pub restart_count: u32,
+    /// Restarts since the previous event for this container (at least 1)
+    pub restarts: u32,
+    /// Termination reason of the last failed instance, e.g. `OOMKilled`, `Error`
+    pub reason: Option<String>,
+    pub exit_code: Option<i32>,
+    /// Lifetime of the last failed instance
+    pub started_at: Option<DateTime<Utc>>,
+    pub terminated_at: Option<DateTime<Utc>>,
+    /// When the replacement instance started; `None` while it is still backing off
+    pub restarted_at: Option<DateTime<Utc>>,
+    pub observed_at: DateTime<Utc>,
+}
+
+impl MetricPodRestartEntity {
+    pub fn is_oom_kill(&self) -> bool {
+        self.reason.as_deref() == Some("OOMKilled")
+    }
+}
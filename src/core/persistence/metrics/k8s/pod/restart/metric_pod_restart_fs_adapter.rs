@@ -0,0 +1,48 @@
+use anyhow::Result;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+};
+
+use crate::core::persistence::metrics::k8s::path::metric_k8s_pod_key_restart_file_path;
+use crate::core::persistence::metrics::k8s::pod::restart::metric_pod_restart_entity::MetricPodRestartEntity;
+use crate::core::persistence::blocking_io::blocking_io;
+
+/// Restart events stored as JSON lines next to the pod's metric partitions, in
+/// `metric/k8s/pod/{pod_uid}/restarts.jsonl`. Removed with the pod's metrics.
+#[derive(Default)]
+pub struct MetricPodRestartFsAdapter;
+
+impl MetricPodRestartFsAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn append(&self, pod_uid: &str, event: &MetricPodRestartEntity) -> Result<()> {
+        blocking_io(|| {
+            let path = metric_k8s_pod_key_restart_file_path(pod_uid);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            file.write_all(format!("{}\n", serde_json::to_string(event)?).as_bytes())?;
+            Ok(())
+        })
+    }
+
+    /// All events for the pod, oldest first. Unparseable lines are skipped.
+    pub fn list(&self, pod_uid: &str) -> Result<Vec<MetricPodRestartEntity>> {
+        blocking_io(|| {
+            let file = match File::open(metric_k8s_pod_key_restart_file_path(pod_uid)) {
+                Ok(f) => f,
+                Err(_) => return Ok(vec![]),
+            };
+            Ok(BufReader::new(file)
+                .lines()
+                .map_while(|l| l.ok())
+                .filter_map(|l| serde_json::from_str(&l).ok())
+                .collect())
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod metric_pod_restart_entity;
+pub mod metric_pod_restart_fs_adapter;
@@ -0,0 +1,157 @@
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use std::{
+    fs::{self, File},
+    io::{BufRead, BufReader},
+};
+
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_pvc_key_hour_dir_path, metric_k8s_pvc_key_hour_file_path,
+};
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::metric_partition_writer::MetricPartitionWriter;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+
+/// Hour rows of a claim in monthly partitions, `pvc/{key}/h/YYYY-MM.rcd`:
+/// `TIME|CAPACITY_BYTES|REQUESTED_BYTES|USED_BYTES|AVAILABLE_BYTES|MOUNTED`.
+#[derive(Debug)]
+pub struct MetricPvcHourFsAdapter;
+
+impl MetricPvcHourFsAdapter {
+    fn opt(v: Option<u64>) -> String {
+        v.map(|x| x.to_string()).unwrap_or_default()
+    }
+
+    pub fn format_row(row: &MetricPvcEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}\n",
+            row.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            Self::opt(row.capacity_bytes),
+            Self::opt(row.requested_bytes),
+            Self::opt(row.used_bytes),
+            Self::opt(row.available_bytes),
+            if row.mounted { 1 } else { 0 },
+        )
+    }
+
+    pub fn parse_line(line: &str) -> Option<MetricPvcEntity> {
+        let parts: Vec<&str> = line.trim_end().split('|').collect();
+        if parts.len() != 6 {
+            return None;
+        }
+
+        let num = |s: &str| if s.is_empty() { None } else { s.parse::<u64>().ok() };
+        Some(MetricPvcEntity {
+            time: DateTime::parse_from_rfc3339(parts[0]).ok()?.with_timezone(&Utc),
+            capacity_bytes: num(parts[1]),
+            requested_bytes: num(parts[2]),
+            used_bytes: num(parts[3]),
+            available_bytes: num(parts[4]),
+            mounted: parts[5] == "1",
+        })
+    }
+}
+
+impl MetricStore<MetricPvcEntity> for MetricPvcHourFsAdapter {
+    fn append_row(&self, key: &str, row: &MetricPvcEntity, _now: DateTime<Utc>) -> Result<()> {
+        let path = metric_k8s_pvc_key_hour_file_path(key, &row.time.format("%Y-%m").to_string());
+        MetricPartitionWriter::upsert_row(&path, row.time, &Self::format_row(row), None)
+    }
+
+    /// Drops monthly partitions that end before `before`'s month.
+    fn cleanup_old(&self, key: &str, before: DateTime<Utc>) -> Result<()> {
+        let dir = metric_k8s_pvc_key_hour_dir_path(key);
+        if !dir.exists() {
+            return Ok(());
+        }
+        let before_month = format!("{:04}-{:02}", before.year(), before.month());
+
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+                continue;
+            }
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            // `YYYY-MM` sorts lexically
+            if stem.len() == 7 && stem < before_month.as_str() {
+                if let Err(e) = fs::remove_file(&path) {
+                    tracing::error!("Failed to delete {:?}: {}", path, e);
+                } else {
+                    tracing::info!("Deleted old metric file {:?}", path);
+                }
+            }
+        }
+
+        // Nothing left for a claim that has been gone longer than the retention
+        if fs::read_dir(&dir)?.next().is_none() {
+            let _ = fs::remove_dir(&dir);
+            if let Some(parent) = dir.parent() {
+                let _ = fs::remove_dir(parent);
+            }
+        }
+        Ok(())
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        key: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricPvcEntity>> {
+        let mut rows = Vec::new();
+        let Some(mut month) = NaiveDate::from_ymd_opt(start.year(), start.month(), 1) else {
+            return Ok(rows);
+        };
+
+        while month <= end.date_naive() {
+            let path = metric_k8s_pvc_key_hour_file_path(key, &month.format("%Y-%m").to_string());
+            if let Ok(file) = File::open(&path) {
+                for line in BufReader::new(file).lines().map_while(|l| l.ok()) {
+                    match Self::parse_line(&line) {
+                        Some(row) if row.time > end => break,
+                        Some(row) if row.time >= start => rows.push(row),
+                        _ => {}
+                    }
+                }
+            }
+            month = match month.checked_add_months(Months::new(1)) {
+                Some(m) => m,
+                None => break,
+            };
+        }
+
+        Ok(rows
+            .into_iter()
+            .skip(offset.unwrap_or(0))
+            .take(limit.unwrap_or(usize::MAX))
+            .collect())
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn row_round_trips_with_missing_usage() {
+        let row = MetricPvcEntity {
+            time: Utc.with_ymd_and_hms(2025, 3, 1, 4, 0, 0).unwrap(),
+            capacity_bytes: Some(10_737_418_240),
+            requested_bytes: Some(10_000_000_000),
+            used_bytes: None,
+            available_bytes: None,
+            mounted: false,
+        };
+
+        let line = MetricPvcHourFsAdapter::format_row(&row);
+        assert_eq!(line, "2025-03-01T04:00:00+00:00|10737418240|10000000000|||0\n");
+        assert_eq!(MetricPvcHourFsAdapter::parse_line(&line), Some(row));
+    }
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::metric_pvc_hour_adapter;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+
+/// Claim hour rows for the collector, the retention task and the API.
+pub struct MetricPvcHourRepository {
+    adapter: Box<dyn MetricStore<MetricPvcEntity>>,
+}
+
+impl MetricPvcHourRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: metric_pvc_hour_adapter(),
+        }
+    }
+
+    pub fn append_row(&self, key: &str, row: &MetricPvcEntity, now: DateTime<Utc>) -> Result<()> {
+        self.adapter.append_row(key, row, now)
+    }
+
+    pub fn get_row_between(&self, key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<MetricPvcEntity>> {
+        self.adapter.get_row_between(start, end, key, None, None)
+    }
+
+    pub fn cleanup_old(&self, key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.adapter.cleanup_old(key, before)
+    }
+}
+
+impl Default for MetricPvcHourRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
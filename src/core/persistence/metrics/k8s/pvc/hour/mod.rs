@@ -0,0 +1,2 @@
+pub mod metric_pvc_hour_fs_adapter;
+pub mod metric_pvc_hour_repository;
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// One hourly sample of a PersistentVolumeClaim.
+///
+/// Usage comes from the kubelet volume stats of a pod mounting the claim, so
+/// `used_bytes`/`available_bytes` are `None` while no pod mounts it.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct MetricPvcEntity {
+    pub time: DateTime<Utc>,
+    /// Provisioned size (`status.capacity.storage`), what the provider bills
+    pub capacity_bytes: Option<u64>,
+    /// Requested size (`spec.resources.requests.storage`)
+    pub requested_bytes: Option<u64>,
+    pub used_bytes: Option<u64>,
+    pub available_bytes: Option<u64>,
+    /// Whether a running pod mounted the claim when the sample was taken
+    #[serde(default)]
+    pub mounted: bool,
+}
+
+impl MetricPvcEntity {
+    /// Size to charge for: the provisioned capacity, else the request.
+    pub fn billable_bytes(&self) -> Option<u64> {
+        self.capacity_bytes.or(self.requested_bytes)
+    }
+}
+
+/// Metric key of a claim. `_` is not allowed in namespace or claim names, so
+/// the key splits back unambiguously.
+pub fn metric_pvc_key(namespace: &str, claim: &str) -> String {
+    format!("{}_{}", namespace, claim)
+}
+
+/// `(namespace, claim)` of a key built by [`metric_pvc_key`].
+pub fn split_metric_pvc_key(key: &str) -> Option<(&str, &str)> {
+    key.split_once('_')
+}
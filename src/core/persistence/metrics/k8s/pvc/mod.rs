@@ -0,0 +1,2 @@
+pub mod hour;
+pub mod metric_pvc_entity;
@@ -0,0 +1,355 @@
+//! Row schema of the pod, node and container `.rcd` files.
+//!
+//! A line is `TIME|<value>|<value>|...` with the values in the entity's
+//! [`MetricColumnRow::COLUMNS`] order; that list is the only place the order is
+//! spelled out. Adding a metric field means adding a [`MetricColumn`] and
+//! listing it in the entity's `metric_column_row!` below; formatting, parsing
+//! and header-less defaults follow from there.
+//!
+//! Column reads hand [`parse_column`] to the row reader so only `TIME` and the
+//! requested column of each line are parsed.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::metric_store_trait::MetricStoreRow;
+
+/// Value columns of the pod, node and container metric files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricColumn {
+    CpuUsageNanoCores,
+    CpuUsageCoreNanoSeconds,
+    MemoryUsageBytes,
+    MemoryWorkingSetBytes,
+    MemoryRssBytes,
+    MemoryPageFaults,
+    NetworkPhysicalRxBytes,
+    NetworkPhysicalTxBytes,
+    NetworkPhysicalRxErrors,
+    NetworkPhysicalTxErrors,
+    EsUsedBytes,
+    EsCapacityBytes,
+    EsInodesUsed,
+    EsInodes,
+    PvUsedBytes,
+    PvCapacityBytes,
+    PvInodesUsed,
+    PvInodes,
+    FsUsedBytes,
+    FsCapacityBytes,
+    FsInodesUsed,
+    FsInodes,
+    CpuAllocatableNanoCores,
+    MemoryAllocatableBytes,
+    MemoryPressurePercent,
+    DiskPressurePercent,
+}
+
+impl MetricColumn {
+    const ALL: [MetricColumn; 26] = [
+        Self::CpuUsageNanoCores,
+        Self::CpuUsageCoreNanoSeconds,
+        Self::MemoryUsageBytes,
+        Self::MemoryWorkingSetBytes,
+        Self::MemoryRssBytes,
+        Self::MemoryPageFaults,
+        Self::NetworkPhysicalRxBytes,
+        Self::NetworkPhysicalTxBytes,
+        Self::NetworkPhysicalRxErrors,
+        Self::NetworkPhysicalTxErrors,
+        Self::EsUsedBytes,
+        Self::EsCapacityBytes,
+        Self::EsInodesUsed,
+        Self::EsInodes,
+        Self::PvUsedBytes,
+        Self::PvCapacityBytes,
+        Self::PvInodesUsed,
+        Self::PvInodes,
+        Self::FsUsedBytes,
+        Self::FsCapacityBytes,
+        Self::FsInodesUsed,
+        Self::FsInodes,
+        Self::CpuAllocatableNanoCores,
+        Self::MemoryAllocatableBytes,
+        Self::MemoryPressurePercent,
+        Self::DiskPressurePercent,
+    ];
+
+    /// Header name in the metric files, e.g. `CPU_USAGE_NANO_CORES`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::CpuUsageNanoCores => "CPU_USAGE_NANO_CORES",
+            Self::CpuUsageCoreNanoSeconds => "CPU_USAGE_CORE_NANO_SECONDS",
+            Self::MemoryUsageBytes => "MEMORY_USAGE_BYTES",
+            Self::MemoryWorkingSetBytes => "MEMORY_WORKING_SET_BYTES",
+            Self::MemoryRssBytes => "MEMORY_RSS_BYTES",
+            Self::MemoryPageFaults => "MEMORY_PAGE_FAULTS",
+            Self::NetworkPhysicalRxBytes => "NETWORK_PHYSICAL_RX_BYTES",
+            Self::NetworkPhysicalTxBytes => "NETWORK_PHYSICAL_TX_BYTES",
+            Self::NetworkPhysicalRxErrors => "NETWORK_PHYSICAL_RX_ERRORS",
+            Self::NetworkPhysicalTxErrors => "NETWORK_PHYSICAL_TX_ERRORS",
+            Self::EsUsedBytes => "ES_USED_BYTES",
+            Self::EsCapacityBytes => "ES_CAPACITY_BYTES",
+            Self::EsInodesUsed => "ES_INODES_USED",
+            Self::EsInodes => "ES_INODES",
+            Self::PvUsedBytes => "PV_USED_BYTES",
+            Self::PvCapacityBytes => "PV_CAPACITY_BYTES",
+            Self::PvInodesUsed => "PV_INODES_USED",
+            Self::PvInodes => "PV_INODES",
+            Self::FsUsedBytes => "FS_USED_BYTES",
+            Self::FsCapacityBytes => "FS_CAPACITY_BYTES",
+            Self::FsInodesUsed => "FS_INODES_USED",
+            Self::FsInodes => "FS_INODES",
+            Self::CpuAllocatableNanoCores => "CPU_ALLOCATABLE_NANO_CORES",
+            Self::MemoryAllocatableBytes => "MEMORY_ALLOCATABLE_BYTES",
+            Self::MemoryPressurePercent => "MEMORY_PRESSURE_PERCENT",
+            Self::DiskPressurePercent => "DISK_PRESSURE_PERCENT",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.name() == name)
+    }
+
+    /// The column `name` refers to when rows of `T` have it. Unknown names and
+    /// columns of other entities give `None`, and callers keep returning full rows.
+    pub fn of<T: MetricColumnRow>(name: &str) -> Option<Self> {
+        Self::from_name(name).filter(|c| T::COLUMNS.contains(c))
+    }
+}
+
+/// A metric entity stored as `|`-separated `.rcd` rows.
+pub trait MetricColumnRow: MetricStoreRow + Sized {
+    /// Value columns in the order header-less files store them, after `TIME`.
+    const COLUMNS: &'static [MetricColumn];
+
+    /// Leading columns every row has. Columns appended to `COLUMNS` later are
+    /// missing from rows written before them, and read as `None`.
+    const BASE_COLUMNS: usize;
+
+    /// An empty row at `time`.
+    fn at(time: DateTime<Utc>) -> Self;
+
+    fn column(&self, column: MetricColumn) -> Option<u64>;
+
+    fn set_column(&mut self, column: MetricColumn, value: Option<u64>);
+}
+
+/// Header line fields of `T` (`TIME` first), the order header-less files use.
+pub fn metric_header<T: MetricColumnRow>() -> Vec<&'static str> {
+    std::iter::once("TIME").chain(T::COLUMNS.iter().map(|c| c.name())).collect()
+}
+
+/// One `.rcd` line for `row` (newline-terminated, time first). Missing values
+/// are written as empty fields.
+pub fn format_metric_row<T: MetricColumnRow>(row: &T) -> String {
+    let mut line = row.time().to_rfc3339_opts(SecondsFormat::Secs, false);
+    for column in T::COLUMNS {
+        line.push('|');
+        if let Some(v) = row.column(*column) {
+            line.push_str(&v.to_string());
+        }
+    }
+    line.push('\n');
+    line
+}
+
+/// Parses a full row of `T`. Values are taken by position in `T::COLUMNS`;
+/// `header` only sets the expected field count (rows from before appended
+/// columns are accepted too), and an empty header accepts rows of any length.
+/// Missing trailing values stay `None`.
+pub fn parse_metric_row<T: MetricColumnRow>(header: &[&str], line: &str) -> Option<T> {
+    let mut parts = line.split('|');
+    let mut row = T::at(parts.next()?.parse::<DateTime<Utc>>().ok()?);
+    let mut fields = 1;
+    for part in parts {
+        if let Some(column) = T::COLUMNS.get(fields - 1) {
+            row.set_column(*column, part.parse().ok());
+        }
+        fields += 1;
+    }
+    if !header.is_empty() && fields != header.len() && fields != T::BASE_COLUMNS + 1 {
+        return None;
+    }
+    Some(row)
+}
+
+/// Parses `TIME` and `column` of `line`; every other field stays `None`.
+/// Field count and positions follow [`parse_metric_row`].
+pub fn parse_column<T: MetricColumnRow>(header: &[&str], line: &str, column: MetricColumn) -> Option<T> {
+    let index = T::COLUMNS.iter().position(|c| *c == column)? + 1;
+
+    let mut fields = 0;
+    let mut time = None;
+    let mut value = None;
+    for (i, part) in line.split('|').enumerate() {
+        if i == 0 {
+            time = Some(part);
+        } else if i == index {
+            value = Some(part);
+        }
+        fields += 1;
+    }
+    if !header.is_empty() && fields != header.len() && fields != T::BASE_COLUMNS + 1 {
+        return None;
+    }
+
+    let mut row = T::at(time?.parse::<DateTime<Utc>>().ok()?);
+    row.set_column(column, value.and_then(|v| v.parse().ok()));
+    Some(row)
+}
+
+/// Implements [`MetricColumnRow`] from the entity's column list, in file order.
+/// Columns added to an existing entity go into the `; appended` group, after the
+/// columns older rows already have.
+macro_rules! metric_column_row {
+    ($entity:ty {
+        $($column:ident => $field:ident),+ $(,)?
+        $(; appended: { $($added:ident => $added_field:ident,)+ })?
+    }) => {
+        impl MetricColumnRow for $entity {
+            const COLUMNS: &'static [MetricColumn] =
+                &[$(MetricColumn::$column,)+ $($(MetricColumn::$added,)+)?];
+            const BASE_COLUMNS: usize = [$(MetricColumn::$column,)+].len();
+
+            fn at(time: DateTime<Utc>) -> Self {
+                Self { time, ..Default::default() }
+            }
+
+            fn column(&self, column: MetricColumn) -> Option<u64> {
+                match column {
+                    $(MetricColumn::$column => self.$field,)+
+                    $($(MetricColumn::$added => self.$added_field,)+)?
+                    _ => None,
+                }
+            }
+
+            fn set_column(&mut self, column: MetricColumn, value: Option<u64>) {
+                match column {
+                    $(MetricColumn::$column => self.$field = value,)+
+                    $($(MetricColumn::$added => self.$added_field = value,)+)?
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+metric_column_row!(MetricPodEntity {
+    CpuUsageNanoCores => cpu_usage_nano_cores,
+    CpuUsageCoreNanoSeconds => cpu_usage_core_nano_seconds,
+    MemoryUsageBytes => memory_usage_bytes,
+    MemoryWorkingSetBytes => memory_working_set_bytes,
+    MemoryRssBytes => memory_rss_bytes,
+    MemoryPageFaults => memory_page_faults,
+    NetworkPhysicalRxBytes => network_physical_rx_bytes,
+    NetworkPhysicalTxBytes => network_physical_tx_bytes,
+    NetworkPhysicalRxErrors => network_physical_rx_errors,
+    NetworkPhysicalTxErrors => network_physical_tx_errors,
+    EsUsedBytes => es_used_bytes,
+    EsCapacityBytes => es_capacity_bytes,
+    EsInodesUsed => es_inodes_used,
+    EsInodes => es_inodes,
+    PvUsedBytes => pv_used_bytes,
+    PvCapacityBytes => pv_capacity_bytes,
+    PvInodesUsed => pv_inodes_used,
+    PvInodes => pv_inodes,
+});
+
+metric_column_row!(MetricNodeEntity {
+    CpuUsageNanoCores => cpu_usage_nano_cores,
+    CpuUsageCoreNanoSeconds => cpu_usage_core_nano_seconds,
+    MemoryUsageBytes => memory_usage_bytes,
+    MemoryWorkingSetBytes => memory_working_set_bytes,
+    MemoryRssBytes => memory_rss_bytes,
+    MemoryPageFaults => memory_page_faults,
+    NetworkPhysicalRxBytes => network_physical_rx_bytes,
+    NetworkPhysicalTxBytes => network_physical_tx_bytes,
+    NetworkPhysicalRxErrors => network_physical_rx_errors,
+    NetworkPhysicalTxErrors => network_physical_tx_errors,
+    FsUsedBytes => fs_used_bytes,
+    FsCapacityBytes => fs_capacity_bytes,
+    FsInodesUsed => fs_inodes_used,
+    FsInodes => fs_inodes;
+    appended: {
+        CpuAllocatableNanoCores => cpu_allocatable_nano_cores,
+        MemoryAllocatableBytes => memory_allocatable_bytes,
+        MemoryPressurePercent => memory_pressure_percent,
+        DiskPressurePercent => disk_pressure_percent,
+    }
+});
+
+metric_column_row!(MetricContainerEntity {
+    CpuUsageNanoCores => cpu_usage_nano_cores,
+    CpuUsageCoreNanoSeconds => cpu_usage_core_nano_seconds,
+    MemoryUsageBytes => memory_usage_bytes,
+    MemoryWorkingSetBytes => memory_working_set_bytes,
+    MemoryRssBytes => memory_rss_bytes,
+    MemoryPageFaults => memory_page_faults,
+    FsUsedBytes => fs_used_bytes,
+    FsCapacityBytes => fs_capacity_bytes,
+    FsInodesUsed => fs_inodes_used,
+    FsInodes => fs_inodes,
+});
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_only_the_requested_column() {
+        let header = ["TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS", "MEMORY_USAGE_BYTES",
+            "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES", "MEMORY_PAGE_FAULTS", "FS_USED_BYTES",
+            "FS_CAPACITY_BYTES", "FS_INODES_USED", "FS_INODES"];
+        let line = "2025-03-01T10:00:00Z|100|200|300|400|500|6|700|800|9|10";
+
+        let column = MetricColumn::of::<MetricContainerEntity>("MEMORY_WORKING_SET_BYTES").unwrap();
+        let row: MetricContainerEntity = parse_column(&header, line, column).unwrap();
+        assert_eq!(row.memory_working_set_bytes, Some(400));
+        assert_eq!(row.cpu_usage_nano_cores, None);
+        assert_eq!(row.fs_inodes, None);
+
+        // Header-less order; short lines are rejected when the header is known
+        let row: MetricContainerEntity = parse_column(&[], line, MetricColumn::FsInodes).unwrap();
+        assert_eq!(row.fs_inodes, Some(10));
+        assert!(parse_column::<MetricContainerEntity>(&header, "2025-03-01T10:00:00Z|1", column).is_none());
+
+        // Pod-only columns aren't projected on containers
+        assert!(MetricColumn::of::<MetricContainerEntity>("PV_USED_BYTES").is_none());
+        assert!(MetricColumn::of::<MetricContainerEntity>("NOPE").is_none());
+    }
+
+    #[test]
+    fn rows_round_trip_in_header_order() {
+        let row = MetricNodeEntity {
+            time: "2025-03-01T10:00:00Z".parse().unwrap(),
+            cpu_usage_nano_cores: Some(100),
+            fs_inodes: Some(7),
+            disk_pressure_percent: Some(100),
+            ..Default::default()
+        };
+
+        let line = format_metric_row(&row);
+        assert_eq!(line, "2025-03-01T10:00:00+00:00|100|||||||||||||7||||100\n");
+
+        let header = metric_header::<MetricNodeEntity>();
+        assert_eq!(header.len(), 19);
+        let parsed: MetricNodeEntity = parse_metric_row(&header, line.trim_end()).unwrap();
+        assert_eq!(parsed.time, row.time);
+        assert_eq!(parsed.cpu_usage_nano_cores, Some(100));
+        assert_eq!(parsed.memory_usage_bytes, None);
+        assert_eq!(parsed.fs_inodes, Some(7));
+        assert_eq!(parsed.disk_pressure_percent, Some(100));
+
+        // Rows written before the appended columns still parse
+        let legacy = "2025-03-01T10:00:00Z|100|||||||||||||7";
+        let parsed: MetricNodeEntity = parse_metric_row(&header, legacy).unwrap();
+        assert_eq!(parsed.fs_inodes, Some(7));
+        assert_eq!(parsed.memory_allocatable_bytes, None);
+        assert!(parse_metric_row::<MetricNodeEntity>(&header, "2025-03-01T10:00:00Z|100|").is_none());
+    }
+}
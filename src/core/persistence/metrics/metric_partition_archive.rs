@@ -0,0 +1,191 @@
+use anyhow::Result;
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::Path,
+    sync::{OnceLock, RwLock},
+};
+
+use super::k8s::path::{metric_k8s_archive_dir_path, metric_k8s_archive_file_path, metric_k8s_archive_index_path};
+use super::metric_partition_index::MetricPartitionIndex;
+
+/// `(scope, key, partition)` → namespace holding the archived rows.
+type ArchiveIndex = HashMap<(String, String, String), String>;
+
+/// Per-namespace archives of small, closed metric partitions.
+///
+/// Short-lived objects leave one tiny `.rcd` file per partition behind. Compaction
+/// moves those rows into `archive/{scope}/{namespace}/{partition}.rca`, one
+/// `KEY|ROW` line per row, and records the move in `archive/index`
+/// (`SCOPE|KEY|PARTITION|NAMESPACE` lines) so readers know where to look without
+/// knowing the namespace. Archive writes and the index entry land before the
+/// original file is removed; a crash in between leaves duplicate rows, which
+/// readers drop by timestamp.
+pub struct MetricPartitionArchive;
+
+impl MetricPartitionArchive {
+    /// Moves the partition file of `key` into the namespace archive. Returns the
+    /// number of rows moved; files with a header line are left in place.
+    pub fn absorb(scope: &str, key: &str, partition: &str, namespace: &str, file: &Path) -> Result<usize> {
+        let lines: Vec<String> = BufReader::new(File::open(file)?)
+            .lines()
+            .collect::<std::io::Result<_>>()?;
+        if lines.first().is_some_and(|l| !l.starts_with("20")) {
+            return Ok(0);
+        }
+
+        let archive_path = metric_k8s_archive_file_path(scope, namespace, partition);
+        if let Some(parent) = archive_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut archive = OpenOptions::new().create(true).append(true).open(&archive_path)?;
+        let mut buf = String::new();
+        for line in lines.iter().filter(|l| !l.trim().is_empty()) {
+            buf.push_str(key);
+            buf.push('|');
+            buf.push_str(line);
+            buf.push('\n');
+        }
+        archive.write_all(buf.as_bytes())?;
+        archive.sync_all()?;
+
+        {
+            let mut index = Self::index().write().unwrap();
+            let entry = (scope.to_string(), key.to_string(), partition.to_string());
+            if index.get(&entry).map(String::as_str) != Some(namespace) {
+                let mut f = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(metric_k8s_archive_index_path())?;
+                f.write_all(format!("{}|{}|{}|{}\n", scope, key, partition, namespace).as_bytes())?;
+                f.sync_all()?;
+                index.insert(entry, namespace.to_string());
+            }
+        }
+
+        fs::remove_file(file)?;
+        MetricPartitionIndex::remove(file);
+        Ok(lines.len())
+    }
+
+    /// Archived rows of `key` in `partition`, without the key prefix; empty when
+    /// the partition was never compacted.
+    pub fn rows(scope: &str, key: &str, partition: &str) -> Result<Vec<String>> {
+        let namespace = {
+            let index = Self::index().read().unwrap();
+            match index.get(&(scope.to_string(), key.to_string(), partition.to_string())) {
+                Some(ns) => ns.clone(),
+                None => return Ok(Vec::new()),
+            }
+        };
+
+        let path = metric_k8s_archive_file_path(scope, &namespace, partition);
+        let file = match File::open(&path) {
+            Ok(f) => f,
+            Err(e) => {
+                tracing::warn!("Archive {:?} listed in the index but unreadable: {}", path, e);
+                return Ok(Vec::new());
+            }
+        };
+
+        let prefix = format!("{}|", key);
+        let mut rows = Vec::new();
+        for line in BufReader::new(file).lines() {
+            if let Some(row) = line?.strip_prefix(&prefix) {
+                rows.push(row.to_string());
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Drops archives of `scope` partitions under `partition_dir` (e.g. `h`) whose
+    /// name sorts before `before` (e.g. `2025-03`), mirroring per-object retention.
+    pub fn cleanup_before(scope: &str, partition_dir: &str, before: &str) -> Result<()> {
+        let scope_dir = metric_k8s_archive_dir_path().join(scope);
+        if !scope_dir.exists() {
+            return Ok(());
+        }
+
+        for ns_entry in fs::read_dir(&scope_dir)? {
+            let dir = ns_entry?.path().join(partition_dir);
+            let Ok(entries) = fs::read_dir(&dir) else { continue };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let expired = path.extension().and_then(|e| e.to_str()) == Some("rca")
+                    && path.file_stem().and_then(|s| s.to_str()).is_some_and(|s| s < before);
+                if expired {
+                    if let Err(e) = fs::remove_file(&path) {
+                        tracing::error!("Failed to delete archive {:?}: {}", path, e);
+                    } else {
+                        tracing::info!("Deleted old metric archive {:?}", path);
+                    }
+                }
+            }
+        }
+
+        let cutoff = format!("{}/{}", partition_dir, before);
+        let prefix = format!("{}/", partition_dir);
+        let mut index = Self::index().write().unwrap();
+        let before_len = index.len();
+        index.retain(|(s, _, p), _| !(s == scope && p.starts_with(&prefix) && *p < cutoff));
+        if index.len() != before_len {
+            Self::write_index(&index)?;
+        }
+        Ok(())
+    }
+
+    fn index() -> &'static RwLock<ArchiveIndex> {
+        static INDEX: OnceLock<RwLock<ArchiveIndex>> = OnceLock::new();
+        INDEX.get_or_init(|| RwLock::new(Self::load_index(&metric_k8s_archive_index_path())))
+    }
+
+    fn load_index(path: &Path) -> ArchiveIndex {
+        let mut index = HashMap::new();
+        let Ok(file) = File::open(path) else { return index };
+        for line in BufReader::new(file).lines().map_while(std::result::Result::ok) {
+            let parts: Vec<&str> = line.split('|').collect();
+            if let [scope, key, partition, namespace] = parts[..] {
+                index.insert((scope.to_string(), key.to_string(), partition.to_string()), namespace.to_string());
+            }
+        }
+        index
+    }
+
+    fn write_index(index: &ArchiveIndex) -> Result<()> {
+        let path = metric_k8s_archive_index_path();
+        let tmp = path.with_extension("tmp");
+        let mut out = String::new();
+        for ((scope, key, partition), namespace) in index {
+            out.push_str(&format!("{}|{}|{}|{}\n", scope, key, partition, namespace));
+        }
+        let mut f = File::create(&tmp)?;
+        f.write_all(out.as_bytes())?;
+        f.sync_all()?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_index_skips_malformed_lines() {
+        let dir = std::env::temp_dir().join("rustcost_partition_archive_test");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("index");
+        fs::write(&path, "pod|uid-a|h/2025-01|shop\nbroken line\npod|uid-b|h/2025-02|web\n").unwrap();
+
+        let index = MetricPartitionArchive::load_index(&path);
+
+        assert_eq!(index.len(), 2);
+        assert_eq!(
+            index.get(&("pod".to_string(), "uid-a".to_string(), "h/2025-01".to_string())),
+            Some(&"shop".to_string())
+        );
+    }
+}
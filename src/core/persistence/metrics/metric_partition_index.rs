@@ -0,0 +1,171 @@
+use anyhow::Result;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+/// Sidecar time index for `.rcd` metric partitions.
+///
+/// Each partition file may have a `.idx` file next to it holding one
+/// `BUCKET|OFFSET` line per bucket (e.g. one per day inside a monthly hour file).
+/// `OFFSET` is the byte position of the first row of that bucket, so readers can
+/// seek straight to the requested range instead of scanning the whole file.
+///
+/// Buckets are formatted so that lexical order matches time order
+/// (`%Y-%m-%d`, `%Y-%m-%dT%H`, ...). Rows are assumed to be appended in time order,
+/// which is the same assumption the readers make when they stop at `end`.
+pub struct MetricPartitionIndex;
+
+impl MetricPartitionIndex {
+    pub fn index_path(data_path: &Path) -> PathBuf {
+        data_path.with_extension("idx")
+    }
+
+    /// Record `offset` as the first row of `bucket`.
+    ///
+    /// Buckets already covered by the index are ignored. A row written at offset 0
+    /// means the data file was (re)created, so any previous index is discarded.
+    pub fn record(data_path: &Path, bucket: &str, offset: u64) -> Result<()> {
+        let idx_path = Self::index_path(data_path);
+
+        if offset == 0 && idx_path.exists() {
+            fs::remove_file(&idx_path)?;
+        }
+
+        if let Some((last, _)) = Self::read_entries(&idx_path).last() {
+            if bucket <= last.as_str() {
+                return Ok(());
+            }
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&idx_path)?;
+        file.write_all(format!("{}|{}\n", bucket, offset).as_bytes())?;
+        Ok(())
+    }
+
+    /// Returns the byte offset to start reading from for rows at or after `bucket`.
+    ///
+    /// `None` means "read from the beginning" (no index, stale index, or the
+    /// requested bucket precedes everything in the file).
+    pub fn seek_offset(data_path: &Path, bucket: &str) -> Option<u64> {
+        let entries = Self::read_entries(&Self::index_path(data_path));
+        if entries.is_empty() {
+            return None;
+        }
+
+        let file_len = fs::metadata(data_path).ok()?.len();
+
+        let offset = entries
+            .iter()
+            .find(|(b, _)| b.as_str() >= bucket)
+            .or_else(|| entries.last())
+            .map(|(_, o)| *o)?;
+
+        // Offsets beyond EOF mean the data file was rewritten without the index.
+        if offset == 0 || offset > file_len {
+            return None;
+        }
+
+        Some(offset)
+    }
+
+    /// Remove the sidecar index for a data file, if any.
+    pub fn remove(data_path: &Path) {
+        let idx_path = Self::index_path(data_path);
+        if idx_path.exists() {
+            if let Err(e) = fs::remove_file(&idx_path) {
+                tracing::warn!("Failed to delete index {:?}: {}", idx_path, e);
+            }
+        }
+    }
+
+    /// Overwrite the index with `entries` (bucket, first-row offset), in file order.
+    pub fn replace(data_path: &Path, entries: &[(String, u64)]) -> Result<()> {
+        let body: String = entries
+            .iter()
+            .map(|(b, o)| format!("{}|{}\n", b, o))
+            .collect();
+        fs::write(Self::index_path(data_path), body)?;
+        Ok(())
+    }
+
+    /// Drop entries pointing at or past `len` after the data file was truncated.
+    pub fn truncate(data_path: &Path, len: u64) -> Result<()> {
+        let idx_path = Self::index_path(data_path);
+        let entries = Self::read_entries(&idx_path);
+        if entries.iter().all(|(_, o)| *o < len) {
+            return Ok(());
+        }
+
+        let kept: String = entries
+            .iter()
+            .filter(|(_, o)| *o < len)
+            .map(|(b, o)| format!("{}|{}\n", b, o))
+            .collect();
+        fs::write(&idx_path, kept)?;
+        Ok(())
+    }
+
+    fn read_entries(idx_path: &Path) -> Vec<(String, u64)> {
+        let file = match File::open(idx_path) {
+            Ok(f) => f,
+            Err(_) => return Vec::new(),
+        };
+
+        BufReader::new(file)
+            .lines()
+            .map_while(|l| l.ok())
+            .filter_map(|line| {
+                let (bucket, offset) = line.split_once('|')?;
+                Some((bucket.to_string(), offset.trim().parse().ok()?))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_data_file(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join("rustcost_partition_index_test");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.rcd", name));
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(MetricPartitionIndex::index_path(&path));
+        path
+    }
+
+    #[test]
+    fn seeks_to_first_bucket_at_or_after_request() {
+        let path = temp_data_file("seek");
+        fs::write(&path, "a\nb\nc\nd\n").unwrap();
+
+        MetricPartitionIndex::record(&path, "2025-02-01", 0).unwrap();
+        MetricPartitionIndex::record(&path, "2025-02-02", 2).unwrap();
+        MetricPartitionIndex::record(&path, "2025-02-04", 6).unwrap();
+        // Already covered bucket is ignored
+        MetricPartitionIndex::record(&path, "2025-02-02", 4).unwrap();
+
+        assert_eq!(MetricPartitionIndex::seek_offset(&path, "2025-02-01"), None);
+        assert_eq!(MetricPartitionIndex::seek_offset(&path, "2025-02-02"), Some(2));
+        assert_eq!(MetricPartitionIndex::seek_offset(&path, "2025-02-03"), Some(6));
+        assert_eq!(MetricPartitionIndex::seek_offset(&path, "2025-02-20"), Some(6));
+
+        MetricPartitionIndex::remove(&path);
+        assert_eq!(MetricPartitionIndex::seek_offset(&path, "2025-02-02"), None);
+    }
+
+    #[test]
+    fn ignores_offsets_past_end_of_file() {
+        let path = temp_data_file("stale");
+        fs::write(&path, "a\n").unwrap();
+        fs::write(MetricPartitionIndex::index_path(&path), "2025-02-01|0\n2025-02-02|500\n").unwrap();
+
+        assert_eq!(MetricPartitionIndex::seek_offset(&path, "2025-02-02"), None);
+    }
+}
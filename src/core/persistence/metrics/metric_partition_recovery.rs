@@ -0,0 +1,127 @@
+use anyhow::Result;
+use std::{
+    fs::{self, OpenOptions},
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+use tracing::{info, warn};
+
+use super::metric_partition_index::MetricPartitionIndex;
+
+const TAIL_CHUNK: u64 = 4096;
+
+/// Summary of a startup recovery pass over `.rcd` partitions.
+#[derive(Debug, Default)]
+pub struct MetricRecoveryReport {
+    pub scanned_files: usize,
+    pub repaired_files: usize,
+    pub truncated_bytes: u64,
+}
+
+/// Repairs metric partitions left behind by a crash mid-append.
+///
+/// Rows are newline-terminated, so a file whose last byte is not `\n` ends in a
+/// partially written row. Left alone, the next append would glue a valid row onto
+/// that fragment and both would be dropped by the parsers. The fragment is cut off
+/// at the last complete line and the sidecar index is trimmed to match.
+pub fn recover_metric_partitions(root: &Path) -> Result<MetricRecoveryReport> {
+    let mut report = MetricRecoveryReport::default();
+    if !root.exists() {
+        return Ok(report);
+    }
+
+    let mut stack: Vec<PathBuf> = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+                continue;
+            }
+
+            report.scanned_files += 1;
+            match repair_partition(&path) {
+                Ok(0) => {}
+                Ok(cut) => {
+                    warn!("🧹 Truncated {} byte(s) of incomplete row from {:?}", cut, path);
+                    report.repaired_files += 1;
+                    report.truncated_bytes += cut;
+                }
+                Err(e) => warn!("⚠️ Failed to check partition {:?}: {}", path, e),
+            }
+        }
+    }
+
+    info!(
+        scanned = report.scanned_files,
+        repaired = report.repaired_files,
+        truncated_bytes = report.truncated_bytes,
+        "Metric partition recovery finished"
+    );
+    Ok(report)
+}
+
+/// Truncates a trailing incomplete line. Returns the number of bytes removed.
+fn repair_partition(path: &Path) -> Result<u64> {
+    let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+    let len = file.metadata()?.len();
+    if len == 0 {
+        return Ok(0);
+    }
+
+    let keep = complete_prefix_len(&mut file, len)?;
+    if keep == len {
+        return Ok(0);
+    }
+
+    file.set_len(keep)?;
+    file.sync_all()?;
+    MetricPartitionIndex::truncate(path, keep)?;
+    Ok(len - keep)
+}
+
+/// Length of the file up to and including the last `\n` (0 if there is none).
+fn complete_prefix_len<R: Read + Seek>(file: &mut R, len: u64) -> Result<u64> {
+    let mut end = len;
+    let mut buf = vec![0u8; TAIL_CHUNK as usize];
+
+    while end > 0 {
+        let start = end.saturating_sub(TAIL_CHUNK);
+        let chunk = &mut buf[..(end - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(chunk)?;
+
+        if let Some(pos) = chunk.iter().rposition(|b| *b == b'\n') {
+            return Ok(start + pos as u64 + 1);
+        }
+        end = start;
+    }
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_partial_trailing_row_only() {
+        let dir = std::env::temp_dir().join("rustcost_partition_recovery_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("pod/a/m")).unwrap();
+
+        let clean = dir.join("pod/a/m/2025-02-01.rcd");
+        let torn = dir.join("pod/a/m/2025-02-02.rcd");
+        fs::write(&clean, "r1\nr2\n").unwrap();
+        fs::write(&torn, "r1\nr2\nr3-partial").unwrap();
+
+        let report = recover_metric_partitions(&dir).unwrap();
+        assert_eq!(report.scanned_files, 2);
+        assert_eq!(report.repaired_files, 1);
+        assert_eq!(report.truncated_bytes, 10);
+        assert_eq!(fs::read_to_string(&clean).unwrap(), "r1\nr2\n");
+        assert_eq!(fs::read_to_string(&torn).unwrap(), "r1\nr2\n");
+    }
+}
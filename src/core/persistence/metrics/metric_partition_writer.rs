@@ -0,0 +1,181 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use super::metric_partition_index::MetricPartitionIndex;
+
+const TAIL_CHUNK: u64 = 4096;
+
+/// Time-keyed writes for aggregated `.rcd` partitions (hour/day).
+///
+/// Aggregation jobs may replay a window after a restart or a backfill, so writing
+/// a row whose timestamp is already present must replace it instead of adding a
+/// duplicate. Rows are kept in time order, which the readers rely on.
+pub struct MetricPartitionWriter;
+
+impl MetricPartitionWriter {
+    /// Write `row` (newline-terminated, first field = RFC 3339 time) for `time`.
+    ///
+    /// The common case — `time` is newer than the last row — is a plain append.
+    /// Otherwise the partition is rewritten with rows for `time` replaced and the
+    /// new row placed in order. `index_bucket` is the chrono format of the sidecar
+    /// index buckets (e.g. `%Y-%m-%d`), or `None` if the partition isn't indexed.
+    pub fn upsert_row(
+        path: &Path,
+        time: DateTime<Utc>,
+        row: &str,
+        index_bucket: Option<&str>,
+    ) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        match Self::last_row_time(path)? {
+            Some(last) if last >= time => Self::rewrite_with(path, time, row, index_bucket),
+            _ => Self::append(path, time, row, index_bucket),
+        }
+    }
+
+    fn append(path: &Path, time: DateTime<Utc>, row: &str, index_bucket: Option<&str>) -> Result<()> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let offset = file.metadata()?.len();
+        let mut writer = BufWriter::new(file);
+        writer.write_all(row.as_bytes())?;
+        writer.flush()?;
+
+        // Track where each bucket starts so reads can seek past earlier ones
+        if let Some(fmt) = index_bucket {
+            let bucket = time.format(fmt).to_string();
+            if let Err(e) = MetricPartitionIndex::record(path, &bucket, offset) {
+                tracing::warn!("Failed to update index for {:?}: {}", path, e);
+            }
+        }
+        Ok(())
+    }
+
+    fn rewrite_with(
+        path: &Path,
+        time: DateTime<Utc>,
+        row: &str,
+        index_bucket: Option<&str>,
+    ) -> Result<()> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut lines: Vec<String> = Vec::new();
+        let mut inserted = false;
+        let mut replaced = 0usize;
+
+        for line in reader.lines() {
+            let line = line?;
+            match Self::row_time(&line) {
+                Some(t) if t == time => {
+                    replaced += 1;
+                    continue;
+                }
+                Some(t) if t > time && !inserted => {
+                    lines.push(row.trim_end_matches('\n').to_string());
+                    inserted = true;
+                }
+                _ => {}
+            }
+            lines.push(line);
+        }
+        if !inserted {
+            lines.push(row.trim_end_matches('\n').to_string());
+        }
+
+        // Write a sibling file, then rename over the original
+        let tmp = path.with_extension("rcd.tmp");
+        let mut entries: Vec<(String, u64)> = Vec::new();
+        {
+            let mut writer = BufWriter::new(File::create(&tmp)?);
+            let mut offset = 0u64;
+            for line in &lines {
+                if let (Some(fmt), Some(t)) = (index_bucket, Self::row_time(line)) {
+                    let bucket = t.format(fmt).to_string();
+                    if !entries.last().is_some_and(|(b, _)| *b >= bucket) {
+                        entries.push((bucket, offset));
+                    }
+                }
+                writer.write_all(line.as_bytes())?;
+                writer.write_all(b"\n")?;
+                offset += line.len() as u64 + 1;
+            }
+            writer.flush()?;
+            writer.get_ref().sync_all()?;
+        }
+        fs::rename(&tmp, path)?;
+
+        if index_bucket.is_some() {
+            if let Err(e) = MetricPartitionIndex::replace(path, &entries) {
+                tracing::warn!("Failed to rebuild index for {:?}: {}", path, e);
+                MetricPartitionIndex::remove(path);
+            }
+        }
+
+        if replaced > 0 {
+            tracing::debug!("Replaced {} row(s) at {} in {:?}", replaced, time, path);
+        }
+        Ok(())
+    }
+
+    /// Timestamp of the last complete row, if any.
+    fn last_row_time(path: &Path) -> Result<Option<DateTime<Utc>>> {
+        let mut file = match File::open(path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let len = file.metadata()?.len();
+        let start = len.saturating_sub(TAIL_CHUNK);
+        file.seek(SeekFrom::Start(start))?;
+        let mut tail = Vec::with_capacity((len - start) as usize);
+        file.read_to_end(&mut tail)?;
+
+        let tail = String::from_utf8_lossy(&tail);
+        Ok(tail
+            .lines()
+            .rev()
+            .find_map(Self::row_time))
+    }
+
+    fn row_time(line: &str) -> Option<DateTime<Utc>> {
+        line.split('|').next()?.parse::<DateTime<Utc>>().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn row(t: DateTime<Utc>, v: u64) -> String {
+        format!("{}|{}\n", t.to_rfc3339_opts(chrono::SecondsFormat::Secs, false), v)
+    }
+
+    #[test]
+    fn replays_replace_rows_and_keep_time_order() {
+        let dir = std::env::temp_dir().join("rustcost_partition_writer_test");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("2025-02.rcd");
+
+        let t1 = Utc.with_ymd_and_hms(2025, 2, 1, 1, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2025, 2, 1, 2, 0, 0).unwrap();
+        let t3 = Utc.with_ymd_and_hms(2025, 2, 2, 1, 0, 0).unwrap();
+
+        MetricPartitionWriter::upsert_row(&path, t1, &row(t1, 1), Some("%Y-%m-%d")).unwrap();
+        MetricPartitionWriter::upsert_row(&path, t3, &row(t3, 3), Some("%Y-%m-%d")).unwrap();
+        // Replay of t3 and a late t2 (backfill)
+        MetricPartitionWriter::upsert_row(&path, t3, &row(t3, 30), Some("%Y-%m-%d")).unwrap();
+        MetricPartitionWriter::upsert_row(&path, t2, &row(t2, 2), Some("%Y-%m-%d")).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content, format!("{}{}{}", row(t1, 1), row(t2, 2), row(t3, 30)));
+
+        let day2_offset = (row(t1, 1).len() + row(t2, 2).len()) as u64;
+        assert_eq!(MetricPartitionIndex::seek_offset(&path, "2025-02-02"), Some(day2_offset));
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::Result;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+use tracing::error;
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
+use crate::core::persistence::metrics::remote::metric_influx_client::MetricInfluxClient;
+use crate::core::persistence::metrics::sqlite::metric_sqlite_store::MetricSqliteStore;
+
+/// Where metric rows are persisted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricStorageBackend {
+    /// One directory per object with `.rcd` partition files (default).
+    Fs,
+    /// A single SQLite database per cluster.
+    Sqlite,
+    /// An external InfluxDB-compatible TSDB; nothing is stored locally.
+    Influx,
+}
+
+impl MetricStorageBackend {
+    /// `None` for unknown values; settings updates reject them.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "" | "fs" => Some(MetricStorageBackend::Fs),
+            "sqlite" => Some(MetricStorageBackend::Sqlite),
+            "influx" | "influxdb" => Some(MetricStorageBackend::Influx),
+            _ => None,
+        }
+    }
+}
+
+/// Backend selected by the `storage_backend` setting.
+///
+/// Resolved once per process; changing the setting takes effect after a restart
+/// so collectors and readers never disagree on where rows live. An unknown value
+/// (only possible by editing the settings file) is logged and runs on the FS backend.
+pub fn metric_storage_backend() -> MetricStorageBackend {
+    static BACKEND: OnceLock<MetricStorageBackend> = OnceLock::new();
+
+    *BACKEND.get_or_init(|| {
+        let settings = InfoSettingFsAdapter::new()
+            .read()
+            .unwrap_or_else(|_| InfoSettingEntity::default());
+        MetricStorageBackend::parse(&settings.storage_backend).unwrap_or_else(|| {
+            error!(
+                storage_backend = %settings.storage_backend,
+                "Unknown storage_backend setting; storing metrics on the filesystem"
+            );
+            MetricStorageBackend::Fs
+        })
+    })
+}
+
+/// Object keys (pod UIDs, node names, container keys) that have stored metrics.
+///
+/// `fs_base_dir` is the scope directory scanned when the FS backend is active.
+pub fn metric_object_keys(scope: &str, fs_base_dir: &Path) -> Result<Vec<String>> {
+    match metric_storage_backend() {
+        MetricStorageBackend::Sqlite => MetricSqliteStore::object_keys(scope),
+        MetricStorageBackend::Influx => MetricInfluxClient::shared()?.object_keys(scope),
+        MetricStorageBackend::Fs => {
+            let mut keys = Vec::new();
+            if !fs_base_dir.exists() {
+                return Ok(keys);
+            }
+
+            for entry in fs::read_dir(fs_base_dir)? {
+                let entry = entry?;
+                if entry.path().is_dir() {
+                    if let Some(key) = entry.file_name().to_str() {
+                        keys.push(key.to_string());
+                    }
+                }
+            }
+            Ok(keys)
+        }
+    }
+}
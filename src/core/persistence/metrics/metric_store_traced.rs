@@ -0,0 +1,85 @@
+//! [`MetricStore`] decorator that wraps reads in tracing spans.
+//!
+//! Every adapter built by the metric adapter factory goes through [`traced`], so
+//! slow queries show which scope/granularity and object the time went to,
+//! whatever the storage backend. It is also where every metric read and write
+//! is moved off the async executor, see [`blocking_io`].
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::{info_span, field};
+
+use crate::core::persistence::blocking_io::blocking_io;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+
+pub struct TracedMetricStore<T> {
+    scope: &'static str,
+    granularity: &'static str,
+    inner: Box<dyn MetricStore<T>>,
+}
+
+/// Wraps `inner` so its reads are recorded as `metric_store.read` spans.
+pub fn traced<T: 'static>(
+    scope: &'static str,
+    granularity: &'static str,
+    inner: Box<dyn MetricStore<T>>,
+) -> Box<dyn MetricStore<T>> {
+    Box::new(TracedMetricStore { scope, granularity, inner })
+}
+
+impl<T> TracedMetricStore<T> {
+    fn read_span(&self, object_name: &str) -> tracing::Span {
+        info_span!(
+            "metric_store.read",
+            scope = self.scope,
+            granularity = self.granularity,
+            object = object_name,
+            rows = field::Empty,
+        )
+    }
+}
+
+impl<T> MetricStore<T> for TracedMetricStore<T> {
+    fn append_row(&self, name: &str, data: &T, now: DateTime<Utc>) -> Result<()> {
+        blocking_io(|| self.inner.append_row(name, data, now))
+    }
+
+    fn append_row_aggregated(&self, pod_uid: &str, start: DateTime<Utc>, end: DateTime<Utc>, now: DateTime<Utc>) -> Result<()> {
+        blocking_io(|| self.inner.append_row_aggregated(pod_uid, start, end, now))
+    }
+
+    fn cleanup_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        blocking_io(|| self.inner.cleanup_old(name, before))
+    }
+
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        let span = self.read_span(object_name);
+        let _guard = span.enter();
+        let rows = blocking_io(|| self.inner.get_column_between(column_name, start, end, object_name, limit, offset))?;
+        span.record("rows", rows.len());
+        Ok(rows)
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        let span = self.read_span(object_name);
+        let _guard = span.enter();
+        let rows = blocking_io(|| self.inner.get_row_between(start, end, object_name, limit, offset))?;
+        span.record("rows", rows.len());
+        Ok(rows)
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::k8s::network_flow::metric_network_flow_entity::MetricNetworkFlowEntity;
+
+/// Unified storage trait for metrics (collector, processor, and API).
+/// Implemented by the FS adapters, the SQLite backend and the remote TSDB backend;
+/// each implementation may only use a subset of these methods.
+pub trait MetricStore<T>: Send + Sync {
+    // === Collector-like ===
+    /// Append one raw metric row (e.g. per-minute data)
+    #[allow(unused_variables)]
+    fn append_row(&self, name: &str, data: &T, now: DateTime<Utc>) -> Result<()> {
+        unimplemented!("append_row not used in this adapter")
+    }
+
+    // === Processor-like ===
+    /// Append aggregated metrics (e.g. hour, day)
+    #[allow(unused_variables)]
+    fn append_row_aggregated(&self, pod_uid: &str, start: DateTime<Utc>, end: DateTime<Utc>, now: DateTime<Utc>) -> Result<()> {
+        unimplemented!("append_row_aggregated not used in this adapter")
+    }
+
+    /// Remove old metric files before a given timestamp
+    #[allow(unused_variables)]
+    fn cleanup_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        unimplemented!("cleanup_old not used in this adapter")
+    }
+
+    // === API-like ===
+    /// Read a column between timestamps
+    #[allow(unused_variables)]
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        unimplemented!("get_column_between not used in this adapter")
+    }
+
+    /// Read full rows between timestamps
+    #[allow(unused_variables)]
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        unimplemented!("get_row_between not used in this adapter")
+    }
+}
+
+/// Metric entities that non-file backends can serialize generically.
+pub trait MetricStoreRow: Serialize + DeserializeOwned + Send + Sync {
+    fn time(&self) -> DateTime<Utc>;
+}
+
+impl MetricStoreRow for MetricPodEntity {
+    fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+}
+
+impl MetricStoreRow for MetricNodeEntity {
+    fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+}
+
+impl MetricStoreRow for MetricContainerEntity {
+    fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+}
+
+impl MetricStoreRow for MetricPvcEntity {
+    fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+}
+
+impl MetricStoreRow for MetricNetworkFlowEntity {
+    fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+}
+
+/// Builds one aggregated row from lower-granularity rows in `[start, end]`.
+pub type MetricAggregateFn<T> = fn(Vec<T>, DateTime<Utc>, DateTime<Utc>) -> Result<T>;
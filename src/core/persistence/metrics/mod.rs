@@ -1,2 +1,11 @@
-pub mod metric_fs_adapter_base_trait;
+pub mod metric_store_trait;
+pub mod metric_store_traced;
+pub mod metric_column;
+pub mod metric_partition_index;
+pub mod metric_partition_archive;
+pub mod metric_partition_recovery;
+pub mod metric_partition_writer;
+pub mod metric_storage_backend;
+pub mod sqlite;
+pub mod remote;
 pub mod k8s;
\ No newline at end of file
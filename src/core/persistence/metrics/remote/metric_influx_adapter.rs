@@ -0,0 +1,188 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+use std::marker::PhantomData;
+
+use crate::core::persistence::metrics::metric_store_trait::{MetricAggregateFn, MetricStore, MetricStoreRow};
+use crate::core::persistence::metrics::remote::metric_influx_client::MetricInfluxClient;
+
+/// Remote TSDB implementation of [`MetricStore`] for one scope/granularity pair.
+///
+/// Each scope/granularity is a measurement (`rustcost_pod_hour`, ...) with the
+/// object key as the `key` tag and one integer field per entity column, so the
+/// data stays queryable from the organization's own dashboards.
+pub struct MetricInfluxAdapter<T> {
+    scope: &'static str,
+    granularity: &'static str,
+    source: Option<(&'static str, MetricAggregateFn<T>)>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: MetricStoreRow> MetricInfluxAdapter<T> {
+    pub fn new(scope: &'static str, granularity: &'static str) -> Self {
+        Self {
+            scope,
+            granularity,
+            source: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Enables `append_row_aggregated` by reading `source_granularity` rows of the same scope.
+    pub fn aggregating(mut self, source_granularity: &'static str, aggregate: MetricAggregateFn<T>) -> Self {
+        self.source = Some((source_granularity, aggregate));
+        self
+    }
+
+    fn measurement(&self, granularity: &str) -> String {
+        format!("rustcost_{}_{}", self.scope, granularity)
+    }
+
+    /// Line protocol for one row, or `None` when the row carries no values.
+    fn to_line(&self, key: &str, data: &T) -> Result<Option<String>> {
+        let value = serde_json::to_value(data)?;
+        let obj = value
+            .as_object()
+            .ok_or_else(|| anyhow!("metric row is not an object"))?;
+
+        let fields: Vec<String> = obj
+            .iter()
+            .filter(|(name, _)| name.as_str() != "time")
+            .filter_map(|(name, v)| match v {
+                Value::Bool(b) => Some(format!("{}={}", name, b)),
+                _ => v.as_u64().map(|n| format!("{}={}i", name, n)),
+            })
+            .collect();
+
+        if fields.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(format!(
+            "{},key={} {} {}",
+            self.measurement(self.granularity),
+            escape_tag(key),
+            fields.join(","),
+            data.time().timestamp()
+        )))
+    }
+
+    fn query_rows(
+        &self,
+        granularity: &str,
+        object_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        let mut q = format!(
+            "SELECT * FROM \"{}\" WHERE \"key\" = '{}' AND time >= {}s AND time <= {}s ORDER BY time ASC",
+            self.measurement(granularity),
+            escape_str(object_name),
+            start.timestamp(),
+            end.timestamp()
+        );
+        if let Some(l) = limit {
+            q.push_str(&format!(" LIMIT {}", l));
+        }
+        if let Some(o) = offset {
+            q.push_str(&format!(" OFFSET {}", o));
+        }
+
+        let mut rows = Vec::new();
+        for series in MetricInfluxClient::shared()?.query(&q)? {
+            for values in series.values {
+                let mut obj = Map::new();
+                for (col, val) in series.columns.iter().zip(values) {
+                    match col.as_str() {
+                        "key" => {}
+                        "time" => {
+                            let secs = val.as_i64().ok_or_else(|| anyhow!("invalid time column"))?;
+                            let time = DateTime::<Utc>::from_timestamp(secs, 0)
+                                .ok_or_else(|| anyhow!("invalid timestamp {}", secs))?;
+                            obj.insert("time".into(), Value::String(time.to_rfc3339()));
+                        }
+                        _ => {
+                            obj.insert(col.clone(), val);
+                        }
+                    }
+                }
+                rows.push(serde_json::from_value(Value::Object(obj))?);
+            }
+        }
+        Ok(rows)
+    }
+}
+
+impl<T: MetricStoreRow> MetricStore<T> for MetricInfluxAdapter<T> {
+    fn append_row(&self, name: &str, data: &T, _now: DateTime<Utc>) -> Result<()> {
+        match self.to_line(name, data)? {
+            Some(line) => MetricInfluxClient::shared()?.write_lines(line),
+            None => Ok(()),
+        }
+    }
+
+    fn append_row_aggregated(
+        &self,
+        pod_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let (source, aggregate) = self
+            .source
+            .ok_or_else(|| anyhow!("{} {} metrics are not aggregated", self.scope, self.granularity))?;
+
+        let rows = self.query_rows(source, pod_uid, start, end, None, None)?;
+        let aggregated = aggregate(rows, start, end)?;
+        self.append_row(pod_uid, &aggregated, now)
+    }
+
+    fn cleanup_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        let q = format!(
+            "DELETE FROM \"{}\" WHERE \"key\" = '{}' AND time < {}s",
+            self.measurement(self.granularity),
+            escape_str(name),
+            before.timestamp()
+        );
+        MetricInfluxClient::shared()?.query(&q)?;
+        Ok(())
+    }
+
+    fn get_column_between(
+        &self,
+        _column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.get_row_between(start, end, object_name, limit, offset)
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.query_rows(self.granularity, object_name, start, end, limit, offset)
+    }
+}
+
+/// Escapes a tag value for line protocol.
+fn escape_tag(v: &str) -> String {
+    v.replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+}
+
+/// Escapes a single-quoted InfluxQL string literal.
+fn escape_str(v: &str) -> String {
+    v.replace('\\', "\\\\").replace('\'', "\\'")
+}
@@ -0,0 +1,156 @@
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::runtime::Handle;
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
+
+static CLIENT: OnceLock<MetricInfluxClient> = OnceLock::new();
+
+/// HTTP client for an InfluxDB-compatible TSDB (v1 `/write` + `/query` API,
+/// also served by InfluxDB 2.x and VictoriaMetrics).
+///
+/// Metric stores are synchronous, so requests run on the current tokio runtime
+/// via `block_in_place`.
+pub struct MetricInfluxClient {
+    http: reqwest::Client,
+    url: String,
+    database: String,
+    token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InfluxQueryResponse {
+    #[serde(default)]
+    results: Vec<InfluxResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InfluxResult {
+    #[serde(default)]
+    series: Vec<InfluxSeries>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InfluxSeries {
+    pub columns: Vec<String>,
+    #[serde(default)]
+    pub values: Vec<Vec<Value>>,
+}
+
+impl MetricInfluxClient {
+    /// Process-wide client configured from settings.
+    pub fn shared() -> Result<&'static MetricInfluxClient> {
+        if let Some(c) = CLIENT.get() {
+            return Ok(c);
+        }
+
+        let settings = InfoSettingFsAdapter::new().read()?;
+        let url = settings
+            .remote_store_url
+            .ok_or_else(|| anyhow!("remote_store_url must be set for the influx storage backend"))?;
+
+        let client = MetricInfluxClient {
+            http: reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()?,
+            url: url.trim_end_matches('/').to_string(),
+            database: settings.remote_store_database,
+            token: settings.remote_store_token,
+        };
+
+        let _ = CLIENT.set(client);
+        Ok(CLIENT.get().expect("influx client initialized"))
+    }
+
+    /// Writes newline-separated line-protocol points (second precision).
+    pub fn write_lines(&self, body: String) -> Result<()> {
+        let req = self
+            .http
+            .post(format!("{}/write", self.url))
+            .query(&[("db", self.database.as_str()), ("precision", "s")])
+            .body(body);
+        let req = self.authorize(req);
+
+        block_on(async move {
+            let resp = req.send().await.context("Failed to reach remote metric store")?;
+            if !resp.status().is_success() {
+                let status = resp.status();
+                let text = resp.text().await.unwrap_or_default();
+                return Err(anyhow!("Remote metric write failed ({}): {}", status, text));
+            }
+            Ok(())
+        })?
+    }
+
+    /// Runs an InfluxQL statement and returns the series of the first result.
+    /// Timestamps are returned as epoch seconds.
+    pub fn query(&self, q: &str) -> Result<Vec<InfluxSeries>> {
+        let req = self
+            .http
+            .post(format!("{}/query", self.url))
+            .query(&[("db", self.database.as_str()), ("epoch", "s"), ("q", q)]);
+        let req = self.authorize(req);
+
+        let body: InfluxQueryResponse = block_on(async move {
+            let resp = req.send().await.context("Failed to reach remote metric store")?;
+            if !resp.status().is_success() {
+                return Err(anyhow!("Remote metric query failed ({})", resp.status()));
+            }
+            resp.json::<InfluxQueryResponse>()
+                .await
+                .context("Invalid remote metric query response")
+        })??;
+
+        let result = match body.results.into_iter().next() {
+            Some(r) => r,
+            None => return Ok(vec![]),
+        };
+
+        if let Some(err) = result.error {
+            return Err(anyhow!("Remote metric query error: {}", err));
+        }
+        Ok(result.series)
+    }
+
+    /// Distinct `key` tag values across all measurements of a scope.
+    pub fn object_keys(&self, scope: &str) -> Result<Vec<String>> {
+        let q = format!(
+            "SHOW TAG VALUES FROM /^rustcost_{}_/ WITH KEY = \"key\"",
+            scope
+        );
+
+        let mut keys = BTreeSet::new();
+        for series in self.query(&q)? {
+            let idx = match series.columns.iter().position(|c| c == "value") {
+                Some(i) => i,
+                None => continue,
+            };
+            for row in series.values {
+                if let Some(Value::String(k)) = row.get(idx) {
+                    keys.insert(k.clone());
+                }
+            }
+        }
+        Ok(keys.into_iter().collect())
+    }
+
+    fn authorize(&self, req: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.token {
+            Some(token) => req.header("Authorization", format!("Token {}", token)),
+            None => req,
+        }
+    }
+}
+
+fn block_on<F: Future>(fut: F) -> Result<F::Output> {
+    let handle = Handle::try_current()
+        .map_err(|_| anyhow!("remote metric store requires a tokio runtime"))?;
+    Ok(tokio::task::block_in_place(|| handle.block_on(fut)))
+}
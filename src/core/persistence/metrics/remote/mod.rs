@@ -0,0 +1,2 @@
+pub mod metric_influx_adapter;
+pub mod metric_influx_client;
@@ -0,0 +1,148 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+use std::marker::PhantomData;
+
+use crate::core::persistence::metrics::metric_store_trait::{MetricAggregateFn, MetricStore, MetricStoreRow};
+use crate::core::persistence::metrics::sqlite::metric_sqlite_store::MetricSqliteStore;
+
+/// SQLite implementation of [`MetricStore`] for one scope/granularity pair.
+///
+/// Aggregation reuses the FS adapters' `aggregate_rows`, so every backend
+/// produces identical hour/day rows.
+pub struct MetricSqliteAdapter<T> {
+    scope: &'static str,
+    granularity: &'static str,
+    source: Option<(&'static str, MetricAggregateFn<T>)>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: MetricStoreRow> MetricSqliteAdapter<T> {
+    pub fn new(scope: &'static str, granularity: &'static str) -> Self {
+        Self {
+            scope,
+            granularity,
+            source: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Enables `append_row_aggregated` by reading `source_granularity` rows of the same scope.
+    pub fn aggregating(mut self, source_granularity: &'static str, aggregate: MetricAggregateFn<T>) -> Self {
+        self.source = Some((source_granularity, aggregate));
+        self
+    }
+
+    fn query_rows(
+        &self,
+        granularity: &str,
+        object_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        let payloads = MetricSqliteStore::with_connection(|conn| {
+            let mut stmt = conn.prepare_cached(
+                "SELECT payload FROM metric_rows
+                 WHERE scope = ?1 AND granularity = ?2 AND object_key = ?3
+                   AND time >= ?4 AND time <= ?5
+                 ORDER BY time
+                 LIMIT ?6 OFFSET ?7",
+            )?;
+            let rows = stmt
+                .query_map(
+                    params![
+                        self.scope,
+                        granularity,
+                        object_name,
+                        start.timestamp(),
+                        end.timestamp(),
+                        limit.map(|l| l as i64).unwrap_or(-1),
+                        offset.unwrap_or(0) as i64,
+                    ],
+                    |row| row.get::<_, String>(0),
+                )?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            Ok(rows)
+        })?;
+
+        payloads
+            .iter()
+            .map(|p| serde_json::from_str(p).map_err(|e| anyhow!("Corrupt metric row: {}", e)))
+            .collect()
+    }
+}
+
+impl<T: MetricStoreRow> MetricStore<T> for MetricSqliteAdapter<T> {
+    fn append_row(&self, name: &str, data: &T, _now: DateTime<Utc>) -> Result<()> {
+        let payload = serde_json::to_string(data)?;
+
+        // Same (key, time) replaces the previous row, so re-running an aggregation is harmless.
+        MetricSqliteStore::with_connection(|conn| {
+            conn.execute(
+                "INSERT OR REPLACE INTO metric_rows (scope, granularity, object_key, time, payload)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![self.scope, self.granularity, name, data.time().timestamp(), payload],
+            )
+        })?;
+        Ok(())
+    }
+
+    fn append_row_aggregated(
+        &self,
+        pod_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let (source, aggregate) = self
+            .source
+            .ok_or_else(|| anyhow!("{} {} metrics are not aggregated", self.scope, self.granularity))?;
+
+        let rows = self.query_rows(source, pod_uid, start, end, None, None)?;
+        let aggregated = aggregate(rows, start, end)?;
+        self.append_row(pod_uid, &aggregated, now)
+    }
+
+    fn cleanup_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        let deleted = MetricSqliteStore::with_connection(|conn| {
+            conn.execute(
+                "DELETE FROM metric_rows
+                 WHERE scope = ?1 AND granularity = ?2 AND object_key = ?3 AND time < ?4",
+                params![self.scope, self.granularity, name, before.timestamp()],
+            )
+        })?;
+
+        if deleted > 0 {
+            tracing::info!(
+                "Deleted {} old {} {} rows for {}",
+                deleted, self.scope, self.granularity, name
+            );
+        }
+        Ok(())
+    }
+
+    fn get_column_between(
+        &self,
+        _column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.get_row_between(start, end, object_name, limit, offset)
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.query_rows(self.granularity, object_name, start, end, limit, offset)
+    }
+}
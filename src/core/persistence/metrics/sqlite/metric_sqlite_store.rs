@@ -0,0 +1,74 @@
+use anyhow::{anyhow, Context, Result};
+use rusqlite::{params, Connection};
+use std::fs;
+use std::sync::{Mutex, OnceLock};
+
+use crate::core::persistence::metrics::k8s::path::metric_k8s_sqlite_db_path;
+
+static CONNECTION: OnceLock<Mutex<Connection>> = OnceLock::new();
+
+/// Shared SQLite database used by the SQLite metric backend.
+///
+/// All scopes and granularities live in one `metric_rows` table keyed by
+/// `(scope, granularity, object_key, time)`, so a cluster with many short-lived
+/// pods needs a single file instead of one directory tree per pod.
+pub struct MetricSqliteStore;
+
+impl MetricSqliteStore {
+    /// Runs `f` with the process-wide connection, opening and migrating it on first use.
+    pub fn with_connection<R>(f: impl FnOnce(&Connection) -> rusqlite::Result<R>) -> Result<R> {
+        let conn = match CONNECTION.get() {
+            Some(c) => c,
+            None => {
+                let opened = Self::open()?;
+                // Another thread may have won the race; either connection is fine.
+                let _ = CONNECTION.set(Mutex::new(opened));
+                CONNECTION.get().expect("sqlite connection initialized")
+            }
+        };
+
+        let guard = conn
+            .lock()
+            .map_err(|_| anyhow!("sqlite metric connection poisoned"))?;
+        Ok(f(&guard)?)
+    }
+
+    /// Distinct object keys (pod UIDs, node names, container keys) stored for a scope.
+    pub fn object_keys(scope: &str) -> Result<Vec<String>> {
+        Self::with_connection(|conn| {
+            let mut stmt = conn.prepare(
+                "SELECT DISTINCT object_key FROM metric_rows WHERE scope = ?1 ORDER BY object_key",
+            )?;
+            let keys = stmt
+                .query_map(params![scope], |row| row.get::<_, String>(0))?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            Ok(keys)
+        })
+    }
+
+    fn open() -> Result<Connection> {
+        let path = metric_k8s_sqlite_db_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(&path)
+            .with_context(|| format!("Failed to open metric database {:?}", path))?;
+
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             PRAGMA synchronous = NORMAL;
+             CREATE TABLE IF NOT EXISTS metric_rows (
+                 scope       TEXT    NOT NULL,
+                 granularity TEXT    NOT NULL,
+                 object_key  TEXT    NOT NULL,
+                 time        INTEGER NOT NULL,
+                 payload     TEXT    NOT NULL,
+                 PRIMARY KEY (scope, granularity, object_key, time)
+             ) WITHOUT ROWID;",
+        )
+        .context("Failed to initialize metric database schema")?;
+
+        Ok(conn)
+    }
+}
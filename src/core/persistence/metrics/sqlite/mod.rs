@@ -0,0 +1,2 @@
+pub mod metric_sqlite_adapter;
+pub mod metric_sqlite_store;
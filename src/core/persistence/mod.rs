@@ -1,4 +1,7 @@
 pub mod info;
 pub mod metrics;
 pub mod storage_path;
-pub mod logs;
\ No newline at end of file
+pub mod logs;
+pub mod jobs;
+pub mod blocking_io;
+pub mod repositories;
@@ -0,0 +1,262 @@
+//! Repository trait objects the metric domain reads through.
+//!
+//! `AppState` builds one [`Repositories`] set and runs every API request,
+//! metric service call and scheduler loop inside [`with_repositories`]; domain
+//! code asks [`repositories`] for the active set instead of constructing FS
+//! repositories inline, so the same services run against in-memory fakes or
+//! another store. Outside a scope (a `tokio::spawn` or `spawn_blocking` that
+//! dropped it, the collectors) [`repositories`] falls back to the default
+//! set, i.e. the info files and the configured storage backend.
+
+use std::fs;
+use std::future::Future;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::core::persistence::blocking_io::blocking_io;
+use crate::core::persistence::info::fixed::attribution::team_precedence::NamespaceTeams;
+use crate::core::persistence::info::k8s::container::info_container_api_repository_trait::InfoContainerApiRepository;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::container::info_container_repository::InfoContainerRepository;
+use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::node::info_node_repository::InfoNodeRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
+use crate::core::persistence::info::path::{
+    info_k8s_container_dir_path, info_k8s_node_dir_path, info_k8s_pod_dir_path,
+};
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::*;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
+
+/// Read access to one kind of info record (pod, node, container), keyed like
+/// its directory on disk (pod UID, node name, container key).
+#[async_trait]
+pub trait InfoStore<T>: Send + Sync {
+    async fn read(&self, key: &str) -> Result<T>;
+
+    /// Every stored record, deleted ones included; unreadable records are skipped.
+    async fn list(&self) -> Result<Vec<T>>;
+}
+
+/// Metric tier stores for pods, nodes and containers.
+pub trait MetricStoreProvider: Send + Sync {
+    fn pod(&self, tier: MetricTier) -> Box<dyn MetricStore<MetricPodEntity>>;
+    fn node(&self, tier: MetricTier) -> Box<dyn MetricStore<MetricNodeEntity>>;
+    fn container(&self, tier: MetricTier) -> Box<dyn MetricStore<MetricContainerEntity>>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetricTier {
+    Minute,
+    Hour,
+    Day,
+    Month,
+}
+
+/// Repositories shared by every metric request of one `AppState`.
+#[derive(Clone)]
+pub struct Repositories {
+    pub info_pods: Arc<dyn InfoStore<InfoPodEntity>>,
+    pub info_nodes: Arc<dyn InfoStore<InfoNodeEntity>>,
+    pub info_containers: Arc<dyn InfoStore<InfoContainerEntity>>,
+    pub metric_stores: Arc<dyn MetricStoreProvider>,
+}
+
+impl Default for Repositories {
+    /// Info files on disk and metric stores from the configured storage backend.
+    fn default() -> Self {
+        Self {
+            info_pods: Arc::new(InfoPodRepository::new()),
+            info_nodes: Arc::new(InfoNodeRepository::new()),
+            info_containers: Arc::new(InfoContainerRepository::new()),
+            metric_stores: Arc::new(BackendMetricStores),
+        }
+    }
+}
+
+tokio::task_local! {
+    static ACTIVE: Repositories;
+}
+
+/// Runs `fut` with `repos` as the set returned by [`repositories`].
+pub async fn with_repositories<F: Future>(repos: Repositories, fut: F) -> F::Output {
+    ACTIVE.scope(repos, fut).await
+}
+
+/// Runs the blocking `f` with `repos` as the active set, for `spawn_blocking`
+/// work that should keep its caller's repositories.
+pub fn with_repositories_blocking<R>(repos: Repositories, f: impl FnOnce() -> R) -> R {
+    ACTIVE.sync_scope(repos, f)
+}
+
+/// Repositories of the current scope, or [`Repositories::default`] outside one.
+pub fn repositories() -> Repositories {
+    ACTIVE.try_with(Repositories::clone).unwrap_or_else(|_| {
+        static FALLBACK: OnceLock<Repositories> = OnceLock::new();
+        FALLBACK.get_or_init(Repositories::default).clone()
+    })
+}
+
+/// Directory entry names under `dir`; a missing directory has none.
+fn stored_keys(dir: &Path) -> Result<Vec<String>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut keys = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        keys.push(entry?.file_name().to_string_lossy().to_string());
+    }
+    Ok(keys)
+}
+
+/// Pods come back with `team` resolved against their namespace's team per the
+/// `team_precedence` setting, so every metric groups and filters the same way.
+#[async_trait]
+impl InfoStore<InfoPodEntity> for InfoPodRepository {
+    async fn read(&self, key: &str) -> Result<InfoPodEntity> {
+        let mut pod = InfoPodApiRepository::read(self, key)?;
+        if let Some(namespace) = pod.namespace.as_deref() {
+            NamespaceTeams::load_one(namespace).resolve(&mut pod);
+        }
+        Ok(pod)
+    }
+
+    async fn list(&self) -> Result<Vec<InfoPodEntity>> {
+        blocking_io(|| {
+            let teams = NamespaceTeams::load();
+            Ok(stored_keys(&info_k8s_pod_dir_path())?
+                .iter()
+                .filter_map(|key| InfoPodApiRepository::read(self, key).ok())
+                .map(|mut pod| {
+                    teams.resolve(&mut pod);
+                    pod
+                })
+                .collect())
+        })
+    }
+}
+
+#[async_trait]
+impl InfoStore<InfoNodeEntity> for InfoNodeRepository {
+    async fn read(&self, key: &str) -> Result<InfoNodeEntity> {
+        InfoNodeApiRepository::read(self, key)
+    }
+
+    async fn list(&self) -> Result<Vec<InfoNodeEntity>> {
+        blocking_io(|| {
+            Ok(stored_keys(&info_k8s_node_dir_path())?
+                .iter()
+                .filter_map(|key| InfoNodeApiRepository::read(self, key).ok())
+                .collect())
+        })
+    }
+}
+
+#[async_trait]
+impl InfoStore<InfoContainerEntity> for InfoContainerRepository {
+    async fn read(&self, key: &str) -> Result<InfoContainerEntity> {
+        InfoContainerApiRepository::read(self, key)
+    }
+
+    async fn list(&self) -> Result<Vec<InfoContainerEntity>> {
+        blocking_io(|| {
+            Ok(stored_keys(&info_k8s_container_dir_path())?
+                .iter()
+                .filter_map(|key| InfoContainerApiRepository::read(self, key).ok())
+                .collect())
+        })
+    }
+}
+
+/// Stores from the metric adapter factory, i.e. the `storage_backend` setting.
+pub struct BackendMetricStores;
+
+impl MetricStoreProvider for BackendMetricStores {
+    fn pod(&self, tier: MetricTier) -> Box<dyn MetricStore<MetricPodEntity>> {
+        match tier {
+            MetricTier::Minute => metric_pod_minute_adapter(),
+            MetricTier::Hour => metric_pod_hour_adapter(),
+            MetricTier::Day => metric_pod_day_adapter(),
+            MetricTier::Month => metric_pod_month_adapter(),
+        }
+    }
+
+    fn node(&self, tier: MetricTier) -> Box<dyn MetricStore<MetricNodeEntity>> {
+        match tier {
+            MetricTier::Minute => metric_node_minute_adapter(),
+            MetricTier::Hour => metric_node_hour_adapter(),
+            MetricTier::Day => metric_node_day_adapter(),
+            MetricTier::Month => metric_node_month_adapter(),
+        }
+    }
+
+    fn container(&self, tier: MetricTier) -> Box<dyn MetricStore<MetricContainerEntity>> {
+        match tier {
+            MetricTier::Minute => metric_container_minute_adapter(),
+            MetricTier::Hour => metric_container_hour_adapter(),
+            MetricTier::Day => metric_container_day_adapter(),
+            MetricTier::Month => metric_container_month_adapter(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakePods(Vec<InfoPodEntity>);
+
+    #[async_trait]
+    impl InfoStore<InfoPodEntity> for FakePods {
+        async fn read(&self, key: &str) -> Result<InfoPodEntity> {
+            self.0
+                .iter()
+                .find(|p| p.pod_uid.as_deref() == Some(key))
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("pod '{}' not found", key))
+        }
+
+        async fn list(&self) -> Result<Vec<InfoPodEntity>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn scoped_set_is_the_active_one() {
+        let pod = InfoPodEntity {
+            pod_uid: Some("uid-1".to_string()),
+            ..Default::default()
+        };
+        let repos = Repositories {
+            info_pods: Arc::new(FakePods(vec![pod])),
+            ..Repositories::default()
+        };
+
+        let listed = with_repositories(repos, async { repositories().info_pods.list().await })
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn spawned_tasks_fall_back_to_the_default_set() {
+        let repos = Repositories {
+            info_pods: Arc::new(FakePods(Vec::new())),
+            ..Repositories::default()
+        };
+
+        let fell_back = with_repositories(repos.clone(), async move {
+            tokio::spawn(async { repositories().info_pods }).await.unwrap()
+        })
+        .await;
+        assert!(!Arc::ptr_eq(&fell_back, &repos.info_pods));
+    }
+}
@@ -2,9 +2,19 @@
 
 use std::{env, path::PathBuf};
 
+/// Base path for tests, set once per test binary instead of mutating the
+/// process environment under the parallel test harness.
+#[cfg(test)]
+pub(crate) static TEST_BASE_PATH: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+
 /// Returns the base data path, using `RUSTCOST_BASE_PATH` env var if set.
 /// Defaults to `data/` if not configured.
 pub fn get_rustcost_base_path() -> PathBuf {
+    #[cfg(test)]
+    if let Some(path) = TEST_BASE_PATH.get() {
+        return path.clone();
+    }
+
     env::var("RUSTCOST_BASE_PATH")
         .map(PathBuf::from)
         .unwrap_or_else(|_| PathBuf::from("data"))
@@ -0,0 +1,201 @@
+//! Rustcost's own operational metrics, rendered in the Prometheus text format.
+//!
+//! Covers the collector (scrape duration per node, rows written, parse errors)
+//! and the HTTP API (request latency per route). Everything lives in a single
+//! in-process registry; values reset on restart like any Prometheus counter.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Mutex, OnceLock};
+
+/// Latency buckets in seconds, shared by every histogram.
+const BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    /// Cumulative count per entry of [`BUCKETS`]
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; BUCKETS.len()];
+        }
+        for (bucket, le) in self.buckets.iter_mut().zip(BUCKETS) {
+            if value <= *le {
+                *bucket += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+/// Label values in the order of the metric's label names.
+type Labels = Vec<String>;
+
+#[derive(Debug, Default)]
+struct Registry {
+    scrape_duration: BTreeMap<Labels, Histogram>,
+    rows_written: BTreeMap<Labels, u64>,
+    parse_errors: BTreeMap<Labels, u64>,
+    http_duration: BTreeMap<Labels, Histogram>,
+}
+
+fn registry() -> &'static Mutex<Registry> {
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(Registry::default()))
+}
+
+/// Time spent fetching and storing one node's `/stats/summary`.
+pub fn observe_scrape_duration(node: &str, seconds: f64) {
+    registry()
+        .lock()
+        .unwrap()
+        .scrape_duration
+        .entry(vec![node.to_string()])
+        .or_default()
+        .observe(seconds);
+}
+
+/// Metric rows appended by the collector; `scope` is `node`, `pod` or `container`.
+pub fn add_rows_written(scope: &str, rows: u64) {
+    *registry()
+        .lock()
+        .unwrap()
+        .rows_written
+        .entry(vec![scope.to_string()])
+        .or_default() += rows;
+}
+
+/// A kubelet summary that could not be decoded.
+pub fn inc_parse_errors(node: &str) {
+    *registry()
+        .lock()
+        .unwrap()
+        .parse_errors
+        .entry(vec![node.to_string()])
+        .or_default() += 1;
+}
+
+/// One API request; `route` is the matched route template, not the raw path.
+pub fn observe_http_request(method: &str, route: &str, status: u16, seconds: f64) {
+    registry()
+        .lock()
+        .unwrap()
+        .http_duration
+        .entry(vec![method.to_string(), route.to_string(), status.to_string()])
+        .or_default()
+        .observe(seconds);
+}
+
+/// Renders every metric in the Prometheus text exposition format (0.0.4).
+pub fn render() -> String {
+    let reg = registry().lock().unwrap();
+    let mut out = String::new();
+
+    write_histogram(
+        &mut out,
+        "rustcost_collector_scrape_duration_seconds",
+        "Time to fetch and store one node's kubelet summary.",
+        &["node"],
+        &reg.scrape_duration,
+    );
+    write_counter(
+        &mut out,
+        "rustcost_collector_rows_written_total",
+        "Metric rows appended by the collector.",
+        &["scope"],
+        &reg.rows_written,
+    );
+    write_counter(
+        &mut out,
+        "rustcost_collector_parse_errors_total",
+        "Kubelet summaries that could not be decoded.",
+        &["node"],
+        &reg.parse_errors,
+    );
+    write_histogram(
+        &mut out,
+        "rustcost_http_request_duration_seconds",
+        "API request latency by route.",
+        &["method", "route", "status"],
+        &reg.http_duration,
+    );
+
+    out
+}
+
+fn write_counter(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    label_names: &[&str],
+    values: &BTreeMap<Labels, u64>,
+) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} counter", name);
+    for (labels, value) in values {
+        let _ = writeln!(out, "{}{{{}}} {}", name, format_labels(label_names, labels), value);
+    }
+}
+
+fn write_histogram(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    label_names: &[&str],
+    values: &BTreeMap<Labels, Histogram>,
+) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} histogram", name);
+    for (labels, hist) in values {
+        let base = format_labels(label_names, labels);
+        for (count, le) in hist.buckets.iter().zip(BUCKETS) {
+            let _ = writeln!(out, "{}_bucket{{{},le=\"{}\"}} {}", name, base, le, count);
+        }
+        let _ = writeln!(out, "{}_bucket{{{},le=\"+Inf\"}} {}", name, base, hist.count);
+        let _ = writeln!(out, "{}_sum{{{}}} {}", name, base, hist.sum);
+        let _ = writeln!(out, "{}_count{{{}}} {}", name, base, hist.count);
+    }
+}
+
+fn format_labels(names: &[&str], values: &[String]) -> String {
+    names
+        .iter()
+        .zip(values)
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape_label(value)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_buckets_are_cumulative() {
+        let mut hist = Histogram::default();
+        hist.observe(0.02);
+        hist.observe(3.0);
+
+        assert_eq!(hist.buckets[0], 0); // le=0.005
+        assert_eq!(hist.buckets[2], 1); // le=0.025
+        assert_eq!(hist.buckets[BUCKETS.len() - 1], 2); // le=10
+        assert_eq!(hist.count, 2);
+    }
+
+    #[test]
+    fn labels_are_escaped() {
+        assert_eq!(format_labels(&["route"], &["/a\"b".to_string()]), "route=\"/a\\\"b\"");
+    }
+}
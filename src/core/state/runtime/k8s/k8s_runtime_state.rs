@@ -134,6 +134,65 @@ impl K8sRuntimeState {
         self.last_error_message = None;
     }
 
+    /// Insert or replace one pod from a watch event, keeping the indexes in step.
+    pub fn upsert_pod(&mut self, pod: RuntimePod) {
+        self.remove_pod(&pod.uid);
+        let uid = pod.uid.clone();
+
+        self.pods_by_namespace
+            .entry(pod.namespace.clone())
+            .or_default()
+            .push(uid.clone());
+        self.pods_by_node
+            .entry(pod.node.clone())
+            .or_default()
+            .push(uid.clone());
+        if let Some(depl) = &pod.deployment {
+            self.pods_by_deployment
+                .entry(depl.clone())
+                .or_default()
+                .push(uid.clone());
+        }
+        insert_name(&mut self.namespaces, &pod.namespace);
+
+        self.pods.insert(uid, pod);
+    }
+
+    /// Drop one pod and its index entries; returns it if it was known.
+    pub fn remove_pod(&mut self, uid: &str) -> Option<RuntimePod> {
+        let pod = self.pods.remove(uid)?;
+
+        remove_from_index(&mut self.pods_by_namespace, &pod.namespace, uid);
+        remove_from_index(&mut self.pods_by_node, &pod.node, uid);
+        if let Some(depl) = &pod.deployment {
+            remove_from_index(&mut self.pods_by_deployment, depl, uid);
+        }
+
+        Some(pod)
+    }
+
+    pub fn upsert_node(&mut self, name: &str) {
+        insert_name(&mut self.nodes, name);
+    }
+
+    pub fn remove_node(&mut self, name: &str) {
+        self.nodes.retain(|n| n != name);
+    }
+
+    pub fn upsert_namespace(&mut self, name: &str) {
+        insert_name(&mut self.namespaces, name);
+    }
+
+    pub fn upsert_deployment(&mut self, name: &str) {
+        insert_name(&mut self.deployments, name);
+    }
+
+    /// Deployments are tracked by name only; one still present in another
+    /// namespace is dropped until the next full discovery.
+    pub fn remove_deployment(&mut self, name: &str) {
+        self.deployments.retain(|d| d != name);
+    }
+
     /// Mark an error during discovery without modifying the object lists.
     pub fn mark_error(&mut self, msg: String) {
         self.last_error_message = Some(msg);
@@ -145,3 +204,53 @@ impl K8sRuntimeState {
         *self = Self::default();
     }
 }
+
+fn insert_name(names: &mut Vec<String>, name: &str) {
+    if !names.iter().any(|n| n == name) {
+        names.push(name.to_string());
+    }
+}
+
+fn remove_from_index(index: &mut HashMap<String, Vec<String>>, key: &str, uid: &str) {
+    if let Some(uids) = index.get_mut(key) {
+        uids.retain(|u| u != uid);
+        if uids.is_empty() {
+            index.remove(key);
+        }
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod(uid: &str, node: &str, deployment: Option<&str>) -> RuntimePod {
+        RuntimePod {
+            uid: uid.into(),
+            name: format!("pod-{}", uid),
+            namespace: "default".into(),
+            deployment: deployment.map(str::to_string),
+            node: node.into(),
+            containers: vec!["app".into()],
+        }
+    }
+
+    #[test]
+    fn upsert_moves_pod_between_indexes() {
+        let mut state = K8sRuntimeState::default();
+        state.upsert_pod(pod("a", "node-1", Some("web")));
+        state.upsert_pod(pod("a", "node-2", None));
+
+        assert_eq!(state.pods.len(), 1);
+        assert!(!state.pods_by_node.contains_key("node-1"));
+        assert_eq!(state.pods_by_node["node-2"], vec!["a".to_string()]);
+        assert!(state.pods_by_deployment.is_empty());
+        assert_eq!(state.namespaces, vec!["default".to_string()]);
+
+        assert!(state.remove_pod("a").is_some());
+        assert!(state.pods_by_namespace.is_empty());
+        assert!(state.remove_pod("a").is_none());
+    }
+}
@@ -41,6 +41,39 @@ impl<R: K8sRuntimeStateRepositoryTrait> K8sRuntimeStateManager<R> {
         Ok(())
     }
 
+    // ===============================================
+    // Incremental updates from watch events
+    // ===============================================
+
+    pub async fn apply_pod(&self, pod: RuntimePod) {
+        self.repo.update(|state| state.upsert_pod(pod)).await;
+    }
+
+    pub async fn remove_pod(&self, uid: &str) {
+        self.repo.update(|state| { state.remove_pod(uid); }).await;
+    }
+
+    pub async fn apply_node(&self, name: &str) {
+        self.repo.update(|state| state.upsert_node(name)).await;
+    }
+
+    pub async fn remove_node(&self, name: &str) {
+        self.repo.update(|state| state.remove_node(name)).await;
+    }
+
+    pub async fn apply_deployment(&self, name: &str, namespace: &str) {
+        self.repo
+            .update(|state| {
+                state.upsert_deployment(name);
+                state.upsert_namespace(namespace);
+            })
+            .await;
+    }
+
+    pub async fn remove_deployment(&self, name: &str) {
+        self.repo.update(|state| state.remove_deployment(name)).await;
+    }
+
     /// Record a discovery failure (state remains intact).
     pub async fn mark_error(&self, message: String) {
         self.repo.update(|state| state.mark_error(message)).await;
@@ -152,6 +185,14 @@ impl<R: K8sRuntimeStateRepositoryTrait> K8sRuntimeStateManager<R> {
         state.pods.keys().cloned().collect()
     }
 
+    // ===============================================
+    // 8b. Get all pods with their placement/ownership
+    // ===============================================
+    pub async fn get_runtime_pods(&self) -> Vec<RuntimePod> {
+        let state = self.repo.get().await;
+        state.pods.values().cloned().collect()
+    }
+
     // ===============================================
     // 9. Get all containers for a pod UID
     // ===============================================
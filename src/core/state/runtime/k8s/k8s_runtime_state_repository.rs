@@ -33,20 +33,13 @@ impl K8sRuntimeStateRepositoryTrait for K8sRuntimeStateRepository {
         *guard = Arc::new(new_state);
     }
 
-    /// Mutate the internal state by cloning and updating.
+    /// Mutate the internal state, cloning it only while readers still hold the
+    /// previous snapshot (watch events update it often).
     async fn update<F>(&self, f: F)
     where
         F: FnOnce(&mut K8sRuntimeState) + Send + Sync,
     {
         let mut guard = self.state.write().await;
-
-        // Clone underlying state
-        let mut new_state = (**guard).clone();
-
-        // Apply mutation
-        f(&mut new_state);
-
-        // Replace Arc pointer
-        *guard = Arc::new(new_state);
+        f(Arc::make_mut(&mut guard));
     }
 }
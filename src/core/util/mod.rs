@@ -1 +1,2 @@
-pub mod cost_util;
\ No newline at end of file
+pub mod cost_util;
+pub mod sha256;
@@ -1,9 +1,10 @@
 use tracing::info;
+use crate::app_state::AppState;
 use crate::scheduler;
 
 /// Runs only when in RUSTCOST_DEBUG_MODE
-pub async fn run_debug() {
+pub async fn run_debug(state: AppState) {
     info!("🔧 Debug mode: running debug tasks...");
-    scheduler::tasks::hour_task().await.expect("TODO: panic message");
+    state.scoped(scheduler::tasks::hour_task(state.clone())).await.expect("TODO: panic message");
     info!("Debug tasks completed. Exiting...");
 }
\ No newline at end of file
@@ -1,10 +1,15 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 use chrono::{DateTime, Duration, Utc};
 
 use crate::core::persistence::info::fixed::alerts::alert_rule_entity::{
     AlertMetricType, AlertOperator, AlertRuleEntity,
 };
+use crate::core::persistence::info::fixed::alerts::info_alert_entity::InfoAlertEntity;
+use crate::domain::alert::dto::alert_firing_dto::AlertFiringDto;
+
+/// Nodes not evaluated for this long are assumed gone and their alerts resolved.
+const STALE_AFTER_MINUTES: i64 = 60;
 
 #[derive(Debug, Clone, Default)]
 pub struct AlertMetricSnapshot {
@@ -14,64 +19,165 @@ pub struct AlertMetricSnapshot {
     pub gpu_usage_percent: Option<f64>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 struct RuleState {
-    active_since: Option<DateTime<Utc>>,
+    node: String,
+    active_since: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+    /// Set once the condition has held for `for_duration_sec`.
+    firing: Option<AlertFiringDto>,
+    notified: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct EvaluateOutcome {
-    pub triggered: Vec<AlertRuleEntity>,
-    pub active_conditions: HashSet<String>,
+    /// Alerts firing on the evaluated node after this cycle.
+    pub firing: Vec<AlertFiringDto>,
+    /// Firing, unsilenced alerts that have not been delivered yet.
+    pub notify: Vec<AlertFiringDto>,
+    /// Alert ids that stopped firing.
+    pub resolved: Vec<String>,
 }
 
-/// Stateful evaluator to track rule durations between metric polls.
+/// Stateful evaluator tracking each rule per node between collection cycles.
 #[derive(Debug, Default)]
 pub struct AlertRuleEvaluator {
     states: HashMap<String, RuleState>,
+    /// Efficiency-drop alerts from the latest hourly run, keyed by alert id.
+    workload_firing: HashMap<String, AlertFiringDto>,
+}
+
+pub fn alert_id(rule_id: &str, node: &str) -> String {
+    format!("{}:{}", rule_id, node)
 }
 
 impl AlertRuleEvaluator {
-    /// Evaluates rules against the current metrics snapshot.
-    /// Returns rules whose conditions have been satisfied for at least `for_duration_sec`.
+    /// Evaluates every enabled rule scoped to `node` against its metrics snapshot.
     pub fn evaluate(
         &mut self,
-        rules: &[AlertRuleEntity],
+        cfg: &InfoAlertEntity,
+        node: &str,
         metrics: &AlertMetricSnapshot,
         now: DateTime<Utc>,
     ) -> EvaluateOutcome {
-        let valid_ids: HashSet<String> = rules.iter().map(|r| r.id.clone()).collect();
-        let mut triggered = Vec::new();
-        let mut active_conditions = HashSet::new();
+        let mut outcome = EvaluateOutcome::default();
+        let mut seen = Vec::new();
+
+        for rule in cfg.rules.iter().filter(|r| r.enabled && r.matches_scope(node)) {
+            let id = alert_id(&rule.id, node);
+            let value = Self::metric_value(rule.metric_type.clone(), metrics);
 
-        for rule in rules.iter().filter(|r| r.enabled) {
-            let value = Self::metric_value(rule.metric_type(), metrics);
-            let state = self.states.entry(rule.id.clone()).or_default();
+            let Some(value) = value.filter(|v| Self::compare(*v, rule.threshold, rule.operator.clone())) else {
+                continue;
+            };
+            seen.push(id.clone());
 
-            let condition_met = value
-                .map(|v| Self::compare(v, rule.threshold, rule.operator()))
-                .unwrap_or(false);
+            let state = self.states.entry(id.clone()).or_insert_with(|| RuleState {
+                node: node.to_string(),
+                active_since: now,
+                last_seen: now,
+                firing: None,
+                notified: false,
+            });
+            state.last_seen = now;
 
-            if condition_met {
-                active_conditions.insert(rule.id.clone());
+            let elapsed = now.signed_duration_since(state.active_since);
+            if elapsed < Duration::seconds(rule.for_duration_sec as i64) {
+                continue;
+            }
 
-                if state.active_since.is_none() {
-                    state.active_since = Some(now);
-                }
+            let silenced = cfg.is_silenced(&rule.id, node, now);
+            let firing = Self::firing_dto(rule, &id, node, value, state, silenced, now);
 
-                let elapsed = now.signed_duration_since(state.active_since.unwrap_or(now));
-                if elapsed >= Duration::seconds(rule.for_duration_sec as i64) {
-                    triggered.push(rule.clone());
-                }
+            if !silenced && !state.notified {
+                state.notified = true;
+                outcome.notify.push(firing.clone());
+            }
+            outcome.firing.push(firing.clone());
+            state.firing = Some(firing);
+        }
+
+        let stale_cutoff = now - Duration::minutes(STALE_AFTER_MINUTES);
+        self.states.retain(|id, s| {
+            let keep = if s.node == node {
+                seen.contains(id)
             } else {
-                state.active_since = None;
+                s.last_seen > stale_cutoff
+            };
+            if !keep && s.firing.is_some() {
+                outcome.resolved.push(id.clone());
+            }
+            keep
+        });
+
+        outcome
+    }
+
+    /// Replaces the workload alerts with the result of an hourly run, keeping
+    /// `firing_since` for alerts that were already firing.
+    pub fn update_workload_firing(&mut self, firing: Vec<AlertFiringDto>) -> EvaluateOutcome {
+        let mut outcome = EvaluateOutcome::default();
+        let mut next = HashMap::with_capacity(firing.len());
+
+        for mut alert in firing {
+            let previous = self.workload_firing.remove(&alert.alert_id);
+            if let Some(prev) = &previous {
+                alert.firing_since = prev.firing_since;
+            }
+
+            let was_delivered = previous.is_some_and(|p| !p.silenced);
+            if !alert.silenced && !was_delivered {
+                outcome.notify.push(alert.clone());
             }
+            outcome.firing.push(alert.clone());
+            next.insert(alert.alert_id.clone(), alert);
         }
 
-        self.states.retain(|id, _| valid_ids.contains(id));
-        EvaluateOutcome {
-            triggered,
-            active_conditions,
+        outcome.resolved = self.workload_firing.drain().map(|(id, _)| id).collect();
+        self.workload_firing = next;
+        outcome
+    }
+
+    /// Every alert currently firing, across all nodes and workloads, oldest first.
+    pub fn firing(&self) -> Vec<AlertFiringDto> {
+        let mut out: Vec<AlertFiringDto> = self
+            .states
+            .values()
+            .filter_map(|s| s.firing.clone())
+            .chain(self.workload_firing.values().cloned())
+            .collect();
+        out.sort_by(|a, b| a.firing_since.cmp(&b.firing_since).then_with(|| a.alert_id.cmp(&b.alert_id)));
+        out
+    }
+
+    fn firing_dto(
+        rule: &AlertRuleEntity,
+        id: &str,
+        node: &str,
+        value: f64,
+        state: &RuleState,
+        silenced: bool,
+        now: DateTime<Utc>,
+    ) -> AlertFiringDto {
+        AlertFiringDto {
+            alert_id: id.to_string(),
+            rule_id: rule.id.clone(),
+            rule_name: rule.name.clone(),
+            scope: "node".to_string(),
+            target: node.to_string(),
+            metric: rule.metric_type.as_code().to_string(),
+            severity: rule.severity.clone(),
+            channel: rule.channel.clone(),
+            value,
+            threshold: rule.threshold,
+            pending_since: state.active_since,
+            firing_since: state
+                .firing
+                .as_ref()
+                .map(|f| f.firing_since)
+                .unwrap_or(now),
+            last_evaluated_at: now,
+            silenced,
         }
     }
 
@@ -94,17 +200,112 @@ impl AlertRuleEvaluator {
     }
 }
 
-trait RuleAccessors {
-    fn metric_type(&self) -> AlertMetricType;
-    fn operator(&self) -> AlertOperator;
-}
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::info::fixed::alerts::alert_rule_entity::{AlertChannel, AlertSeverity};
+    use crate::core::persistence::info::fixed::alerts::alert_silence_entity::AlertSilenceEntity;
+
+    fn cpu_rule(for_duration_sec: u64) -> AlertRuleEntity {
+        AlertRuleEntity {
+            id: "cpu-high".into(),
+            name: "CPU high".into(),
+            metric_type: AlertMetricType::CpuUsagePercent,
+            operator: AlertOperator::GreaterThan,
+            threshold: 80.0,
+            for_duration_sec,
+            severity: AlertSeverity::Warning,
+            enabled: true,
+            scope_selector: Some("worker-*".into()),
+            channel: AlertChannel::All,
+        }
+    }
+
+    fn cpu(v: f64) -> AlertMetricSnapshot {
+        AlertMetricSnapshot {
+            cpu_usage_percent: Some(v),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fires_per_node_after_duration_and_respects_silences() {
+        let t0 = Utc::now();
+        let mut cfg = InfoAlertEntity {
+            rules: vec![cpu_rule(120)],
+            ..Default::default()
+        };
+        let mut ev = AlertRuleEvaluator::default();
+
+        // Out of scope node never fires.
+        assert!(ev.evaluate(&cfg, "master-1", &cpu(99.0), t0).firing.is_empty());
+
+        assert!(ev.evaluate(&cfg, "worker-1", &cpu(95.0), t0).firing.is_empty());
+        let out = ev.evaluate(&cfg, "worker-1", &cpu(95.0), t0 + Duration::seconds(120));
+        assert_eq!(out.firing.len(), 1);
+        assert_eq!(out.notify.len(), 1);
+        assert_eq!(out.firing[0].alert_id, "cpu-high:worker-1");
+
+        // Another node's cycle does not resolve worker-1.
+        let out = ev.evaluate(&cfg, "worker-2", &cpu(10.0), t0 + Duration::seconds(130));
+        assert!(out.resolved.is_empty());
+        assert_eq!(ev.firing().len(), 1);
 
-impl RuleAccessors for AlertRuleEntity {
-    fn metric_type(&self) -> AlertMetricType {
-        self.metric_type.clone()
+        // Silenced alerts still fire but aren't delivered.
+        cfg.silences.push(AlertSilenceEntity {
+            id: "maint".into(),
+            rule_id: None,
+            scope_selector: Some("worker-2".into()),
+            starts_at: t0,
+            ends_at: t0 + Duration::hours(1),
+            comment: None,
+        });
+        ev.evaluate(&cfg, "worker-2", &cpu(95.0), t0 + Duration::seconds(140));
+        let out = ev.evaluate(&cfg, "worker-2", &cpu(95.0), t0 + Duration::seconds(300));
+        assert!(out.firing[0].silenced);
+        assert!(out.notify.is_empty());
+
+        let out = ev.evaluate(&cfg, "worker-1", &cpu(50.0), t0 + Duration::seconds(310));
+        assert_eq!(out.resolved, vec!["cpu-high:worker-1".to_string()]);
+        assert_eq!(ev.firing().len(), 1);
     }
 
-    fn operator(&self) -> AlertOperator {
-        self.operator.clone()
+    #[test]
+    fn workload_alerts_keep_firing_since_and_notify_once() {
+        let t0 = Utc::now();
+        let alert = |now: DateTime<Utc>, silenced: bool| AlertFiringDto {
+            alert_id: "eff:payments".into(),
+            rule_id: "eff".into(),
+            rule_name: "Low efficiency".into(),
+            scope: "namespace".into(),
+            target: "payments".into(),
+            metric: "EFFICIENCY".into(),
+            severity: AlertSeverity::Warning,
+            channel: AlertChannel::All,
+            value: 12.0,
+            threshold: 30.0,
+            pending_since: t0,
+            firing_since: now,
+            last_evaluated_at: now,
+            silenced,
+        };
+        let mut ev = AlertRuleEvaluator::default();
+
+        let out = ev.update_workload_firing(vec![alert(t0, true)]);
+        assert!(out.notify.is_empty());
+
+        let t1 = t0 + Duration::hours(1);
+        let out = ev.update_workload_firing(vec![alert(t1, false)]);
+        assert_eq!(out.notify.len(), 1);
+        assert_eq!(out.firing[0].firing_since, t0);
+
+        let out = ev.update_workload_firing(vec![alert(t1 + Duration::hours(1), false)]);
+        assert!(out.notify.is_empty());
+
+        let out = ev.update_workload_firing(Vec::new());
+        assert_eq!(out.resolved, vec!["eff:payments".to_string()]);
+        assert!(ev.firing().is_empty());
     }
 }
@@ -3,7 +3,7 @@ use reqwest::{Client, StatusCode};
 use serde::Serialize;
 use tracing::{debug, warn};
 
-use crate::core::persistence::info::fixed::alerts::alert_rule_entity::{AlertRuleEntity, AlertSeverity};
+use crate::core::persistence::info::fixed::alerts::alert_rule_entity::AlertSeverity;
 
 pub struct DiscordWebhookSender {
     client: Client,
@@ -23,13 +23,19 @@ impl DiscordWebhookSender {
     }
 
     /// Sends an alert to Discord using embeds and retries on non-2xx responses.
-    pub async fn send(&self, webhook_url: &str, rule: &AlertRuleEntity, message: &str) -> Result<()> {
+    pub async fn send(
+        &self,
+        webhook_url: &str,
+        title: &str,
+        severity: &AlertSeverity,
+        message: &str,
+    ) -> Result<()> {
         let payload = DiscordWebhookPayload {
             content: None,
             embeds: vec![DiscordEmbed {
-                title: rule.name.clone(),
+                title: title.to_string(),
                 description: Some(message.to_string()),
-                color: Self::color_for(&rule.severity),
+                color: Self::color_for(severity),
             }],
         };
 
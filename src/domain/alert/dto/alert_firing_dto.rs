@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::info::fixed::alerts::alert_rule_entity::{
+    AlertChannel, AlertSeverity,
+};
+
+/// A rule currently firing on one node or workload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertFiringDto {
+    /// `<rule_id>:<target>`, also the id used in the runtime alert state
+    pub alert_id: String,
+    pub rule_id: String,
+    pub rule_name: String,
+    /// `node`, `namespace` or `deployment`
+    pub scope: String,
+    pub target: String,
+    /// Metric code, e.g. `CPU` or `EFFICIENCY`
+    pub metric: String,
+    pub severity: AlertSeverity,
+    pub channel: AlertChannel,
+    /// Value observed on the latest evaluation
+    pub value: f64,
+    pub threshold: f64,
+    /// When the condition was first met
+    pub pending_since: DateTime<Utc>,
+    /// When the condition had held for the rule's duration
+    pub firing_since: DateTime<Utc>,
+    pub last_evaluated_at: DateTime<Utc>,
+    /// Covered by a silence window; still tracked but not delivered
+    pub silenced: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertFiringResponseDto {
+    pub evaluated_at: DateTime<Utc>,
+    pub firing: Vec<AlertFiringDto>,
+}
@@ -0,0 +1 @@
+pub mod alert_firing_dto;
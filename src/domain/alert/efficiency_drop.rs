@@ -0,0 +1,183 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use std::{collections::BTreeMap, fs};
+use tracing::debug;
+
+use crate::api::dto::metrics_dto::{CostMode, RangeQuery};
+use crate::core::persistence::info::fixed::alerts::efficiency_alert_rule_entity::{
+    AlertEfficiencyScope, EfficiencyAlertRuleEntity,
+};
+use crate::core::persistence::info::fixed::alerts::info_alert_entity::InfoAlertEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::{
+    info_pod_entity::InfoPodEntity, info_pod_repository::InfoPodRepository,
+};
+use crate::core::persistence::info::path::info_k8s_pod_dir_path;
+use crate::domain::alert::alert_rule_evaluator::alert_id;
+use crate::domain::alert::dto::alert_firing_dto::AlertFiringDto;
+use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::MetricRawEfficiencyResponseDto;
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+use crate::domain::metric::k8s::deployment::service::pod_deployment;
+use crate::domain::metric::k8s::pod::service::get_metric_k8s_pods_raw_efficiency;
+
+/// Evaluates every enabled efficiency rule over the hours completed before `now`.
+/// Returns the rule/workload pairs currently below threshold for the full streak.
+pub async fn evaluate_efficiency_rules(
+    cfg: &InfoAlertEntity,
+    now: DateTime<Utc>,
+) -> Result<Vec<AlertFiringDto>> {
+    let rules: Vec<&EfficiencyAlertRuleEntity> = cfg.efficiency_rules.iter().filter(|r| r.enabled).collect();
+    if rules.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pods = load_live_pods()?;
+    let last_hour = now.duration_trunc(Duration::hours(1))? - Duration::hours(1);
+    let mut firing = Vec::new();
+
+    for rule in rules {
+        for (target, pod_uids) in group_targets(&rule.scope, &pods) {
+            if !rule.matches_target(&target) {
+                continue;
+            }
+
+            let Some(latest) = below_threshold_streak(rule, &pod_uids, last_hour).await? else {
+                continue;
+            };
+            debug!(rule_id = %rule.id, %target, latest, "efficiency_rule_below_threshold");
+
+            let id = alert_id(&rule.id, &target);
+            firing.push(AlertFiringDto {
+                alert_id: id,
+                rule_id: rule.id.clone(),
+                rule_name: rule.name.clone(),
+                scope: rule.scope.as_code().to_lowercase(),
+                silenced: cfg.is_silenced(&rule.id, &target, now),
+                target,
+                metric: "EFFICIENCY".to_string(),
+                severity: rule.severity.clone(),
+                channel: rule.channel.clone(),
+                value: latest,
+                threshold: rule.threshold_percent,
+                pending_since: last_hour - Duration::hours(rule.consecutive_hours as i64 - 1),
+                firing_since: now,
+                last_evaluated_at: now,
+            });
+        }
+    }
+
+    Ok(firing)
+}
+
+/// Walks back from `last_hour`; returns the latest efficiency (percent) only when every
+/// hour of the streak had data and stayed below the threshold.
+async fn below_threshold_streak(
+    rule: &EfficiencyAlertRuleEntity,
+    pod_uids: &[String],
+    last_hour: DateTime<Utc>,
+) -> Result<Option<f64>> {
+    let mut latest = None;
+
+    for i in 0..rule.consecutive_hours.max(1) {
+        let hour_start = last_hour - Duration::hours(i as i64);
+        match hourly_efficiency(pod_uids, hour_start).await? {
+            Some(pct) if pct < rule.threshold_percent => {
+                latest.get_or_insert(pct);
+            }
+            _ => return Ok(None),
+        }
+    }
+
+    Ok(latest)
+}
+
+/// CPU/memory efficiency (usage over requests, percent) for `[hour_start, +1h)`.
+/// `None` when the pods have no samples or no requests in that hour.
+async fn hourly_efficiency(pod_uids: &[String], hour_start: DateTime<Utc>) -> Result<Option<f64>> {
+    let q = RangeQuery {
+        start: Some(hour_start.naive_utc()),
+        end: Some((hour_start + Duration::hours(1)).naive_utc()),
+        granularity: Some(MetricGranularity::Hour),
+        tz: None,
+        range: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        sort: None,
+        mode: CostMode::Showback,
+        cost_basis: None,
+        team: None,
+        service: None,
+        env: None,
+        namespace: None,
+        exclude_namespace: None,
+        exclude_team: None,
+        exclude_service: None,
+        exclude_env: None,
+        labels: None,
+        key: None,
+    };
+
+    let value = get_metric_k8s_pods_raw_efficiency(q, pod_uids.to_vec()).await?;
+    let dto: MetricRawEfficiencyResponseDto = serde_json::from_value(value)?;
+    let eff = dto.efficiency;
+
+    let mut ratios = Vec::with_capacity(2);
+    if eff.total_cpu_allocatable_cores > 0.0 {
+        ratios.push(eff.cpu_efficiency);
+    }
+    if eff.total_memory_allocatable_gb > 0.0 {
+        ratios.push(eff.memory_efficiency);
+    }
+    // Zero usage across the board means the hour was never collected.
+    if ratios.is_empty() || ratios.iter().all(|r| *r == 0.0) {
+        return Ok(None);
+    }
+
+    Ok(Some(ratios.iter().sum::<f64>() / ratios.len() as f64 * 100.0))
+}
+
+/// Pods that still exist; evaluated hours are recent, so deleted pods would only
+/// add stale requests.
+fn load_live_pods() -> Result<Vec<(String, InfoPodEntity)>> {
+    let mut pods = Vec::new();
+    let dir = info_k8s_pod_dir_path();
+
+    if !dir.exists() {
+        return Ok(pods);
+    }
+
+    let repo = InfoPodRepository::new();
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let pod_uid = entry.file_name().to_string_lossy().to_string();
+
+        if let Ok(pod) = repo.read(&pod_uid) {
+            if pod.deleted != Some(true) {
+                pods.push((pod_uid, pod));
+            }
+        }
+    }
+
+    Ok(pods)
+}
+
+fn group_targets(
+    scope: &AlertEfficiencyScope,
+    pods: &[(String, InfoPodEntity)],
+) -> BTreeMap<String, Vec<String>> {
+    let mut targets: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for (uid, pod) in pods {
+        let target = match scope {
+            AlertEfficiencyScope::Namespace => pod.namespace.clone(),
+            AlertEfficiencyScope::Deployment => pod_deployment(pod),
+        };
+        if let Some(target) = target {
+            targets.entry(target).or_default().push(uid.clone());
+        }
+    }
+
+    targets
+}
@@ -1,2 +1,5 @@
 pub mod alert_rule_evaluator;
 pub mod discord_webhook_sender;
+pub mod dto;
+pub mod efficiency_drop;
+pub mod webhook_text_sender;
@@ -0,0 +1,54 @@
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::Serialize;
+use tracing::{debug, warn};
+
+use crate::core::persistence::info::fixed::alerts::alert_rule_entity::AlertSeverity;
+
+/// Posts `{"text": ...}` payloads, which both Slack and Teams incoming webhooks accept.
+pub struct WebhookTextSender {
+    client: Client,
+}
+
+impl Default for WebhookTextSender {
+    fn default() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+}
+
+impl WebhookTextSender {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    pub async fn send(
+        &self,
+        webhook_url: &str,
+        title: &str,
+        severity: &AlertSeverity,
+        message: &str,
+    ) -> Result<()> {
+        let payload = TextWebhookPayload {
+            text: format!("[{}] {}\n{}", severity.as_code(), title, message),
+        };
+
+        let resp = self.client.post(webhook_url).json(&payload).send().await?;
+        let status = resp.status();
+        debug!(status = ?status, "text_webhook_response");
+        if status.is_success() {
+            return Ok(());
+        }
+
+        // Log the body but never the URL, which carries the webhook secret.
+        let body = resp.text().await.unwrap_or_default();
+        warn!(status = ?status, body = %body, "text_webhook_non_success");
+        Err(anyhow!("Webhook returned {}", status))
+    }
+}
+
+#[derive(Serialize)]
+struct TextWebhookPayload {
+    text: String,
+}
@@ -19,5 +19,25 @@ pub struct DaySplitRows<T> {
     pub start_hour_rows: Vec<T>,
     pub end_hour_rows: Vec<T>,
     pub middle_day_rows: Vec<T>,
+    /// Full local days read from hour rows, because stored day rows are UTC-aligned
+    /// and don't match the window's offset. Empty for UTC windows.
+    pub middle_hour_rows: Vec<T>,
+}
+
+impl<T> DaySplitRows<T> {
+    /// Hours covered by the rows, counting a day row as 24.
+    pub fn running_hours(&self) -> f64 {
+        (self.start_hour_rows.len() + self.middle_hour_rows.len() + self.end_hour_rows.len()) as f64
+            + self.middle_day_rows.len() as f64 * 24.0
+    }
+
+    /// All rows, start to end.
+    pub fn into_rows(self) -> Vec<T> {
+        let mut rows = self.start_hour_rows;
+        rows.extend(self.middle_day_rows);
+        rows.extend(self.middle_hour_rows);
+        rows.extend(self.end_hour_rows);
+        rows
+    }
 }
 
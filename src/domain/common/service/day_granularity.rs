@@ -2,36 +2,42 @@ use crate::domain::common::model::DaySplitRows;
 use crate::domain::metric::k8s::common::service_helpers::TimeWindow;
 use crate::domain::common::service::MetricRowRepository;
 use anyhow::Result;
-use chrono::NaiveTime;
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
 
+/// Splits a day-granularity window into partial-day hour rows and full-day rows.
+///
+/// Day boundaries are local midnights in `window.offset`. Stored day rows are
+/// UTC-aligned, so they are only used when the offset is zero; otherwise the
+/// full local days are read from hour rows into `middle_hour_rows`.
 pub fn split_day_granularity_rows<T>(
     object_name: &str,
     window: &TimeWindow,
     day_repo: &dyn MetricRowRepository<T>,
     hour_repo: &dyn MetricRowRepository<T>,
 ) -> Result<DaySplitRows<T>> {
-    let start_date = window.start.date_naive();
-    let end_date = window.end.date_naive();
+    let local_start = window.start.with_timezone(&window.offset);
+    let local_end = window.end.with_timezone(&window.offset);
+
+    let start_date = local_start.date_naive();
+    let end_date = local_end.date_naive();
 
     let is_start_full_day =
-        window.start.time() == NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        local_start.time() == NaiveTime::from_hms_opt(0, 0, 0).unwrap();
 
     let is_end_full_day =
-        window.end.time() >= NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+        local_end.time() >= NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+
+    let local_day_start = |date: NaiveDate| local_time(window, date, 0, 0, 0);
+    let local_day_end = |date: NaiveDate| local_time(window, date, 23, 59, 59);
 
     // =========================
     // 1️⃣ start day → hour rows
     // =========================
     let start_hour_rows = if !is_start_full_day {
-        let start_day_end = start_date
-            .and_hms_opt(23, 59, 59)
-            .unwrap()
-            .and_utc();
-
         hour_repo.get_row_between(
             object_name,
             window.start,
-            start_day_end.min(window.end),
+            local_day_end(start_date).min(window.end),
         )?
     } else {
         vec![]
@@ -41,14 +47,9 @@ pub fn split_day_granularity_rows<T>(
     // 2️⃣ end day → hour rows
     // =========================
     let end_hour_rows = if start_date != end_date && !is_end_full_day {
-        let end_day_start = end_date
-            .and_hms_opt(0, 0, 0)
-            .unwrap()
-            .and_utc();
-
         hour_repo.get_row_between(
             object_name,
-            end_day_start.max(window.start),
+            local_day_start(end_date).max(window.start),
             window.end,
         )?
     } else {
@@ -56,7 +57,7 @@ pub fn split_day_granularity_rows<T>(
     };
 
     // =========================
-    // 3️⃣ middle full days → day rows
+    // 3️⃣ middle full days → day rows (UTC) or hour rows (other offsets)
     // =========================
     let middle_start = if is_start_full_day {
         start_date
@@ -70,24 +71,104 @@ pub fn split_day_granularity_rows<T>(
         end_date.pred_opt().unwrap()
     };
 
-    let middle_day_rows = if middle_start <= middle_end {
-        let middle_start_dt =
-            middle_start.and_hms_opt(0, 0, 0).unwrap().and_utc();
-        let middle_end_dt =
-            middle_end.and_hms_opt(23, 59, 59).unwrap().and_utc();
+    let mut middle_day_rows = Vec::new();
+    let mut middle_hour_rows = Vec::new();
+    if middle_start <= middle_end {
+        let middle_start_dt = local_day_start(middle_start);
+        let middle_end_dt = local_day_end(middle_end);
 
-        day_repo.get_row_between(
-            object_name,
-            middle_start_dt,
-            middle_end_dt,
-        )?
-    } else {
-        Vec::new()
-    };
+        if window.offset.local_minus_utc() == 0 {
+            middle_day_rows = day_repo.get_row_between(object_name, middle_start_dt, middle_end_dt)?;
+        } else {
+            middle_hour_rows = hour_repo.get_row_between(object_name, middle_start_dt, middle_end_dt)?;
+        }
+    }
 
     Ok(DaySplitRows {
         start_hour_rows,
         end_hour_rows,
         middle_day_rows,
+        middle_hour_rows,
     })
 }
+
+fn local_time(window: &TimeWindow, date: NaiveDate, h: u32, m: u32, s: u32) -> DateTime<Utc> {
+    let naive = date.and_hms_opt(h, m, s).unwrap();
+    window
+        .offset
+        .from_local_datetime(&naive)
+        .single()
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|| naive.and_utc())
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::metric::k8s::common::dto::MetricGranularity;
+    use crate::domain::metric::k8s::common::service_helpers::parse_utc_offset;
+    use std::sync::Mutex;
+
+    /// Records the ranges it was asked for and returns one row per call.
+    #[derive(Default)]
+    struct RecordingRepo {
+        calls: Mutex<Vec<(DateTime<Utc>, DateTime<Utc>)>>,
+    }
+
+    impl MetricRowRepository<()> for RecordingRepo {
+        fn get_row_between(&self, _: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<()>> {
+            self.calls.lock().unwrap().push((start, end));
+            Ok(vec![()])
+        }
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn kst_window_reads_local_days_from_hour_rows() {
+        // 2025-01-01 00:00 .. 2025-01-03 12:00 KST
+        let window = TimeWindow {
+            start: utc("2024-12-31T15:00:00Z"),
+            end: utc("2025-01-03T03:00:00Z"),
+            granularity: MetricGranularity::Day,
+            offset: parse_utc_offset("Asia/Seoul").unwrap(),
+        };
+        let day_repo = RecordingRepo::default();
+        let hour_repo = RecordingRepo::default();
+
+        let split = split_day_granularity_rows("n", &window, &day_repo, &hour_repo).unwrap();
+
+        assert!(day_repo.calls.lock().unwrap().is_empty());
+        assert!(split.start_hour_rows.is_empty());
+        assert_eq!(split.middle_hour_rows.len(), 1);
+        assert_eq!(split.end_hour_rows.len(), 1);
+
+        let calls = hour_repo.calls.lock().unwrap();
+        // Partial Jan 3 from local midnight, then Jan 1-2 KST
+        assert_eq!(calls[0], (utc("2025-01-02T15:00:00Z"), utc("2025-01-03T03:00:00Z")));
+        assert_eq!(calls[1], (utc("2024-12-31T15:00:00Z"), utc("2025-01-02T14:59:59Z")));
+    }
+
+    #[test]
+    fn utc_window_keeps_using_day_rows() {
+        let window = TimeWindow {
+            start: utc("2025-01-01T00:00:00Z"),
+            end: utc("2025-01-02T23:59:59Z"),
+            granularity: MetricGranularity::Day,
+            offset: parse_utc_offset("UTC").unwrap(),
+        };
+        let day_repo = RecordingRepo::default();
+        let hour_repo = RecordingRepo::default();
+
+        let split = split_day_granularity_rows("n", &window, &day_repo, &hour_repo).unwrap();
+
+        assert_eq!(split.middle_day_rows.len(), 1);
+        assert!(split.middle_hour_rows.is_empty());
+        assert!(hour_repo.calls.lock().unwrap().is_empty());
+        assert_eq!(split.running_hours(), 24.0);
+    }
+}
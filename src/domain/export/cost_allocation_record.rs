@@ -0,0 +1,131 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::api::dto::metrics_dto::CostBasis;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::domain::metric::k8s::common::dto::MetricSeriesDto;
+
+/// One pod's cost for one UTC day, flattened for warehouse tables.
+/// Missing metadata is exported as an empty string so every column stays non-null.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostAllocationRecord {
+    pub date: String,
+    pub namespace: String,
+    pub pod_uid: String,
+    pub pod_name: String,
+    pub node_name: String,
+    pub workload_kind: String,
+    pub workload_name: String,
+    pub team: String,
+    pub service: String,
+    pub env: String,
+    /// Pod labels, flattened as `key=value,...`
+    pub labels: String,
+    pub cost_basis: String,
+    pub running_hours: f64,
+    pub cpu_cost_usd: f64,
+    pub memory_cost_usd: f64,
+    pub storage_cost_usd: f64,
+    pub total_cost_usd: f64,
+}
+
+impl CostAllocationRecord {
+    pub const TEXT_COLUMNS: [&'static str; 12] = [
+        "date",
+        "namespace",
+        "pod_uid",
+        "pod_name",
+        "node_name",
+        "workload_kind",
+        "workload_name",
+        "team",
+        "service",
+        "env",
+        "labels",
+        "cost_basis",
+    ];
+
+    pub const NUMBER_COLUMNS: [&'static str; 5] = [
+        "running_hours",
+        "cpu_cost_usd",
+        "memory_cost_usd",
+        "storage_cost_usd",
+        "total_cost_usd",
+    ];
+
+    /// `None` when the pod neither ran nor cost anything that day.
+    pub fn from_series(
+        date: NaiveDate,
+        basis: CostBasis,
+        series: &MetricSeriesDto,
+        pod: Option<&InfoPodEntity>,
+    ) -> Option<Self> {
+        let running_hours = series.running_hours.unwrap_or(0.0);
+        let cost = series.cost_summary.clone().unwrap_or_default();
+        let total_cost_usd = cost.total_cost_usd.unwrap_or(0.0);
+        if running_hours <= 0.0 && total_cost_usd == 0.0 {
+            return None;
+        }
+
+        let text = |f: fn(&InfoPodEntity) -> &Option<String>| {
+            pod.and_then(|p| f(p).clone()).unwrap_or_default()
+        };
+
+        Some(Self {
+            date: date.format("%Y-%m-%d").to_string(),
+            namespace: text(|p| &p.namespace),
+            pod_uid: series.key.clone(),
+            pod_name: pod.and_then(|p| p.pod_name.clone()).unwrap_or_else(|| series.name.clone()),
+            node_name: text(|p| &p.node_name),
+            workload_kind: text(|p| &p.workload_kind),
+            workload_name: text(|p| &p.workload_name),
+            team: text(|p| &p.team),
+            service: text(|p| &p.service),
+            env: text(|p| &p.env),
+            labels: text(|p| &p.label),
+            cost_basis: basis_code(basis).to_string(),
+            running_hours,
+            cpu_cost_usd: cost.cpu_cost_usd.unwrap_or(0.0),
+            memory_cost_usd: cost.memory_cost_usd.unwrap_or(0.0),
+            storage_cost_usd: cost.storage_cost_usd.unwrap_or(0.0),
+            total_cost_usd,
+        })
+    }
+
+    /// Values in [`Self::TEXT_COLUMNS`] order.
+    pub fn text_values(&self) -> [&str; 12] {
+        [
+            &self.date,
+            &self.namespace,
+            &self.pod_uid,
+            &self.pod_name,
+            &self.node_name,
+            &self.workload_kind,
+            &self.workload_name,
+            &self.team,
+            &self.service,
+            &self.env,
+            &self.labels,
+            &self.cost_basis,
+        ]
+    }
+
+    /// Values in [`Self::NUMBER_COLUMNS`] order.
+    pub fn number_values(&self) -> [f64; 5] {
+        [
+            self.running_hours,
+            self.cpu_cost_usd,
+            self.memory_cost_usd,
+            self.storage_cost_usd,
+            self.total_cost_usd,
+        ]
+    }
+}
+
+pub(crate) fn basis_code(basis: CostBasis) -> &'static str {
+    match basis {
+        CostBasis::Usage => "usage",
+        CostBasis::Request => "request",
+        CostBasis::Max => "max",
+    }
+}
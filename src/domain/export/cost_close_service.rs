@@ -0,0 +1,211 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Duration, Months, NaiveDate, Utc};
+use serde_json::{json, Value};
+use tracing::info;
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::api::dto::system_dto::{ClosePeriodQuery, CostAllocationQuery};
+use crate::api::util::tenant_scope::TenantScope;
+use crate::core::persistence::info::close::info_close_period_entity::InfoClosePeriodEntity;
+use crate::core::persistence::info::close::info_close_repository::{InfoCloseRepository, InfoCloseRepositoryImpl};
+use crate::domain::export::cost_allocation_record::{basis_code, CostAllocationRecord};
+use crate::domain::export::cost_materialization_service::whole_utc_days;
+use crate::domain::export::export_service::compute_cost_allocation_records;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{
+    MetricCostSummaryDto, MetricCostSummaryResponseDto,
+};
+use crate::domain::metric::k8s::common::dto::MetricScope;
+use crate::domain::metric::k8s::common::service_helpers::resolve_time_window;
+
+/// Longest range `/system/cost-allocation` computes in one request.
+const MAX_ALLOCATION_DAYS: i64 = 31;
+
+/// `YYYY-MM` period a UTC day belongs to.
+pub fn period_of(date: NaiveDate) -> String {
+    format!("{:04}-{:02}", date.year(), date.month())
+}
+
+/// First day of a `YYYY-MM` period and of the month after it.
+fn period_bounds(period: &str) -> Result<(NaiveDate, NaiveDate)> {
+    let first = NaiveDate::parse_from_str(&format!("{}-01", period), "%Y-%m-%d")
+        .map_err(|_| anyhow!("invalid period '{}', expected YYYY-MM", period))?;
+    let next = first
+        .checked_add_months(Months::new(1))
+        .ok_or_else(|| anyhow!("invalid period '{}'", period))?;
+    Ok((first, next))
+}
+
+/// Freezes every day of an ended month into an immutable snapshot.
+pub async fn close_cost_period(q: ClosePeriodQuery) -> Result<Value> {
+    let repo = InfoCloseRepositoryImpl::new();
+    let (first, next) = period_bounds(&q.period)?;
+    let period = period_of(first);
+
+    if next > Utc::now().date_naive() {
+        return Err(anyhow!("period {} has not ended yet", period));
+    }
+    if repo.read(&period)?.is_some() {
+        return Err(anyhow!("period {} is already closed", period));
+    }
+
+    let mut records = Vec::new();
+    let mut date = first;
+    while date < next {
+        records.extend(compute_cost_allocation_records(date).await?);
+        date += Duration::days(1);
+    }
+
+    let snapshot = InfoClosePeriodEntity {
+        period,
+        closed_at: Utc::now(),
+        note: q.note,
+        records,
+    };
+    repo.create(&snapshot)?;
+
+    info!(period = %snapshot.period, records = snapshot.records.len(), "cost_period_closed");
+    Ok(close_summary(&snapshot))
+}
+
+/// Closed periods with their totals, oldest first.
+pub async fn list_closed_periods() -> Result<Value> {
+    let repo = InfoCloseRepositoryImpl::new();
+    let mut periods = Vec::new();
+    for period in repo.list()? {
+        if let Some(snapshot) = repo.read(&period)? {
+            periods.push(close_summary(&snapshot));
+        }
+    }
+    Ok(json!({ "periods": periods }))
+}
+
+/// Daily allocation records for `[start, end]` the caller's tenant scope may
+/// see; closed months are served from their snapshot, open ones computed.
+pub async fn get_cost_allocation(q: CostAllocationQuery, scope: TenantScope) -> Result<Value> {
+    if q.end < q.start {
+        return Err(anyhow!("end must not be before start"));
+    }
+    if (q.end - q.start).num_days() >= MAX_ALLOCATION_DAYS {
+        return Err(anyhow!("range is limited to {} days", MAX_ALLOCATION_DAYS));
+    }
+
+    let repo = InfoCloseRepositoryImpl::new();
+    // Each month's snapshot is read once, not once per day
+    let mut snapshots: HashMap<String, Option<InfoClosePeriodEntity>> = HashMap::new();
+    let mut records = Vec::new();
+    let mut closed = Vec::new();
+    let mut date = q.start;
+    while date <= q.end {
+        let period = period_of(date);
+        if !snapshots.contains_key(&period) {
+            let snapshot = repo.read(&period)?;
+            if snapshot.is_some() {
+                closed.push(period.clone());
+            }
+            snapshots.insert(period.clone(), snapshot);
+        }
+        match &snapshots[&period] {
+            Some(snapshot) => records.extend(snapshot.records_on(&date.format("%Y-%m-%d").to_string())),
+            None => records.extend(compute_cost_allocation_records(date).await?),
+        }
+        date += Duration::days(1);
+    }
+    records.retain(|r| scope.allows_attribution(&r.namespace, &r.team));
+
+    Ok(json!({
+        "start": q.start,
+        "end": q.end,
+        "closed_periods": closed,
+        "records": records,
+    }))
+}
+
+/// Cost summary of the snapshot records `keep` selects, when `q` covers whole
+/// UTC days of closed months only and asks for the cost basis they were closed
+/// on, so finance numbers don't move with later price changes. `None` means the
+/// caller computes as usual (also when no record matched).
+pub fn closed_cost_summary(
+    q: &RangeQuery,
+    scope: MetricScope,
+    target: Option<String>,
+    keep: impl Fn(&CostAllocationRecord) -> bool,
+) -> Result<Option<MetricCostSummaryResponseDto>> {
+    if q.labels.is_some() || q.sort.is_some() || q.limit.is_some() || q.offset.is_some() || q.cursor.is_some() {
+        return Ok(None);
+    }
+
+    let window = resolve_time_window(q);
+    let Some(dates) = whole_utc_days(window.start, window.end, window.offset.local_minus_utc(), Utc::now()) else {
+        return Ok(None);
+    };
+    let days: HashSet<String> = dates.iter().map(|d| d.format("%Y-%m-%d").to_string()).collect();
+    let periods: BTreeSet<String> = dates.iter().map(|d| period_of(*d)).collect();
+    let basis = basis_code(q.effective_cost_basis());
+
+    let repo = InfoCloseRepositoryImpl::new();
+    let mut summary = MetricCostSummaryDto::default();
+    let mut matched = false;
+    for period in periods {
+        let Some(snapshot) = repo.read(&period)? else {
+            return Ok(None);
+        };
+        for record in snapshot
+            .records
+            .iter()
+            .filter(|r| r.cost_basis == basis && days.contains(&r.date) && keep(r))
+        {
+            summary.total_cost_usd += record.total_cost_usd;
+            summary.cpu_cost_usd += record.cpu_cost_usd;
+            summary.memory_cost_usd += record.memory_cost_usd;
+            summary.ephemeral_storage_cost_usd += record.storage_cost_usd;
+            matched = true;
+        }
+    }
+    if !matched {
+        return Ok(None);
+    }
+
+    Ok(Some(MetricCostSummaryResponseDto {
+        start: window.start,
+        end: window.end,
+        scope,
+        target,
+        granularity: window.granularity,
+        summary,
+        commitments: None,
+    }))
+}
+
+/// Empty allocation text (missing metadata) as `None`, for the metric filters.
+pub fn record_text(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn close_summary(snapshot: &InfoClosePeriodEntity) -> Value {
+    json!({
+        "period": snapshot.period,
+        "closed_at": snapshot.closed_at,
+        "note": snapshot.note,
+        "records": snapshot.records.len(),
+        "total_cost_usd": snapshot.total_cost_usd(),
+    })
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_bounds_cover_the_whole_month() {
+        let (first, next) = period_bounds("2024-02").unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+        assert_eq!(next, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        assert_eq!(period_of(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()), "2024-02");
+        assert!(period_bounds("2024-13").is_err());
+        assert!(period_bounds("Feb 2024").is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Timelike, Utc};
+use std::collections::HashMap;
+use tracing::{error, info};
+
+use crate::api::dto::metrics_dto::{CostMode, RangeQuery};
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::metrics::k8s::allocation::metric_cost_allocation_entity::{
+    MetricCostAllocationDayEntity, MetricPodDayCostEntity,
+};
+use crate::core::persistence::metrics::k8s::allocation::metric_cost_allocation_fs_adapter::MetricCostAllocationFsAdapter;
+use crate::core::persistence::metrics::k8s::path::metric_k8s_pod_dir_path;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
+use crate::domain::export::export_service::day_query;
+use crate::domain::info::service::info_unit_price_service::get_info_unit_prices;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{
+    MetricCostSummaryDto, MetricCostSummaryResponseDto,
+};
+use crate::domain::metric::k8s::common::dto::MetricScope;
+use crate::domain::metric::k8s::common::service_helpers::{resolve_time_window, series_cost_summary};
+use crate::domain::metric::k8s::pod::service::build_pod_cost_response_with_infos;
+use crate::scheduler::job_tracker::{record_failure, record_processed};
+
+/// Closed days a nightly run fills in when they are missing (downtime, days
+/// dropped by a back-dated price change).
+const CATCH_UP_DAYS: i64 = 7;
+
+/// Scheduled from the day task: materializes the day that just closed, plus
+/// any of the days before it that have no records yet.
+pub async fn run_cost_materialization(now: DateTime<Utc>) -> Result<()> {
+    let adapter = MetricCostAllocationFsAdapter::new();
+    let today = now.date_naive();
+
+    for back in (1..=CATCH_UP_DAYS).rev() {
+        let date = today - Duration::days(back);
+        if adapter.exists(date) {
+            continue;
+        }
+        match materialize_day(date).await {
+            Ok(pods) => {
+                info!(%date, pods, "cost_allocation_materialized");
+                record_processed();
+            }
+            Err(e) => {
+                error!("⚠️ Materializing cost allocation for {} failed: {}", date, e);
+                record_failure(&date.to_string(), &e);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Prices every pod's hour rows of `date` (UTC) on the usage basis and stores
+/// one record per pod that ran or cost anything. Returns the number of records.
+pub async fn materialize_day(date: NaiveDate) -> Result<usize> {
+    let q = day_query(date, CostMode::Showback)?;
+    let pod_uids = metric_object_keys("pod", &metric_k8s_pod_dir_path())?;
+    let (response, pod_infos) = build_pod_cost_response_with_infos(q, pod_uids).await?;
+
+    // A partial day would be served as complete from then on
+    if response.partial {
+        return Err(anyhow!("metrics of {} pods could not be read", response.warnings.len()));
+    }
+
+    let unit_prices = get_info_unit_prices().await?;
+    let pods: HashMap<&str, _> = pod_infos
+        .iter()
+        .filter_map(|p| p.pod_uid.as_deref().map(|uid| (uid, p)))
+        .collect();
+
+    let mut records = Vec::new();
+    for series in &response.series {
+        let running_hours = series.running_hours.unwrap_or(0.0);
+        let cost = series_cost_summary(series, &response.granularity, &unit_prices);
+        if running_hours <= 0.0 && cost.total_cost_usd == 0.0 {
+            continue;
+        }
+
+        let pod = pods.get(series.key.as_str());
+        let text = |f: fn(&InfoPodEntity) -> &Option<String>| pod.and_then(|p| f(*p).clone());
+        records.push(MetricPodDayCostEntity {
+            pod_uid: series.key.clone(),
+            pod_name: text(|p| &p.pod_name),
+            namespace: text(|p| &p.namespace),
+            workload_kind: text(|p| &p.workload_kind),
+            workload_name: text(|p| &p.workload_name),
+            team: text(|p| &p.team),
+            service: text(|p| &p.service),
+            env: text(|p| &p.env),
+            running_hours,
+            cost,
+        });
+    }
+
+    let count = records.len();
+    MetricCostAllocationFsAdapter::new().write(&MetricCostAllocationDayEntity {
+        date,
+        materialized_at: Utc::now(),
+        pods: records,
+    })?;
+    Ok(count)
+}
+
+/// Cost summary of the pod records `keep` selects, when `q` can be answered
+/// from materialized days: whole UTC days that have all closed and been
+/// materialized, with no label filter or paging. `None` means the caller
+/// prices raw points as usual (also when no record matched, so "no pods"
+/// errors stay the same).
+pub fn materialized_cost_summary(
+    q: &RangeQuery,
+    scope: MetricScope,
+    target: Option<String>,
+    keep: impl Fn(&MetricPodDayCostEntity) -> bool,
+) -> Result<Option<MetricCostSummaryResponseDto>> {
+    if q.labels.is_some() || q.sort.is_some() || q.limit.is_some() || q.offset.is_some() || q.cursor.is_some() {
+        return Ok(None);
+    }
+
+    let window = resolve_time_window(q);
+    let Some(dates) = whole_utc_days(window.start, window.end, window.offset.local_minus_utc(), Utc::now()) else {
+        return Ok(None);
+    };
+
+    let adapter = MetricCostAllocationFsAdapter::new();
+    let mut summary = MetricCostSummaryDto::default();
+    let mut matched = false;
+    for date in dates {
+        let Some(day) = adapter.read(date)? else {
+            return Ok(None);
+        };
+        for pod in day.pods.iter().filter(|p| keep(p)) {
+            add_summary(&mut summary, &pod.cost);
+            matched = true;
+        }
+    }
+    if !matched {
+        return Ok(None);
+    }
+
+    Ok(Some(MetricCostSummaryResponseDto {
+        start: window.start,
+        end: window.end,
+        scope,
+        target,
+        granularity: window.granularity,
+        summary,
+        commitments: None,
+    }))
+}
+
+/// Days of `[start, end)` when both bounds are UTC midnights and the last day
+/// has closed by `now`.
+pub(crate) fn whole_utc_days(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    offset_seconds: i32,
+    now: DateTime<Utc>,
+) -> Option<Vec<NaiveDate>> {
+    let midnight = |t: DateTime<Utc>| t.time() == NaiveTime::MIN && t.nanosecond() == 0;
+    if offset_seconds != 0 || !midnight(start) || !midnight(end) || start >= end || end.date_naive() > now.date_naive() {
+        return None;
+    }
+
+    let mut dates = Vec::new();
+    let mut date = start.date_naive();
+    while date < end.date_naive() {
+        dates.push(date);
+        date += Duration::days(1);
+    }
+    Some(dates)
+}
+
+fn add_summary(total: &mut MetricCostSummaryDto, cost: &MetricCostSummaryDto) {
+    total.total_cost_usd += cost.total_cost_usd;
+    total.cpu_cost_usd += cost.cpu_cost_usd;
+    total.memory_cost_usd += cost.memory_cost_usd;
+    total.ephemeral_storage_cost_usd += cost.ephemeral_storage_cost_usd;
+    total.persistent_storage_cost_usd += cost.persistent_storage_cost_usd;
+    total.network_cost_usd += cost.network_cost_usd;
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn only_closed_whole_utc_days_are_materialized() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 10, 8, 0, 0).unwrap();
+        let day = |d| Utc.with_ymd_and_hms(2025, 3, d, 0, 0, 0).unwrap();
+
+        let dates = whole_utc_days(day(1), day(4), 0, now).unwrap();
+        assert_eq!(dates.len(), 3);
+        assert_eq!(dates[0], NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+
+        // Ends today: today hasn't closed
+        assert!(whole_utc_days(day(1), day(11), 0, now).is_none());
+        // Not midnight-aligned, or local days
+        assert!(whole_utc_days(day(1) + Duration::hours(1), day(4), 0, now).is_none());
+        assert!(whole_utc_days(day(1), day(4), 9 * 3600, now).is_none());
+    }
+}
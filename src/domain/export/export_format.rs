@@ -0,0 +1,146 @@
+use anyhow::Result;
+
+use crate::domain::export::cost_allocation_record::CostAllocationRecord;
+use crate::domain::export::parquet_writer::{write_parquet, ParquetColumn};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Parquet,
+    Csv,
+    Ndjson,
+}
+
+impl ExportFormat {
+    pub fn parse(v: &str) -> Option<Self> {
+        match v.trim().to_lowercase().as_str() {
+            "parquet" => Some(Self::Parquet),
+            "csv" => Some(Self::Csv),
+            "ndjson" | "jsonl" => Some(Self::Ndjson),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Parquet => "parquet",
+            Self::Csv => "csv",
+            Self::Ndjson => "ndjson",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Parquet => "application/vnd.apache.parquet",
+            Self::Csv => "text/csv",
+            Self::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    pub fn encode(&self, records: &[CostAllocationRecord]) -> Result<Vec<u8>> {
+        match self {
+            Self::Parquet => Ok(encode_parquet(records)),
+            Self::Csv => Ok(encode_csv(records)),
+            Self::Ndjson => {
+                let mut out = Vec::new();
+                for r in records {
+                    serde_json::to_writer(&mut out, r)?;
+                    out.push(b'\n');
+                }
+                Ok(out)
+            }
+        }
+    }
+}
+
+fn encode_parquet(records: &[CostAllocationRecord]) -> Vec<u8> {
+    let mut columns = Vec::new();
+    for (i, name) in CostAllocationRecord::TEXT_COLUMNS.iter().enumerate() {
+        let values = records.iter().map(|r| r.text_values()[i].to_string()).collect();
+        columns.push((*name, ParquetColumn::Utf8(values)));
+    }
+    for (i, name) in CostAllocationRecord::NUMBER_COLUMNS.iter().enumerate() {
+        let values = records.iter().map(|r| r.number_values()[i]).collect();
+        columns.push((*name, ParquetColumn::Double(values)));
+    }
+    write_parquet(&columns)
+}
+
+/// RFC 4180: header row, CRLF line ends, fields quoted only when needed.
+fn encode_csv(records: &[CostAllocationRecord]) -> Vec<u8> {
+    let header: Vec<&str> = CostAllocationRecord::TEXT_COLUMNS
+        .iter()
+        .chain(CostAllocationRecord::NUMBER_COLUMNS.iter())
+        .copied()
+        .collect();
+
+    let mut out = header.join(",");
+    out.push_str("\r\n");
+    for r in records {
+        let fields: Vec<String> = r
+            .text_values()
+            .iter()
+            .map(|v| csv_field(v))
+            .chain(r.number_values().iter().map(|v| v.to_string()))
+            .collect();
+        out.push_str(&fields.join(","));
+        out.push_str("\r\n");
+    }
+    out.into_bytes()
+}
+
+fn csv_field(v: &str) -> String {
+    if v.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", v.replace('"', "\"\""))
+    } else {
+        v.to_string()
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> CostAllocationRecord {
+        CostAllocationRecord {
+            date: "2025-03-01".into(),
+            namespace: "payments".into(),
+            pod_uid: "uid-1".into(),
+            pod_name: "api-7d9f".into(),
+            node_name: "node-a".into(),
+            workload_kind: "Deployment".into(),
+            workload_name: "api".into(),
+            team: "core".into(),
+            service: String::new(),
+            env: "prod".into(),
+            labels: "app=api,tier=\"web\"".into(),
+            cost_basis: "max".into(),
+            running_hours: 24.0,
+            cpu_cost_usd: 1.5,
+            memory_cost_usd: 0.5,
+            storage_cost_usd: 0.0,
+            total_cost_usd: 2.0,
+        }
+    }
+
+    #[test]
+    fn csv_quotes_fields_with_separators() {
+        let csv = String::from_utf8(ExportFormat::Csv.encode(&[record()]).unwrap()).unwrap();
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+
+        assert!(lines[0].starts_with("date,namespace,pod_uid"));
+        assert!(lines[0].ends_with("storage_cost_usd,total_cost_usd"));
+        assert_eq!(
+            lines[1],
+            "2025-03-01,payments,uid-1,api-7d9f,node-a,Deployment,api,core,,prod,\"app=api,tier=\"\"web\"\"\",max,24,1.5,0.5,0,2"
+        );
+    }
+
+    #[test]
+    fn parses_format_names() {
+        assert_eq!(ExportFormat::parse("Parquet"), Some(ExportFormat::Parquet));
+        assert_eq!(ExportFormat::parse("jsonl"), Some(ExportFormat::Ndjson));
+        assert_eq!(ExportFormat::parse("avro"), None);
+    }
+}
@@ -0,0 +1,121 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use reqwest::Client;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tracing::{debug, info};
+
+use crate::api::dto::metrics_dto::{CostMode, RangeQuery};
+use crate::api::dto::system_dto::ExportQuery;
+use crate::core::persistence::info::close::info_close_repository::{InfoCloseRepository, InfoCloseRepositoryImpl};
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::metrics::k8s::path::metric_k8s_pod_dir_path;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
+use crate::domain::export::cost_allocation_record::CostAllocationRecord;
+use crate::domain::export::cost_close_service::period_of;
+use crate::domain::export::export_format::ExportFormat;
+use crate::domain::export::export_sink::ExportSink;
+use crate::domain::info::service::info_settings_service::get_info_settings;
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+use crate::domain::metric::k8s::pod::service::build_pod_cost_response_with_infos;
+
+/// Scheduled from the day task: exports the UTC day that just closed.
+/// A no-op until `export_sink_url` is configured.
+pub async fn run_daily_export(now: DateTime<Utc>) -> Result<()> {
+    let settings = get_info_settings().await?;
+    if settings.export_sink_url.is_none() {
+        debug!("No export sink configured, skipping cost export");
+        return Ok(());
+    }
+
+    let date = now.date_naive() - Duration::days(1);
+    export_with_settings(&settings, date).await?;
+    Ok(())
+}
+
+/// Exports one UTC day on demand (re-runs overwrite the day's file).
+pub async fn export_cost_allocation(q: ExportQuery) -> Result<Value> {
+    let settings = get_info_settings().await?;
+    export_with_settings(&settings, q.date).await
+}
+
+async fn export_with_settings(settings: &InfoSettingEntity, date: NaiveDate) -> Result<Value> {
+    let sink_url = settings
+        .export_sink_url
+        .as_deref()
+        .ok_or_else(|| anyhow!("export_sink_url is not configured"))?;
+    let sink = ExportSink::parse(sink_url)?;
+    let format = ExportFormat::parse(&settings.export_format)
+        .ok_or_else(|| anyhow!("unsupported export_format: {}", settings.export_format))?;
+
+    let records = build_cost_allocation_records(date).await?;
+    let body = format.encode(&records)?;
+    let key = sink.object_key(date, format);
+    let target = sink
+        .put(&Client::new(), &key, body, format, settings.export_token.as_deref())
+        .await?;
+
+    info!(%date, records = records.len(), %target, "cost_allocation_exported");
+    Ok(json!({
+        "date": date,
+        "records": records.len(),
+        "target": target,
+    }))
+}
+
+/// Chargeback cost of every pod with metrics on `date` (UTC), one record per pod.
+/// Days of a closed period come from its snapshot, never recomputed.
+pub async fn build_cost_allocation_records(date: NaiveDate) -> Result<Vec<CostAllocationRecord>> {
+    if let Some(snapshot) = InfoCloseRepositoryImpl::new().read(&period_of(date))? {
+        return Ok(snapshot.records_on(&date.format("%Y-%m-%d").to_string()));
+    }
+    compute_cost_allocation_records(date).await
+}
+
+/// Allocation of `date` from current metrics and prices, ignoring any close.
+pub(crate) async fn compute_cost_allocation_records(date: NaiveDate) -> Result<Vec<CostAllocationRecord>> {
+    let q = day_query(date, CostMode::Chargeback)?;
+    let basis = q.effective_cost_basis();
+
+    let pod_uids = metric_object_keys("pod", &metric_k8s_pod_dir_path())?;
+    let (response, pod_infos) = build_pod_cost_response_with_infos(q, pod_uids).await?;
+
+    let pods: HashMap<&str, _> = pod_infos
+        .iter()
+        .filter_map(|p| p.pod_uid.as_deref().map(|uid| (uid, p)))
+        .collect();
+
+    Ok(response
+        .series
+        .iter()
+        .filter_map(|s| CostAllocationRecord::from_series(date, basis, s, pods.get(s.key.as_str()).copied()))
+        .collect())
+}
+
+/// Hourly query over one UTC day in `mode`, unfiltered.
+pub(crate) fn day_query(date: NaiveDate, mode: CostMode) -> Result<RangeQuery> {
+    let start = date.and_hms_opt(0, 0, 0).ok_or_else(|| anyhow!("invalid date {}", date))?;
+    Ok(RangeQuery {
+        start: Some(start),
+        end: Some(start + Duration::days(1)),
+        granularity: Some(MetricGranularity::Hour),
+        tz: None,
+        range: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        sort: None,
+        mode,
+        cost_basis: None,
+        team: None,
+        service: None,
+        env: None,
+        namespace: None,
+        exclude_namespace: None,
+        exclude_team: None,
+        exclude_service: None,
+        exclude_env: None,
+        labels: None,
+        key: None,
+    })
+}
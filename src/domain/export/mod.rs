@@ -0,0 +1,17 @@
+//! Daily cost allocation export to external warehouses.
+//!
+//! - cost_allocation_record: one row per pod and day
+//! - export_format: Parquet / CSV / NDJSON encoding
+//! - export_sink: S3, GCS, HTTP or local file targets
+//! - export_service: builds the day's records and ships them
+//! - cost_close_service: freezes a month's records for finance close
+//! - cost_materialization_service: nightly per-pod day costs that summaries of
+//!   past days read instead of raw points
+
+pub mod cost_allocation_record;
+pub mod cost_close_service;
+pub mod cost_materialization_service;
+pub mod export_format;
+pub mod export_service;
+pub mod export_sink;
+pub mod parquet_writer;
@@ -0,0 +1,275 @@
+//! Minimal Parquet writer for flat export tables.
+//!
+//! Writes one row group with one uncompressed, PLAIN-encoded data page per
+//! column. Columns are `REQUIRED`, so pages carry no definition or repetition
+//! levels. That covers what warehouses need to load a daily export file
+//! (BigQuery, Snowflake, Athena, DuckDB all read it) without a Parquet crate.
+
+const MAGIC: &[u8; 4] = b"PAR1";
+const CREATED_BY: &str = concat!("rustcost ", env!("CARGO_PKG_VERSION"));
+
+// parquet.thrift enums
+const TYPE_DOUBLE: i32 = 5;
+const TYPE_BYTE_ARRAY: i32 = 6;
+const REPETITION_REQUIRED: i32 = 0;
+const CONVERTED_UTF8: i32 = 0;
+const ENCODING_PLAIN: i32 = 0;
+const ENCODING_RLE: i32 = 3;
+const CODEC_UNCOMPRESSED: i32 = 0;
+const PAGE_DATA: i32 = 0;
+
+pub enum ParquetColumn {
+    Utf8(Vec<String>),
+    Double(Vec<f64>),
+}
+
+impl ParquetColumn {
+    fn len(&self) -> usize {
+        match self {
+            ParquetColumn::Utf8(v) => v.len(),
+            ParquetColumn::Double(v) => v.len(),
+        }
+    }
+
+    fn physical_type(&self) -> i32 {
+        match self {
+            ParquetColumn::Utf8(_) => TYPE_BYTE_ARRAY,
+            ParquetColumn::Double(_) => TYPE_DOUBLE,
+        }
+    }
+
+    fn plain_values(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        match self {
+            ParquetColumn::Utf8(values) => {
+                for v in values {
+                    out.extend_from_slice(&(v.len() as u32).to_le_bytes());
+                    out.extend_from_slice(v.as_bytes());
+                }
+            }
+            ParquetColumn::Double(values) => {
+                for v in values {
+                    out.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Encodes `columns` as a Parquet file. Every column must hold the same number of rows.
+pub fn write_parquet(columns: &[(&str, ParquetColumn)]) -> Vec<u8> {
+    let num_rows = columns.first().map(|(_, c)| c.len()).unwrap_or(0);
+    assert!(
+        columns.iter().all(|(_, c)| c.len() == num_rows),
+        "parquet columns must have the same length"
+    );
+
+    let mut file = MAGIC.to_vec();
+    let mut chunks = Vec::with_capacity(columns.len());
+
+    for (_, column) in columns {
+        let values = column.plain_values();
+
+        let mut header = CompactWriter::default();
+        header.i32_field(1, PAGE_DATA);
+        header.i32_field(2, values.len() as i32);
+        header.i32_field(3, values.len() as i32);
+        header.struct_field(5, |h| {
+            h.i32_field(1, num_rows as i32);
+            h.i32_field(2, ENCODING_PLAIN);
+            h.i32_field(3, ENCODING_RLE);
+            h.i32_field(4, ENCODING_RLE);
+        });
+        let header = header.finish();
+
+        let offset = file.len() as i64;
+        let size = (header.len() + values.len()) as i64;
+        file.extend_from_slice(&header);
+        file.extend_from_slice(&values);
+        chunks.push((offset, size));
+    }
+
+    let mut meta = CompactWriter::default();
+    meta.i32_field(1, 1);
+    meta.list_field(2, CompactType::Struct, columns.len() + 1);
+    meta.list_struct(|s| {
+        s.binary_field(4, b"schema");
+        s.i32_field(5, columns.len() as i32);
+    });
+    for (name, column) in columns {
+        meta.list_struct(|s| {
+            s.i32_field(1, column.physical_type());
+            s.i32_field(3, REPETITION_REQUIRED);
+            s.binary_field(4, name.as_bytes());
+            if matches!(column, ParquetColumn::Utf8(_)) {
+                s.i32_field(6, CONVERTED_UTF8);
+            }
+        });
+    }
+    meta.i64_field(3, num_rows as i64);
+    meta.list_field(4, CompactType::Struct, 1);
+    meta.list_struct(|rg| {
+        rg.list_field(1, CompactType::Struct, columns.len());
+        for ((name, column), (offset, size)) in columns.iter().zip(&chunks) {
+            rg.list_struct(|cc| {
+                cc.i64_field(2, *offset);
+                cc.struct_field(3, |md| {
+                    md.i32_field(1, column.physical_type());
+                    md.list_field(2, CompactType::I32, 1);
+                    md.list_i32(ENCODING_PLAIN);
+                    md.list_field(3, CompactType::Binary, 1);
+                    md.list_binary(name.as_bytes());
+                    md.i32_field(4, CODEC_UNCOMPRESSED);
+                    md.i64_field(5, num_rows as i64);
+                    md.i64_field(6, *size);
+                    md.i64_field(7, *size);
+                    md.i64_field(9, *offset);
+                });
+            });
+        }
+        rg.i64_field(2, chunks.iter().map(|(_, s)| s).sum());
+        rg.i64_field(3, num_rows as i64);
+    });
+    meta.binary_field(6, CREATED_BY.as_bytes());
+    let meta = meta.finish();
+
+    file.extend_from_slice(&meta);
+    file.extend_from_slice(&(meta.len() as u32).to_le_bytes());
+    file.extend_from_slice(MAGIC);
+    file
+}
+
+#[derive(Clone, Copy)]
+enum CompactType {
+    I32 = 5,
+    I64 = 6,
+    Binary = 8,
+    List = 9,
+    Struct = 12,
+}
+
+/// Thrift compact protocol, just the parts the Parquet footer needs.
+#[derive(Default)]
+struct CompactWriter {
+    buf: Vec<u8>,
+    last_field: i16,
+    parents: Vec<i16>,
+}
+
+impl CompactWriter {
+    fn finish(mut self) -> Vec<u8> {
+        self.buf.push(0); // stop
+        self.buf
+    }
+
+    fn field_header(&mut self, id: i16, ty: CompactType) {
+        let delta = id - self.last_field;
+        if (1..=15).contains(&delta) {
+            self.buf.push(((delta as u8) << 4) | ty as u8);
+        } else {
+            self.buf.push(ty as u8);
+            self.varint(zigzag(id as i64));
+        }
+        self.last_field = id;
+    }
+
+    fn varint(&mut self, mut v: u64) {
+        while v >= 0x80 {
+            self.buf.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        self.buf.push(v as u8);
+    }
+
+    fn i32_field(&mut self, id: i16, v: i32) {
+        self.field_header(id, CompactType::I32);
+        self.varint(zigzag(v as i64));
+    }
+
+    fn i64_field(&mut self, id: i16, v: i64) {
+        self.field_header(id, CompactType::I64);
+        self.varint(zigzag(v));
+    }
+
+    fn binary_field(&mut self, id: i16, v: &[u8]) {
+        self.field_header(id, CompactType::Binary);
+        self.list_binary(v);
+    }
+
+    fn list_field(&mut self, id: i16, elem: CompactType, len: usize) {
+        self.field_header(id, CompactType::List);
+        if len < 15 {
+            self.buf.push(((len as u8) << 4) | elem as u8);
+        } else {
+            self.buf.push(0xf0 | elem as u8);
+            self.varint(len as u64);
+        }
+    }
+
+    fn list_i32(&mut self, v: i32) {
+        self.varint(zigzag(v as i64));
+    }
+
+    fn list_binary(&mut self, v: &[u8]) {
+        self.varint(v.len() as u64);
+        self.buf.extend_from_slice(v);
+    }
+
+    /// One struct element of a list.
+    fn list_struct(&mut self, body: impl FnOnce(&mut Self)) {
+        self.parents.push(self.last_field);
+        self.last_field = 0;
+        body(self);
+        self.buf.push(0);
+        self.last_field = self.parents.pop().unwrap_or(0);
+    }
+
+    fn struct_field(&mut self, id: i16, body: impl FnOnce(&mut Self)) {
+        self.field_header(id, CompactType::Struct);
+        self.list_struct(body);
+    }
+}
+
+fn zigzag(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_is_framed_by_magic_and_footer_length() {
+        let bytes = write_parquet(&[
+            ("namespace", ParquetColumn::Utf8(vec!["default".into(), "payments".into()])),
+            ("total_cost_usd", ParquetColumn::Double(vec![1.5, 0.25])),
+        ]);
+
+        assert_eq!(&bytes[..4], MAGIC);
+        assert_eq!(&bytes[bytes.len() - 4..], MAGIC);
+
+        let footer_len =
+            u32::from_le_bytes(bytes[bytes.len() - 8..bytes.len() - 4].try_into().unwrap()) as usize;
+        let footer_start = bytes.len() - 8 - footer_len;
+        // Footer starts with `version = 1` (field 1, i32, zigzag 2)
+        assert_eq!(&bytes[footer_start..footer_start + 2], &[0x15, 0x02]);
+
+        // First page's values follow its header: "default" length-prefixed
+        let needle = [7u8, 0, 0, 0, b'd', b'e', b'f'];
+        assert!(bytes.windows(needle.len()).any(|w| w == needle));
+    }
+
+    #[test]
+    fn compact_ints_use_zigzag_varints() {
+        assert_eq!(zigzag(0), 0);
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+
+        let mut w = CompactWriter::default();
+        w.i64_field(1, 300);
+        assert_eq!(w.finish(), vec![0x16, 0xd8, 0x04, 0x00]);
+    }
+}
@@ -1,12 +1,18 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
 
 use crate::core::persistence::info::fixed::alerts::alert_rule_entity::{
-    AlertMetricType, AlertOperator, AlertRuleEntity, AlertSeverity,
+    AlertChannel, AlertMetricType, AlertOperator, AlertRuleEntity, AlertSeverity,
+};
+use crate::core::persistence::info::fixed::alerts::alert_silence_entity::AlertSilenceEntity;
+use crate::core::persistence::info::fixed::alerts::efficiency_alert_rule_entity::{
+    AlertEfficiencyScope, EfficiencyAlertRuleEntity,
 };
 
 /// Upsert payload for alert configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct InfoAlertUpsertRequest {
     /// Enable cluster-level health monitoring alerts.
     pub enable_cluster_health_alert: Option<bool>,
@@ -40,9 +46,17 @@ pub struct InfoAlertUpsertRequest {
     /// Declarative alert rules.
     #[validate(nested)]
     pub rules: Option<Vec<AlertRuleUpsertRequest>>,
+
+    /// Workload efficiency-drop rules; replaces the stored list.
+    #[validate(nested)]
+    pub efficiency_rules: Option<Vec<EfficiencyAlertRuleUpsertRequest>>,
+
+    /// Silence windows; replaces the stored list. Expired windows are dropped.
+    #[validate(nested)]
+    pub silences: Option<Vec<AlertSilenceUpsertRequest>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct AlertRuleUpsertRequest {
     #[validate(length(min = 1))]
     pub id: String,
@@ -54,6 +68,51 @@ pub struct AlertRuleUpsertRequest {
     pub for_duration_sec: u64,
     pub severity: AlertSeverity,
     pub enabled: bool,
+    /// Node name pattern (`*` wildcards); omitted applies the rule to every node.
+    pub scope_selector: Option<String>,
+    #[serde(default)]
+    pub channel: AlertChannel,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct EfficiencyAlertRuleUpsertRequest {
+    #[validate(length(min = 1))]
+    pub id: String,
+    #[validate(length(min = 1))]
+    pub name: String,
+    pub scope: AlertEfficiencyScope,
+    /// Namespace or deployment name pattern (`*` wildcards); omitted matches all.
+    pub target_selector: Option<String>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub threshold_percent: f64,
+    /// Full hours the efficiency must stay below the threshold (1–168).
+    #[validate(range(min = 1, max = 168))]
+    pub consecutive_hours: u32,
+    pub severity: AlertSeverity,
+    #[serde(default)]
+    pub channel: AlertChannel,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_silence_window"))]
+pub struct AlertSilenceUpsertRequest {
+    #[validate(length(min = 1))]
+    pub id: String,
+    /// Rule to silence; omitted silences every rule.
+    pub rule_id: Option<String>,
+    /// Node name pattern (`*` wildcards); omitted matches every node.
+    pub scope_selector: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub comment: Option<String>,
+}
+
+fn validate_silence_window(v: &AlertSilenceUpsertRequest) -> Result<(), ValidationError> {
+    if v.ends_at <= v.starts_at {
+        return Err(ValidationError::new("silence_ends_before_start"));
+    }
+    Ok(())
 }
 
 impl From<AlertRuleUpsertRequest> for AlertRuleEntity {
@@ -67,6 +126,37 @@ impl From<AlertRuleUpsertRequest> for AlertRuleEntity {
             for_duration_sec: value.for_duration_sec,
             severity: value.severity,
             enabled: value.enabled,
+            scope_selector: value.scope_selector.filter(|s| !s.trim().is_empty()),
+            channel: value.channel,
+        }
+    }
+}
+
+impl From<EfficiencyAlertRuleUpsertRequest> for EfficiencyAlertRuleEntity {
+    fn from(value: EfficiencyAlertRuleUpsertRequest) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            scope: value.scope,
+            target_selector: value.target_selector.filter(|s| !s.trim().is_empty()),
+            threshold_percent: value.threshold_percent,
+            consecutive_hours: value.consecutive_hours,
+            severity: value.severity,
+            channel: value.channel,
+            enabled: value.enabled,
+        }
+    }
+}
+
+impl From<AlertSilenceUpsertRequest> for AlertSilenceEntity {
+    fn from(value: AlertSilenceUpsertRequest) -> Self {
+        Self {
+            id: value.id,
+            rule_id: value.rule_id.filter(|s| !s.trim().is_empty()),
+            scope_selector: value.scope_selector.filter(|s| !s.trim().is_empty()),
+            starts_at: value.starts_at,
+            ends_at: value.ends_at,
+            comment: value.comment,
         }
     }
 }
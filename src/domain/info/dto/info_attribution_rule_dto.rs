@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+/// Body for creating or replacing an attribution rule.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_sets_something"))]
+pub struct InfoAttributionRuleUpsertRequest {
+    #[validate(length(min = 1, max = 128))]
+    pub name: String,
+    pub priority: Option<i32>,
+    pub enabled: Option<bool>,
+    /// Namespace pattern with `*` wildcards; omit to match every namespace.
+    #[validate(length(max = 253))]
+    pub namespace_selector: Option<String>,
+    /// Labels a pod must all carry.
+    pub label_selector: Option<BTreeMap<String, String>>,
+    #[validate(length(min = 1, max = 128))]
+    pub team: Option<String>,
+    #[validate(length(min = 1, max = 128))]
+    pub service: Option<String>,
+    #[validate(length(min = 1, max = 64))]
+    pub env: Option<String>,
+}
+
+/// Dry run of the stored rules plus an optional change.
+///
+/// `rule` alone previews a new rule, `rule_id` + `rule` an edit, and `rule_id`
+/// alone the removal of that rule. An empty body previews the stored rules as-is.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct InfoAttributionPreviewRequest {
+    pub rule_id: Option<String>,
+    #[validate(nested)]
+    pub rule: Option<InfoAttributionRuleUpsertRequest>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct InfoAttributionValuesDto {
+    pub team: Option<String>,
+    pub service: Option<String>,
+    pub env: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InfoAttributionChangeDto {
+    pub pod_uid: String,
+    pub pod_name: Option<String>,
+    pub namespace: Option<String>,
+    pub before: InfoAttributionValuesDto,
+    pub after: InfoAttributionValuesDto,
+    /// Rules that set the new values (`rule:<id>,...`).
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InfoAttributionPreviewDto {
+    /// Stored pods of the current runtime state that were evaluated
+    pub evaluated: usize,
+    /// Pods attributed manually, which rules never override
+    pub skipped_manual: usize,
+    pub changes: Vec<InfoAttributionChangeDto>,
+}
+
+/// A pod whose own team disagrees with its namespace's team.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct InfoAttributionConflictDto {
+    pub pod_uid: String,
+    pub pod_name: Option<String>,
+    pub namespace: Option<String>,
+    /// Team set on the pod by a patch or an attribution rule
+    pub pod_team: Option<String>,
+    pub namespace_team: Option<String>,
+    /// `manual` or `rule:<id>,...`, i.e. where to fix the pod side
+    pub attribution_source: Option<String>,
+    /// Team the pod's cost currently goes to under `team_precedence`
+    pub effective_team: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InfoAttributionConflictReportDto {
+    /// Current `team_precedence` setting
+    pub precedence: String,
+    /// Stored pods of the current runtime state that were checked
+    pub evaluated: usize,
+    pub conflicts: Vec<InfoAttributionConflictDto>,
+}
+
+fn validate_sets_something(req: &InfoAttributionRuleUpsertRequest) -> Result<(), ValidationError> {
+    if req.team.is_some() || req.service.is_some() || req.env.is_some() {
+        Ok(())
+    } else {
+        Err(ValidationError::new("attribution")
+            .with_message("rule must set at least one of team, service or env".into()))
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::core::persistence::info::fixed::carbon::info_carbon_entity::PowerProfileEntity;
+
+/// Partial update of the energy and carbon settings. `profiles`, when given,
+/// replaces the stored map.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_carbon"))]
+pub struct InfoCarbonUpsertRequest {
+    /// Grid carbon intensity in gCO2e per kWh.
+    #[validate(range(min = 0.0, max = 2000.0))]
+    pub grid_intensity_g_per_kwh: Option<f64>,
+
+    /// Power usage effectiveness; 1.0 means no facility overhead.
+    #[validate(range(min = 1.0, max = 3.0))]
+    pub pue: Option<f64>,
+
+    pub default_profile: Option<PowerProfileEntity>,
+
+    /// Profiles keyed by node instance type.
+    pub profiles: Option<BTreeMap<String, PowerProfileEntity>>,
+}
+
+fn validate_profile(name: &str, p: &PowerProfileEntity) -> Result<(), ValidationError> {
+    let valid = p.cpu_idle_watts >= 0.0 && p.cpu_max_watts >= p.cpu_idle_watts && p.memory_watts_per_gb >= 0.0;
+    if !valid {
+        return Err(ValidationError::new("carbon").with_message(
+            format!("profile '{}' needs non-negative watts and cpu_max_watts >= cpu_idle_watts", name).into(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_carbon(req: &InfoCarbonUpsertRequest) -> Result<(), ValidationError> {
+    if let Some(p) = &req.default_profile {
+        validate_profile("default", p)?;
+    }
+    for (name, p) in req.profiles.iter().flatten() {
+        if name.trim().is_empty() {
+            return Err(ValidationError::new("carbon").with_message("profile instance type is empty".into()));
+        }
+        validate_profile(name, p)?;
+    }
+    Ok(())
+}
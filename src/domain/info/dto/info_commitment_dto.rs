@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::core::persistence::info::fixed::commitment::commitment_entity::{CommitmentKind, CommitmentTerm};
+
+/// Body for creating or replacing a savings plan / reserved instance.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct InfoCommitmentUpsertRequest {
+    #[validate(length(min = 1, max = 128))]
+    pub name: String,
+    pub kind: CommitmentKind,
+    /// Spend committed per hour, in USD.
+    #[validate(range(exclusive_min = 0.0))]
+    pub hourly_commitment_usd: f64,
+    pub term: CommitmentTerm,
+    pub starts_at: DateTime<Utc>,
+    /// Instance families covered, e.g. `m5`; omit to cover every node.
+    pub instance_families: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+}
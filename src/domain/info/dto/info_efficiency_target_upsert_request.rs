@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+/// Partial update of the efficiency targets. `teams` and `namespaces`, when
+/// given, replace the stored maps.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_targets"))]
+pub struct InfoEfficiencyTargetUpsertRequest {
+    /// Target percentage of requested resources used.
+    #[validate(range(exclusive_min = 0.0, max = 100.0))]
+    pub default_target_percent: Option<f64>,
+
+    /// Targets keyed by team.
+    pub teams: Option<BTreeMap<String, f64>>,
+
+    /// Targets keyed by namespace.
+    pub namespaces: Option<BTreeMap<String, f64>>,
+}
+
+fn validate_targets(req: &InfoEfficiencyTargetUpsertRequest) -> Result<(), ValidationError> {
+    for (name, target) in req.teams.iter().flatten().chain(req.namespaces.iter().flatten()) {
+        if name.trim().is_empty() {
+            return Err(ValidationError::new("efficiency_target").with_message("target name is empty".into()));
+        }
+        if !(*target > 0.0 && *target <= 100.0) {
+            return Err(ValidationError::new("efficiency_target").with_message(
+                format!("target for '{}' must be in (0, 100]", name).into(),
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::core::persistence::info::fixed::fixed_cost::fixed_cost_entity::{
+    FixedCostAllocation, FixedCostPeriod,
+};
+
+/// Body for creating or replacing a fixed cost line item.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_fixed_cost"))]
+pub struct InfoFixedCostUpsertRequest {
+    #[validate(length(min = 1, max = 128))]
+    pub name: String,
+    #[validate(length(min = 1, max = 64))]
+    pub category: Option<String>,
+    /// Amount billed per `period`, in USD.
+    #[validate(range(min = 0.0))]
+    pub amount_usd: f64,
+    pub period: FixedCostPeriod,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Defaults to `Cluster` (not allocated to namespaces).
+    pub allocation: Option<FixedCostAllocation>,
+    /// Required with the `Namespaces` allocation.
+    pub namespaces: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+}
+
+fn validate_fixed_cost(req: &InfoFixedCostUpsertRequest) -> Result<(), ValidationError> {
+    if let (Some(start), Some(end)) = (req.starts_at, req.ends_at) {
+        if end <= start {
+            return Err(ValidationError::new("fixed_cost")
+                .with_message("ends_at must be after starts_at".into()));
+        }
+    }
+
+    let has_namespaces = req.namespaces.as_ref().is_some_and(|ns| !ns.is_empty());
+    if req.allocation == Some(FixedCostAllocation::Namespaces) && !has_namespaces {
+        return Err(ValidationError::new("fixed_cost")
+            .with_message("Namespaces allocation needs at least one namespace".into()));
+    }
+    Ok(())
+}
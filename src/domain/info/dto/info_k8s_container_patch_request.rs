@@ -1,8 +1,9 @@
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct InfoK8sContainerPatchRequest {
     // --- Team / Service metadata (NEW) ---
     pub team: Option<String>,
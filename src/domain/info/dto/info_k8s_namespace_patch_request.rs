@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct InfoK8sNamespacePatchRequest {
+    #[validate(length(max = 128))]
+    pub team: Option<String>,
+    #[validate(length(max = 128))]
+    pub service: Option<String>,
+    #[validate(length(max = 64))]
+    pub env: Option<String>, // "dev", "stage", "prod"
+}
@@ -1,9 +1,10 @@
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 use crate::core::persistence::info::k8s::node::info_node_entity::NodePricePeriod;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct InfoK8sNodePatchRequest {
     // --- Team / Service metadata (NEW) ---
     pub team: Option<String>,
@@ -11,7 +12,7 @@ pub struct InfoK8sNodePatchRequest {
     pub env: Option<String>, // "dev", "stage", "prod"
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct InfoK8sNodePricePatchRequest {
     /// Fixed price for this node in USD (instance / VM / bare metal)
     pub fixed_instance_usd: Option<f64>,
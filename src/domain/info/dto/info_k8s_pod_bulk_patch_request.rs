@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+/// Sets team/service/env on every stored pod matching the selector.
+/// All given selector fields must match; at least one is required.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_bulk_patch"))]
+pub struct InfoK8sPodBulkPatchRequest {
+    /// Namespace pattern with `*` wildcards
+    pub namespace: Option<String>,
+    /// Labels a pod must all carry
+    pub label_selector: Option<BTreeMap<String, String>>,
+    /// Owner or resolved workload kind (e.g. `Deployment`, `ReplicaSet`)
+    pub owner_kind: Option<String>,
+    /// Owner or resolved workload name, `*` wildcards allowed
+    pub owner_name: Option<String>,
+
+    #[validate(length(min = 1, max = 128))]
+    pub team: Option<String>,
+    #[validate(length(min = 1, max = 128))]
+    pub service: Option<String>,
+    #[validate(length(min = 1, max = 64))]
+    pub env: Option<String>,
+
+    /// Report the matching pods without writing
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InfoK8sPodBulkPatchResponseDto {
+    pub matched: usize,
+    pub updated: usize,
+    pub dry_run: bool,
+    pub pod_uids: Vec<String>,
+}
+
+fn validate_bulk_patch(req: &InfoK8sPodBulkPatchRequest) -> Result<(), ValidationError> {
+    let given = |v: &Option<String>| v.as_deref().is_some_and(|s| !s.trim().is_empty());
+    let has_selector = given(&req.namespace)
+        || req.label_selector.as_ref().is_some_and(|l| !l.is_empty())
+        || given(&req.owner_kind)
+        || given(&req.owner_name);
+    if !has_selector {
+        return Err(ValidationError::new("selector")
+            .with_message("give at least one of namespace, label_selector, owner_kind or owner_name".into()));
+    }
+
+    if req.team.is_none() && req.service.is_none() && req.env.is_none() {
+        return Err(ValidationError::new("values")
+            .with_message("give at least one of team, service or env".into()));
+    }
+    Ok(())
+}
@@ -1,11 +1,15 @@
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct InfoK8sPodPatchRequest {
     // --- Team / Service metadata (NEW) ---
     pub team: Option<String>,
     pub service: Option<String>,
     pub env: Option<String>, // "dev", "stage", "prod"
+
+    /// Hand the pod back to attribution rules; a patch otherwise pins its values.
+    pub use_rules: Option<bool>,
 }
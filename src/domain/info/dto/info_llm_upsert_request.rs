@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 use crate::core::persistence::info::fixed::llm::llm_provider::LlmProvider;
 
 /// Upsert payload for LLM configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct InfoLlmUpsertRequest {
     pub provider: Option<LlmProvider>,
     #[validate(url)]
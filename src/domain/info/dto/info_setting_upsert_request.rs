@@ -1,9 +1,16 @@
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::core::persistence::info::fixed::attribution::team_precedence::TeamPrecedence;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::is_valid_scrape_interval;
+use crate::domain::export::export_format::ExportFormat;
+use crate::domain::metric::k8s::common::util::k8s_efficiency_weights::EfficiencyWeights;
+use crate::scheduler::tasks::collectors::k8s::source::MetricsSourceKind;
 
 /// Represents an upsert (create/update) request for InfoSettingEntity.
 /// All fields are optional to allow partial updates.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct InfoSettingUpsertRequest {
     // ===== General & UI =====
     /// Enables dark mode for the RustCost web UI.
@@ -13,13 +20,13 @@ pub struct InfoSettingUpsertRequest {
     #[validate(length(min = 2, max = 10))]
     pub language: Option<String>,
 
-    /// Number of days to retain minute-level metric data.
+    /// Number of days to retain minute-level metric data (0 = keep forever).
     pub minute_retention_days: Option<u32>,
 
-    /// Number of months to retain hour-level metric data.
+    /// Number of months to retain hour-level metric data (0 = keep forever).
     pub hour_retention_months: Option<u32>,
 
-    /// Number of years to retain day-level metric data.
+    /// Number of years to retain day-level metric data (0 = keep forever).
     pub day_retention_years: Option<u32>,
 
     /// Retention behavior: "delete" or "archive".
@@ -39,13 +46,91 @@ pub struct InfoSettingUpsertRequest {
     /// Enables on-disk compression (gzip or zstd).
     pub compression_enabled: Option<bool>,
 
+    /// Metric storage backend: "fs", "sqlite" or "influx" (applied on restart).
+    #[validate(length(min = 2))]
+    pub storage_backend: Option<String>,
+
+    /// Base URL of the InfluxDB-compatible TSDB for the "influx" backend.
+    #[validate(url)]
+    pub remote_store_url: Option<String>,
+
+    /// Database (bucket) written by the "influx" backend.
+    #[validate(length(min = 1))]
+    pub remote_store_database: Option<String>,
+
+    /// API token for the remote TSDB.
+    pub remote_store_token: Option<String>,
+
     // ===== Metrics Collection =====
-    /// Scrape interval in seconds (e.g. 60 = every minute).
+    /// Scrape interval in seconds (e.g. 30, 60, 300); must divide 3600.
+    #[validate(custom(function = "validate_scrape_interval"))]
     pub scrape_interval_sec: Option<u32>,
 
+    /// Maximum per-node scrape delay in seconds (0 = no jitter).
+    pub scrape_jitter_sec: Option<u32>,
+
+    /// Maximum number of nodes scraped concurrently.
+    #[validate(range(min = 1, max = 256))]
+    pub scrape_concurrency: Option<u32>,
+
+    /// Per-attempt kubelet summary timeout in seconds.
+    #[validate(range(min = 1, max = 3600))]
+    pub scrape_timeout_sec: Option<u32>,
+
+    /// Retries per node after a failed summary call.
+    #[validate(range(max = 10))]
+    pub scrape_retries: Option<u32>,
+
+    /// Usage source: "kubelet", "metrics-server" or "prometheus".
+    #[validate(custom(function = "validate_metrics_source"))]
+    pub metrics_source: Option<String>,
+
+    /// Prometheus base URL for the "prometheus" metrics source.
+    #[validate(url)]
+    pub metrics_source_url: Option<String>,
+
     /// Number of metrics batched together when written to disk.
     pub metrics_batch_size: Option<u32>,
 
+    // ===== Cost Attribution =====
+    /// Node label holding the cloud account.
+    #[validate(length(min = 1, max = 316))]
+    pub cloud_account_label: Option<String>,
+
+    /// Node label holding the cloud project.
+    #[validate(length(min = 1, max = 316))]
+    pub cloud_project_label: Option<String>,
+
+    /// Fallback account for nodes without the account label (empty clears it).
+    pub cloud_account: Option<String>,
+
+    /// Team of pods whose namespace team disagrees: "pod" or "namespace".
+    #[validate(custom(function = "validate_team_precedence"))]
+    pub team_precedence: Option<String>,
+
+    // ===== Cost Export =====
+    /// Daily export target: s3://, gs://, http(s):// or file:// (empty disables it).
+    pub export_sink_url: Option<String>,
+
+    /// Export file format: "parquet", "csv" or "ndjson".
+    #[validate(custom(function = "validate_export_format"))]
+    pub export_format: Option<String>,
+
+    /// Bearer token for GCS / HTTP export sinks (empty clears it).
+    pub export_token: Option<String>,
+
+    // ===== Network Flows =====
+    /// Prometheus API with per-destination egress counters (empty disables flows).
+    pub network_flow_url: Option<String>,
+
+    /// PromQL override for the hourly flow query (empty restores the default).
+    pub network_flow_query: Option<String>,
+
+    // ===== Efficiency =====
+    /// Overall efficiency weights: "cost", "equal" or e.g. "cpu=2,memory=1,storage=0".
+    #[validate(custom(function = "validate_efficiency_weights"))]
+    pub efficiency_weights: Option<String>,
+
     // ===== LLM Integration =====
     /// Endpoint for an external LLM API (e.g., OpenAI, Anthropic).
     #[validate(url)]
@@ -83,3 +168,46 @@ pub struct InfoSettingUpsertRequest {
     #[validate(url)]
     pub k8s_api_url: Option<String>,
 }
+
+fn validate_scrape_interval(v: u32) -> Result<(), ValidationError> {
+    if is_valid_scrape_interval(v) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("scrape_interval_sec")
+            .with_message("must be between 10 and 3600 seconds and divide an hour evenly".into()))
+    }
+}
+
+fn validate_metrics_source(v: &str) -> Result<(), ValidationError> {
+    if MetricsSourceKind::parse(v).is_some() {
+        Ok(())
+    } else {
+        Err(ValidationError::new("metrics_source")
+            .with_message("must be kubelet, metrics-server or prometheus".into()))
+    }
+}
+
+fn validate_export_format(v: &str) -> Result<(), ValidationError> {
+    if ExportFormat::parse(v).is_some() {
+        Ok(())
+    } else {
+        Err(ValidationError::new("export_format").with_message("must be parquet, csv or ndjson".into()))
+    }
+}
+
+fn validate_team_precedence(v: &str) -> Result<(), ValidationError> {
+    if TeamPrecedence::parse(v).is_some() {
+        Ok(())
+    } else {
+        Err(ValidationError::new("team_precedence").with_message("must be pod or namespace".into()))
+    }
+}
+
+fn validate_efficiency_weights(v: &str) -> Result<(), ValidationError> {
+    if EfficiencyWeights::parse(v).is_some() {
+        Ok(())
+    } else {
+        Err(ValidationError::new("efficiency_weights")
+            .with_message("must be cost, equal or non-negative cpu=,memory=,storage= weights".into()))
+    }
+}
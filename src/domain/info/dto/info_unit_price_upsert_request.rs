@@ -1,11 +1,13 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// Represents an upsert (create/update) request for `InfoUnitPriceEntity`.
 ///
 /// All fields are optional to allow partial updates.
 /// Each value represents the price per *unit* of resource usage (usually per hour).
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct InfoUnitPriceUpsertRequest {
     // --- CPU ---
     /// Price per CPU core-hour.
@@ -41,4 +43,12 @@ pub struct InfoUnitPriceUpsertRequest {
 
     /// Price per GB transferred to external networks (internet egress).
     pub network_external_gb: Option<f64>,
+
+    // --- Load balancers ---
+    /// Price per hour of each `LoadBalancer` Service.
+    pub load_balancer_hour: Option<f64>,
+
+    /// When these prices take effect; defaults to now. Points before it keep the
+    /// prices that were in effect then.
+    pub effective_from: Option<DateTime<Utc>>,
 }
@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_entity::VirtualNodeRate;
+
+/// Partial update of the virtual node rates. `providers`, when given, replaces
+/// the stored map.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_rates"))]
+pub struct InfoVirtualNodePriceUpsertRequest {
+    /// Rates for providers without their own entry.
+    pub default_rate: Option<VirtualNodeRate>,
+
+    /// Rates keyed by provider (`fargate`, `aci`, ...).
+    pub providers: Option<BTreeMap<String, VirtualNodeRate>>,
+}
+
+fn validate_rates(req: &InfoVirtualNodePriceUpsertRequest) -> Result<(), ValidationError> {
+    let named = req.providers.iter().flatten().map(|(name, rate)| (name.as_str(), rate));
+    for (name, rate) in req.default_rate.iter().map(|r| ("default", r)).chain(named) {
+        if name.trim().is_empty() {
+            return Err(ValidationError::new("virtual_node_price").with_message("provider name is empty".into()));
+        }
+        let valid = |v: f64| v.is_finite() && v >= 0.0;
+        if !valid(rate.vcpu_hour) || !valid(rate.memory_gb_hour) {
+            return Err(ValidationError::new("virtual_node_price").with_message(
+                format!("rates for '{}' must be non-negative", name).into(),
+            ));
+        }
+    }
+    Ok(())
+}
@@ -6,7 +6,15 @@ pub mod info_llm_upsert_request;
 pub mod info_unit_price_upsert_request;
 pub mod info_k8s_container_patch_request;
 pub mod info_k8s_pod_patch_request;
+pub mod info_k8s_pod_bulk_patch_request;
 pub mod info_k8s_node_patch_request;
+pub mod info_k8s_namespace_patch_request;
+pub mod info_attribution_rule_dto;
+pub mod info_fixed_cost_dto;
+pub mod info_carbon_upsert_request;
+pub mod info_efficiency_target_upsert_request;
+pub mod info_commitment_dto;
+pub mod info_virtual_node_price_upsert_request;
 
 use serde::{Deserialize, Serialize};
 
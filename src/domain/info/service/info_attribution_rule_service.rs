@@ -0,0 +1,355 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tracing::warn;
+use validator::Validate;
+
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::attribution::attribution_rule_entity::{
+    AttributionRuleEntity, InfoAttributionRuleEntity,
+};
+use crate::core::persistence::info::fixed::attribution::info_attribution_rule_api_repository_trait::InfoAttributionRuleApiRepository;
+use crate::core::persistence::info::fixed::attribution::info_attribution_rule_repository::InfoAttributionRuleRepository;
+use crate::core::persistence::info::fixed::attribution::team_precedence::{teams_conflict, NamespaceTeams};
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
+use crate::domain::info::dto::info_attribution_rule_dto::{
+    InfoAttributionChangeDto, InfoAttributionConflictDto, InfoAttributionConflictReportDto,
+    InfoAttributionPreviewDto, InfoAttributionPreviewRequest, InfoAttributionRuleUpsertRequest,
+    InfoAttributionValuesDto,
+};
+
+/// `attribution_source` of pods patched by hand.
+pub const MANUAL_ATTRIBUTION: &str = "manual";
+
+/// Id used for the unsaved rule of a preview.
+const PREVIEW_RULE_ID: &str = "preview";
+
+pub async fn list_attribution_rules() -> Result<InfoAttributionRuleEntity> {
+    InfoAttributionRuleRepository::new().read()
+}
+
+pub async fn create_attribution_rule(
+    req: InfoAttributionRuleUpsertRequest,
+) -> Result<AttributionRuleEntity> {
+    req.validate()?;
+    let repo = InfoAttributionRuleRepository::new();
+    let mut rules = repo.read()?;
+
+    let now = Utc::now();
+    let mut id = new_rule_id(now);
+    while rules.find(&id).is_some() {
+        id = new_rule_id(Utc::now());
+    }
+
+    let rule = build_rule(id, req, now);
+    rules.rules.push(rule.clone());
+    rules.updated_at = Some(now);
+    repo.update(&rules)?;
+    Ok(rule)
+}
+
+pub async fn update_attribution_rule(
+    id: String,
+    req: InfoAttributionRuleUpsertRequest,
+) -> Result<AttributionRuleEntity> {
+    req.validate()?;
+    let repo = InfoAttributionRuleRepository::new();
+    let mut rules = repo.read()?;
+
+    let now = Utc::now();
+    let slot = rules
+        .rules
+        .iter_mut()
+        .find(|r| r.id == id)
+        .ok_or_else(|| anyhow!("Attribution rule '{}' not found", id))?;
+    *slot = build_rule(id, req, now);
+    let rule = slot.clone();
+
+    rules.updated_at = Some(now);
+    repo.update(&rules)?;
+    Ok(rule)
+}
+
+/// Pods keep the values the rule gave them until another rule or a patch
+/// changes them.
+pub async fn delete_attribution_rule(id: String) -> Result<Value> {
+    let repo = InfoAttributionRuleRepository::new();
+    let mut rules = repo.read()?;
+
+    let before = rules.rules.len();
+    rules.rules.retain(|r| r.id != id);
+    if rules.rules.len() == before {
+        return Err(anyhow!("Attribution rule '{}' not found", id));
+    }
+
+    rules.updated_at = Some(Utc::now());
+    repo.update(&rules)?;
+    Ok(serde_json::json!({ "deleted": id }))
+}
+
+/// What the rule set (with the requested change) would do to the stored pods of
+/// the current runtime state. Nothing is written.
+pub async fn preview_attribution_rules(
+    state: AppState,
+    req: InfoAttributionPreviewRequest,
+) -> Result<InfoAttributionPreviewDto> {
+    req.validate()?;
+    let mut rules = InfoAttributionRuleRepository::new().read()?;
+
+    let now = Utc::now();
+    match (req.rule_id, req.rule) {
+        (Some(id), rule) => {
+            let idx = rules
+                .rules
+                .iter()
+                .position(|r| r.id == id)
+                .ok_or_else(|| anyhow!("Attribution rule '{}' not found", id))?;
+            match rule {
+                Some(rule) => rules.rules[idx] = build_rule(id, rule, now),
+                None => {
+                    rules.rules.remove(idx);
+                }
+            }
+        }
+        (None, Some(rule)) => rules.rules.push(build_rule(PREVIEW_RULE_ID.to_string(), rule, now)),
+        (None, None) => {}
+    }
+
+    let uids = state.k8s_state.get_pods().await;
+    let pod_repo = InfoPodRepository::new();
+
+    let mut evaluated = 0;
+    let mut skipped_manual = 0;
+    let mut changes = Vec::new();
+
+    for uid in uids {
+        let Ok(pod) = pod_repo.read(&uid) else {
+            continue;
+        };
+        evaluated += 1;
+
+        if is_manual(&pod) {
+            skipped_manual += 1;
+            continue;
+        }
+
+        let mut after = pod.clone();
+        if !apply_attribution_rules(&rules, &mut after) {
+            continue;
+        }
+
+        changes.push(InfoAttributionChangeDto {
+            pod_uid: uid,
+            pod_name: pod.pod_name.clone(),
+            namespace: pod.namespace.clone(),
+            before: values_of(&pod),
+            after: values_of(&after),
+            source: after.attribution_source.clone(),
+        });
+    }
+
+    changes.sort_by(|a, b| (&a.namespace, &a.pod_name).cmp(&(&b.namespace, &b.pod_name)));
+
+    Ok(InfoAttributionPreviewDto {
+        evaluated,
+        skipped_manual,
+        changes,
+    })
+}
+
+/// Pods of the current runtime state whose team disagrees with their
+/// namespace's team, with the team `team_precedence` currently picks.
+pub async fn get_attribution_conflicts(state: AppState) -> Result<InfoAttributionConflictReportDto> {
+    let uids = state.k8s_state.get_pods().await;
+    let pod_repo = InfoPodRepository::new();
+    let pods: Vec<InfoPodEntity> = uids.iter().filter_map(|uid| pod_repo.read(uid).ok()).collect();
+
+    let teams = NamespaceTeams::load();
+    Ok(InfoAttributionConflictReportDto {
+        precedence: teams.precedence.as_str().to_string(),
+        evaluated: pods.len(),
+        conflicts: find_team_conflicts(&pods, &teams),
+    })
+}
+
+fn find_team_conflicts(pods: &[InfoPodEntity], teams: &NamespaceTeams) -> Vec<InfoAttributionConflictDto> {
+    let mut conflicts: Vec<InfoAttributionConflictDto> = pods
+        .iter()
+        .filter_map(|pod| {
+            let namespace_team = teams.team_of(pod.namespace.as_deref());
+            if !teams_conflict(pod.team.as_deref(), namespace_team) {
+                return None;
+            }
+            Some(InfoAttributionConflictDto {
+                pod_uid: pod.pod_uid.clone().unwrap_or_default(),
+                pod_name: pod.pod_name.clone(),
+                namespace: pod.namespace.clone(),
+                pod_team: pod.team.clone(),
+                namespace_team: namespace_team.map(str::to_string),
+                attribution_source: pod.attribution_source.clone(),
+                effective_team: teams.precedence.effective_team(pod.team.as_deref(), namespace_team),
+            })
+        })
+        .collect();
+    conflicts.sort_by(|a, b| (&a.namespace, &a.pod_name).cmp(&(&b.namespace, &b.pod_name)));
+    conflicts
+}
+
+/// Sets team/service/env from `rules`, leaving fields no rule sets untouched.
+/// Manually attributed pods are skipped. Returns whether anything changed.
+pub fn apply_attribution_rules(rules: &InfoAttributionRuleEntity, pod: &mut InfoPodEntity) -> bool {
+    if is_manual(pod) {
+        return false;
+    }
+
+    let namespace = pod.namespace.as_deref().unwrap_or_default();
+    let resolved = rules.resolve(namespace, pod.label.as_deref());
+    if resolved.is_empty() {
+        return false;
+    }
+
+    let before = values_of(pod);
+    let mut rule_ids: Vec<String> = Vec::new();
+    for (slot, value) in [
+        (&mut pod.team, resolved.team),
+        (&mut pod.service, resolved.service),
+        (&mut pod.env, resolved.env),
+    ] {
+        if let Some((value, rule_id)) = value {
+            *slot = Some(value);
+            if !rule_ids.contains(&rule_id) {
+                rule_ids.push(rule_id);
+            }
+        }
+    }
+
+    let source = format!("rule:{}", rule_ids.join(","));
+    let changed = before != values_of(pod) || pod.attribution_source.as_deref() != Some(source.as_str());
+    pod.attribution_source = Some(source);
+    changed
+}
+
+/// [`apply_attribution_rules`] with the stored rule set, for pod info sync.
+/// A broken rules file must not stop the sync, so errors are only logged.
+pub(crate) fn apply_stored_attribution_rules(pod: &mut InfoPodEntity) {
+    match InfoAttributionRuleRepository::new().read() {
+        Ok(rules) => {
+            apply_attribution_rules(&rules, pod);
+        }
+        Err(e) => warn!(?e, "failed to read attribution rules"),
+    }
+}
+
+fn is_manual(pod: &InfoPodEntity) -> bool {
+    pod.attribution_source.as_deref() == Some(MANUAL_ATTRIBUTION)
+}
+
+fn values_of(pod: &InfoPodEntity) -> InfoAttributionValuesDto {
+    InfoAttributionValuesDto {
+        team: pod.team.clone(),
+        service: pod.service.clone(),
+        env: pod.env.clone(),
+    }
+}
+
+fn new_rule_id(now: DateTime<Utc>) -> String {
+    let nanos = now.timestamp_nanos_opt().unwrap_or_else(|| now.timestamp_micros());
+    format!("rule-{:x}", nanos)
+}
+
+fn build_rule(id: String, req: InfoAttributionRuleUpsertRequest, now: DateTime<Utc>) -> AttributionRuleEntity {
+    AttributionRuleEntity {
+        id,
+        name: req.name,
+        priority: req.priority.unwrap_or(0),
+        enabled: req.enabled.unwrap_or(true),
+        namespace_selector: req.namespace_selector.filter(|s| !s.trim().is_empty()),
+        label_selector: req.label_selector.unwrap_or_default(),
+        team: req.team,
+        service: req.service,
+        env: req.env,
+        updated_at: now,
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::core::persistence::info::fixed::attribution::team_precedence::TeamPrecedence;
+
+    #[test]
+    fn rules_fill_pods_but_never_override_manual_attribution() {
+        let rules = InfoAttributionRuleEntity {
+            rules: vec![build_rule(
+                "r1".into(),
+                InfoAttributionRuleUpsertRequest {
+                    name: "payments".into(),
+                    priority: None,
+                    enabled: None,
+                    namespace_selector: Some("pay-*".into()),
+                    label_selector: None,
+                    team: Some("payments".into()),
+                    service: None,
+                    env: Some("prod".into()),
+                },
+                Utc::now(),
+            )],
+            updated_at: None,
+        };
+
+        let mut pod = InfoPodEntity {
+            namespace: Some("pay-api".into()),
+            service: Some("checkout".into()),
+            ..Default::default()
+        };
+        assert!(apply_attribution_rules(&rules, &mut pod));
+        assert_eq!(pod.team.as_deref(), Some("payments"));
+        assert_eq!(pod.service.as_deref(), Some("checkout"));
+        assert_eq!(pod.attribution_source.as_deref(), Some("rule:r1"));
+        assert!(!apply_attribution_rules(&rules, &mut pod));
+
+        let mut manual = InfoPodEntity {
+            namespace: Some("pay-api".into()),
+            team: Some("core".into()),
+            attribution_source: Some(MANUAL_ATTRIBUTION.into()),
+            ..Default::default()
+        };
+        assert!(!apply_attribution_rules(&rules, &mut manual));
+        assert_eq!(manual.team.as_deref(), Some("core"));
+    }
+
+    #[test]
+    fn conflicts_list_pods_disagreeing_with_their_namespace() {
+        let teams = NamespaceTeams::new(
+            TeamPrecedence::Namespace,
+            HashMap::from([("pay-api".to_string(), "payments".to_string())]),
+        );
+        let pod = |uid: &str, ns: &str, team: Option<&str>| InfoPodEntity {
+            pod_uid: Some(uid.into()),
+            pod_name: Some(uid.into()),
+            namespace: Some(ns.into()),
+            team: team.map(Into::into),
+            attribution_source: Some(MANUAL_ATTRIBUTION.into()),
+            ..Default::default()
+        };
+        let pods = vec![
+            pod("agrees", "pay-api", Some("payments")),
+            pod("unset", "pay-api", None),
+            pod("other-ns", "search", Some("core")),
+            pod("disagrees", "pay-api", Some("core")),
+        ];
+
+        let conflicts = find_team_conflicts(&pods, &teams);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].pod_uid, "disagrees");
+        assert_eq!(conflicts[0].pod_team.as_deref(), Some("core"));
+        assert_eq!(conflicts[0].namespace_team.as_deref(), Some("payments"));
+        assert_eq!(conflicts[0].effective_team.as_deref(), Some("payments"));
+    }
+}
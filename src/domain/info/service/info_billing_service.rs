@@ -0,0 +1,263 @@
+use anyhow::{anyhow, Result};
+use chrono::{NaiveDate, Utc};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use tracing::info;
+
+use crate::api::dto::info_dto::{BillingImportQuery, BillingRangeQuery};
+use crate::core::persistence::info::billing::info_billing_day_entity::{
+    InfoBillingDayEntity, InfoBillingItemEntity,
+};
+use crate::core::persistence::info::billing::info_billing_repository::{
+    InfoBillingRepository, InfoBillingRepositoryImpl,
+};
+
+/// Imports a billing export slice. Every day present in the file replaces the
+/// stored day, so re-importing an updated CUR slice is safe.
+pub async fn import_billing(q: BillingImportQuery, body: String) -> Result<Value> {
+    let source = BillingSource::parse(&q.source)?;
+    let parsed = parse_billing_csv(source, &body)?;
+
+    let repo = InfoBillingRepositoryImpl::new();
+    let now = Utc::now();
+    let mut total = 0.0;
+    for (date, day) in &parsed.days {
+        let items: Vec<InfoBillingItemEntity> = day
+            .costs
+            .iter()
+            .map(|((service, account), cost)| InfoBillingItemEntity {
+                service: service.clone(),
+                account: account.clone(),
+                cost: *cost,
+            })
+            .collect();
+        total += items.iter().map(|i| i.cost).sum::<f64>();
+
+        repo.write(&InfoBillingDayEntity {
+            date: *date,
+            source: source.code().to_string(),
+            currency: day.currency.clone(),
+            imported_at: now,
+            items,
+        })?;
+    }
+
+    info!(source = source.code(), rows = parsed.rows, days = parsed.days.len(), "billing_imported");
+    Ok(json!({
+        "source": source.code(),
+        "rows": parsed.rows,
+        "days": parsed.days.keys().collect::<Vec<_>>(),
+        "total_cost": total,
+    }))
+}
+
+pub async fn list_billing(q: BillingRangeQuery) -> Result<Vec<InfoBillingDayEntity>> {
+    if q.end < q.start {
+        return Err(anyhow!("end must not be before start"));
+    }
+    InfoBillingRepositoryImpl::new().list_between(q.start, q.end)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BillingSource {
+    AwsCur,
+    Gcp,
+}
+
+impl BillingSource {
+    fn parse(v: &str) -> Result<Self> {
+        match v.trim().to_lowercase().as_str() {
+            "aws_cur" | "aws" | "cur" => Ok(Self::AwsCur),
+            "gcp" | "gcp_billing" => Ok(Self::Gcp),
+            other => Err(anyhow!("unsupported billing source '{}' (expected aws_cur or gcp)", other)),
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Self::AwsCur => "aws_cur",
+            Self::Gcp => "gcp",
+        }
+    }
+
+    /// Normalized header of each field: usage date, cost, service, account, currency.
+    /// CUR 1 (`lineItem/UsageStartDate`) and CUR 2.0 (`line_item_usage_start_date`)
+    /// normalize to the same name.
+    fn columns(&self) -> [&'static str; 5] {
+        match self {
+            Self::AwsCur => [
+                "line_item_usage_start_date",
+                "line_item_unblended_cost",
+                "line_item_product_code",
+                "line_item_usage_account_id",
+                "line_item_currency_code",
+            ],
+            Self::Gcp => ["usage_start_time", "cost", "service_description", "project_id", "currency"],
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ParsedBillingDay {
+    currency: String,
+    costs: BTreeMap<(String, String), f64>,
+}
+
+#[derive(Debug, Default)]
+struct ParsedBilling {
+    rows: usize,
+    days: BTreeMap<NaiveDate, ParsedBillingDay>,
+}
+
+fn parse_billing_csv(source: BillingSource, text: &str) -> Result<ParsedBilling> {
+    let mut rows = parse_csv(text).into_iter();
+    let header: Vec<String> = rows
+        .next()
+        .ok_or_else(|| anyhow!("billing file is empty"))?
+        .iter()
+        .map(|h| normalize_header(h))
+        .collect();
+
+    let [date_col, cost_col, service_col, account_col, currency_col] = source
+        .columns()
+        .map(|name| header.iter().position(|h| h == name));
+    let date_col = date_col.ok_or_else(|| anyhow!("{} column missing", source.columns()[0]))?;
+    let cost_col = cost_col.ok_or_else(|| anyhow!("{} column missing", source.columns()[1]))?;
+
+    let field = |row: &[String], col: Option<usize>| -> String {
+        col.and_then(|c| row.get(c)).map(|v| v.trim().to_string()).unwrap_or_default()
+    };
+
+    let mut parsed = ParsedBilling::default();
+    for row in rows {
+        // Usage dates start with `YYYY-MM-DD` in both exports
+        let Some(date) = row
+            .get(date_col)
+            .and_then(|v| v.get(..10))
+            .and_then(|v| NaiveDate::parse_from_str(v, "%Y-%m-%d").ok())
+        else {
+            continue;
+        };
+        let Some(cost) = row.get(cost_col).and_then(|v| v.trim().parse::<f64>().ok()) else {
+            continue;
+        };
+
+        let mut service = field(&row, service_col);
+        if service.is_empty() {
+            service = "unknown".to_string();
+        }
+        let account = field(&row, account_col);
+        let currency = field(&row, currency_col);
+
+        let day = parsed.days.entry(date).or_default();
+        if day.currency.is_empty() {
+            day.currency = if currency.is_empty() { "USD".to_string() } else { currency };
+        }
+        *day.costs.entry((service, account)).or_insert(0.0) += cost;
+        parsed.rows += 1;
+    }
+
+    Ok(parsed)
+}
+
+/// `lineItem/UsageStartDate` → `line_item_usage_start_date`, `service.description` → `service_description`.
+fn normalize_header(h: &str) -> String {
+    let mut out = String::with_capacity(h.len() + 4);
+    let mut prev_lower = false;
+    for c in h.trim().chars() {
+        if c.is_ascii_uppercase() {
+            if prev_lower {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+            prev_lower = false;
+        } else if c == '/' || c == '.' || c == ' ' {
+            out.push('_');
+            prev_lower = false;
+        } else {
+            out.push(c);
+            prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        }
+    }
+    out
+}
+
+/// RFC 4180 rows; quoted fields may hold commas, doubled quotes and line breaks.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_cur_rows_per_day_service_and_account() {
+        let csv = "identity/LineItemId,lineItem/UsageAccountId,lineItem/UsageStartDate,lineItem/ProductCode,lineItem/UnblendedCost,lineItem/CurrencyCode\r\n\
+                   a,111,2025-03-01T00:00:00Z,AmazonEC2,1.25,USD\r\n\
+                   b,111,2025-03-01T05:00:00Z,AmazonEC2,0.75,USD\r\n\
+                   c,111,2025-03-01T05:00:00Z,AmazonEBS,0.5,USD\r\n\
+                   d,111,2025-03-02T00:00:00Z,AmazonEC2,2,USD\r\n";
+
+        let parsed = parse_billing_csv(BillingSource::AwsCur, csv).unwrap();
+        assert_eq!(parsed.rows, 4);
+
+        let day = &parsed.days[&NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()];
+        assert_eq!(day.currency, "USD");
+        assert_eq!(day.costs[&("AmazonEC2".to_string(), "111".to_string())], 2.0);
+        assert_eq!(day.costs[&("AmazonEBS".to_string(), "111".to_string())], 0.5);
+        assert_eq!(parsed.days.len(), 2);
+    }
+
+    #[test]
+    fn reads_quoted_gcp_export_columns() {
+        let csv = "billing_account_id,service.description,project.id,usage_start_time,cost,currency,labels\n\
+                   X,Compute Engine,prod-1,2025-03-01 00:00:00 UTC,3.5,EUR,\"[{\"\"key\"\":\"\"a,b\"\"}]\"\n";
+
+        let parsed = parse_billing_csv(BillingSource::Gcp, csv).unwrap();
+        let day = &parsed.days[&NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()];
+        assert_eq!(day.currency, "EUR");
+        assert_eq!(day.costs[&("Compute Engine".to_string(), "prod-1".to_string())], 3.5);
+    }
+
+    #[test]
+    fn rejects_files_without_cost_column() {
+        assert!(parse_billing_csv(BillingSource::Gcp, "usage_start_time,amount\n2025-03-01,1\n").is_err());
+    }
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use serde_json::Value;
+use validator::Validate;
+
+use crate::core::persistence::info::fixed::carbon::info_carbon_api_repository_trait::InfoCarbonApiRepository;
+use crate::core::persistence::info::fixed::carbon::info_carbon_entity::InfoCarbonEntity;
+use crate::core::persistence::info::fixed::carbon::info_carbon_repository::InfoCarbonRepository;
+use crate::domain::info::dto::info_carbon_upsert_request::InfoCarbonUpsertRequest;
+
+pub async fn get_info_carbon() -> Result<InfoCarbonEntity> {
+    InfoCarbonRepository::new().read()
+}
+
+pub async fn upsert_info_carbon(req: InfoCarbonUpsertRequest) -> Result<Value> {
+    req.validate()?;
+    let repo = InfoCarbonRepository::new();
+    let mut carbon = repo.read()?;
+    carbon.apply_update(req);
+
+    repo.update(&carbon)?;
+
+    Ok(serde_json::json!({
+        "message": "Carbon settings updated successfully",
+        "updated_at": carbon.updated_at.to_rfc3339(),
+    }))
+}
@@ -0,0 +1,94 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use validator::Validate;
+
+use crate::core::persistence::info::fixed::commitment::commitment_entity::{CommitmentEntity, InfoCommitmentEntity};
+use crate::core::persistence::info::fixed::commitment::info_commitment_api_repository_trait::InfoCommitmentApiRepository;
+use crate::core::persistence::info::fixed::commitment::info_commitment_repository::InfoCommitmentRepository;
+use crate::domain::info::dto::info_commitment_dto::InfoCommitmentUpsertRequest;
+
+pub async fn list_commitments() -> Result<InfoCommitmentEntity> {
+    InfoCommitmentRepository::new().read()
+}
+
+pub async fn create_commitment(req: InfoCommitmentUpsertRequest) -> Result<CommitmentEntity> {
+    req.validate()?;
+    let repo = InfoCommitmentRepository::new();
+    let mut commitments = repo.read()?;
+
+    let now = Utc::now();
+    let mut id = new_commitment_id(now);
+    while commitments.find(&id).is_some() {
+        id = new_commitment_id(Utc::now());
+    }
+
+    let commitment = build_commitment(id, req, now);
+    commitments.items.push(commitment.clone());
+    commitments.updated_at = Some(now);
+    repo.update(&commitments)?;
+    Ok(commitment)
+}
+
+pub async fn update_commitment(id: String, req: InfoCommitmentUpsertRequest) -> Result<CommitmentEntity> {
+    req.validate()?;
+    let repo = InfoCommitmentRepository::new();
+    let mut commitments = repo.read()?;
+
+    let now = Utc::now();
+    let slot = commitments
+        .items
+        .iter_mut()
+        .find(|c| c.id == id)
+        .ok_or_else(|| anyhow!("Commitment '{}' not found", id))?;
+    *slot = build_commitment(id, req, now);
+    let commitment = slot.clone();
+
+    commitments.updated_at = Some(now);
+    repo.update(&commitments)?;
+    Ok(commitment)
+}
+
+pub async fn delete_commitment(id: String) -> Result<Value> {
+    let repo = InfoCommitmentRepository::new();
+    let mut commitments = repo.read()?;
+
+    let before = commitments.items.len();
+    commitments.items.retain(|c| c.id != id);
+    if commitments.items.len() == before {
+        return Err(anyhow!("Commitment '{}' not found", id));
+    }
+
+    commitments.updated_at = Some(Utc::now());
+    repo.update(&commitments)?;
+    Ok(serde_json::json!({ "deleted": id }))
+}
+
+fn new_commitment_id(now: DateTime<Utc>) -> String {
+    let nanos = now.timestamp_nanos_opt().unwrap_or_else(|| now.timestamp_micros());
+    format!("cm-{:x}", nanos)
+}
+
+fn build_commitment(id: String, req: InfoCommitmentUpsertRequest, now: DateTime<Utc>) -> CommitmentEntity {
+    let mut instance_families: Vec<String> = req
+        .instance_families
+        .unwrap_or_default()
+        .into_iter()
+        .map(|f| f.trim().to_ascii_lowercase())
+        .filter(|f| !f.is_empty())
+        .collect();
+    instance_families.sort();
+    instance_families.dedup();
+
+    CommitmentEntity {
+        id,
+        name: req.name,
+        kind: req.kind,
+        hourly_commitment_usd: req.hourly_commitment_usd,
+        term: req.term,
+        starts_at: req.starts_at,
+        instance_families,
+        enabled: req.enabled.unwrap_or(true),
+        updated_at: now,
+    }
+}
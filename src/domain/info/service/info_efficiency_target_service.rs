@@ -0,0 +1,26 @@
+use anyhow::Result;
+use serde_json::Value;
+use validator::Validate;
+
+use crate::core::persistence::info::fixed::efficiency_target::info_efficiency_target_api_repository_trait::InfoEfficiencyTargetApiRepository;
+use crate::core::persistence::info::fixed::efficiency_target::info_efficiency_target_entity::InfoEfficiencyTargetEntity;
+use crate::core::persistence::info::fixed::efficiency_target::info_efficiency_target_repository::InfoEfficiencyTargetRepository;
+use crate::domain::info::dto::info_efficiency_target_upsert_request::InfoEfficiencyTargetUpsertRequest;
+
+pub async fn get_info_efficiency_targets() -> Result<InfoEfficiencyTargetEntity> {
+    InfoEfficiencyTargetRepository::new().read()
+}
+
+pub async fn upsert_info_efficiency_targets(req: InfoEfficiencyTargetUpsertRequest) -> Result<Value> {
+    req.validate()?;
+    let repo = InfoEfficiencyTargetRepository::new();
+    let mut targets = repo.read()?;
+    targets.apply_update(req);
+
+    repo.update(&targets)?;
+
+    Ok(serde_json::json!({
+        "message": "Efficiency targets updated successfully",
+        "updated_at": targets.updated_at.to_rfc3339(),
+    }))
+}
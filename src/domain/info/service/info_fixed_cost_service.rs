@@ -0,0 +1,103 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use validator::Validate;
+
+use crate::core::persistence::info::fixed::fixed_cost::fixed_cost_entity::{
+    FixedCostAllocation, FixedCostEntity, InfoFixedCostEntity,
+};
+use crate::core::persistence::info::fixed::fixed_cost::info_fixed_cost_api_repository_trait::InfoFixedCostApiRepository;
+use crate::core::persistence::info::fixed::fixed_cost::info_fixed_cost_repository::InfoFixedCostRepository;
+use crate::domain::info::dto::info_fixed_cost_dto::InfoFixedCostUpsertRequest;
+
+pub async fn list_fixed_costs() -> Result<InfoFixedCostEntity> {
+    InfoFixedCostRepository::new().read()
+}
+
+pub async fn create_fixed_cost(req: InfoFixedCostUpsertRequest) -> Result<FixedCostEntity> {
+    req.validate()?;
+    let repo = InfoFixedCostRepository::new();
+    let mut costs = repo.read()?;
+
+    let now = Utc::now();
+    let mut id = new_fixed_cost_id(now);
+    while costs.find(&id).is_some() {
+        id = new_fixed_cost_id(Utc::now());
+    }
+
+    let cost = build_fixed_cost(id, req, now);
+    costs.items.push(cost.clone());
+    costs.updated_at = Some(now);
+    repo.update(&costs)?;
+    Ok(cost)
+}
+
+pub async fn update_fixed_cost(id: String, req: InfoFixedCostUpsertRequest) -> Result<FixedCostEntity> {
+    req.validate()?;
+    let repo = InfoFixedCostRepository::new();
+    let mut costs = repo.read()?;
+
+    let now = Utc::now();
+    let slot = costs
+        .items
+        .iter_mut()
+        .find(|c| c.id == id)
+        .ok_or_else(|| anyhow!("Fixed cost '{}' not found", id))?;
+    *slot = build_fixed_cost(id, req, now);
+    let cost = slot.clone();
+
+    costs.updated_at = Some(now);
+    repo.update(&costs)?;
+    Ok(cost)
+}
+
+/// Past windows lose the item too; set `ends_at` instead to stop billing it.
+pub async fn delete_fixed_cost(id: String) -> Result<Value> {
+    let repo = InfoFixedCostRepository::new();
+    let mut costs = repo.read()?;
+
+    let before = costs.items.len();
+    costs.items.retain(|c| c.id != id);
+    if costs.items.len() == before {
+        return Err(anyhow!("Fixed cost '{}' not found", id));
+    }
+
+    costs.updated_at = Some(Utc::now());
+    repo.update(&costs)?;
+    Ok(serde_json::json!({ "deleted": id }))
+}
+
+fn new_fixed_cost_id(now: DateTime<Utc>) -> String {
+    let nanos = now.timestamp_nanos_opt().unwrap_or_else(|| now.timestamp_micros());
+    format!("fc-{:x}", nanos)
+}
+
+fn build_fixed_cost(id: String, req: InfoFixedCostUpsertRequest, now: DateTime<Utc>) -> FixedCostEntity {
+    let allocation = req.allocation.unwrap_or_default();
+    let mut namespaces: Vec<String> = req
+        .namespaces
+        .unwrap_or_default()
+        .into_iter()
+        .map(|ns| ns.trim().to_string())
+        .filter(|ns| !ns.is_empty())
+        .collect();
+    namespaces.sort();
+    namespaces.dedup();
+    if allocation != FixedCostAllocation::Namespaces {
+        namespaces.clear();
+    }
+
+    FixedCostEntity {
+        id,
+        name: req.name,
+        category: req.category,
+        amount_usd: req.amount_usd,
+        period: req.period,
+        starts_at: req.starts_at,
+        ends_at: req.ends_at,
+        allocation,
+        namespaces,
+        enabled: req.enabled.unwrap_or(true),
+        updated_at: now,
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::fs;
 
 use anyhow::{anyhow, Result};
 use chrono::{Duration, Utc};
@@ -11,12 +12,21 @@ use crate::app_state::AppState;
 use crate::core::client::kube_client::build_kube_client;
 use crate::core::client::mappers::map_pod_to_info_entity;
 use crate::core::client::pods::{fetch_pod_by_name_and_namespace, fetch_pod_by_uid};
+use crate::core::persistence::info::fixed::alerts::alert_rule_entity::selector_matches;
+use crate::core::persistence::info::fixed::attribution::attribution_rule_entity::label_selector_matches;
 use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
 use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
 use crate::core::state::runtime::k8s::k8s_runtime_state::RuntimePod;
 use crate::core::state::runtime::k8s::k8s_runtime_state_repository_trait::K8sRuntimeStateRepositoryTrait;
+use crate::core::persistence::info::path::info_k8s_pod_dir_path;
+use crate::domain::info::dto::info_k8s_pod_bulk_patch_request::{
+    InfoK8sPodBulkPatchRequest, InfoK8sPodBulkPatchResponseDto,
+};
 use crate::domain::info::dto::info_k8s_pod_patch_request::InfoK8sPodPatchRequest;
+use crate::domain::info::service::info_attribution_rule_service::{
+    apply_stored_attribution_rules, MANUAL_ATTRIBUTION,
+};
 
 pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
     let repo = InfoPodRepository::new();
@@ -37,6 +47,14 @@ pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
             let mut updated = map_pod_to_info_entity(&pod)?;
             updated.last_updated_info_at = Some(Utc::now());
             updated.pod_uid = Some(pod_uid.clone());
+            // Workloads are resolved by the discovery task, not the pod mapper
+            updated.workload_kind = existing.workload_kind.clone();
+            updated.workload_name = existing.workload_name.clone();
+            updated.team = existing.team.clone();
+            updated.service = existing.service.clone();
+            updated.env = existing.env.clone();
+            updated.attribution_source = existing.attribution_source.clone();
+            apply_stored_attribution_rules(&mut updated);
             repo.update(&updated)?;
 
             return Ok(updated);
@@ -52,6 +70,7 @@ pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
     let mut entity = map_pod_to_info_entity(&pod)?;
     entity.last_updated_info_at = Some(Utc::now());
     entity.pod_uid = Some(pod_uid.clone());
+    apply_stored_attribution_rules(&mut entity);
     repo.insert(&entity)?;
 
     Ok(entity)
@@ -111,6 +130,7 @@ pub async fn load_pod_entities(uids: &[String], state: AppState) -> Result<Vec<I
         };
 
         entity.last_updated_info_at = Some(Utc::now());
+        apply_stored_attribution_rules(&mut entity);
 
         if let Err(err) = repo.update(&entity) {
             debug!("Update failed for pod {uid}, attempting insert: {err:?}");
@@ -262,21 +282,164 @@ pub async fn patch_info_k8s_pod(
         .read(&id)
         .map_err(|_| anyhow!("Pod '{}' not found", id))?;
 
-    if let Some(team) = patch.team {
+    if patch.use_rules == Some(true) {
+        set_attribution(&mut entity, patch.team, patch.service, patch.env);
+        entity.attribution_source = None;
+        apply_stored_attribution_rules(&mut entity);
+    } else {
+        pin_attribution(&mut entity, patch.team, patch.service, patch.env);
+    }
+
+    entity.last_updated_info_at = Some(Utc::now());
+
+    repo.update(&entity)?;
+
+    Ok(serde_json::to_value(&entity)?)
+}
+
+/// Patches every stored pod matching the selector, the way a single PATCH would.
+pub async fn bulk_patch_info_k8s_pods(
+    req: InfoK8sPodBulkPatchRequest,
+) -> Result<InfoK8sPodBulkPatchResponseDto> {
+    req.validate()?;
+    let dry_run = req.dry_run.unwrap_or(false);
+    let repo = InfoPodRepository::new();
+    let dir = info_k8s_pod_dir_path();
+
+    let mut pod_uids = Vec::new();
+    let mut updated = 0;
+
+    if dir.exists() {
+        for entry in fs::read_dir(dir)? {
+            let pod_uid = entry?.file_name().to_string_lossy().to_string();
+            let Ok(mut entity) = repo.read(&pod_uid) else {
+                continue;
+            };
+            if !bulk_selector_matches(&req, &entity) {
+                continue;
+            }
+
+            if !dry_run {
+                pin_attribution(&mut entity, req.team.clone(), req.service.clone(), req.env.clone());
+                entity.last_updated_info_at = Some(Utc::now());
+                match repo.update(&entity) {
+                    Ok(()) => updated += 1,
+                    Err(e) => debug!("Bulk patch failed for pod {pod_uid}: {e:?}"),
+                }
+            }
+            pod_uids.push(pod_uid);
+        }
+    }
+
+    pod_uids.sort();
+    Ok(InfoK8sPodBulkPatchResponseDto {
+        matched: pod_uids.len(),
+        updated,
+        dry_run,
+        pod_uids,
+    })
+}
+
+fn bulk_selector_matches(req: &InfoK8sPodBulkPatchRequest, pod: &InfoPodEntity) -> bool {
+    let namespace_ok = req.namespace.is_none()
+        || selector_matches(req.namespace.as_deref(), pod.namespace.as_deref().unwrap_or_default());
+    let labels_ok = match &req.label_selector {
+        Some(sel) => label_selector_matches(sel, pod.label.as_deref()),
+        None => true,
+    };
+
+    // Either the direct owner or the resolved workload may match
+    let owners = [
+        (pod.owner_kind.as_deref(), pod.owner_name.as_deref()),
+        (pod.workload_kind.as_deref(), pod.workload_name.as_deref()),
+    ];
+    let owner_ok = (req.owner_kind.is_none() && req.owner_name.is_none())
+        || owners.iter().any(|(kind, name)| {
+            let kind_ok = match req.owner_kind.as_deref() {
+                Some(want) => kind.is_some_and(|k| k.eq_ignore_ascii_case(want)),
+                None => true,
+            };
+            let name_ok = match req.owner_name.as_deref() {
+                Some(want) => name.is_some_and(|n| selector_matches(Some(want), n)),
+                None => true,
+            };
+            kind_ok && name_ok
+        });
+
+    namespace_ok && labels_ok && owner_ok
+}
+
+fn set_attribution(
+    entity: &mut InfoPodEntity,
+    team: Option<String>,
+    service: Option<String>,
+    env: Option<String>,
+) {
+    if let Some(team) = team {
         entity.team = Some(team);
     }
 
-    if let Some(service) = patch.service {
+    if let Some(service) = service {
         entity.service = Some(service);
     }
 
-    if let Some(env) = patch.env {
+    if let Some(env) = env {
         entity.env = Some(env);
     }
+}
 
-    entity.last_updated_info_at = Some(Utc::now());
+/// Hand-set values win over attribution rules from now on.
+fn pin_attribution(
+    entity: &mut InfoPodEntity,
+    team: Option<String>,
+    service: Option<String>,
+    env: Option<String>,
+) {
+    let pins_values = team.is_some() || service.is_some() || env.is_some();
+    set_attribution(entity, team, service, env);
+    if pins_values {
+        entity.attribution_source = Some(MANUAL_ATTRIBUTION.to_string());
+    }
+}
 
-    repo.update(&entity)?;
+/* ---------------- Tests ---------------- */
 
-    Ok(serde_json::to_value(&entity)?)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk_request(value: serde_json::Value) -> InfoK8sPodBulkPatchRequest {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn bulk_selector_matches_owner_or_resolved_workload() {
+        let pod = InfoPodEntity {
+            namespace: Some("payments".into()),
+            label: Some("app=checkout,tier=web".into()),
+            owner_kind: Some("ReplicaSet".into()),
+            owner_name: Some("checkout-7d9f".into()),
+            workload_kind: Some("Deployment".into()),
+            workload_name: Some("checkout".into()),
+            ..Default::default()
+        };
+
+        let by_workload = bulk_request(serde_json::json!({
+            "namespace": "pay*", "owner_kind": "deployment", "owner_name": "checkout", "team": "payments"
+        }));
+        assert!(bulk_selector_matches(&by_workload, &pod));
+
+        let by_label = bulk_request(serde_json::json!({
+            "label_selector": { "app": "checkout" }, "team": "payments"
+        }));
+        assert!(bulk_selector_matches(&by_label, &pod));
+
+        let wrong_label = bulk_request(serde_json::json!({
+            "namespace": "payments", "label_selector": { "app": "search" }, "team": "payments"
+        }));
+        assert!(!bulk_selector_matches(&wrong_label, &pod));
+
+        let blank = bulk_request(serde_json::json!({ "namespace": " ", "team": "payments" }));
+        assert!(blank.validate().is_err());
+    }
 }
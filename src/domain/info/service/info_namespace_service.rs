@@ -1,5 +1,11 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use serde_json::Value;
+use validator::Validate;
+
+use crate::core::persistence::info::k8s::namespace::info_namespace_api_repository_trait::InfoNamespaceApiRepository;
+use crate::core::persistence::info::k8s::namespace::info_namespace_entity::InfoNamespaceEntity;
+use crate::core::persistence::info::k8s::namespace::info_namespace_repository::InfoNamespaceRepository;
+use crate::domain::info::dto::info_k8s_namespace_patch_request::InfoK8sNamespacePatchRequest;
 use crate::core::client::k8s::client_k8s_namespace;
 use crate::core::client::k8s::util::{build_client, read_token};
 
@@ -10,3 +16,54 @@ pub async fn get_k8s_namespaces() -> Result<Value> {
     let namespaces = client_k8s_namespace::fetch_namespaces(&token, &client).await?;
     Ok(serde_json::to_value(namespaces)?)
 }
+
+// ------------------------------
+// Stored namespaces
+// ------------------------------
+
+/// Namespaces stored by discovery, including ones flagged `deleted`.
+pub async fn list_info_k8s_namespaces() -> Result<Vec<InfoNamespaceEntity>> {
+    InfoNamespaceRepository::new().list()
+}
+
+pub async fn get_info_k8s_namespace(name: String) -> Result<InfoNamespaceEntity> {
+    InfoNamespaceRepository::new()
+        .read(&name)
+        .map_err(|_| anyhow!("Namespace '{}' not found", name))
+}
+
+/// Sets the local team/service/env annotations; empty strings clear them.
+pub async fn patch_info_k8s_namespace(
+    name: String,
+    patch: InfoK8sNamespacePatchRequest,
+) -> Result<InfoNamespaceEntity> {
+    patch.validate()?;
+    let repo = InfoNamespaceRepository::new();
+    let mut entity = repo
+        .read(&name)
+        .map_err(|_| anyhow!("Namespace '{}' not found", name))?;
+
+    let clearable = |v: String| Some(v).filter(|s| !s.is_empty());
+    if let Some(team) = patch.team {
+        entity.team = clearable(team);
+    }
+    if let Some(service) = patch.service {
+        entity.service = clearable(service);
+    }
+    if let Some(env) = patch.env {
+        entity.env = clearable(env);
+    }
+
+    repo.update(&entity)?;
+    Ok(entity)
+}
+
+/// Removes a stored namespace record. Discovery recreates it while the
+/// namespace still exists in the cluster.
+pub async fn delete_info_k8s_namespace(name: String) -> Result<Value> {
+    let repo = InfoNamespaceRepository::new();
+    repo.read(&name)
+        .map_err(|_| anyhow!("Namespace '{}' not found", name))?;
+    repo.delete(&name)?;
+    Ok(serde_json::json!({ "deleted": name }))
+}
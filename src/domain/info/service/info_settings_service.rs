@@ -3,7 +3,9 @@ use serde_json::Value;
 use crate::core::persistence::info::fixed::setting::info_setting_api_repository_trait::InfoSettingApiRepository;
 use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
 use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
+use crate::core::persistence::metrics::metric_storage_backend::MetricStorageBackend;
 use crate::domain::info::dto::info_setting_upsert_request::InfoSettingUpsertRequest;
+use crate::scheduler::tasks::collectors::k8s::source::MetricsSourceKind;
 use validator::Validate;
 
 pub async fn get_info_settings() -> Result<InfoSettingEntity> {
@@ -30,6 +32,25 @@ async fn upsert_info_settings_with_repo<R: InfoSettingApiRepository>(
     let mut settings = repo.read()?;
     settings.apply_update(req);
 
+    if settings.scrape_jitter_sec >= settings.scrape_interval_sec {
+        anyhow::bail!(
+            "scrape_jitter_sec ({}) must be less than scrape_interval_sec ({})",
+            settings.scrape_jitter_sec,
+            settings.scrape_interval_sec
+        );
+    }
+    if MetricsSourceKind::parse(&settings.metrics_source) == Some(MetricsSourceKind::Prometheus)
+        && settings.metrics_source_url.is_none()
+    {
+        anyhow::bail!("metrics_source_url must be set for the prometheus metrics source");
+    }
+    if MetricStorageBackend::parse(&settings.storage_backend).is_none() {
+        anyhow::bail!(
+            "Unknown storage_backend '{}'; expected fs, sqlite or influx",
+            settings.storage_backend
+        );
+    }
+
     repo.update(&settings)?;
 
     Ok(serde_json::json!({
@@ -106,4 +127,30 @@ mod tests {
             Some("Settings updated successfully")
         );
     }
+
+    #[tokio::test]
+    async fn upsert_rejects_jitter_not_below_interval() {
+        let repo = MockInfoSettingRepository::default();
+        let payload: InfoSettingUpsertRequest = serde_json::from_value(json!({
+            "scrape_interval_sec": 30,
+            "scrape_jitter_sec": 30
+        }))
+        .unwrap();
+
+        assert!(upsert_info_settings_with_repo(&repo, payload).await.is_err());
+        assert_eq!(repo.adapter.state.lock().unwrap().scrape_interval_sec, 60);
+    }
+
+    #[tokio::test]
+    async fn upsert_rejects_unknown_storage_backend() {
+        let repo = MockInfoSettingRepository::default();
+        let payload: InfoSettingUpsertRequest = serde_json::from_value(json!({
+            "storage_backend": "sqlit"
+        }))
+        .unwrap();
+
+        let err = upsert_info_settings_with_repo(&repo, payload).await.unwrap_err();
+        assert!(err.to_string().contains("sqlit"));
+        assert_eq!(repo.adapter.state.lock().unwrap().storage_backend, "fs");
+    }
 }
@@ -1,40 +1,91 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use serde_json::Value;
+use std::sync::Arc;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_api_repository_trait::InfoUnitPriceApiRepository;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_history_api_repository_trait::InfoUnitPriceHistoryApiRepository;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_history_entity::{InfoUnitPriceHistoryEntity, UnitPriceVersionEntity};
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_history_repository::InfoUnitPriceHistoryRepository;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_repository::InfoUnitPriceRepository;
+use crate::core::persistence::metrics::k8s::allocation::metric_cost_allocation_fs_adapter::MetricCostAllocationFsAdapter;
 use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
 use validator::Validate;
+use crate::domain::metric::k8s::common::util::k8s_metric_query_cache::invalidate_all;
 
+/// Current unit prices, carrying the price history so costs of past points
+/// are computed with the prices in effect at the time.
 pub async fn get_info_unit_prices() -> Result<InfoUnitPriceEntity> {
     let repo = InfoUnitPriceRepository::new();
-    get_info_unit_prices_with_repo(&repo).await
+    let history_repo = InfoUnitPriceHistoryRepository::new();
+    get_info_unit_prices_with_repo(&repo, &history_repo).await
+}
+
+pub async fn get_info_unit_price_history() -> Result<InfoUnitPriceHistoryEntity> {
+    InfoUnitPriceHistoryRepository::new().read()
 }
 
 pub async fn upsert_info_unit_prices(req: InfoUnitPriceUpsertRequest) -> Result<Value> {
     req.validate()?;
     let repo = InfoUnitPriceRepository::new();
-    upsert_info_unit_prices_with_repo(&repo, req).await
+    let history_repo = InfoUnitPriceHistoryRepository::new();
+    upsert_info_unit_prices_with_repo(&repo, &history_repo, req).await
 }
 
-async fn get_info_unit_prices_with_repo<R: InfoUnitPriceApiRepository>(
+async fn get_info_unit_prices_with_repo<R: InfoUnitPriceApiRepository, H: InfoUnitPriceHistoryApiRepository>(
     repo: &R,
+    history_repo: &H,
 ) -> Result<InfoUnitPriceEntity> {
     let entity = repo.read()?;
-    Ok(entity)
+    let history = history_repo.read()?;
+    if history.versions.is_empty() {
+        return Ok(entity);
+    }
+
+    // A version scheduled earlier may have taken effect since the record was written
+    let mut current = history.price_at(Utc::now()).cloned().unwrap_or(entity);
+    current.history = Some(Arc::new(history));
+    Ok(current)
 }
 
-async fn upsert_info_unit_prices_with_repo<R: InfoUnitPriceApiRepository>(
+async fn upsert_info_unit_prices_with_repo<R: InfoUnitPriceApiRepository, H: InfoUnitPriceHistoryApiRepository>(
     repo: &R,
+    history_repo: &H,
     req: InfoUnitPriceUpsertRequest,
 ) -> Result<Value> {
-    let mut unit_prices = repo.read()?;
-    unit_prices.apply_update(req);
+    let stored = repo.read()?;
+    let mut history = history_repo.read()?;
+
+    // First dated change: the prices used so far become the baseline for all history
+    if history.versions.is_empty() {
+        history.upsert(UnitPriceVersionEntity {
+            effective_from: DateTime::<Utc>::UNIX_EPOCH,
+            prices: stored.clone(),
+        });
+    }
+
+    let effective_from = req.effective_from.unwrap_or_else(Utc::now);
+    let mut prices = history.price_at(effective_from).cloned().unwrap_or(stored);
+    prices.apply_update(req);
+    history.upsert(UnitPriceVersionEntity {
+        effective_from,
+        prices,
+    });
+    history_repo.update(&history)?;
+
+    // Keep the plain record on the prices in effect now for readers without history
+    let current = history.price_at(Utc::now()).cloned().unwrap_or_default();
+    repo.update(&current)?;
 
-    repo.update(&unit_prices)?;
+    // A back-dated change reprices windows that are already cached or materialized
+    if effective_from.date_naive() < Utc::now().date_naive() {
+        MetricCostAllocationFsAdapter::new().remove_from(effective_from.date_naive())?;
+    }
+    invalidate_all();
 
     Ok(serde_json::json!({
         "message": "Unit prices updated successfully",
-        "updated_at": unit_prices.updated_at.to_rfc3339(),
+        "effective_from": effective_from.to_rfc3339(),
+        "updated_at": current.updated_at.to_rfc3339(),
     }))
 }
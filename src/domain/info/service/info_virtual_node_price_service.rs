@@ -0,0 +1,26 @@
+use anyhow::Result;
+use serde_json::Value;
+use validator::Validate;
+
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_api_repository_trait::InfoVirtualNodePriceApiRepository;
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_entity::InfoVirtualNodePriceEntity;
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_repository::InfoVirtualNodePriceRepository;
+use crate::domain::info::dto::info_virtual_node_price_upsert_request::InfoVirtualNodePriceUpsertRequest;
+
+pub async fn get_info_virtual_node_prices() -> Result<InfoVirtualNodePriceEntity> {
+    InfoVirtualNodePriceRepository::new().read()
+}
+
+pub async fn upsert_info_virtual_node_prices(req: InfoVirtualNodePriceUpsertRequest) -> Result<Value> {
+    req.validate()?;
+    let repo = InfoVirtualNodePriceRepository::new();
+    let mut prices = repo.read()?;
+    prices.apply_update(req);
+
+    repo.update(&prices)?;
+
+    Ok(serde_json::json!({
+        "message": "Virtual node prices updated successfully",
+        "updated_at": prices.updated_at.to_rfc3339(),
+    }))
+}
@@ -2,6 +2,13 @@
 
 pub mod info_settings_service;
 pub mod info_alerts_service;
+pub mod info_attribution_rule_service;
+pub mod info_billing_service;
+pub mod info_carbon_service;
+pub mod info_efficiency_target_service;
+pub mod info_commitment_service;
+pub mod info_virtual_node_price_service;
+pub mod info_fixed_cost_service;
 pub mod info_llm_service;
 pub mod info_unit_price_service;
 pub mod info_version_service;
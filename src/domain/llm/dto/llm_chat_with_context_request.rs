@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 use super::llm_chat_request::{LlmChatRequest, LlmMessage};
 
 /// Chat request with backend-built context.
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct LlmChatWithContextRequest {
     #[validate(length(min = 1))]
     pub messages: Vec<LlmMessage>,
@@ -29,6 +30,22 @@ pub struct LlmChatWithContextRequest {
     /// Lookback window in minutes for metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_window_minutes: Option<u32>,
+    /// Token budget for the generated context (default 2000); lower-priority
+    /// sections are dropped first when it is exceeded.
+    #[validate(range(min = 200, max = 32000))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_context_tokens: Option<u32>,
+    /// Let the model call read-only metric tools (cost summaries, top pods,
+    /// cost comparisons, efficiency). Responses are never streamed in this mode.
+    #[serde(default)]
+    pub enable_tools: bool,
+    /// Upper bound on tool-calling round trips (default 4).
+    #[validate(range(min = 1, max = 8))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tool_rounds: Option<u8>,
+    /// Replay the conversation's recent turns as context and store this exchange in it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversation_id: Option<String>,
 }
 
 impl From<LlmChatWithContextRequest> for LlmChatRequest {
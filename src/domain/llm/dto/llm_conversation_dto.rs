@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::core::persistence::info::llm_conversation::llm_conversation_entity::LlmConversationEntity;
+
+/// Roles a stored message may have.
+pub const CONVERSATION_ROLES: &[&str] = &["user", "assistant", "system"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct LlmConversationCreateRequest {
+    #[validate(length(max = 200))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct LlmConversationMessageRequest {
+    #[validate(custom(function = "validate_role"))]
+    pub role: String,
+    #[validate(length(min = 1))]
+    pub content: String,
+}
+
+/// Conversation listing entry without its messages.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LlmConversationSummaryDto {
+    pub id: String,
+    pub title: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub message_count: usize,
+}
+
+impl From<&LlmConversationEntity> for LlmConversationSummaryDto {
+    fn from(c: &LlmConversationEntity) -> Self {
+        Self {
+            id: c.id.clone(),
+            title: c.title.clone(),
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+            message_count: c.messages.len(),
+        }
+    }
+}
+
+fn validate_role(role: &str) -> Result<(), ValidationError> {
+    if CONVERSATION_ROLES.contains(&role) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("role").with_message("must be user, assistant or system".into()))
+    }
+}
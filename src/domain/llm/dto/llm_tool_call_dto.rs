@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// One tool invocation made by the model while answering, returned alongside the completion.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LlmToolCallDto {
+    pub round: u8,
+    pub name: String,
+    pub arguments: Value,
+    /// Set when the tool failed; the error text is what the model saw.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
@@ -1,2 +1,4 @@
 pub mod llm_chat_request;
 pub mod llm_chat_with_context_request;
+pub mod llm_tool_call_dto;
+pub mod llm_conversation_dto;
@@ -1,48 +1,148 @@
 // src/domain/llm/service/llm_chat_service.rs
 use anyhow::{anyhow, Result};
+use chrono::Utc;
 use reqwest::Client;
 use serde_json::Value;
+use tracing::warn;
 use validator::Validate;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::llm::info_llm_entity::InfoLlmEntity;
 use crate::core::persistence::info::fixed::llm::info_llm_api_repository_trait::InfoLlmApiRepository;
 use crate::core::persistence::info::fixed::llm::info_llm_repository::InfoLlmRepository;
 use crate::core::persistence::info::fixed::llm::llm_provider::LlmProvider;
 use crate::domain::info::service::{info_alerts_service, info_k8s_node_service};
 use crate::domain::llm::dto::llm_chat_request::{LlmChatRequest, LlmMessage};
 use crate::domain::llm::dto::llm_chat_with_context_request::LlmChatWithContextRequest;
+use crate::domain::llm::dto::llm_tool_call_dto::LlmToolCallDto;
+use crate::domain::llm::dto::llm_conversation_dto::CONVERSATION_ROLES;
+use crate::domain::llm::service::llm_context_builder::{
+    sanitized_json, ContextPriority, LlmContextBuilder, DEFAULT_CONTEXT_TOKENS,
+};
+use crate::domain::llm::service::{llm_conversation_service, llm_tool_service};
+
+/// Tool-calling round trips when the request doesn't set `max_tool_rounds`.
+const DEFAULT_TOOL_ROUNDS: u8 = 4;
+
+/// Tool output beyond this is cut before it goes back to the model.
+const MAX_TOOL_RESULT_CHARS: usize = 6000;
 
 /// Call Hugging Face router using stored LLM configuration.
 pub async fn chat(payload: LlmChatRequest) -> Result<Value> {
     payload.validate()?;
 
     let cfg = InfoLlmRepository::new().read()?;
-    if cfg.provider != LlmProvider::HuggingFace {
-        return Err(anyhow!(
-            "LLM provider must be set to HuggingFace to call this endpoint"
-        ));
+    let endpoint = ChatEndpoint::from_config(&cfg)?;
+    let body = completion_body(&cfg, &payload)?;
+    endpoint.post(&body).await
+}
+
+/// One non-streaming completion of `question` over `context`, returning the reply
+/// text. For backend features that want the model to write up their own data.
+pub async fn complete(context: LlmContextBuilder, question: &str) -> Result<String> {
+    let cfg = InfoLlmRepository::new().read()?;
+    let endpoint = ChatEndpoint::from_config(&cfg)?;
+
+    let mut messages = Vec::new();
+    if let Some(content) = context.build() {
+        messages.push(LlmMessage {
+            role: "system".into(),
+            content,
+        });
     }
+    messages.push(LlmMessage {
+        role: "user".into(),
+        content: question.to_string(),
+    });
+    let payload = LlmChatRequest {
+        model: None,
+        messages,
+        stream: Some(false),
+        max_tokens: None,
+        temperature: None,
+        top_p: None,
+    };
 
-    let token = cfg
-        .token
-        .clone()
-        .ok_or_else(|| anyhow!("LLM token is missing; set it in /info/llm"))?;
+    let resp = endpoint.post(&completion_body(&cfg, &payload)?).await?;
+    resp["choices"][0]["message"]["content"]
+        .as_str()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("LLM returned an empty reply"))
+}
+
+/// Resolved chat-completions URL and bearer token.
+struct ChatEndpoint {
+    url: String,
+    token: String,
+}
 
+impl ChatEndpoint {
+    fn from_config(cfg: &InfoLlmEntity) -> Result<Self> {
+        if cfg.provider != LlmProvider::HuggingFace {
+            return Err(anyhow!(
+                "LLM provider must be set to HuggingFace to call this endpoint"
+            ));
+        }
+
+        let token = cfg
+            .token
+            .clone()
+            .ok_or_else(|| anyhow!("LLM token is missing; set it in /info/llm"))?;
+
+        let base_url = cfg
+            .base_url
+            .clone()
+            .unwrap_or_else(|| "https://router.huggingface.co/v1".to_string());
+        let trimmed = base_url.trim_end_matches('/');
+        let url = if trimmed.ends_with("/chat/completions") {
+            trimmed.to_string()
+        } else {
+            format!("{}/chat/completions", trimmed)
+        };
+
+        Ok(Self { url, token })
+    }
+
+    async fn post(&self, body: &Value) -> Result<Value> {
+        let url = &self.url;
+        let body_str = serde_json::to_string(body).unwrap_or_else(|_| "<failed-to-serialize-body>".to_string());
+
+        let client = Client::builder()
+            .build()
+            .map_err(|e| anyhow!("Failed to build HTTP client: {}", e))?;
+
+        let resp = client
+            .post(url)
+            .bearer_auth(&self.token)
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to call Hugging Face (url={}, body={}): {}", url, body_str, e))?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            return Err(anyhow!("Hugging Face returned {}: {} (url={}, body={})", status, text, url, body_str));
+        }
+
+        let json: Value = resp
+            .json()
+            .await
+            .map_err(|e| anyhow!("Failed to decode Hugging Face response: {} (url={}, body={})", e, url, body_str))?;
+
+        Ok(json)
+    }
+}
+
+/// Request body with payload values falling back to the stored configuration.
+fn completion_body(cfg: &InfoLlmEntity, payload: &LlmChatRequest) -> Result<Value> {
     let model = payload
         .model
         .clone()
         .or_else(|| cfg.model.clone())
         .ok_or_else(|| anyhow!("Model is missing; set it in /info/llm or request payload"))?;
 
-    let base_url = cfg
-        .base_url
-        .clone()
-        .unwrap_or_else(|| "https://router.huggingface.co/v1".to_string());
-    let trimmed = base_url.trim_end_matches('/');
-    let url = if trimmed.ends_with("/chat/completions") {
-        trimmed.to_string()
-    } else {
-        format!("{}/chat/completions", trimmed)
-    };
-
     let mut body = serde_json::json!({
         "model": model,
         "messages": payload.messages,
@@ -59,65 +159,123 @@ pub async fn chat(payload: LlmChatRequest) -> Result<Value> {
         body["top_p"] = serde_json::json!(v);
     }
 
-    let body_str = serde_json::to_string(&body).unwrap_or_else(|_| "<failed-to-serialize-body>".to_string());
+    Ok(body)
+}
 
-    let client = Client::builder()
-        .build()
-        .map_err(|e| anyhow!("Failed to build HTTP client: {}", e))?;
+/// Chat loop where the model may call the read-only tools in [`llm_tool_service`].
+/// Tool results are fed back until the model answers or `max_rounds` is spent; the
+/// last round offers no tools so it always ends in an answer. Streaming is not
+/// supported here.
+async fn chat_with_tools(state: &AppState, payload: LlmChatRequest, max_rounds: u8) -> Result<Value> {
+    let cfg = InfoLlmRepository::new().read()?;
+    let endpoint = ChatEndpoint::from_config(&cfg)?;
+    let mut body = completion_body(&cfg, &payload)?;
+    body["stream"] = Value::Bool(false);
 
-    let resp = client
-        .post(&url)
-        .bearer_auth(token)
-        .json(&body)
-        .send()
-        .await
-        .map_err(|e| anyhow!("Failed to call Hugging Face (url={}, body={}): {}", url, body_str, e))?;
+    let mut messages: Vec<Value> = payload
+        .messages
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<serde_json::Result<_>>()?;
+    let mut trace: Vec<LlmToolCallDto> = Vec::new();
 
-    let status = resp.status();
-    if !status.is_success() {
-        let text = resp.text().await.unwrap_or_default();
-        return Err(anyhow!("Hugging Face returned {}: {} (url={}, body={})", status, text, url, body_str));
-    }
+    let mut round = 0;
+    loop {
+        body["messages"] = Value::Array(messages.clone());
+        if round < max_rounds {
+            body["tools"] = llm_tool_service::tool_definitions();
+            body["tool_choice"] = Value::String("auto".into());
+        } else {
+            body["tool_choice"] = Value::String("none".into());
+        }
 
-    let json: Value = resp
-        .json()
-        .await
-        .map_err(|e| anyhow!("Failed to decode Hugging Face response: {} (url={}, body={})", e, url, body_str))?;
+        let mut resp = endpoint.post(&body).await?;
+        let message = resp["choices"][0]["message"].clone();
+        let calls = message["tool_calls"].as_array().cloned().unwrap_or_default();
+
+        if calls.is_empty() || round == max_rounds {
+            resp["tool_trace"] = serde_json::to_value(&trace)?;
+            return Ok(resp);
+        }
 
-    Ok(json)
+        messages.push(message);
+        for call in calls {
+            let name = call["function"]["name"].as_str().unwrap_or_default().to_string();
+            // Arguments arrive as a JSON-encoded string
+            let arguments = match &call["function"]["arguments"] {
+                Value::String(raw) => serde_json::from_str(raw).unwrap_or(Value::Null),
+                other => other.clone(),
+            };
+
+            let (content, error) = match llm_tool_service::execute_tool(state, &name, &arguments).await {
+                Ok(result) => (trim_str(&sanitized_json(&result), MAX_TOOL_RESULT_CHARS), None),
+                Err(e) => {
+                    warn!(tool = %name, error = %e, "llm_tool_call_failed");
+                    (serde_json::json!({ "error": e.to_string() }).to_string(), Some(e.to_string()))
+                }
+            };
+
+            messages.push(serde_json::json!({
+                "role": "tool",
+                "tool_call_id": call["id"],
+                "name": name,
+                "content": content,
+            }));
+            trace.push(LlmToolCallDto { round: round + 1, name, arguments, error });
+        }
+        round += 1;
+    }
 }
 
 /// Call LLM with backend-built cluster/alert context.
-pub async fn chat_with_context(payload: LlmChatWithContextRequest) -> Result<Value> {
+pub async fn chat_with_context(state: AppState, payload: LlmChatWithContextRequest) -> Result<Value> {
     payload.validate()?;
 
-    let mut context_sections = Vec::new();
+    let mut context = LlmContextBuilder::new(
+        payload.max_context_tokens.map_or(DEFAULT_CONTEXT_TOKENS, |t| t as usize),
+    );
 
     if payload.include_cluster_summary {
-        if let Some(section) = build_node_summary(payload.time_window_minutes).await? {
-            context_sections.push(section);
+        if let Some(summary) = build_node_summary(payload.time_window_minutes).await? {
+            context.data(ContextPriority::High, "cluster_node_summary", &summary);
         }
     }
 
     if payload.include_alerts {
-        if let Some(section) = build_alerts_summary().await? {
-            context_sections.push(section);
+        if let Some(summary) = build_alerts_summary().await? {
+            context.data(ContextPriority::Normal, "alert_config", &summary);
         }
     }
 
+    let tool_rounds = payload
+        .enable_tools
+        .then(|| payload.max_tool_rounds.unwrap_or(DEFAULT_TOOL_ROUNDS));
+    if tool_rounds.is_some() {
+        context.instruction(format!(
+            "You can call tools to read RustCost cost and usage metrics. Times are UTC; now is {}. \
+             Prefer calling a tool over guessing numbers.",
+            Utc::now().format("%Y-%m-%dT%H:%M:%S")
+        ));
+    }
+
     let include_cluster_summary = payload.include_cluster_summary;
     let include_alerts = payload.include_alerts;
     let window_label = payload.time_window_minutes.unwrap_or(15);
+    let conversation_id = payload.conversation_id.clone();
 
     let mut chat_payload: LlmChatRequest = payload.into();
+    let new_turns = chat_payload.messages.clone();
     let mut messages = Vec::new();
-    if !context_sections.is_empty() {
+    if let Some(content) = context.build() {
         messages.push(LlmMessage {
             role: "system".into(),
-            content: context_sections.join("\n\n"),
+            content,
         });
     }
-    messages.extend(chat_payload.messages.clone());
+    if let Some(id) = &conversation_id {
+        messages.extend(llm_conversation_service::conversation_history(id)?);
+    }
+    messages.extend(new_turns.clone());
     chat_payload.messages = messages;
 
     let model_label = chat_payload
@@ -125,22 +283,52 @@ pub async fn chat_with_context(payload: LlmChatWithContextRequest) -> Result<Val
         .clone()
         .unwrap_or_else(|| "default-from-config".to_string());
 
-    chat(chat_payload).await.map_err(|e| {
+    let result = match tool_rounds {
+        Some(rounds) => {
+            chat_payload.validate()?;
+            chat_with_tools(&state, chat_payload, rounds).await
+        }
+        None => chat(chat_payload).await,
+    };
+
+    let resp = result.map_err(|e| {
         anyhow!(
-            "LLM chat_with_context failed (model={}, include_cluster_summary={}, include_alerts={}, window_minutes={}): {}",
+            "LLM chat_with_context failed (model={}, include_cluster_summary={}, include_alerts={}, window_minutes={}, tools={}): {}",
             model_label,
             include_cluster_summary,
             include_alerts,
             window_label,
+            tool_rounds.is_some(),
             e
         )
-    })
+    })?;
+
+    if let Some(id) = &conversation_id {
+        record_turns(id, &new_turns, &resp)?;
+    }
+    Ok(resp)
+}
+
+/// Stores the caller's new messages and the model's reply in the conversation.
+fn record_turns(conversation_id: &str, new_turns: &[LlmMessage], resp: &Value) -> Result<()> {
+    let mut turns: Vec<(String, String)> = new_turns
+        .iter()
+        .filter(|m| CONVERSATION_ROLES.contains(&m.role.as_str()))
+        .map(|m| (m.role.clone(), m.content.clone()))
+        .collect();
+    if let Some(reply) = resp["choices"][0]["message"]["content"].as_str().filter(|c| !c.is_empty()) {
+        turns.push(("assistant".to_string(), reply.to_string()));
+    }
+
+    if !turns.is_empty() {
+        llm_conversation_service::append_messages(conversation_id, &turns)?;
+    }
+    Ok(())
 }
 
-async fn build_node_summary(time_window_minutes: Option<u32>) -> Result<Option<String>> {
+async fn build_node_summary(time_window_minutes: Option<u32>) -> Result<Option<Value>> {
     use crate::api::dto::info_dto::K8sListNodeQuery;
     use crate::api::dto::metrics_dto::{CostMode, RangeQuery};
-    use chrono::Utc;
 
     let nodes = info_k8s_node_service::list_k8s_nodes(K8sListNodeQuery::default()).await?;
     let node_names: Vec<String> = nodes
@@ -161,14 +349,22 @@ async fn build_node_summary(time_window_minutes: Option<u32>) -> Result<Option<S
         start: Some(start),
         end: Some(end),
         granularity: None,
+        tz: None,
+        range: None,
         limit: Some(node_names.len()),
         offset: Some(0),
+        cursor: None,
         sort: None,
         mode: CostMode::Showback,
+        cost_basis: None,
         team: None,
         service: None,
         env: None,
         namespace: None,
+        exclude_namespace: None,
+        exclude_team: None,
+        exclude_service: None,
+        exclude_env: None,
         labels: None,
         key: None,
     };
@@ -179,42 +375,30 @@ async fn build_node_summary(time_window_minutes: Option<u32>) -> Result<Option<S
     )
         .await?;
 
-    let summary_str = serde_json::to_string(&summary)?;
-    Ok(Some(format!(
-        "Cluster node summary ({} nodes, last {}m): {}",
-        node_names.len(),
-        minutes,
-        trim_str(&summary_str, 1200)
-    )))
+    Ok(Some(serde_json::json!({
+        "nodes": node_names.len(),
+        "window_minutes": minutes,
+        "summary": summary,
+    })))
 }
 
-async fn build_alerts_summary() -> Result<Option<String>> {
+async fn build_alerts_summary() -> Result<Option<Value>> {
     let alerts = info_alerts_service::get_info_alerts().await?;
-    let mut parts = Vec::new();
-    parts.push(format!(
-        "Cluster alerts enabled: {}",
-        alerts.enable_cluster_health_alert
-    ));
-    parts.push(format!(
-        "RustCost health alerts enabled: {}",
-        alerts.enable_rustcost_health_alert
-    ));
-    if !alerts.email_recipients.is_empty() {
-        parts.push(format!(
-            "Email recipients: {}",
-            alerts.email_recipients.join(", ")
-        ));
-    }
-    let rule_count = alerts.rules.len();
-    parts.push(format!("Alert rules: {}", rule_count));
-
-    Ok(Some(format!("Alert config: {}", parts.join(" | "))))
+    Ok(Some(serde_json::json!({
+        "cluster_health_alerts_enabled": alerts.enable_cluster_health_alert,
+        "rustcost_health_alerts_enabled": alerts.enable_rustcost_health_alert,
+        "email_recipients": alerts.email_recipients,
+        "alert_rules": alerts.rules.len(),
+    })))
 }
 
 fn trim_str(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...<truncated>", &s[..max_len])
+        return s.to_string();
+    }
+    let mut end = max_len;
+    while !s.is_char_boundary(end) {
+        end -= 1;
     }
+    format!("{}...<truncated>", &s[..end])
 }
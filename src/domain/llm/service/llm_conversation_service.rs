@@ -0,0 +1,99 @@
+// src/domain/llm/service/llm_conversation_service.rs
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use serde_json::Value;
+use validator::Validate;
+
+use crate::core::persistence::info::llm_conversation::llm_conversation_entity::LlmConversationEntity;
+use crate::core::persistence::info::llm_conversation::llm_conversation_repository::{
+    LlmConversationRepository, LlmConversationRepositoryImpl,
+};
+use crate::domain::llm::dto::llm_chat_request::LlmMessage;
+use crate::domain::llm::dto::llm_conversation_dto::{
+    LlmConversationCreateRequest, LlmConversationMessageRequest, LlmConversationSummaryDto,
+};
+
+/// Prior turns replayed to the model when chatting inside a conversation.
+const MAX_HISTORY_MESSAGES: usize = 20;
+
+/// Stored messages per conversation; the oldest are dropped beyond this.
+const MAX_STORED_MESSAGES: usize = 500;
+
+fn new_conversation_id() -> String {
+    let now = Utc::now();
+    format!(
+        "conv-{:x}",
+        now.timestamp_nanos_opt().unwrap_or_else(|| now.timestamp_micros())
+    )
+}
+
+pub async fn create_conversation(req: LlmConversationCreateRequest) -> Result<LlmConversationEntity> {
+    req.validate()?;
+
+    let repo = LlmConversationRepositoryImpl::new();
+    let mut id = new_conversation_id();
+    while repo.exists(&id)? {
+        id = new_conversation_id();
+    }
+
+    let title = req.title.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+    let conversation = LlmConversationEntity::new(id, title, Utc::now());
+    repo.write(&conversation)?;
+    Ok(conversation)
+}
+
+/// Conversations without their messages, most recently updated first.
+pub async fn list_conversations() -> Result<Vec<LlmConversationSummaryDto>> {
+    let mut conversations = LlmConversationRepositoryImpl::new().list()?;
+    conversations.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    Ok(conversations.iter().map(LlmConversationSummaryDto::from).collect())
+}
+
+pub async fn get_conversation(id: String) -> Result<LlmConversationEntity> {
+    LlmConversationRepositoryImpl::new().read(&id)
+}
+
+pub async fn append_conversation_message(
+    id: String,
+    req: LlmConversationMessageRequest,
+) -> Result<LlmConversationEntity> {
+    req.validate()?;
+    append_messages(&id, &[(req.role, req.content)])
+}
+
+pub async fn delete_conversation(id: String) -> Result<Value> {
+    LlmConversationRepositoryImpl::new().delete(&id)?;
+    Ok(serde_json::json!({ "deleted": id }))
+}
+
+/// The latest turns of `id` as chat messages, oldest first.
+pub(crate) fn conversation_history(id: &str) -> Result<Vec<LlmMessage>> {
+    let conversation = LlmConversationRepositoryImpl::new().read(id)?;
+    let skip = conversation.messages.len().saturating_sub(MAX_HISTORY_MESSAGES);
+    Ok(conversation
+        .messages
+        .into_iter()
+        .skip(skip)
+        .map(|m| LlmMessage { role: m.role, content: m.content })
+        .collect())
+}
+
+/// Appends `(role, content)` pairs and trims the conversation to [`MAX_STORED_MESSAGES`].
+pub(crate) fn append_messages(id: &str, messages: &[(String, String)]) -> Result<LlmConversationEntity> {
+    if messages.is_empty() {
+        return Err(anyhow!("no messages to append"));
+    }
+
+    let repo = LlmConversationRepositoryImpl::new();
+    let mut conversation = repo.read(id)?;
+    let now = Utc::now();
+    for (role, content) in messages {
+        conversation.push(role, content, now);
+    }
+
+    let overflow = conversation.messages.len().saturating_sub(MAX_STORED_MESSAGES);
+    conversation.messages.drain(..overflow);
+
+    repo.write(&conversation)?;
+    Ok(conversation)
+}
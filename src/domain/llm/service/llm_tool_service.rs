@@ -0,0 +1,212 @@
+// src/domain/llm/service/llm_tool_service.rs
+//! Read-only metric operations the LLM may call while answering a chat.
+use anyhow::{anyhow, Result};
+use serde_json::{json, Map, Value};
+
+use crate::api::dto::metrics_dto::{CostCompareQuery, RangeQuery};
+use crate::app_state::AppState;
+use crate::domain::metric::k8s::common::dto::MetricGetResponseDto;
+use crate::domain::metric::k8s::common::util::k8s_metric_sort::series_cost_usd;
+
+const LIST_NAMESPACES: &str = "list_namespaces";
+const NAMESPACE_COST_SUMMARY: &str = "get_namespace_cost_summary";
+const TOP_PODS_BY_COST: &str = "get_top_pods_by_cost";
+const POD_COST_TREND: &str = "get_pod_cost_trend";
+const POD_COST_COMPARE: &str = "compare_pod_cost";
+const POD_EFFICIENCY: &str = "get_pod_efficiency";
+
+const DEFAULT_TOP_N: usize = 10;
+const MAX_TOP_N: usize = 25;
+
+/// `RangeQuery` fields a tool may set; anything else the model sends is dropped.
+const RANGE_ARGS: &[&str] = &["start", "end", "namespace", "team", "service", "env"];
+
+/// Tool definitions in the OpenAI-compatible `tools` format.
+pub fn tool_definitions() -> Value {
+    let window = json!({
+        "start": { "type": "string", "description": "Window start, UTC, YYYY-MM-DDTHH:MM:SS" },
+        "end": { "type": "string", "description": "Window end, UTC, YYYY-MM-DDTHH:MM:SS" },
+    });
+    let filters = json!({
+        "namespace": { "type": "string", "description": "Comma-separated namespaces; prefix a value with ~ for a regex" },
+        "team": { "type": "string", "description": "Comma-separated owning teams; prefix a value with ~ for a regex" },
+        "service": { "type": "string", "description": "Comma-separated services" },
+        "env": { "type": "string", "description": "Comma-separated environments, e.g. prod" },
+    });
+    let params = |extra: Value| {
+        let mut props = Map::new();
+        for v in [&window, &filters, &extra] {
+            if let Some(obj) = v.as_object() {
+                props.extend(obj.clone());
+            }
+        }
+        json!({ "type": "object", "properties": props })
+    };
+
+    let tools = [
+        (
+            LIST_NAMESPACES,
+            "List the namespaces currently known to the cluster.",
+            json!({ "type": "object", "properties": {} }),
+        ),
+        (
+            NAMESPACE_COST_SUMMARY,
+            "Cost summary (total, CPU, memory, storage, network in USD) for one namespace or all namespaces.",
+            params(json!({})),
+        ),
+        (
+            TOP_PODS_BY_COST,
+            "Most expensive pods over the window, optionally filtered by namespace/team/service/env.",
+            params(json!({ "limit": { "type": "integer", "description": "Number of pods, at most 25" } })),
+        ),
+        (
+            POD_COST_TREND,
+            "Cost trend over time for the pods matching the filters, e.g. one team's spend per day.",
+            params(json!({})),
+        ),
+        (
+            POD_COST_COMPARE,
+            "Compare pod cost in the window against a baseline window (defaults to the preceding window of equal length). Use this to explain spend jumps.",
+            params(json!({
+                "compare_start": { "type": "string", "description": "Baseline start, UTC, YYYY-MM-DDTHH:MM:SS" },
+                "compare_end": { "type": "string", "description": "Baseline end, UTC, YYYY-MM-DDTHH:MM:SS" },
+            })),
+        ),
+        (
+            POD_EFFICIENCY,
+            "CPU and memory usage efficiency of the pods matching the filters.",
+            params(json!({})),
+        ),
+    ];
+
+    Value::Array(
+        tools
+            .into_iter()
+            .map(|(name, description, parameters)| {
+                json!({
+                    "type": "function",
+                    "function": { "name": name, "description": description, "parameters": parameters },
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Runs one tool call. Every tool is cluster-wide; callers must be unrestricted.
+pub async fn execute_tool(state: &AppState, name: &str, args: &Value) -> Result<Value> {
+    match name {
+        LIST_NAMESPACES => Ok(json!(state.k8s_state.get_namespaces().await)),
+        NAMESPACE_COST_SUMMARY => {
+            let q = range_query(args)?;
+            let namespaces = match &q.namespace {
+                Some(ns) if !ns.contains(',') && !ns.starts_with('~') => vec![ns.clone()],
+                _ => state.k8s_state.get_namespaces().await,
+            };
+            state.metric_service.get_metric_k8s_namespaces_cost_summary(q, namespaces).await
+        }
+        TOP_PODS_BY_COST => {
+            let limit = args
+                .get("limit")
+                .and_then(Value::as_u64)
+                .map(|n| (n as usize).clamp(1, MAX_TOP_N))
+                .unwrap_or(DEFAULT_TOP_N);
+            let q = RangeQuery {
+                sort: Some("cost".to_string()),
+                limit: Some(limit),
+                ..range_query(args)?
+            };
+            let pods = state.k8s_state.get_pods().await;
+            let response: MetricGetResponseDto =
+                serde_json::from_value(state.metric_service.get_metric_k8s_pods_cost(q, pods).await?)?;
+            Ok(top_pods(&response))
+        }
+        POD_COST_TREND => {
+            let pods = state.k8s_state.get_pods().await;
+            state.metric_service.get_metric_k8s_pods_cost_trend(range_query(args)?, pods).await
+        }
+        POD_COST_COMPARE => {
+            let cmp = CostCompareQuery {
+                compare_start: date_arg(args, "compare_start")?,
+                compare_end: date_arg(args, "compare_end")?,
+            };
+            let pods = state.k8s_state.get_pods().await;
+            state.metric_service.get_metric_k8s_pods_cost_compare(range_query(args)?, cmp, pods).await
+        }
+        POD_EFFICIENCY => {
+            let pods = state.k8s_state.get_pods().await;
+            state.metric_service.get_metric_k8s_pods_raw_efficiency(range_query(args)?, pods).await
+        }
+        other => Err(anyhow!("unknown tool '{}'", other)),
+    }
+}
+
+/// Per-pod totals instead of full series, which would blow the model's context.
+fn top_pods(response: &MetricGetResponseDto) -> Value {
+    let pods: Vec<Value> = response
+        .series
+        .iter()
+        .map(|s| json!({ "pod_uid": s.key, "pod": s.name, "cost_usd": series_cost_usd(s) }))
+        .collect();
+    json!({ "start": response.start, "end": response.end, "total": response.total, "pods": pods })
+}
+
+/// Builds a query from the whitelisted tool arguments.
+fn range_query(args: &Value) -> Result<RangeQuery> {
+    let mut fields = Map::new();
+    for key in RANGE_ARGS {
+        match args.get(*key) {
+            Some(Value::String(v)) if !v.is_empty() => {
+                fields.insert(key.to_string(), Value::String(normalize_datetime(key, v)));
+            }
+            _ => {}
+        }
+    }
+    serde_json::from_value(Value::Object(fields)).map_err(|e| anyhow!("invalid tool arguments: {}", e))
+}
+
+fn date_arg(args: &Value, key: &str) -> Result<Option<chrono::NaiveDateTime>> {
+    match args.get(key).and_then(Value::as_str) {
+        Some(v) if !v.is_empty() => serde_json::from_value(json!(normalize_datetime(key, v)))
+            .map(Some)
+            .map_err(|e| anyhow!("invalid {}: {}", key, e)),
+        _ => Ok(None),
+    }
+}
+
+/// Models often send bare dates or a trailing `Z`; the query expects a naive UTC datetime.
+fn normalize_datetime(key: &str, v: &str) -> String {
+    if !matches!(key, "start" | "end" | "compare_start" | "compare_end") {
+        return v.to_string();
+    }
+    let v = v.trim_end_matches('Z');
+    if v.len() == 10 {
+        format!("{}T00:00:00", v)
+    } else {
+        v.to_string()
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_query_keeps_whitelisted_args_and_normalizes_dates() {
+        let q = range_query(&json!({
+            "start": "2025-03-01",
+            "end": "2025-03-02T12:00:00Z",
+            "team": "team-x",
+            "limit": 500,
+            "key": "some-pod",
+        }))
+        .unwrap();
+
+        assert_eq!(q.start.unwrap().to_string(), "2025-03-01 00:00:00");
+        assert_eq!(q.end.unwrap().to_string(), "2025-03-02 12:00:00");
+        assert_eq!(q.team.as_deref(), Some("team-x"));
+        assert!(q.limit.is_none());
+        assert!(q.key.is_none());
+    }
+}
@@ -1 +1,4 @@
 pub mod llm_chat_service;
+pub mod llm_tool_service;
+pub mod llm_conversation_service;
+pub mod llm_context_builder;
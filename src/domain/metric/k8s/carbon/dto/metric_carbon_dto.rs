@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::metric::k8s::common::dto::MetricScope;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCarbonResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub scope: MetricScope,
+    pub target: Option<String>,
+    /// Settings the estimate was computed with
+    pub grid_intensity_g_per_kwh: f64,
+    pub pue: f64,
+    /// One entry per node, namespace or pod; empty for the cluster
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub series: Vec<MetricCarbonSeriesDto>,
+    pub summary: MetricEnergyDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCarbonSeriesDto {
+    pub key: String,
+    pub name: String,
+    pub energy: MetricEnergyDto,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricEnergyDto {
+    pub cpu_kwh: f64,
+    pub memory_kwh: f64,
+    /// Facility energy on top of the IT load (`pue` - 1)
+    pub overhead_kwh: f64,
+    pub total_kwh: f64,
+    pub co2e_grams: f64,
+    /// Cost of the same usage, for carbon per dollar
+    pub cost_usd: f64,
+}
+
+impl MetricEnergyDto {
+    pub fn add(&mut self, other: &Self) {
+        self.cpu_kwh += other.cpu_kwh;
+        self.memory_kwh += other.memory_kwh;
+        self.overhead_kwh += other.overhead_kwh;
+        self.total_kwh += other.total_kwh;
+        self.co2e_grams += other.co2e_grams;
+        self.cost_usd += other.cost_usd;
+    }
+}
@@ -0,0 +1 @@
+pub mod metric_carbon_dto;
@@ -0,0 +1,350 @@
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::fixed::carbon::info_carbon_entity::{InfoCarbonEntity, PowerProfileEntity};
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::repositories::repositories;
+use crate::domain::info::service::{info_carbon_service, info_unit_price_service};
+use crate::domain::metric::k8s::carbon::dto::metric_carbon_dto::{
+    MetricCarbonResponseDto, MetricCarbonSeriesDto, MetricEnergyDto,
+};
+use crate::domain::metric::k8s::common::dto::{MetricGetResponseDto, MetricGranularity, MetricScope, MetricSeriesDto};
+use crate::domain::metric::k8s::common::service_helpers::{
+    apply_costs, apply_node_costs, granularity_interval_hours, point_interval_hours, resolve_time_window,
+    TimeWindow, BYTES_PER_GB,
+};
+use crate::domain::metric::k8s::common::util::k8s_metric_filter::MetricFilters;
+use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
+use crate::domain::metric::k8s::common::util::k8s_metric_sort::series_cost_usd;
+use crate::domain::metric::k8s::namespace::service::load_pods_by_namespace;
+use crate::domain::metric::k8s::node::service::{load_node_infos, node_series};
+use crate::domain::metric::k8s::node_pool::service::node_instance_type;
+use crate::domain::metric::k8s::pod::service::build_pod_response_from_infos;
+
+// ------------------------------
+// Energy model
+// ------------------------------
+
+/// Watt-hours of one series before PUE.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct WattHours {
+    cpu: f64,
+    memory: f64,
+}
+
+fn used_core_hours(series: &MetricSeriesDto) -> f64 {
+    series
+        .points
+        .iter()
+        .filter_map(|p| p.cpu_memory.cpu_usage_core_nano_seconds)
+        .sum::<f64>()
+        / 1_000_000_000.0
+        / 3600.0
+}
+
+/// A workload only draws power for what it uses: used cores at full load plus
+/// its memory. Idle capacity stays with the node.
+fn workload_watt_hours(series: &MetricSeriesDto, granularity: &MetricGranularity, profile: &PowerProfileEntity) -> WattHours {
+    let default_hours = granularity_interval_hours(granularity);
+    let memory_gb_hours: f64 = series
+        .points
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, p)| {
+            let bytes = p.cpu_memory.memory_working_set_bytes.or(p.cpu_memory.memory_usage_bytes)?;
+            Some(bytes / BYTES_PER_GB * point_interval_hours(&series.points, idx, default_hours))
+        })
+        .sum();
+
+    WattHours {
+        cpu: used_core_hours(series) * profile.cpu_max_watts,
+        memory: memory_gb_hours * profile.memory_watts_per_gb,
+    }
+}
+
+/// A node draws idle power on every core for as long as it runs, plus the
+/// load-dependent part for the cores in use. Memory is powered whole.
+fn node_watt_hours(series: &MetricSeriesDto, node: &InfoNodeEntity, profile: &PowerProfileEntity) -> WattHours {
+    let hours = series.running_hours.unwrap_or(0.0);
+    let cores = node.cpu_capacity_cores.unwrap_or(0) as f64;
+    let memory_gb = node.memory_capacity_bytes.unwrap_or(0) as f64 / BYTES_PER_GB;
+
+    WattHours {
+        cpu: cores * hours * profile.cpu_idle_watts
+            + used_core_hours(series) * (profile.cpu_max_watts - profile.cpu_idle_watts),
+        memory: memory_gb * hours * profile.memory_watts_per_gb,
+    }
+}
+
+fn energy(wh: WattHours, cost_usd: f64, carbon: &InfoCarbonEntity) -> MetricEnergyDto {
+    let cpu_kwh = wh.cpu / 1000.0;
+    let memory_kwh = wh.memory / 1000.0;
+    let it_kwh = cpu_kwh + memory_kwh;
+    let total_kwh = it_kwh * carbon.pue;
+    MetricEnergyDto {
+        cpu_kwh,
+        memory_kwh,
+        overhead_kwh: total_kwh - it_kwh,
+        total_kwh,
+        co2e_grams: total_kwh * carbon.grid_intensity_g_per_kwh,
+        cost_usd,
+    }
+}
+
+fn carbon_response(
+    window: &TimeWindow,
+    scope: MetricScope,
+    target: Option<String>,
+    carbon: &InfoCarbonEntity,
+    series: Vec<MetricCarbonSeriesDto>,
+) -> MetricCarbonResponseDto {
+    let mut summary = MetricEnergyDto::default();
+    for s in &series {
+        summary.add(&s.energy);
+    }
+    MetricCarbonResponseDto {
+        start: window.start,
+        end: window.end,
+        scope,
+        target,
+        grid_intensity_g_per_kwh: carbon.grid_intensity_g_per_kwh,
+        pue: carbon.pue,
+        series,
+        summary,
+    }
+}
+
+// ------------------------------
+// Nodes
+// ------------------------------
+
+async fn node_carbon_series(q: &RangeQuery, node_names: Vec<String>, carbon: &InfoCarbonEntity) -> Result<Vec<MetricCarbonSeriesDto>> {
+    let window = resolve_time_window(q);
+    let metric_repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
+    let node_infos = load_node_infos(q, node_names).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+
+    let series = node_infos
+        .iter()
+        .map(|node| node_series(node, &metric_repo, &window))
+        .collect::<Result<Vec<_>>>()?;
+    let mut response = MetricGetResponseDto {
+        start: window.start,
+        end: window.end,
+        scope: "node".to_string(),
+        target: None,
+        granularity: window.granularity.clone(),
+        series,
+        total: None,
+        limit: None,
+        offset: None,
+        next_cursor: None,
+        warnings: Vec::new(),
+        partial: false,
+    };
+    apply_node_costs(&mut response, &unit_prices, &node_infos);
+
+    Ok(response
+        .series
+        .iter()
+        .zip(&node_infos)
+        .map(|(series, node)| {
+            let profile = carbon.profile_for(node_instance_type(node).as_deref());
+            MetricCarbonSeriesDto {
+                key: series.key.clone(),
+                name: series.name.clone(),
+                energy: energy(node_watt_hours(series, node, profile), series_cost_usd(series), carbon),
+            }
+        })
+        .collect())
+}
+
+// ------------------------------
+// Pods and namespaces
+// ------------------------------
+
+/// Energy of each pod, keyed by pod UID. Pods draw on their node's profile.
+async fn pod_energy(q: &RangeQuery, pods: Vec<InfoPodEntity>, carbon: &InfoCarbonEntity) -> Result<HashMap<String, MetricEnergyDto>> {
+    let node_types: HashMap<String, Option<String>> = {
+        let repo = repositories().info_nodes;
+        let mut types = HashMap::new();
+        for name in pods.iter().filter_map(|p| p.node_name.clone()) {
+            if !types.contains_key(&name) {
+                let instance = repo.read(&name).await.ok().and_then(|n| node_instance_type(&n));
+                types.insert(name, instance);
+            }
+        }
+        types
+    };
+    let pod_nodes: HashMap<String, String> = pods
+        .iter()
+        .filter_map(|p| Some((p.pod_uid.clone()?, p.node_name.clone()?)))
+        .collect();
+
+    let unpaged = RangeQuery {
+        offset: None,
+        limit: None,
+        cursor: None,
+        ..q.clone()
+    };
+    let mut response = build_pod_response_from_infos(unpaged, pods, None)?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    apply_costs(&mut response, &unit_prices);
+
+    Ok(response
+        .series
+        .iter()
+        .map(|series| {
+            let instance = pod_nodes
+                .get(&series.key)
+                .and_then(|node| node_types.get(node))
+                .and_then(|t| t.as_deref());
+            let profile = carbon.profile_for(instance);
+            let wh = workload_watt_hours(series, &response.granularity, profile);
+            (series.key.clone(), energy(wh, series_cost_usd(series), carbon))
+        })
+        .collect())
+}
+
+async fn namespace_carbon_series(q: &RangeQuery, namespaces: &[String], carbon: &InfoCarbonEntity) -> Result<Vec<MetricCarbonSeriesDto>> {
+    let filters = MetricFilters::from_query(q)?;
+    let by_namespace = load_pods_by_namespace(namespaces, &filters).await?;
+
+    let mut pod_namespace = HashMap::new();
+    let mut pods = Vec::new();
+    for (ns, ns_pods) in by_namespace {
+        for pod in ns_pods {
+            if let Some(uid) = pod.pod_uid.clone() {
+                pod_namespace.insert(uid, ns.clone());
+            }
+            pods.push(pod);
+        }
+    }
+
+    let mut totals: BTreeMap<String, MetricEnergyDto> = BTreeMap::new();
+    for (uid, e) in pod_energy(q, pods, carbon).await? {
+        if let Some(ns) = pod_namespace.get(&uid) {
+            totals.entry(ns.clone()).or_default().add(&e);
+        }
+    }
+
+    Ok(totals
+        .into_iter()
+        .map(|(ns, energy)| MetricCarbonSeriesDto {
+            key: ns.clone(),
+            name: ns,
+            energy,
+        })
+        .collect())
+}
+
+// ------------------------------
+// API
+// ------------------------------
+
+pub async fn get_metric_k8s_cluster_carbon(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let carbon = info_carbon_service::get_info_carbon().await?;
+    let series = node_carbon_series(&q, node_names, &carbon).await?;
+    let mut dto = carbon_response(&resolve_time_window(&q), MetricScope::Cluster, None, &carbon, series);
+    dto.series.clear();
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_nodes_carbon(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let carbon = info_carbon_service::get_info_carbon().await?;
+    let series = node_carbon_series(&q, node_names, &carbon).await?;
+    Ok(serde_json::to_value(carbon_response(&resolve_time_window(&q), MetricScope::Node, None, &carbon, series))?)
+}
+
+pub async fn get_metric_k8s_node_carbon(node_name: String, q: RangeQuery) -> Result<Value> {
+    let carbon = info_carbon_service::get_info_carbon().await?;
+    let series = node_carbon_series(&q, vec![node_name.clone()], &carbon).await?;
+    let dto = carbon_response(&resolve_time_window(&q), MetricScope::Node, Some(node_name), &carbon, series);
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_namespaces_carbon(q: RangeQuery, namespaces: Vec<String>) -> Result<Value> {
+    let carbon = info_carbon_service::get_info_carbon().await?;
+    let series = namespace_carbon_series(&q, &namespaces, &carbon).await?;
+    let dto = carbon_response(&resolve_time_window(&q), MetricScope::Namespace, None, &carbon, series);
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_namespace_carbon(namespace: String, q: RangeQuery) -> Result<Value> {
+    let carbon = info_carbon_service::get_info_carbon().await?;
+    let series = namespace_carbon_series(&q, std::slice::from_ref(&namespace), &carbon).await?;
+    let dto = carbon_response(&resolve_time_window(&q), MetricScope::Namespace, Some(namespace), &carbon, series);
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_pod_carbon(pod_uid: String, q: RangeQuery) -> Result<Value> {
+    let carbon = info_carbon_service::get_info_carbon().await?;
+    let pod = repositories()
+        .info_pods
+        .read(&pod_uid)
+        .await
+        .map_err(|_| anyhow!("pod '{}' not found", pod_uid))?;
+    let name = pod.pod_name.clone().unwrap_or_else(|| pod_uid.clone());
+
+    let series = pod_energy(&q, vec![pod], &carbon)
+        .await?
+        .into_iter()
+        .map(|(key, energy)| MetricCarbonSeriesDto { key, name: name.clone(), energy })
+        .collect();
+    let dto = carbon_response(&resolve_time_window(&q), MetricScope::Pod, Some(pod_uid), &carbon, series);
+    Ok(serde_json::to_value(dto)?)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use crate::domain::metric::k8s::common::dto::{CommonMetricValuesDto, UniversalMetricPointDto};
+
+    fn series(running_hours: f64) -> MetricSeriesDto {
+        // Two hour points, each using half a core and 2 GB
+        let point = |hour: u32| UniversalMetricPointDto {
+            time: Utc.with_ymd_and_hms(2025, 3, 1, hour, 0, 0).unwrap(),
+            cpu_memory: CommonMetricValuesDto {
+                cpu_usage_core_nano_seconds: Some(0.5 * 3600.0 * 1e9),
+                memory_working_set_bytes: Some(2.0 * BYTES_PER_GB),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        MetricSeriesDto {
+            key: "k".into(),
+            name: "k".into(),
+            scope: MetricScope::Pod,
+            points: vec![point(0), point(1)],
+            running_hours: Some(running_hours),
+            cost_summary: None,
+        }
+    }
+
+    #[test]
+    fn workloads_draw_full_load_power_for_used_cores_only() {
+        let profile = PowerProfileEntity { cpu_idle_watts: 1.0, cpu_max_watts: 4.0, memory_watts_per_gb: 0.5 };
+
+        let wh = workload_watt_hours(&series(2.0), &MetricGranularity::Hour, &profile);
+        assert_eq!(wh, WattHours { cpu: 4.0, memory: 2.0 });
+
+        let node = InfoNodeEntity {
+            cpu_capacity_cores: Some(4),
+            memory_capacity_bytes: Some(8 * BYTES_PER_GB as u64),
+            ..Default::default()
+        };
+        // 4 idle cores for 2 h, plus 1 used core-hour above idle; 8 GB for 2 h
+        let wh = node_watt_hours(&series(2.0), &node, &profile);
+        assert_eq!(wh, WattHours { cpu: 11.0, memory: 8.0 });
+
+        let carbon = InfoCarbonEntity { pue: 1.5, grid_intensity_g_per_kwh: 400.0, ..Default::default() };
+        let e = energy(WattHours { cpu: 1000.0, memory: 0.0 }, 0.0, &carbon);
+        assert_eq!((e.total_kwh, e.overhead_kwh, e.co2e_grams), (1.5, 0.5, 600.0));
+    }
+}
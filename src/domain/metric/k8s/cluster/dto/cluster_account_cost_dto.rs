@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryDto;
+
+/// Cluster cost split by cloud account / project, for reconciling against the cloud bill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterAccountCostResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularity: MetricGranularity,
+    pub groups: Vec<ClusterAccountCostDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterAccountCostDto {
+    /// Cloud account, or `"unassigned"` when neither the node label nor the cluster default is set
+    pub account: String,
+    /// Cloud project, if the nodes carry the project label
+    pub project: Option<String>,
+    pub nodes: Vec<String>,
+    pub summary: MetricCostSummaryDto,
+}
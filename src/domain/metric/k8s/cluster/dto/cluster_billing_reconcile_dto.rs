@@ -0,0 +1,34 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Cluster cost computed by rustcost against the imported cloud bill.
+///
+/// Totals only cover days that have billing data; days without it are listed
+/// in `days_without_billing` instead of counting as zero spend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterBillingReconcileResponseDto {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Billed services counted; empty means every service in the bill
+    pub services: Vec<String>,
+    pub account: Option<String>,
+    pub currency: Option<String>,
+    pub rustcost_cost_usd: f64,
+    pub billed_cost: f64,
+    /// rustcost cost as a share of the bill (percent); `None` when nothing was billed
+    pub coverage_percent: Option<f64>,
+    /// rustcost minus billed; negative when rustcost under-reports
+    pub drift: f64,
+    pub drift_percent: Option<f64>,
+    pub days: Vec<ClusterBillingReconcileDayDto>,
+    pub days_without_billing: Vec<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterBillingReconcileDayDto {
+    pub date: NaiveDate,
+    pub rustcost_cost_usd: f64,
+    pub billed_cost: f64,
+    pub coverage_percent: Option<f64>,
+    pub drift: f64,
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+/// Cluster cost series annotated with the node scale-up / scale-down events
+/// that happened inside the window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterCostEventsResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularity: MetricGranularity,
+    pub points: Vec<ClusterCostEventPointDto>,
+    /// Every node event in the window, oldest first
+    pub events: Vec<ClusterNodeEventDto>,
+    pub scale_up_count: usize,
+    pub scale_down_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterCostEventPointDto {
+    pub time: DateTime<Utc>,
+    pub total_cost_usd: f64,
+    /// Change against the previous point; `None` for the first point
+    pub delta_cost_usd: Option<f64>,
+    /// Node events since the previous point (up to and including this one)
+    pub events: Vec<ClusterNodeEventDto>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClusterNodeEventKind {
+    ScaleUp,
+    ScaleDown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterNodeEventDto {
+    pub time: DateTime<Utc>,
+    pub kind: ClusterNodeEventKind,
+    pub node_name: String,
+    /// From the `node.kubernetes.io/instance-type` label, if present
+    pub instance_type: Option<String>,
+    /// Capacity cost of the node per hour at current unit prices
+    pub hourly_cost_usd: f64,
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryDto;
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+/// Cluster cost summary against the sums of its node and namespace summaries
+/// over the same window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterCostReconcileResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularity: MetricGranularity,
+    pub cluster: MetricCostSummaryDto,
+    /// Should match the cluster except for fixed line items, which only the cluster carries
+    pub nodes: ClusterCostReconcilePartDto,
+    /// Misses idle capacity and unallocated fixed costs; adds load balancers and network
+    pub namespaces: ClusterCostReconcilePartDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterCostReconcilePartDto {
+    /// Sum of the parts
+    pub summary: MetricCostSummaryDto,
+    /// Cluster minus the parts per cost component; positive when the parts under-count
+    pub discrepancy: MetricCostSummaryDto,
+    /// `discrepancy.total_cost_usd` as a share of the cluster total; `None` when the cluster cost nothing
+    pub discrepancy_percent: Option<f64>,
+}
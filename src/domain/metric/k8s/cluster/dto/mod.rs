@@ -1 +1,5 @@
 pub mod cluster_response_dto;
+pub mod cluster_account_cost_dto;
+pub mod cluster_cost_events_dto;
+pub mod cluster_billing_reconcile_dto;
+pub mod cluster_cost_reconcile_dto;
@@ -1,21 +1,38 @@
-use crate::api::dto::metrics_dto::RangeQuery;
+use crate::api::dto::info_dto::BillingRangeQuery;
+use crate::api::dto::metrics_dto::{CostMode, RangeQuery};
+use crate::core::persistence::info::billing::info_billing_repository::{InfoBillingRepository, InfoBillingRepositoryImpl};
+use crate::core::persistence::info::fixed::fixed_cost::info_fixed_cost_api_repository_trait::InfoFixedCostApiRepository;
+use crate::core::persistence::info::fixed::fixed_cost::info_fixed_cost_repository::InfoFixedCostRepository;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
-use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_api_repository_trait::MetricNodeDayApiRepository;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repository::MetricNodeDayRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_api_repository_trait::MetricNodeHourApiRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_repository::MetricNodeHourRepository;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_api_repository_trait::MetricNodeMinuteApiRepository;
-use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::{MetricRawEfficiencyDto, MetricRawEfficiencyResponseDto};
+use crate::domain::export::cost_close_service::closed_cost_summary;
+use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::{MetricEfficiencyPointDto, MetricRawEfficiencyDto, MetricRawEfficiencyResponseDto, MetricRawEfficiencyTrendResponseDto};
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::{MetricRawSummaryDto, MetricRawSummaryResponseDto};
-use crate::domain::metric::k8s::common::dto::{CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricGranularity, MetricScope, MetricSeriesDto, NetworkMetricDto, UniversalMetricPointDto};
-use crate::domain::metric::k8s::common::service_helpers::{apply_costs, build_cost_trend_dto, resolve_time_window};
+use crate::domain::metric::k8s::common::dto::{CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricGranularity, MetricLoadWarningDto, MetricScope, MetricSeriesDto, NetworkMetricDto, StorageMetricDto, UniversalMetricPointDto};
+use crate::domain::metric::k8s::common::service_helpers::{align_to_step, apply_costs, bucket_member_points, build_cost_trend_dto, minute_row_hours, month_row_hours, resolve_time_window, sample_step, sum_filesystem, sum_storage, TimeWindow};
+use crate::domain::metric::k8s::node_pool::service::virtual_node_provider;
+use crate::domain::metric::k8s::node::service::node_baselines;
+use crate::domain::metric::k8s::common::util::k8s_efficiency_weights::EfficiencyWeights;
+use crate::domain::metric::k8s::cluster::dto::cluster_account_cost_dto::{ClusterAccountCostDto, ClusterAccountCostResponseDto};
+use crate::domain::metric::k8s::cluster::dto::cluster_billing_reconcile_dto::{ClusterBillingReconcileDayDto, ClusterBillingReconcileResponseDto};
+use crate::domain::metric::k8s::cluster::dto::cluster_cost_reconcile_dto::{ClusterCostReconcilePartDto, ClusterCostReconcileResponseDto};
+use crate::domain::metric::k8s::namespace::service::get_metric_k8s_namespaces_cost_summary;
+use crate::domain::metric::k8s::node::service::get_metric_k8s_nodes_cost_summary;
+use crate::domain::metric::k8s::cluster::dto::cluster_cost_events_dto::{ClusterCostEventPointDto, ClusterCostEventsResponseDto, ClusterNodeEventDto, ClusterNodeEventKind};
+use crate::core::persistence::repositories::repositories;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use std::collections::{BTreeMap, HashMap};
 use crate::domain::common::service::day_granularity::{split_day_granularity_rows};
+use crate::domain::metric::k8s::common::util::k8s_commitment_coverage::{load_commitment_coverage, NodeComputeSpend};
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
 use anyhow::{anyhow, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde_json::{json, Value};
 use tracing::log;
 use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{MetricCostSummaryDto, MetricCostSummaryResponseDto};
@@ -26,6 +43,17 @@ pub async fn get_metric_k8s_cluster_cost_summary(
     unit_prices: InfoUnitPriceEntity,
     q: RangeQuery,
 ) -> Result<Value> {
+    // A closed month is the pod allocation frozen at close, plus fixed line items
+    let closed = closed_cost_summary(&q, MetricScope::Cluster, None, |r| node_names.contains(&r.node_name))?;
+    if let Some(mut resp) = closed {
+        let fixed_cost = InfoFixedCostRepository::new()
+            .read()?
+            .total_between(resp.start, resp.end);
+        resp.summary.fixed_cost_usd = fixed_cost;
+        resp.summary.total_cost_usd += fixed_cost;
+        return Ok(serde_json::to_value(resp)?);
+    }
+
     let mut total_cpu_cost = 0.0;
     let mut total_memory_cost = 0.0;
     let mut total_storage_cost = 0.0;
@@ -34,79 +62,43 @@ pub async fn get_metric_k8s_cluster_cost_summary(
     log::info!("HELLO");
     log::info!("{:?}", window.granularity);
 
-    let info_repo = crate::core::persistence::info::k8s::node::info_node_repository::InfoNodeRepository::new();
+    let info_repo = repositories().info_nodes;
     let metric_repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
-
-
-
-
+    let mut compute_spend = Vec::new();
 
     for node_name in node_names {
-        let running_hours = match window.granularity {
-
-            MetricGranularity::Minute => {
-                let rows = match &metric_repo {
-                    K8sMetricRepositoryVariant::NodeMinute(r) =>
-                        r.get_row_between(&node_name, window.start, window.end),
-                    _ => Ok(vec![]),
-                }?;
-                rows.len() as f64 / 60.0
-            }
-
-            MetricGranularity::Hour => {
-                let rows = match &metric_repo {
-                    K8sMetricRepositoryVariant::NodeHour(r) =>
-                        MetricNodeHourApiRepository::get_row_between(
-                            r,
-                            &node_name,
-                            window.start,
-                            window.end,
-                        ),
-                    _ => Ok(vec![]),
-                }?;
-                rows.len() as f64
-            }
-
-            MetricGranularity::Day => {
-                let day_repo = MetricNodeDayRepository::new();
-                let hour_repo = MetricNodeHourRepository::new();
-
-                let split_row = split_day_granularity_rows(
-                    &node_name,
-                    &window,
-                    &day_repo,
-                    &hour_repo,
-                )?;
-
-                split_row.start_hour_rows.len() as f64 + split_row.end_hour_rows.len() as f64 + split_row.middle_day_rows.len() as f64 * 24.0
-            }
-        };
+        let running_hours = node_running_hours(&node_name, &window, &metric_repo)?;
 
         if running_hours <= 0.0 {
             continue;
         }
 
-        let node_info = match info_repo.read(&node_name) {
+        let node_info = match info_repo.read(&node_name).await {
             Ok(v) => v,
             Err(_) => continue,
         };
 
-        let cpu_cores = node_info.cpu_capacity_cores.unwrap_or(0) as f64;
-        let memory_gb = node_info.memory_capacity_bytes.unwrap_or(0) as f64 / 1_073_741_824.0;
-        let storage_gb = node_info.ephemeral_storage_capacity_bytes.unwrap_or(0) as f64 / 1_073_741_824.0;
-
-        total_cpu_cost += cpu_cores * running_hours * unit_prices.cpu_core_hour;
-        total_memory_cost += memory_gb * running_hours * unit_prices.memory_gb_hour;
-        total_storage_cost += storage_gb * running_hours * unit_prices.storage_gb_hour;
+        let (cpu, memory, storage) = node_capacity_cost(&node_info, running_hours, &unit_prices);
+        total_cpu_cost += cpu;
+        total_memory_cost += memory;
+        total_storage_cost += storage;
+        compute_spend.push(NodeComputeSpend::new(Some(&node_info), cpu + memory));
     }
 
+    // Every fixed line item lands on the cluster, allocated to namespaces or not
+    let fixed_cost = InfoFixedCostRepository::new()
+        .read()?
+        .total_between(window.start, window.end);
+
     let summary = MetricCostSummaryDto {
         cpu_cost_usd: total_cpu_cost,
         memory_cost_usd: total_memory_cost,
         ephemeral_storage_cost_usd: total_storage_cost,
         persistent_storage_cost_usd: 0.0,
-        total_cost_usd: total_cpu_cost + total_memory_cost + total_storage_cost,
+        total_cost_usd: total_cpu_cost + total_memory_cost + total_storage_cost + fixed_cost,
         network_cost_usd: 0.0,
+        loadbalancer_cost_usd: 0.0,
+        fixed_cost_usd: fixed_cost,
     };
 
     let resp = MetricCostSummaryResponseDto {
@@ -116,22 +108,373 @@ pub async fn get_metric_k8s_cluster_cost_summary(
         target: None,
         granularity: window.granularity.clone(),
         summary,
+        commitments: load_commitment_coverage(&compute_spend, window.start, window.end)?,
     };
 
     Ok(serde_json::to_value(resp)?)
 }
 
+/// Cluster cost grouped by cloud account / project.
+///
+/// Accounts come from the `cloud_account_label` node label, falling back to the
+/// cluster-level `cloud_account` setting; projects from `cloud_project_label`.
+pub async fn get_metric_k8s_cluster_cost_by_account(
+    node_names: Vec<String>,
+    unit_prices: InfoUnitPriceEntity,
+    settings: InfoSettingEntity,
+    q: RangeQuery,
+) -> Result<Value> {
+    let window = resolve_time_window(&q);
+    let info_repo = repositories().info_nodes;
+    let metric_repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
+
+    let mut groups: BTreeMap<(String, Option<String>), ClusterAccountCostDto> = BTreeMap::new();
+
+    for node_name in node_names {
+        let running_hours = node_running_hours(&node_name, &window, &metric_repo)?;
+        if running_hours <= 0.0 {
+            continue;
+        }
+
+        let node_info = match info_repo.read(&node_name).await {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let labels = parse_node_labels(&node_info);
+        let account = labels
+            .get(&settings.cloud_account_label)
+            .cloned()
+            .or_else(|| settings.cloud_account.clone())
+            .unwrap_or_else(|| "unassigned".to_string());
+        let project = labels.get(&settings.cloud_project_label).cloned();
+
+        let (cpu, memory, storage) = node_capacity_cost(&node_info, running_hours, &unit_prices);
+
+        let group = groups
+            .entry((account.clone(), project.clone()))
+            .or_insert_with(|| ClusterAccountCostDto {
+                account,
+                project,
+                nodes: Vec::new(),
+                summary: MetricCostSummaryDto::default(),
+            });
+        group.nodes.push(node_name);
+        group.summary.cpu_cost_usd += cpu;
+        group.summary.memory_cost_usd += memory;
+        group.summary.ephemeral_storage_cost_usd += storage;
+        group.summary.total_cost_usd += cpu + memory + storage;
+    }
+
+    let resp = ClusterAccountCostResponseDto {
+        start: window.start,
+        end: window.end,
+        granularity: window.granularity.clone(),
+        groups: groups.into_values().collect(),
+    };
+
+    Ok(serde_json::to_value(resp)?)
+}
+
+/// Reconciles rustcost cluster cost against imported cloud billing, day by day.
+///
+/// rustcost cost is the node capacity cost of each UTC day, narrowed to `account`
+/// the same way as [`get_metric_k8s_cluster_cost_by_account`]. Billed amounts are
+/// compared as imported (no currency conversion), so import USD bills.
+pub async fn get_metric_k8s_cluster_cost_billing_reconcile(
+    node_names: Vec<String>,
+    unit_prices: InfoUnitPriceEntity,
+    settings: InfoSettingEntity,
+    q: BillingRangeQuery,
+) -> Result<Value> {
+    if q.end < q.start {
+        return Err(anyhow!("end must not be before start"));
+    }
+    let services = q.services();
+    let billing_repo = InfoBillingRepositoryImpl::new();
+
+    let mut days = Vec::new();
+    let mut days_without_billing = Vec::new();
+    let mut currency = None;
+
+    for date in q.start.iter_days().take_while(|d| *d <= q.end) {
+        let Some(bill) = billing_repo.read(date)? else {
+            days_without_billing.push(date);
+            continue;
+        };
+        currency.get_or_insert_with(|| bill.currency.clone());
+        let billed_cost = bill.total_cost(&services, q.account.as_deref());
+
+        let by_account = get_metric_k8s_cluster_cost_by_account(
+            node_names.clone(),
+            unit_prices.clone(),
+            settings.clone(),
+            day_range_query(date)?,
+        )
+        .await?;
+        let by_account: ClusterAccountCostResponseDto = serde_json::from_value(by_account)?;
+        let rustcost_cost_usd: f64 = by_account
+            .groups
+            .iter()
+            .filter(|g| q.account.as_ref().is_none_or(|a| *a == g.account))
+            .map(|g| g.summary.total_cost_usd)
+            .sum();
+
+        days.push(ClusterBillingReconcileDayDto {
+            date,
+            rustcost_cost_usd,
+            billed_cost,
+            coverage_percent: percent_of(rustcost_cost_usd, billed_cost),
+            drift: rustcost_cost_usd - billed_cost,
+        });
+    }
+
+    let rustcost_cost_usd: f64 = days.iter().map(|d| d.rustcost_cost_usd).sum();
+    let billed_cost: f64 = days.iter().map(|d| d.billed_cost).sum();
+    let drift = rustcost_cost_usd - billed_cost;
+
+    let resp = ClusterBillingReconcileResponseDto {
+        start: q.start,
+        end: q.end,
+        services,
+        account: q.account,
+        currency,
+        rustcost_cost_usd,
+        billed_cost,
+        coverage_percent: percent_of(rustcost_cost_usd, billed_cost),
+        drift,
+        drift_percent: percent_of(drift, billed_cost),
+        days,
+        days_without_billing,
+    };
+
+    Ok(serde_json::to_value(resp)?)
+}
+
+/// Reconciles the cluster cost summary with the sum of the node cost summaries
+/// and the sum of the namespace cost summaries over the window `q`.
+///
+/// Query filters narrow only the namespace side, so leave them out for a full
+/// reconciliation.
+pub async fn get_metric_k8s_cost_reconcile(
+    node_names: Vec<String>,
+    unit_prices: InfoUnitPriceEntity,
+    q: RangeQuery,
+) -> Result<Value> {
+    let cluster: MetricCostSummaryResponseDto = serde_json::from_value(
+        get_metric_k8s_cluster_cost_summary(node_names.clone(), unit_prices, q.clone()).await?,
+    )?;
+    let nodes = summary_of(get_metric_k8s_nodes_cost_summary(q.clone(), node_names).await?)?;
+    let namespaces = summary_of(get_metric_k8s_namespaces_cost_summary(q, Vec::new()).await?)?;
+
+    let resp = ClusterCostReconcileResponseDto {
+        start: cluster.start,
+        end: cluster.end,
+        granularity: cluster.granularity,
+        nodes: reconcile_part(&cluster.summary, nodes),
+        namespaces: reconcile_part(&cluster.summary, namespaces),
+        cluster: cluster.summary,
+    };
+
+    Ok(serde_json::to_value(resp)?)
+}
+
+/// The `summary` of a cost summary response; an empty window ("no data") sums to zero.
+fn summary_of(value: Value) -> Result<MetricCostSummaryDto> {
+    match value.get("summary") {
+        Some(summary) => Ok(serde_json::from_value(summary.clone())?),
+        None => Ok(MetricCostSummaryDto::default()),
+    }
+}
+
+fn reconcile_part(cluster: &MetricCostSummaryDto, parts: MetricCostSummaryDto) -> ClusterCostReconcilePartDto {
+    let discrepancy = MetricCostSummaryDto {
+        total_cost_usd: cluster.total_cost_usd - parts.total_cost_usd,
+        cpu_cost_usd: cluster.cpu_cost_usd - parts.cpu_cost_usd,
+        memory_cost_usd: cluster.memory_cost_usd - parts.memory_cost_usd,
+        ephemeral_storage_cost_usd: cluster.ephemeral_storage_cost_usd - parts.ephemeral_storage_cost_usd,
+        persistent_storage_cost_usd: cluster.persistent_storage_cost_usd - parts.persistent_storage_cost_usd,
+        network_cost_usd: cluster.network_cost_usd - parts.network_cost_usd,
+        loadbalancer_cost_usd: cluster.loadbalancer_cost_usd - parts.loadbalancer_cost_usd,
+        fixed_cost_usd: cluster.fixed_cost_usd - parts.fixed_cost_usd,
+    };
+    ClusterCostReconcilePartDto {
+        discrepancy_percent: percent_of(discrepancy.total_cost_usd, cluster.total_cost_usd),
+        discrepancy,
+        summary: parts,
+    }
+}
+
+fn percent_of(value: f64, base: f64) -> Option<f64> {
+    (base.abs() > f64::EPSILON).then(|| value / base * 100.0)
+}
+
+/// One UTC day at hour granularity.
+fn day_range_query(date: NaiveDate) -> Result<RangeQuery> {
+    let start = date.and_hms_opt(0, 0, 0).ok_or_else(|| anyhow!("invalid date {}", date))?;
+    Ok(RangeQuery {
+        start: Some(start),
+        end: Some(start + Duration::days(1)),
+        granularity: Some(MetricGranularity::Hour),
+        tz: None,
+        range: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        sort: None,
+        mode: CostMode::Showback,
+        cost_basis: None,
+        team: None,
+        service: None,
+        env: None,
+        namespace: None,
+        exclude_namespace: None,
+        exclude_team: None,
+        exclude_service: None,
+        exclude_env: None,
+        labels: None,
+        key: None,
+    })
+}
+
+/// Hours a node reported metrics within the window, at the window's granularity.
+fn node_running_hours(
+    node_name: &str,
+    window: &TimeWindow,
+    metric_repo: &K8sMetricRepositoryVariant,
+) -> Result<f64> {
+    let hours = match window.granularity {
+        MetricGranularity::Minute => {
+            let rows = match metric_repo {
+                K8sMetricRepositoryVariant::NodeMinute(r) =>
+                    r.get_row_between(node_name, window.start, window.end),
+                _ => Ok(vec![]),
+            }?;
+            rows.len() as f64 * minute_row_hours()
+        }
+
+        MetricGranularity::Hour => {
+            let rows = match metric_repo {
+                K8sMetricRepositoryVariant::NodeHour(r) =>
+                    MetricNodeHourApiRepository::get_row_between(
+                        r,
+                        node_name,
+                        window.start,
+                        window.end,
+                    ),
+                _ => Ok(vec![]),
+            }?;
+            rows.len() as f64
+        }
+
+        MetricGranularity::Day => {
+            let day_repo = MetricNodeDayRepository::new();
+            let hour_repo = MetricNodeHourRepository::new();
+
+            let split_row = split_day_granularity_rows(
+                node_name,
+                window,
+                &day_repo,
+                &hour_repo,
+            )?;
+
+            split_row.running_hours()
+        }
+
+        MetricGranularity::Month => {
+            let rows = match metric_repo {
+                K8sMetricRepositoryVariant::NodeMonth(r) =>
+                    r.get_row_between(node_name, window.start, window.end),
+                _ => Ok(vec![]),
+            }?;
+            rows.iter().map(|r| month_row_hours(r.time)).sum()
+        }
+    };
+    Ok(hours)
+}
+
+/// (cpu, memory, ephemeral storage) cost of a node's capacity over `running_hours`.
+fn node_capacity_cost(
+    node_info: &InfoNodeEntity,
+    running_hours: f64,
+    unit_prices: &InfoUnitPriceEntity,
+) -> (f64, f64, f64) {
+    // Virtual nodes advertise placeholder capacity; their pods are billed per request instead.
+    if virtual_node_provider(node_info).is_some() {
+        return (0.0, 0.0, 0.0);
+    }
+    let cpu_cores = node_info.cpu_capacity_cores.unwrap_or(0) as f64;
+    let memory_gb = node_info.memory_capacity_bytes.unwrap_or(0) as f64 / 1_073_741_824.0;
+    let storage_gb = node_info.ephemeral_storage_capacity_bytes.unwrap_or(0) as f64 / 1_073_741_824.0;
+
+    (
+        cpu_cores * running_hours * unit_prices.cpu_core_hour,
+        memory_gb * running_hours * unit_prices.memory_gb_hour,
+        storage_gb * running_hours * unit_prices.storage_gb_hour,
+    )
+}
+
+/// Well-known node label carrying the cloud instance type.
+const INSTANCE_TYPE_LABEL: &str = "node.kubernetes.io/instance-type";
+
+/// Node labels are stored as a JSON object string.
+fn parse_node_labels(node_info: &InfoNodeEntity) -> HashMap<String, String> {
+    node_info
+        .label
+        .as_deref()
+        .and_then(|l| serde_json::from_str(l).ok())
+        .unwrap_or_default()
+}
+
 pub async fn get_metric_k8s_cluster_raw(
     node_names: Vec<String>,
     q: RangeQuery,
 ) -> Result<Value, anyhow::Error> {
 
     let window = resolve_time_window(&q);
+    let (node_points, warnings) = load_node_points(&node_names, &window);
+
+    // Aggregate multiple nodes ??cluster values
+    let cluster_points = aggregate_cluster_points(node_points, sample_step(&window.granularity));
+
+    let response = MetricGetResponseDto {
+        start: window.start,
+        end: window.end,
+        scope: "cluster".into(),
+        target: None,
+        granularity: window.granularity,
+        series: vec![MetricSeriesDto {
+            key: "cluster".into(),
+            name: "cluster".into(),
+            scope: MetricScope::Cluster,
+            points: cluster_points,
+            running_hours: None,
+            cost_summary: None,
+        }],
+        // Cluster API does not paginate output
+        total: None,
+        limit: None,
+        offset: None,
+        next_cursor: None,
+        partial: !warnings.is_empty(),
+        warnings,
+    };
+
+    Ok(serde_json::to_value(response)?)
+}
+
+/// Raw points of each node over the window, one series per node. Nodes that
+/// fail to load yield an empty series and a warning.
+fn load_node_points(
+    node_names: &[String],
+    window: &TimeWindow,
+) -> (Vec<Vec<UniversalMetricPointDto>>, Vec<MetricLoadWarningDto>) {
     let repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
 
-    let mut aggregated_points: Vec<UniversalMetricPointDto> = Vec::new();
+    let mut node_points: Vec<Vec<UniversalMetricPointDto>> = Vec::with_capacity(node_names.len());
+    let mut warnings: Vec<MetricLoadWarningDto> = Vec::new();
 
-    for node_name in &node_names {
+    for node_name in node_names {
 
         // Load per-node metric rows
         let rows = match &repo {
@@ -141,7 +484,7 @@ pub async fn get_metric_k8s_cluster_raw(
             K8sMetricRepositoryVariant::NodeHour(r) => {
                 MetricNodeHourApiRepository::get_row_between(
                     r,
-                    &node_name,
+                    node_name,
                     window.start,
                     window.end,
                 )
@@ -149,28 +492,37 @@ pub async fn get_metric_k8s_cluster_raw(
             K8sMetricRepositoryVariant::NodeDay(r) => {
                 MetricNodeDayApiRepository::get_row_between(
                     r,
-                    &node_name,
+                    node_name,
                     window.start,
                     window.end,
                 )
             }
+            K8sMetricRepositoryVariant::NodeMonth(r) => {
+                r.get_row_between(node_name, window.start, window.end)
+            }
             K8sMetricRepositoryVariant::PodMinute(_)
             | K8sMetricRepositoryVariant::PodHour(_)
             | K8sMetricRepositoryVariant::PodDay(_)
+            | K8sMetricRepositoryVariant::PodMonth(_)
             | K8sMetricRepositoryVariant::ContainerMinute(_)
             | K8sMetricRepositoryVariant::ContainerHour(_)
-            | K8sMetricRepositoryVariant::ContainerDay(_) => Err(anyhow!(
+            | K8sMetricRepositoryVariant::ContainerDay(_)
+            | K8sMetricRepositoryVariant::ContainerMonth(_) => Err(anyhow!(
                 "Cluster node metrics require a node repository for granularity {:?}",
                 window.granularity
             )),
         }
         .unwrap_or_else(|err| {
             tracing::warn!("Failed loading metrics for {}: {}", node_name, err);
+            warnings.push(MetricLoadWarningDto {
+                key: node_name.clone(),
+                reason: err.to_string(),
+            });
             vec![]
         });
 
         // Convert to universal struct ??preserve missing values (None/null)
-        aggregated_points.extend(rows.into_iter().map(|m| {
+        node_points.push(rows.into_iter().map(|m| {
             UniversalMetricPointDto {
                 time: m.time,
                 cpu_memory: CommonMetricValuesDto {
@@ -195,34 +547,60 @@ pub async fn get_metric_k8s_cluster_raw(
                 }),
                 storage: None,
                 cost: None,
+                saturation: None,
             }
-        }));
+        }).collect());
     }
 
-    // Aggregate multiple nodes ??cluster values
-    let cluster_points = aggregate_cluster_points(aggregated_points);
+    (node_points, warnings)
+}
 
-    let response = MetricGetResponseDto {
-        start: window.start,
-        end: window.end,
-        scope: "cluster".into(),
-        target: None,
-        granularity: window.granularity,
-        series: vec![MetricSeriesDto {
-            key: "cluster".into(),
-            name: "cluster".into(),
-            scope: MetricScope::Cluster,
-            points: cluster_points,
-            running_hours: None,
-            cost_summary: None,
-        }],
-        // Cluster API does not paginate output
-        total: None,
-        limit: None,
-        offset: None,
+/// Bytes sent and received by each node between consecutive samples, summed
+/// per sampling step across nodes.
+///
+/// Deltas are taken on each node's own rx and tx counters before summing: a
+/// counter that goes down means that node restarted from 0, so its current
+/// value is the increase (as the hour aggregator does). Taking deltas on the
+/// cluster sum would read one node restart as a cluster-wide reset.
+pub fn cluster_network_increases(
+    node_points: &[Vec<UniversalMetricPointDto>],
+    step: Duration,
+) -> BTreeMap<DateTime<Utc>, f64> {
+    let increase = |prev: Option<f64>, cur: Option<f64>| -> Option<f64> {
+        match (prev, cur) {
+            (Some(p), Some(c)) if c >= p => Some(c - p),
+            // Counter reset compensation.
+            (Some(_), Some(c)) => Some(c),
+            _ => None,
+        }
     };
+    let valid = |v: Option<f64>| v.filter(|v| v.is_finite() && *v >= 0.0);
 
-    Ok(serde_json::to_value(response)?)
+    let mut increases: BTreeMap<DateTime<Utc>, f64> = BTreeMap::new();
+
+    for points in node_points {
+        let mut sorted: Vec<&UniversalMetricPointDto> = points.iter().collect();
+        sorted.sort_by_key(|p| p.time);
+
+        let mut prev_rx: Option<f64> = None;
+        let mut prev_tx: Option<f64> = None;
+
+        for p in sorted {
+            let Some(net) = p.network.as_ref() else { continue };
+            let (rx, tx) = (valid(net.rx_bytes), valid(net.tx_bytes));
+
+            let rx_inc = increase(prev_rx, rx);
+            let tx_inc = increase(prev_tx, tx);
+            if rx_inc.is_some() || tx_inc.is_some() {
+                *increases.entry(align_to_step(p.time, step)).or_default() += rx_inc.unwrap_or(0.0) + tx_inc.unwrap_or(0.0);
+            }
+
+            prev_rx = rx.or(prev_rx);
+            prev_tx = tx.or(prev_tx);
+        }
+    }
+
+    increases
 }
 
 
@@ -231,9 +609,11 @@ pub async fn get_metric_k8s_cluster_raw(
 /// Summarize raw cluster resource usage (CPU, memory, storage, network).
 ///
 /// - Fetches raw metrics for the given nodes and time range
-/// - Computes averages and max values across all valid samples
+/// - Computes averages and max values across all valid samples; each sample is
+///   the whole cluster's usage (summed over nodes by [`aggregate_cluster_points`])
 /// - Handles missing data gracefully (skips missing/NaN/negative samples)
-/// - For network, treats rx/tx as cumulative counters and aggregates deltas
+/// - For network, treats rx/tx as cumulative counters and aggregates the
+///   per-node deltas (see [`cluster_network_increases`])
 pub async fn get_metric_k8s_cluster_raw_summary(
     node_names: Vec<String>,
     q: RangeQuery,
@@ -241,13 +621,12 @@ pub async fn get_metric_k8s_cluster_raw_summary(
     const NANOCORES_PER_CORE: f64 = 1_000_000_000.0;
     const BYTES_PER_GIB: f64 = 1_073_741_824.0;
 
-    // 1️⃣ Retrieve the raw metrics for the time range
-    let raw_value = get_metric_k8s_cluster_raw(node_names.clone(), q).await?;
-    let cluster_metrics: MetricGetResponseDto = serde_json::from_value(raw_value)?;
-
-    if cluster_metrics.series.is_empty() {
-        return Ok(json!({ "status": "no data" }));
-    }
+    // 1️⃣ Retrieve the raw metrics for the time range, per node and summed
+    let window = resolve_time_window(&q);
+    let (node_points, _warnings) = load_node_points(&node_names, &window);
+    let step = sample_step(&window.granularity);
+    let network_increases = cluster_network_increases(&node_points, step);
+    let cluster_points = aggregate_cluster_points(node_points, step);
 
     // 2️⃣ Prepare accumulators (per-metric sample counts)
     let mut total_cpu_cores = 0.0;
@@ -262,108 +641,75 @@ pub async fn get_metric_k8s_cluster_raw_summary(
     let mut max_storage_gib = 0.0;
     let mut storage_samples = 0u64;
 
-    // For network, we treat rx/tx as cumulative counters and accumulate deltas.
-    let mut total_network_bytes = 0.0;
-    let mut max_network_gib_per_interval = 0.0;
-    let mut network_intervals = 0u64;
-
-    let mut has_any_point = false;
+    let has_any_point = !cluster_points.is_empty();
 
     // 3️⃣ Aggregate usage across all metric points
-    for series in &cluster_metrics.series {
-        // For network deltas within this series
-        let mut prev_net_bytes: Option<f64> = None;
-
-        for point in &series.points {
-            has_any_point = true;
-
-            // --- CPU ---
-            if let Some(nano_cores) = point.cpu_memory.cpu_usage_nano_cores {
-                let cores = nano_cores / NANOCORES_PER_CORE;
+    for point in &cluster_points {
+        // --- CPU ---
+        if let Some(nano_cores) = point.cpu_memory.cpu_usage_nano_cores {
+            let cores = nano_cores / NANOCORES_PER_CORE;
 
-                if cores.is_finite() && cores >= 0.0 {
-                    total_cpu_cores += cores;
-                    cpu_samples += 1;
+            if cores.is_finite() && cores >= 0.0 {
+                total_cpu_cores += cores;
+                cpu_samples += 1;
 
-                    if cores > max_cpu_cores {
-                        max_cpu_cores = cores;
-                    }
-                } else {
-                    // warn!("Invalid CPU value: {}", cores);
+                if cores > max_cpu_cores {
+                    max_cpu_cores = cores;
                 }
             } else {
-                // debug!("Missing cpu_usage_nano_cores for a point");
+                // warn!("Invalid CPU value: {}", cores);
             }
+        } else {
+            // debug!("Missing cpu_usage_nano_cores for a point");
+        }
 
-            // --- Memory ---
-            if let Some(mem_bytes) = point.cpu_memory.memory_usage_bytes {
-                let mem_gib = mem_bytes / BYTES_PER_GIB;
+        // --- Memory ---
+        if let Some(mem_bytes) = point.cpu_memory.memory_usage_bytes {
+            let mem_gib = mem_bytes / BYTES_PER_GIB;
 
-                if mem_gib.is_finite() && mem_gib >= 0.0 {
-                    total_mem_gib += mem_gib;
-                    mem_samples += 1;
+            if mem_gib.is_finite() && mem_gib >= 0.0 {
+                total_mem_gib += mem_gib;
+                mem_samples += 1;
 
-                    if mem_gib > max_mem_gib {
-                        max_mem_gib = mem_gib;
-                    }
-                } else {
-                    // warn!("Invalid memory value: {}", mem_gib);
+                if mem_gib > max_mem_gib {
+                    max_mem_gib = mem_gib;
                 }
             } else {
-                // debug!("Missing memory_usage_bytes for a point");
+                // warn!("Invalid memory value: {}", mem_gib);
             }
+        } else {
+            // debug!("Missing memory_usage_bytes for a point");
+        }
 
-            // --- Storage ---
-            if let Some(fs) = point.filesystem.as_ref() {
-                if let Some(used_bytes) = fs.used_bytes {
-                    let fs_gib = used_bytes / BYTES_PER_GIB;
-
-                    if fs_gib.is_finite() && fs_gib >= 0.0 {
-                        total_storage_gib += fs_gib;
-                        storage_samples += 1;
+        // --- Storage ---
+        if let Some(fs) = point.filesystem.as_ref() {
+            if let Some(used_bytes) = fs.used_bytes {
+                let fs_gib = used_bytes / BYTES_PER_GIB;
 
-                        if fs_gib > max_storage_gib {
-                            max_storage_gib = fs_gib;
-                        }
-                    } else {
-                        // warn!("Invalid filesystem.used_bytes value: {}", fs_gib);
-                    }
-                } else {
-                    // debug!("Missing filesystem.used_bytes for a point");
-                }
-            }
+                if fs_gib.is_finite() && fs_gib >= 0.0 {
+                    total_storage_gib += fs_gib;
+                    storage_samples += 1;
 
-            // --- Network (counters -> deltas) ---
-            if let Some(net) = point.network.as_ref() {
-                let rx = net.rx_bytes.unwrap_or(0.0);
-                let tx = net.tx_bytes.unwrap_or(0.0);
-                let combined = rx + tx;
-
-                if combined.is_finite() && combined >= 0.0 {
-                    if let Some(prev) = prev_net_bytes {
-                        if combined >= prev {
-                            let delta_bytes = combined - prev;
-                            total_network_bytes += delta_bytes;
-                            network_intervals += 1;
-
-                            let delta_gib = delta_bytes / BYTES_PER_GIB;
-                            if delta_gib > max_network_gib_per_interval {
-                                max_network_gib_per_interval = delta_gib;
-                            }
-                        } else {
-                            // Counter reset or rollover
-                            // warn!("Network counters decreased (possible reset)");
-                        }
+                    if fs_gib > max_storage_gib {
+                        max_storage_gib = fs_gib;
                     }
-
-                    prev_net_bytes = Some(combined);
                 } else {
-                    // warn!("Invalid network counter value: {}", combined);
+                    // warn!("Invalid filesystem.used_bytes value: {}", fs_gib);
                 }
+            } else {
+                // debug!("Missing filesystem.used_bytes for a point");
             }
         }
     }
 
+    // --- Network (per-node counters -> deltas, summed per interval) ---
+    let network_intervals = network_increases.len() as u64;
+    let total_network_bytes: f64 = network_increases.values().sum();
+    let max_network_gib_per_interval = network_increases
+        .values()
+        .map(|bytes| bytes / BYTES_PER_GIB)
+        .fold(0.0, f64::max);
+
     if !has_any_point {
         return Ok(json!({ "status": "no data" }));
     }
@@ -414,10 +760,10 @@ pub async fn get_metric_k8s_cluster_raw_summary(
     };
 
     let response = MetricRawSummaryResponseDto {
-        start: cluster_metrics.start,
-        end: cluster_metrics.end,
+        start: window.start,
+        end: window.end,
         scope: MetricScope::Cluster,
-        granularity: cluster_metrics.granularity,
+        granularity: window.granularity,
         summary,
     };
 
@@ -454,6 +800,125 @@ pub async fn get_metric_k8s_cluster_cost_trend(
     Ok(serde_json::to_value(response)?)
 }
 
+/// Cluster cost series annotated with node scale-up / scale-down events.
+///
+/// Node creation comes from the stored `creation_timestamp`, removal from the
+/// `deleted_at` recorded by the watcher. Nodes removed inside the window are
+/// priced in too, so the drop after a scale-down shows up in the series.
+pub async fn get_metric_k8s_cluster_cost_events(
+    node_names: Vec<String>,
+    unit_prices: InfoUnitPriceEntity,
+    q: RangeQuery,
+) -> Result<Value> {
+    let window = resolve_time_window(&q);
+    let stored_nodes = load_stored_nodes().await;
+
+    let mut names = node_names;
+    for node in &stored_nodes {
+        let Some(name) = node.node_name.as_ref() else { continue };
+        let removed_in_window = node.deleted_at.is_some_and(|t| t >= window.start);
+        if removed_in_window && !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+
+    let events = node_lifecycle_events(&stored_nodes, &window, &unit_prices);
+
+    let raw_value = get_metric_k8s_cluster_cost(names, unit_prices, q).await?;
+    let cluster_cost: MetricGetResponseDto = serde_json::from_value(raw_value)?;
+    let series: Vec<(DateTime<Utc>, f64)> = cluster_cost
+        .series
+        .iter()
+        .flat_map(|s| s.points.iter())
+        .filter_map(|p| p.cost.as_ref().and_then(|c| c.total_cost_usd).map(|t| (p.time, t)))
+        .collect();
+
+    let response = ClusterCostEventsResponseDto {
+        start: window.start,
+        end: window.end,
+        granularity: window.granularity.clone(),
+        points: annotate_cost_points(series, &events),
+        scale_up_count: events.iter().filter(|e| e.kind == ClusterNodeEventKind::ScaleUp).count(),
+        scale_down_count: events.iter().filter(|e| e.kind == ClusterNodeEventKind::ScaleDown).count(),
+        events,
+    };
+
+    Ok(serde_json::to_value(response)?)
+}
+
+/// Every stored node info record, including nodes already marked deleted.
+async fn load_stored_nodes() -> Vec<InfoNodeEntity> {
+    repositories().info_nodes.list().await.unwrap_or_default()
+}
+
+/// Node creations and removals that fall inside the window, oldest first.
+fn node_lifecycle_events(
+    nodes: &[InfoNodeEntity],
+    window: &TimeWindow,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Vec<ClusterNodeEventDto> {
+    let in_window = |t: &DateTime<Utc>| *t >= window.start && *t <= window.end;
+    let mut events = Vec::new();
+
+    for node in nodes {
+        let Some(name) = node.node_name.clone() else { continue };
+        let instance_type = parse_node_labels(node).remove(INSTANCE_TYPE_LABEL);
+        let (cpu, memory, storage) = node_capacity_cost(node, 1.0, unit_prices);
+
+        let lifecycle = [
+            (node.creation_timestamp, ClusterNodeEventKind::ScaleUp),
+            (node.deleted_at, ClusterNodeEventKind::ScaleDown),
+        ];
+        for (time, kind) in lifecycle {
+            let Some(time) = time.filter(in_window) else { continue };
+            events.push(ClusterNodeEventDto {
+                time,
+                kind,
+                node_name: name.clone(),
+                instance_type: instance_type.clone(),
+                hourly_cost_usd: cpu + memory + storage,
+            });
+        }
+    }
+
+    events.sort_by(|a, b| a.time.cmp(&b.time).then_with(|| a.node_name.cmp(&b.node_name)));
+    events
+}
+
+/// Attaches each event to the first cost point at or after it, and computes the
+/// point-to-point cost delta. Events after the last point stay unattached.
+fn annotate_cost_points(
+    mut series: Vec<(DateTime<Utc>, f64)>,
+    events: &[ClusterNodeEventDto],
+) -> Vec<ClusterCostEventPointDto> {
+    series.sort_by_key(|(time, _)| *time);
+
+    let mut points = Vec::with_capacity(series.len());
+    let mut prev: Option<(DateTime<Utc>, f64)> = None;
+
+    for (time, total) in series {
+        let after_prev = |t: DateTime<Utc>| match prev {
+            Some((prev_time, _)) => t > prev_time,
+            None => true,
+        };
+        let attached = events
+            .iter()
+            .filter(|e| e.time <= time && after_prev(e.time))
+            .cloned()
+            .collect();
+
+        points.push(ClusterCostEventPointDto {
+            time,
+            total_cost_usd: total,
+            delta_cost_usd: prev.map(|(_, p)| total - p),
+            events: attached,
+        });
+        prev = Some((time, total));
+    }
+
+    points
+}
+
 /// Compute cluster-level resource efficiency (CPU, memory, storage)
 pub async fn get_metric_k8s_cluster_raw_efficiency(
     node_info_list: Vec<InfoNodeEntity>,
@@ -497,62 +962,149 @@ pub async fn get_metric_k8s_cluster_raw_efficiency(
         0.0
     };
 
-    let overall_eff = (cpu_eff + mem_eff + storage_eff) / 3.0;
+    let weights = EfficiencyWeights::configured().resolve(total_cpu_alloc, total_mem_alloc_gb, total_storage_alloc_gb);
+    let overall_eff = weights.overall(cpu_eff, mem_eff, storage_eff);
+
+    // 4️⃣ Build DTO, with requests/limits of every container on these nodes
+    let mut efficiency = MetricRawEfficiencyDto {
+        cpu_efficiency: cpu_eff,
+        memory_efficiency: mem_eff,
+        storage_efficiency: storage_eff,
+        overall_efficiency: overall_eff,
+        overall_weights: weights,
+        total_cpu_allocatable_cores: total_cpu_alloc,
+        total_memory_allocatable_gb: total_mem_alloc_gb,
+        total_storage_allocatable_gb: total_storage_alloc_gb,
+        ..Default::default()
+    };
+    node_baselines(&node_info_list).await.apply(&mut efficiency, summary.summary.avg_cpu_cores, summary.summary.avg_memory_gb);
 
-    // 4️⃣ Build DTO
     let dto = MetricRawEfficiencyResponseDto {
         start: summary.start,
         end: summary.end,
         scope: MetricScope::Cluster,
         granularity: summary.granularity,
-        efficiency: MetricRawEfficiencyDto {
-            cpu_efficiency: cpu_eff,
-            memory_efficiency: mem_eff,
-            storage_efficiency: storage_eff,
-            overall_efficiency: overall_eff,
-            total_cpu_allocatable_cores: total_cpu_alloc,
-            total_memory_allocatable_gb: total_mem_alloc_gb,
-            total_storage_allocatable_gb: total_storage_alloc_gb,
-        },
+        efficiency,
     };
 
     Ok(serde_json::to_value(dto)?)
 }
 
-#[must_use] // Dropping aggregated data is almost certainly unintended.
-pub fn aggregate_cluster_points(
-    points: Vec<UniversalMetricPointDto>,
-) -> Vec<UniversalMetricPointDto> {
-    use std::collections::BTreeMap;
+/// Cluster efficiency per time bucket, using the same usage and allocatable
+/// figures as [`get_metric_k8s_cluster_raw_efficiency`].
+pub async fn get_metric_k8s_cluster_raw_efficiency_trend(
+    node_info_list: Vec<InfoNodeEntity>,
+    node_names: Vec<String>,
+    q: RangeQuery,
+) -> Result<Value> {
+    const NANOCORES_PER_CORE: f64 = 1_000_000_000.0;
+    const BYTES_PER_GIB: f64 = 1_073_741_824.0;
+
+    let raw_value = get_metric_k8s_cluster_raw(node_names, q).await?;
+    let cluster_metrics: MetricGetResponseDto = serde_json::from_value(raw_value)?;
+
+    let cpu_alloc: f64 = node_info_list.iter().map(|n| n.cpu_allocatable_cores.unwrap_or(0) as f64).sum();
+    let mem_alloc_gb = node_info_list
+        .iter()
+        .map(|n| n.memory_allocatable_bytes.unwrap_or(0) as f64)
+        .sum::<f64>()
+        / BYTES_PER_GIB;
+    let storage_alloc_gb = node_info_list
+        .iter()
+        .map(|n| n.ephemeral_storage_allocatable_bytes.unwrap_or(0) as f64)
+        .sum::<f64>()
+        / BYTES_PER_GIB;
+
+    let weights = EfficiencyWeights::configured().resolve(cpu_alloc, mem_alloc_gb, storage_alloc_gb);
+    let ratio = |used: Option<f64>, alloc: f64| match used {
+        Some(v) if alloc > 0.0 && v.is_finite() && v >= 0.0 => (v / alloc).clamp(0.0, 1.0),
+        _ => 0.0,
+    };
+
+    let points: Vec<MetricEfficiencyPointDto> = cluster_metrics
+        .series
+        .iter()
+        .flat_map(|s| s.points.iter())
+        .map(|p| {
+            let cpu = ratio(p.cpu_memory.cpu_usage_nano_cores.map(|v| v / NANOCORES_PER_CORE), cpu_alloc);
+            let mem = ratio(p.cpu_memory.memory_usage_bytes.map(|v| v / BYTES_PER_GIB), mem_alloc_gb);
+            let storage = ratio(
+                p.filesystem.as_ref().and_then(|fs| fs.used_bytes).map(|v| v / BYTES_PER_GIB),
+                storage_alloc_gb,
+            );
+            MetricEfficiencyPointDto {
+                time: p.time,
+                cpu_efficiency: cpu,
+                memory_efficiency: mem,
+                storage_efficiency: storage,
+                overall_efficiency: weights.overall(cpu, mem, storage),
+            }
+        })
+        .collect();
+
+    let overall_efficiency_change = match (points.first(), points.last()) {
+        (Some(first), Some(last)) if points.len() > 1 => Some(last.overall_efficiency - first.overall_efficiency),
+        _ => None,
+    };
+
+    let dto = MetricRawEfficiencyTrendResponseDto {
+        start: cluster_metrics.start,
+        end: cluster_metrics.end,
+        scope: MetricScope::Cluster,
+        granularity: cluster_metrics.granularity,
+        total_cpu_allocatable_cores: cpu_alloc,
+        total_memory_allocatable_gb: mem_alloc_gb,
+        total_storage_allocatable_gb: storage_alloc_gb,
+        overall_efficiency_change,
+        overall_weights: weights,
+        points,
+    };
 
-    let mut buckets: BTreeMap<DateTime<Utc>, Vec<UniversalMetricPointDto>> = BTreeMap::new();
+    Ok(serde_json::to_value(dto)?)
+}
 
-    for p in points {
-        buckets.entry(p.time).or_default().push(p);
+/// Sum of the node samples present at one timestamp; `None` when no node
+/// reported the metric, so a gap stays a gap instead of reading as zero.
+#[derive(Default)]
+struct NodeSum(Option<f64>);
+
+impl NodeSum {
+    fn add(&mut self, v: Option<f64>) {
+        if let Some(v) = v {
+            *self.0.get_or_insert(0.0) += v;
+        }
     }
+}
+
+/// Merges per-node series into one cluster point per `step` (see
+/// [`sample_step`]), counting each node's latest sample in a step once.
+///
+/// Every node metric is additive across nodes: CPU and memory usage (gauges)
+/// and their counters sum to the cluster's usage, like filesystem (inodes
+/// included), storage and network (errors included).
+/// Averaging would shrink cluster usage as nodes are added and compare one
+/// node's usage with the whole cluster's allocatable in efficiency.
+#[must_use] // Dropping aggregated data is almost certainly unintended.
+pub fn aggregate_cluster_points(
+    node_points: Vec<Vec<UniversalMetricPointDto>>,
+    step: Duration,
+) -> Vec<UniversalMetricPointDto> {
+    let buckets = bucket_member_points(node_points, step);
 
     let mut result = Vec::with_capacity(buckets.len());
 
     for (time, bucket) in buckets {
-        // CPU
-        let mut cpu_sum = 0.0;
-        let mut cpu_count = 0.0;
-        let mut cpu_core_sum = 0.0;
-        let mut cpu_core_count = 0.0;
-
-        // Memory
-        let mut mem_sum = 0.0;
-        let mut mem_count = 0.0;
-        let mut mem_working_sum = 0.0;
-        let mut mem_working_count = 0.0;
-        let mut mem_rss_sum = 0.0;
-        let mut mem_rss_count = 0.0;
-        let mut mem_pf_sum = 0.0;
-        let mut mem_pf_count = 0.0;
-
-        // Filesystem SUM
-        let mut fs_used_sum = 0.0;
-        let mut fs_capacity_sum = 0.0;
+        // CPU / memory SUM
+        let mut cpu = NodeSum::default();
+        let mut cpu_core_seconds = NodeSum::default();
+        let mut mem = NodeSum::default();
+        let mut mem_working = NodeSum::default();
+        let mut mem_rss = NodeSum::default();
+        let mut mem_pf = NodeSum::default();
+
+        // Filesystem / storage SUM
+        let mut filesystem: Option<FilesystemMetricDto> = None;
+        let mut storage: Option<StorageMetricDto> = None;
 
         // Network SUM
         let mut rx_sum = 0.0;
@@ -561,38 +1113,16 @@ pub fn aggregate_cluster_points(
         let mut tx_err_sum = 0.0;
 
         for p in &bucket {
-            // CPU AVG
-            if let Some(v) = p.cpu_memory.cpu_usage_nano_cores {
-                cpu_sum += v;
-                cpu_count += 1.0;
-            }
-            if let Some(v) = p.cpu_memory.cpu_usage_core_nano_seconds {
-                cpu_core_sum += v;
-                cpu_core_count += 1.0;
-            }
-            // MEMORY AVG
-            if let Some(v) = p.cpu_memory.memory_usage_bytes {
-                mem_sum += v;
-                mem_count += 1.0;
-            }
-            if let Some(v) = p.cpu_memory.memory_working_set_bytes {
-                mem_working_sum += v;
-                mem_working_count += 1.0;
-            }
-            if let Some(v) = p.cpu_memory.memory_rss_bytes {
-                mem_rss_sum += v;
-                mem_rss_count += 1.0;
-            }
-            if let Some(v) = p.cpu_memory.memory_page_faults {
-                mem_pf_sum += v;
-                mem_pf_count += 1.0;
-            }
+            cpu.add(p.cpu_memory.cpu_usage_nano_cores);
+            cpu_core_seconds.add(p.cpu_memory.cpu_usage_core_nano_seconds);
+            mem.add(p.cpu_memory.memory_usage_bytes);
+            mem_working.add(p.cpu_memory.memory_working_set_bytes);
+            mem_rss.add(p.cpu_memory.memory_rss_bytes);
+            mem_pf.add(p.cpu_memory.memory_page_faults);
 
-            // FILESYSTEM SUM
-            if let Some(fs) = &p.filesystem {
-                fs_used_sum += fs.used_bytes.unwrap_or(0.0);
-                fs_capacity_sum += fs.capacity_bytes.unwrap_or(0.0);
-            }
+            // FILESYSTEM / STORAGE SUM
+            sum_filesystem(&mut filesystem, p.filesystem.as_ref());
+            sum_storage(&mut storage, p.storage.as_ref());
 
             // NETWORK SUM
             if let Some(net) = &p.network {
@@ -606,36 +1136,201 @@ pub fn aggregate_cluster_points(
         result.push(UniversalMetricPointDto {
             time,
             cpu_memory: CommonMetricValuesDto {
-                cpu_usage_nano_cores: (cpu_count > 0.0).then(|| cpu_sum / cpu_count),
-                cpu_usage_core_nano_seconds: (cpu_core_count > 0.0)
-                    .then(|| cpu_core_sum / cpu_core_count),
-                memory_usage_bytes: (mem_count > 0.0).then(|| mem_sum / mem_count),
-                memory_working_set_bytes: (mem_working_count > 0.0)
-                    .then(|| mem_working_sum / mem_working_count),
-                memory_rss_bytes: (mem_rss_count > 0.0)
-                    .then(|| mem_rss_sum / mem_rss_count),
-                memory_page_faults: (mem_pf_count > 0.0)
-                    .then(|| mem_pf_sum / mem_pf_count),
+                cpu_usage_nano_cores: cpu.0,
+                cpu_usage_core_nano_seconds: cpu_core_seconds.0,
+                memory_usage_bytes: mem.0,
+                memory_working_set_bytes: mem_working.0,
+                memory_rss_bytes: mem_rss.0,
+                memory_page_faults: mem_pf.0,
             },
-            filesystem: Some(FilesystemMetricDto {
-                used_bytes: Some(fs_used_sum),
-                capacity_bytes: Some(fs_capacity_sum),
-                inodes_used: None,
-                inodes: None,
-            }),
+            filesystem,
             network: Some(NetworkMetricDto {
                 rx_bytes: Some(rx_sum),
                 tx_bytes: Some(tx_sum),
                 rx_errors: Some(rx_err_sum),
                 tx_errors: Some(tx_err_sum),
             }),
-            storage: None,
+            storage,
             cost: None,
+            saturation: None,
         });
     }
 
     result
 }
 
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn event(hour: u32, kind: ClusterNodeEventKind) -> ClusterNodeEventDto {
+        ClusterNodeEventDto {
+            time: Utc.with_ymd_and_hms(2025, 1, 1, hour, 30, 0).unwrap(),
+            kind,
+            node_name: format!("node-{hour}"),
+            instance_type: None,
+            hourly_cost_usd: 1.0,
+        }
+    }
+
+    #[test]
+    fn events_attach_to_the_next_cost_point() {
+        let at = |h| Utc.with_ymd_and_hms(2025, 1, 1, h, 0, 0).unwrap();
+        let series = vec![(at(2), 3.0), (at(0), 2.0), (at(1), 2.0)];
+        let events = vec![
+            event(0, ClusterNodeEventKind::ScaleUp),
+            event(1, ClusterNodeEventKind::ScaleDown),
+            event(5, ClusterNodeEventKind::ScaleUp),
+        ];
+
+        let points = annotate_cost_points(series, &events);
+
+        assert_eq!(points.len(), 3);
+        assert!(points[0].events.is_empty());
+        assert_eq!(points[0].delta_cost_usd, None);
+        assert_eq!(points[1].events.len(), 1);
+        assert_eq!(points[1].events[0].kind, ClusterNodeEventKind::ScaleUp);
+        assert_eq!(points[1].delta_cost_usd, Some(0.0));
+        assert_eq!(points[2].events[0].kind, ClusterNodeEventKind::ScaleDown);
+        assert_eq!(points[2].delta_cost_usd, Some(1.0));
+    }
+
+    #[test]
+    fn reconcile_reports_what_the_parts_miss_per_component() {
+        let cluster = MetricCostSummaryDto {
+            total_cost_usd: 110.0,
+            cpu_cost_usd: 60.0,
+            memory_cost_usd: 40.0,
+            fixed_cost_usd: 10.0,
+            ..Default::default()
+        };
+        let namespaces = MetricCostSummaryDto {
+            total_cost_usd: 82.0,
+            cpu_cost_usd: 45.0,
+            memory_cost_usd: 30.0,
+            loadbalancer_cost_usd: 2.0,
+            fixed_cost_usd: 5.0,
+            ..Default::default()
+        };
+
+        let part = reconcile_part(&cluster, namespaces);
+
+        assert_eq!(part.discrepancy.total_cost_usd, 28.0);
+        assert_eq!(part.discrepancy.cpu_cost_usd, 15.0);
+        assert_eq!(part.discrepancy.loadbalancer_cost_usd, -2.0);
+        assert_eq!(part.discrepancy.fixed_cost_usd, 5.0);
+        assert!((part.discrepancy_percent.unwrap() - 28.0 / 110.0 * 100.0).abs() < 1e-9);
+        assert_eq!(reconcile_part(&MetricCostSummaryDto::default(), part.summary).discrepancy_percent, None);
+    }
+
+    #[test]
+    fn cluster_usage_is_the_sum_of_node_usage() {
+        let t = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let node = |cpu: Option<f64>, mem: f64| UniversalMetricPointDto {
+            time: t,
+            cpu_memory: CommonMetricValuesDto {
+                cpu_usage_nano_cores: cpu,
+                memory_usage_bytes: Some(mem),
+                ..Default::default()
+            },
+            filesystem: Some(FilesystemMetricDto {
+                inodes_used: Some(100.0),
+                inodes: Some(1000.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let points = aggregate_cluster_points(
+            vec![vec![node(Some(2e9), 4.0)], vec![node(Some(1e9), 6.0)], vec![node(None, 2.0)]],
+            Duration::hours(1),
+        );
+
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].cpu_memory.cpu_usage_nano_cores, Some(3e9));
+        assert_eq!(points[0].cpu_memory.memory_usage_bytes, Some(12.0));
+        assert_eq!(points[0].cpu_memory.memory_rss_bytes, None);
+        let fs = points[0].filesystem.as_ref().unwrap();
+        assert_eq!((fs.inodes_used, fs.inodes), (Some(300.0), Some(3000.0)));
+        assert_eq!(fs.used_bytes, None);
+    }
+
+    #[test]
+    fn one_node_restart_keeps_the_interval_and_counts_its_traffic() {
+        let at = |h| Utc.with_ymd_and_hms(2025, 1, 1, h, 0, 0).unwrap();
+        let node = |samples: &[(u32, f64, f64)]| -> Vec<UniversalMetricPointDto> {
+            samples
+                .iter()
+                .map(|&(h, rx, tx)| UniversalMetricPointDto {
+                    time: at(h),
+                    network: Some(NetworkMetricDto {
+                        rx_bytes: Some(rx),
+                        tx_bytes: Some(tx),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })
+                .collect()
+        };
+
+        // node-b restarts at 02:00; its counters start over from 0
+        let increases = cluster_network_increases(
+            &[
+                node(&[(0, 1000.0, 500.0), (1, 1100.0, 600.0), (2, 1200.0, 700.0)]),
+                node(&[(0, 5000.0, 4000.0), (1, 5300.0, 4100.0), (2, 50.0, 30.0)]),
+            ],
+            Duration::hours(1),
+        );
+
+        assert_eq!(increases.len(), 2);
+        assert_eq!(increases[&at(1)], 200.0 + 400.0);
+        assert_eq!(increases[&at(2)], 200.0 + 80.0);
+    }
+
+    #[test]
+    fn nodes_on_another_phase_share_a_point() {
+        let node = |h: u32, sec: u32, cpu: f64| UniversalMetricPointDto {
+            time: Utc.with_ymd_and_hms(2025, 1, 1, h, 0, sec).unwrap(),
+            cpu_memory: CommonMetricValuesDto {
+                cpu_usage_nano_cores: Some(cpu),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
 
+        let points = aggregate_cluster_points(
+            vec![vec![node(0, 2, 1e9), node(1, 0, 1e9)], vec![node(0, 47, 2e9), node(1, 13, 1e9)]],
+            Duration::hours(1),
+        );
 
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].time, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(points[0].cpu_memory.cpu_usage_nano_cores, Some(3e9));
+        assert_eq!(points[1].cpu_memory.cpu_usage_nano_cores, Some(2e9));
+    }
+
+    #[test]
+    fn two_samples_of_one_node_in_a_step_count_once() {
+        let at = |min: u32| Utc.with_ymd_and_hms(2025, 1, 1, 0, min, 0).unwrap();
+        let sample = |min: u32, cpu: f64| UniversalMetricPointDto {
+            time: at(min),
+            cpu_memory: CommonMetricValuesDto {
+                cpu_usage_nano_cores: Some(cpu),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // node-a was scraped at :00 and again at :30 after an interval change
+        let points = aggregate_cluster_points(
+            vec![vec![sample(30, 2e9), sample(0, 1e9)], vec![sample(0, 4e9)]],
+            Duration::hours(1),
+        );
+
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].cpu_memory.cpu_usage_nano_cores, Some(2e9 + 4e9));
+    }
+}
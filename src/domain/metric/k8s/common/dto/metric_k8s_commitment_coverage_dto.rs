@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::info::fixed::commitment::commitment_entity::CommitmentKind;
+
+/// Node compute spend split into the part savings plans / reserved instances
+/// paid for and the part billed on demand.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricCommitmentCoverageDto {
+    /// CPU + memory cost of the nodes in the summary
+    pub compute_cost_usd: f64,
+    /// Spend absorbed by commitments
+    pub covered_cost_usd: f64,
+    /// Spend left over at on-demand rates
+    pub on_demand_cost_usd: f64,
+    /// Committed spend over the window, used or not
+    pub commitment_usd: f64,
+    /// Committed spend no node used
+    pub unused_commitment_usd: f64,
+    /// `covered_cost_usd` as a share of `compute_cost_usd`
+    pub coverage_percent: f64,
+    /// `covered_cost_usd` as a share of `commitment_usd`
+    pub utilization_percent: f64,
+    pub commitments: Vec<MetricCommitmentUsageDto>,
+}
+
+/// One commitment's use over the window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCommitmentUsageDto {
+    pub id: String,
+    pub name: String,
+    pub kind: CommitmentKind,
+    pub commitment_usd: f64,
+    pub used_usd: f64,
+    pub utilization_percent: f64,
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryDto;
+
+/// One object's cost split across its parts (e.g. a pod across its containers)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCostBreakdownResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub scope: MetricScope,
+    pub target: Option<String>,             // Pod UID
+    pub granularity: MetricGranularity,
+    /// Sum of all items
+    pub summary: MetricCostSummaryDto,
+    /// Most expensive first
+    pub items: Vec<MetricCostBreakdownItemDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCostBreakdownItemDto {
+    /// Series key, e.g. `<pod_uid>-<container_name>`
+    pub key: String,
+    pub name: String,
+    /// Share of the total cost, 0–100
+    pub share_percent: f64,
+    pub summary: MetricCostSummaryDto,
+}
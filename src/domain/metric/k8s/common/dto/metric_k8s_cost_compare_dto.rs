@@ -0,0 +1,33 @@
+use serde::{Serialize, Deserialize};
+use crate::domain::metric::k8s::common::dto::MetricScope;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryResponseDto;
+
+/// Cost summaries for two windows and how the current one differs from the baseline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCostCompareResponseDto {
+    pub scope: MetricScope,
+    pub target: Option<String>,
+    pub current: MetricCostSummaryResponseDto,
+    pub previous: MetricCostSummaryResponseDto,
+    pub delta: MetricCostDeltaDto,
+}
+
+/// `current - previous` per resource
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricCostDeltaDto {
+    pub total: MetricCostDeltaValueDto,
+    pub cpu: MetricCostDeltaValueDto,
+    pub memory: MetricCostDeltaValueDto,
+    pub ephemeral_storage: MetricCostDeltaValueDto,
+    pub persistent_storage: MetricCostDeltaValueDto,
+    pub network: MetricCostDeltaValueDto,
+    pub loadbalancer: MetricCostDeltaValueDto,
+    pub fixed: MetricCostDeltaValueDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricCostDeltaValueDto {
+    pub absolute_usd: f64,
+    /// `None` when the baseline cost is zero
+    pub percent: Option<f64>,
+}
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use crate::domain::metric::k8s::common::dto::metric_k8s_commitment_coverage_dto::MetricCommitmentCoverageDto;
 use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
 
 /// Summarized cost view for any Kubernetes metric scope (Cluster, Node, Pod, Container)
@@ -11,6 +12,10 @@ pub struct MetricCostSummaryResponseDto {
     pub target: Option<String>,             // Node / Pod / Container name
     pub granularity: MetricGranularity,
     pub summary: MetricCostSummaryDto,
+    /// Savings plan / reserved instance coverage of node compute spend
+    /// (cluster summaries, while a commitment is active)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commitments: Option<MetricCommitmentCoverageDto>,
 }
 
 /// Aggregated cost breakdown (includes PV and network)
@@ -33,4 +38,12 @@ pub struct MetricCostSummaryDto {
 
     /// Network transfer cost in USD
     pub network_cost_usd: f64,
+
+    /// Cloud load balancers of `LoadBalancer` Services (namespace summaries only)
+    #[serde(default)]
+    pub loadbalancer_cost_usd: f64,
+
+    /// Amortized fixed line items (cluster summaries, and namespace summaries for allocated items)
+    #[serde(default)]
+    pub fixed_cost_usd: f64,
 }
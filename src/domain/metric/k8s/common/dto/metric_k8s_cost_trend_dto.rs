@@ -9,6 +9,11 @@ pub struct MetricCostTrendPointDto {
     pub cpu_cost_usd: f64,
     pub memory_cost_usd: f64,
     pub storage_cost_usd: f64,
+    /// Pods reporting in the bucket (deployment trends only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_per_replica_usd: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +25,9 @@ pub struct MetricCostTrendResponseDto {
     pub granularity: MetricGranularity,
     pub trend: MetricCostTrendDto,
     pub points: Vec<MetricCostTrendPointDto>,
+    /// Replica overlay (deployment trends only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<MetricReplicaTrendDto>,
 }
 
 
@@ -39,3 +47,27 @@ pub struct MetricCostTrendDto {
     /// Optional next predicted cost point (simple extrapolation)
     pub predicted_next_cost_usd: Option<f64>,
 }
+
+/// Splits a cost change into scaling (more replicas) and per-replica growth.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricReplicaTrendDto {
+    pub start_replicas: u32,
+    pub end_replicas: u32,
+    pub max_replicas: u32,
+    pub replica_growth_percent: f64,
+
+    pub start_cost_per_replica_usd: f64,
+    pub end_cost_per_replica_usd: f64,
+    pub cost_per_replica_growth_percent: f64,
+
+    /// `scaling` when the replica count moved more than the cost per replica
+    /// (in log terms), `per_replica` otherwise; `None` without both endpoints
+    pub driver: Option<CostGrowthDriver>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CostGrowthDriver {
+    Scaling,
+    PerReplica,
+}
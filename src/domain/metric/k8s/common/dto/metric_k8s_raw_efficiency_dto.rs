@@ -21,11 +21,67 @@ pub struct MetricRawEfficiencyDto {
     pub memory_efficiency: f64,
     /// Storage utilization ratio (0.0–1.0)
     pub storage_efficiency: f64,
-    /// Overall mean efficiency across all resources
+    /// Weighted mean of the three ratios, see `overall_weights`
     pub overall_efficiency: f64,
+    /// Weights behind `overall_efficiency`; cost share of each resource by default
+    #[serde(default)]
+    pub overall_weights: EfficiencyWeightsDto,
 
     /// Optional details for reference
     pub total_cpu_allocatable_cores: f64,
     pub total_memory_allocatable_gb: f64,
     pub total_storage_allocatable_gb: f64,
+
+    /// Average usage ÷ requests; above 1.0 means running over what was requested
+    #[serde(default)]
+    pub usage_to_requests: ResourceRatiosDto,
+    /// Average usage ÷ limits; close to 1.0 means throttling or OOM kills are near
+    #[serde(default)]
+    pub usage_to_limits: ResourceRatiosDto,
+    /// Requests ÷ allocatable capacity (quota for namespaces); how much is reserved
+    #[serde(default)]
+    pub requests_to_allocatable: ResourceRatiosDto,
+}
+
+/// Per-resource weights of the overall efficiency, summing to 1.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct EfficiencyWeightsDto {
+    pub cpu: f64,
+    pub memory: f64,
+    pub storage: f64,
+}
+
+/// One ratio per resource, unclamped. `None` when the denominator is unknown,
+/// e.g. no requests set, or a container without a limit.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ResourceRatiosDto {
+    pub cpu: Option<f64>,
+    pub memory: Option<f64>,
+}
+
+/// Per-bucket efficiency over the window, for charting utilization over time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricRawEfficiencyTrendResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub scope: MetricScope,
+    pub granularity: MetricGranularity,
+    pub total_cpu_allocatable_cores: f64,
+    pub total_memory_allocatable_gb: f64,
+    pub total_storage_allocatable_gb: f64,
+    /// Last minus first `overall_efficiency`; positive means utilization improved
+    pub overall_efficiency_change: Option<f64>,
+    /// Weights behind every point's `overall_efficiency`
+    #[serde(default)]
+    pub overall_weights: EfficiencyWeightsDto,
+    pub points: Vec<MetricEfficiencyPointDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricEfficiencyPointDto {
+    pub time: DateTime<Utc>,
+    pub cpu_efficiency: f64,
+    pub memory_efficiency: f64,
+    pub storage_efficiency: f64,
+    pub overall_efficiency: f64,
 }
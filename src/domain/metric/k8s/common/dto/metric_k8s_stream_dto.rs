@@ -0,0 +1,52 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use crate::domain::metric::k8s::common::dto::{MetricGetResponseDto, MetricGranularity, MetricSeriesDto};
+
+/// First line of an NDJSON raw-metric stream; every following line is one `MetricSeriesDto`.
+/// Same fields as `MetricGetResponseDto` minus `series`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricStreamHeaderDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub scope: String,
+    pub target: Option<String>,
+    pub granularity: MetricGranularity,
+    pub total: Option<usize>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// Series produced lazily, one object at a time, so memory stays bounded by the
+/// largest single series instead of the whole response.
+pub struct MetricSeriesStream {
+    pub header: MetricStreamHeaderDto,
+    pub series: Box<dyn Iterator<Item = Result<MetricSeriesDto>> + Send>,
+}
+
+impl MetricSeriesStream {
+    pub fn new(
+        header: MetricStreamHeaderDto,
+        series: impl Iterator<Item = Result<MetricSeriesDto>> + Send + 'static,
+    ) -> Self {
+        Self { header, series: Box::new(series) }
+    }
+
+    /// Streams an already built response, e.g. when the page order needs every series.
+    pub fn from_response(response: MetricGetResponseDto) -> Self {
+        let header = MetricStreamHeaderDto {
+            start: response.start,
+            end: response.end,
+            scope: response.scope,
+            target: response.target,
+            granularity: response.granularity,
+            total: response.total,
+            limit: response.limit,
+            offset: response.offset,
+            next_cursor: response.next_cursor,
+        };
+        Self::new(header, response.series.into_iter().map(Ok))
+    }
+}
@@ -1,7 +1,11 @@
 pub mod metric_k8s_cost_summary_dto;
+pub mod metric_k8s_cost_breakdown_dto;
+pub mod metric_k8s_cost_compare_dto;
 pub mod metric_k8s_cost_trend_dto;
 pub mod metric_k8s_raw_summary_dto;
 pub mod metric_k8s_raw_efficiency_dto;
+pub mod metric_k8s_stream_dto;
+pub mod metric_k8s_commitment_coverage_dto;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricGetResponseDto {
@@ -16,6 +20,26 @@ pub struct MetricGetResponseDto {
     pub total: Option<usize>,  // total points in range (not just returned count)
     pub limit: Option<usize>,  // how many points returned max
     pub offset: Option<usize>, // starting index of current page
+
+    /// Cursor for the next page (pod and node lists); `None` on the last page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+
+    /// Objects whose metrics failed to load and are missing from `series`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<MetricLoadWarningDto>,
+
+    /// `true` when `warnings` is non-empty, so totals may be understated.
+    #[serde(default)]
+    pub partial: bool,
+}
+
+/// An object left out of a response because its metrics could not be read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricLoadWarningDto {
+    /// Series key of the missing object (node name, pod UID, ...)
+    pub key: String,
+    pub reason: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,20 +62,26 @@ pub struct MetricSeriesDto {
     pub name: String,
     pub scope: MetricScope,
     pub points: Vec<UniversalMetricPointDto>,
+    /// Hours the object reported metrics within the window.
+    /// Namespace / deployment series sum their pods' hours (pod-hours); cluster has none.
     pub running_hours: Option<f64>,
     pub cost_summary: Option<CostMetricDto>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MetricGranularity {
     Minute,
     Hour,
     Day,
+    /// Calendar months (UTC). Never picked automatically; request it explicitly
+    /// for multi-year history.
+    Month,
 }
 
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UniversalMetricPointDto {
@@ -70,6 +100,24 @@ pub struct UniversalMetricPointDto {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cost: Option<CostMetricDto>, // <-- add this
+
+    /// Node points only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saturation: Option<NodeSaturationMetricDto>,
+}
+
+/// Allocatable capacity, what's left of it, and pressure conditions of a node.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NodeSaturationMetricDto {
+    pub cpu_allocatable_nano_cores: Option<f64>,
+    pub memory_allocatable_bytes: Option<f64>,
+    /// Allocatable minus usage (memory: working set); negative when usage
+    /// runs into the system reservation.
+    pub cpu_headroom_nano_cores: Option<f64>,
+    pub memory_headroom_bytes: Option<f64>,
+    /// Share of the point's window the condition was `True`, `0..=100`.
+    pub memory_pressure_percent: Option<f64>,
+    pub disk_pressure_percent: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -116,6 +164,11 @@ pub enum MetricScope {
     Container,
     Namespace,
     Deployment,
+    Job,
+    #[serde(rename = "cronjob")]
+    CronJob,
+    #[serde(rename = "nodepool")]
+    NodePool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
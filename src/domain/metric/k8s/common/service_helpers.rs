@@ -1,28 +1,44 @@
 use anyhow::{anyhow, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, DurationRound, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use serde_json::{json, Value};
 
-use crate::api::dto::metrics_dto::RangeQuery;
+use crate::api::dto::metrics_dto::{CostBasis, CostCompareQuery, GranularityRejection, RangeQuery, RelativeRange};
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::domain::metric::k8s::common::dto::{
     CommonMetricValuesDto, CostMetricDto, FilesystemMetricDto, MetricGetResponseDto, MetricGranularity,
-    MetricScope, MetricSeriesDto, UniversalMetricPointDto,
+    MetricScope, MetricSeriesDto, StorageMetricDto, UniversalMetricPointDto,
+};
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_breakdown_dto::{
+    MetricCostBreakdownItemDto, MetricCostBreakdownResponseDto,
+};
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_compare_dto::{
+    MetricCostCompareResponseDto, MetricCostDeltaDto, MetricCostDeltaValueDto,
 };
 use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{
     MetricCostSummaryDto, MetricCostSummaryResponseDto,
 };
 use crate::domain::metric::k8s::common::dto::metric_k8s_cost_trend_dto::{MetricCostTrendDto, MetricCostTrendPointDto, MetricCostTrendResponseDto};
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::{
-    MetricRawEfficiencyDto, MetricRawEfficiencyResponseDto,
+    MetricRawEfficiencyDto, MetricRawEfficiencyResponseDto, ResourceRatiosDto,
 };
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::{
     MetricRawSummaryDto, MetricRawSummaryResponseDto,
 };
+use crate::domain::metric::k8s::common::util::k8s_efficiency_weights::EfficiencyWeights;
 use crate::domain::metric::k8s::common::util::k8s_metric_determine_granularity::determine_granularity;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
 use tracing::log::warn;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::repositories::repositories;
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_api_repository_trait::InfoVirtualNodePriceApiRepository;
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_entity::VirtualNodeRate;
+use crate::core::persistence::info::fixed::virtual_node_price::info_virtual_node_price_repository::InfoVirtualNodePriceRepository;
+use crate::domain::metric::k8s::node_pool::service::virtual_node_provider;
 use crate::core::util::cost_util::CostUtil;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
 
 pub const BYTES_PER_GB: f64 = 1_073_741_824.0;
 
@@ -31,6 +47,8 @@ pub struct TimeWindow {
     pub start: DateTime<Utc>,
     pub end: DateTime<Utc>,
     pub granularity: MetricGranularity,
+    /// Offset of the query's `tz`; day boundaries are local midnights in this offset.
+    pub offset: FixedOffset,
 }
 
 // Resolves a time window from a query by:
@@ -40,19 +58,38 @@ pub struct TimeWindow {
 //    - Use the query granularity if valid
 //    - Otherwise fall back to an automatically determined granularity
 pub fn resolve_time_window(q: &RangeQuery) -> TimeWindow {
-    // Start time:
-    // - Use q.start if provided
-    // - Otherwise default to 1 hour ago
-    let start = q.start
-        .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc))
-        .unwrap_or(Utc::now() - chrono::Duration::hours(1));
-
-    // End time:
-    // - Use q.end if provided
-    // - Otherwise default to now
-    let end = q.end
-        .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc))
-        .unwrap_or(Utc::now());
+    // Offset:
+    // - Use q.tz if provided and recognised
+    // - Otherwise (or if unknown) UTC
+    let offset = match q.tz.as_deref() {
+        Some(tz) => parse_utc_offset(tz).unwrap_or_else(|| {
+            warn!("Unknown tz {:?}, falling back to UTC", tz);
+            utc_offset()
+        }),
+        None => utc_offset(),
+    };
+
+    let (start, end) = match q.range {
+        // Named range: resolved against now, start/end are ignored
+        Some(range) => resolve_relative_range(range, Utc::now(), offset),
+        None => {
+            // Start time:
+            // - Use q.start (local to the offset) if provided
+            // - Otherwise default to 1 hour ago
+            let start = q.start
+                .map(|dt| local_to_utc(dt, offset))
+                .unwrap_or(Utc::now() - chrono::Duration::hours(1));
+
+            // End time:
+            // - Use q.end (local to the offset) if provided
+            // - Otherwise default to now
+            let end = q.end
+                .map(|dt| local_to_utc(dt, offset))
+                .unwrap_or(Utc::now());
+
+            (start, end)
+        }
+    };
 
     // Granularity:
     // - If provided in the query, validate it
@@ -74,9 +111,112 @@ pub fn resolve_time_window(q: &RangeQuery) -> TimeWindow {
         start,
         end,
         granularity,
+        offset,
+    }
+}
+
+fn utc_offset() -> FixedOffset {
+    FixedOffset::east_opt(0).unwrap()
+}
+
+fn local_to_utc(dt: NaiveDateTime, offset: FixedOffset) -> DateTime<Utc> {
+    offset
+        .from_local_datetime(&dt)
+        .single()
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|| dt.and_utc())
+}
+
+/// Start/end of a named range. Month boundaries are local midnights in `offset`;
+/// `lastMonth` ends one second before the current month so its last day counts as full.
+pub fn resolve_relative_range(
+    range: RelativeRange,
+    now: DateTime<Utc>,
+    offset: FixedOffset,
+) -> (DateTime<Utc>, DateTime<Utc>) {
+    let month_start = |date: NaiveDate| {
+        local_to_utc(date.with_day(1).unwrap().and_hms_opt(0, 0, 0).unwrap(), offset)
+    };
+    let today = now.with_timezone(&offset).date_naive();
+
+    match range {
+        RelativeRange::Last24h => (now - chrono::Duration::hours(24), now),
+        RelativeRange::Last7d => (now - chrono::Duration::days(7), now),
+        RelativeRange::MonthToDate => (month_start(today), now),
+        RelativeRange::LastMonth => {
+            let this_month = month_start(today);
+            let previous = today.with_day(1).unwrap().pred_opt().unwrap();
+            (month_start(previous), this_month - chrono::Duration::seconds(1))
+        }
     }
 }
 
+/// Zone names accepted by `tz`. Only zones without daylight saving, since the
+/// offset is fixed for the whole window.
+const FIXED_ZONES: &[(&str, i32)] = &[
+    ("UTC", 0),
+    ("Etc/UTC", 0),
+    ("GMT", 0),
+    ("Asia/Seoul", 9 * 3600),
+    ("KST", 9 * 3600),
+    ("Asia/Tokyo", 9 * 3600),
+    ("JST", 9 * 3600),
+    ("Asia/Shanghai", 8 * 3600),
+    ("Asia/Hong_Kong", 8 * 3600),
+    ("Asia/Singapore", 8 * 3600),
+    ("Asia/Kolkata", 5 * 3600 + 1800),
+];
+
+/// Parses a `tz` value: a zone from [`FIXED_ZONES`], or an offset like `+09:00`,
+/// `+0900`, `-5`, `UTC+9`.
+pub fn parse_utc_offset(tz: &str) -> Option<FixedOffset> {
+    let tz = tz.trim();
+    if let Some((_, secs)) = FIXED_ZONES.iter().find(|(name, _)| name.eq_ignore_ascii_case(tz)) {
+        return FixedOffset::east_opt(*secs);
+    }
+
+    let raw = tz
+        .strip_prefix("UTC")
+        .or_else(|| tz.strip_prefix("GMT"))
+        .unwrap_or(tz);
+    let (sign, rest) = match raw.chars().next()? {
+        '+' => (1, &raw[1..]),
+        '-' => (-1, &raw[1..]),
+        _ => return None,
+    };
+
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 14 || minutes >= 60 {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Rejects an explicit `granularity` that the window is too long for, suggesting
+/// the one [`resolve_time_window`] would pick. Queries without a granularity pass.
+pub fn check_granularity(q: &RangeQuery) -> Result<(), GranularityRejection> {
+    let Some(requested) = q.granularity.clone() else {
+        return Ok(());
+    };
+
+    let window = resolve_time_window(&RangeQuery { granularity: None, ..q.clone() });
+    match validate_granularity(window.start, window.end, requested.clone()) {
+        Ok(()) => Ok(()),
+        Err(message) => Err(GranularityRejection {
+            requested,
+            suggested: window.granularity,
+            range_hours: (window.end - window.start).num_seconds() as f64 / 3600.0,
+            message,
+        }),
+    }
+}
 
 pub fn validate_granularity(
     start: DateTime<Utc>,
@@ -96,7 +236,7 @@ pub fn validate_granularity(
                 return Err("hour granularity cannot be used for ranges > 3 days".into());
             }
         }
-        MetricGranularity::Day => { /* always allowed */ }
+        MetricGranularity::Day | MetricGranularity::Month => { /* always allowed */ }
     }
 
     Ok(())
@@ -184,15 +324,130 @@ pub fn build_raw_summary_value(
     Ok(serde_json::to_value(dto)?)
 }
 
-fn granularity_interval_hours(granularity: &MetricGranularity) -> f64 {
+/// Hours covered by one minute-granularity row, i.e. the configured scrape interval.
+pub fn minute_row_hours() -> f64 {
+    let settings = InfoSettingFsAdapter::new().read().unwrap_or_default();
+    settings.effective_scrape_interval_sec() as f64 / 3600.0
+}
+
+/// Hours covered by the month row stamped `end`, i.e. the length of the month before it.
+pub fn month_row_hours(end: DateTime<Utc>) -> f64 {
+    end.checked_sub_months(chrono::Months::new(1))
+        .map(|start| (end - start).num_hours() as f64)
+        .unwrap_or(0.0)
+}
+
+/// Start of the `granularity` bucket an hour row falls in. Rows are stamped at the
+/// end of their hour, so the row at local midnight belongs to the previous day.
+pub fn hour_row_bucket_start(time: DateTime<Utc>, granularity: &MetricGranularity, offset: FixedOffset) -> DateTime<Utc> {
+    let local_day = (time - chrono::Duration::hours(1)).with_timezone(&offset).date_naive();
+    let day = match granularity {
+        MetricGranularity::Minute | MetricGranularity::Hour => return time,
+        MetricGranularity::Day => local_day,
+        MetricGranularity::Month => NaiveDate::from_ymd_opt(local_day.year(), local_day.month(), 1).unwrap_or(local_day),
+    };
+    offset
+        .from_local_datetime(&day.and_hms_opt(0, 0, 0).unwrap_or_default())
+        .single()
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or(time)
+}
+
+/// Sampling step of `granularity`'s rows, for grouping samples across nodes or
+/// pods. One collection tick stamps every member with the same time, so such
+/// rows group as they are; the step lines up rows on another phase, e.g.
+/// after a scrape interval change. Minute rows step by the scrape interval;
+/// hour, day and month rows sit on hour boundaries (a day window's partial
+/// days are hour rows), so they are only snapped to the hour.
+///
+/// Reads the settings for minute rows; compute it once per request and pass
+/// it to the aggregators.
+pub fn sample_step(granularity: &MetricGranularity) -> chrono::Duration {
+    match granularity {
+        MetricGranularity::Minute => {
+            let settings = InfoSettingFsAdapter::new().read().unwrap_or_default();
+            chrono::Duration::seconds(settings.effective_scrape_interval_sec().max(1) as i64)
+        }
+        MetricGranularity::Hour | MetricGranularity::Day | MetricGranularity::Month => chrono::Duration::hours(1),
+    }
+}
+
+/// Start of the `step` (see [`sample_step`]) that `time` falls in.
+pub fn align_to_step(time: DateTime<Utc>, step: chrono::Duration) -> DateTime<Utc> {
+    time.duration_trunc(step).unwrap_or(time)
+}
+
+/// Groups member series (one per node or pod) into buckets of `step`, keeping
+/// only each member's latest sample per bucket. Two samples of one member can
+/// share a bucket when its rows are on another phase; summing both would
+/// count that member twice.
+pub fn bucket_member_points(
+    members: Vec<Vec<UniversalMetricPointDto>>,
+    step: chrono::Duration,
+) -> BTreeMap<DateTime<Utc>, Vec<UniversalMetricPointDto>> {
+    let mut buckets: BTreeMap<DateTime<Utc>, Vec<UniversalMetricPointDto>> = BTreeMap::new();
+
+    for points in members {
+        let mut latest: BTreeMap<DateTime<Utc>, UniversalMetricPointDto> = BTreeMap::new();
+        for p in points {
+            let bucket = align_to_step(p.time, step);
+            if latest.get(&bucket).is_none_or(|kept| kept.time <= p.time) {
+                latest.insert(bucket, p);
+            }
+        }
+        for (bucket, p) in latest {
+            buckets.entry(bucket).or_default().push(p);
+        }
+    }
+
+    buckets
+}
+
+/// Adds a member's filesystem into an aggregate point's, field by field. Fields
+/// no member reports stay `None` rather than reading as zero.
+pub fn sum_filesystem(acc: &mut Option<FilesystemMetricDto>, fs: Option<&FilesystemMetricDto>) {
+    let Some(fs) = fs else { return };
+    let out = acc.get_or_insert_with(FilesystemMetricDto::default);
+    let sum = |slot: &mut Option<f64>, v: Option<f64>| {
+        if let Some(v) = v {
+            *slot = Some(slot.unwrap_or(0.0) + v);
+        }
+    };
+    sum(&mut out.used_bytes, fs.used_bytes);
+    sum(&mut out.capacity_bytes, fs.capacity_bytes);
+    sum(&mut out.inodes_used, fs.inodes_used);
+    sum(&mut out.inodes, fs.inodes);
+}
+
+/// Adds a member's ephemeral and persistent storage into an aggregate point's,
+/// keeping the two apart.
+pub fn sum_storage(acc: &mut Option<StorageMetricDto>, storage: Option<&StorageMetricDto>) {
+    let Some(storage) = storage else { return };
+    let out = acc.get_or_insert_with(StorageMetricDto::default);
+    sum_filesystem(&mut out.ephemeral, storage.ephemeral.as_ref());
+    sum_filesystem(&mut out.persistent, storage.persistent.as_ref());
+}
+
+/// Running hours of an aggregate (namespace, deployment): the members' hours summed,
+/// i.e. pod-hours. `None` when no member reports any.
+pub fn sum_running_hours(series: &[MetricSeriesDto]) -> Option<f64> {
+    series
+        .iter()
+        .filter_map(|s| s.running_hours)
+        .reduce(|a, b| a + b)
+}
+
+pub(crate) fn granularity_interval_hours(granularity: &MetricGranularity) -> f64 {
     match granularity {
-        MetricGranularity::Minute => 1.0 / 60.0,
+        MetricGranularity::Minute => minute_row_hours(),
         MetricGranularity::Hour => 1.0,
         MetricGranularity::Day => 24.0,
+        // Average month; actual month rows are spaced by their real length
+        MetricGranularity::Month => 730.0,
     }
 }
 
-fn point_interval_hours(points: &[UniversalMetricPointDto], idx: usize, default: f64) -> f64 {
+pub(crate) fn point_interval_hours(points: &[UniversalMetricPointDto], idx: usize, default: f64) -> f64 {
     if let Some(next) = points.get(idx + 1) {
         let delta_seconds = next.time.signed_duration_since(points[idx].time).num_seconds();
         if delta_seconds > 0 {
@@ -225,16 +480,40 @@ fn point_interval_hours_from_timestamps(
         default_interval_hours
     }
 }
+/// Requested CPU cores and memory bytes of the object behind one series.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResourceRequests {
+    pub cpu_cores: f64,
+    pub memory_bytes: f64,
+}
+
 pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPriceEntity) {
+    apply_costs_with_basis(response, unit_prices, CostBasis::Usage, &HashMap::new());
+}
+
+/// Like [`apply_costs`], but prices CPU and memory on `basis`. `requests` is keyed by
+/// series key; series without an entry fall back to usage.
+pub fn apply_costs_with_basis(
+    response: &mut MetricGetResponseDto,
+    unit_prices: &InfoUnitPriceEntity,
+    basis: CostBasis,
+    requests: &HashMap<String, ResourceRequests>,
+) {
     let default_interval_hours = granularity_interval_hours(&response.granularity);
 
     for series in &mut response.series {
+        let requested = match basis {
+            CostBasis::Usage => None,
+            _ => requests.get(&series.key).copied(),
+        };
+
         // Precompute timestamps (avoids borrow conflicts)
         let timestamps: Vec<_> = series.points.iter().map(|p| p.time).collect();
 
         for (idx, point) in series.points.iter_mut().enumerate() {
             let interval_hours =
                 point_interval_hours_from_timestamps(&timestamps, idx, default_interval_hours);
+            let unit_prices = unit_prices.at(point.time);
 
             // ---------------------------
             // CPU (usage-based)
@@ -258,6 +537,21 @@ pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPr
             let memory_cost_usd = memory_bytes_for_cost
                 .map(|bytes| CostUtil::compute_memory_cost(bytes, interval_hours, unit_prices));
 
+            // ---------------------------
+            // REQUEST BASIS
+            // ---------------------------
+            let (cpu_cost_usd, memory_cost_usd) = match requested {
+                Some(req) => (
+                    priced_on(basis, cpu_cost_usd, req.cpu_cores * interval_hours * unit_prices.cpu_core_hour),
+                    priced_on(
+                        basis,
+                        memory_cost_usd,
+                        CostUtil::compute_memory_cost(req.memory_bytes, interval_hours, unit_prices),
+                    ),
+                ),
+                None => (cpu_cost_usd, memory_cost_usd),
+            };
+
             // ---------------------------
             // STORAGE (gauge * time)
             // ---------------------------
@@ -307,6 +601,72 @@ pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPr
     }
 }
 
+/// Virtual node rate of each node in `node_names` that is a virtual node
+/// (Fargate, ACI, ...); nodes with real capacity are left out.
+pub async fn virtual_node_rates<'a>(node_names: impl IntoIterator<Item = &'a str>) -> Result<HashMap<String, VirtualNodeRate>> {
+    let repo = repositories().info_nodes;
+    let mut providers: HashMap<String, String> = HashMap::new();
+    let mut seen = HashSet::new();
+    for name in node_names {
+        if !seen.insert(name) {
+            continue;
+        }
+        if let Some(provider) = repo.read(name).await.ok().as_ref().and_then(virtual_node_provider) {
+            providers.insert(name.to_string(), provider);
+        }
+    }
+    if providers.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let prices = InfoVirtualNodePriceRepository::new().read()?;
+    Ok(providers
+        .into_iter()
+        .map(|(node, provider)| (node, prices.rate_for(&provider)))
+        .collect())
+}
+
+/// Re-prices CPU and memory of series on virtual nodes by their requests, whatever
+/// the cost basis: such nodes bill requested vCPU/GB-hours, not a share of capacity.
+/// `rates` and `requests` are keyed by series key; storage and network are kept.
+pub fn apply_virtual_node_costs(
+    response: &mut MetricGetResponseDto,
+    rates: &HashMap<String, VirtualNodeRate>,
+    requests: &HashMap<String, ResourceRequests>,
+) {
+    let default_interval_hours = granularity_interval_hours(&response.granularity);
+
+    for series in &mut response.series {
+        let Some(rate) = rates.get(&series.key) else { continue };
+        let req = requests.get(&series.key).copied().unwrap_or_default();
+        let timestamps: Vec<_> = series.points.iter().map(|p| p.time).collect();
+
+        for (idx, point) in series.points.iter_mut().enumerate() {
+            let Some(cost) = point.cost.as_mut() else { continue };
+            let interval_hours =
+                point_interval_hours_from_timestamps(&timestamps, idx, default_interval_hours);
+
+            let cpu = req.cpu_cores * interval_hours * rate.vcpu_hour;
+            let memory = CostUtil::bytes_to_gb_hours(req.memory_bytes, interval_hours) * rate.memory_gb_hour;
+            let other = cost.total_cost_usd.unwrap_or(0.0)
+                - cost.cpu_cost_usd.unwrap_or(0.0)
+                - cost.memory_cost_usd.unwrap_or(0.0);
+
+            cost.cpu_cost_usd = Some(cpu);
+            cost.memory_cost_usd = Some(memory);
+            cost.total_cost_usd = Some(other + cpu + memory);
+        }
+    }
+}
+
+fn priced_on(basis: CostBasis, usage_cost: Option<f64>, request_cost: f64) -> Option<f64> {
+    match basis {
+        CostBasis::Usage => usage_cost,
+        CostBasis::Request => Some(request_cost),
+        CostBasis::Max => Some(usage_cost.unwrap_or(0.0).max(request_cost)),
+    }
+}
+
 pub fn apply_node_costs(
     response: &mut MetricGetResponseDto,
     unit_prices: &InfoUnitPriceEntity,
@@ -322,6 +682,11 @@ pub fn apply_node_costs(
             None => continue,
         };
 
+        // Virtual nodes report placeholder capacity; their pods carry the cost
+        if virtual_node_provider(node_info).is_some() {
+            continue;
+        }
+
         // Check Running Hours
         let running_hours = match series.running_hours {
             Some(h) if h > 0.0 => h,
@@ -336,9 +701,11 @@ pub fn apply_node_costs(
             node_info.ephemeral_storage_capacity_bytes.unwrap_or(0) as f64 / 1_073_741_824.0;
 
 
-        let cpu_cost_usd = Some(cpu_cores * running_hours * unit_prices.cpu_core_hour);
-        let memory_cost_usd = Some(memory_gb * running_hours * unit_prices.memory_gb_hour);
-        let storage_cost_usd = Some(storage_gb * running_hours * unit_prices.storage_gb_hour);
+        let (cpu_rate, memory_rate, storage_rate) = capacity_rates(series, unit_prices);
+
+        let cpu_cost_usd = Some(cpu_cores * running_hours * cpu_rate);
+        let memory_cost_usd = Some(memory_gb * running_hours * memory_rate);
+        let storage_cost_usd = Some(storage_gb * running_hours * storage_rate);
 
         let network_cost_usd = 0.0;
 
@@ -358,6 +725,25 @@ pub fn apply_node_costs(
     }
 }
 
+/// CPU, memory and storage hourly rates averaged over the series' points.
+/// Each point is one running interval, so capacity running across a price
+/// change pays each price for its share of the running hours.
+fn capacity_rates(series: &MetricSeriesDto, unit_prices: &InfoUnitPriceEntity) -> (f64, f64, f64) {
+    if unit_prices.history.is_none() || series.points.is_empty() {
+        return (unit_prices.cpu_core_hour, unit_prices.memory_gb_hour, unit_prices.storage_gb_hour);
+    }
+
+    let (mut cpu, mut memory, mut storage) = (0.0, 0.0, 0.0);
+    for point in &series.points {
+        let prices = unit_prices.at(point.time);
+        cpu += prices.cpu_core_hour;
+        memory += prices.memory_gb_hour;
+        storage += prices.storage_gb_hour;
+    }
+
+    let n = series.points.len() as f64;
+    (cpu / n, memory / n, storage / n)
+}
 
 pub fn build_cost_summary_dto(
     metrics: &MetricGetResponseDto,
@@ -366,59 +752,126 @@ pub fn build_cost_summary_dto(
     unit_prices: &InfoUnitPriceEntity,
 ) -> MetricCostSummaryResponseDto {
     let mut summary = MetricCostSummaryDto::default();
-    let default_interval_hours = granularity_interval_hours(&metrics.granularity);
 
     for series in &metrics.series {
-        for (idx, point) in series.points.iter().enumerate() {
-            let interval_hours = point_interval_hours(&series.points, idx, default_interval_hours);
+        add_series_costs(&mut summary, series, &metrics.granularity, unit_prices);
+    }
 
-            if let Some(cost) = &point.cost {
-                let cpu_cost = cost.cpu_cost_usd.unwrap_or(0.0);
-                let memory_cost = cost.memory_cost_usd.unwrap_or(0.0);
+    MetricCostSummaryResponseDto {
+        start: metrics.start,
+        end: metrics.end,
+        scope,
+        target,
+        granularity: metrics.granularity.clone(),
+        summary,
+        commitments: None,
+    }
+}
 
-                let ephemeral_cost = point
-                    .filesystem
-                    .as_ref()
-                    .and_then(|fs| fs.used_bytes)
-                    .map(|b| (b / BYTES_PER_GB) * interval_hours * unit_prices.storage_gb_hour)
-                    .unwrap_or(0.0);
+/// Cost of one priced series, as [`build_cost_summary_dto`] counts it.
+pub fn series_cost_summary(
+    series: &MetricSeriesDto,
+    granularity: &MetricGranularity,
+    unit_prices: &InfoUnitPriceEntity,
+) -> MetricCostSummaryDto {
+    let mut summary = MetricCostSummaryDto::default();
+    add_series_costs(&mut summary, series, granularity, unit_prices);
+    summary
+}
 
-                let persistent_cost = point
-                    .storage
-                    .as_ref()
-                    .and_then(|s| s.persistent.as_ref())
-                    .and_then(|fs| fs.used_bytes)
-                    .map(|b| (b / BYTES_PER_GB) * interval_hours * unit_prices.storage_gb_hour)
-                    .unwrap_or(0.0);
+/// Adds one priced series to `summary`; storage and network are re-derived from usage.
+fn add_series_costs(
+    summary: &mut MetricCostSummaryDto,
+    series: &MetricSeriesDto,
+    granularity: &MetricGranularity,
+    unit_prices: &InfoUnitPriceEntity,
+) {
+    let default_interval_hours = granularity_interval_hours(granularity);
 
-                let network_cost = point
-                    .network
-                    .as_ref()
-                    .map(|n| {
-                        let rx_gb = n.rx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
-                        let tx_gb = n.tx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
-                        (rx_gb + tx_gb) * unit_prices.network_external_gb
-                    })
-                    .unwrap_or(0.0);
+    for (idx, point) in series.points.iter().enumerate() {
+        let interval_hours = point_interval_hours(&series.points, idx, default_interval_hours);
+        let unit_prices = unit_prices.at(point.time);
+
+        if let Some(cost) = &point.cost {
+            let cpu_cost = cost.cpu_cost_usd.unwrap_or(0.0);
+            let memory_cost = cost.memory_cost_usd.unwrap_or(0.0);
+
+            let ephemeral_cost = point
+                .filesystem
+                .as_ref()
+                .and_then(|fs| fs.used_bytes)
+                .map(|b| (b / BYTES_PER_GB) * interval_hours * unit_prices.storage_gb_hour)
+                .unwrap_or(0.0);
+
+            let persistent_cost = point
+                .storage
+                .as_ref()
+                .and_then(|s| s.persistent.as_ref())
+                .and_then(|fs| fs.used_bytes)
+                .map(|b| (b / BYTES_PER_GB) * interval_hours * unit_prices.storage_gb_hour)
+                .unwrap_or(0.0);
+
+            let network_cost = point
+                .network
+                .as_ref()
+                .map(|n| {
+                    let rx_gb = n.rx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
+                    let tx_gb = n.tx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
+                    (rx_gb + tx_gb) * unit_prices.network_external_gb
+                })
+                .unwrap_or(0.0);
+
+            summary.cpu_cost_usd += cpu_cost;
+            summary.memory_cost_usd += memory_cost;
+            summary.ephemeral_storage_cost_usd += ephemeral_cost;
+            summary.persistent_storage_cost_usd += persistent_cost;
+            summary.network_cost_usd += network_cost;
 
-                summary.cpu_cost_usd += cpu_cost;
-                summary.memory_cost_usd += memory_cost;
-                summary.ephemeral_storage_cost_usd += ephemeral_cost;
-                summary.persistent_storage_cost_usd += persistent_cost;
-                summary.network_cost_usd += network_cost;
+            summary.total_cost_usd += cpu_cost + memory_cost + ephemeral_cost + persistent_cost + network_cost;
+        }
+    }
+}
 
-                summary.total_cost_usd += cpu_cost + memory_cost + ephemeral_cost + persistent_cost + network_cost;
+/// Splits a priced response's cost per series (e.g. the containers of one pod),
+/// most expensive first.
+pub fn build_cost_breakdown_dto(
+    metrics: &MetricGetResponseDto,
+    scope: MetricScope,
+    target: Option<String>,
+    unit_prices: &InfoUnitPriceEntity,
+) -> MetricCostBreakdownResponseDto {
+    let mut total = MetricCostSummaryDto::default();
+    let mut items: Vec<MetricCostBreakdownItemDto> = metrics
+        .series
+        .iter()
+        .map(|series| {
+            let mut summary = MetricCostSummaryDto::default();
+            add_series_costs(&mut summary, series, &metrics.granularity, unit_prices);
+            add_series_costs(&mut total, series, &metrics.granularity, unit_prices);
+            MetricCostBreakdownItemDto {
+                key: series.key.clone(),
+                name: series.name.clone(),
+                share_percent: 0.0,
+                summary,
             }
+        })
+        .collect();
+
+    for item in &mut items {
+        if total.total_cost_usd > 0.0 {
+            item.share_percent = item.summary.total_cost_usd / total.total_cost_usd * 100.0;
         }
     }
+    items.sort_by(|a, b| b.summary.total_cost_usd.total_cmp(&a.summary.total_cost_usd));
 
-    MetricCostSummaryResponseDto {
+    MetricCostBreakdownResponseDto {
         start: metrics.start,
         end: metrics.end,
         scope,
         target,
         granularity: metrics.granularity.clone(),
-        summary,
+        summary: total,
+        items,
     }
 }
 
@@ -440,7 +893,7 @@ pub fn build_node_cost_summary_dto(
                     .map(|n| {
                         let rx_gb = n.rx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
                         let tx_gb = n.tx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
-                        (rx_gb + tx_gb) * unit_prices.network_external_gb
+                        (rx_gb + tx_gb) * unit_prices.at(point.time).network_external_gb
                     })
                     .unwrap_or(0.0);
 
@@ -463,6 +916,7 @@ pub fn build_node_cost_summary_dto(
         target,
         granularity: metrics.granularity.clone(),
         summary,
+        commitments: None,
     }
 }
 
@@ -485,6 +939,8 @@ pub fn build_cost_trend_dto(
                         cpu_cost_usd: c.cpu_cost_usd.unwrap_or(0.0),
                         memory_cost_usd: c.memory_cost_usd.unwrap_or(0.0),
                         storage_cost_usd: c.storage_cost_usd.unwrap_or(0.0),
+                        replicas: None,
+                        cost_per_replica_usd: None,
                     })
                 })
             })
@@ -556,16 +1012,102 @@ pub fn build_cost_trend_dto(
         },
 
         points: trend_points,
+        replicas: None,
     })
 }
 
 
+/// CPU cores and memory GB of one efficiency baseline; `None` when unknown.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourceTotals {
+    pub cpu_cores: Option<f64>,
+    pub memory_gb: Option<f64>,
+}
+
+impl ResourceTotals {
+    fn positive(cpu_cores: f64, memory_gb: f64) -> Self {
+        Self {
+            cpu_cores: (cpu_cores > 0.0).then_some(cpu_cores),
+            memory_gb: (memory_gb > 0.0).then_some(memory_gb),
+        }
+    }
+}
+
+/// Requests, limits and allocatable capacity behind the separate
+/// usage/requests, usage/limits and requests/allocatable ratios.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EfficiencyBaselines {
+    pub requests: ResourceTotals,
+    pub limits: ResourceTotals,
+    pub allocatable: ResourceTotals,
+}
+
+impl EfficiencyBaselines {
+    /// Summed requests and limits of `containers`. Limits of a resource are
+    /// unknown as soon as one container runs without one, since the sum would
+    /// understate what the group may use.
+    pub fn from_containers<'a>(containers: impl IntoIterator<Item = &'a InfoContainerEntity>) -> Self {
+        let mut request_cpu = 0.0;
+        let mut request_mem = 0.0;
+        let mut limit_cpu = Some(0.0);
+        let mut limit_mem = Some(0.0);
+
+        for c in containers {
+            request_cpu += c.cpu_request_millicores.unwrap_or(0) as f64 / 1000.0;
+            request_mem += c.memory_request_bytes.unwrap_or(0) as f64 / BYTES_PER_GB;
+            limit_cpu = limit_cpu.zip(c.cpu_limit_millicores).map(|(t, l)| t + l as f64 / 1000.0);
+            limit_mem = limit_mem.zip(c.memory_limit_bytes).map(|(t, l)| t + l as f64 / BYTES_PER_GB);
+        }
+
+        Self {
+            requests: ResourceTotals::positive(request_cpu, request_mem),
+            limits: ResourceTotals::positive(limit_cpu.unwrap_or(0.0), limit_mem.unwrap_or(0.0)),
+            allocatable: ResourceTotals::default(),
+        }
+    }
+
+    pub fn with_allocatable(mut self, cpu_cores: f64, memory_gb: f64) -> Self {
+        self.allocatable = ResourceTotals::positive(cpu_cores, memory_gb);
+        self
+    }
+
+    /// Fills the three ratio breakdowns of `dto` from average usage.
+    pub fn apply(&self, dto: &mut MetricRawEfficiencyDto, avg_cpu_cores: f64, avg_memory_gb: f64) {
+        let ratio = |value: Option<f64>, base: Option<f64>| Some(value? / base?);
+        let usage = ResourceTotals { cpu_cores: Some(avg_cpu_cores), memory_gb: Some(avg_memory_gb) };
+        let ratios = |num: &ResourceTotals, den: &ResourceTotals| ResourceRatiosDto {
+            cpu: ratio(num.cpu_cores, den.cpu_cores),
+            memory: ratio(num.memory_gb, den.memory_gb),
+        };
+
+        dto.usage_to_requests = ratios(&usage, &self.requests);
+        dto.usage_to_limits = ratios(&usage, &self.limits);
+        dto.requests_to_allocatable = ratios(&self.requests, &self.allocatable);
+    }
+}
+
+/// Stored containers that are not deleted and match `keep`, for baselines of
+/// scopes that don't already hold their containers.
+pub async fn stored_containers(keep: impl Fn(&InfoContainerEntity) -> bool) -> Vec<InfoContainerEntity> {
+    repositories()
+        .info_containers
+        .list()
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|c| c.deleted != Some(true) && keep(c))
+        .collect()
+}
+
+/// Efficiency response: the existing usage-over-capacity ratios against
+/// `total_*`, plus the request/limit breakdown from `baselines`.
 pub fn build_efficiency_value(
     summary: MetricRawSummaryResponseDto,
     scope: MetricScope,
     total_cpu_alloc: f64,
     total_mem_alloc_gb: f64,
     total_storage_alloc_gb: f64,
+    baselines: EfficiencyBaselines,
 ) -> Result<Value> {
     let cpu_eff = if total_cpu_alloc > 0.0 {
         (summary.summary.avg_cpu_cores / total_cpu_alloc).clamp(0.0, 1.0)
@@ -585,20 +1127,26 @@ pub fn build_efficiency_value(
         0.0
     };
 
+    let weights = EfficiencyWeights::configured().resolve(total_cpu_alloc, total_mem_alloc_gb, total_storage_alloc_gb);
+    let mut efficiency = MetricRawEfficiencyDto {
+        cpu_efficiency: cpu_eff,
+        memory_efficiency: mem_eff,
+        storage_efficiency: storage_eff,
+        overall_efficiency: weights.overall(cpu_eff, mem_eff, storage_eff),
+        overall_weights: weights,
+        total_cpu_allocatable_cores: total_cpu_alloc,
+        total_memory_allocatable_gb: total_mem_alloc_gb,
+        total_storage_allocatable_gb: total_storage_alloc_gb,
+        ..Default::default()
+    };
+    baselines.apply(&mut efficiency, summary.summary.avg_cpu_cores, summary.summary.avg_memory_gb);
+
     let dto = MetricRawEfficiencyResponseDto {
         start: summary.start,
         end: summary.end,
         scope,
         granularity: summary.granularity,
-        efficiency: MetricRawEfficiencyDto {
-            cpu_efficiency: cpu_eff,
-            memory_efficiency: mem_eff,
-            storage_efficiency: storage_eff,
-            overall_efficiency: (cpu_eff + mem_eff + storage_eff) / 3.0,
-            total_cpu_allocatable_cores: total_cpu_alloc,
-            total_memory_allocatable_gb: total_mem_alloc_gb,
-            total_storage_allocatable_gb: total_storage_alloc_gb,
-        },
+        efficiency,
     };
 
     Ok(serde_json::to_value(dto)?)
@@ -691,3 +1239,196 @@ pub fn aggregate_cost_points(series: &[MetricSeriesDto]) -> Vec<UniversalMetricP
     aggregated.sort_by_key(|p| p.time);
     aggregated
 }
+
+/// Current and baseline queries for `cost/compare`. The current window is pinned
+/// first so both resolve against the same "now".
+pub fn compare_queries(q: &RangeQuery, cmp: &CostCompareQuery) -> (RangeQuery, RangeQuery) {
+    let window = resolve_time_window(q);
+    // Pinned in the query's own offset, since `tz` is carried over to both queries
+    let (start, end) = (
+        window.start.with_timezone(&window.offset).naive_local(),
+        window.end.with_timezone(&window.offset).naive_local(),
+    );
+    let length = end - start;
+
+    let (prev_start, prev_end) = match (cmp.compare_start, cmp.compare_end) {
+        (Some(s), Some(e)) => (s, e),
+        (Some(s), None) => (s, s + length),
+        (None, Some(e)) => (e - length, e),
+        (None, None) => (start - length, start),
+    };
+
+    let mut current = q.clone();
+    current.start = Some(start);
+    current.end = Some(end);
+    current.range = None;
+
+    let mut previous = q.clone();
+    previous.start = Some(prev_start);
+    previous.end = Some(prev_end);
+    previous.range = None;
+
+    (current, previous)
+}
+
+/// Runs a scope's cost summary for both windows and diffs them.
+pub async fn build_cost_compare_value<F, Fut>(
+    q: RangeQuery,
+    cmp: CostCompareQuery,
+    cost_summary: F,
+) -> Result<Value>
+where
+    F: Fn(RangeQuery) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    let (current_q, previous_q) = compare_queries(&q, &cmp);
+    let current: MetricCostSummaryResponseDto = serde_json::from_value(cost_summary(current_q).await?)?;
+    let previous: MetricCostSummaryResponseDto = serde_json::from_value(cost_summary(previous_q).await?)?;
+
+    let dto = MetricCostCompareResponseDto {
+        scope: current.scope.clone(),
+        target: current.target.clone(),
+        delta: cost_delta(&current.summary, &previous.summary),
+        current,
+        previous,
+    };
+    Ok(serde_json::to_value(dto)?)
+}
+
+fn cost_delta(current: &MetricCostSummaryDto, previous: &MetricCostSummaryDto) -> MetricCostDeltaDto {
+    let diff = |cur: f64, prev: f64| MetricCostDeltaValueDto {
+        absolute_usd: cur - prev,
+        percent: (prev != 0.0).then(|| (cur - prev) / prev * 100.0),
+    };
+
+    MetricCostDeltaDto {
+        total: diff(current.total_cost_usd, previous.total_cost_usd),
+        cpu: diff(current.cpu_cost_usd, previous.cpu_cost_usd),
+        memory: diff(current.memory_cost_usd, previous.memory_cost_usd),
+        ephemeral_storage: diff(current.ephemeral_storage_cost_usd, previous.ephemeral_storage_cost_usd),
+        persistent_storage: diff(current.persistent_storage_cost_usd, previous.persistent_storage_cost_usd),
+        network: diff(current.network_cost_usd, previous.network_cost_usd),
+        loadbalancer: diff(current.loadbalancer_cost_usd, previous.loadbalancer_cost_usd),
+        fixed: diff(current.fixed_cost_usd, previous.fixed_cost_usd),
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32) -> chrono::NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn month_row_covers_the_preceding_calendar_month() {
+        let march = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        assert_eq!(month_row_hours(march), 29.0 * 24.0);
+        let april = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        assert_eq!(month_row_hours(april), 31.0 * 24.0);
+    }
+
+    #[test]
+    fn baseline_defaults_to_preceding_window() {
+        let q: RangeQuery = serde_json::from_value(json!({})).unwrap();
+        let q = RangeQuery { start: Some(at(8)), end: Some(at(15)), ..q };
+
+        let (current, previous) = compare_queries(&q, &CostCompareQuery::default());
+        assert_eq!((current.start, current.end), (Some(at(8)), Some(at(15))));
+        assert_eq!((previous.start, previous.end), (Some(at(1)), Some(at(8))));
+
+        let cmp = CostCompareQuery { compare_start: Some(at(20)), compare_end: None };
+        let (_, previous) = compare_queries(&q, &cmp);
+        assert_eq!((previous.start, previous.end), (Some(at(20)), Some(at(27))));
+    }
+
+    #[test]
+    fn request_and_limit_ratios_are_reported_separately() {
+        let container = |req_m: u64, lim_m: Option<u64>| InfoContainerEntity {
+            cpu_request_millicores: Some(req_m),
+            cpu_limit_millicores: lim_m,
+            memory_request_bytes: Some(BYTES_PER_GB as u64),
+            memory_limit_bytes: Some(2 * BYTES_PER_GB as u64),
+            ..Default::default()
+        };
+        let containers = [container(500, Some(1000)), container(500, Some(3000))];
+        let baselines = EfficiencyBaselines::from_containers(&containers).with_allocatable(8.0, 0.0);
+
+        let mut dto = MetricRawEfficiencyDto::default();
+        baselines.apply(&mut dto, 0.5, 1.5);
+        assert_eq!(dto.usage_to_requests, ResourceRatiosDto { cpu: Some(0.5), memory: Some(0.75) });
+        assert_eq!(dto.usage_to_limits, ResourceRatiosDto { cpu: Some(0.125), memory: Some(0.375) });
+        assert_eq!(dto.requests_to_allocatable, ResourceRatiosDto { cpu: Some(0.125), memory: None });
+
+        // One unlimited container leaves the CPU limit ratio undefined
+        let unlimited = [container(500, Some(1000)), container(500, None)];
+        let mut dto = MetricRawEfficiencyDto::default();
+        EfficiencyBaselines::from_containers(&unlimited).apply(&mut dto, 0.5, 1.5);
+        assert_eq!(dto.usage_to_limits.cpu, None);
+        assert_eq!(dto.usage_to_limits.memory, Some(0.375));
+    }
+
+    #[test]
+    fn delta_percent_is_none_without_baseline() {
+        let current = MetricCostSummaryDto { total_cost_usd: 15.0, cpu_cost_usd: 5.0, ..Default::default() };
+        let previous = MetricCostSummaryDto { total_cost_usd: 10.0, ..Default::default() };
+
+        let delta = cost_delta(&current, &previous);
+        assert_eq!(delta.total.absolute_usd, 5.0);
+        assert_eq!(delta.total.percent, Some(50.0));
+        assert_eq!(delta.cpu.percent, None);
+    }
+
+    #[test]
+    fn tz_shifts_window_to_local_midnight() {
+        assert_eq!(parse_utc_offset("UTC+9"), parse_utc_offset("Asia/Seoul"));
+        assert_eq!(parse_utc_offset("+0530").unwrap().local_minus_utc(), 5 * 3600 + 1800);
+        assert_eq!(parse_utc_offset("-05:00").unwrap().local_minus_utc(), -5 * 3600);
+        assert_eq!(parse_utc_offset("Europe/Berlin"), None);
+
+        let q: RangeQuery = serde_json::from_value(json!({ "tz": "Asia/Seoul" })).unwrap();
+        let q = RangeQuery { start: Some(at(1)), end: Some(at(8)), ..q };
+        let window = resolve_time_window(&q);
+        assert_eq!(window.start.to_rfc3339(), "2025-02-28T15:00:00+00:00");
+        assert_eq!(window.end.to_rfc3339(), "2025-03-07T15:00:00+00:00");
+    }
+
+    #[test]
+    fn named_ranges_resolve_in_local_time() {
+        let kst = parse_utc_offset("Asia/Seoul").unwrap();
+        // 2025-03-01 01:00 KST, still February in UTC
+        let now: DateTime<Utc> = "2025-02-28T16:00:00Z".parse().unwrap();
+
+        let (start, end) = resolve_relative_range(RelativeRange::MonthToDate, now, kst);
+        assert_eq!(start.to_rfc3339(), "2025-02-28T15:00:00+00:00");
+        assert_eq!(end, now);
+
+        let (start, end) = resolve_relative_range(RelativeRange::LastMonth, now, kst);
+        assert_eq!(start.to_rfc3339(), "2025-01-31T15:00:00+00:00");
+        assert_eq!(end.to_rfc3339(), "2025-02-28T14:59:59+00:00");
+
+        let (start, _) = resolve_relative_range(RelativeRange::Last7d, now, kst);
+        assert_eq!(now - start, chrono::Duration::days(7));
+
+        let q: RangeQuery = serde_json::from_value(json!({ "range": "lastMonth" })).unwrap();
+        assert_eq!(q.range, Some(RelativeRange::LastMonth));
+    }
+
+    #[test]
+    fn explicit_granularity_too_fine_for_window_is_rejected() {
+        let q: RangeQuery = serde_json::from_value(json!({ "granularity": "minute" })).unwrap();
+        let q = RangeQuery { start: Some(at(1)), end: Some(at(15)), ..q };
+
+        let rejection = check_granularity(&q).unwrap_err();
+        assert!(matches!(rejection.requested, MetricGranularity::Minute));
+        assert!(matches!(rejection.suggested, MetricGranularity::Day));
+        assert_eq!(rejection.range_hours, 14.0 * 24.0);
+
+        let auto = RangeQuery { granularity: None, ..q };
+        assert!(check_granularity(&auto).is_ok());
+    }
+}
@@ -0,0 +1,166 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::info::fixed::commitment::commitment_entity::{instance_family, CommitmentEntity};
+use crate::core::persistence::info::fixed::commitment::info_commitment_api_repository_trait::InfoCommitmentApiRepository;
+use crate::core::persistence::info::fixed::commitment::info_commitment_repository::InfoCommitmentRepository;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::domain::metric::k8s::common::dto::metric_k8s_commitment_coverage_dto::{
+    MetricCommitmentCoverageDto, MetricCommitmentUsageDto,
+};
+use crate::domain::metric::k8s::node_pool::service::node_instance_type;
+
+/// Compute (CPU + memory) cost of one node over a window.
+#[derive(Debug, Clone)]
+pub struct NodeComputeSpend {
+    /// Instance family from the node's instance type, e.g. `m5`
+    pub family: Option<String>,
+    pub cost_usd: f64,
+}
+
+impl NodeComputeSpend {
+    pub fn new(node: Option<&InfoNodeEntity>, cost_usd: f64) -> Self {
+        let family = node
+            .and_then(node_instance_type)
+            .map(|t| instance_family(&t).to_ascii_lowercase());
+        Self { family, cost_usd }
+    }
+}
+
+/// Splits node compute spend into covered and on-demand portions using the
+/// stored commitments; `None` when no commitment is active in the window.
+pub fn load_commitment_coverage(
+    nodes: &[NodeComputeSpend],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Option<MetricCommitmentCoverageDto>> {
+    let stored = InfoCommitmentRepository::new().read()?;
+    Ok(commitment_coverage(&stored.items, nodes, start, end))
+}
+
+/// Applies each commitment's spend over the window to the nodes it covers.
+///
+/// Matching is done on window totals, not hour by hour, so a commitment can
+/// absorb a spike in one hour with slack from another. Family-scoped
+/// commitments (reserved instances) go first, then broad ones (savings plans)
+/// take what is left; within each group the eligible spend is drawn down
+/// pro rata.
+pub fn commitment_coverage(
+    commitments: &[CommitmentEntity],
+    nodes: &[NodeComputeSpend],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Option<MetricCommitmentCoverageDto> {
+    let mut active: Vec<(&CommitmentEntity, f64)> = commitments
+        .iter()
+        .map(|c| (c, c.active_hours_between(start, end) * c.hourly_commitment_usd))
+        .filter(|(_, usd)| *usd > 0.0)
+        .collect();
+    if active.is_empty() {
+        return None;
+    }
+    active.sort_by(|(a, _), (b, _)| {
+        a.instance_families
+            .is_empty()
+            .cmp(&b.instance_families.is_empty())
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut remaining: Vec<f64> = nodes.iter().map(|n| n.cost_usd.max(0.0)).collect();
+    let mut usages = Vec::with_capacity(active.len());
+
+    for (commitment, commitment_usd) in active {
+        let eligible: Vec<usize> = (0..nodes.len())
+            .filter(|&i| commitment.covers_family(nodes[i].family.as_deref()))
+            .collect();
+        let eligible_usd: f64 = eligible.iter().map(|&i| remaining[i]).sum();
+        let used = commitment_usd.min(eligible_usd);
+
+        if used > 0.0 {
+            let share = used / eligible_usd;
+            for &i in &eligible {
+                remaining[i] -= remaining[i] * share;
+            }
+        }
+
+        usages.push(MetricCommitmentUsageDto {
+            id: commitment.id.clone(),
+            name: commitment.name.clone(),
+            kind: commitment.kind,
+            commitment_usd,
+            used_usd: used,
+            utilization_percent: percent(used, commitment_usd),
+        });
+    }
+
+    let compute_cost_usd: f64 = nodes.iter().map(|n| n.cost_usd.max(0.0)).sum();
+    let on_demand_cost_usd: f64 = remaining.iter().sum();
+    let covered_cost_usd = compute_cost_usd - on_demand_cost_usd;
+    let commitment_usd: f64 = usages.iter().map(|u| u.commitment_usd).sum();
+
+    Some(MetricCommitmentCoverageDto {
+        compute_cost_usd,
+        covered_cost_usd,
+        on_demand_cost_usd,
+        commitment_usd,
+        unused_commitment_usd: (commitment_usd - covered_cost_usd).max(0.0),
+        coverage_percent: percent(covered_cost_usd, compute_cost_usd),
+        utilization_percent: percent(covered_cost_usd, commitment_usd),
+        commitments: usages,
+    })
+}
+
+fn percent(part: f64, whole: f64) -> f64 {
+    if whole > 0.0 { part / whole * 100.0 } else { 0.0 }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::info::fixed::commitment::commitment_entity::{CommitmentKind, CommitmentTerm};
+    use chrono::TimeZone;
+
+    fn commitment(id: &str, hourly: f64, families: &[&str]) -> CommitmentEntity {
+        CommitmentEntity {
+            id: id.into(),
+            name: id.into(),
+            kind: if families.is_empty() { CommitmentKind::SavingsPlan } else { CommitmentKind::ReservedInstance },
+            hourly_commitment_usd: hourly,
+            term: CommitmentTerm::OneYear,
+            starts_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            instance_families: families.iter().map(|f| f.to_string()).collect(),
+            enabled: true,
+            updated_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn reserved_instances_apply_before_savings_plans() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let end = start + chrono::Duration::hours(10);
+        let nodes = vec![
+            NodeComputeSpend { family: Some("m5".into()), cost_usd: 20.0 },
+            NodeComputeSpend { family: Some("c6g".into()), cost_usd: 10.0 },
+        ];
+        // 1 $/h savings plan (10 $) and a 1.5 $/h m5 reservation (15 $)
+        let commitments = vec![commitment("sp", 1.0, &[]), commitment("ri", 1.5, &["M5"])];
+
+        let dto = commitment_coverage(&commitments, &nodes, start, end).unwrap();
+
+        assert_eq!(dto.commitments[0].id, "ri");
+        assert!((dto.commitments[0].used_usd - 15.0).abs() < 1e-9);
+        assert!((dto.commitments[1].used_usd - 10.0).abs() < 1e-9);
+        assert!((dto.covered_cost_usd - 25.0).abs() < 1e-9);
+        assert!((dto.on_demand_cost_usd - 5.0).abs() < 1e-9);
+        assert!((dto.utilization_percent - 100.0).abs() < 1e-9);
+
+        let idle = commitment_coverage(&commitments, &[], start, end).unwrap();
+        assert_eq!(idle.covered_cost_usd, 0.0);
+        assert!((idle.unused_commitment_usd - 25.0).abs() < 1e-9);
+
+        let before = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        assert!(commitment_coverage(&commitments, &nodes, before, before + chrono::Duration::hours(1)).is_none());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_fs_adapter::InfoUnitPriceFsAdapter;
+use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::EfficiencyWeightsDto;
+
+/// How `overall_efficiency` combines the CPU, memory and storage ratios
+/// (the `efficiency_weights` setting).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EfficiencyWeights {
+    /// Each resource by its share of the capacity cost in the window (`cost`, default)
+    CostShare,
+    /// Plain mean of the three ratios (`equal`)
+    Equal,
+    /// Relative `[cpu, memory, storage]` weights, e.g. `cpu=2,memory=1,storage=0`
+    Fixed([f64; 3]),
+}
+
+impl EfficiencyWeights {
+    pub fn parse(v: &str) -> Option<Self> {
+        match v.trim().to_lowercase().as_str() {
+            "" | "cost" => return Some(Self::CostShare),
+            "equal" => return Some(Self::Equal),
+            _ => {}
+        }
+
+        let mut weights = [0.0; 3];
+        for part in v.split(',') {
+            let (key, value) = part.split_once('=')?;
+            let value: f64 = value.trim().parse().ok().filter(|w: &f64| w.is_finite() && *w >= 0.0)?;
+            let slot = match key.trim().to_lowercase().as_str() {
+                "cpu" => 0,
+                "memory" => 1,
+                "storage" => 2,
+                _ => return None,
+            };
+            weights[slot] = value;
+        }
+        (weights.iter().sum::<f64>() > 0.0).then_some(Self::Fixed(weights))
+    }
+
+    /// From the stored settings; unset or unparseable values weigh by cost share.
+    pub fn configured() -> Self {
+        InfoSettingFsAdapter::new()
+            .read()
+            .ok()
+            .and_then(|s| Self::parse(&s.efficiency_weights))
+            .unwrap_or(Self::CostShare)
+    }
+
+    /// Normalized weights for the given capacity. Cost share prices it at the
+    /// current unit prices; the window length cancels out of the shares.
+    pub fn resolve(&self, cpu_cores: f64, memory_gb: f64, storage_gb: f64) -> EfficiencyWeightsDto {
+        match self {
+            Self::Equal => normalize([1.0; 3]),
+            Self::Fixed(w) => normalize(*w),
+            Self::CostShare => {
+                let prices = InfoUnitPriceFsAdapter::new().read().unwrap_or_default();
+                normalize(capacity_costs(&prices, cpu_cores, memory_gb, storage_gb))
+            }
+        }
+    }
+}
+
+fn capacity_costs(prices: &InfoUnitPriceEntity, cpu_cores: f64, memory_gb: f64, storage_gb: f64) -> [f64; 3] {
+    [
+        cpu_cores.max(0.0) * prices.cpu_core_hour,
+        memory_gb.max(0.0) * prices.memory_gb_hour,
+        storage_gb.max(0.0) * prices.storage_gb_hour,
+    ]
+}
+
+/// Scales to a sum of 1; all-zero weights fall back to equal.
+fn normalize(w: [f64; 3]) -> EfficiencyWeightsDto {
+    let total: f64 = w.iter().sum();
+    let [cpu, memory, storage] = if total > 0.0 { w.map(|v| v / total) } else { [1.0 / 3.0; 3] };
+    EfficiencyWeightsDto { cpu, memory, storage }
+}
+
+impl EfficiencyWeightsDto {
+    /// Weighted mean of the per-resource ratios.
+    pub fn overall(&self, cpu: f64, memory: f64, storage: f64) -> f64 {
+        self.cpu * cpu + self.memory * memory + self.storage * storage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_modes_and_fixed_weights() {
+        assert_eq!(EfficiencyWeights::parse("cost"), Some(EfficiencyWeights::CostShare));
+        assert_eq!(EfficiencyWeights::parse(" Equal "), Some(EfficiencyWeights::Equal));
+        assert_eq!(
+            EfficiencyWeights::parse("cpu=2, memory=1"),
+            Some(EfficiencyWeights::Fixed([2.0, 1.0, 0.0]))
+        );
+        assert_eq!(EfficiencyWeights::parse("cpu=0,storage=0"), None);
+        assert_eq!(EfficiencyWeights::parse("gpu=1"), None);
+        assert_eq!(EfficiencyWeights::parse("cpu=-1,memory=2"), None);
+    }
+
+    #[test]
+    fn cost_share_keeps_cheap_storage_from_dragging_the_score() {
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 0.04,
+            memory_gb_hour: 0.005,
+            storage_gb_hour: 0.0001,
+            ..Default::default()
+        };
+        // 16 cores, 64 GB, 500 GB disk → $0.64, $0.32 and $0.05 per hour
+        let w = normalize(capacity_costs(&prices, 16.0, 64.0, 500.0));
+        assert!((w.cpu + w.memory + w.storage - 1.0).abs() < 1e-9);
+        assert!(w.storage < 0.06);
+
+        let weighted = w.overall(0.6, 0.6, 0.02);
+        let plain = (0.6 + 0.6 + 0.02) / 3.0;
+        assert!(weighted > 0.55 && plain < 0.41);
+    }
+
+    #[test]
+    fn zero_weights_fall_back_to_equal() {
+        let w = normalize([0.0; 3]);
+        assert!((w.overall(0.3, 0.6, 0.9) - 0.6).abs() < 1e-9);
+    }
+}
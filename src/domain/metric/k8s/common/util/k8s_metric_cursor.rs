@@ -0,0 +1,67 @@
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+/// Opaque page cursor: the key (pod UID, node name) of the last series on the previous page.
+pub fn encode_cursor(last_key: &str) -> String {
+    URL_SAFE_NO_PAD.encode(last_key.as_bytes())
+}
+
+pub fn decode_cursor(cursor: &str) -> Result<String> {
+    URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .ok_or_else(|| anyhow!("Invalid cursor"))
+}
+
+/// One page of `items`, which must be sorted by `key`.
+///
+/// With a cursor the page starts right after the cursor's key (objects deleted since
+/// the previous page don't shift it); otherwise at `offset`. Returns the page range
+/// and the cursor for the next page, if any items remain.
+pub fn cursor_page<T>(
+    items: &[T],
+    key: impl Fn(&T) -> &str,
+    cursor: Option<&str>,
+    offset: usize,
+    limit: usize,
+) -> Result<(std::ops::Range<usize>, Option<String>)> {
+    let start = match cursor {
+        Some(c) => {
+            let after = decode_cursor(c)?;
+            items.partition_point(|item| key(item) <= after.as_str())
+        }
+        None => offset.min(items.len()),
+    };
+    let end = start.saturating_add(limit).min(items.len());
+
+    let next = (end < items.len() && end > start).then(|| encode_cursor(key(&items[end - 1])));
+    Ok((start..end, next))
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_follow_the_cursor() {
+        let keys = ["a", "b", "c", "d", "e"];
+
+        let (first, next) = cursor_page(&keys, |k| *k, None, 0, 2).unwrap();
+        assert_eq!(first, 0..2);
+
+        let (second, next) = cursor_page(&keys, |k| *k, next.as_deref(), 0, 2).unwrap();
+        assert_eq!(second, 2..4);
+
+        // "d" was deleted before the last page was requested
+        let remaining = ["a", "b", "c", "e"];
+        let (last, next) = cursor_page(&remaining, |k| *k, next.as_deref(), 0, 2).unwrap();
+        assert_eq!(last, 3..4);
+        assert!(next.is_none());
+
+        assert!(cursor_page(&keys, |k| *k, Some("%%"), 0, 2).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::{anyhow, Result};
+use regex::{Regex, RegexBuilder};
+
+use crate::api::dto::metrics_dto::RangeQuery;
+
+/// One `namespace` / `team` / `service` / `env` query filter.
+///
+/// The raw value is a comma-separated list of alternatives; an alternative starting
+/// with `~` is a regex matched against the whole value (`~^team-` style prefixes need
+/// a trailing `.*`), anything else is an exact match. Both are case-insensitive.
+/// Commas always separate alternatives, so a pattern can't contain one.
+#[derive(Debug, Clone)]
+pub struct ValueFilter {
+    exact: Vec<String>,
+    patterns: Vec<Regex>,
+}
+
+impl ValueFilter {
+    pub fn parse(raw: &str) -> Result<Self> {
+        let mut exact = Vec::new();
+        let mut patterns = Vec::new();
+
+        for alt in raw.split(',').map(str::trim).filter(|a| !a.is_empty()) {
+            match alt.strip_prefix('~') {
+                Some(pattern) => {
+                    let re = RegexBuilder::new(&format!("^(?:{})$", pattern))
+                        .case_insensitive(true)
+                        .build()
+                        .map_err(|e| anyhow!("Invalid filter pattern '{}': {}", pattern, e))?;
+                    patterns.push(re);
+                }
+                None => exact.push(alt.to_string()),
+            }
+        }
+
+        Ok(Self { exact, patterns })
+    }
+
+    pub fn matches(&self, value: &str) -> bool {
+        let value = value.trim();
+        self.exact.iter().any(|e| e.eq_ignore_ascii_case(value))
+            || self.patterns.iter().any(|re| re.is_match(value))
+    }
+
+    /// Attribution fields may themselves list several values (`"team-a,team-b"`);
+    /// any of them matching is enough. Missing values never match.
+    pub fn matches_field(&self, field: &Option<String>) -> bool {
+        field
+            .as_deref()
+            .map(|v| v.split(',').any(|x| self.matches(x)))
+            .unwrap_or(false)
+    }
+}
+
+/// The query's scope filters, parsed once per request. An object must match every
+/// include filter and none of the exclude filters.
+#[derive(Debug, Clone, Default)]
+pub struct MetricFilters {
+    pub namespace: Option<ValueFilter>,
+    pub team: Option<ValueFilter>,
+    pub service: Option<ValueFilter>,
+    pub env: Option<ValueFilter>,
+    pub exclude_namespace: Option<ValueFilter>,
+    pub exclude_team: Option<ValueFilter>,
+    pub exclude_service: Option<ValueFilter>,
+    pub exclude_env: Option<ValueFilter>,
+}
+
+impl MetricFilters {
+    pub fn from_query(q: &RangeQuery) -> Result<Self> {
+        let parse = |raw: &Option<String>| raw.as_deref().map(ValueFilter::parse).transpose();
+        Ok(Self {
+            namespace: parse(&q.namespace)?,
+            team: parse(&q.team)?,
+            service: parse(&q.service)?,
+            env: parse(&q.env)?,
+            exclude_namespace: parse(&q.exclude_namespace)?,
+            exclude_team: parse(&q.exclude_team)?,
+            exclude_service: parse(&q.exclude_service)?,
+            exclude_env: parse(&q.exclude_env)?,
+        })
+    }
+
+    /// Team / service / env only, for objects without a namespace (nodes).
+    pub fn matches_attribution(
+        &self,
+        team: &Option<String>,
+        service: &Option<String>,
+        env: &Option<String>,
+    ) -> bool {
+        passes(&self.team, &self.exclude_team, team)
+            && passes(&self.service, &self.exclude_service, service)
+            && passes(&self.env, &self.exclude_env, env)
+    }
+
+    pub fn matches_namespace(&self, namespace: &Option<String>) -> bool {
+        let ns = namespace.as_deref();
+        let included = match &self.namespace {
+            Some(f) => ns.map(|v| f.matches(v)).unwrap_or(false),
+            None => true,
+        };
+        let excluded = match &self.exclude_namespace {
+            Some(f) => ns.map(|v| f.matches(v)).unwrap_or(false),
+            None => false,
+        };
+        included && !excluded
+    }
+}
+
+/// Unset fields fail an include filter but are never excluded.
+fn passes(include: &Option<ValueFilter>, exclude: &Option<ValueFilter>, field: &Option<String>) -> bool {
+    let included = match include {
+        Some(f) => f.matches_field(field),
+        None => true,
+    };
+    let excluded = match exclude {
+        Some(f) => f.matches_field(field),
+        None => false,
+    };
+    included && !excluded
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_lists_and_patterns() {
+        let f = ValueFilter::parse("payments, ~team-.*").unwrap();
+        assert!(f.matches("Payments"));
+        assert!(f.matches("team-search"));
+        assert!(!f.matches("my-team-search"));
+        assert!(f.matches_field(&Some("infra,team-a".to_string())));
+        assert!(!f.matches_field(&None));
+
+        assert!(ValueFilter::parse("~(").is_err());
+    }
+
+    #[test]
+    fn excludes_win_over_includes() {
+        let filters = MetricFilters {
+            exclude_namespace: Some(ValueFilter::parse("kube-system,monitoring").unwrap()),
+            team: Some(ValueFilter::parse("~.*").unwrap()),
+            exclude_team: Some(ValueFilter::parse("sre").unwrap()),
+            ..Default::default()
+        };
+        assert!(filters.matches_namespace(&Some("payments".to_string())));
+        assert!(!filters.matches_namespace(&Some("kube-system".to_string())));
+        assert!(filters.matches_namespace(&None));
+
+        let team = |t: &str| Some(t.to_string());
+        assert!(filters.matches_attribution(&team("search"), &None, &None));
+        assert!(!filters.matches_attribution(&team("sre"), &None, &None));
+    }
+}
@@ -0,0 +1,161 @@
+use anyhow::Result;
+use chrono::Utc;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::api::dto::metrics_dto::{EfficiencyGroupBy, RangeQuery};
+use crate::domain::metric::k8s::common::service_helpers::resolve_time_window;
+
+/// How long a response for a window that reaches into the recent past is reused.
+const LIVE_TTL: Duration = Duration::from_secs(60);
+
+/// Rollups of a window are complete once the day aggregation after its end has
+/// run; windows ending earlier than this are cached until invalidated.
+const SETTLE: chrono::Duration = chrono::Duration::hours(26);
+
+/// Upper bound on stored responses; the cache is emptied when it is reached.
+const MAX_ENTRIES: usize = 2048;
+
+/// Freshness class of a cached response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryCacheTtl {
+    /// The window ends "now" (or recently); expires after `LIVE_TTL`
+    Live,
+    /// Fully historical window; kept until `invalidate_all`
+    Historical,
+}
+
+/// An argument of a cached endpoint. Each contributes to the cache key; the
+/// `RangeQuery` also decides how long the response stays valid.
+pub trait QueryCacheArg {
+    fn key_part(&self) -> Value;
+
+    fn ttl(&self) -> Option<QueryCacheTtl> {
+        None
+    }
+}
+
+impl QueryCacheArg for String {
+    fn key_part(&self) -> Value {
+        Value::from(self.as_str())
+    }
+}
+
+impl QueryCacheArg for Vec<String> {
+    fn key_part(&self) -> Value {
+        Value::from(self.clone())
+    }
+}
+
+impl QueryCacheArg for EfficiencyGroupBy {
+    fn key_part(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl QueryCacheArg for RangeQuery {
+    /// Historical windows are keyed on the resolved UTC bounds, offset and
+    /// granularity, so equivalent spellings (`tz=UTC+9` vs `+09:00`) share an
+    /// entry. Live windows keep the query as sent, since "now" moves.
+    fn key_part(&self) -> Value {
+        let mut v = serde_json::to_value(self).unwrap_or_default();
+        if self.ttl() == Some(QueryCacheTtl::Historical) {
+            let w = resolve_time_window(self);
+            v["start"] = Value::from(w.start.to_rfc3339());
+            v["end"] = Value::from(w.end.to_rfc3339());
+            v["tz"] = Value::from(w.offset.to_string());
+            v["granularity"] = serde_json::to_value(&w.granularity).unwrap_or_default();
+        }
+        v
+    }
+
+    /// Named ranges and open-ended windows are relative to "now"; only an
+    /// explicit `end` far enough in the past is historical.
+    fn ttl(&self) -> Option<QueryCacheTtl> {
+        if self.range.is_some() || self.end.is_none() {
+            return Some(QueryCacheTtl::Live);
+        }
+        if resolve_time_window(self).end <= Utc::now() - SETTLE {
+            Some(QueryCacheTtl::Historical)
+        } else {
+            Some(QueryCacheTtl::Live)
+        }
+    }
+}
+
+struct Entry {
+    value: Value,
+    expires_at: Option<Instant>,
+}
+
+fn entries() -> &'static Mutex<HashMap<String, Entry>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Entry>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Returns the cached response for `(endpoint, key_parts)` or computes and
+/// stores it. Errors are never cached.
+pub async fn cached<F>(endpoint: &str, key_parts: Vec<Value>, ttl: QueryCacheTtl, compute: F) -> Result<Value>
+where
+    F: Future<Output = Result<Value>>,
+{
+    let key = format!("{}|{}", endpoint, Value::Array(key_parts));
+    let now = Instant::now();
+
+    if let Some(entry) = entries().lock().unwrap().get(&key) {
+        if entry.expires_at.is_none_or(|t| t > now) {
+            return Ok(entry.value.clone());
+        }
+    }
+
+    let value = compute.await?;
+
+    let mut cache = entries().lock().unwrap();
+    if cache.len() >= MAX_ENTRIES {
+        cache.retain(|_, e| e.expires_at.is_none_or(|t| t > now));
+        if cache.len() >= MAX_ENTRIES {
+            cache.clear();
+        }
+    }
+    let expires_at = match ttl {
+        QueryCacheTtl::Live => Some(now + LIVE_TTL),
+        QueryCacheTtl::Historical => None,
+    };
+    cache.insert(key, Entry { value: value.clone(), expires_at });
+    Ok(value)
+}
+
+/// Drops every cached response, e.g. after rollups were recomputed.
+pub fn invalidate_all() {
+    entries().lock().unwrap().clear();
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn query(end: Option<chrono::NaiveDateTime>, tz: Option<&str>) -> RangeQuery {
+        let mut q: RangeQuery = serde_json::from_value(serde_json::json!({})).unwrap();
+        q.start = Some(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        q.end = end;
+        q.tz = tz.map(str::to_string);
+        q
+    }
+
+    #[test]
+    fn only_settled_explicit_windows_are_historical() {
+        let past = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap().and_hms_opt(0, 0, 0);
+
+        assert_eq!(query(None, None).ttl(), Some(QueryCacheTtl::Live));
+        assert_eq!(query(past, None).ttl(), Some(QueryCacheTtl::Historical));
+
+        // Same window spelled two ways shares a key
+        assert_eq!(query(past, Some("UTC+9")).key_part(), query(past, Some("+09:00")).key_part());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
+use crate::core::persistence::metrics::k8s::month::metric_month_repository::MetricMonthRepository;
 use super::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
 
 /// Resolve metric repository variant from metric scope and granularity.
@@ -14,27 +15,35 @@ pub fn resolve_k8s_metric_repository(
             Minute => NodeMinute(Default::default()),
             Hour => NodeHour(Default::default()),
             Day => NodeDay(Default::default()),
+            Month => NodeMonth(MetricMonthRepository::node()),
         },
         MetricScope::Pod => match granularity {
             Minute => PodMinute(Default::default()),
             Hour => PodHour(Default::default()),
             Day => PodDay(Default::default()),
+            Month => PodMonth(MetricMonthRepository::pod()),
         },
         MetricScope::Container => match granularity {
             Minute => ContainerMinute(Default::default()),
             Hour => ContainerHour(Default::default()),
             Day => ContainerDay(Default::default()),
+            Month => ContainerMonth(MetricMonthRepository::container()),
         },
-        MetricScope::Cluster => match granularity {
-            // For cluster, reuse node-level repos
+        MetricScope::Cluster | MetricScope::NodePool => match granularity {
+            // For cluster and node pools, reuse node-level repos
             Minute => NodeMinute(Default::default()),
             Hour => NodeHour(Default::default()),
             Day => NodeDay(Default::default()),
+            Month => NodeMonth(MetricMonthRepository::node()),
         },
-        MetricScope::Namespace | MetricScope::Deployment => match granularity {
+        MetricScope::Namespace
+        | MetricScope::Deployment
+        | MetricScope::Job
+        | MetricScope::CronJob => match granularity {
             Minute => PodMinute(Default::default()),
             Hour => PodHour(Default::default()),
             Day => PodDay(Default::default()),
+            Month => PodMonth(MetricMonthRepository::pod()),
         },
     }
 }
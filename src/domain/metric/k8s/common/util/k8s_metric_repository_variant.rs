@@ -2,6 +2,10 @@
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_repository::MetricContainerDayRepository;
 use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_repository::MetricContainerHourRepository;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_repository::MetricContainerMinuteRepository;
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::month::metric_month_repository::MetricMonthRepository;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repository::MetricNodeDayRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_repository::MetricNodeHourRepository;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_repository::MetricNodeMinuteRepository;
@@ -14,14 +18,17 @@ pub enum K8sMetricRepositoryVariant {
     NodeMinute(MetricNodeMinuteRepository),
     NodeHour(MetricNodeHourRepository),
     NodeDay(MetricNodeDayRepository),
+    NodeMonth(MetricMonthRepository<MetricNodeEntity>),
 
     // Pod
     PodMinute(MetricPodMinuteRepository),
     PodHour(MetricPodHourRepository),
     PodDay(MetricPodDayRepository),
+    PodMonth(MetricMonthRepository<MetricPodEntity>),
 
     // Container
     ContainerMinute(MetricContainerMinuteRepository),
     ContainerHour(MetricContainerHourRepository),
     ContainerDay(MetricContainerDayRepository),
+    ContainerMonth(MetricMonthRepository<MetricContainerEntity>),
 }
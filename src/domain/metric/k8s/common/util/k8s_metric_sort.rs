@@ -0,0 +1,76 @@
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::domain::metric::k8s::common::dto::MetricSeriesDto;
+
+/// List orderings computed from the requested window (`sort=cost`, `sort=usage`),
+/// always highest first. Every object's series is loaded before the page is cut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricSortKey {
+    /// Total cost over the window.
+    Cost,
+    /// Mean CPU usage in cores over the window.
+    Usage,
+}
+
+impl MetricSortKey {
+    pub fn from_query(q: &RangeQuery) -> Option<Self> {
+        match q.sort.as_deref() {
+            Some("cost") => Some(Self::Cost),
+            Some("usage") => Some(Self::Usage),
+            _ => None,
+        }
+    }
+}
+
+/// Summed cost of a priced series: the series summary when present (nodes),
+/// otherwise the per-point costs (pods).
+pub fn series_cost_usd(series: &MetricSeriesDto) -> f64 {
+    if let Some(total) = series.cost_summary.as_ref().and_then(|c| c.total_cost_usd) {
+        return total;
+    }
+    series
+        .points
+        .iter()
+        .filter_map(|p| p.cost.as_ref().and_then(|c| c.total_cost_usd))
+        .sum()
+}
+
+pub fn series_cpu_cores(series: &MetricSeriesDto) -> f64 {
+    let samples: Vec<f64> = series
+        .points
+        .iter()
+        .filter_map(|p| p.cpu_memory.cpu_usage_nano_cores)
+        .collect();
+    if samples.is_empty() {
+        return 0.0;
+    }
+    samples.iter().sum::<f64>() / samples.len() as f64 / 1_000_000_000.0
+}
+
+/// Indices of `keys` ordered highest first. The sort is stable, so ties keep the
+/// input order (name / UID) and pages stay deterministic.
+pub fn rank_desc(keys: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    order.sort_by(|&a, &b| keys[b].total_cmp(&keys[a]));
+    order
+}
+
+/// Offset/limit page for orderings that have no cursor key.
+pub fn offset_page(len: usize, offset: usize, limit: usize) -> std::ops::Range<usize> {
+    let start = offset.min(len);
+    let end = start.saturating_add(limit).min(len);
+    start..end
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranks_highest_first_with_stable_ties() {
+        assert_eq!(rank_desc(&[1.0, 3.0, 2.0, 3.0]), vec![1, 3, 2, 0]);
+        assert_eq!(offset_page(4, 3, 2), 3..4);
+        assert_eq!(offset_page(4, 9, 2), 4..4);
+    }
+}
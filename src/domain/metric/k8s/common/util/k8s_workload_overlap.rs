@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::domain::metric::k8s::common::dto::{
+    FilesystemMetricDto, MetricGetResponseDto, UniversalMetricPointDto,
+};
+use crate::domain::metric::k8s::common::service_helpers::{granularity_interval_hours, point_interval_hours};
+
+/// Lifecycle of one pod as far as overlap weighting needs it.
+struct PodLifetime {
+    workload: String,
+    started: Option<DateTime<Utc>>,
+    stopped: Option<DateTime<Utc>>,
+}
+
+/// Per-pod points for aggregation, one series per pod, weighting replicas that
+/// overlap within a bucket by the part of the bucket they were actually alive.
+///
+/// When a pod is evicted and its replacement starts in the same hour, both
+/// rows carry a full hour of memory and disk gauges, so the workload would be
+/// billed twice for that hour. Where two or more pods of the same workload
+/// report in one bucket, gauges of pods that started or stopped inside it are
+/// scaled by their alive fraction. Counters (CPU core-seconds, network bytes,
+/// page faults) already measure only what each pod used and are left alone.
+pub fn overlap_adjusted_points(
+    per_pod: &MetricGetResponseDto,
+    pods: &[InfoPodEntity],
+) -> Vec<Vec<UniversalMetricPointDto>> {
+    let lifetimes: HashMap<&str, PodLifetime> = pods
+        .iter()
+        .filter_map(|p| Some((p.pod_uid.as_deref()?, pod_lifetime(p)?)))
+        .collect();
+
+    let mut reporting: HashMap<(&str, DateTime<Utc>), usize> = HashMap::new();
+    for series in &per_pod.series {
+        if let Some(life) = lifetimes.get(series.key.as_str()) {
+            for point in &series.points {
+                *reporting.entry((life.workload.as_str(), point.time)).or_default() += 1;
+            }
+        }
+    }
+
+    let default_hours = granularity_interval_hours(&per_pod.granularity);
+    let mut out = Vec::with_capacity(per_pod.series.len());
+
+    for series in &per_pod.series {
+        let life = lifetimes.get(series.key.as_str());
+        let mut points = Vec::with_capacity(series.points.len());
+        for (idx, point) in series.points.iter().enumerate() {
+            let mut point = point.clone();
+            if let Some(life) = life {
+                let overlapping = reporting
+                    .get(&(life.workload.as_str(), point.time))
+                    .is_some_and(|n| *n > 1);
+                if overlapping {
+                    let hours = point_interval_hours(&series.points, idx, default_hours);
+                    let fraction = alive_fraction(life, point.time, hours);
+                    // A row outside the recorded lifetime means the lifecycle is off, not
+                    // that the pod used nothing; keep the row as scraped.
+                    if fraction > 0.0 && fraction < 1.0 {
+                        scale_gauges(&mut point, fraction);
+                    }
+                }
+            }
+            points.push(point);
+        }
+        out.push(points);
+    }
+
+    out
+}
+
+/// Workload key and alive range; `None` for bare pods, which have nothing to
+/// overlap with.
+fn pod_lifetime(pod: &InfoPodEntity) -> Option<PodLifetime> {
+    let (kind, name) = match (&pod.workload_kind, &pod.workload_name) {
+        (Some(kind), Some(name)) => (kind, name),
+        _ => (pod.owner_kind.as_ref()?, pod.owner_name.as_ref()?),
+    };
+    let namespace = pod.namespace.as_deref().unwrap_or_default();
+
+    Some(PodLifetime {
+        workload: format!("{namespace}/{kind}/{name}"),
+        started: pod.start_time.or(pod.creation_timestamp),
+        stopped: pod.deleted_at,
+    })
+}
+
+/// Share of `[bucket, bucket + hours)` the pod was alive; an unknown start or
+/// stop counts as open-ended.
+fn alive_fraction(life: &PodLifetime, bucket: DateTime<Utc>, hours: f64) -> f64 {
+    if hours <= 0.0 {
+        return 1.0;
+    }
+    let bucket_end = bucket + Duration::seconds((hours * 3600.0) as i64);
+    let from = life.started.map_or(bucket, |s| s.max(bucket));
+    let to = life.stopped.map_or(bucket_end, |s| s.min(bucket_end));
+    if to <= from {
+        return 0.0;
+    }
+    ((to - from).num_seconds() as f64 / 3600.0 / hours).min(1.0)
+}
+
+fn scale_gauges(point: &mut UniversalMetricPointDto, fraction: f64) {
+    let scale = |v: &mut Option<f64>| {
+        if let Some(v) = v {
+            *v *= fraction;
+        }
+    };
+    let scale_fs = |fs: &mut Option<FilesystemMetricDto>| {
+        if let Some(fs) = fs {
+            scale(&mut fs.used_bytes);
+            scale(&mut fs.capacity_bytes);
+            scale(&mut fs.inodes_used);
+            scale(&mut fs.inodes);
+        }
+    };
+
+    scale(&mut point.cpu_memory.cpu_usage_nano_cores);
+    scale(&mut point.cpu_memory.memory_usage_bytes);
+    scale(&mut point.cpu_memory.memory_working_set_bytes);
+    scale(&mut point.cpu_memory.memory_rss_bytes);
+    scale_fs(&mut point.filesystem);
+    if let Some(storage) = point.storage.as_mut() {
+        scale_fs(&mut storage.ephemeral);
+        scale_fs(&mut storage.persistent);
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::metric::k8s::common::dto::{
+        CommonMetricValuesDto, MetricGranularity, MetricScope, MetricSeriesDto,
+    };
+    use chrono::TimeZone;
+
+    fn pod(uid: &str, started: DateTime<Utc>, deleted: Option<DateTime<Utc>>) -> InfoPodEntity {
+        InfoPodEntity {
+            pod_uid: Some(uid.into()),
+            namespace: Some("shop".into()),
+            workload_kind: Some("Deployment".into()),
+            workload_name: Some("api".into()),
+            start_time: Some(started),
+            deleted_at: deleted,
+            ..Default::default()
+        }
+    }
+
+    fn series(uid: &str, times: &[DateTime<Utc>]) -> MetricSeriesDto {
+        MetricSeriesDto {
+            key: uid.into(),
+            name: uid.into(),
+            scope: MetricScope::Pod,
+            points: times
+                .iter()
+                .map(|t| UniversalMetricPointDto {
+                    time: *t,
+                    cpu_memory: CommonMetricValuesDto {
+                        memory_working_set_bytes: Some(1000.0),
+                        cpu_usage_core_nano_seconds: Some(500.0),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .collect(),
+            running_hours: None,
+            cost_summary: None,
+        }
+    }
+
+    #[test]
+    fn evicted_pod_and_replacement_share_the_hour() {
+        let h0 = Utc.with_ymd_and_hms(2025, 3, 1, 10, 0, 0).unwrap();
+        let h1 = h0 + Duration::hours(1);
+        let h2 = h0 + Duration::hours(2);
+        // Old pod evicted at 11:15, replacement up from 11:15
+        let old = pod("old", h0 - Duration::hours(5), Some(h1 + Duration::minutes(15)));
+        let new = pod("new", h1 + Duration::minutes(15), None);
+
+        let per_pod = MetricGetResponseDto {
+            start: h0,
+            end: h2,
+            scope: "pod".into(),
+            target: None,
+            granularity: MetricGranularity::Hour,
+            series: vec![series("old", &[h0, h1]), series("new", &[h1])],
+            total: None,
+            limit: None,
+            offset: None,
+            next_cursor: None,
+            warnings: Vec::new(),
+            partial: false,
+        };
+
+        let points: Vec<_> = overlap_adjusted_points(&per_pod, &[old, new]).into_iter().flatten().collect();
+        let memory_at = |t| -> f64 {
+            points
+                .iter()
+                .filter(|p| p.time == t)
+                .map(|p| p.cpu_memory.memory_working_set_bytes.unwrap())
+                .sum()
+        };
+
+        assert!((memory_at(h0) - 1000.0).abs() < 1e-9);
+        // 0.25 h of the old pod + 0.75 h of the new one
+        assert!((memory_at(h1) - 1000.0).abs() < 1e-9);
+        // Counters are untouched
+        let cpu: f64 = points.iter().filter_map(|p| p.cpu_memory.cpu_usage_core_nano_seconds).sum();
+        assert!((cpu - 1500.0).abs() < 1e-9);
+    }
+}
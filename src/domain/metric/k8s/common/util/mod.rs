@@ -1,3 +1,10 @@
 pub mod k8s_metric_repository_variant;
 pub mod k8s_metric_repository_resolve;
-pub mod k8s_metric_determine_granularity;
\ No newline at end of file
+pub mod k8s_metric_determine_granularity;
+pub mod k8s_metric_cursor;
+pub mod k8s_metric_sort;
+pub mod k8s_metric_filter;
+pub mod k8s_commitment_coverage;
+pub mod k8s_workload_overlap;
+pub mod k8s_metric_query_cache;
+pub mod k8s_efficiency_weights;
@@ -1,26 +1,35 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::api::dto::{info_dto::K8sListQuery, metrics_dto::RangeQuery};
+use crate::api::dto::{info_dto::K8sListQuery, metrics_dto::{CostBasis, RangeQuery}};
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
-use crate::core::persistence::metrics::k8s::container::day::metric_container_day_api_repository_trait::MetricContainerDayApiRepository;
 use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_api_repository_trait::MetricContainerHourApiRepository;
+use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_repository::MetricContainerHourRepository;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_api_repository_trait::MetricContainerMinuteApiRepository;
+use crate::core::persistence::metrics::k8s::path::metric_k8s_container_dir_path;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
+use crate::domain::common::service::day_granularity::split_day_granularity_rows;
 use crate::domain::info::service::{info_k8s_container_service, info_unit_price_service};
 use crate::domain::metric::k8s::common::dto::{
     CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
-    UniversalMetricPointDto,
+    NetworkMetricDto, UniversalMetricPointDto,
 };
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
+use crate::domain::metric::k8s::common::dto::metric_k8s_stream_dto::{MetricSeriesStream, MetricStreamHeaderDto};
 use crate::domain::metric::k8s::common::service_helpers::{
-    apply_costs, build_cost_summary_dto, build_cost_trend_dto, build_efficiency_value,
-    build_raw_summary_value, resolve_time_window, TimeWindow, BYTES_PER_GB,
+    apply_costs_with_basis, apply_virtual_node_costs, build_cost_breakdown_dto, build_cost_summary_dto,
+    build_cost_trend_dto, build_efficiency_value, build_raw_summary_value, minute_row_hours, month_row_hours,
+    resolve_time_window, virtual_node_rates, EfficiencyBaselines, ResourceRequests, TimeWindow, BYTES_PER_GB,
 };
+use crate::domain::metric::k8s::node::service::hosting_node_allocations;
+use crate::domain::metric::k8s::common::util::k8s_metric_filter::MetricFilters;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
+use crate::domain::metric::k8s::pod::service::{pod_network_by_time, PodMetricRepos};
 
 fn container_metric_key(info: &InfoContainerEntity) -> Option<String> {
     match (&info.pod_uid, &info.container_name) {
@@ -29,28 +38,132 @@ fn container_metric_key(info: &InfoContainerEntity) -> Option<String> {
     }
 }
 
-fn fetch_container_points(
+/// Rows plus the hours the container reported within the window.
+fn fetch_container_rows(
     repo: &K8sMetricRepositoryVariant,
     container_key: &str,
     window: &TimeWindow,
-) -> Result<Vec<UniversalMetricPointDto>> {
-    let rows = match repo {
+) -> Result<(Vec<MetricContainerEntity>, f64)> {
+    let (rows, running_hours) = match repo {
         K8sMetricRepositoryVariant::ContainerMinute(r) => {
-            r.get_row_between(window.start, window.end, container_key, None, None)
+            let rows = r.get_row_between(window.start, window.end, container_key, None, None)?;
+            let running_hours = rows.len() as f64 * minute_row_hours();
+            (rows, running_hours)
         }
         K8sMetricRepositoryVariant::ContainerHour(r) => {
-            r.get_row_between(window.start, window.end, container_key, None, None)
+            let rows = r.get_row_between(window.start, window.end, container_key, None, None)?;
+            let running_hours = rows.len() as f64;
+            (rows, running_hours)
         }
-        K8sMetricRepositoryVariant::ContainerDay(r) => {
-            r.get_row_between(window.start, window.end, container_key, None, None)
+        K8sMetricRepositoryVariant::ContainerDay(day_repo) => {
+            // Partial start/end days come from hour rows, like pods and nodes
+            let hour_repo = MetricContainerHourRepository::new();
+            let split = split_day_granularity_rows(container_key, window, day_repo, &hour_repo)?;
+
+            let running_hours = split.running_hours();
+            let mut merged = split.into_rows();
+            merged.sort_by_key(|r| r.time);
+            (merged, running_hours)
         }
-        _ => Ok(vec![]),
-    }?;
+        K8sMetricRepositoryVariant::ContainerMonth(r) => {
+            let rows = r.get_row_between(container_key, window.start, window.end)?;
+            let running_hours = rows.iter().map(|r| month_row_hours(r.time)).sum();
+            (rows, running_hours)
+        }
+        _ => (vec![], 0.0),
+    };
+
+    Ok((rows, running_hours))
+}
+
+/// Splits pod network traffic across the pod's containers. Containers share the
+/// pod's network namespace, so kubelet only reports traffic per pod; each container
+/// gets its share of the pod's CPU over the window (an even split when no container
+/// reported CPU), so a busy sidecar like `istio-proxy` carries most of it.
+struct PodNetworkAttribution {
+    /// Keys of every container with metrics, sorted so a pod's keys are adjacent
+    container_keys: Vec<String>,
+    pods: HashMap<String, PodNetworkShares>,
+    /// Taken up front: a streamed response reads pods after the request scope ends
+    pod_repos: PodMetricRepos,
+}
+
+struct PodNetworkShares {
+    network: HashMap<DateTime<Utc>, NetworkMetricDto>,
+    /// Share of the pod's traffic per container key
+    shares: HashMap<String, f64>,
+}
+
+impl PodNetworkAttribution {
+    fn new() -> Result<Self> {
+        let mut container_keys = metric_object_keys("container", &metric_k8s_container_dir_path())?;
+        container_keys.sort();
+        Ok(Self {
+            container_keys,
+            pods: HashMap::new(),
+            pod_repos: PodMetricRepos::new(),
+        })
+    }
+
+    /// Network of `key`'s point at `time`, one lookup per point of the container.
+    fn network_of(
+        &mut self,
+        pod_uid: &str,
+        key: &str,
+        repo: &K8sMetricRepositoryVariant,
+        window: &TimeWindow,
+    ) -> Result<impl Fn(DateTime<Utc>) -> Option<NetworkMetricDto> + '_> {
+        if !self.pods.contains_key(pod_uid) {
+            let shares = self.load(pod_uid, repo, window)?;
+            self.pods.insert(pod_uid.to_string(), shares);
+        }
+        let pod = &self.pods[pod_uid];
+        let share = pod.shares.get(key).copied().unwrap_or(0.0);
+        Ok(move |time| pod.network.get(&time).map(|n| scale_network(n, share)))
+    }
+
+    fn load(&self, pod_uid: &str, repo: &K8sMetricRepositoryVariant, window: &TimeWindow) -> Result<PodNetworkShares> {
+        let network = pod_network_by_time(pod_uid, window, &self.pod_repos)?;
+        if network.is_empty() {
+            return Ok(PodNetworkShares { network, shares: HashMap::new() });
+        }
+
+        let prefix = format!("{}-", pod_uid);
+        let mut cpu: Vec<(String, f64)> = Vec::new();
+        for key in self.container_keys.iter().filter(|k| k.starts_with(&prefix)) {
+            let (rows, _) = fetch_container_rows(repo, key, window)?;
+            let nano_cores: f64 = rows.iter().filter_map(|r| r.cpu_usage_nano_cores).map(|v| v as f64).sum();
+            cpu.push((key.clone(), nano_cores));
+        }
+
+        Ok(PodNetworkShares { network, shares: network_shares(&cpu) })
+    }
+}
 
-    Ok(rows.into_iter().map(metric_container_entity_to_point).collect())
+/// Each container's share of the total CPU, or an even split without any CPU.
+fn network_shares(cpu: &[(String, f64)]) -> HashMap<String, f64> {
+    let total: f64 = cpu.iter().map(|(_, v)| v).sum();
+    cpu.iter()
+        .map(|(key, v)| {
+            let share = if total > 0.0 { v / total } else { 1.0 / cpu.len() as f64 };
+            (key.clone(), share)
+        })
+        .collect()
 }
 
-fn metric_container_entity_to_point(entity: MetricContainerEntity) -> UniversalMetricPointDto {
+fn scale_network(n: &NetworkMetricDto, share: f64) -> NetworkMetricDto {
+    NetworkMetricDto {
+        rx_bytes: n.rx_bytes.map(|v| v * share),
+        tx_bytes: n.tx_bytes.map(|v| v * share),
+        rx_errors: n.rx_errors.map(|v| v * share),
+        tx_errors: n.tx_errors.map(|v| v * share),
+    }
+}
+
+fn metric_container_entity_to_point(
+    entity: MetricContainerEntity,
+    network: Option<NetworkMetricDto>,
+) -> UniversalMetricPointDto {
     UniversalMetricPointDto {
         time: entity.time,
         cpu_memory: CommonMetricValuesDto {
@@ -67,6 +180,7 @@ fn metric_container_entity_to_point(entity: MetricContainerEntity) -> UniversalM
             inodes_used: entity.fs_inodes_used.map(|v| v as f64),
             inodes: entity.fs_inodes.map(|v| v as f64),
         }),
+        network,
         ..Default::default()
     }
 }
@@ -75,13 +189,50 @@ async fn build_container_raw_data(
     q: RangeQuery,
     container_keys: Vec<String>,
 ) -> Result<(MetricGetResponseDto, Vec<InfoContainerEntity>)> {
-    let window = resolve_time_window(&q);
+    let container_infos = load_container_infos(&q, container_keys).await?;
+    let response = build_container_response_from_infos(&q, &container_infos)?;
+    Ok((response, container_infos))
+}
+
+fn build_container_response_from_infos(
+    q: &RangeQuery,
+    container_infos: &[InfoContainerEntity],
+) -> Result<MetricGetResponseDto> {
+    let window = resolve_time_window(q);
     let repo = resolve_k8s_metric_repository(&MetricScope::Container, &window.granularity);
 
+    // 3. Build metric series
+    let mut network = PodNetworkAttribution::new()?;
+    let series = container_infos
+        .iter()
+        .filter_map(|container| container_series(container, &repo, &window, &mut network))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(MetricGetResponseDto {
+        start: window.start,
+        end: window.end,
+        scope: "container".to_string(),
+        target: None, // target only used for "single" calls; we keep it None here
+        granularity: window.granularity.clone(),
+        series,
+        total: None,
+        limit: None,
+        offset: None,
+        next_cursor: None,
+        warnings: Vec::new(),
+        partial: false,
+    })
+}
+
+async fn load_container_infos(
+    q: &RangeQuery,
+    container_keys: Vec<String>,
+) -> Result<Vec<InfoContainerEntity>> {
     // 1. Load containers via service (as you already do today)
     let mut container_infos =
         info_k8s_container_service::list_k8s_containers(K8sListQuery {
-            namespace: q.namespace.clone(),
+            // Namespace may be a list or pattern, so it's matched below instead
+            namespace: None,
             label_selector: None,
             node_name: None,
         })
@@ -97,61 +248,53 @@ async fn build_container_raw_data(
         });
     }
 
-    // 2. Apply filtering: team, service, env
-    let matches = |value: &Option<String>, filter: &str| {
-        value
-            .as_deref()
-            .map(|v| {
-                v.split(',')
-                    .any(|x| x.trim().eq_ignore_ascii_case(filter.trim()))
-            })
-            .unwrap_or(false)
-    };
+    // 2. Apply filtering: namespace, team, service, env
+    let filters = MetricFilters::from_query(q)?;
+    container_infos.retain(|c| {
+        filters.matches_namespace(&c.namespace)
+            && filters.matches_attribution(&c.team, &c.service, &c.env)
+    });
 
-    if let Some(ref team) = q.team {
-        container_infos.retain(|c| matches(&c.team, team));
-    }
-    if let Some(ref service) = q.service {
-        container_infos.retain(|c| matches(&c.service, service));
-    }
-    if let Some(ref env) = q.env {
-        container_infos.retain(|c| matches(&c.env, env));
-    }
-
-    // 3. Build metric series
-    let mut series = Vec::new();
-    for container in container_infos.iter() {
-        if let Some(key) = container_metric_key(container) {
-            let points = fetch_container_points(&repo, &key, &window)?;
-            let name = container
-                .container_name
-                .clone()
-                .unwrap_or_else(|| key.clone());
-
-            series.push(MetricSeriesDto {
-                key,
-                name,
-                scope: MetricScope::Container,
-                points,
-                running_hours: None,
-                cost_summary: None,
-            });
-        }
-    }
+    Ok(container_infos)
+}
 
-    let response = MetricGetResponseDto {
-        start: window.start,
-        end: window.end,
-        scope: "container".to_string(),
-        target: None, // target only used for "single" calls; we keep it None here
-        granularity: window.granularity.clone(),
-        series,
-        total: None,
-        limit: None,
-        offset: None,
+/// `None` for containers without a pod UID or name (no metric key).
+fn container_series(
+    container: &InfoContainerEntity,
+    repo: &K8sMetricRepositoryVariant,
+    window: &TimeWindow,
+    network: &mut PodNetworkAttribution,
+) -> Option<Result<MetricSeriesDto>> {
+    let key = container_metric_key(container)?;
+    let pod_uid = container.pod_uid.as_deref()?;
+    let fetched = fetch_container_rows(repo, &key, window).and_then(|(rows, running_hours)| {
+        let network_at = network.network_of(pod_uid, &key, repo, window)?;
+        let points = rows
+            .into_iter()
+            .map(|row| {
+                let n = network_at(row.time);
+                metric_container_entity_to_point(row, n)
+            })
+            .collect::<Vec<_>>();
+        Ok((points, running_hours))
+    });
+    let (points, running_hours) = match fetched {
+        Ok(fetched) => fetched,
+        Err(e) => return Some(Err(e)),
     };
-
-    Ok((response, container_infos))
+    let name = container
+        .container_name
+        .clone()
+        .unwrap_or_else(|| key.clone());
+
+    Some(Ok(MetricSeriesDto {
+        key,
+        name,
+        scope: MetricScope::Container,
+        points,
+        running_hours: Some(running_hours),
+        cost_summary: None,
+    }))
 }
 
 fn sum_container_requests(containers: &[InfoContainerEntity]) -> (f64, f64) {
@@ -166,16 +309,52 @@ fn sum_container_requests(containers: &[InfoContainerEntity]) -> (f64, f64) {
     (total_cpu, total_mem_gb)
 }
 
+/// Requests and limits of `containers`, against the allocatable capacity of
+/// the nodes they run on.
+async fn container_baselines(containers: &[InfoContainerEntity]) -> EfficiencyBaselines {
+    let (cpu, mem) = hosting_node_allocations(containers.iter().filter_map(|c| c.node_name.as_deref())).await;
+    EfficiencyBaselines::from_containers(containers).with_allocatable(cpu, mem)
+}
+
 async fn build_container_cost_response(
     q: RangeQuery,
     container_keys: Vec<String>,
     unit_prices: InfoUnitPriceEntity,
 ) -> Result<MetricGetResponseDto> {
-    let (mut response, _) = build_container_raw_data(q, container_keys).await?;
-    apply_costs(&mut response, &unit_prices);
+    let basis = q.effective_cost_basis();
+    let (mut response, container_infos) = build_container_raw_data(q, container_keys).await?;
+    price_container_response(&mut response, &container_infos, basis, &unit_prices).await?;
     Ok(response)
 }
 
+async fn price_container_response(
+    response: &mut MetricGetResponseDto,
+    container_infos: &[InfoContainerEntity],
+    basis: CostBasis,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<()> {
+    let requests: HashMap<String, ResourceRequests> = container_infos
+        .iter()
+        .filter_map(|c| {
+            let key = container_metric_key(c)?;
+            Some((key, ResourceRequests {
+                cpu_cores: c.cpu_request_millicores.unwrap_or(0) as f64 / 1000.0,
+                memory_bytes: c.memory_request_bytes.unwrap_or(0) as f64,
+            }))
+        })
+        .collect();
+
+    let node_rates = virtual_node_rates(container_infos.iter().filter_map(|c| c.node_name.as_deref())).await?;
+    let virtual_containers: HashMap<String, _> = container_infos
+        .iter()
+        .filter_map(|c| Some((container_metric_key(c)?, *node_rates.get(c.node_name.as_deref()?)?)))
+        .collect();
+
+    apply_costs_with_basis(response, unit_prices, basis, &requests);
+    apply_virtual_node_costs(response, &virtual_containers, &requests);
+    Ok(())
+}
+
 // ======================================================================
 // PUBLIC APIS (MATCH DELEGATE SIGNATURES)
 // ======================================================================
@@ -190,6 +369,35 @@ pub async fn get_metric_k8s_containers_raw(
     Ok(serde_json::to_value(response)?)
 }
 
+/// Same as [`get_metric_k8s_containers_raw`], but yields one series at a time.
+pub async fn stream_metric_k8s_containers_raw(
+    q: RangeQuery,
+    container_keys: Vec<String>,
+) -> Result<MetricSeriesStream> {
+    let window = resolve_time_window(&q);
+    let repo = resolve_k8s_metric_repository(&MetricScope::Container, &window.granularity);
+    let container_infos = load_container_infos(&q, container_keys).await?;
+
+    let header = MetricStreamHeaderDto {
+        start: window.start,
+        end: window.end,
+        scope: "container".to_string(),
+        target: None,
+        granularity: window.granularity.clone(),
+        total: None,
+        limit: None,
+        offset: None,
+        next_cursor: None,
+    };
+
+    let mut network = PodNetworkAttribution::new()?;
+    let series = container_infos
+        .into_iter()
+        .filter_map(move |container| container_series(&container, &repo, &window, &mut network));
+
+    Ok(MetricSeriesStream::new(header, series))
+}
+
 pub async fn get_metric_k8s_containers_raw_summary(
     q: RangeQuery,
     container_keys: Vec<String>,
@@ -216,6 +424,7 @@ pub async fn get_metric_k8s_containers_raw_efficiency(
         total_cpu,
         total_mem_gb,
         total_storage_gb,
+        container_baselines(&containers).await,
     )
 }
 
@@ -257,6 +466,7 @@ pub async fn get_metric_k8s_container_raw_efficiency(
         total_cpu,
         total_mem_gb,
         total_storage_gb,
+        container_baselines(&containers).await,
     )
 }
 
@@ -328,3 +538,41 @@ pub async fn get_metric_k8s_container_cost_trend(
     let dto = build_cost_trend_dto(&response, MetricScope::Container, Some(id))?;
     Ok(serde_json::to_value(dto)?)
 }
+
+// ---------- COST: containers of one pod ----------
+
+/// The pod's cost split across its containers, most expensive first.
+pub async fn get_metric_k8s_pod_cost_breakdown(pod_uid: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+
+    let mut container_infos = load_container_infos(&q, Vec::new()).await?;
+    container_infos.retain(|c| c.pod_uid.as_deref() == Some(pod_uid.as_str()));
+    if container_infos.is_empty() {
+        return Err(anyhow!("pod '{}' has no containers", pod_uid));
+    }
+
+    let mut response = build_container_response_from_infos(&q, &container_infos)?;
+    price_container_response(&mut response, &container_infos, q.effective_cost_basis(), &unit_prices).await?;
+
+    let dto = build_cost_breakdown_dto(&response, MetricScope::Pod, Some(pod_uid), &unit_prices);
+    Ok(serde_json::to_value(dto)?)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_follows_cpu_share_or_splits_evenly() {
+        let shares = network_shares(&[("p-app".into(), 1.0), ("p-istio-proxy".into(), 3.0)]);
+        assert_eq!(shares["p-istio-proxy"], 0.75);
+
+        let shares = network_shares(&[("p-a".into(), 0.0), ("p-b".into(), 0.0)]);
+        assert_eq!(shares["p-a"], 0.5);
+
+        let n = scale_network(&NetworkMetricDto { rx_bytes: Some(400.0), ..Default::default() }, 0.25);
+        assert_eq!((n.rx_bytes, n.tx_bytes), (Some(100.0), None));
+    }
+}
@@ -1,50 +1,41 @@
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
 use serde_json::{json, Value};
-use std::{collections::{HashMap, HashSet}, fs};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use crate::api::dto::metrics_dto::RangeQuery;
-use crate::core::persistence::info::{
-    k8s::pod::{info_pod_entity::InfoPodEntity, info_pod_repository::InfoPodRepository},
-    path::info_k8s_pod_dir_path,
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::repositories::repositories;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_trend_dto::{
+    CostGrowthDriver, MetricCostTrendResponseDto, MetricReplicaTrendDto,
 };
-use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
 use crate::domain::metric::k8s::common::dto::{
-    MetricGetResponseDto, MetricScope, MetricSeriesDto, UniversalMetricPointDto,
+    MetricGetResponseDto, MetricScope, MetricSeriesDto,
 };
 use crate::domain::metric::k8s::common::service_helpers::{
-    apply_costs, build_cost_summary_dto, build_cost_trend_dto, build_raw_summary_value,
+    align_to_step, apply_costs, build_cost_summary_dto, build_cost_trend_dto, build_raw_summary_value,
+    resolve_time_window, sample_step, sum_running_hours,
 };
 use crate::domain::metric::k8s::namespace::service::aggregate_namespace_points;
 
 use crate::domain::info::service::info_unit_price_service;
 use crate::domain::metric::k8s::pod::service::build_pod_response_from_infos;
+use crate::domain::metric::k8s::common::util::k8s_workload_overlap::overlap_adjusted_points;
 
 // ------------------------------
 // Helpers
 // ------------------------------
 
 /// Load pods grouped by deployment name from local pod info.
-fn load_pods_by_deployment(filter: &[String]) -> Result<HashMap<String, Vec<InfoPodEntity>>> {
-    let mut map = HashMap::new();
-    let dir = info_k8s_pod_dir_path();
-
-    if !dir.exists() {
-        return Ok(map);
-    }
-
+async fn load_pods_by_deployment(filter: &[String]) -> Result<HashMap<String, Vec<InfoPodEntity>>> {
+    let mut map: HashMap<String, Vec<InfoPodEntity>> = HashMap::new();
     let filters: HashSet<String> = filter.iter().cloned().collect();
     let allow_all = filters.is_empty();
-    let repo = InfoPodRepository::new();
 
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let pod_uid = entry.file_name().to_string_lossy().to_string();
-
-        if let Ok(pod) = repo.read(&pod_uid) {
-            if let Some(owner) = pod.owner_name.clone() {
-                if allow_all || filters.contains(&owner) {
-                    map.entry(owner).or_default().push(pod);
-                }
+    for pod in repositories().info_pods.list().await? {
+        if let Some(deployment) = pod_deployment(&pod) {
+            if allow_all || filters.contains(&deployment) {
+                map.entry(deployment).or_default().push(pod);
             }
         }
     }
@@ -52,8 +43,24 @@ fn load_pods_by_deployment(filter: &[String]) -> Result<HashMap<String, Vec<Info
     Ok(map)
 }
 
-fn pods_for_deployment(depl: &str) -> Result<Vec<InfoPodEntity>> {
-    let map = load_pods_by_deployment(&[depl.to_string()])?;
+/// Deployment a pod belongs to. Uses the workload resolved by discovery, falling back
+/// to stripping the pod-template hash from a ReplicaSet owner for older records.
+pub(crate) fn pod_deployment(pod: &InfoPodEntity) -> Option<String> {
+    if pod.workload_kind.is_some() {
+        return match pod.workload_kind.as_deref() {
+            Some("Deployment") => pod.workload_name.clone(),
+            _ => None,
+        };
+    }
+
+    match (pod.owner_kind.as_deref(), pod.owner_name.as_deref()) {
+        (Some("ReplicaSet"), Some(rs)) => rs.rsplit_once('-').map(|(base, _)| base.to_string()),
+        _ => None,
+    }
+}
+
+async fn pods_for_deployment(depl: &str) -> Result<Vec<InfoPodEntity>> {
+    let map = load_pods_by_deployment(&[depl.to_string()]).await?;
 
     if let Some(pods) = map.get(depl) {
         if !pods.is_empty() {
@@ -64,8 +71,8 @@ fn pods_for_deployment(depl: &str) -> Result<Vec<InfoPodEntity>> {
     Err(anyhow!("deployment '{}' has no pods", depl))
 }
 
-fn all_pods_for(deployments: &[String]) -> Result<Vec<InfoPodEntity>> {
-    let map = load_pods_by_deployment(deployments)?;
+async fn all_pods_for(deployments: &[String]) -> Result<Vec<InfoPodEntity>> {
+    let map = load_pods_by_deployment(deployments).await?;
     Ok(map.into_values().flatten().collect())
 }
 
@@ -83,11 +90,12 @@ fn collect_targets(
 fn aggregate_deployment_response(
     deployment: &str,
     per_pod_response: &MetricGetResponseDto,
+    pods: &[InfoPodEntity],
+    step: Duration,
 ) -> MetricGetResponseDto {
-    let all_points: Vec<UniversalMetricPointDto> =
-        per_pod_response.series.iter().flat_map(|s| s.points.clone()).collect();
+    let pod_points = overlap_adjusted_points(per_pod_response, pods);
 
-    let aggregated_points = aggregate_namespace_points(all_points);
+    let aggregated_points = aggregate_namespace_points(pod_points, step);
 
     MetricGetResponseDto {
         start: per_pod_response.start,
@@ -100,12 +108,15 @@ fn aggregate_deployment_response(
             name: deployment.to_string(),
             scope: MetricScope::Deployment,
             points: aggregated_points,
-            running_hours: None,
+            running_hours: sum_running_hours(&per_pod_response.series),
             cost_summary: None,
         }],
         total: None,
         limit: None,
         offset: None,
+        next_cursor: None,
+        warnings: Vec::new(),
+        partial: false,
     }
 }
 
@@ -117,11 +128,12 @@ pub async fn get_metric_k8s_deployments_raw(
     q: RangeQuery,
     deployments: Vec<String>,
 ) -> Result<Value> {
-    let map = load_pods_by_deployment(&deployments)?;
+    let map = load_pods_by_deployment(&deployments).await?;
     let target_list = collect_targets(deployments, &map);
 
     let mut series = Vec::new();
     let mut base = None;
+    let step = sample_step(&resolve_time_window(&q).granularity);
 
     for depl in target_list {
         if let Some(pods) = map.get(&depl) {
@@ -129,7 +141,7 @@ pub async fn get_metric_k8s_deployments_raw(
                 continue;
             }
             let pod_response = build_pod_response_from_infos(q.clone(), pods.clone(), Some(depl.clone()))?;
-            let aggregated = aggregate_deployment_response(&depl, &pod_response);
+            let aggregated = aggregate_deployment_response(&depl, &pod_response, pods, step);
 
             if base.is_none() {
                 base = Some(aggregated.clone());
@@ -155,9 +167,9 @@ pub async fn get_metric_k8s_deployment_raw(
     name: String,
     q: RangeQuery,
 ) -> Result<Value> {
-    let pods = pods_for_deployment(&name)?;
-    let pod_response = build_pod_response_from_infos(q, pods, Some(name.clone()))?;
-    let aggregated = aggregate_deployment_response(&name, &pod_response);
+    let pods = pods_for_deployment(&name).await?;
+    let pod_response = build_pod_response_from_infos(q, pods.clone(), Some(name.clone()))?;
+    let aggregated = aggregate_deployment_response(&name, &pod_response, &pods, sample_step(&pod_response.granularity));
 
     Ok(serde_json::to_value(aggregated)?)
 }
@@ -170,7 +182,7 @@ pub async fn get_metric_k8s_deployments_raw_summary(
     q: RangeQuery,
     deployments: Vec<String>,
 ) -> Result<Value> {
-    let map = load_pods_by_deployment(&deployments)?;
+    let map = load_pods_by_deployment(&deployments).await?;
     let target_list = collect_targets(deployments, &map);
 
     let mut all_pods = Vec::new();
@@ -185,7 +197,7 @@ pub async fn get_metric_k8s_deployments_raw_summary(
     }
 
     let per_pod = build_pod_response_from_infos(q, all_pods.clone(), None)?;
-    let aggregated = aggregate_deployment_response("all", &per_pod);
+    let aggregated = aggregate_deployment_response("all", &per_pod, &all_pods, sample_step(&per_pod.granularity));
 
     build_raw_summary_value(&aggregated, MetricScope::Deployment, all_pods.len())
 }
@@ -198,9 +210,9 @@ pub async fn get_metric_k8s_deployment_raw_summary(
     name: String,
     q: RangeQuery,
 ) -> Result<Value> {
-    let pods = pods_for_deployment(&name)?;
+    let pods = pods_for_deployment(&name).await?;
     let per_pod = build_pod_response_from_infos(q, pods.clone(), Some(name.clone()))?;
-    let aggregated = aggregate_deployment_response(&name, &per_pod);
+    let aggregated = aggregate_deployment_response(&name, &per_pod, &pods, sample_step(&per_pod.granularity));
 
     build_raw_summary_value(&aggregated, MetricScope::Deployment, pods.len())
 }
@@ -238,20 +250,102 @@ async fn build_deployment_cost(
     q: RangeQuery,
     filter: &[String],
 ) -> Result<MetricGetResponseDto> {
+    let (response, _) = build_deployment_cost_with_replicas(deployment, q, filter).await?;
+    Ok(response)
+}
+
+/// Like [`build_deployment_cost`], also returning the replica count per bucket.
+async fn build_deployment_cost_with_replicas(
+    deployment: Option<String>,
+    q: RangeQuery,
+    filter: &[String],
+) -> Result<(MetricGetResponseDto, BTreeMap<DateTime<Utc>, u32>)> {
     let pods = match deployment.as_ref() {
-        Some(name) => pods_for_deployment(name)?,
-        None => all_pods_for(filter)?,
+        Some(name) => pods_for_deployment(name).await?,
+        None => all_pods_for(filter).await?,
     };
 
     if pods.is_empty() {
         return Err(anyhow!("no pods available for deployment cost calculation"));
     }
 
-    let per_pod = build_pod_response_from_infos(q, pods, deployment.clone())?;
-    Ok(aggregate_deployment_response(
+    let per_pod = build_pod_response_from_infos(q, pods.clone(), deployment.clone())?;
+    let step = sample_step(&per_pod.granularity);
+    let replicas = replica_counts(&per_pod, step);
+    let response = aggregate_deployment_response(
         deployment.as_deref().unwrap_or("all"),
         &per_pod,
-    ))
+        &pods,
+        step,
+    );
+    Ok((response, replicas))
+}
+
+/// Pods reporting in each bucket, keyed like the aggregated points; a pod with
+/// two samples in one step counts once. Exact at minute and hour granularity;
+/// a day bucket counts every pod that ran that day, so churn inflates it.
+fn replica_counts(per_pod: &MetricGetResponseDto, step: Duration) -> BTreeMap<DateTime<Utc>, u32> {
+    let mut counts = BTreeMap::new();
+    for series in &per_pod.series {
+        let buckets: BTreeSet<DateTime<Utc>> =
+            series.points.iter().map(|p| align_to_step(p.time, step)).collect();
+        for bucket in buckets {
+            *counts.entry(bucket).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Adds replica counts and cost per replica to each trend point, and compares
+/// how much of the change came from scaling vs. each replica costing more.
+fn apply_replica_overlay(
+    trend: &mut MetricCostTrendResponseDto,
+    replicas: &BTreeMap<DateTime<Utc>, u32>,
+) {
+    for point in &mut trend.points {
+        if let Some(&n) = replicas.get(&point.time).filter(|n| **n > 0) {
+            point.replicas = Some(n);
+            point.cost_per_replica_usd = Some(point.total_cost_usd / n as f64);
+        }
+    }
+
+    let counted: Vec<_> = trend.points.iter().filter(|p| p.replicas.is_some()).collect();
+    let (Some(first), Some(last)) = (counted.first(), counted.last()) else {
+        return;
+    };
+
+    let start_replicas = first.replicas.unwrap_or(0);
+    let end_replicas = last.replicas.unwrap_or(0);
+    let start_per_replica = first.cost_per_replica_usd.unwrap_or(0.0);
+    let end_per_replica = last.cost_per_replica_usd.unwrap_or(0.0);
+
+    // Cost = replicas × cost per replica, so the log ratios add up to the cost's
+    let driver = if start_per_replica > 0.0 && end_per_replica > 0.0 {
+        let scaling = (end_replicas as f64 / start_replicas as f64).ln().abs();
+        let per_replica = (end_per_replica / start_per_replica).ln().abs();
+        match (scaling, per_replica) {
+            (s, p) if s == 0.0 && p == 0.0 => None,
+            (s, p) if s >= p => Some(CostGrowthDriver::Scaling),
+            _ => Some(CostGrowthDriver::PerReplica),
+        }
+    } else {
+        None
+    };
+
+    trend.replicas = Some(MetricReplicaTrendDto {
+        start_replicas,
+        end_replicas,
+        max_replicas: trend.points.iter().filter_map(|p| p.replicas).max().unwrap_or(0),
+        replica_growth_percent: growth_percent(start_replicas as f64, end_replicas as f64),
+        start_cost_per_replica_usd: start_per_replica,
+        end_cost_per_replica_usd: end_per_replica,
+        cost_per_replica_growth_percent: growth_percent(start_per_replica, end_per_replica),
+        driver,
+    });
+}
+
+fn growth_percent(from: f64, to: f64) -> f64 {
+    if from > 0.0 { (to - from) / from * 100.0 } else { 0.0 }
 }
 
 // ------------------------------
@@ -287,12 +381,13 @@ pub async fn get_metric_k8s_deployments_cost_trend(
     q: RangeQuery,
     deployments: Vec<String>,
 ) -> Result<Value> {
-    let mut dto = build_deployment_cost(None, q, &deployments).await?;
+    let (mut dto, replicas) = build_deployment_cost_with_replicas(None, q, &deployments).await?;
 
     let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
     apply_costs(&mut dto, &unit_prices);
 
-    let trend = build_cost_trend_dto(&dto, MetricScope::Deployment, None)?;
+    let mut trend = build_cost_trend_dto(&dto, MetricScope::Deployment, None)?;
+    apply_replica_overlay(&mut trend, &replicas);
     Ok(serde_json::to_value(trend)?)
 }
 
@@ -329,11 +424,56 @@ pub async fn get_metric_k8s_deployment_cost_trend(
     name: String,
     q: RangeQuery,
 ) -> Result<Value> {
-    let mut dto = build_deployment_cost(Some(name.clone()), q, &[]).await?;
+    let (mut dto, replicas) = build_deployment_cost_with_replicas(Some(name.clone()), q, &[]).await?;
 
     let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
     apply_costs(&mut dto, &unit_prices);
 
-    let trend = build_cost_trend_dto(&dto, MetricScope::Deployment, Some(name))?;
+    let mut trend = build_cost_trend_dto(&dto, MetricScope::Deployment, Some(name))?;
+    apply_replica_overlay(&mut trend, &replicas);
     Ok(serde_json::to_value(trend)?)
 }
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::metric::k8s::common::dto::metric_k8s_cost_trend_dto::{MetricCostTrendDto, MetricCostTrendPointDto};
+    use crate::domain::metric::k8s::common::dto::MetricGranularity;
+    use chrono::TimeZone;
+
+    #[test]
+    fn overlay_attributes_growth_to_scaling_when_cost_per_replica_holds() {
+        let at = |h: u32| Utc.with_ymd_and_hms(2025, 3, 1, h, 0, 0).unwrap();
+        let point = |h: u32, total: f64| MetricCostTrendPointDto {
+            time: at(h),
+            total_cost_usd: total,
+            cpu_cost_usd: total,
+            memory_cost_usd: 0.0,
+            storage_cost_usd: 0.0,
+            replicas: None,
+            cost_per_replica_usd: None,
+        };
+        let mut trend = MetricCostTrendResponseDto {
+            start: at(0),
+            end: at(3),
+            scope: MetricScope::Deployment,
+            target: Some("api".into()),
+            granularity: MetricGranularity::Hour,
+            trend: MetricCostTrendDto::default(),
+            points: vec![point(0, 2.0), point(1, 4.0), point(2, 6.2)],
+            replicas: None,
+        };
+        let replicas = BTreeMap::from([(at(0), 2), (at(1), 4), (at(2), 6)]);
+
+        apply_replica_overlay(&mut trend, &replicas);
+
+        assert_eq!(trend.points[1].replicas, Some(4));
+        assert_eq!(trend.points[1].cost_per_replica_usd, Some(1.0));
+        let overlay = trend.replicas.unwrap();
+        assert_eq!((overlay.start_replicas, overlay.end_replicas, overlay.max_replicas), (2, 6, 6));
+        assert!((overlay.replica_growth_percent - 200.0).abs() < 1e-9);
+        assert_eq!(overlay.driver, Some(CostGrowthDriver::Scaling));
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::dto::metrics_dto::EfficiencyGroupBy;
+
+/// Letter grade of a score: A ≥ 90, B ≥ 80, C ≥ 70, D ≥ 60, F below.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EfficiencyGrade {
+    A,
+    B,
+    C,
+    D,
+    F,
+}
+
+impl EfficiencyGrade {
+    pub fn from_score(score: f64) -> Self {
+        match score {
+            s if s >= 90.0 => Self::A,
+            s if s >= 80.0 => Self::B,
+            s if s >= 70.0 => Self::C,
+            s if s >= 60.0 => Self::D,
+            _ => Self::F,
+        }
+    }
+}
+
+/// One team's or namespace's standing over the window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricEfficiencyScoreDto {
+    pub rank: usize,
+    pub key: String,
+    pub grade: EfficiencyGrade,
+    /// 0–100; half efficiency against target, a quarter each idle and over-request
+    pub score: f64,
+
+    /// Share of requested CPU + memory cost actually used
+    pub efficiency_percent: f64,
+    pub target_percent: f64,
+    /// Requested but unused CPU + memory cost
+    pub idle_cost_usd: f64,
+    /// `idle_cost_usd` as a share of requested cost
+    pub idle_percent: f64,
+    /// Share of requested cost held by pods using less than half their request,
+    /// except those scaled on CPU by an HPA
+    pub over_request_percent: f64,
+
+    pub requested_cost_usd: f64,
+    pub used_cost_usd: f64,
+    pub pods: usize,
+    /// Pods under half their request left out of `over_request_percent`
+    /// because an HPA scales their workload on CPU
+    #[serde(default)]
+    pub hpa_suppressed_pods: usize,
+    /// Those HPAs as `namespace/name`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hpas: Vec<String>,
+
+    /// LLM-written justification of the grade from the numbers above; only
+    /// with `explain=true`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricEfficiencyScoreResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub group_by: EfficiencyGroupBy,
+    /// Best score first
+    pub scores: Vec<MetricEfficiencyScoreDto>,
+    /// The leaderboard as Slack mrkdwn, ready to post
+    pub slack_text: String,
+    /// Why `explain=true` produced no explanations; the scores are still valid
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explain_error: Option<String>,
+}
@@ -0,0 +1 @@
+pub mod metric_efficiency_score_dto;
@@ -0,0 +1,351 @@
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use tracing::warn;
+
+use crate::api::dto::metrics_dto::{CostBasis, EfficiencyGroupBy, RangeQuery};
+use crate::core::persistence::info::fixed::efficiency_target::info_efficiency_target_entity::InfoEfficiencyTargetEntity;
+use crate::core::persistence::info::k8s::hpa::info_hpa_entity::InfoHpaEntity;
+use crate::core::persistence::info::k8s::hpa::info_hpa_fs_adapter::InfoHpaFsAdapter;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::domain::info::service::{info_efficiency_target_service, info_unit_price_service};
+use crate::domain::llm::service::llm_chat_service;
+use crate::domain::llm::service::llm_context_builder::{ContextPriority, LlmContextBuilder};
+use crate::domain::metric::k8s::common::dto::MetricSeriesDto;
+use crate::domain::metric::k8s::common::service_helpers::{apply_costs_with_basis, resolve_time_window};
+use crate::domain::metric::k8s::common::util::k8s_metric_filter::MetricFilters;
+use crate::domain::metric::k8s::deployment::service::pod_deployment;
+use crate::domain::metric::k8s::efficiency::dto::metric_efficiency_score_dto::{
+    EfficiencyGrade, MetricEfficiencyScoreDto, MetricEfficiencyScoreResponseDto,
+};
+use crate::domain::metric::k8s::namespace::service::load_pods_by_namespace;
+use crate::domain::metric::k8s::pod::service::{build_pod_response_from_infos, load_pod_requests};
+
+/// Group key for pods without a team.
+const UNASSIGNED: &str = "unassigned";
+
+/// A pod using less than this share of its request counts as over-requested.
+const OVER_REQUEST_USAGE_RATIO: f64 = 0.5;
+
+/// Context budget for `explain=true`; a leaderboard of a few dozen groups fits.
+const EXPLAIN_CONTEXT_TOKENS: usize = 4000;
+
+const EXPLAIN_INSTRUCTION: &str = "You explain RustCost efficiency grades. Scores are 0-100: half is \
+efficiency_percent against target_percent, a quarter each is 100 - idle_percent and 100 - over_request_percent. \
+Costs are USD over the whole window. Pods counted in hpa_suppressed_pods are scaled on CPU by the HPAs in \
+hpas; never suggest shrinking their requests. Only use numbers from the data; do not estimate or invent any.";
+
+const EXPLAIN_QUESTION: &str = "For every score in efficiency_scores, write one or two sentences justifying its \
+grade and naming the cost to act on first. Reply with only a JSON object mapping each score's key to its text.";
+
+/// CPU + memory cost of one pod on usage and on requests.
+#[derive(Debug, Clone, Default)]
+struct PodSpend {
+    used: f64,
+    requested: f64,
+    /// `namespace/name` of an HPA scaling the pod's workload on CPU
+    cpu_hpa: Option<String>,
+}
+
+fn compute_cost(series: &MetricSeriesDto) -> f64 {
+    series
+        .points
+        .iter()
+        .filter_map(|p| p.cost.as_ref())
+        .map(|c| c.cpu_cost_usd.unwrap_or(0.0) + c.memory_cost_usd.unwrap_or(0.0))
+        .sum()
+}
+
+/// The CPU-scaling HPA of the pod's workload, as `namespace/name`. Requests set
+/// the utilization such an HPA scales on, so shrinking them changes its behaviour.
+fn cpu_hpa(pod: &InfoPodEntity, hpas: &[InfoHpaEntity]) -> Option<String> {
+    let namespace = pod.namespace.as_deref()?;
+    let (kind, name) = match (pod.workload_kind.as_deref(), pod.workload_name.as_deref()) {
+        (Some(kind), Some(name)) => (kind.to_string(), name.to_string()),
+        _ => ("Deployment".to_string(), pod_deployment(pod)?),
+    };
+    hpas.iter()
+        .find(|h| h.scales_on_cpu && h.scales(namespace, &kind, &name))
+        .map(|h| format!("{}/{}", h.namespace, h.name))
+}
+
+fn group_key(pod: &InfoPodEntity, group_by: EfficiencyGroupBy) -> String {
+    let key = match group_by {
+        EfficiencyGroupBy::Team => pod.team.as_deref(),
+        EfficiencyGroupBy::Namespace => pod.namespace.as_deref(),
+    };
+    key.filter(|k| !k.is_empty()).unwrap_or(UNASSIGNED).to_string()
+}
+
+/// Scores one group. Pods without requests are priced on usage for both
+/// sides, so they neither help nor hurt beyond their weight. Pods scaled on CPU
+/// by an HPA are never counted as over-requested; they are reported instead.
+fn score_group(key: String, pods: &[PodSpend], target_percent: f64) -> MetricEfficiencyScoreDto {
+    let requested: f64 = pods.iter().map(|p| p.requested).sum();
+    let used: f64 = pods.iter().map(|p| p.used).sum();
+    // Bursting above its request doesn't make a pod more efficient
+    let used_within: f64 = pods.iter().map(|p| p.used.min(p.requested)).sum();
+    let idle: f64 = pods.iter().map(|p| (p.requested - p.used).max(0.0)).sum();
+    let (hpa_scaled, unscaled): (Vec<&PodSpend>, Vec<&PodSpend>) = pods
+        .iter()
+        .filter(|p| p.used < p.requested * OVER_REQUEST_USAGE_RATIO)
+        .partition(|p| p.cpu_hpa.is_some());
+    let over_requested: f64 = unscaled.iter().map(|p| p.requested).sum();
+    let hpas: BTreeSet<String> = hpa_scaled.iter().filter_map(|p| p.cpu_hpa.clone()).collect();
+
+    let share = |part: f64| if requested > 0.0 { part / requested * 100.0 } else { 0.0 };
+    let efficiency_percent = if requested > 0.0 { share(used_within) } else { 100.0 };
+    let idle_percent = share(idle);
+    let over_request_percent = share(over_requested);
+
+    let efficiency_score = (efficiency_percent / target_percent).min(1.0) * 100.0;
+    let score = 0.5 * efficiency_score + 0.25 * (100.0 - idle_percent) + 0.25 * (100.0 - over_request_percent);
+
+    MetricEfficiencyScoreDto {
+        rank: 0,
+        key,
+        grade: EfficiencyGrade::from_score(score),
+        score,
+        efficiency_percent,
+        target_percent,
+        idle_cost_usd: idle,
+        idle_percent,
+        over_request_percent,
+        requested_cost_usd: requested,
+        used_cost_usd: used,
+        pods: pods.len(),
+        hpa_suppressed_pods: hpa_scaled.len(),
+        hpas: hpas.into_iter().collect(),
+        explanation: None,
+    }
+}
+
+fn target_for(targets: &InfoEfficiencyTargetEntity, group_by: EfficiencyGroupBy, key: &str) -> f64 {
+    match group_by {
+        EfficiencyGroupBy::Team => targets.team_target(key),
+        EfficiencyGroupBy::Namespace => targets.namespace_target(key),
+    }
+}
+
+fn slack_text(dto: &MetricEfficiencyScoreResponseDto) -> String {
+    let mut text = format!(
+        "*Efficiency leaderboard* ({} – {})\n",
+        dto.start.format("%Y-%m-%d"),
+        dto.end.format("%Y-%m-%d"),
+    );
+    for s in &dto.scores {
+        text.push_str(&format!(
+            "{}. *{}* — {:?} ({:.0}) · {:.0}% efficient (target {:.0}%) · idle ${:.2} · {:.0}% over-requested",
+            s.rank, s.key, s.grade, s.score, s.efficiency_percent, s.target_percent, s.idle_cost_usd, s.over_request_percent,
+        ));
+        if s.hpa_suppressed_pods > 0 {
+            text.push_str(&format!(" · {} pods left to CPU HPAs", s.hpa_suppressed_pods));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Grades each team (or namespace) A–F on how much of its requested CPU and
+/// memory it uses against its target, its idle cost and its over-requested pods.
+pub async fn get_metric_k8s_efficiency_scores(
+    q: RangeQuery,
+    namespaces: Vec<String>,
+    group_by: EfficiencyGroupBy,
+) -> Result<Value> {
+    let window = resolve_time_window(&q);
+    let filters = MetricFilters::from_query(&q)?;
+    let pods: Vec<InfoPodEntity> = load_pods_by_namespace(&namespaces, &filters)
+        .await?
+        .into_values()
+        .flatten()
+        .collect();
+
+    let targets = info_efficiency_target_service::get_info_efficiency_targets().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let requests = load_pod_requests(&pods).await?;
+    let hpas = InfoHpaFsAdapter::new().list()?;
+
+    let mut used = build_pod_response_from_infos(q, pods.clone(), None)?;
+    let mut requested = used.clone();
+    apply_costs_with_basis(&mut used, &unit_prices, CostBasis::Usage, &HashMap::new());
+    apply_costs_with_basis(&mut requested, &unit_prices, CostBasis::Request, &requests);
+
+    let requested_by_pod: HashMap<&str, f64> = requested
+        .series
+        .iter()
+        .map(|s| (s.key.as_str(), compute_cost(s)))
+        .collect();
+    let pods_by_uid: HashMap<&str, &InfoPodEntity> = pods
+        .iter()
+        .filter_map(|p| p.pod_uid.as_deref().map(|uid| (uid, p)))
+        .collect();
+
+    let mut groups: BTreeMap<String, Vec<PodSpend>> = BTreeMap::new();
+    for series in &used.series {
+        let Some(pod) = pods_by_uid.get(series.key.as_str()) else {
+            continue;
+        };
+        let spend = PodSpend {
+            used: compute_cost(series),
+            requested: requested_by_pod.get(series.key.as_str()).copied().unwrap_or(0.0),
+            cpu_hpa: cpu_hpa(pod, &hpas),
+        };
+        if spend.used == 0.0 && spend.requested == 0.0 {
+            continue;
+        }
+        groups.entry(group_key(pod, group_by)).or_default().push(spend);
+    }
+
+    let mut scores: Vec<MetricEfficiencyScoreDto> = groups
+        .into_iter()
+        .map(|(key, spend)| {
+            let target = target_for(&targets, group_by, &key);
+            score_group(key, &spend, target)
+        })
+        .collect();
+    scores.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.key.cmp(&b.key)));
+    for (idx, s) in scores.iter_mut().enumerate() {
+        s.rank = idx + 1;
+    }
+
+    let mut dto = MetricEfficiencyScoreResponseDto {
+        start: window.start,
+        end: window.end,
+        group_by,
+        scores,
+        slack_text: String::new(),
+        explain_error: None,
+    };
+    dto.slack_text = slack_text(&dto);
+    Ok(serde_json::to_value(dto)?)
+}
+
+/// Adds an LLM-written justification to each score of an
+/// [`get_metric_k8s_efficiency_scores`] response, grounded in that score's own
+/// numbers. Kept apart from the cached scores so a failed or slow model never
+/// holds them back: on error the scores come back as they were, with
+/// `explain_error` set.
+pub async fn explain_metric_k8s_efficiency_scores(scores: Value) -> Result<Value> {
+    let mut dto: MetricEfficiencyScoreResponseDto = serde_json::from_value(scores)?;
+    match explanations(&dto.scores).await {
+        Ok(mut by_key) => {
+            for s in &mut dto.scores {
+                s.explanation = by_key.remove(&s.key);
+            }
+        }
+        Err(e) => {
+            warn!(error = %e, "efficiency_explain_failed");
+            dto.explain_error = Some(e.to_string());
+        }
+    }
+    Ok(serde_json::to_value(dto)?)
+}
+
+async fn explanations(scores: &[MetricEfficiencyScoreDto]) -> Result<HashMap<String, String>> {
+    if scores.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let mut context = LlmContextBuilder::new(EXPLAIN_CONTEXT_TOKENS);
+    context
+        .instruction(EXPLAIN_INSTRUCTION)
+        .data(ContextPriority::High, "efficiency_scores", &serde_json::to_value(scores)?);
+    let reply = llm_chat_service::complete(context, EXPLAIN_QUESTION).await?;
+    parse_explanations(&reply)
+}
+
+/// The model's key → text object, tolerating a Markdown code fence around it.
+fn parse_explanations(reply: &str) -> Result<HashMap<String, String>> {
+    let body = reply.trim();
+    let body = body
+        .strip_prefix("```json")
+        .or_else(|| body.strip_prefix("```"))
+        .and_then(|b| b.strip_suffix("```"))
+        .unwrap_or(body)
+        .trim();
+    serde_json::from_str(body).map_err(|e| anyhow!("LLM reply is not a JSON object of explanations: {}", e))
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grades_on_efficiency_against_target_idle_and_over_request() {
+        // Uses 3 of 4 requested: efficient enough for a 60% target
+        let lean = score_group("lean".into(), &[PodSpend { used: 3.0, requested: 4.0, ..Default::default() }], 60.0);
+        assert!((lean.efficiency_percent - 75.0).abs() < 1e-9);
+        assert!((lean.idle_cost_usd - 1.0).abs() < 1e-9);
+        assert_eq!(lean.over_request_percent, 0.0);
+        assert!((lean.score - 93.75).abs() < 1e-9);
+        assert_eq!(lean.grade, EfficiencyGrade::A);
+
+        // One pod at 10% of a large request drags the whole team down
+        let bloated = score_group(
+            "bloated".into(),
+            &[
+                PodSpend { used: 1.0, requested: 10.0, ..Default::default() },
+                PodSpend { used: 5.0, requested: 4.0, ..Default::default() },
+            ],
+            60.0,
+        );
+        assert!((bloated.efficiency_percent - 5.0 / 14.0 * 100.0).abs() < 1e-9);
+        assert!((bloated.over_request_percent - 10.0 / 14.0 * 100.0).abs() < 1e-9);
+        assert_eq!(bloated.grade, EfficiencyGrade::F);
+        assert_eq!(bloated.hpa_suppressed_pods, 0);
+    }
+
+    #[test]
+    fn cpu_scaled_pods_are_reported_instead_of_over_requested() {
+        let scaled = score_group(
+            "web".into(),
+            &[
+                PodSpend { used: 1.0, requested: 10.0, cpu_hpa: Some("shop/web".into()) },
+                PodSpend { used: 1.0, requested: 10.0, cpu_hpa: Some("shop/web".into()) },
+                PodSpend { used: 1.0, requested: 4.0, ..Default::default() },
+            ],
+            60.0,
+        );
+        assert!((scaled.over_request_percent - 4.0 / 24.0 * 100.0).abs() < 1e-9);
+        assert_eq!(scaled.hpa_suppressed_pods, 2);
+        assert_eq!(scaled.hpas, vec!["shop/web".to_string()]);
+    }
+
+    #[test]
+    fn cpu_hpa_matches_the_pod_workload_and_ignores_memory_only_hpas() {
+        let hpa = |name: &str, scales_on_cpu: bool| InfoHpaEntity {
+            namespace: "shop".into(),
+            name: name.into(),
+            target_kind: "Deployment".into(),
+            target_name: "web".into(),
+            min_replicas: Some(2),
+            max_replicas: 10,
+            scales_on_cpu,
+            cpu_target_utilization: scales_on_cpu.then_some(70),
+            memory_target_utilization: (!scales_on_cpu).then_some(80),
+            observed_at: chrono::Utc::now(),
+        };
+        let pod = InfoPodEntity {
+            namespace: Some("shop".into()),
+            workload_kind: Some("Deployment".into()),
+            workload_name: Some("web".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(cpu_hpa(&pod, &[hpa("web-mem", false), hpa("web-cpu", true)]), Some("shop/web-cpu".into()));
+        assert_eq!(cpu_hpa(&pod, &[hpa("web-mem", false)]), None);
+    }
+
+    #[test]
+    fn parses_explanations_with_or_without_a_code_fence() {
+        let plain = parse_explanations(r#"{"payments": "Idle $12 is the gap."}"#).unwrap();
+        assert_eq!(plain["payments"], "Idle $12 is the gap.");
+
+        let fenced = parse_explanations("```json\n{\"search\": \"Fine.\"}\n```").unwrap();
+        assert_eq!(fenced["search"], "Fine.");
+
+        assert!(parse_explanations("Payments is wasteful.").is_err());
+    }
+}
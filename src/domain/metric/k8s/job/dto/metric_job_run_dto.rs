@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryDto;
+
+/// Cost of each Job a CronJob spawned within the window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCronJobRunsResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub namespace: String,
+    pub cronjob: String,
+    pub granularity: MetricGranularity,
+    /// Sum of all runs
+    pub summary: MetricCostSummaryDto,
+    /// Oldest run first
+    pub runs: Vec<MetricJobRunDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricJobRunDto {
+    pub job: String,
+    /// First and last sample seen for the run's pods
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub pod_count: usize,
+    pub running_hours: Option<f64>,
+    pub summary: MetricCostSummaryDto,
+}
@@ -0,0 +1 @@
+pub mod metric_job_run_dto;
@@ -0,0 +1,304 @@
+use anyhow::{anyhow, Result};
+use chrono::Duration;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::repositories::repositories;
+use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryDto;
+use crate::domain::metric::k8s::common::dto::{
+    MetricGetResponseDto, MetricScope, MetricSeriesDto,
+};
+use crate::domain::metric::k8s::common::service_helpers::{
+    apply_costs, build_cost_summary_dto, build_cost_trend_dto, resolve_time_window, sample_step,
+    sum_running_hours,
+};
+use crate::domain::metric::k8s::job::dto::metric_job_run_dto::{
+    MetricCronJobRunsResponseDto, MetricJobRunDto,
+};
+use crate::domain::metric::k8s::namespace::service::aggregate_namespace_points;
+use crate::domain::metric::k8s::pod::service::build_pod_response_from_infos;
+
+// ------------------------------
+// Helpers
+// ------------------------------
+
+/// Load pods in `namespace` matching `pred` from local pod info.
+async fn load_pods(namespace: &str, pred: impl Fn(&InfoPodEntity) -> bool) -> Result<Vec<InfoPodEntity>> {
+    Ok(repositories()
+        .info_pods
+        .list()
+        .await?
+        .into_iter()
+        .filter(|pod| pod.namespace.as_deref() == Some(namespace) && pred(pod))
+        .collect())
+}
+
+fn is_job_pod(pod: &InfoPodEntity, job: &str) -> bool {
+    pod.owner_kind.as_deref() == Some("Job") && pod.owner_name.as_deref() == Some(job)
+}
+
+/// Uses the resolved workload when present; older records fall back to the
+/// `<cronjob>-<scheduled-minute>` naming the CronJob controller gives its Jobs.
+fn is_cronjob_pod(pod: &InfoPodEntity, cronjob: &str) -> bool {
+    if pod.workload_kind.is_some() {
+        return pod.workload_kind.as_deref() == Some("CronJob")
+            && pod.workload_name.as_deref() == Some(cronjob);
+    }
+
+    match (pod.owner_kind.as_deref(), pod.owner_name.as_deref()) {
+        (Some("Job"), Some(job)) => job
+            .strip_prefix(cronjob)
+            .and_then(|rest| rest.strip_prefix('-'))
+            .is_some_and(|suffix| !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_digit())),
+        _ => false,
+    }
+}
+
+async fn pods_for_job(namespace: &str, job: &str) -> Result<Vec<InfoPodEntity>> {
+    let pods = load_pods(namespace, |p| is_job_pod(p, job)).await?;
+    if pods.is_empty() {
+        return Err(anyhow!("job '{}/{}' has no pods", namespace, job));
+    }
+    Ok(pods)
+}
+
+async fn pods_for_cronjob(namespace: &str, cronjob: &str) -> Result<Vec<InfoPodEntity>> {
+    let pods = load_pods(namespace, |p| is_cronjob_pod(p, cronjob)).await?;
+    if pods.is_empty() {
+        return Err(anyhow!("cronjob '{}/{}' has no pods", namespace, cronjob));
+    }
+    Ok(pods)
+}
+
+fn aggregate_workload_response(
+    scope: MetricScope,
+    target: &str,
+    per_pod_response: &MetricGetResponseDto,
+    step: Duration,
+) -> MetricGetResponseDto {
+    let member_points = per_pod_response
+        .series
+        .iter()
+        .map(|s| s.points.clone())
+        .collect();
+
+    MetricGetResponseDto {
+        start: per_pod_response.start,
+        end: per_pod_response.end,
+        scope: scope_name(&scope).to_string(),
+        target: Some(target.to_string()),
+        granularity: per_pod_response.granularity.clone(),
+        series: vec![MetricSeriesDto {
+            key: target.to_string(),
+            name: target.to_string(),
+            scope,
+            points: aggregate_namespace_points(member_points, step),
+            running_hours: sum_running_hours(&per_pod_response.series),
+            cost_summary: None,
+        }],
+        total: None,
+        limit: None,
+        offset: None,
+        next_cursor: None,
+        warnings: Vec::new(),
+        partial: false,
+    }
+}
+
+fn scope_name(scope: &MetricScope) -> &'static str {
+    match scope {
+        MetricScope::CronJob => "cronjob",
+        _ => "job",
+    }
+}
+
+async fn build_workload_response(
+    scope: MetricScope,
+    namespace: &str,
+    name: &str,
+    q: RangeQuery,
+) -> Result<MetricGetResponseDto> {
+    let pods = match scope {
+        MetricScope::CronJob => pods_for_cronjob(namespace, name).await?,
+        _ => pods_for_job(namespace, name).await?,
+    };
+    let target = format!("{}/{}", namespace, name);
+    let per_pod = build_pod_response_from_infos(q, pods, Some(target.clone()))?;
+    let step = sample_step(&per_pod.granularity);
+    Ok(aggregate_workload_response(scope, &target, &per_pod, step))
+}
+
+async fn build_workload_cost(
+    scope: MetricScope,
+    namespace: &str,
+    name: &str,
+    q: RangeQuery,
+) -> Result<MetricGetResponseDto> {
+    let mut dto = build_workload_response(scope, namespace, name, q).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    apply_costs(&mut dto, &unit_prices);
+    Ok(dto)
+}
+
+// ------------------------------
+// JOB
+// ------------------------------
+
+pub async fn get_metric_k8s_job_raw(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let dto = build_workload_response(MetricScope::Job, &namespace, &name, q).await?;
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_job_cost(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let dto = build_workload_cost(MetricScope::Job, &namespace, &name, q).await?;
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_job_cost_summary(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let dto = build_workload_cost(MetricScope::Job, &namespace, &name, q).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let target = Some(format!("{}/{}", namespace, name));
+    let summary = build_cost_summary_dto(&dto, MetricScope::Job, target, &unit_prices);
+    Ok(serde_json::to_value(summary)?)
+}
+
+pub async fn get_metric_k8s_job_cost_trend(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let dto = build_workload_cost(MetricScope::Job, &namespace, &name, q).await?;
+    let target = Some(format!("{}/{}", namespace, name));
+    let trend = build_cost_trend_dto(&dto, MetricScope::Job, target)?;
+    Ok(serde_json::to_value(trend)?)
+}
+
+// ------------------------------
+// CRONJOB
+// ------------------------------
+
+pub async fn get_metric_k8s_cronjob_raw(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let dto = build_workload_response(MetricScope::CronJob, &namespace, &name, q).await?;
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_cronjob_cost(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let dto = build_workload_cost(MetricScope::CronJob, &namespace, &name, q).await?;
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_cronjob_cost_summary(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let dto = build_workload_cost(MetricScope::CronJob, &namespace, &name, q).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let target = Some(format!("{}/{}", namespace, name));
+    let summary = build_cost_summary_dto(&dto, MetricScope::CronJob, target, &unit_prices);
+    Ok(serde_json::to_value(summary)?)
+}
+
+pub async fn get_metric_k8s_cronjob_cost_trend(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let dto = build_workload_cost(MetricScope::CronJob, &namespace, &name, q).await?;
+    let target = Some(format!("{}/{}", namespace, name));
+    let trend = build_cost_trend_dto(&dto, MetricScope::CronJob, target)?;
+    Ok(serde_json::to_value(trend)?)
+}
+
+/// One entry per Job the CronJob spawned; runs with no samples in the window are dropped.
+pub async fn get_metric_k8s_cronjob_cost_runs(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let pods = pods_for_cronjob(&namespace, &name).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+
+    let mut by_job: BTreeMap<String, Vec<InfoPodEntity>> = BTreeMap::new();
+    for pod in pods {
+        if let Some(job) = pod.owner_name.clone() {
+            by_job.entry(job).or_default().push(pod);
+        }
+    }
+
+    let step = sample_step(&resolve_time_window(&q).granularity);
+    let mut window = None;
+    let mut total = MetricCostSummaryDto::default();
+    let mut runs = Vec::new();
+
+    for (job, pods) in by_job {
+        let pod_count = pods.len();
+        let target = format!("{}/{}", namespace, job);
+        let per_pod = build_pod_response_from_infos(q.clone(), pods, Some(target.clone()))?;
+        let mut dto = aggregate_workload_response(MetricScope::Job, &target, &per_pod, step);
+        apply_costs(&mut dto, &unit_prices);
+
+        if window.is_none() {
+            window = Some((dto.start, dto.end, dto.granularity.clone()));
+        }
+
+        let series = &dto.series[0];
+        let (Some(first), Some(last)) = (series.points.first(), series.points.last()) else {
+            continue;
+        };
+        let (started_at, finished_at) = (Some(first.time), Some(last.time));
+        let running_hours = series.running_hours;
+
+        let summary = build_cost_summary_dto(&dto, MetricScope::Job, Some(target), &unit_prices).summary;
+        add_summary(&mut total, &summary);
+
+        runs.push(MetricJobRunDto {
+            job,
+            started_at,
+            finished_at,
+            pod_count,
+            running_hours,
+            summary,
+        });
+    }
+
+    let Some((start, end, granularity)) = window else {
+        return Err(anyhow!("cronjob '{}/{}' has no runs", namespace, name));
+    };
+    runs.sort_by_key(|r| r.started_at);
+
+    Ok(serde_json::to_value(MetricCronJobRunsResponseDto {
+        start,
+        end,
+        namespace,
+        cronjob: name,
+        granularity,
+        summary: total,
+        runs,
+    })?)
+}
+
+fn add_summary(total: &mut MetricCostSummaryDto, s: &MetricCostSummaryDto) {
+    total.total_cost_usd += s.total_cost_usd;
+    total.cpu_cost_usd += s.cpu_cost_usd;
+    total.memory_cost_usd += s.memory_cost_usd;
+    total.ephemeral_storage_cost_usd += s.ephemeral_storage_cost_usd;
+    total.persistent_storage_cost_usd += s.persistent_storage_cost_usd;
+    total.network_cost_usd += s.network_cost_usd;
+    total.loadbalancer_cost_usd += s.loadbalancer_cost_usd;
+    total.fixed_cost_usd += s.fixed_cost_usd;
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job_pod(job: &str) -> InfoPodEntity {
+        InfoPodEntity {
+            owner_kind: Some("Job".into()),
+            owner_name: Some(job.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn matches_cronjob_pods_by_job_name_without_workload() {
+        assert!(is_cronjob_pod(&job_pod("backup-28913760"), "backup"));
+        assert!(!is_cronjob_pod(&job_pod("backup-manual"), "backup"));
+        assert!(!is_cronjob_pod(&job_pod("backup-db-28913760"), "backup"));
+
+        let mut resolved = job_pod("manual-run");
+        resolved.workload_kind = Some("CronJob".into());
+        resolved.workload_name = Some("backup".into());
+        assert!(is_cronjob_pod(&resolved, "backup"));
+    }
+}
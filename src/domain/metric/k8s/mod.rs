@@ -1,9 +1,16 @@
-//! K8s metrics subdomain (cluster, node, pod, container)
+//! K8s metrics subdomain (cluster, node, pod, container, workloads)
 
 pub mod cluster;
 pub mod node;
+pub mod node_pool;
 pub mod pod;
+pub mod pvc;
+pub mod network;
 pub mod container;
 pub mod namespace;
 pub mod deployment;
+pub mod job;
+pub mod carbon;
+pub mod efficiency;
+pub mod query;
 pub mod common;
@@ -1,55 +1,63 @@
 use anyhow::{anyhow, Result};
-use chrono::{DateTime, Utc};
+use chrono::Duration;
 use serde_json::{json, Value};
-use std::{
-    collections::{BTreeMap, HashMap, HashSet},
-    fs,
-};
+use std::collections::{HashMap, HashSet};
 
 use crate::api::dto::metrics_dto::RangeQuery;
-use crate::core::persistence::info::{
-    k8s::pod::{info_pod_entity::InfoPodEntity, info_pod_repository::InfoPodRepository},
-    path::info_k8s_pod_dir_path,
-};
-use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
-use crate::domain::info::service::info_unit_price_service;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::info::fixed::fixed_cost::fixed_cost_entity::FixedCostAllocation;
+use crate::core::persistence::info::fixed::fixed_cost::info_fixed_cost_api_repository_trait::InfoFixedCostApiRepository;
+use crate::core::persistence::info::fixed::fixed_cost::info_fixed_cost_repository::InfoFixedCostRepository;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::k8s::load_balancer::info_load_balancer_fs_adapter::InfoLoadBalancerFsAdapter;
+use crate::core::persistence::info::k8s::namespace::info_namespace_api_repository_trait::InfoNamespaceApiRepository;
+use crate::core::persistence::info::k8s::namespace::info_namespace_entity::InfoNamespaceEntity;
+use crate::core::persistence::info::k8s::namespace::info_namespace_repository::InfoNamespaceRepository;
+use crate::core::persistence::repositories::repositories;
+use crate::domain::info::service::{info_settings_service, info_unit_price_service};
+use crate::domain::export::cost_close_service::{closed_cost_summary, record_text};
+use crate::domain::export::cost_materialization_service::materialized_cost_summary;
 
 use crate::domain::metric::k8s::common::dto::{
-    FilesystemMetricDto, MetricGetResponseDto, MetricScope,
+    MetricGetResponseDto, MetricScope,
     MetricSeriesDto, NetworkMetricDto, UniversalMetricPointDto,
 };
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryResponseDto;
+use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
 use crate::domain::metric::k8s::common::service_helpers::{
-    apply_costs, build_cost_summary_dto, build_cost_trend_dto, build_raw_summary_value,
+    apply_costs, build_cost_summary_dto, build_cost_trend_dto, build_efficiency_value,
+    bucket_member_points, build_raw_summary_value, resolve_time_window, sample_step, stored_containers,
+    sum_filesystem, sum_running_hours, sum_storage, EfficiencyBaselines, BYTES_PER_GB,
 };
 
+use crate::domain::metric::k8s::network::service::{flow_namespaces, network_flow_cost};
 use crate::domain::metric::k8s::pod::service::build_pod_response_from_infos;
+use crate::domain::metric::k8s::common::util::k8s_metric_filter::MetricFilters;
+use crate::domain::metric::k8s::common::util::k8s_workload_overlap::overlap_adjusted_points;
 
 // =====================================================================
 // HELPERS
 // =====================================================================
 
-/// Load pods grouped by namespace from the local repository.
-fn load_pods_by_namespace(namespaces: &[String]) -> Result<HashMap<String, Vec<InfoPodEntity>>> {
-    let mut map = HashMap::new();
-    let dir = info_k8s_pod_dir_path();
-
-    if !dir.exists() {
-        return Ok(map);
-    }
-
-    let filters: HashSet<String> = namespaces.iter().cloned().collect();
-    let allow_all = filters.is_empty();
-    let repo = InfoPodRepository::new();
-
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let pod_uid = entry.file_name().to_string_lossy().to_string();
-
-        if let Ok(pod) = repo.read(&pod_uid) {
-            if let Some(ns) = pod.namespace.clone() {
-                if allow_all || filters.contains(&ns) {
-                    map.entry(ns).or_default().push(pod);
-                }
+/// Load pods grouped by namespace from the local repository, keeping only pods
+/// that pass the query's namespace / team / service / env filters.
+pub(crate) async fn load_pods_by_namespace(
+    namespaces: &[String],
+    filters: &MetricFilters,
+) -> Result<HashMap<String, Vec<InfoPodEntity>>> {
+    let mut map: HashMap<String, Vec<InfoPodEntity>> = HashMap::new();
+    let wanted: HashSet<String> = namespaces.iter().cloned().collect();
+    let allow_all = wanted.is_empty();
+
+    for pod in repositories().info_pods.list().await? {
+        if !filters.matches_namespace(&pod.namespace)
+            || !filters.matches_attribution(&pod.team, &pod.service, &pod.env)
+        {
+            continue;
+        }
+        if let Some(ns) = pod.namespace.clone() {
+            if allow_all || wanted.contains(&ns) {
+                map.entry(ns).or_default().push(pod);
             }
         }
     }
@@ -58,8 +66,8 @@ fn load_pods_by_namespace(namespaces: &[String]) -> Result<HashMap<String, Vec<I
 }
 
 /// Load all pods for a specific namespace (errors if none found).
-fn namespace_pods(ns: &str) -> Result<Vec<InfoPodEntity>> {
-    let map = load_pods_by_namespace(&[ns.to_string()])?;
+async fn namespace_pods(ns: &str, filters: &MetricFilters) -> Result<Vec<InfoPodEntity>> {
+    let map = load_pods_by_namespace(&[ns.to_string()], filters).await?;
 
     if let Some(pods) = map.get(ns) {
         if !pods.is_empty() {
@@ -70,8 +78,8 @@ fn namespace_pods(ns: &str) -> Result<Vec<InfoPodEntity>> {
     Err(anyhow!("namespace '{}' has no pods", ns))
 }
 
-fn all_pods_for(namespaces: &[String]) -> Result<Vec<InfoPodEntity>> {
-    let map = load_pods_by_namespace(namespaces)?;
+async fn all_pods_for(namespaces: &[String], filters: &MetricFilters) -> Result<Vec<InfoPodEntity>> {
+    let map = load_pods_by_namespace(namespaces, filters).await?;
     Ok(map.into_values().flatten().collect())
 }
 
@@ -83,11 +91,12 @@ fn all_pods_for(namespaces: &[String]) -> Result<Vec<InfoPodEntity>> {
 fn build_namespace_response(
     namespace: &str,
     per_pod: &MetricGetResponseDto,
+    pods: &[InfoPodEntity],
+    step: Duration,
 ) -> MetricGetResponseDto {
-    let all_points: Vec<UniversalMetricPointDto> =
-        per_pod.series.iter().flat_map(|s| s.points.clone()).collect();
+    let pod_points = overlap_adjusted_points(per_pod, pods);
 
-    let aggregated = aggregate_namespace_points(all_points);
+    let aggregated = aggregate_namespace_points(pod_points, step);
 
     MetricGetResponseDto {
         start: per_pod.start,
@@ -100,12 +109,15 @@ fn build_namespace_response(
             name: namespace.to_string(),
             scope: MetricScope::Namespace,
             points: aggregated,
-            running_hours: None,
+            running_hours: sum_running_hours(&per_pod.series),
             cost_summary: None,
         }],
         total: None,
         limit: None,
         offset: None,
+        next_cursor: None,
+        warnings: Vec::new(),
+        partial: false,
     }
 }
 
@@ -114,14 +126,15 @@ fn build_namespace_response(
 // NAMESPACE MULTI-POINT AGGREGATION
 // =====================================================================
 
+/// Sums member series (pods, or nodes of a pool) into one point per `step`
+/// (see [`sample_step`]), counting each member's latest sample in a step once.
+/// Ephemeral and persistent storage are summed separately, so the aggregate
+/// still shows (and prices) both.
 pub fn aggregate_namespace_points(
-    points: Vec<UniversalMetricPointDto>,
+    member_points: Vec<Vec<UniversalMetricPointDto>>,
+    step: Duration,
 ) -> Vec<UniversalMetricPointDto> {
-    let mut buckets: BTreeMap<DateTime<Utc>, Vec<UniversalMetricPointDto>> = BTreeMap::new();
-
-    for p in points {
-        buckets.entry(p.time).or_default().push(p);
-    }
+    let buckets = bucket_member_points(member_points, step);
 
     let mut out = Vec::with_capacity(buckets.len());
 
@@ -147,13 +160,8 @@ pub fn aggregate_namespace_points(
             sum(&mut acc.cpu_memory.memory_rss_bytes, p.cpu_memory.memory_rss_bytes);
             sum(&mut acc.cpu_memory.memory_page_faults, p.cpu_memory.memory_page_faults);
 
-            if let Some(fs) = p.filesystem.as_ref() {
-                let outfs = acc.filesystem.get_or_insert(FilesystemMetricDto::default());
-                sum(&mut outfs.used_bytes, fs.used_bytes);
-                sum(&mut outfs.capacity_bytes, fs.capacity_bytes);
-                sum(&mut outfs.inodes_used, fs.inodes_used);
-                sum(&mut outfs.inodes, fs.inodes);
-            }
+            sum_filesystem(&mut acc.filesystem, p.filesystem.as_ref());
+            sum_storage(&mut acc.storage, p.storage.as_ref());
 
             if let Some(net) = p.network.as_ref() {
                 let outnet = acc.network.get_or_insert(NetworkMetricDto::default());
@@ -180,7 +188,7 @@ pub async fn get_metric_k8s_namespaces_raw(
     namespaces: Vec<String>
 ) -> Result<Value> {
 
-    let ns_map = load_pods_by_namespace(&namespaces)?;
+    let ns_map = load_pods_by_namespace(&namespaces, &MetricFilters::from_query(&q)?).await?;
 
     let targets =
         if namespaces.is_empty() {
@@ -191,6 +199,7 @@ pub async fn get_metric_k8s_namespaces_raw(
 
     let mut series = Vec::new();
     let mut base_resp = None;
+    let step = sample_step(&resolve_time_window(&q).granularity);
 
     for ns in targets {
         if let Some(pods) = ns_map.get(&ns) {
@@ -198,7 +207,7 @@ pub async fn get_metric_k8s_namespaces_raw(
                 continue;
             }
             let per_pod = build_pod_response_from_infos(q.clone(), pods.clone(), Some(ns.clone()))?;
-            let aggregated = build_namespace_response(&ns, &per_pod);
+            let aggregated = build_namespace_response(&ns, &per_pod, pods, step);
 
             if base_resp.is_none() {
                 base_resp = Some(aggregated.clone());
@@ -227,9 +236,9 @@ pub async fn get_metric_k8s_namespace_raw(
     q: RangeQuery
 ) -> Result<Value> {
 
-    let pods = namespace_pods(&ns)?;
-    let per_pod = build_pod_response_from_infos(q, pods, Some(ns.clone()))?;
-    let aggregated = build_namespace_response(&ns, &per_pod);
+    let pods = namespace_pods(&ns, &MetricFilters::from_query(&q)?).await?;
+    let per_pod = build_pod_response_from_infos(q, pods.clone(), Some(ns.clone()))?;
+    let aggregated = build_namespace_response(&ns, &per_pod, &pods, sample_step(&per_pod.granularity));
 
     Ok(serde_json::to_value(aggregated)?)
 }
@@ -244,7 +253,7 @@ pub async fn get_metric_k8s_namespaces_raw_summary(
     namespaces: Vec<String>
 ) -> Result<Value> {
 
-    let ns_map = load_pods_by_namespace(&namespaces)?;
+    let ns_map = load_pods_by_namespace(&namespaces, &MetricFilters::from_query(&q)?).await?;
 
     let targets =
         if namespaces.is_empty() {
@@ -266,7 +275,7 @@ pub async fn get_metric_k8s_namespaces_raw_summary(
     }
 
     let per_pod = build_pod_response_from_infos(q, all_pods.clone(), None)?;
-    let aggregated = build_namespace_response("all", &per_pod);
+    let aggregated = build_namespace_response("all", &per_pod, &all_pods, sample_step(&per_pod.granularity));
 
     build_raw_summary_value(&aggregated, MetricScope::Namespace, all_pods.len())
 }
@@ -277,9 +286,9 @@ pub async fn get_metric_k8s_namespace_raw_summary(
     q: RangeQuery
 ) -> Result<Value> {
 
-    let pods = namespace_pods(&ns)?;
+    let pods = namespace_pods(&ns, &MetricFilters::from_query(&q)?).await?;
     let per_pod = build_pod_response_from_infos(q, pods.clone(), Some(ns.clone()))?;
-    let aggregated = build_namespace_response(&ns, &per_pod);
+    let aggregated = build_namespace_response(&ns, &per_pod, &pods, sample_step(&per_pod.granularity));
 
     build_raw_summary_value(&aggregated, MetricScope::Namespace, pods.len())
 }
@@ -287,26 +296,88 @@ pub async fn get_metric_k8s_namespace_raw_summary(
 
 
 // =====================================================================
-// EFFICIENCY (NOT SUPPORTED)
+// EFFICIENCY (USAGE VS RESOURCEQUOTA)
 // =====================================================================
 
+/// Quota hard limits as (cpu cores, memory GB, storage GB). Requests are
+/// preferred; a quota that only caps limits falls back to those.
+fn quota_allocations(ns: &InfoNamespaceEntity) -> (f64, f64, f64) {
+    let cpu = ns.quota_cpu_request_cores.or(ns.quota_cpu_limit_cores).unwrap_or(0.0);
+    let mem = ns
+        .quota_memory_request_bytes
+        .or(ns.quota_memory_limit_bytes)
+        .unwrap_or(0) as f64
+        / BYTES_PER_GB;
+    let storage = ns.quota_storage_request_bytes.unwrap_or(0) as f64 / BYTES_PER_GB;
+    (cpu, mem, storage)
+}
+
+/// Requests and limits of the containers in `namespaces`, against their quota.
+async fn namespace_baselines(namespaces: &[String], quota_cpu: f64, quota_mem_gb: f64) -> EfficiencyBaselines {
+    let containers = stored_containers(|c| c.namespace.as_ref().is_some_and(|n| namespaces.contains(n))).await;
+    EfficiencyBaselines::from_containers(&containers).with_allocatable(quota_cpu, quota_mem_gb)
+}
+
+fn no_quota_value(namespaces: &[String]) -> Value {
+    json!({
+        "status": "no_quota",
+        "message": "No ResourceQuota stored for the requested namespaces",
+        "namespaces": namespaces,
+    })
+}
+
+/// Stored namespaces with a quota among `namespaces` (all when empty).
+fn namespaces_with_quota(namespaces: &[String]) -> Result<Vec<InfoNamespaceEntity>> {
+    let all = InfoNamespaceRepository::new().list()?;
+    Ok(all
+        .into_iter()
+        .filter(|ns| ns.has_quota() && ns.deleted != Some(true))
+        .filter(|ns| {
+            namespaces.is_empty() || ns.name.as_ref().is_some_and(|n| namespaces.contains(n))
+        })
+        .collect())
+}
+
 pub async fn get_metric_k8s_namespace_raw_efficiency(
-    _ns: String, _q: RangeQuery
+    ns: String, q: RangeQuery
 ) -> Result<Value> {
-    Ok(json!({
-        "status": "not_supported",
-        "message": "Namespace efficiency not supported yet"
-    }))
+    let quota = match InfoNamespaceRepository::new().read(&ns) {
+        Ok(info) if info.has_quota() => info,
+        _ => return Ok(no_quota_value(&[ns])),
+    };
+
+    let baselines_for = [ns.clone()];
+    let summary_value = get_metric_k8s_namespace_raw_summary(ns, q).await?;
+    let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
+    let (cpu, mem, storage) = quota_allocations(&quota);
+    let baselines = namespace_baselines(&baselines_for, cpu, mem).await;
+    build_efficiency_value(summary, MetricScope::Namespace, cpu, mem, storage, baselines)
 }
 
+/// Combined usage of the namespaces that have a quota, against the sum of
+/// their quotas. Namespaces without a quota are left out of both sides.
 pub async fn get_metric_k8s_namespaces_raw_efficiency(
-    _q: RangeQuery,
-    _namespaces: Vec<String>
+    q: RangeQuery,
+    namespaces: Vec<String>
 ) -> Result<Value> {
-    Ok(json!({
-        "status": "not_supported",
-        "message": "Namespace efficiency not supported yet"
-    }))
+    let quotas = namespaces_with_quota(&namespaces)?;
+    if quotas.is_empty() {
+        return Ok(no_quota_value(&namespaces));
+    }
+
+    let names: Vec<String> = quotas.iter().filter_map(|ns| ns.name.clone()).collect();
+    let summary_value = get_metric_k8s_namespaces_raw_summary(q, names.clone()).await?;
+    let Ok(summary) = serde_json::from_value::<MetricRawSummaryResponseDto>(summary_value.clone()) else {
+        // "no data" for every quota'd namespace
+        return Ok(summary_value);
+    };
+
+    let (cpu, mem, storage) = quotas.iter().map(quota_allocations).fold(
+        (0.0, 0.0, 0.0),
+        |acc, v| (acc.0 + v.0, acc.1 + v.1, acc.2 + v.2),
+    );
+    let baselines = namespace_baselines(&names, cpu, mem).await;
+    build_efficiency_value(summary, MetricScope::Namespace, cpu, mem, storage, baselines)
 }
 
 
@@ -320,23 +391,151 @@ async fn build_namespace_cost(
     filter_namespaces: &[String],
 ) -> Result<MetricGetResponseDto> {
 
+    let filters = MetricFilters::from_query(&q)?;
     let pods = match namespace.as_ref() {
-        Some(ns) => namespace_pods(ns)?,
-        None => all_pods_for(filter_namespaces)?,
+        Some(ns) => namespace_pods(ns, &filters).await?,
+        None => all_pods_for(filter_namespaces, &filters).await?,
     };
 
     if pods.is_empty() {
         return Err(anyhow!("no pods available for namespace cost calculation"));
     }
 
-    let per_pod = build_pod_response_from_infos(q, pods, namespace.clone())?;
+    let per_pod = build_pod_response_from_infos(q, pods.clone(), namespace.clone())?;
 
     Ok(build_namespace_response(
         namespace.as_deref().unwrap_or("all"),
         &per_pod,
+        &pods,
+        sample_step(&per_pod.granularity),
     ))
 }
 
+/// Adds the hours each `LoadBalancer` Service of the selected namespaces existed in
+/// the window, at `load_balancer_hour`. Services have no pods, so only namespace
+/// filters apply; team/service/env filters don't narrow them.
+fn add_load_balancer_cost(
+    dto: &mut MetricCostSummaryResponseDto,
+    namespace: Option<&str>,
+    namespaces: &[String],
+    filters: &MetricFilters,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<()> {
+    let hours: f64 = InfoLoadBalancerFsAdapter::new()
+        .list()?
+        .iter()
+        .filter(|lb| match namespace {
+            Some(ns) => lb.namespace == ns,
+            None => namespaces.is_empty() || namespaces.contains(&lb.namespace),
+        })
+        .filter(|lb| filters.matches_namespace(&Some(lb.namespace.clone())))
+        .map(|lb| lb.active_hours(dto.start, dto.end))
+        .sum();
+
+    let cost = hours * unit_prices.load_balancer_hour;
+    dto.summary.loadbalancer_cost_usd = cost;
+    dto.summary.total_cost_usd += cost;
+    Ok(())
+}
+
+/// Pod cost of every namespace, ignoring the query's scope filters.
+async fn all_namespaces_pod_cost(q: &RangeQuery, unit_prices: &InfoUnitPriceEntity) -> Result<f64> {
+    let unscoped = RangeQuery {
+        team: None,
+        service: None,
+        env: None,
+        namespace: None,
+        exclude_namespace: None,
+        exclude_team: None,
+        exclude_service: None,
+        exclude_env: None,
+        labels: None,
+        key: None,
+        ..q.clone()
+    };
+    let mut all = build_namespace_cost(None, unscoped, &[]).await?;
+    apply_costs(&mut all, unit_prices);
+    Ok(build_cost_summary_dto(&all, MetricScope::Namespace, None, unit_prices).summary.total_cost_usd)
+}
+
+/// Adds the selection's share of fixed line items. `Namespaces` items split evenly
+/// across their namespaces; `Proportional` ones follow the selection's share of
+/// the pod cost of all namespaces. Must run before other non-pod costs are added.
+async fn add_fixed_cost(
+    dto: &mut MetricCostSummaryResponseDto,
+    namespace: Option<&str>,
+    namespaces: &[String],
+    filters: &MetricFilters,
+    q: &RangeQuery,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<()> {
+    let selected = |ns: &String| {
+        let in_scope = match namespace {
+            Some(only) => ns == only,
+            None => namespaces.is_empty() || namespaces.contains(ns),
+        };
+        in_scope && filters.matches_namespace(&Some(ns.clone()))
+    };
+
+    let mut fixed = 0.0;
+    let mut proportional = 0.0;
+    for item in InfoFixedCostRepository::new().read()?.items {
+        let cost = item.cost_between(dto.start, dto.end);
+        if cost <= 0.0 {
+            continue;
+        }
+        match item.allocation {
+            FixedCostAllocation::Cluster => {}
+            FixedCostAllocation::Namespaces if !item.namespaces.is_empty() => {
+                let hits = item.namespaces.iter().filter(|ns| selected(ns)).count();
+                fixed += cost * hits as f64 / item.namespaces.len() as f64;
+            }
+            FixedCostAllocation::Namespaces => {}
+            FixedCostAllocation::Proportional => proportional += cost,
+        }
+    }
+
+    if proportional > 0.0 {
+        let all = all_namespaces_pod_cost(q, unit_prices).await?;
+        if all > 0.0 {
+            fixed += proportional * (dto.summary.total_cost_usd / all).min(1.0);
+        }
+    }
+
+    dto.summary.fixed_cost_usd = fixed;
+    dto.summary.total_cost_usd += fixed;
+    Ok(())
+}
+
+/// With a network flow source configured, reprices egress by destination instead of
+/// charging all pod traffic at `network_external_gb`. Selections without flow rows in
+/// the window keep the counter-based cost.
+async fn apply_network_flow_cost(
+    dto: &mut MetricCostSummaryResponseDto,
+    namespace: Option<&str>,
+    namespaces: &[String],
+    filters: &MetricFilters,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<()> {
+    if info_settings_service::get_info_settings().await?.network_flow_url.is_none() {
+        return Ok(());
+    }
+
+    let only = namespace.map(|ns| vec![ns.to_string()]);
+    let allowed = only.as_deref().or((!namespaces.is_empty()).then_some(namespaces));
+    let flow_ns = flow_namespaces(allowed, filters)?;
+    if flow_ns.is_empty() {
+        return Ok(());
+    }
+
+    let flows = network_flow_cost(&flow_ns, dto.start, dto.end, unit_prices)?;
+    if flows.intra_zone_bytes + flows.cross_zone_bytes + flows.internet_bytes == 0 {
+        return Ok(());
+    }
+    dto.summary.total_cost_usd += flows.total_cost_usd - dto.summary.network_cost_usd;
+    dto.summary.network_cost_usd = flows.total_cost_usd;
+    Ok(())
+}
 
 // MULTIPLE NS
 pub async fn get_metric_k8s_namespaces_cost(
@@ -364,13 +563,33 @@ pub async fn get_metric_k8s_namespaces_cost_summary(
     namespaces: Vec<String>
 ) -> Result<Value> {
 
-    let aggregated = build_namespace_cost(None, q.clone(), &namespaces).await?;
+    let filters = MetricFilters::from_query(&q)?;
     let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
 
-    let mut cost_resp = aggregated.clone();
-    apply_costs(&mut cost_resp, &unit_prices);
-
-    let dto = build_cost_summary_dto(&cost_resp, MetricScope::Namespace, None, &unit_prices);
+    let closed = closed_cost_summary(&q, MetricScope::Namespace, None, |r| {
+        (namespaces.is_empty() || namespaces.contains(&r.namespace))
+            && filters.matches_namespace(&record_text(&r.namespace))
+            && filters.matches_attribution(&record_text(&r.team), &record_text(&r.service), &record_text(&r.env))
+    })?;
+    let materialized = match closed {
+        Some(dto) => Some(dto),
+        None => materialized_cost_summary(&q, MetricScope::Namespace, None, |p| {
+            (namespaces.is_empty() || p.namespace.as_ref().is_some_and(|ns| namespaces.contains(ns)))
+                && filters.matches_namespace(&p.namespace)
+                && filters.matches_attribution(&p.team, &p.service, &p.env)
+        })?,
+    };
+    let mut dto = match materialized {
+        Some(dto) => dto,
+        None => {
+            let mut cost_resp = build_namespace_cost(None, q.clone(), &namespaces).await?;
+            apply_costs(&mut cost_resp, &unit_prices);
+            build_cost_summary_dto(&cost_resp, MetricScope::Namespace, None, &unit_prices)
+        }
+    };
+    add_fixed_cost(&mut dto, None, &namespaces, &filters, &q, &unit_prices).await?;
+    add_load_balancer_cost(&mut dto, None, &namespaces, &filters, &unit_prices)?;
+    apply_network_flow_cost(&mut dto, None, &namespaces, &filters, &unit_prices).await?;
     Ok(serde_json::to_value(dto)?)
 }
 
@@ -379,18 +598,33 @@ pub async fn get_metric_k8s_namespace_cost_summary(
     q: RangeQuery
 ) -> Result<Value> {
 
-    let aggregated = build_namespace_cost(Some(ns.clone()), q.clone(), &[]).await?;
+    let filters = MetricFilters::from_query(&q)?;
     let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
 
-    let mut cost_resp = aggregated.clone();
-    apply_costs(&mut cost_resp, &unit_prices);
-
-    let dto = build_cost_summary_dto(
-        &cost_resp,
-        MetricScope::Namespace,
-        Some(ns),
-        &unit_prices,
-    );
+    let closed = closed_cost_summary(&q, MetricScope::Namespace, Some(ns.clone()), |r| {
+        r.namespace == ns
+            && filters.matches_namespace(&record_text(&r.namespace))
+            && filters.matches_attribution(&record_text(&r.team), &record_text(&r.service), &record_text(&r.env))
+    })?;
+    let materialized = match closed {
+        Some(dto) => Some(dto),
+        None => materialized_cost_summary(&q, MetricScope::Namespace, Some(ns.clone()), |p| {
+            p.namespace.as_deref() == Some(ns.as_str())
+                && filters.matches_namespace(&p.namespace)
+                && filters.matches_attribution(&p.team, &p.service, &p.env)
+        })?,
+    };
+    let mut dto = match materialized {
+        Some(dto) => dto,
+        None => {
+            let mut cost_resp = build_namespace_cost(Some(ns.clone()), q.clone(), &[]).await?;
+            apply_costs(&mut cost_resp, &unit_prices);
+            build_cost_summary_dto(&cost_resp, MetricScope::Namespace, Some(ns.clone()), &unit_prices)
+        }
+    };
+    add_fixed_cost(&mut dto, Some(&ns), &[], &filters, &q, &unit_prices).await?;
+    add_load_balancer_cost(&mut dto, Some(&ns), &[], &filters, &unit_prices)?;
+    apply_network_flow_cost(&mut dto, Some(&ns), &[], &filters, &unit_prices).await?;
 
     Ok(serde_json::to_value(dto)?)
 }
@@ -430,3 +664,56 @@ pub async fn get_metric_k8s_namespace_cost_trend(
 
     Ok(serde_json::to_value(dto)?)
 }
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quota_allocations_prefer_requests_over_limits() {
+        let ns = InfoNamespaceEntity {
+            quota_names: Some(vec!["compute".into()]),
+            quota_cpu_request_cores: Some(4.0),
+            quota_cpu_limit_cores: Some(8.0),
+            quota_memory_limit_bytes: Some(2 * 1_073_741_824),
+            ..Default::default()
+        };
+        assert_eq!(quota_allocations(&ns), (4.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn aggregation_keeps_ephemeral_and_persistent_storage_apart() {
+        use crate::domain::metric::k8s::common::dto::{FilesystemMetricDto, StorageMetricDto};
+        use chrono::{TimeZone, Utc};
+
+        let fs = |used: f64, inodes_used: f64| FilesystemMetricDto {
+            used_bytes: Some(used),
+            inodes_used: Some(inodes_used),
+            ..Default::default()
+        };
+        let pod = |ephemeral: FilesystemMetricDto, persistent: Option<FilesystemMetricDto>| UniversalMetricPointDto {
+            time: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            filesystem: Some(ephemeral.clone()),
+            storage: Some(StorageMetricDto {
+                ephemeral: Some(ephemeral),
+                persistent,
+            }),
+            ..Default::default()
+        };
+
+        let points = aggregate_namespace_points(
+            vec![vec![pod(fs(10.0, 5.0), Some(fs(100.0, 50.0)))], vec![pod(fs(20.0, 7.0), None)]],
+            Duration::hours(1),
+        );
+
+        let storage = points[0].storage.as_ref().unwrap();
+        let ephemeral = storage.ephemeral.as_ref().unwrap();
+        let persistent = storage.persistent.as_ref().unwrap();
+        assert_eq!((ephemeral.used_bytes, ephemeral.inodes_used), (Some(30.0), Some(12.0)));
+        assert_eq!((persistent.used_bytes, persistent.inodes_used), (Some(100.0), Some(50.0)));
+        assert_eq!(persistent.capacity_bytes, None);
+        assert_eq!(points[0].filesystem.as_ref().unwrap().inodes_used, Some(12.0));
+    }
+}
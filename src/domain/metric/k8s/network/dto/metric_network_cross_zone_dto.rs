@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+/// Cross-zone transfer estimated from pod placement and pod network counters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCrossZoneCostResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularity: MetricGranularity,
+    pub namespaces: Vec<MetricCrossZoneSeriesDto>,
+    /// Estimated transfer between each pair of zones, largest first
+    pub routes: Vec<MetricCrossZoneRouteDto>,
+    pub summary: MetricCrossZoneSummaryDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCrossZoneSeriesDto {
+    pub namespace: String,
+    pub points: Vec<MetricCrossZonePointDto>,
+    pub tx_bytes: f64,
+    pub cross_zone_bytes: f64,
+    pub cost_usd: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricCrossZonePointDto {
+    pub time: DateTime<Utc>,
+    /// Bytes sent by the namespace's zoned pods
+    pub tx_bytes: f64,
+    /// Part of `tx_bytes` estimated to land in another zone
+    pub cross_zone_bytes: f64,
+    pub cost_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCrossZoneRouteDto {
+    pub from_zone: String,
+    pub to_zone: String,
+    pub bytes: f64,
+    pub cost_usd: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricCrossZoneSummaryDto {
+    pub tx_bytes: f64,
+    pub cross_zone_bytes: f64,
+    /// `cross_zone_bytes` at `network_regional_gb`
+    pub cost_usd: f64,
+    pub zone_count: usize,
+    /// Sent by pods whose node has no zone label; left out of the estimate
+    pub unzoned_tx_bytes: f64,
+}
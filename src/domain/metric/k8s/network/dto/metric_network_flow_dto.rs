@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricNetworkFlowsResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularity: MetricGranularity,
+    pub namespaces: Vec<MetricNetworkFlowSeriesDto>,
+    /// Totals over `namespaces`
+    pub summary: MetricNetworkFlowCostDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricNetworkFlowSeriesDto {
+    pub namespace: String,
+    pub points: Vec<MetricNetworkFlowPointDto>,
+    pub cost: MetricNetworkFlowCostDto,
+}
+
+/// Egress of one hour row, or the hour rows of a day/month bucket.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricNetworkFlowPointDto {
+    pub time: DateTime<Utc>,
+    pub intra_zone_bytes: u64,
+    pub cross_zone_bytes: u64,
+    pub internet_bytes: u64,
+    pub cost_usd: f64,
+}
+
+/// Egress by destination, priced at `network_local_gb`, `network_regional_gb`
+/// and `network_external_gb` respectively.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricNetworkFlowCostDto {
+    pub intra_zone_bytes: u64,
+    pub cross_zone_bytes: u64,
+    pub internet_bytes: u64,
+    pub intra_zone_cost_usd: f64,
+    pub cross_zone_cost_usd: f64,
+    pub internet_cost_usd: f64,
+    pub total_cost_usd: f64,
+}
+
+impl MetricNetworkFlowCostDto {
+    pub fn add(&mut self, other: &Self) {
+        self.intra_zone_bytes += other.intra_zone_bytes;
+        self.cross_zone_bytes += other.cross_zone_bytes;
+        self.internet_bytes += other.internet_bytes;
+        self.intra_zone_cost_usd += other.intra_zone_cost_usd;
+        self.cross_zone_cost_usd += other.cross_zone_cost_usd;
+        self.internet_cost_usd += other.internet_cost_usd;
+        self.total_cost_usd += other.total_cost_usd;
+    }
+}
@@ -0,0 +1,2 @@
+pub mod metric_network_flow_dto;
+pub mod metric_network_cross_zone_dto;
@@ -0,0 +1,404 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::metrics::k8s::network_flow::hour::metric_network_flow_hour_repository::MetricNetworkFlowHourRepository;
+use crate::core::persistence::metrics::k8s::network_flow::metric_network_flow_entity::MetricNetworkFlowEntity;
+use crate::core::persistence::metrics::k8s::path::metric_k8s_network_flow_dir_path;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
+use crate::core::persistence::repositories::repositories;
+use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::common::dto::NetworkMetricDto;
+use crate::domain::metric::k8s::common::service_helpers::{
+    hour_row_bucket_start, resolve_time_window, TimeWindow, BYTES_PER_GB,
+};
+use crate::domain::metric::k8s::common::util::k8s_metric_filter::MetricFilters;
+use crate::domain::metric::k8s::namespace::service::load_pods_by_namespace;
+use crate::domain::metric::k8s::network::dto::metric_network_cross_zone_dto::{
+    MetricCrossZoneCostResponseDto, MetricCrossZonePointDto, MetricCrossZoneRouteDto, MetricCrossZoneSeriesDto,
+    MetricCrossZoneSummaryDto,
+};
+use crate::domain::metric::k8s::network::dto::metric_network_flow_dto::{
+    MetricNetworkFlowCostDto, MetricNetworkFlowPointDto, MetricNetworkFlowSeriesDto, MetricNetworkFlowsResponseDto,
+};
+use crate::domain::metric::k8s::node_pool::service::node_labels;
+use crate::domain::metric::k8s::pod::service::{pod_network_by_time, PodMetricRepos};
+
+/// Node labels carrying the availability zone, current first.
+const ZONE_LABELS: &[&str] = &[
+    "topology.kubernetes.io/zone",
+    "failure-domain.beta.kubernetes.io/zone",
+];
+
+// ------------------------------
+// Helpers
+// ------------------------------
+
+/// Namespaces with stored flow rows, narrowed to `namespaces` (any when `None`) and the
+/// namespace filters.
+pub(crate) fn flow_namespaces(namespaces: Option<&[String]>, filters: &MetricFilters) -> Result<Vec<String>> {
+    let mut keys: Vec<String> = metric_object_keys("network_flow", &metric_k8s_network_flow_dir_path())?
+        .into_iter()
+        .filter(|ns| namespaces.is_none_or(|allowed| allowed.contains(ns)))
+        .filter(|ns| filters.matches_namespace(&Some(ns.clone())))
+        .collect();
+    keys.sort();
+    Ok(keys)
+}
+
+fn row_cost(row: &MetricNetworkFlowEntity, unit_prices: &InfoUnitPriceEntity) -> MetricNetworkFlowCostDto {
+    let gb = |bytes: u64| bytes as f64 / BYTES_PER_GB;
+    let intra = gb(row.intra_zone_bytes) * unit_prices.network_local_gb;
+    let cross = gb(row.cross_zone_bytes) * unit_prices.network_regional_gb;
+    let internet = gb(row.internet_bytes) * unit_prices.network_external_gb;
+    MetricNetworkFlowCostDto {
+        intra_zone_bytes: row.intra_zone_bytes,
+        cross_zone_bytes: row.cross_zone_bytes,
+        internet_bytes: row.internet_bytes,
+        intra_zone_cost_usd: intra,
+        cross_zone_cost_usd: cross,
+        internet_cost_usd: internet,
+        total_cost_usd: intra + cross + internet,
+    }
+}
+
+fn flow_series(
+    namespace: &str,
+    rows: &[MetricNetworkFlowEntity],
+    window: &TimeWindow,
+    unit_prices: &InfoUnitPriceEntity,
+) -> MetricNetworkFlowSeriesDto {
+    let mut buckets: BTreeMap<DateTime<Utc>, MetricNetworkFlowPointDto> = BTreeMap::new();
+    let mut cost = MetricNetworkFlowCostDto::default();
+
+    for row in rows {
+        let row_cost = row_cost(row, unit_prices);
+        let time = hour_row_bucket_start(row.time, &window.granularity, window.offset);
+        let point = buckets.entry(time).or_insert_with(|| MetricNetworkFlowPointDto {
+            time,
+            ..Default::default()
+        });
+        point.intra_zone_bytes += row.intra_zone_bytes;
+        point.cross_zone_bytes += row.cross_zone_bytes;
+        point.internet_bytes += row.internet_bytes;
+        point.cost_usd += row_cost.total_cost_usd;
+        cost.add(&row_cost);
+    }
+
+    MetricNetworkFlowSeriesDto {
+        namespace: namespace.to_string(),
+        points: buckets.into_values().collect(),
+        cost,
+    }
+}
+
+/// Priced egress of `namespaces` over `[start, end)`.
+pub(crate) fn network_flow_cost(
+    namespaces: &[String],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<MetricNetworkFlowCostDto> {
+    let repo = MetricNetworkFlowHourRepository::new();
+    let mut cost = MetricNetworkFlowCostDto::default();
+    for ns in namespaces {
+        for row in repo.get_row_between(ns, start, end)? {
+            cost.add(&row_cost(&row, unit_prices));
+        }
+    }
+    Ok(cost)
+}
+
+/// Series for `namespaces`; list views (`skip_empty`) leave out namespaces without rows
+/// in the window.
+async fn build_flows_response(q: RangeQuery, namespaces: Vec<String>, skip_empty: bool) -> Result<MetricNetworkFlowsResponseDto> {
+    let window = resolve_time_window(&q);
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+
+    let repo = MetricNetworkFlowHourRepository::new();
+    let mut series = Vec::with_capacity(namespaces.len());
+    let mut summary = MetricNetworkFlowCostDto::default();
+    for ns in &namespaces {
+        let rows = repo.get_row_between(ns, window.start, window.end)?;
+        if skip_empty && rows.is_empty() {
+            continue;
+        }
+        let s = flow_series(ns, &rows, &window, &unit_prices);
+        summary.add(&s.cost);
+        series.push(s);
+    }
+
+    Ok(MetricNetworkFlowsResponseDto {
+        start: window.start,
+        end: window.end,
+        granularity: window.granularity,
+        namespaces: series,
+        summary,
+    })
+}
+
+// ------------------------------
+// Cross-zone estimate
+// ------------------------------
+
+fn node_zone(node: &InfoNodeEntity) -> Option<String> {
+    let labels = node_labels(node);
+    ZONE_LABELS
+        .iter()
+        .find_map(|key| labels.get(*key).filter(|v| !v.is_empty()).cloned())
+}
+
+/// Zone of every stored node, deleted ones included so their pods' history still places.
+async fn node_zones() -> Result<HashMap<String, String>> {
+    let mut zones = HashMap::new();
+    for node in repositories().info_nodes.list().await? {
+        if let (Some(zone), Some(name)) = (node_zone(&node), node.node_name.clone()) {
+            zones.insert(name, zone);
+        }
+    }
+    Ok(zones)
+}
+
+/// Network deltas of one pod per bucket.
+struct PodTraffic {
+    namespace: String,
+    /// `None` when the pod's node has no zone label
+    zone: Option<String>,
+    /// Part of the requested selection; other pods only weigh the zone shares
+    selected: bool,
+    network: HashMap<DateTime<Utc>, NetworkMetricDto>,
+}
+
+#[derive(Default)]
+struct CrossZoneEstimate {
+    /// `(namespace, time)` -> `(tx, cross-zone)` bytes
+    points: BTreeMap<(String, DateTime<Utc>), (f64, f64)>,
+    /// `(from, to)` -> bytes
+    routes: BTreeMap<(String, String), f64>,
+    zones: BTreeSet<String>,
+    unzoned_tx: f64,
+}
+
+/// Pod counters don't say where bytes went, so each bucket's egress from a zone is
+/// spread over the zones in proportion to what their pods received in that bucket.
+/// Traffic to other zones is the cross-zone estimate.
+fn estimate_cross_zone(pods: &[PodTraffic]) -> CrossZoneEstimate {
+    let mut est = CrossZoneEstimate::default();
+
+    let mut received: HashMap<DateTime<Utc>, HashMap<&str, f64>> = HashMap::new();
+    for pod in pods {
+        let Some(zone) = pod.zone.as_deref() else { continue };
+        est.zones.insert(zone.to_string());
+        for (time, n) in &pod.network {
+            *received.entry(*time).or_default().entry(zone).or_default() += n.rx_bytes.unwrap_or(0.0);
+        }
+    }
+
+    for pod in pods.iter().filter(|p| p.selected) {
+        for (time, n) in &pod.network {
+            let tx = n.tx_bytes.unwrap_or(0.0);
+            let Some(zone) = pod.zone.as_deref() else {
+                est.unzoned_tx += tx;
+                continue;
+            };
+
+            let mut cross = 0.0;
+            if let Some(by_zone) = received.get(time) {
+                let total: f64 = by_zone.values().sum();
+                if total > 0.0 {
+                    for (to, rx) in by_zone {
+                        if *to == zone {
+                            continue;
+                        }
+                        let bytes = tx * rx / total;
+                        *est.routes.entry((zone.to_string(), to.to_string())).or_default() += bytes;
+                        cross += bytes;
+                    }
+                }
+            }
+
+            let point = est.points.entry((pod.namespace.clone(), *time)).or_default();
+            point.0 += tx;
+            point.1 += cross;
+        }
+    }
+
+    est
+}
+
+fn cross_zone_response(
+    est: CrossZoneEstimate,
+    window: &TimeWindow,
+    unit_prices: &InfoUnitPriceEntity,
+) -> MetricCrossZoneCostResponseDto {
+    let price = |bytes: f64| bytes / BYTES_PER_GB * unit_prices.network_regional_gb;
+
+    let mut namespaces: Vec<MetricCrossZoneSeriesDto> = Vec::new();
+    let mut summary = MetricCrossZoneSummaryDto {
+        zone_count: est.zones.len(),
+        unzoned_tx_bytes: est.unzoned_tx,
+        ..Default::default()
+    };
+    for ((namespace, time), (tx, cross)) in est.points {
+        if namespaces.last().is_none_or(|s| s.namespace != namespace) {
+            namespaces.push(MetricCrossZoneSeriesDto {
+                namespace,
+                points: Vec::new(),
+                tx_bytes: 0.0,
+                cross_zone_bytes: 0.0,
+                cost_usd: 0.0,
+            });
+        }
+        let series = namespaces.last_mut().expect("series pushed above");
+        let cost = price(cross);
+        series.points.push(MetricCrossZonePointDto {
+            time,
+            tx_bytes: tx,
+            cross_zone_bytes: cross,
+            cost_usd: cost,
+        });
+        series.tx_bytes += tx;
+        series.cross_zone_bytes += cross;
+        series.cost_usd += cost;
+        summary.tx_bytes += tx;
+        summary.cross_zone_bytes += cross;
+        summary.cost_usd += cost;
+    }
+
+    let mut routes: Vec<MetricCrossZoneRouteDto> = est
+        .routes
+        .into_iter()
+        .map(|((from_zone, to_zone), bytes)| MetricCrossZoneRouteDto {
+            from_zone,
+            to_zone,
+            bytes,
+            cost_usd: price(bytes),
+        })
+        .collect();
+    routes.sort_by(|a, b| b.bytes.total_cmp(&a.bytes));
+
+    MetricCrossZoneCostResponseDto {
+        start: window.start,
+        end: window.end,
+        granularity: window.granularity.clone(),
+        namespaces,
+        routes,
+        summary,
+    }
+}
+
+// ------------------------------
+// API
+// ------------------------------
+
+pub async fn get_metric_k8s_network_flows(q: RangeQuery, namespaces: Option<Vec<String>>) -> Result<Value> {
+    let filters = MetricFilters::from_query(&q)?;
+    let keys = flow_namespaces(namespaces.as_deref(), &filters)?;
+    Ok(serde_json::to_value(build_flows_response(q, keys, true).await?)?)
+}
+
+pub async fn get_metric_k8s_namespace_network_flows(namespace: String, q: RangeQuery) -> Result<Value> {
+    Ok(serde_json::to_value(build_flows_response(q, vec![namespace], false).await?)?)
+}
+
+/// Every pod's traffic weighs the zone shares; only pods in `namespaces` (any when
+/// `None`) passing the query's filters are reported.
+pub async fn get_metric_k8s_network_cross_zone_cost(q: RangeQuery, namespaces: Option<Vec<String>>) -> Result<Value> {
+    let window = resolve_time_window(&q);
+    let filters = MetricFilters::from_query(&q)?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let zones = node_zones().await?;
+    let now = Utc::now();
+    let repos = PodMetricRepos::new();
+
+    let mut pods = Vec::new();
+    for (namespace, infos) in load_pods_by_namespace(&[], &MetricFilters::default()).await? {
+        let in_scope = namespaces.as_ref().is_none_or(|allowed| allowed.contains(&namespace))
+            && filters.matches_namespace(&Some(namespace.clone()));
+        for pod in infos {
+            if pod.running_hours_within(window.start, window.end, now) == Some(0.0) {
+                continue;
+            }
+            let Some(uid) = pod.pod_uid.as_deref() else { continue };
+            pods.push(PodTraffic {
+                namespace: namespace.clone(),
+                zone: pod.node_name.as_ref().and_then(|n| zones.get(n)).cloned(),
+                selected: in_scope && filters.matches_attribution(&pod.team, &pod.service, &pod.env),
+                network: pod_network_by_time(uid, &window, &repos)?,
+            });
+        }
+    }
+
+    let est = estimate_cross_zone(&pods);
+    Ok(serde_json::to_value(cross_zone_response(est, &window, &unit_prices))?)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, TimeZone};
+    use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+    #[test]
+    fn prices_each_destination_separately_and_buckets_by_day() {
+        let prices = InfoUnitPriceEntity {
+            network_local_gb: 0.0,
+            network_regional_gb: 0.01,
+            network_external_gb: 0.09,
+            ..Default::default()
+        };
+        let window = TimeWindow {
+            start: Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap(),
+            granularity: MetricGranularity::Day,
+            offset: FixedOffset::east_opt(0).unwrap(),
+        };
+        let row = |hour: u32| MetricNetworkFlowEntity {
+            time: Utc.with_ymd_and_hms(2025, 3, 1, hour, 0, 0).unwrap(),
+            intra_zone_bytes: 5 * BYTES_PER_GB as u64,
+            cross_zone_bytes: 2 * BYTES_PER_GB as u64,
+            internet_bytes: BYTES_PER_GB as u64,
+        };
+
+        let series = flow_series("shop", &[row(1), row(2)], &window, &prices);
+        assert_eq!(series.points.len(), 1);
+        assert_eq!(series.points[0].cross_zone_bytes, 4 * BYTES_PER_GB as u64);
+        assert!((series.cost.cross_zone_cost_usd - 0.04).abs() < 1e-9);
+        assert!((series.cost.internet_cost_usd - 0.18).abs() < 1e-9);
+        assert!((series.cost.total_cost_usd - 0.22).abs() < 1e-9);
+        assert!((series.points[0].cost_usd - 0.22).abs() < 1e-9);
+    }
+
+    #[test]
+    fn spreads_zone_egress_by_where_traffic_is_received() {
+        let time = Utc.with_ymd_and_hms(2025, 3, 1, 1, 0, 0).unwrap();
+        let pod = |ns: &str, zone: Option<&str>, rx: f64, tx: f64, selected: bool| PodTraffic {
+            namespace: ns.into(),
+            zone: zone.map(Into::into),
+            selected,
+            network: HashMap::from([(
+                time,
+                NetworkMetricDto { rx_bytes: Some(rx), tx_bytes: Some(tx), ..Default::default() },
+            )]),
+        };
+        let pods = vec![
+            pod("shop", Some("a"), 100.0, 80.0, true),
+            pod("db", Some("b"), 300.0, 0.0, false),
+            pod("batch", None, 0.0, 50.0, true),
+        ];
+
+        let est = estimate_cross_zone(&pods);
+        // Zone b receives 3/4 of the traffic, so 3/4 of shop's egress crosses zones
+        assert_eq!(est.points[&("shop".to_string(), time)], (80.0, 60.0));
+        assert_eq!(est.routes[&("a".to_string(), "b".to_string())], 60.0);
+        assert_eq!(est.points.len(), 1);
+        assert_eq!(est.unzoned_tx, 50.0);
+        assert_eq!(est.zones.len(), 2);
+    }
+}
@@ -19,6 +19,10 @@ pub struct MetricNodeDto {
     pub fs_capacity_bytes: Option<u64>,
     pub fs_inodes_used: Option<u64>,
     pub fs_inodes: Option<u64>,
+    pub cpu_allocatable_nano_cores: Option<u64>,
+    pub memory_allocatable_bytes: Option<u64>,
+    pub memory_pressure_percent: Option<u64>,
+    pub disk_pressure_percent: Option<u64>,
 }
 
 impl From<MetricNodeEntity> for MetricNodeDto {
@@ -39,6 +43,10 @@ impl From<MetricNodeEntity> for MetricNodeDto {
             fs_capacity_bytes: e.fs_capacity_bytes,
             fs_inodes_used: e.fs_inodes_used,
             fs_inodes: e.fs_inodes,
+            cpu_allocatable_nano_cores: e.cpu_allocatable_nano_cores,
+            memory_allocatable_bytes: e.memory_allocatable_bytes,
+            memory_pressure_percent: e.memory_pressure_percent,
+            disk_pressure_percent: e.disk_pressure_percent,
         }
     }
 }
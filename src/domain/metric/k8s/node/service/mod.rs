@@ -1,11 +1,12 @@
 use anyhow::{anyhow, Result};
 use serde_json::Value;
+use std::collections::HashSet;
+use std::ops::Range;
 
 use crate::api::dto::metrics_dto::RangeQuery;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
-use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
-use crate::core::persistence::info::k8s::node::info_node_repository::InfoNodeRepository;
+use crate::core::persistence::repositories::repositories;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repository::MetricNodeDayRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_api_repository_trait::MetricNodeHourApiRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_repository::MetricNodeHourRepository;
@@ -13,9 +14,13 @@ use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNode
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_api_repository_trait::MetricNodeMinuteApiRepository;
 use crate::domain::common::service::day_granularity::split_day_granularity_rows;
 use crate::domain::info::service::{info_unit_price_service};
-use crate::domain::metric::k8s::common::dto::{CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto, NetworkMetricDto, UniversalMetricPointDto};
+use crate::domain::metric::k8s::common::dto::{CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto, NetworkMetricDto, NodeSaturationMetricDto, UniversalMetricPointDto};
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
-use crate::domain::metric::k8s::common::service_helpers::{apply_node_costs, build_cost_summary_dto, build_cost_trend_dto, build_efficiency_value, build_node_cost_summary_dto, build_raw_summary_value, resolve_time_window, TimeWindow, BYTES_PER_GB};
+use crate::domain::metric::k8s::common::dto::metric_k8s_stream_dto::{MetricSeriesStream, MetricStreamHeaderDto};
+use crate::domain::metric::k8s::common::service_helpers::{apply_node_costs, build_cost_summary_dto, build_cost_trend_dto, build_efficiency_value, stored_containers, EfficiencyBaselines, build_node_cost_summary_dto, build_raw_summary_value, minute_row_hours, month_row_hours, resolve_time_window, TimeWindow, BYTES_PER_GB};
+use crate::domain::metric::k8s::common::util::k8s_metric_cursor::cursor_page;
+use crate::domain::metric::k8s::common::util::k8s_metric_filter::MetricFilters;
+use crate::domain::metric::k8s::common::util::k8s_metric_sort::{offset_page, rank_desc, series_cost_usd, series_cpu_cores, MetricSortKey};
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
 
@@ -31,7 +36,7 @@ fn fetch_node_points(
         // --------------------
         K8sMetricRepositoryVariant::NodeMinute(r) => {
             let rows = r.get_row_between(node_name, window.start, window.end)?;
-            let running_hours = rows.len() as f64 / 60.0;
+            let running_hours = rows.len() as f64 * minute_row_hours();
 
             let points = rows
                 .into_iter()
@@ -70,15 +75,23 @@ fn fetch_node_points(
                 &hour_repo,
             )?;
 
-            let running_hours =
-                split.start_hour_rows.len() as f64 +
-                    split.end_hour_rows.len() as f64 +
-                    split.middle_day_rows.len() as f64 * 24.0;
+            let running_hours = split.running_hours();
+            let rows = split.into_rows();
 
-            let mut rows = Vec::new();
-            rows.extend(split.start_hour_rows);
-            rows.extend(split.middle_day_rows);
-            rows.extend(split.end_hour_rows);
+            let points = rows
+                .into_iter()
+                .map(metric_node_entity_to_point)
+                .collect();
+
+            Ok((points, running_hours))
+        }
+
+        // --------------------
+        // Month
+        // --------------------
+        K8sMetricRepositoryVariant::NodeMonth(r) => {
+            let rows = r.get_row_between(node_name, window.start, window.end)?;
+            let running_hours = rows.iter().map(|r| month_row_hours(r.time)).sum();
 
             let points = rows
                 .into_iter()
@@ -115,10 +128,32 @@ fn metric_node_entity_to_point(entity: MetricNodeEntity) -> UniversalMetricPoint
             rx_errors: entity.network_physical_rx_errors.map(|v| v as f64),
             tx_errors: entity.network_physical_tx_errors.map(|v| v as f64),
         }),
+        saturation: node_saturation(&entity),
         ..Default::default()
     }
 }
 
+/// `None` for rows collected before saturation was recorded.
+fn node_saturation(entity: &MetricNodeEntity) -> Option<NodeSaturationMetricDto> {
+    let cpu_allocatable = entity.cpu_allocatable_nano_cores.map(|v| v as f64);
+    let memory_allocatable = entity.memory_allocatable_bytes.map(|v| v as f64);
+    let headroom = |allocatable: Option<f64>, used: Option<u64>| Some(allocatable? - used? as f64);
+
+    let saturation = NodeSaturationMetricDto {
+        cpu_allocatable_nano_cores: cpu_allocatable,
+        memory_allocatable_bytes: memory_allocatable,
+        cpu_headroom_nano_cores: headroom(cpu_allocatable, entity.cpu_usage_nano_cores),
+        memory_headroom_bytes: headroom(memory_allocatable, entity.memory_working_set_bytes),
+        memory_pressure_percent: entity.memory_pressure_percent.map(|v| v as f64),
+        disk_pressure_percent: entity.disk_pressure_percent.map(|v| v as f64),
+    };
+    let recorded = cpu_allocatable.is_some()
+        || memory_allocatable.is_some()
+        || saturation.memory_pressure_percent.is_some()
+        || saturation.disk_pressure_percent.is_some();
+    recorded.then_some(saturation)
+}
+
 async fn build_node_raw_data(
     q: RangeQuery,
     node_names: Vec<String>,
@@ -128,74 +163,27 @@ async fn build_node_raw_data(
     let window = resolve_time_window(&q);
     let metric_repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
 
-    // 2️⃣ Load node metadata from repo (POD MODEL)
-    let info_repo = InfoNodeRepository::new();
-    let mut node_infos = Vec::new();
-
-    for name in node_names {
-        if let Ok(info) = info_repo.read(&name) {
-            node_infos.push(info);
-        }
-    }
-
-    // 3️⃣ Apply filters
-    let matches = |value: &Option<String>, filter: &str| {
-        value.as_deref()
-            .map(|v| v.split(',').any(|x| x.trim().eq_ignore_ascii_case(filter)))
-            .unwrap_or(false)
-    };
-
-    if let Some(team) = &q.team {
-        node_infos.retain(|n| matches(&n.team, team));
-    }
-    if let Some(service) = &q.service {
-        node_infos.retain(|n| matches(&n.service, service));
-    }
-    if let Some(env) = &q.env {
-        node_infos.retain(|n| matches(&n.env, env));
-    }
-
-    // 4️⃣ Sorting
-    match q.sort.as_deref() {
-        Some("cpu") => node_infos.sort_by(|a, b| a.cpu_capacity_cores.cmp(&b.cpu_capacity_cores)),
-        Some("memory") => node_infos.sort_by(|a, b| a.memory_capacity_bytes.cmp(&b.memory_capacity_bytes)),
-        Some("ready") => node_infos.sort_by(|a, b| a.ready.cmp(&b.ready)),
-        Some("ip") => node_infos.sort_by(|a, b| a.internal_ip.cmp(&b.internal_ip)),
-        _ => node_infos.sort_by(|a, b| a.node_name.cmp(&b.node_name)),
+    // 2️⃣ Load, filter and sort node metadata
+    let node_infos = load_node_infos(&q, node_names).await?;
+    if let Some(sort) = MetricSortKey::from_query(&q) {
+        return build_ranked_node_data(&q, node_infos, &metric_repo, window, sort).await;
     }
 
-    // 5️⃣ Pagination
+    // 3️⃣ Pagination
     let offset = q.offset.unwrap_or(0);
     let limit = q.limit.unwrap_or(100);
     let total = node_infos.len();
+    let (page, next_cursor) = node_page(&q, &node_infos, offset, limit)?;
+
+    let page_slice = node_infos[page.clone()].to_vec();
 
-    let page_slice = node_infos
+    // 4️⃣ Build metric series (from correct metric repo)
+    let series = page_slice
         .iter()
-        .skip(offset)
-        .take(limit)
-        .cloned()
-        .collect::<Vec<_>>();
-
-    // 6️⃣ Build metric series (from correct metric repo)
-    let mut series = Vec::new();
-    for node in &page_slice {
-        let name = node
-            .node_name
-            .clone()
-            .ok_or_else(|| anyhow!("Node record missing name"))?;
-
-        let (points, running_hours) = fetch_node_points(&metric_repo, &name, &window)?;
-        series.push(MetricSeriesDto {
-            key: name.clone(),
-            name: name.clone(),
-            scope: MetricScope::Node,
-            points,
-            running_hours: Some(running_hours),
-            cost_summary: None,
-        });
-    }
+        .map(|node| node_series(node, &metric_repo, &window))
+        .collect::<Result<Vec<_>>>()?;
 
-    // 7️⃣ Build response
+    // 5️⃣ Build response
     let response = MetricGetResponseDto {
         start: window.start,
         end: window.end,
@@ -205,13 +193,147 @@ async fn build_node_raw_data(
         series,
         total: Some(total),
         limit: Some(limit),
-        offset: Some(offset),
+        offset: Some(page.start),
+        next_cursor,
+        warnings: Vec::new(),
+        partial: false,
     };
 
     Ok((response, page_slice))
 }
 
-fn sum_node_allocations(nodes: &[InfoNodeEntity]) -> (f64, f64, f64) {
+/// `sort=cost|usage`: builds every node's series, ranks them over the window, then
+/// cuts the page. Returns the page's node infos in ranked order.
+async fn build_ranked_node_data(
+    q: &RangeQuery,
+    node_infos: Vec<InfoNodeEntity>,
+    metric_repo: &K8sMetricRepositoryVariant,
+    window: TimeWindow,
+    sort: MetricSortKey,
+) -> Result<(MetricGetResponseDto, Vec<InfoNodeEntity>)> {
+    if q.cursor.is_some() {
+        return Err(anyhow!("cursor pagination requires nodes sorted by name"));
+    }
+
+    let series = node_infos
+        .iter()
+        .map(|node| node_series(node, metric_repo, &window))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut response = MetricGetResponseDto {
+        start: window.start,
+        end: window.end,
+        scope: "node".to_string(),
+        target: None,
+        granularity: window.granularity,
+        series,
+        total: Some(node_infos.len()),
+        limit: None,
+        offset: None,
+        next_cursor: None,
+        warnings: Vec::new(),
+        partial: false,
+    };
+
+    let keys: Vec<f64> = match sort {
+        MetricSortKey::Usage => response.series.iter().map(series_cpu_cores).collect(),
+        MetricSortKey::Cost => {
+            // Price a copy so raw responses stay cost-free
+            let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+            let mut priced = response.clone();
+            apply_node_costs(&mut priced, &unit_prices, &node_infos);
+            priced.series.iter().map(series_cost_usd).collect()
+        }
+    };
+
+    let offset = q.offset.unwrap_or(0);
+    let limit = q.limit.unwrap_or(100);
+    let page = offset_page(node_infos.len(), offset, limit);
+    let order = &rank_desc(&keys)[page.clone()];
+
+    let mut series: Vec<Option<MetricSeriesDto>> = response.series.drain(..).map(Some).collect();
+    response.series = order.iter().filter_map(|&i| series[i].take()).collect();
+    response.limit = Some(limit);
+    response.offset = Some(page.start);
+
+    let page_infos = order.iter().map(|&i| node_infos[i].clone()).collect();
+    Ok((response, page_infos))
+}
+
+/// Cursors are keyed by node name, so they only apply to the default name ordering.
+fn node_page(
+    q: &RangeQuery,
+    node_infos: &[InfoNodeEntity],
+    offset: usize,
+    limit: usize,
+) -> Result<(Range<usize>, Option<String>)> {
+    let by_name = !matches!(q.sort.as_deref(), Some("cpu" | "memory" | "ready" | "ip"));
+    if !by_name {
+        if q.cursor.is_some() {
+            return Err(anyhow!("cursor pagination requires nodes sorted by name"));
+        }
+        return Ok((offset_page(node_infos.len(), offset, limit), None));
+    }
+
+    cursor_page(
+        node_infos,
+        |n| n.node_name.as_deref().unwrap_or_default(),
+        q.cursor.as_deref(),
+        offset,
+        limit,
+    )
+}
+
+pub(crate) async fn load_node_infos(q: &RangeQuery, node_names: Vec<String>) -> Result<Vec<InfoNodeEntity>> {
+    let filters = MetricFilters::from_query(q)?;
+
+    // Load node metadata from repo (POD MODEL)
+    let info_repo = repositories().info_nodes;
+    let mut node_infos = Vec::new();
+
+    for name in node_names {
+        if let Ok(info) = info_repo.read(&name).await {
+            node_infos.push(info);
+        }
+    }
+
+    // Apply filters
+    node_infos.retain(|n| filters.matches_attribution(&n.team, &n.service, &n.env));
+
+    // Sorting
+    match q.sort.as_deref() {
+        Some("cpu") => node_infos.sort_by(|a, b| a.cpu_capacity_cores.cmp(&b.cpu_capacity_cores)),
+        Some("memory") => node_infos.sort_by(|a, b| a.memory_capacity_bytes.cmp(&b.memory_capacity_bytes)),
+        Some("ready") => node_infos.sort_by(|a, b| a.ready.cmp(&b.ready)),
+        Some("ip") => node_infos.sort_by(|a, b| a.internal_ip.cmp(&b.internal_ip)),
+        _ => node_infos.sort_by(|a, b| a.node_name.cmp(&b.node_name)),
+    }
+
+    Ok(node_infos)
+}
+
+pub(crate) fn node_series(
+    node: &InfoNodeEntity,
+    metric_repo: &K8sMetricRepositoryVariant,
+    window: &TimeWindow,
+) -> Result<MetricSeriesDto> {
+    let name = node
+        .node_name
+        .clone()
+        .ok_or_else(|| anyhow!("Node record missing name"))?;
+
+    let (points, running_hours) = fetch_node_points(metric_repo, &name, window)?;
+    Ok(MetricSeriesDto {
+        key: name.clone(),
+        name,
+        scope: MetricScope::Node,
+        points,
+        running_hours: Some(running_hours),
+        cost_summary: None,
+    })
+}
+
+pub(crate) fn sum_node_allocations(nodes: &[InfoNodeEntity]) -> (f64, f64, f64) {
     let mut total_cpu = 0.0;
     let mut total_mem_bytes = 0.0;
     let mut total_storage_bytes = 0.0;
@@ -229,12 +351,71 @@ fn sum_node_allocations(nodes: &[InfoNodeEntity]) -> (f64, f64, f64) {
     )
 }
 
+/// Requests and limits of the containers scheduled on `nodes`, against their
+/// allocatable capacity.
+pub(crate) async fn node_baselines(nodes: &[InfoNodeEntity]) -> EfficiencyBaselines {
+    let names: HashSet<&str> = nodes.iter().filter_map(|n| n.node_name.as_deref()).collect();
+    let containers = stored_containers(|c| c.node_name.as_deref().is_some_and(|n| names.contains(n))).await;
+    let (cpu, mem, _) = sum_node_allocations(nodes);
+    EfficiencyBaselines::from_containers(&containers).with_allocatable(cpu, mem)
+}
+
+/// Allocatable (cores, GB) of the distinct nodes in `names`, from stored node info.
+pub(crate) async fn hosting_node_allocations<'a>(names: impl IntoIterator<Item = &'a str>) -> (f64, f64) {
+    let names: HashSet<&str> = names.into_iter().collect();
+    let repo = repositories().info_nodes;
+    let mut nodes = Vec::new();
+    for name in names {
+        if let Ok(node) = repo.read(name).await {
+            nodes.push(node);
+        }
+    }
+    let (cpu, mem, _) = sum_node_allocations(&nodes);
+    (cpu, mem)
+}
+
 
 pub async fn get_metric_k8s_nodes_raw(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
     let (response, _) = build_node_raw_data(q, node_names).await?;
     Ok(serde_json::to_value(response)?)
 }
 
+/// Same as [`get_metric_k8s_nodes_raw`], but yields one series at a time.
+pub async fn stream_metric_k8s_nodes_raw(q: RangeQuery, node_names: Vec<String>) -> Result<MetricSeriesStream> {
+    if MetricSortKey::from_query(&q).is_some() {
+        // Ranking needs every series up front, so there is nothing left to stream lazily
+        let (response, _) = build_node_raw_data(q, node_names).await?;
+        return Ok(MetricSeriesStream::from_response(response));
+    }
+
+    let window = resolve_time_window(&q);
+    let metric_repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
+    let node_infos = load_node_infos(&q, node_names).await?;
+    let offset = q.offset.unwrap_or(0);
+    let limit = q.limit.unwrap_or(100);
+    let (page, next_cursor) = node_page(&q, &node_infos, offset, limit)?;
+
+    let header = MetricStreamHeaderDto {
+        start: window.start,
+        end: window.end,
+        scope: "node".to_string(),
+        target: None,
+        granularity: window.granularity.clone(),
+        total: Some(node_infos.len()),
+        limit: Some(limit),
+        offset: Some(page.start),
+        next_cursor,
+    };
+
+    let series = node_infos
+        .into_iter()
+        .skip(page.start)
+        .take(page.len())
+        .map(move |node| node_series(&node, &metric_repo, &window));
+
+    Ok(MetricSeriesStream::new(header, series))
+}
+
 pub async fn get_metric_k8s_nodes_raw_summary(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
     let (response, node_infos) = build_node_raw_data(q, node_names).await?;
     build_raw_summary_value(&response, MetricScope::Node, node_infos.len())
@@ -249,7 +430,7 @@ pub async fn get_metric_k8s_nodes_raw_efficiency(q: RangeQuery, node_names: Vec<
 
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
     let (total_cpu, total_mem, total_storage) = sum_node_allocations(&node_infos);
-    build_efficiency_value(summary, MetricScope::Node, total_cpu, total_mem, total_storage)
+    build_efficiency_value(summary, MetricScope::Node, total_cpu, total_mem, total_storage, node_baselines(&node_infos).await)
 }
 
 pub async fn get_metric_k8s_node_raw(node_name: String, q: RangeQuery) -> Result<Value> {
@@ -270,7 +451,7 @@ pub async fn get_metric_k8s_node_raw_efficiency(node_name: String, q: RangeQuery
     let summary_value = build_raw_summary_value(&response, MetricScope::Node, 1)?;
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
     let (total_cpu, total_mem, total_storage) = sum_node_allocations(&node_infos);
-    build_efficiency_value(summary, MetricScope::Node, total_cpu, total_mem, total_storage)
+    build_efficiency_value(summary, MetricScope::Node, total_cpu, total_mem, total_storage, node_baselines(&node_infos).await)
 }
 
 async fn build_node_cost_response(
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::MetricRawEfficiencyDto;
+
+/// Efficiency of every node pool over the same window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricNodePoolsEfficiencyResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularity: MetricGranularity,
+    pub pools: Vec<MetricNodePoolEfficiencyDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricNodePoolEfficiencyDto {
+    pub pool: String,
+    pub node_count: usize,
+    pub efficiency: MetricRawEfficiencyDto,
+}
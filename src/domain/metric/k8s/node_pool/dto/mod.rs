@@ -0,0 +1 @@
+pub mod metric_node_pool_efficiency_dto;
@@ -0,0 +1,428 @@
+use anyhow::{anyhow, Result};
+use chrono::Duration;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::MetricRawEfficiencyResponseDto;
+use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
+use crate::domain::metric::k8s::common::dto::{
+    CostMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
+};
+use crate::domain::metric::k8s::common::service_helpers::{
+    apply_node_costs, build_cost_trend_dto, build_efficiency_value, build_node_cost_summary_dto,
+    build_raw_summary_value, resolve_time_window, sample_step, sum_running_hours,
+};
+use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
+use crate::domain::metric::k8s::namespace::service::aggregate_namespace_points;
+use crate::domain::metric::k8s::node::service::{load_node_infos, node_baselines, node_series, sum_node_allocations};
+use crate::domain::metric::k8s::node_pool::dto::metric_node_pool_efficiency_dto::{
+    MetricNodePoolEfficiencyDto, MetricNodePoolsEfficiencyResponseDto,
+};
+
+/// Node labels naming the pool/group a node was provisioned from, most specific first.
+const POOL_LABELS: &[&str] = &[
+    "eks.amazonaws.com/nodegroup",
+    "alpha.eksctl.io/nodegroup-name",
+    "cloud.google.com/gke-nodepool",
+    "karpenter.sh/nodepool",
+    "karpenter.sh/provisioner-name",
+    "kubernetes.azure.com/agentpool",
+    "agentpool",
+];
+
+/// Pool name for nodes carrying none of the [`POOL_LABELS`].
+pub const UNPOOLED: &str = "unpooled";
+
+// ------------------------------
+// Helpers
+// ------------------------------
+
+/// Node labels are stored as a JSON object; older records use `key=value,...`.
+pub(crate) fn node_labels(node: &InfoNodeEntity) -> HashMap<String, String> {
+    let Some(raw) = node.label.as_deref() else {
+        return HashMap::new();
+    };
+
+    serde_json::from_str(raw).unwrap_or_else(|_| {
+        raw.split(',')
+            .filter_map(|kv| kv.split_once('='))
+            .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+            .collect()
+    })
+}
+
+/// Node labels carrying the cloud instance type, current first.
+const INSTANCE_TYPE_LABELS: &[&str] = &[
+    "node.kubernetes.io/instance-type",
+    "beta.kubernetes.io/instance-type",
+];
+
+pub(crate) fn node_instance_type(node: &InfoNodeEntity) -> Option<String> {
+    let labels = node_labels(node);
+    INSTANCE_TYPE_LABELS
+        .iter()
+        .find_map(|key| labels.get(*key).filter(|v| !v.is_empty()).cloned())
+}
+
+/// Node labels carrying the operating system, current first.
+const OS_LABELS: &[&str] = &["kubernetes.io/os", "beta.kubernetes.io/os"];
+
+/// OS bucket for nodes that report neither `nodeInfo.operatingSystem` nor an OS label.
+pub const UNKNOWN_OS: &str = "unknown";
+
+/// `linux`, `windows`, ...: the kubelet-reported OS, else the OS label.
+pub fn node_os(node: &InfoNodeEntity) -> String {
+    node.operating_system
+        .as_deref()
+        .map(str::trim)
+        .filter(|os| !os.is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            let labels = node_labels(node);
+            OS_LABELS
+                .iter()
+                .find_map(|key| labels.get(*key).filter(|v| !v.is_empty()).cloned())
+        })
+        .map(|os| os.to_lowercase())
+        .unwrap_or_else(|| UNKNOWN_OS.to_string())
+}
+
+/// Taint virtual-kubelet nodes carry, valued with the provider (`azure`, ...).
+const VIRTUAL_KUBELET_PROVIDER_TAINT: &str = "virtual-kubelet.io/provider";
+
+/// Provider of a virtual node (`fargate`, `aci`, ...), or `None` for nodes with
+/// real capacity. Virtual nodes advertise placeholder capacity, so their pods
+/// are priced on requests instead.
+pub fn virtual_node_provider(node: &InfoNodeEntity) -> Option<String> {
+    let labels = node_labels(node);
+    if labels.get("eks.amazonaws.com/compute-type").map(String::as_str) == Some("fargate") {
+        return Some("fargate".to_string());
+    }
+    if labels.get("type").map(String::as_str) != Some("virtual-kubelet") {
+        return None;
+    }
+
+    // Taints are stored as `key=value (effect), ...`
+    let provider = node.taints.as_deref().unwrap_or_default().split(", ").find_map(|taint| {
+        let (key, rest) = taint.split_once('=')?;
+        (key == VIRTUAL_KUBELET_PROVIDER_TAINT)
+            .then(|| rest.split(" (").next().unwrap_or_default().to_lowercase())
+    });
+    Some(match provider.as_deref() {
+        Some("azure") => "aci".to_string(),
+        Some(p) if !p.is_empty() => p.to_string(),
+        _ => "virtual-kubelet".to_string(),
+    })
+}
+
+pub fn node_pool(node: &InfoNodeEntity) -> String {
+    let labels = node_labels(node);
+    POOL_LABELS
+        .iter()
+        .find_map(|key| labels.get(*key).filter(|v| !v.is_empty()).cloned())
+        .unwrap_or_else(|| UNPOOLED.to_string())
+}
+
+/// How nodes are bucketed into groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeGrouping {
+    Pool,
+    Os,
+}
+
+impl NodeGrouping {
+    fn key(self, node: &InfoNodeEntity) -> String {
+        match self {
+            NodeGrouping::Pool => node_pool(node),
+            NodeGrouping::Os => node_os(node),
+        }
+    }
+
+    fn scope(self) -> &'static str {
+        match self {
+            NodeGrouping::Pool => "nodepool",
+            NodeGrouping::Os => "nodeos",
+        }
+    }
+}
+
+fn group_nodes(node_infos: Vec<InfoNodeEntity>, grouping: NodeGrouping) -> BTreeMap<String, Vec<InfoNodeEntity>> {
+    let mut groups: BTreeMap<String, Vec<InfoNodeEntity>> = BTreeMap::new();
+    for node in node_infos {
+        groups.entry(grouping.key(&node)).or_default().push(node);
+    }
+    groups
+}
+
+/// Sums a pool's node series into one. Node costs live on the series, not the points.
+fn pool_series(pool: &str, node_series: &[MetricSeriesDto], step: Duration) -> MetricSeriesDto {
+    let points = aggregate_namespace_points(node_series.iter().map(|s| s.points.clone()).collect(), step);
+
+    let cost_summary = node_series
+        .iter()
+        .filter_map(|s| s.cost_summary.as_ref())
+        .fold(None, |acc: Option<CostMetricDto>, c| {
+            let mut acc = acc.unwrap_or_default();
+            let add = |slot: &mut Option<f64>, v: Option<f64>| {
+                *slot = Some(slot.unwrap_or(0.0) + v.unwrap_or(0.0));
+            };
+            add(&mut acc.total_cost_usd, c.total_cost_usd);
+            add(&mut acc.cpu_cost_usd, c.cpu_cost_usd);
+            add(&mut acc.memory_cost_usd, c.memory_cost_usd);
+            add(&mut acc.storage_cost_usd, c.storage_cost_usd);
+            Some(acc)
+        });
+
+    MetricSeriesDto {
+        key: pool.to_string(),
+        name: pool.to_string(),
+        scope: MetricScope::NodePool,
+        points,
+        running_hours: sum_running_hours(node_series),
+        cost_summary,
+    }
+}
+
+/// One series per pool (or just `pool` when set), priced when `unit_prices` is given.
+async fn build_pool_response(
+    q: &RangeQuery,
+    node_names: Vec<String>,
+    pool: Option<&str>,
+    unit_prices: Option<&InfoUnitPriceEntity>,
+) -> Result<(MetricGetResponseDto, BTreeMap<String, Vec<InfoNodeEntity>>)> {
+    build_group_response(q, node_names, NodeGrouping::Pool, pool, unit_prices).await
+}
+
+async fn build_group_response(
+    q: &RangeQuery,
+    node_names: Vec<String>,
+    grouping: NodeGrouping,
+    group: Option<&str>,
+    unit_prices: Option<&InfoUnitPriceEntity>,
+) -> Result<(MetricGetResponseDto, BTreeMap<String, Vec<InfoNodeEntity>>)> {
+    let window = resolve_time_window(q);
+    let metric_repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
+
+    let mut pools = group_nodes(load_node_infos(q, node_names).await?, grouping);
+    if let Some(name) = group {
+        pools.retain(|p, _| p == name);
+        if pools.is_empty() {
+            return Err(anyhow!("{} '{}' has no nodes", grouping.scope(), name));
+        }
+    }
+
+    let step = sample_step(&window.granularity);
+    let mut series = Vec::with_capacity(pools.len());
+    for (name, nodes) in &pools {
+        let mut per_node = MetricGetResponseDto {
+            start: window.start,
+            end: window.end,
+            scope: "node".to_string(),
+            target: None,
+            granularity: window.granularity.clone(),
+            series: nodes
+                .iter()
+                .map(|node| node_series(node, &metric_repo, &window))
+                .collect::<Result<Vec<_>>>()?,
+            total: None,
+            limit: None,
+            offset: None,
+            next_cursor: None,
+            warnings: Vec::new(),
+            partial: false,
+        };
+        if let Some(prices) = unit_prices {
+            apply_node_costs(&mut per_node, prices, nodes);
+        }
+        series.push(pool_series(name, &per_node.series, step));
+    }
+
+    let response = MetricGetResponseDto {
+        start: window.start,
+        end: window.end,
+        scope: grouping.scope().to_string(),
+        target: group.map(str::to_string),
+        granularity: window.granularity,
+        total: Some(series.len()),
+        series,
+        limit: None,
+        offset: None,
+        next_cursor: None,
+        warnings: Vec::new(),
+        partial: false,
+    };
+
+    Ok((response, pools))
+}
+
+async fn pool_efficiency(response: &MetricGetResponseDto, nodes: &[InfoNodeEntity]) -> Result<Value> {
+    let summary_value = build_raw_summary_value(response, MetricScope::NodePool, nodes.len())?;
+    let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
+    let (total_cpu, total_mem, total_storage) = sum_node_allocations(nodes);
+    build_efficiency_value(summary, MetricScope::NodePool, total_cpu, total_mem, total_storage, node_baselines(nodes).await)
+}
+
+// ------------------------------
+// RAW
+// ------------------------------
+
+pub async fn get_metric_k8s_nodepools_raw(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let (response, _) = build_pool_response(&q, node_names, None, None).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_nodepool_raw(pool: String, q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let (response, _) = build_pool_response(&q, node_names, Some(&pool), None).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+// ------------------------------
+// EFFICIENCY
+// ------------------------------
+
+pub async fn get_metric_k8s_nodepools_raw_efficiency(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let (response, pools) = build_pool_response(&q, node_names, None, None).await?;
+
+    let mut out = Vec::with_capacity(pools.len());
+    for series in &response.series {
+        let nodes = &pools[&series.key];
+        let single = MetricGetResponseDto {
+            series: vec![series.clone()],
+            ..response.clone()
+        };
+        let dto: MetricRawEfficiencyResponseDto = serde_json::from_value(pool_efficiency(&single, nodes).await?)?;
+        out.push(MetricNodePoolEfficiencyDto {
+            pool: series.key.clone(),
+            node_count: nodes.len(),
+            efficiency: dto.efficiency,
+        });
+    }
+
+    Ok(serde_json::to_value(MetricNodePoolsEfficiencyResponseDto {
+        start: response.start,
+        end: response.end,
+        granularity: response.granularity,
+        pools: out,
+    })?)
+}
+
+pub async fn get_metric_k8s_nodepool_raw_efficiency(pool: String, q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let (response, pools) = build_pool_response(&q, node_names, Some(&pool), None).await?;
+    pool_efficiency(&response, &pools[&pool]).await
+}
+
+// ------------------------------
+// COST
+// ------------------------------
+
+pub async fn get_metric_k8s_nodepools_cost(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let (response, _) = build_pool_response(&q, node_names, None, Some(&unit_prices)).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_nodepools_cost_summary(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let (response, _) = build_pool_response(&q, node_names, None, Some(&unit_prices)).await?;
+    let dto = build_node_cost_summary_dto(&response, MetricScope::NodePool, None, &unit_prices);
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_nodepools_cost_trend(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let (response, _) = build_pool_response(&q, node_names, None, Some(&unit_prices)).await?;
+    let dto = build_cost_trend_dto(&response, MetricScope::NodePool, None)?;
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_nodepool_cost(pool: String, q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let (response, _) = build_pool_response(&q, node_names, Some(&pool), Some(&unit_prices)).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_nodepool_cost_summary(pool: String, q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let (response, _) = build_pool_response(&q, node_names, Some(&pool), Some(&unit_prices)).await?;
+    let dto = build_node_cost_summary_dto(&response, MetricScope::NodePool, Some(pool), &unit_prices);
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_nodepool_cost_trend(pool: String, q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let (response, _) = build_pool_response(&q, node_names, Some(&pool), Some(&unit_prices)).await?;
+    let dto = build_cost_trend_dto(&response, MetricScope::NodePool, Some(pool))?;
+    Ok(serde_json::to_value(dto)?)
+}
+
+// ------------------------------
+// OPERATING SYSTEM
+// ------------------------------
+
+/// Node cost series per operating system, e.g. to compare Windows and Linux nodes.
+pub async fn get_metric_k8s_nodes_os_cost(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let (response, _) = build_group_response(&q, node_names, NodeGrouping::Os, None, Some(&unit_prices)).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_nodes_os_cost_summary(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let (response, _) = build_group_response(&q, node_names, NodeGrouping::Os, None, Some(&unit_prices)).await?;
+    let dto = build_node_cost_summary_dto(&response, MetricScope::NodePool, None, &unit_prices);
+    Ok(serde_json::to_value(dto)?)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(label: &str) -> InfoNodeEntity {
+        InfoNodeEntity {
+            label: Some(label.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn resolves_pool_from_well_known_labels() {
+        assert_eq!(node_pool(&node(r#"{"eks.amazonaws.com/nodegroup":"general"}"#)), "general");
+        assert_eq!(node_pool(&node("cloud.google.com/gke-nodepool=gpu,kubernetes.io/os=linux")), "gpu");
+        assert_eq!(node_pool(&node(r#"{"karpenter.sh/nodepool":"spot"}"#)), "spot");
+        assert_eq!(node_pool(&node(r#"{"kubernetes.io/os":"linux"}"#)), UNPOOLED);
+        assert_eq!(node_pool(&InfoNodeEntity::default()), UNPOOLED);
+    }
+
+    #[test]
+    fn resolves_os_from_node_info_then_label() {
+        let windows = InfoNodeEntity {
+            operating_system: Some("Windows".into()),
+            ..node(r#"{"kubernetes.io/os":"linux"}"#)
+        };
+        assert_eq!(node_os(&windows), "windows");
+        assert_eq!(node_os(&node(r#"{"kubernetes.io/os":"linux"}"#)), "linux");
+        assert_eq!(node_os(&node("beta.kubernetes.io/os=windows")), "windows");
+        assert_eq!(node_os(&InfoNodeEntity::default()), UNKNOWN_OS);
+    }
+
+    #[test]
+    fn detects_virtual_node_providers() {
+        assert_eq!(
+            virtual_node_provider(&node(r#"{"eks.amazonaws.com/compute-type":"fargate"}"#)).as_deref(),
+            Some("fargate")
+        );
+        let aci = InfoNodeEntity {
+            taints: Some("virtual-kubelet.io/provider=azure (NoSchedule)".into()),
+            ..node(r#"{"type":"virtual-kubelet","kubernetes.io/role":"agent"}"#)
+        };
+        assert_eq!(virtual_node_provider(&aci).as_deref(), Some("aci"));
+        assert_eq!(virtual_node_provider(&node(r#"{"type":"virtual-kubelet"}"#)).as_deref(), Some("virtual-kubelet"));
+        assert_eq!(virtual_node_provider(&node(r#"{"eks.amazonaws.com/compute-type":"ec2"}"#)), None);
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+/// Pods that restarted within the window and the cost of the time lost to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPodReliabilityResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularity: MetricGranularity,
+    pub total_restarts: u32,
+    pub total_oom_kills: u32,
+    pub total_crash_loop_hours: f64,
+    pub total_wasted_cost_usd: f64,
+    /// Highest wasted cost first
+    pub pods: Vec<MetricPodReliabilityDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPodReliabilityDto {
+    pub pod_uid: String,
+    pub pod_name: Option<String>,
+    pub namespace: Option<String>,
+    pub restarts: u32,
+    pub oom_kills: u32,
+    /// Restarts per termination reason, e.g. `OOMKilled`, `Error`
+    pub reasons: Vec<MetricPodRestartReasonDto>,
+    pub last_restart_at: Option<DateTime<Utc>>,
+    /// Backoff downtime plus the runtime of attempts that failed quickly
+    pub crash_loop_hours: f64,
+    /// Pod cost over the window divided by the hours it reported
+    pub hourly_cost_usd: Option<f64>,
+    pub wasted_cost_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPodRestartReasonDto {
+    pub reason: String,
+    pub restarts: u32,
+}
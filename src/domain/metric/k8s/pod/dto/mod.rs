@@ -1,3 +1,4 @@
 pub mod metric_pod_dto;
 pub mod deployment_response_dto;
 pub mod namespace_response_dto;
+pub mod metric_pod_reliability_dto;
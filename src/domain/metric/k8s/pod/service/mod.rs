@@ -1,18 +1,20 @@
 use anyhow::{anyhow, Result};
 use serde_json::Value;
-use std::collections::HashSet;
-use crate::api::dto::{info_dto::K8sListQuery, metrics_dto::RangeQuery};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use chrono::{DateTime, Duration, Utc};
+use crate::api::dto::{info_dto::K8sListQuery, metrics_dto::{CostBasis, RangeQuery}};
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
-use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
-use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
 use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_repository::MetricPodDayRepository;
 use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_repository::MetricPodHourRepository;
 use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_api_repository_trait::MetricPodHourApiRepository;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_repository::MetricPodMinuteRepository;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_api_repository_trait::MetricPodMinuteApiRepository;
+use crate::core::persistence::metrics::k8s::pod::restart::metric_pod_restart_entity::MetricPodRestartEntity;
+use crate::core::persistence::metrics::k8s::pod::restart::metric_pod_restart_fs_adapter::MetricPodRestartFsAdapter;
+use crate::core::persistence::repositories::repositories;
 use crate::domain::info::service::{
     info_k8s_container_service, info_unit_price_service,
 };
@@ -21,20 +23,34 @@ use crate::domain::metric::k8s::common::dto::{
     NetworkMetricDto, StorageMetricDto, UniversalMetricPointDto, MetricGranularity,
 };
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
+use crate::domain::metric::k8s::common::dto::metric_k8s_stream_dto::{MetricSeriesStream, MetricStreamHeaderDto};
 use crate::domain::metric::k8s::common::service_helpers::{
-    apply_costs, build_cost_summary_dto, build_cost_trend_dto, build_efficiency_value,
-    build_raw_summary_value, resolve_time_window, TimeWindow, BYTES_PER_GB,
+    apply_costs_with_basis, apply_virtual_node_costs, build_cost_summary_dto, build_cost_trend_dto,
+    build_efficiency_value, build_raw_summary_value, minute_row_hours, month_row_hours, resolve_time_window,
+    virtual_node_rates, EfficiencyBaselines, ResourceRequests, TimeWindow, BYTES_PER_GB,
 };
+use crate::domain::metric::k8s::node::service::hosting_node_allocations;
 use crate::domain::common::service::day_granularity::{split_day_granularity_rows};
+use crate::core::persistence::metrics::k8s::month::metric_month_repository::MetricMonthRepository;
+use crate::domain::export::cost_close_service::{closed_cost_summary, record_text};
+use crate::domain::export::cost_materialization_service::materialized_cost_summary;
+use crate::domain::metric::k8s::common::util::k8s_metric_cursor::cursor_page;
+use crate::domain::metric::k8s::common::util::k8s_metric_filter::MetricFilters;
+use crate::domain::metric::k8s::pod::dto::metric_pod_reliability_dto::{
+    MetricPodReliabilityDto, MetricPodReliabilityResponseDto, MetricPodRestartReasonDto,
+};
+use crate::domain::metric::k8s::common::util::k8s_metric_sort::{offset_page, rank_desc, series_cost_usd, series_cpu_cores, MetricSortKey};
 
+/// Points plus the hours the pod reported within the window (row count × row span,
+/// like nodes). Only a fallback; see [`pod_running_hours`].
 fn fetch_pod_points(
     pod_uid: &str,
     window: &TimeWindow,
     day_repo: &MetricPodDayRepository,
     hour_repo: &MetricPodHourRepository,
     minute_repo: &MetricPodMinuteRepository,
-) -> Result<Vec<UniversalMetricPointDto>> {
-    let rows: Vec<MetricPodEntity> = match window.granularity {
+) -> Result<(Vec<UniversalMetricPointDto>, f64)> {
+    let (rows, running_hours): (Vec<MetricPodEntity>, f64) = match window.granularity {
         MetricGranularity::Day => {
             let split_rows = split_day_granularity_rows(
                 pod_uid,   // object_name 역할 = pod_uid
@@ -43,28 +59,35 @@ fn fetch_pod_points(
                 hour_repo,
             )?;
 
-            let mut merged = Vec::new();
-            merged.extend(split_rows.start_hour_rows);
-            merged.extend(split_rows.middle_day_rows);
-            merged.extend(split_rows.end_hour_rows);
+            let running_hours = split_rows.running_hours();
+            let mut merged = split_rows.into_rows();
 
             // Ensure chronological order
             merged.sort_by_key(|r| r.time);
-            merged
+            (merged, running_hours)
         }
 
         MetricGranularity::Hour => {
-            hour_repo.get_row_between(window.start, window.end, pod_uid, None, None)?
+            let rows = hour_repo.get_row_between(window.start, window.end, pod_uid, None, None)?;
+            let running_hours = rows.len() as f64;
+            (rows, running_hours)
         }
 
         MetricGranularity::Minute => {
-            minute_repo.get_row_between(window.start, window.end, pod_uid, None, None)?
+            let rows = minute_repo.get_row_between(window.start, window.end, pod_uid, None, None)?;
+            let running_hours = rows.len() as f64 * minute_row_hours();
+            (rows, running_hours)
         }
 
-        _ => Vec::new(),
+        MetricGranularity::Month => {
+            let rows = MetricMonthRepository::pod().get_row_between(pod_uid, window.start, window.end)?;
+            let running_hours = rows.iter().map(|r| month_row_hours(r.time)).sum();
+            (rows, running_hours)
+        }
     };
 
-    Ok(rows.into_iter().map(metric_pod_entity_to_point).collect())
+    let points = rows.into_iter().map(metric_pod_entity_to_point).collect();
+    Ok((points, running_hours))
 }
 
 fn metric_pod_entity_to_point(entity: MetricPodEntity) -> UniversalMetricPointDto {
@@ -111,46 +134,163 @@ async fn build_pod_raw_data(
     q: RangeQuery,
     pod_uids: Vec<String>,
 ) -> Result<(MetricGetResponseDto, Vec<InfoPodEntity>)> {
+    let pod_infos = load_pod_infos(&q, pod_uids).await?;
+
+    // --- build metrics ---
+    let response = match MetricSortKey::from_query(&q) {
+        Some(sort) => build_ranked_pod_response(&q, &pod_infos, sort).await?,
+        None => build_pod_series_for_infos(&q, &pod_infos, None)?,
+    };
+
+    Ok((response, pod_infos))
+}
+
+/// `sort=cost|usage`: builds every pod's series, ranks them over the window, then
+/// cuts the page.
+async fn build_ranked_pod_response(
+    q: &RangeQuery,
+    pod_infos: &[InfoPodEntity],
+    sort: MetricSortKey,
+) -> Result<MetricGetResponseDto> {
+    if q.cursor.is_some() {
+        return Err(anyhow!("cursor pagination requires pods in UID order"));
+    }
+
+    let unpaged = RangeQuery { offset: None, limit: None, ..q.clone() };
+    let mut response = build_pod_series_for_infos(&unpaged, pod_infos, None)?;
+
+    let keys: Vec<f64> = match sort {
+        MetricSortKey::Usage => response.series.iter().map(series_cpu_cores).collect(),
+        MetricSortKey::Cost => {
+            // Price a copy so raw responses stay cost-free
+            let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+            let mut priced = response.clone();
+            price_pod_response(&mut priced, pod_infos, q.effective_cost_basis(), &unit_prices).await?;
+            priced.series.iter().map(series_cost_usd).collect()
+        }
+    };
+
+    let offset = q.offset.unwrap_or(0);
+    let limit = q.limit.unwrap_or(pod_infos.len());
+    let page = offset_page(pod_infos.len(), offset, limit);
 
-    let repo = InfoPodRepository::new();
+    let mut series: Vec<Option<MetricSeriesDto>> = response.series.drain(..).map(Some).collect();
+    response.series = rank_desc(&keys)[page.clone()]
+        .iter()
+        .filter_map(|&i| series[i].take())
+        .collect();
+    response.limit = Some(limit);
+    response.offset = Some(page.start);
+    response.next_cursor = None;
+
+    Ok(response)
+}
+
+async fn load_pod_infos(q: &RangeQuery, pod_uids: Vec<String>) -> Result<Vec<InfoPodEntity>> {
+    let filters = MetricFilters::from_query(q)?;
+    let repo = repositories().info_pods;
     let mut pod_infos = Vec::new();
 
     // --- load from repo only, no fetch, no cache refresh ---
     for uid in pod_uids {
-        if let Ok(entity) = repo.read(&uid) {
+        if let Ok(entity) = repo.read(&uid).await {
             pod_infos.push(entity);
         }
     }
 
     // --- filters ---
-    let matches = |value: &Option<String>, filter: &str| {
-        value
-            .as_deref()
-            .map(|v| {
-                v.split(',')
-                    .any(|x| x.trim().eq_ignore_ascii_case(filter))
-            })
-            .unwrap_or(false)
-    };
+    pod_infos.retain(|p| {
+        filters.matches_namespace(&p.namespace)
+            && filters.matches_attribution(&p.team, &p.service, &p.env)
+    });
 
-    if let Some(ref team) = q.team {
-        pod_infos.retain(|p| matches(&p.team, team));
-    }
+    sort_by_pod_uid(&mut pod_infos);
+    Ok(pod_infos)
+}
 
-    if let Some(ref service) = q.service {
-        pod_infos.retain(|p| matches(&p.service, service));
-    }
+/// Stable page order; cursors are keyed by pod UID.
+fn sort_by_pod_uid(pod_infos: &mut [InfoPodEntity]) {
+    pod_infos.sort_by(|a, b| a.pod_uid.cmp(&b.pod_uid));
+}
+
+fn pod_uid_key(pod: &InfoPodEntity) -> &str {
+    pod.pod_uid.as_deref().unwrap_or_default()
+}
 
-    if let Some(ref env) = q.env {
-        pod_infos.retain(|p| matches(&p.env, env));
+/// Metric repositories shared by every pod of one request.
+pub(crate) struct PodMetricRepos {
+    day: MetricPodDayRepository,
+    hour: MetricPodHourRepository,
+    minute: MetricPodMinuteRepository,
+}
+
+impl PodMetricRepos {
+    pub(crate) fn new() -> Self {
+        Self {
+            day: MetricPodDayRepository::new(),
+            hour: MetricPodHourRepository::new(),
+            minute: MetricPodMinuteRepository::new(),
+        }
     }
+}
 
-    // --- build metrics ---
-    let response = build_pod_series_for_infos(&q, &pod_infos, None)?;
+/// Network values of the pod's points in the window, by point time.
+pub(crate) fn pod_network_by_time(
+    pod_uid: &str,
+    window: &TimeWindow,
+    repos: &PodMetricRepos,
+) -> Result<HashMap<DateTime<Utc>, NetworkMetricDto>> {
+    let (points, _) = fetch_pod_points(pod_uid, window, &repos.day, &repos.hour, &repos.minute)?;
+    Ok(points
+        .into_iter()
+        .filter_map(|p| p.network.map(|n| (p.time, n)))
+        .collect())
+}
 
-    Ok((response, pod_infos))
+fn pod_series(pod: &InfoPodEntity, window: &TimeWindow, repos: &PodMetricRepos) -> Result<MetricSeriesDto> {
+    let pod_uid = pod
+        .pod_uid
+        .clone()
+        .ok_or_else(|| anyhow!("Pod record missing UID"))?;
+
+    let (points, row_hours) = fetch_pod_points(
+        &pod_uid,
+        window,
+        &repos.day,
+        &repos.hour,
+        &repos.minute,
+    )?;
+    let running_hours = pod_running_hours(pod, window, &points, row_hours);
+
+    let name = pod.pod_name.clone().unwrap_or_else(|| pod_uid.clone());
+
+    Ok(MetricSeriesDto {
+        key: pod_uid,
+        name,
+        scope: MetricScope::Pod,
+        points,
+        running_hours: Some(running_hours),
+        cost_summary: None,
+    })
+}
+
+/// Running hours from the recorded lifecycle, so failed scrapes don't shave hours
+/// off the bill. Falls back to the metric row count when start/end is unknown, or
+/// when nothing was scraped at all (likely deleted while no watcher was running).
+fn pod_running_hours(
+    pod: &InfoPodEntity,
+    window: &TimeWindow,
+    points: &[UniversalMetricPointDto],
+    row_hours: f64,
+) -> f64 {
+    if points.is_empty() {
+        return row_hours;
+    }
+    pod.running_hours_within(window.start, window.end, Utc::now())
+        .unwrap_or(row_hours)
 }
 
+/// `pod_infos` must be sorted by pod UID (see [`sort_by_pod_uid`]).
 fn build_pod_series_for_infos(
     q: &RangeQuery,
     pod_infos: &[InfoPodEntity],
@@ -159,47 +299,17 @@ fn build_pod_series_for_infos(
     let window = resolve_time_window(q);
 
     // 1) Create repos ONCE (reuse across all pods)
-    let day_repo = MetricPodDayRepository::new();
-    let hour_repo = MetricPodHourRepository::new();
-    let minute_repo = MetricPodMinuteRepository::new();
+    let repos = PodMetricRepos::new();
 
     // 2) Apply API-level paging to the POD list (not to metric rows)
-    //    Adjust field names if your RangeQuery uses different ones.
     let offset = q.offset.unwrap_or(0);
     let limit = q.limit.unwrap_or(pod_infos.len());
+    let (page, next_cursor) = cursor_page(pod_infos, pod_uid_key, q.cursor.as_deref(), offset, limit)?;
 
-    let sliced = pod_infos
+    let series = pod_infos[page.clone()]
         .iter()
-        .skip(offset)
-        .take(limit);
-
-    let mut series = Vec::new();
-
-    for pod in sliced {
-        let pod_uid = pod
-            .pod_uid
-            .clone()
-            .ok_or_else(|| anyhow!("Pod record missing UID"))?;
-
-        let points = fetch_pod_points(
-            &pod_uid,
-            &window,
-            &day_repo,
-            &hour_repo,
-            &minute_repo,
-        )?;
-
-        let name = pod.pod_name.clone().unwrap_or_else(|| pod_uid.clone());
-
-        series.push(MetricSeriesDto {
-            key: pod_uid,
-            name,
-            scope: MetricScope::Pod,
-            points,
-            running_hours: None,
-            cost_summary: None,
-        });
-    }
+        .map(|pod| pod_series(pod, &window, &repos))
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(MetricGetResponseDto {
         start: window.start,
@@ -210,15 +320,19 @@ fn build_pod_series_for_infos(
         series,
         total: Some(pod_infos.len()),
         limit: Some(limit),
-        offset: Some(offset),
+        offset: Some(page.start),
+        next_cursor,
+        warnings: Vec::new(),
+        partial: false,
     })
 }
 
 pub(crate) fn build_pod_response_from_infos(
     q: RangeQuery,
-    pod_infos: Vec<InfoPodEntity>,
+    mut pod_infos: Vec<InfoPodEntity>,
     target: Option<String>,
 ) -> Result<MetricGetResponseDto> {
+    sort_by_pod_uid(&mut pod_infos);
     build_pod_series_for_infos(&q, &pod_infos, target)
 }
 
@@ -260,16 +374,95 @@ fn sum_container_requests(
     (total_cpu, total_memory_gb)
 }
 
+/// Requests and limits of the target pods' containers, against the
+/// allocatable capacity of the nodes they run on.
+async fn pod_baselines(
+    containers: &[InfoContainerEntity],
+    target_pods: &HashSet<String>,
+    pods: &[InfoPodEntity],
+) -> EfficiencyBaselines {
+    let (cpu, mem) = hosting_node_allocations(pods.iter().filter_map(|p| p.node_name.as_deref())).await;
+    EfficiencyBaselines::from_containers(
+        containers
+            .iter()
+            .filter(|c| c.pod_uid.as_ref().is_some_and(|uid| target_pods.contains(uid))),
+    )
+    .with_allocatable(cpu, mem)
+}
+
 async fn build_pod_cost_response(
     q: RangeQuery,
     pod_uids: Vec<String>,
     unit_prices: InfoUnitPriceEntity,
 ) -> Result<MetricGetResponseDto> {
-    let (mut response, _) = build_pod_raw_data(q, pod_uids).await?;
-    apply_costs(&mut response, &unit_prices);
+    let basis = q.effective_cost_basis();
+    let (mut response, pod_infos) = build_pod_raw_data(q, pod_uids).await?;
+    price_pod_response(&mut response, &pod_infos, basis, &unit_prices).await?;
     Ok(response)
 }
 
+/// Priced pod series together with the pod infos they were built from, for
+/// callers that need pod metadata next to the costs (warehouse export).
+pub(crate) async fn build_pod_cost_response_with_infos(
+    q: RangeQuery,
+    pod_uids: Vec<String>,
+) -> Result<(MetricGetResponseDto, Vec<InfoPodEntity>)> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let basis = q.effective_cost_basis();
+    let (mut response, pod_infos) = build_pod_raw_data(q, pod_uids).await?;
+    price_pod_response(&mut response, &pod_infos, basis, &unit_prices).await?;
+    Ok((response, pod_infos))
+}
+
+/// Applies costs on `basis`, loading container requests when it isn't pure usage
+/// or when some pods run on virtual nodes (always priced on requests).
+async fn price_pod_response(
+    response: &mut MetricGetResponseDto,
+    pod_infos: &[InfoPodEntity],
+    basis: CostBasis,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<()> {
+    let node_rates = virtual_node_rates(pod_infos.iter().filter_map(|p| p.node_name.as_deref())).await?;
+    let virtual_pods: HashMap<String, _> = pod_infos
+        .iter()
+        .filter_map(|p| {
+            let rate = node_rates.get(p.node_name.as_deref()?)?;
+            Some((pod_uid_key(p).to_string(), *rate))
+        })
+        .collect();
+
+    let requests = match basis {
+        CostBasis::Usage if virtual_pods.is_empty() => HashMap::new(),
+        _ => load_pod_requests(pod_infos).await?,
+    };
+    apply_costs_with_basis(response, unit_prices, basis, &requests);
+    apply_virtual_node_costs(response, &virtual_pods, &requests);
+    Ok(())
+}
+
+/// Summed container requests per pod UID.
+pub(crate) async fn load_pod_requests(pod_infos: &[InfoPodEntity]) -> Result<HashMap<String, ResourceRequests>> {
+    let containers = info_k8s_container_service::list_k8s_containers(K8sListQuery {
+        namespace: derive_namespace_hint(pod_infos),
+        label_selector: None,
+        node_name: None,
+    })
+    .await?;
+
+    let wanted: HashSet<String> = collect_pod_uids(pod_infos).into_iter().collect();
+    let mut requests: HashMap<String, ResourceRequests> = HashMap::new();
+    for container in containers {
+        let Some(pod_uid) = container.pod_uid.filter(|uid| wanted.contains(uid)) else {
+            continue;
+        };
+        let entry = requests.entry(pod_uid).or_default();
+        entry.cpu_cores += container.cpu_request_millicores.unwrap_or(0) as f64 / 1000.0;
+        entry.memory_bytes += container.memory_request_bytes.unwrap_or(0) as f64;
+    }
+
+    Ok(requests)
+}
+
 pub async fn get_metric_k8s_pods_raw(
     q: RangeQuery,
     pod_uids: Vec<String>) -> Result<Value> {
@@ -277,13 +470,49 @@ pub async fn get_metric_k8s_pods_raw(
     Ok(serde_json::to_value(response)?)
 }
 
+/// Same as [`get_metric_k8s_pods_raw`], but yields one series at a time.
+pub async fn stream_metric_k8s_pods_raw(q: RangeQuery, pod_uids: Vec<String>) -> Result<MetricSeriesStream> {
+    if MetricSortKey::from_query(&q).is_some() {
+        // Ranking needs every series up front, so there is nothing left to stream lazily
+        let (response, _) = build_pod_raw_data(q, pod_uids).await?;
+        return Ok(MetricSeriesStream::from_response(response));
+    }
+
+    let pod_infos = load_pod_infos(&q, pod_uids).await?;
+    let window = resolve_time_window(&q);
+    let offset = q.offset.unwrap_or(0);
+    let limit = q.limit.unwrap_or(pod_infos.len());
+    let (page, next_cursor) = cursor_page(&pod_infos, pod_uid_key, q.cursor.as_deref(), offset, limit)?;
+
+    let header = MetricStreamHeaderDto {
+        start: window.start,
+        end: window.end,
+        scope: "pod".to_string(),
+        target: None,
+        granularity: window.granularity.clone(),
+        total: Some(pod_infos.len()),
+        limit: Some(limit),
+        offset: Some(page.start),
+        next_cursor,
+    };
+
+    let repos = PodMetricRepos::new();
+    let series = pod_infos
+        .into_iter()
+        .skip(page.start)
+        .take(page.len())
+        .map(move |pod| pod_series(&pod, &window, &repos));
+
+    Ok(MetricSeriesStream::new(header, series))
+}
+
 pub async fn get_metric_k8s_pods_raw_summary(q: RangeQuery, pod_uids: Vec<String>) -> Result<Value> {
     let (response, pod_infos) = build_pod_raw_data(q, pod_uids).await?;
     build_raw_summary_value(&response, MetricScope::Pod, pod_infos.len())
 }
 
 pub async fn get_metric_k8s_pods_raw_efficiency(q: RangeQuery, pod_uids: Vec<String>) -> Result<Value> {
-    let (response, pod_infos) = build_pod_raw_data(q.clone(), pod_uids).await?;
+    let (response, pod_infos) = build_pod_raw_data(q, pod_uids).await?;
     let summary_value = build_raw_summary_value(&response, MetricScope::Pod, pod_infos.len())?;
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
 
@@ -292,7 +521,8 @@ pub async fn get_metric_k8s_pods_raw_efficiency(q: RangeQuery, pod_uids: Vec<Str
         return Err(anyhow!("no pods available for efficiency calculation"));
     }
 
-    let namespace_hint = q.namespace.or_else(|| derive_namespace_hint(&pod_infos));
+    // Pods are already namespace-filtered; the raw filter may be a list or pattern
+    let namespace_hint = derive_namespace_hint(&pod_infos);
     let containers = info_k8s_container_service::list_k8s_containers(K8sListQuery {
         namespace: namespace_hint,
         label_selector: None,
@@ -303,6 +533,7 @@ pub async fn get_metric_k8s_pods_raw_efficiency(q: RangeQuery, pod_uids: Vec<Str
     let target_set: HashSet<String> = pod_uids.into_iter().collect();
     let (total_cpu, total_mem_gb) = sum_container_requests(&containers, &target_set);
     let total_storage_gb = summary.summary.max_storage_gb;
+    let baselines = pod_baselines(&containers, &target_set, &pod_infos).await;
 
     build_efficiency_value(
         summary,
@@ -310,6 +541,7 @@ pub async fn get_metric_k8s_pods_raw_efficiency(q: RangeQuery, pod_uids: Vec<Str
         total_cpu,
         total_mem_gb,
         total_storage_gb,
+        baselines,
     )
 }
 
@@ -327,14 +559,11 @@ pub async fn get_metric_k8s_pod_raw_summary(pod_uid: String, q: RangeQuery) -> R
 
 pub async fn get_metric_k8s_pod_raw_efficiency(pod_uid: String, q: RangeQuery) -> Result<Value> {
     let pod_uids = vec![pod_uid.clone()];
-    let (response, pod_infos) = build_pod_raw_data(q.clone(), pod_uids).await?;
+    let (response, pod_infos) = build_pod_raw_data(q, pod_uids).await?;
     let summary_value = build_raw_summary_value(&response, MetricScope::Pod, 1)?;
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
 
-    let namespace_hint = pod_infos
-        .first()
-        .and_then(|p| p.namespace.clone())
-        .or(q.namespace);
+    let namespace_hint = pod_infos.first().and_then(|p| p.namespace.clone());
 
     let containers = info_k8s_container_service::list_k8s_containers(K8sListQuery {
         namespace: namespace_hint,
@@ -347,6 +576,7 @@ pub async fn get_metric_k8s_pod_raw_efficiency(pod_uid: String, q: RangeQuery) -
     target.insert(pod_uid);
     let (total_cpu, total_mem_gb) = sum_container_requests(&containers, &target);
     let total_storage_gb = summary.summary.max_storage_gb;
+    let baselines = pod_baselines(&containers, &target, &pod_infos).await;
 
     build_efficiency_value(
         summary,
@@ -354,6 +584,7 @@ pub async fn get_metric_k8s_pod_raw_efficiency(pod_uid: String, q: RangeQuery) -
         total_cpu,
         total_mem_gb,
         total_storage_gb,
+        baselines,
     )
 }
 
@@ -364,6 +595,28 @@ pub async fn get_metric_k8s_pods_cost(q: RangeQuery, pod_uids: Vec<String>) -> R
 }
 
 pub async fn get_metric_k8s_pods_cost_summary(q: RangeQuery, pod_uids: Vec<String>) -> Result<Value> {
+    let filters = MetricFilters::from_query(&q)?;
+    let wanted: HashSet<&str> = pod_uids.iter().map(String::as_str).collect();
+    let closed = closed_cost_summary(&q, MetricScope::Pod, None, |r| {
+        wanted.contains(r.pod_uid.as_str())
+            && filters.matches_namespace(&record_text(&r.namespace))
+            && filters.matches_attribution(&record_text(&r.team), &record_text(&r.service), &record_text(&r.env))
+    })?;
+    if let Some(dto) = closed {
+        return Ok(serde_json::to_value(dto)?);
+    }
+
+    if q.effective_cost_basis() == CostBasis::Usage {
+        let materialized = materialized_cost_summary(&q, MetricScope::Pod, None, |p| {
+            wanted.contains(p.pod_uid.as_str())
+                && filters.matches_namespace(&p.namespace)
+                && filters.matches_attribution(&p.team, &p.service, &p.env)
+        })?;
+        if let Some(dto) = materialized {
+            return Ok(serde_json::to_value(dto)?);
+        }
+    }
+
     let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
     let response = build_pod_cost_response(q, pod_uids, unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Pod, None, &unit_prices);
@@ -385,6 +638,18 @@ pub async fn get_metric_k8s_pod_cost(pod_uid: String, q: RangeQuery) -> Result<V
 }
 
 pub async fn get_metric_k8s_pod_cost_summary(pod_uid: String, q: RangeQuery) -> Result<Value> {
+    if let Some(dto) = closed_cost_summary(&q, MetricScope::Pod, Some(pod_uid.clone()), |r| r.pod_uid == pod_uid)? {
+        return Ok(serde_json::to_value(dto)?);
+    }
+
+    if q.effective_cost_basis() == CostBasis::Usage {
+        let materialized =
+            materialized_cost_summary(&q, MetricScope::Pod, Some(pod_uid.clone()), |p| p.pod_uid == pod_uid)?;
+        if let Some(dto) = materialized {
+            return Ok(serde_json::to_value(dto)?);
+        }
+    }
+
     let pod_uids = vec![pod_uid.clone()];
     let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
     let response =
@@ -400,3 +665,159 @@ pub async fn get_metric_k8s_pod_cost_trend(pod_uid: String, q: RangeQuery) -> Re
     let dto = build_cost_trend_dto(&response, MetricScope::Pod, Some(pod_uid))?;
     Ok(serde_json::to_value(dto)?)
 }
+
+// ------------------------------
+// RELIABILITY
+// ------------------------------
+
+/// A failed instance that ran longer than this was doing useful work before it died.
+const CRASH_LOOP_MAX_RUNTIME_MINUTES: i64 = 10;
+
+/// Reason reported when the kubelet gave no termination state.
+const UNKNOWN_REASON: &str = "Unknown";
+
+fn restart_time(event: &MetricPodRestartEntity) -> DateTime<Utc> {
+    event.terminated_at.unwrap_or(event.observed_at)
+}
+
+/// Hours lost to one restart event: backoff until the replacement started (or until
+/// the event was observed / the window closed), plus the failed instance's runtime
+/// when it died quickly. Scaled by the restarts the event folds together.
+fn crash_loop_hours(event: &MetricPodRestartEntity, window_end: DateTime<Utc>) -> f64 {
+    let Some(terminated_at) = event.terminated_at else {
+        return 0.0;
+    };
+
+    let resumed_at = event.restarted_at.unwrap_or(event.observed_at).min(window_end);
+    let backoff = (resumed_at - terminated_at).max(Duration::zero());
+
+    let runtime = match event.started_at {
+        Some(started_at) if terminated_at - started_at <= Duration::minutes(CRASH_LOOP_MAX_RUNTIME_MINUTES) => {
+            (terminated_at - started_at).max(Duration::zero())
+        }
+        _ => Duration::zero(),
+    };
+
+    (backoff + runtime).num_seconds() as f64 / 3600.0 * event.restarts.max(1) as f64
+}
+
+fn pod_reliability(
+    pod: &InfoPodEntity,
+    events: &[MetricPodRestartEntity],
+    window_end: DateTime<Utc>,
+) -> MetricPodReliabilityDto {
+    let mut reasons: BTreeMap<String, u32> = BTreeMap::new();
+    for e in events {
+        let reason = e.reason.clone().unwrap_or_else(|| UNKNOWN_REASON.to_string());
+        *reasons.entry(reason).or_default() += e.restarts;
+    }
+
+    MetricPodReliabilityDto {
+        pod_uid: pod_uid_key(pod).to_string(),
+        pod_name: pod.pod_name.clone(),
+        namespace: pod.namespace.clone(),
+        restarts: events.iter().map(|e| e.restarts).sum(),
+        oom_kills: events.iter().filter(|e| e.is_oom_kill()).map(|e| e.restarts).sum(),
+        reasons: reasons
+            .into_iter()
+            .map(|(reason, restarts)| MetricPodRestartReasonDto { reason, restarts })
+            .collect(),
+        last_restart_at: events.iter().map(restart_time).max(),
+        crash_loop_hours: events.iter().map(|e| crash_loop_hours(e, window_end)).sum(),
+        hourly_cost_usd: None,
+        wasted_cost_usd: 0.0,
+    }
+}
+
+/// Pods that restarted in the window, with crash-loop time priced at each pod's
+/// average hourly cost over the same window.
+pub async fn get_metric_k8s_pods_reliability(q: RangeQuery, pod_uids: Vec<String>) -> Result<Value> {
+    let window = resolve_time_window(&q);
+    let adapter = MetricPodRestartFsAdapter::new();
+
+    let mut pods = Vec::new();
+    for pod in load_pod_infos(&q, pod_uids).await? {
+        let events: Vec<_> = adapter
+            .list(pod_uid_key(&pod))?
+            .into_iter()
+            .filter(|e| {
+                let t = restart_time(e);
+                t >= window.start && t < window.end
+            })
+            .collect();
+        if !events.is_empty() {
+            pods.push(pod_reliability(&pod, &events, window.end));
+        }
+    }
+
+    if !pods.is_empty() {
+        let unpaged = RangeQuery { limit: None, offset: None, cursor: None, sort: None, ..q };
+        let uids = pods.iter().map(|p| p.pod_uid.clone()).collect();
+        let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+        let priced = build_pod_cost_response(unpaged, uids, unit_prices).await?;
+
+        let hourly: HashMap<&str, f64> = priced
+            .series
+            .iter()
+            .filter_map(|s| {
+                let hours = s.running_hours.filter(|h| *h > 0.0)?;
+                Some((s.key.as_str(), series_cost_usd(s) / hours))
+            })
+            .collect();
+
+        for pod in &mut pods {
+            pod.hourly_cost_usd = hourly.get(pod.pod_uid.as_str()).copied();
+            pod.wasted_cost_usd = pod.hourly_cost_usd.unwrap_or(0.0) * pod.crash_loop_hours;
+        }
+    }
+
+    pods.sort_by(|a, b| b.wasted_cost_usd.total_cmp(&a.wasted_cost_usd).then(b.restarts.cmp(&a.restarts)));
+
+    Ok(serde_json::to_value(MetricPodReliabilityResponseDto {
+        start: window.start,
+        end: window.end,
+        granularity: window.granularity,
+        total_restarts: pods.iter().map(|p| p.restarts).sum(),
+        total_oom_kills: pods.iter().map(|p| p.oom_kills).sum(),
+        total_crash_loop_hours: pods.iter().map(|p| p.crash_loop_hours).sum(),
+        total_wasted_cost_usd: pods.iter().map(|p| p.wasted_cost_usd).sum(),
+        pods,
+    })?)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(min: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap() + Duration::minutes(min)
+    }
+
+    fn event(started: i64, terminated: i64, restarted: Option<i64>, restarts: u32) -> MetricPodRestartEntity {
+        MetricPodRestartEntity {
+            container: "app".into(),
+            restart_count: restarts,
+            restarts,
+            reason: Some("OOMKilled".into()),
+            exit_code: Some(137),
+            started_at: Some(at(started)),
+            terminated_at: Some(at(terminated)),
+            restarted_at: restarted.map(at),
+            observed_at: at(60),
+        }
+    }
+
+    #[test]
+    fn crash_loop_hours_counts_backoff_and_short_runs() {
+        // 6 min runtime + 24 min backoff
+        assert_eq!(crash_loop_hours(&event(0, 6, Some(30), 1), at(120)), 0.5);
+        // long-lived instance: only the backoff counts, twice
+        assert_eq!(crash_loop_hours(&event(0, 30, Some(45), 2), at(120)), 0.5);
+        // still backing off: up to the observation, capped at window end
+        assert_eq!(crash_loop_hours(&event(0, 30, None, 1), at(120)), 0.5);
+        assert_eq!(crash_loop_hours(&event(0, 30, None, 1), at(45)), 0.25);
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvcsResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularity: MetricGranularity,
+    pub pvcs: Vec<MetricPvcSeriesDto>,
+    /// Totals over `pvcs`; only on cost responses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<MetricPvcCostSummaryDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvcSeriesDto {
+    /// `{namespace}_{claim}`
+    pub key: String,
+    pub namespace: String,
+    pub name: String,
+    pub points: Vec<MetricPvcPointDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<MetricPvcCostDto>,
+}
+
+/// One hour row, or the hour rows of a day/month bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvcPointDto {
+    pub time: DateTime<Utc>,
+    /// Largest provisioned size within the bucket
+    pub capacity_bytes: Option<u64>,
+    pub requested_bytes: Option<u64>,
+    /// Last observed usage within the bucket; `None` while unmounted
+    pub used_bytes: Option<u64>,
+    pub available_bytes: Option<u64>,
+    /// Sampled hours, and how many of them a pod had the claim mounted
+    pub hours: u32,
+    pub mounted_hours: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricPvcCostDto {
+    pub total_cost_usd: f64,
+    /// Part of the total accrued while no pod mounted the claim
+    pub unmounted_cost_usd: f64,
+    pub provisioned_gb_hours: f64,
+    pub unmounted_hours: u32,
+    /// No pod mounted the claim at any point of the window
+    pub orphaned: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricPvcCostSummaryDto {
+    pub total_cost_usd: f64,
+    pub unmounted_cost_usd: f64,
+    pub provisioned_gb_hours: f64,
+    pub pvc_count: usize,
+    pub orphaned_count: usize,
+    /// Cost of the orphaned claims, all of it unmounted
+    pub orphaned_cost_usd: f64,
+}
@@ -0,0 +1 @@
+pub mod metric_pvc_dto;
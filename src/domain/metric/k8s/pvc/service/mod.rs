@@ -0,0 +1,226 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::metrics::k8s::path::metric_k8s_pvc_dir_path;
+use crate::core::persistence::metrics::k8s::pvc::hour::metric_pvc_hour_repository::MetricPvcHourRepository;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::{
+    metric_pvc_key, split_metric_pvc_key, MetricPvcEntity,
+};
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
+use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::common::service_helpers::{
+    hour_row_bucket_start, resolve_time_window, TimeWindow, BYTES_PER_GB,
+};
+use crate::domain::metric::k8s::common::util::k8s_metric_filter::MetricFilters;
+use crate::domain::metric::k8s::pvc::dto::metric_pvc_dto::{
+    MetricPvcCostDto, MetricPvcCostSummaryDto, MetricPvcPointDto, MetricPvcSeriesDto, MetricPvcsResponseDto,
+};
+
+// ------------------------------
+// Helpers
+// ------------------------------
+
+/// Claim keys with stored rows, narrowed to `namespaces` (any when `None`) and the query's
+/// namespace filters.
+fn pvc_keys(q: &RangeQuery, namespaces: Option<&[String]>) -> Result<Vec<String>> {
+    let filters = MetricFilters::from_query(q)?;
+    let mut keys: Vec<String> = metric_object_keys("pvc", &metric_k8s_pvc_dir_path())?
+        .into_iter()
+        .filter(|key| match split_metric_pvc_key(key) {
+            Some((ns, _)) => {
+                namespaces.is_none_or(|allowed| allowed.iter().any(|a| a == ns))
+                    && filters.matches_namespace(&Some(ns.to_string()))
+            }
+            None => false,
+        })
+        .collect();
+    keys.sort();
+    Ok(keys)
+}
+
+fn row_cost(row: &MetricPvcEntity, gb_hour_price: f64) -> Option<f64> {
+    row.billable_bytes().map(|b| b as f64 / BYTES_PER_GB * gb_hour_price)
+}
+
+/// Groups hour rows (oldest first) into points of the window's granularity.
+fn bucket_rows(rows: &[MetricPvcEntity], window: &TimeWindow, gb_hour_price: Option<f64>) -> Vec<MetricPvcPointDto> {
+    let mut buckets: BTreeMap<DateTime<Utc>, MetricPvcPointDto> = BTreeMap::new();
+
+    for row in rows {
+        let time = hour_row_bucket_start(row.time, &window.granularity, window.offset);
+        let point = buckets.entry(time).or_insert_with(|| MetricPvcPointDto {
+            time,
+            capacity_bytes: None,
+            requested_bytes: None,
+            used_bytes: None,
+            available_bytes: None,
+            hours: 0,
+            mounted_hours: 0,
+            cost_usd: gb_hour_price.map(|_| 0.0),
+        });
+
+        point.capacity_bytes = point.capacity_bytes.max(row.capacity_bytes);
+        point.requested_bytes = point.requested_bytes.max(row.requested_bytes);
+        if row.mounted {
+            point.used_bytes = row.used_bytes;
+            point.available_bytes = row.available_bytes;
+            point.mounted_hours += 1;
+        }
+        point.hours += 1;
+        if let (Some(total), Some(price)) = (point.cost_usd.as_mut(), gb_hour_price) {
+            *total += row_cost(row, price).unwrap_or(0.0);
+        }
+    }
+
+    buckets.into_values().collect()
+}
+
+fn pvc_cost(rows: &[MetricPvcEntity], gb_hour_price: f64) -> MetricPvcCostDto {
+    let mut cost = MetricPvcCostDto::default();
+    for row in rows {
+        let hour_cost = row_cost(row, gb_hour_price).unwrap_or(0.0);
+        cost.total_cost_usd += hour_cost;
+        cost.provisioned_gb_hours += row.billable_bytes().map_or(0.0, |b| b as f64 / BYTES_PER_GB);
+        if !row.mounted {
+            cost.unmounted_cost_usd += hour_cost;
+            cost.unmounted_hours += 1;
+        }
+    }
+    cost.orphaned = !rows.is_empty() && rows.iter().all(|r| !r.mounted);
+    cost
+}
+
+fn pvc_series(key: &str, rows: &[MetricPvcEntity], window: &TimeWindow, gb_hour_price: Option<f64>) -> MetricPvcSeriesDto {
+    let (namespace, name) = split_metric_pvc_key(key).unwrap_or(("", key));
+    MetricPvcSeriesDto {
+        key: key.to_string(),
+        namespace: namespace.to_string(),
+        name: name.to_string(),
+        points: bucket_rows(rows, window, gb_hour_price),
+        cost: gb_hour_price.map(|price| pvc_cost(rows, price)),
+    }
+}
+
+/// Series for `keys`; list views (`skip_empty`) leave out claims without rows in the window.
+async fn build_pvcs_response(q: RangeQuery, keys: Vec<String>, with_cost: bool, skip_empty: bool) -> Result<MetricPvcsResponseDto> {
+    let window = resolve_time_window(&q);
+    let gb_hour_price = if with_cost {
+        Some(info_unit_price_service::get_info_unit_prices().await?.storage_gb_hour)
+    } else {
+        None
+    };
+
+    let repo = MetricPvcHourRepository::new();
+    let mut pvcs = Vec::with_capacity(keys.len());
+    for key in &keys {
+        let rows = repo.get_row_between(key, window.start, window.end)?;
+        if skip_empty && rows.is_empty() {
+            continue;
+        }
+        pvcs.push(pvc_series(key, &rows, &window, gb_hour_price));
+    }
+
+    let summary = with_cost.then(|| {
+        let mut summary = MetricPvcCostSummaryDto {
+            pvc_count: pvcs.len(),
+            ..Default::default()
+        };
+        for cost in pvcs.iter().filter_map(|p| p.cost.as_ref()) {
+            summary.total_cost_usd += cost.total_cost_usd;
+            summary.unmounted_cost_usd += cost.unmounted_cost_usd;
+            summary.provisioned_gb_hours += cost.provisioned_gb_hours;
+            if cost.orphaned {
+                summary.orphaned_count += 1;
+                summary.orphaned_cost_usd += cost.total_cost_usd;
+            }
+        }
+        summary
+    });
+
+    Ok(MetricPvcsResponseDto {
+        start: window.start,
+        end: window.end,
+        granularity: window.granularity,
+        pvcs,
+        summary,
+    })
+}
+
+// ------------------------------
+// API
+// ------------------------------
+
+pub async fn get_metric_k8s_pvcs_raw(q: RangeQuery, namespaces: Option<Vec<String>>) -> Result<Value> {
+    let keys = pvc_keys(&q, namespaces.as_deref())?;
+    Ok(serde_json::to_value(build_pvcs_response(q, keys, false, true).await?)?)
+}
+
+pub async fn get_metric_k8s_pvcs_cost(q: RangeQuery, namespaces: Option<Vec<String>>) -> Result<Value> {
+    let keys = pvc_keys(&q, namespaces.as_deref())?;
+    Ok(serde_json::to_value(build_pvcs_response(q, keys, true, true).await?)?)
+}
+
+pub async fn get_metric_k8s_pvc_raw(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let keys = vec![metric_pvc_key(&namespace, &name)];
+    Ok(serde_json::to_value(build_pvcs_response(q, keys, false, false).await?)?)
+}
+
+pub async fn get_metric_k8s_pvc_cost(namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let keys = vec![metric_pvc_key(&namespace, &name)];
+    Ok(serde_json::to_value(build_pvcs_response(q, keys, true, false).await?)?)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, TimeZone};
+    use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+    fn row(hour: u32, mounted: bool) -> MetricPvcEntity {
+        MetricPvcEntity {
+            time: Utc.with_ymd_and_hms(2025, 3, 1, hour, 0, 0).unwrap(),
+            capacity_bytes: Some(10 * BYTES_PER_GB as u64),
+            requested_bytes: Some(8 * BYTES_PER_GB as u64),
+            used_bytes: mounted.then_some(BYTES_PER_GB as u64),
+            available_bytes: mounted.then_some(9 * BYTES_PER_GB as u64),
+            mounted,
+        }
+    }
+
+    #[test]
+    fn charges_capacity_and_splits_out_unmounted_hours() {
+        let rows = vec![row(1, true), row(2, false), row(3, false)];
+
+        let cost = pvc_cost(&rows, 0.01);
+        assert!((cost.total_cost_usd - 0.3).abs() < 1e-9);
+        assert!((cost.unmounted_cost_usd - 0.2).abs() < 1e-9);
+        assert_eq!(cost.unmounted_hours, 2);
+        assert!(!cost.orphaned);
+
+        assert!(pvc_cost(&rows[1..], 0.01).orphaned);
+    }
+
+    #[test]
+    fn day_buckets_keep_last_mounted_usage() {
+        let window = TimeWindow {
+            start: Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap(),
+            granularity: MetricGranularity::Day,
+            offset: FixedOffset::east_opt(0).unwrap(),
+        };
+        let rows = vec![row(0, true), row(1, true), row(2, false)];
+
+        let points = bucket_rows(&rows, &window, None);
+        // The midnight row closes Feb 28
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[1].time, window.start);
+        assert_eq!((points[1].hours, points[1].mounted_hours), (2, 1));
+        assert_eq!(points[1].used_bytes, Some(BYTES_PER_GB as u64));
+        assert_eq!(points[1].cost_usd, None);
+    }
+}
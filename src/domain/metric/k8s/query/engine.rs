@@ -0,0 +1,189 @@
+//! Query engine: one request shape (scope, targets, filters, window, group-by,
+//! aggregation) for every list view.
+//!
+//! Each scope is one [`ScopeViews`] entry naming its six list methods on the
+//! [`MetricService`] facade, so results keep going through the response cache and
+//! a new scope only needs a new entry in [`scope_views`].
+
+use anyhow::{anyhow, Result};
+use futures::future::BoxFuture;
+use serde_json::Value;
+
+use crate::api::dto::metrics_dto::{CostCompareQuery, RangeQuery};
+use crate::api::dto::metrics_query_dto::{
+    MetricQueryAggregation, MetricQueryGroupBy, MetricQueryRequest, MetricQueryScope, MetricQueryView,
+};
+use crate::app_state::MetricService;
+use crate::domain::metric::k8s::common::service_helpers::build_cost_compare_value;
+use crate::domain::metric::k8s::query::service::group_series;
+
+/// A resolved query: `targets` are already checked against the caller's scope
+/// and filled in with every visible object when the request named none.
+#[derive(Debug, Clone)]
+pub struct MetricQuery {
+    pub scope: MetricQueryScope,
+    pub view: MetricQueryView,
+    pub targets: Vec<String>,
+    pub range: RangeQuery,
+    pub group_by: Option<MetricQueryGroupBy>,
+    pub aggregation: MetricQueryAggregation,
+}
+
+impl MetricQuery {
+    pub fn from_request(req: &MetricQueryRequest, targets: Vec<String>) -> Self {
+        Self {
+            scope: req.scope,
+            view: req.view,
+            targets,
+            range: req.range_query(),
+            group_by: req.group_by,
+            aggregation: req.aggregation,
+        }
+    }
+
+    /// Rejects combinations no scope can answer, before any data is read.
+    pub fn validate(&self) -> Result<()> {
+        let Some(group_by) = self.group_by else {
+            return Ok(());
+        };
+        if !self.view.is_series() {
+            return Err(anyhow!("groupBy only applies to the raw and cost views"));
+        }
+        if group_by != MetricQueryGroupBy::All && !scope_views(self.scope).per_pod {
+            return Err(anyhow!("groupBy {:?} needs the pod or container scope", group_by));
+        }
+        Ok(())
+    }
+}
+
+type ViewFn = for<'a> fn(&'a MetricService, RangeQuery, Vec<String>) -> BoxFuture<'a, Result<Value>>;
+
+/// List methods of one scope, each taking the range and the target keys.
+pub struct ScopeViews {
+    /// Series are pods or containers, so pod attributes can group them.
+    pub per_pod: bool,
+    raw: ViewFn,
+    raw_summary: ViewFn,
+    raw_efficiency: ViewFn,
+    cost: ViewFn,
+    cost_summary: ViewFn,
+    cost_trend: ViewFn,
+}
+
+impl ScopeViews {
+    fn get(&self, view: MetricQueryView) -> ViewFn {
+        match view {
+            MetricQueryView::Raw => self.raw,
+            MetricQueryView::RawSummary => self.raw_summary,
+            MetricQueryView::RawEfficiency => self.raw_efficiency,
+            MetricQueryView::Cost => self.cost,
+            MetricQueryView::CostSummary => self.cost_summary,
+            MetricQueryView::CostTrend => self.cost_trend,
+        }
+    }
+}
+
+/// Wraps a `MetricService` list method as a [`ViewFn`].
+macro_rules! view_fn {
+    ($method:ident) => {{
+        fn call(svc: &MetricService, q: RangeQuery, targets: Vec<String>) -> BoxFuture<'_, Result<Value>> {
+            Box::pin(svc.$method(q, targets))
+        }
+        call
+    }};
+}
+
+macro_rules! scope_views {
+    (per_pod: $per_pod:expr,
+     $raw:ident, $raw_summary:ident, $raw_efficiency:ident, $cost:ident, $cost_summary:ident, $cost_trend:ident) => {
+        ScopeViews {
+            per_pod: $per_pod,
+            raw: view_fn!($raw),
+            raw_summary: view_fn!($raw_summary),
+            raw_efficiency: view_fn!($raw_efficiency),
+            cost: view_fn!($cost),
+            cost_summary: view_fn!($cost_summary),
+            cost_trend: view_fn!($cost_trend),
+        }
+    };
+}
+
+pub fn scope_views(scope: MetricQueryScope) -> ScopeViews {
+    match scope {
+        MetricQueryScope::Cluster => scope_views!(per_pod: false,
+            get_metric_k8s_cluster_raw, get_metric_k8s_cluster_raw_summary,
+            get_metric_k8s_cluster_raw_efficiency, get_metric_k8s_cluster_cost,
+            get_metric_k8s_cluster_cost_summary, get_metric_k8s_cluster_cost_trend),
+        MetricQueryScope::Node => scope_views!(per_pod: false,
+            get_metric_k8s_nodes_raw, get_metric_k8s_nodes_raw_summary,
+            get_metric_k8s_nodes_raw_efficiency, get_metric_k8s_nodes_cost,
+            get_metric_k8s_nodes_cost_summary, get_metric_k8s_nodes_cost_trend),
+        MetricQueryScope::Namespace => scope_views!(per_pod: false,
+            get_metric_k8s_namespaces_raw, get_metric_k8s_namespaces_raw_summary,
+            get_metric_k8s_namespaces_raw_efficiency, get_metric_k8s_namespaces_cost,
+            get_metric_k8s_namespaces_cost_summary, get_metric_k8s_namespaces_cost_trend),
+        MetricQueryScope::Deployment => scope_views!(per_pod: false,
+            get_metric_k8s_deployments_raw, get_metric_k8s_deployments_raw_summary,
+            get_metric_k8s_deployments_raw_efficiency, get_metric_k8s_deployments_cost,
+            get_metric_k8s_deployments_cost_summary, get_metric_k8s_deployments_cost_trend),
+        MetricQueryScope::Pod => scope_views!(per_pod: true,
+            get_metric_k8s_pods_raw, get_metric_k8s_pods_raw_summary,
+            get_metric_k8s_pods_raw_efficiency, get_metric_k8s_pods_cost,
+            get_metric_k8s_pods_cost_summary, get_metric_k8s_pods_cost_trend),
+        MetricQueryScope::Container => scope_views!(per_pod: true,
+            get_metric_k8s_containers_raw, get_metric_k8s_containers_raw_summary,
+            get_metric_k8s_containers_raw_efficiency, get_metric_k8s_containers_cost,
+            get_metric_k8s_containers_cost_summary, get_metric_k8s_containers_cost_trend),
+    }
+}
+
+/// Runs `query` against the scope's list view, then applies `group_by`.
+pub async fn run(svc: &MetricService, query: MetricQuery) -> Result<Value> {
+    query.validate()?;
+
+    let view = scope_views(query.scope).get(query.view);
+    let value = view(svc, query.range, query.targets).await?;
+
+    match query.group_by {
+        Some(group_by) => group_series(value, query.scope, group_by, query.aggregation).await,
+        None => Ok(value),
+    }
+}
+
+/// Cost summary of `targets` for the query window next to the `cmp` baseline.
+pub async fn cost_compare(
+    svc: &MetricService,
+    scope: MetricQueryScope,
+    q: RangeQuery,
+    cmp: CostCompareQuery,
+    targets: Vec<String>,
+) -> Result<Value> {
+    let cost_summary = scope_views(scope).cost_summary;
+    build_cost_compare_value(q, cmp, |q| cost_summary(svc, q, targets.clone())).await
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(scope: MetricQueryScope, view: MetricQueryView, group_by: Option<MetricQueryGroupBy>) -> MetricQuery {
+        let req: MetricQueryRequest = serde_json::from_value(serde_json::json!({ "scope": "pod" })).unwrap();
+        MetricQuery { scope, view, group_by, ..MetricQuery::from_request(&req, Vec::new()) }
+    }
+
+    #[test]
+    fn group_by_needs_a_series_view_and_pod_attributes() {
+        use MetricQueryGroupBy as G;
+        use MetricQueryScope as S;
+        use MetricQueryView as V;
+
+        assert!(query(S::Pod, V::Cost, Some(G::Team)).validate().is_ok());
+        assert!(query(S::Container, V::Raw, Some(G::Namespace)).validate().is_ok());
+        assert!(query(S::Node, V::Raw, Some(G::All)).validate().is_ok());
+        assert!(query(S::Node, V::Raw, Some(G::Team)).validate().is_err());
+        assert!(query(S::Pod, V::CostSummary, Some(G::Team)).validate().is_err());
+        assert!(query(S::Namespace, V::CostSummary, None).validate().is_ok());
+    }
+}
@@ -0,0 +1,295 @@
+use anyhow::{anyhow, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::api::dto::metrics_query_dto::{MetricQueryAggregation, MetricQueryGroupBy, MetricQueryScope};
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::repositories::repositories;
+use crate::domain::metric::k8s::common::dto::{
+    CostMetricDto, MetricGetResponseDto, MetricSeriesDto, UniversalMetricPointDto,
+};
+use crate::domain::metric::k8s::common::service_helpers::sum_running_hours;
+
+/// Group key for objects without a value for the dimension (untagged spend).
+/// Always present when grouping by a dimension, so groups add up to the total.
+pub const UNALLOCATED: &str = "__unallocated__";
+
+/// Group key of `group_by = all`.
+const ALL: &str = "all";
+
+/// Merges the series of a `raw`/`cost` response into one series per group,
+/// combining points at the same timestamp with `agg`.
+pub async fn group_series(
+    response: Value,
+    scope: MetricQueryScope,
+    group_by: MetricQueryGroupBy,
+    agg: MetricQueryAggregation,
+) -> Result<Value> {
+    // Services answer `{"status": "no data"}` when nothing matched
+    let Ok(mut response) = serde_json::from_value::<MetricGetResponseDto>(response.clone()) else {
+        return Ok(response);
+    };
+
+    let keys = group_keys(&response.series, scope, group_by).await?;
+    let template = response.series.first().map(empty_like);
+    let mut groups: BTreeMap<String, Vec<MetricSeriesDto>> = BTreeMap::new();
+    for series in std::mem::take(&mut response.series) {
+        let key = keys.get(&series.key).cloned().unwrap_or_else(|| UNALLOCATED.to_string());
+        groups.entry(key).or_default().push(series);
+    }
+
+    let mut merged = Vec::with_capacity(groups.len() + 1);
+    if group_by != MetricQueryGroupBy::All && !groups.contains_key(UNALLOCATED) {
+        if let Some(template) = template {
+            merged.push(MetricSeriesDto {
+                key: UNALLOCATED.to_string(),
+                name: UNALLOCATED.to_string(),
+                ..template
+            });
+        }
+    }
+    for (key, members) in groups {
+        merged.push(merge_group(key, members, agg)?);
+    }
+    merged.sort_by(|a, b| a.key.cmp(&b.key));
+    response.series = merged;
+    response.target = None;
+    response.total = None;
+    response.limit = None;
+    response.offset = None;
+    response.next_cursor = None;
+
+    Ok(serde_json::to_value(response)?)
+}
+
+/// Group key per series key.
+async fn group_keys(
+    series: &[MetricSeriesDto],
+    scope: MetricQueryScope,
+    group_by: MetricQueryGroupBy,
+) -> Result<HashMap<String, String>> {
+    if group_by == MetricQueryGroupBy::All {
+        return Ok(series.iter().map(|s| (s.key.clone(), ALL.to_string())).collect());
+    }
+
+    let repos = repositories();
+    let (pods, containers) = (repos.info_pods, repos.info_containers);
+    let mut keys = HashMap::new();
+    for s in series {
+        let pod = match scope {
+            MetricQueryScope::Pod => pods.read(&s.key).await.ok(),
+            MetricQueryScope::Container => match containers.read(&s.key).await.ok().and_then(|c| c.pod_uid) {
+                Some(uid) => pods.read(&uid).await.ok(),
+                None => None,
+            },
+            _ => return Err(anyhow!("groupBy {:?} needs the pod or container scope", group_by)),
+        };
+        if let Some(key) = pod.as_ref().and_then(|p| pod_group_key(p, group_by)) {
+            keys.insert(s.key.clone(), key);
+        }
+    }
+    Ok(keys)
+}
+
+fn pod_group_key(pod: &InfoPodEntity, group_by: MetricQueryGroupBy) -> Option<String> {
+    match group_by {
+        MetricQueryGroupBy::All => Some(ALL.to_string()),
+        MetricQueryGroupBy::Namespace => pod.namespace.clone(),
+        MetricQueryGroupBy::Node => pod.node_name.clone(),
+        MetricQueryGroupBy::Team => pod.team.clone(),
+        MetricQueryGroupBy::Service => pod.service.clone(),
+        MetricQueryGroupBy::Env => pod.env.clone(),
+        MetricQueryGroupBy::Workload => match (&pod.workload_kind, &pod.workload_name) {
+            (Some(kind), Some(name)) => Some(format!("{}/{}", kind, name)),
+            _ => None,
+        },
+    }
+    .filter(|k| !k.is_empty())
+}
+
+fn merge_group(key: String, members: Vec<MetricSeriesDto>, agg: MetricQueryAggregation) -> Result<MetricSeriesDto> {
+    let scope = members[0].scope.clone();
+    let running_hours = sum_running_hours(&members);
+
+    let mut by_time: BTreeMap<_, Vec<&UniversalMetricPointDto>> = BTreeMap::new();
+    for point in members.iter().flat_map(|s| s.points.iter()) {
+        by_time.entry(point.time).or_default().push(point);
+    }
+    let points = by_time
+        .into_values()
+        .map(|points| combine_typed(&points, agg))
+        .collect::<Result<_>>()?;
+
+    let summaries: Vec<_> = members.iter().filter_map(|s| s.cost_summary.as_ref()).collect();
+    let cost_summary = if summaries.is_empty() {
+        None
+    } else {
+        Some(combine_typed(&summaries, agg)?)
+    };
+
+    Ok(MetricSeriesDto {
+        name: key.clone(),
+        key,
+        scope,
+        points,
+        running_hours,
+        cost_summary,
+    })
+}
+
+/// A series with no spend, shaped like `series` (same scope, cost summary
+/// only if `series` has one).
+fn empty_like(series: &MetricSeriesDto) -> MetricSeriesDto {
+    let zero = Some(0.0);
+    MetricSeriesDto {
+        key: String::new(),
+        name: String::new(),
+        scope: series.scope.clone(),
+        points: Vec::new(),
+        running_hours: series.running_hours.map(|_| 0.0),
+        cost_summary: series.cost_summary.as_ref().map(|_| CostMetricDto {
+            total_cost_usd: zero,
+            cpu_cost_usd: zero,
+            memory_cost_usd: zero,
+            storage_cost_usd: zero,
+        }),
+    }
+}
+
+/// [`combine`] over typed values, through their JSON form so every numeric
+/// field (including ones added later) is aggregated without listing it here.
+fn combine_typed<T: Serialize + DeserializeOwned>(items: &[&T], agg: MetricQueryAggregation) -> Result<T> {
+    let values = items
+        .iter()
+        .map(|item| serde_json::to_value(item))
+        .collect::<serde_json::Result<Vec<_>>>()?;
+    let refs: Vec<&Value> = values.iter().collect();
+    Ok(serde_json::from_value(combine(&refs, agg))?)
+}
+
+/// Aggregates numbers field by field; nulls are skipped and any other value
+/// (timestamps, flags) is taken from the first item that has it.
+fn combine(values: &[&Value], agg: MetricQueryAggregation) -> Value {
+    let present: Vec<&Value> = values.iter().copied().filter(|v| !v.is_null()).collect();
+    match present.first() {
+        None => Value::Null,
+        Some(Value::Object(_)) => {
+            let mut keys: Vec<&String> = Vec::new();
+            for v in &present {
+                if let Value::Object(map) = v {
+                    for k in map.keys() {
+                        if !keys.contains(&k) {
+                            keys.push(k);
+                        }
+                    }
+                }
+            }
+            let mut out = Map::new();
+            for k in keys {
+                let children: Vec<&Value> = present.iter().filter_map(|v| v.get(k.as_str())).collect();
+                out.insert(k.clone(), combine(&children, agg));
+            }
+            Value::Object(out)
+        }
+        Some(Value::Number(_)) => {
+            let nums: Vec<f64> = present.iter().filter_map(|v| v.as_f64()).collect();
+            let n = match agg {
+                MetricQueryAggregation::Sum => nums.iter().sum(),
+                MetricQueryAggregation::Avg => nums.iter().sum::<f64>() / nums.len() as f64,
+                MetricQueryAggregation::Max => nums.iter().copied().fold(f64::MIN, f64::max),
+                MetricQueryAggregation::Min => nums.iter().copied().fold(f64::MAX, f64::min),
+            };
+            serde_json::Number::from_f64(n).map(Value::Number).unwrap_or(Value::Null)
+        }
+        Some(first) => (*first).clone(),
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    use crate::core::persistence::repositories::with_repositories;
+    use crate::test_support::fixtures::small_cluster;
+
+    fn cost_response(keys: &[&str]) -> Value {
+        json!({
+            "start": "2025-03-03T00:00:00Z",
+            "end": "2025-03-03T05:00:00Z",
+            "scope": "pod",
+            "target": null,
+            "granularity": "hour",
+            "series": keys.iter().map(|k| json!({
+                "key": k, "name": k, "scope": "pod", "points": [], "running_hours": 6.0,
+                "cost_summary": {
+                    "total_cost_usd": 1.5, "cpu_cost_usd": 1.0,
+                    "memory_cost_usd": 0.5, "storage_cost_usd": 0.0
+                }
+            })).collect::<Vec<_>>(),
+            "total": null, "limit": null, "offset": null,
+        })
+    }
+
+    #[tokio::test]
+    async fn untagged_spend_lands_in_an_always_present_unallocated_group() {
+        let mut cluster = small_cluster();
+        cluster.pods[0].team = Some("payments".into());
+        cluster.pods[2].team = Some("search".into());
+
+        let grouped = with_repositories(cluster.repositories(), async {
+            group_series(
+                cost_response(&["pod-1", "pod-2", "pod-3", "gone"]),
+                MetricQueryScope::Pod,
+                MetricQueryGroupBy::Team,
+                MetricQueryAggregation::Sum,
+            )
+            .await
+        })
+        .await
+        .unwrap();
+        let keys: Vec<&str> = grouped["series"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["key"].as_str().unwrap())
+            .collect();
+        assert_eq!(keys, [UNALLOCATED, "payments", "search"]);
+        assert_eq!(grouped["series"][0]["cost_summary"]["total_cost_usd"], 3.0);
+
+        let tagged = with_repositories(cluster.repositories(), async {
+            group_series(
+                cost_response(&["pod-1", "pod-3"]),
+                MetricQueryScope::Pod,
+                MetricQueryGroupBy::Team,
+                MetricQueryAggregation::Sum,
+            )
+            .await
+        })
+        .await
+        .unwrap();
+        let unallocated = &tagged["series"][0];
+        assert_eq!(unallocated["key"], UNALLOCATED);
+        assert_eq!(unallocated["cost_summary"]["total_cost_usd"], 0.0);
+        assert_eq!(tagged["series"].as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn combine_aggregates_numbers_and_skips_missing_fields() {
+        let a = json!({"time": "2024-01-01T00:00:00Z", "cpu": 1.0, "fs": {"used": 10.0}});
+        let b = json!({"time": "2024-01-01T00:00:00Z", "cpu": 3.0, "fs": null});
+
+        let sum = combine(&[&a, &b], MetricQueryAggregation::Sum);
+        assert_eq!(sum["time"], "2024-01-01T00:00:00Z");
+        assert_eq!(sum["cpu"], 4.0);
+        assert_eq!(sum["fs"]["used"], 10.0);
+
+        assert_eq!(combine(&[&a, &b], MetricQueryAggregation::Avg)["cpu"], 2.0);
+        assert_eq!(combine(&[&a, &b], MetricQueryAggregation::Max)["cpu"], 3.0);
+        assert_eq!(combine(&[&a, &b], MetricQueryAggregation::Min)["cpu"], 1.0);
+    }
+}
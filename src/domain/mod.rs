@@ -5,6 +5,7 @@
 //! - info: domain entities/services/usecases for static and k8s info
 //! - system: domain for system health/backup/etc.
 //! - common: shared domain types and services
+//! - export: daily cost allocation export to warehouses
 
 pub mod info;
 pub mod system;
@@ -12,3 +13,4 @@ pub mod common;
 pub mod metric;
 pub mod alert;
 pub mod llm;
+pub mod export;
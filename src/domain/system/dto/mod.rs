@@ -1,5 +1,6 @@
 //! System domain DTOs
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,3 +19,38 @@ pub struct BackupJobDto {
     pub state: String,
 }
 
+
+/// Why a metric directory is due for garbage collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricGcReason {
+    /// No info record exists for the object
+    NoInfo,
+    /// The object is deleted and nothing was written since the retention cutoff
+    Tombstoned,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricGcCandidateDto {
+    /// `pod`, `node` or `container`
+    pub scope: String,
+    pub key: String,
+    pub reason: MetricGcReason,
+    pub last_write_at: Option<DateTime<Utc>>,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricGcReportDto {
+    pub dry_run: bool,
+    pub generated_at: DateTime<Utc>,
+    /// Tombstones last written before this are collected; `None` when day
+    /// retention is disabled
+    pub tombstone_cutoff: Option<DateTime<Utc>>,
+    pub candidates: Vec<MetricGcCandidateDto>,
+    pub total_bytes: u64,
+    /// Directories actually removed (always 0 on a dry run)
+    pub removed: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
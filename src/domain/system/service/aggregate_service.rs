@@ -0,0 +1,159 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, DurationRound, NaiveTime, Utc};
+use serde_json::{json, Value};
+use tracing::{error, info};
+
+use crate::api::dto::system_dto::BackfillQuery;
+use crate::domain::export::cost_materialization_service::materialize_day;
+use crate::domain::metric::k8s::common::util::k8s_metric_query_cache::invalidate_all;
+use crate::scheduler::job_tracker::{record_failure, track_job};
+use crate::scheduler::tasks::processors::day::container::task::process_container_hour_to_day;
+use crate::scheduler::tasks::processors::day::node::task::process_node_hour_to_day;
+use crate::scheduler::tasks::processors::day::pod::task::process_pod_hour_to_day;
+use crate::scheduler::tasks::processors::hour::container::task::process_container_minute_to_hour;
+use crate::scheduler::tasks::processors::hour::node::task::process_node_minute_to_hour;
+use crate::scheduler::tasks::processors::hour::pod::task::process_pod_minute_to_hour;
+
+/// Upper bound on a single backfill request, to keep the call bounded.
+const MAX_BACKFILL_DAYS: i64 = 62;
+
+/// Re-runs minute→hour and hour→day aggregation for `[start, end)`.
+///
+/// Every hour and day window touching the range is recomputed; existing rows with
+/// the same timestamp are replaced, so the call is safe to repeat.
+pub async fn backfill_aggregates(q: BackfillQuery) -> Result<Value> {
+    let scopes: &[&str] = match q.scope.as_str() {
+        "pod" => &["pod"],
+        "node" => &["node"],
+        "container" => &["container"],
+        "all" => &["node", "pod", "container"],
+        other => return Err(anyhow!("Unsupported scope '{}' (expected pod, node, container or all)", other)),
+    };
+
+    let start = q.start.and_utc();
+    let end = q.end.and_utc();
+    if start >= end {
+        return Err(anyhow!("start must be before end"));
+    }
+    if end - start > Duration::days(MAX_BACKFILL_DAYS) {
+        return Err(anyhow!("Backfill range is limited to {} days", MAX_BACKFILL_DAYS));
+    }
+    if end > Utc::now() {
+        return Err(anyhow!("end must not be in the future"));
+    }
+
+    // 1️⃣ Hour windows first, so day aggregation reads the refreshed hours
+    let hour_ends = window_ends(start, end, Duration::hours(1))?;
+    // 2️⃣ Then every day touching the range
+    let day_ends = window_ends(start, end, Duration::days(1))?;
+
+    info!(
+        scope = %q.scope,
+        %start,
+        %end,
+        hours = hour_ends.len(),
+        days = day_ends.len(),
+        "Starting aggregate backfill"
+    );
+
+    let mut failed: Vec<String> = Vec::new();
+
+    // Recorded in the job history like the scheduled runs
+    track_job("backfill", async {
+        for scope in scopes {
+            for window_end in &hour_ends {
+                let res = match *scope {
+                    "pod" => process_pod_minute_to_hour(*window_end).await,
+                    "node" => process_node_minute_to_hour(*window_end).await,
+                    _ => process_container_minute_to_hour(*window_end).await,
+                };
+                if let Err(e) = res {
+                    error!("⚠️ Backfill {} hour ending {} failed: {}", scope, window_end, e);
+                    record_failure(scope, &e);
+                    failed.push(format!("{} hour {}", scope, window_end));
+                }
+            }
+
+            for window_end in &day_ends {
+                let res = match *scope {
+                    "pod" => process_pod_hour_to_day(*window_end).await,
+                    "node" => process_node_hour_to_day(*window_end).await,
+                    _ => process_container_hour_to_day(*window_end).await,
+                };
+                if let Err(e) = res {
+                    error!("⚠️ Backfill {} day ending {} failed: {}", scope, window_end, e);
+                    record_failure(scope, &e);
+                    failed.push(format!("{} day {}", scope, window_end));
+                }
+            }
+        }
+        Ok(())
+    })
+    .await?;
+
+    // Materialized days in the range were priced from the old rollups
+    if scopes.contains(&"pod") {
+        let today = Utc::now().date_naive();
+        let mut date = start.date_naive();
+        while date < today && date.and_time(NaiveTime::MIN).and_utc() < end {
+            if let Err(e) = materialize_day(date).await {
+                error!("⚠️ Backfill cost allocation {} failed: {}", date, e);
+                failed.push(format!("cost allocation {}", date));
+            }
+            date += Duration::days(1);
+        }
+    }
+
+    // Cached historical windows may cover the recomputed rollups
+    invalidate_all();
+
+    Ok(json!({
+        "scope": q.scope,
+        "start": start,
+        "end": end,
+        "hour_windows": hour_ends.len(),
+        "day_windows": day_ends.len(),
+        "failed": failed,
+    }))
+}
+
+/// End timestamps of every `step`-aligned window overlapping `[start, end)`.
+///
+/// The processors take the *end* of a window (they aggregate the period before it).
+fn window_ends(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: Duration,
+) -> Result<Vec<DateTime<Utc>>> {
+    let mut current = start.duration_trunc(step)? + step;
+    let mut ends = Vec::new();
+    while current - step < end {
+        ends.push(current);
+        current += step;
+    }
+    Ok(ends)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn window_ends_cover_partial_windows() {
+        let start = Utc.with_ymd_and_hms(2025, 2, 1, 10, 30, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 2, 1, 12, 0, 0).unwrap();
+
+        let hours = window_ends(start, end, Duration::hours(1)).unwrap();
+        assert_eq!(
+            hours,
+            vec![
+                Utc.with_ymd_and_hms(2025, 2, 1, 11, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 2, 1, 12, 0, 0).unwrap(),
+            ]
+        );
+
+        let days = window_ends(start, end, Duration::days(1)).unwrap();
+        assert_eq!(days, vec![Utc.with_ymd_and_hms(2025, 2, 2, 0, 0, 0).unwrap()]);
+    }
+}
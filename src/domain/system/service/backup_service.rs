@@ -1,7 +1,279 @@
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
+use axum::body::Bytes;
+use chrono::Utc;
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+use crate::domain::metric::k8s::common::util::k8s_metric_query_cache::invalidate_all;
+
+/// Top-level data directories included in a backup.
+const BACKUP_DIRS: [&str; 2] = ["info", "metric"];
+const MANIFEST_NAME: &str = "rustcost-backup.json";
+const BACKUP_FORMAT: &str = "rustcost-backup";
+const BACKUP_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupManifest {
+    format: String,
+    format_version: u32,
+    app_version: String,
+    created_at: String,
+}
 
 pub async fn backup() -> Result<Value> {
     Ok(json!({"backup": "scheduled"}))
 }
 
+/// File name suggested to clients downloading a backup.
+pub fn backup_file_name() -> String {
+    format!("rustcost-backup-{}.tar.zst", Utc::now().format("%Y%m%dT%H%M%SZ"))
+}
+
+/// Streams a `tar.zst` archive of the info and metric data.
+///
+/// The archive is produced on a blocking thread and handed over in chunks,
+/// so memory use stays flat regardless of the data size.
+pub fn backup_archive_stream() -> impl Stream<Item = io::Result<Bytes>> {
+    let (tx, rx) = mpsc::channel::<io::Result<Bytes>>(16);
+
+    tokio::task::spawn_blocking(move || {
+        let err_tx = tx.clone();
+        if let Err(e) = write_backup_archive(ChannelWriter { tx }) {
+            error!(error = %e, "Backup archive failed");
+            let _ = err_tx.blocking_send(Err(io::Error::other(e.to_string())));
+        }
+    });
+
+    futures::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|chunk| (chunk, rx))
+    })
+}
+
+/// Restores info and metric data from an uploaded `tar.zst` archive.
+///
+/// The upload is spooled to disk and fully unpacked into a staging directory;
+/// the manifest is validated before anything is touched. Live directories are
+/// then swapped with renames, keeping the previous copy until the swap succeeds.
+pub async fn restore<S, E>(mut body: S) -> Result<Value>
+where
+    S: Stream<Item = std::result::Result<Bytes, E>> + Unpin,
+    E: std::fmt::Display,
+{
+    let base = get_rustcost_base_path();
+    fs::create_dir_all(&base)?;
+
+    let stamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
+    let upload_path = base.join(format!(".restore-upload-{}.tar.zst", stamp));
+
+    // 1️⃣ Spool the upload to disk
+    let mut file = tokio::fs::File::create(&upload_path).await?;
+    let mut received: u64 = 0;
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(|e| anyhow!("Failed to read upload: {}", e))?;
+        received += chunk.len() as u64;
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+    drop(file);
+
+    // 2️⃣ Unpack, validate and swap on a blocking thread
+    let result = tokio::task::spawn_blocking({
+        let upload_path = upload_path.clone();
+        move || restore_from_file(&base, &upload_path, &stamp)
+    })
+    .await
+    .map_err(|e| anyhow!("Restore task panicked: {}", e))?;
+
+    if let Err(e) = fs::remove_file(&upload_path) {
+        warn!("Failed to remove restore upload {:?}: {}", upload_path, e);
+    }
+
+    let manifest = result?;
+    info!(bytes = received, created_at = %manifest.created_at, "Restored data from backup");
+    invalidate_all();
+
+    Ok(json!({
+        "restored": true,
+        "bytes": received,
+        "backup_created_at": manifest.created_at,
+        "backup_app_version": manifest.app_version,
+        // Open handles (SQLite store, cached settings) still point at the old data.
+        "restart_recommended": true,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// Archive writing
+// ---------------------------------------------------------------------------
+
+fn write_backup_archive<W: Write>(out: W) -> Result<()> {
+    let base = get_rustcost_base_path();
+
+    let encoder = zstd::stream::write::Encoder::new(BufWriter::with_capacity(64 * 1024, out), 3)?;
+    let mut builder = tar::Builder::new(encoder);
+    builder.follow_symlinks(false);
+
+    // Manifest goes first so restores can reject foreign archives early.
+    let manifest = BackupManifest {
+        format: BACKUP_FORMAT.into(),
+        format_version: BACKUP_FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").into(),
+        created_at: Utc::now().to_rfc3339(),
+    };
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest)?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest_bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(Utc::now().timestamp() as u64);
+    header.set_cksum();
+    builder.append_data(&mut header, MANIFEST_NAME, manifest_bytes.as_slice())?;
+
+    for dir in BACKUP_DIRS {
+        let path = base.join(dir);
+        if path.is_dir() {
+            builder.append_dir_all(dir, &path)?;
+        }
+    }
+
+    let encoder = builder.into_inner()?;
+    let mut buf = encoder.finish()?;
+    buf.flush()?;
+    Ok(())
+}
+
+/// Forwards written bytes to the response stream.
+struct ChannelWriter {
+    tx: mpsc::Sender<io::Result<Bytes>>,
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.tx
+            .blocking_send(Ok(Bytes::copy_from_slice(buf)))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "backup download cancelled"))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Restore
+// ---------------------------------------------------------------------------
+
+fn restore_from_file(base: &Path, upload: &Path, stamp: &str) -> Result<BackupManifest> {
+    let staging = base.join(format!(".restore-staging-{}", stamp));
+    let previous = base.join(format!(".restore-previous-{}", stamp));
+
+    let result = unpack_and_validate(upload, &staging)
+        .and_then(|manifest| swap_dirs(base, &staging, &previous).map(|_| manifest));
+
+    if staging.exists() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    result
+}
+
+fn unpack_and_validate(upload: &Path, staging: &Path) -> Result<BackupManifest> {
+    fs::create_dir_all(staging)?;
+
+    let decoder = zstd::stream::read::Decoder::new(File::open(upload)?)
+        .context("Archive is not zstd-compressed")?;
+    let mut archive = tar::Archive::new(decoder);
+
+    let mut manifest: Option<BackupManifest> = None;
+
+    for entry in archive.entries().context("Archive is not a valid tar stream")? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+
+        if manifest.is_none() {
+            if path != Path::new(MANIFEST_NAME) {
+                return Err(anyhow!("Archive does not start with {}", MANIFEST_NAME));
+            }
+            let mut raw = String::new();
+            entry.read_to_string(&mut raw)?;
+            let m: BackupManifest = serde_json::from_str(&raw).context("Invalid backup manifest")?;
+
+            if m.format != BACKUP_FORMAT {
+                return Err(anyhow!("Unsupported backup format '{}'", m.format));
+            }
+            if m.format_version > BACKUP_FORMAT_VERSION {
+                return Err(anyhow!(
+                    "Backup format version {} is newer than supported version {}",
+                    m.format_version,
+                    BACKUP_FORMAT_VERSION
+                ));
+            }
+            manifest = Some(m);
+            continue;
+        }
+
+        let top = path.components().next().and_then(|c| c.as_os_str().to_str());
+        if !matches!(top, Some(t) if BACKUP_DIRS.contains(&t)) {
+            return Err(anyhow!("Unexpected archive entry {:?}", path));
+        }
+
+        // `unpack_in` refuses entries escaping the staging directory.
+        if !entry.unpack_in(staging)? {
+            return Err(anyhow!("Refusing unsafe archive entry {:?}", path));
+        }
+    }
+
+    manifest.ok_or_else(|| anyhow!("Archive is empty"))
+}
+
+fn swap_dirs(base: &Path, staging: &Path, previous: &Path) -> Result<()> {
+    fs::create_dir_all(previous)?;
+    let mut moved: Vec<(PathBuf, PathBuf)> = Vec::new();
+
+    // Move current directories aside
+    for dir in BACKUP_DIRS {
+        let live = base.join(dir);
+        if live.exists() {
+            let aside = previous.join(dir);
+            if let Err(e) = fs::rename(&live, &aside) {
+                rollback(&moved);
+                return Err(anyhow!("Failed to move {:?} aside: {}", live, e));
+            }
+            moved.push((aside, live));
+        }
+    }
+
+    // Move restored directories in
+    for dir in BACKUP_DIRS {
+        let staged = staging.join(dir);
+        if staged.exists() {
+            if let Err(e) = fs::rename(&staged, base.join(dir)) {
+                for d in BACKUP_DIRS {
+                    let _ = fs::remove_dir_all(base.join(d));
+                }
+                rollback(&moved);
+                return Err(anyhow!("Failed to install restored {}: {}", dir, e));
+            }
+        }
+    }
+
+    if let Err(e) = fs::remove_dir_all(previous) {
+        warn!("Failed to remove pre-restore copy {:?}: {}", previous, e);
+    }
+    Ok(())
+}
+
+fn rollback(moved: &[(PathBuf, PathBuf)]) {
+    for (aside, live) in moved {
+        if let Err(e) = fs::rename(aside, live) {
+            error!("Failed to roll back {:?} -> {:?}: {}", aside, live, e);
+        }
+    }
+}
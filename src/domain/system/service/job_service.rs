@@ -0,0 +1,26 @@
+use anyhow::Result;
+
+use crate::api::dto::system_dto::JobHistoryQuery;
+use crate::core::persistence::jobs::job_history_repository::{
+    JobHistoryRepository, JobHistoryRepositoryImpl,
+};
+use crate::core::persistence::jobs::job_run_entity::JobRunEntity;
+
+const DEFAULT_LIMIT: usize = 50;
+
+/// Recent background job runs, newest first.
+pub async fn get_job_history(q: JobHistoryQuery) -> Result<Vec<JobRunEntity>> {
+    let repo = JobHistoryRepositoryImpl::new();
+    let limit = q.limit.unwrap_or(DEFAULT_LIMIT);
+
+    Ok(repo
+        .list()?
+        .into_iter()
+        .rev()
+        .filter(|run| match &q.job {
+            Some(job) => &run.job == job,
+            None => true,
+        })
+        .take(limit)
+        .collect())
+}
@@ -1,3 +1,4 @@
+use crate::core::persistence::logs::log_filter::LogLineFilter;
 use crate::core::persistence::logs::log_repository::LogRepository;
 use crate::api::dto::system_dto::PaginatedLogResponse;
 
@@ -21,6 +22,7 @@ impl<R: LogRepository> LogService<R> {
         date: &str,
         cursor: Option<usize>,
         limit: Option<usize>,
+        filter: LogLineFilter,
     ) -> anyhow::Result<PaginatedLogResponse> {
 
         let cursor = cursor.unwrap_or(0);
@@ -28,7 +30,7 @@ impl<R: LogRepository> LogService<R> {
 
         let (lines, next_cursor) = self
             .repo
-            .get_system_log_lines(date, cursor, limit)
+            .get_system_log_lines(date, cursor, limit, filter)
             .await?;
 
         Ok(PaginatedLogResponse {
@@ -0,0 +1,225 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{error, info};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
+use crate::core::persistence::info::k8s::container::info_container_api_repository_trait::InfoContainerApiRepository;
+use crate::core::persistence::info::k8s::container::info_container_repository::InfoContainerRepository;
+use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
+use crate::core::persistence::info::k8s::node::info_node_repository::InfoNodeRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
+use crate::core::persistence::info::path::{
+    info_k8s_container_dir_path, info_k8s_container_file_path, info_k8s_node_dir_path,
+    info_k8s_node_file_path, info_k8s_pod_dir_path, info_k8s_pod_file_path,
+};
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_dir_path, metric_k8s_node_dir_path, metric_k8s_pod_dir_path,
+};
+use crate::core::persistence::metrics::metric_storage_backend::{metric_storage_backend, MetricStorageBackend};
+use crate::domain::system::dto::{MetricGcCandidateDto, MetricGcReason, MetricGcReportDto};
+use crate::scheduler::tasks::processors::retention::task::RetentionCutoffs;
+
+/// A directory without an info record is left alone this long, so objects
+/// whose first metrics land before their info record aren't collected.
+const ORPHAN_GRACE_DAYS: i64 = 7;
+
+/// Info state of the object a metric directory belongs to.
+enum InfoState {
+    Missing,
+    Live,
+    Tombstoned,
+}
+
+/// What the collector would remove right now, without touching anything.
+pub async fn get_metric_gc_report() -> Result<Value> {
+    Ok(serde_json::to_value(collect_metric_garbage(Utc::now(), true)?)?)
+}
+
+/// Nightly run: removes the directories the report lists.
+pub async fn run_metric_gc(now: DateTime<Utc>) -> Result<()> {
+    let report = collect_metric_garbage(now, false)?;
+    info!(
+        removed = report.removed,
+        candidates = report.candidates.len(),
+        bytes = report.total_bytes,
+        "Metric directory GC finished"
+    );
+    Ok(())
+}
+
+/// Cross-references metric directories with info records.
+///
+/// A directory is collected when its object has no info record and nothing
+/// was written for `ORPHAN_GRACE_DAYS`, or when the object is deleted and its
+/// last write is older than the day-retention cutoff — every row in it would
+/// be past retention anyway. Month rollups of such objects go with them.
+fn collect_metric_garbage(now: DateTime<Utc>, dry_run: bool) -> Result<MetricGcReportDto> {
+    let settings = InfoSettingFsAdapter::new().read()?;
+    let tombstone_cutoff = RetentionCutoffs::from_settings(&settings, now).day_before;
+
+    let mut report = MetricGcReportDto {
+        dry_run,
+        generated_at: now,
+        tombstone_cutoff,
+        candidates: Vec::new(),
+        total_bytes: 0,
+        removed: 0,
+        note: None,
+    };
+
+    if metric_storage_backend() != MetricStorageBackend::Fs {
+        report.note = Some("metric storage backend keeps no per-object directories".to_string());
+        return Ok(report);
+    }
+
+    let orphan_before = now - Duration::days(ORPHAN_GRACE_DAYS);
+    let scopes: [(&str, PathBuf, PathBuf, fn(&str) -> InfoState); 3] = [
+        ("pod", metric_k8s_pod_dir_path(), info_k8s_pod_dir_path(), pod_state),
+        ("node", metric_k8s_node_dir_path(), info_k8s_node_dir_path(), node_state),
+        ("container", metric_k8s_container_dir_path(), info_k8s_container_dir_path(), container_state),
+    ];
+
+    for (scope, metric_dir, info_dir, state_of) in scopes {
+        // With no info records at all (fresh restore, wiped info dir) every
+        // directory would look orphaned; don't treat that as garbage.
+        let has_info = dir_has_entries(&info_dir);
+
+        for (key, path) in sub_dirs(&metric_dir)? {
+            let (last_write, bytes) = dir_usage(&path);
+            let written_before = |cutoff: DateTime<Utc>| last_write.is_none_or(|t| t < cutoff);
+
+            let reason = match state_of(&key) {
+                InfoState::Missing if has_info && written_before(orphan_before) => MetricGcReason::NoInfo,
+                InfoState::Tombstoned if tombstone_cutoff.is_some_and(written_before) => MetricGcReason::Tombstoned,
+                _ => continue,
+            };
+
+            if !dry_run {
+                match fs::remove_dir_all(&path) {
+                    Ok(()) => report.removed += 1,
+                    Err(e) => {
+                        error!("⚠️ Failed to remove metric directory {:?}: {}", path, e);
+                        continue;
+                    }
+                }
+            }
+
+            report.total_bytes += bytes;
+            report.candidates.push(MetricGcCandidateDto {
+                scope: scope.to_string(),
+                key,
+                reason,
+                last_write_at: last_write,
+                bytes,
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+fn pod_state(uid: &str) -> InfoState {
+    if !info_k8s_pod_file_path(uid).exists() {
+        return InfoState::Missing;
+    }
+    match InfoPodRepository::new().read(uid) {
+        Ok(p) if p.deleted == Some(true) || p.deleted_at.is_some() => InfoState::Tombstoned,
+        _ => InfoState::Live,
+    }
+}
+
+fn node_state(name: &str) -> InfoState {
+    if !info_k8s_node_file_path(name).exists() {
+        return InfoState::Missing;
+    }
+    match InfoNodeRepository::new().read(name) {
+        Ok(n) if n.deleted == Some(true) || n.deleted_at.is_some() => InfoState::Tombstoned,
+        _ => InfoState::Live,
+    }
+}
+
+fn container_state(key: &str) -> InfoState {
+    if !info_k8s_container_file_path(key).exists() {
+        return InfoState::Missing;
+    }
+    match InfoContainerRepository::new().read(key) {
+        Ok(c) if c.deleted == Some(true) => InfoState::Tombstoned,
+        _ => InfoState::Live,
+    }
+}
+
+fn dir_has_entries(dir: &Path) -> bool {
+    fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_some())
+}
+
+fn sub_dirs(dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let mut out = Vec::new();
+    if !dir.exists() {
+        return Ok(out);
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if let Some(key) = path.file_name().and_then(|n| n.to_str()).map(str::to_string) {
+            out.push((key, path));
+        }
+    }
+    Ok(out)
+}
+
+/// Newest file modification time and total size below `dir`.
+fn dir_usage(dir: &Path) -> (Option<DateTime<Utc>>, u64) {
+    let mut newest: Option<DateTime<Utc>> = None;
+    let mut bytes = 0;
+    let mut stack = vec![dir.to_path_buf()];
+
+    while let Some(current) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&current) else { continue };
+        for entry in entries.flatten() {
+            let Ok(meta) = entry.metadata() else { continue };
+            if meta.is_dir() {
+                stack.push(entry.path());
+                continue;
+            }
+            bytes += meta.len();
+            if let Ok(modified) = meta.modified() {
+                let modified = DateTime::<Utc>::from(modified);
+                newest = Some(newest.map_or(modified, |n| n.max(modified)));
+            }
+        }
+    }
+
+    (newest, bytes)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dir_usage_walks_granularity_subdirectories() {
+        let dir = std::env::temp_dir().join("rustcost_metric_gc_test/pod-a");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("h")).unwrap();
+        fs::create_dir_all(dir.join("d")).unwrap();
+        fs::write(dir.join("h/2025-01.rcd"), [0u8; 10]).unwrap();
+        fs::write(dir.join("d/2025.rcd"), [0u8; 5]).unwrap();
+
+        let (newest, bytes) = dir_usage(&dir);
+        assert_eq!(bytes, 15);
+        assert!(newest.is_some_and(|t| t > Utc::now() - Duration::minutes(5)));
+
+        let (_, empty) = dir_usage(&dir.join("missing"));
+        assert_eq!(empty, 0);
+        assert!(!dir_has_entries(&dir.join("missing")));
+    }
+}
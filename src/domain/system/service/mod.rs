@@ -5,4 +5,8 @@ pub mod health_service;
 pub mod backup_service;
 pub mod resync_service;
 pub mod log_service;
+pub mod aggregate_service;
+pub mod job_service;
+pub mod metric_gc_service;
+pub mod simulate_service;
 
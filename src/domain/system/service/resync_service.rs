@@ -6,6 +6,7 @@ use kube::api::{Api, ListParams};
 use serde_json::{json, Value};
 use tokio::time::sleep;
 use tracing::{error};
+use crate::core::persistence::repositories::{repositories, with_repositories};
 use crate::core::state::runtime::k8s::k8s_runtime_state_manager::K8sRuntimeStateManager;
 use crate::core::state::runtime::k8s::k8s_runtime_state_repository::K8sRuntimeStateRepository;
 use crate::scheduler::tasks::info::k8s_refresh::task::refresh_k8s_object_info;
@@ -32,6 +33,9 @@ pub async fn resync(
 }
 
 /// Kick off a background refresh of the Kubernetes runtime state.
+///
+/// The `k8s_watch` task already applies pod/node/deployment changes as they
+/// happen; this forces a full reconcile on demand.
 pub async fn do_resync(
     k8s_state: Arc<K8sRuntimeStateManager<K8sRuntimeStateRepository>>,
 ) -> Result<Value> {
@@ -43,7 +47,7 @@ pub async fn do_resync(
 
     let mgr = k8s_state.clone();
 
-    tokio::spawn(async move {
+    tokio::spawn(with_repositories(repositories(), async move {
         if let Err(e) = refresh_k8s_object_info(&mgr).await {
             error!("K8s resync failed: {e}");
         }
@@ -51,7 +55,7 @@ pub async fn do_resync(
         sleep(Duration::from_secs(10)).await;
         // Mark as finished
         mgr.is_resyncing.store(false, Ordering::SeqCst);
-    });
+    }));
 
     Ok(json!({ "resync": "started" }))
 }
\ No newline at end of file
@@ -0,0 +1,337 @@
+use std::f64::consts::PI;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Datelike, Duration, DurationRound, Timelike, Utc, Weekday};
+use serde_json::{json, Value};
+use tracing::info;
+
+use crate::api::dto::system_dto::SimulateSeedQuery;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::node::info_node_fs_adapter::InfoNodeFsAdapter;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_fs_adapter::InfoPodFsAdapter;
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::{
+    metric_node_day_adapter, metric_node_hour_adapter, metric_pod_day_adapter, metric_pod_hour_adapter,
+};
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::domain::metric::k8s::common::util::k8s_metric_query_cache::invalidate_all;
+
+const MAX_NODES: u32 = 200;
+const MAX_PODS: u32 = 5000;
+const MAX_DAYS: u32 = 31;
+
+const GIB: u64 = 1024 * 1024 * 1024;
+const MIB: u64 = 1024 * 1024;
+const NANO: u64 = 1_000_000_000;
+
+/// `(cpu cores, memory GiB)` node shapes picked per node.
+const NODE_SHAPES: &[(u32, u64)] = &[(4, 16), (8, 32), (16, 64)];
+
+/// `(namespace, team, deployments)` the synthetic pods are spread over.
+const WORKLOADS: &[(&str, &str, &[&str])] = &[
+    ("payments", "checkout", &["api", "ledger"]),
+    ("search", "discovery", &["indexer", "query"]),
+    ("analytics", "data", &["etl", "dashboard"]),
+    ("platform", "infra", &["gateway", "auth"]),
+];
+
+/// Writes a synthetic cluster into the metric store: `sim-node-*` nodes and
+/// `sim-pod-*` pods with hourly rows following a diurnal cycle, rolled up to days.
+///
+/// Usage peaks mid-afternoon UTC and drops on weekends. Info records and hour/day
+/// rows are overwritten on repeat, so seeding again with the same parameters is
+/// idempotent apart from the moving time window.
+pub async fn seed_simulation(q: SimulateSeedQuery) -> Result<Value> {
+    let node_count = q.nodes.unwrap_or(3);
+    let pod_count = q.pods.unwrap_or(30);
+    let days = q.days.unwrap_or(7);
+    let seed = q.seed.unwrap_or(42);
+
+    if node_count == 0 || node_count > MAX_NODES {
+        return Err(anyhow!("nodes must be between 1 and {}", MAX_NODES));
+    }
+    if pod_count > MAX_PODS {
+        return Err(anyhow!("pods must be at most {}", MAX_PODS));
+    }
+    if days == 0 || days > MAX_DAYS {
+        return Err(anyhow!("days must be between 1 and {}", MAX_DAYS));
+    }
+
+    let now = Utc::now();
+    let end = now.duration_trunc(Duration::hours(1))?;
+    let start = end - Duration::days(days as i64);
+    let cluster = SimCluster::generate(node_count, pod_count, seed, start);
+
+    info!(nodes = node_count, pods = pod_count, days, %start, %end, "Seeding simulated cluster");
+
+    // 1️⃣ Info records, so list endpoints and attribution see the objects
+    for node in &cluster.nodes {
+        InfoNodeFsAdapter.update(&node.info)?;
+    }
+    for pod in &cluster.pods {
+        InfoPodFsAdapter.update(&pod.info)?;
+    }
+
+    // 2️⃣ Hourly rows; counters accumulate from the start of the window
+    let node_hours = metric_node_hour_adapter();
+    let pod_hours = metric_pod_hour_adapter();
+    let mut node_cpu_total = vec![0u64; cluster.nodes.len()];
+    let mut pod_cpu_total = vec![0u64; cluster.pods.len()];
+    let mut hours = 0usize;
+
+    let mut t = start;
+    while t < end {
+        let load = diurnal_load(t);
+        let h = hours as u64;
+        let mut node_cpu = vec![0u64; cluster.nodes.len()];
+        let mut node_memory = vec![0u64; cluster.nodes.len()];
+
+        for (j, pod) in cluster.pods.iter().enumerate() {
+            let jitter = 0.9 + 0.2 * unit(seed, j as u64, h);
+            let cpu = (pod.cpu_nano_cores as f64 * load * jitter) as u64;
+            // Memory follows load loosely: a resident floor plus a load-driven part
+            let working_set = (pod.memory_bytes as f64 * (0.6 + 0.4 * load * jitter)) as u64;
+            pod_cpu_total[j] += cpu * 3600;
+            node_cpu[pod.node] += cpu;
+            node_memory[pod.node] += working_set;
+
+            let row = MetricPodEntity {
+                time: t,
+                cpu_usage_nano_cores: Some(cpu),
+                cpu_usage_core_nano_seconds: Some(pod_cpu_total[j]),
+                memory_usage_bytes: Some(working_set + 8 * MIB),
+                memory_working_set_bytes: Some(working_set),
+                memory_rss_bytes: Some(working_set * 3 / 4),
+                memory_page_faults: Some(100 * (h + 1)),
+                network_physical_rx_bytes: Some((h + 1) * pod.network_bytes_per_hour),
+                network_physical_tx_bytes: Some((h + 1) * pod.network_bytes_per_hour / 3),
+                network_physical_rx_errors: Some(0),
+                network_physical_tx_errors: Some(0),
+                es_used_bytes: Some(pod.memory_bytes / 4),
+                es_capacity_bytes: Some(20 * GIB),
+                es_inodes_used: Some(1_000 + 10 * (j as u64 % 50)),
+                es_inodes: Some(1_000_000),
+                ..Default::default()
+            };
+            pod_hours.append_row(pod.uid(), &row, now)?;
+        }
+
+        for (i, node) in cluster.nodes.iter().enumerate() {
+            // System daemons take ~5% of the node on top of the pods
+            let cores = node.info.cpu_capacity_cores.unwrap_or_default() as u64;
+            let memory = node.info.memory_capacity_bytes.unwrap_or_default();
+            let cpu = (node_cpu[i] + cores * NANO / 20).min(cores * NANO);
+            let working_set = (node_memory[i] + memory / 20).min(memory);
+            node_cpu_total[i] += cpu * 3600;
+
+            let row = MetricNodeEntity {
+                time: t,
+                cpu_usage_nano_cores: Some(cpu),
+                cpu_usage_core_nano_seconds: Some(node_cpu_total[i]),
+                memory_usage_bytes: Some((working_set + 512 * MIB).min(memory)),
+                memory_working_set_bytes: Some(working_set),
+                memory_rss_bytes: Some(working_set * 3 / 4),
+                memory_page_faults: Some(1_000 * (h + 1)),
+                network_physical_rx_bytes: Some((h + 1) * (i as u64 + 1) * 200 * MIB),
+                network_physical_tx_bytes: Some((h + 1) * (i as u64 + 1) * 80 * MIB),
+                network_physical_rx_errors: Some(0),
+                network_physical_tx_errors: Some(0),
+                fs_used_bytes: Some(30 * GIB + (h % 24) * 100 * MIB),
+                fs_capacity_bytes: node.info.ephemeral_storage_capacity_bytes,
+                fs_inodes_used: Some(200_000 + 100 * (h % 24)),
+                fs_inodes: Some(6_000_000),
+                cpu_allocatable_nano_cores: Some(cores * NANO),
+                memory_allocatable_bytes: node.info.memory_allocatable_bytes,
+                ..Default::default()
+            };
+            node_hours.append_row(node.name(), &row, now)?;
+        }
+
+        hours += 1;
+        t += Duration::hours(1);
+    }
+
+    // 3️⃣ Day rollups for every full day in the window
+    let node_days = metric_node_day_adapter();
+    let pod_days = metric_pod_day_adapter();
+    let mut day_start = start.duration_trunc(Duration::days(1))?;
+    let mut day_count = 0usize;
+    while day_start + Duration::days(1) <= end {
+        let day_end = day_start + Duration::days(1);
+        for node in &cluster.nodes {
+            node_days.append_row_aggregated(node.name(), day_start, day_end, now)?;
+        }
+        for pod in &cluster.pods {
+            pod_days.append_row_aggregated(pod.uid(), day_start, day_end, now)?;
+        }
+        day_count += 1;
+        day_start = day_end;
+    }
+
+    // Cached windows may predate the seeded rows
+    invalidate_all();
+
+    Ok(json!({
+        "nodes": cluster.nodes.iter().map(|n| n.name()).collect::<Vec<_>>(),
+        "pods": cluster.pods.len(),
+        "start": start,
+        "end": end,
+        "hour_rows": hours * (cluster.nodes.len() + cluster.pods.len()),
+        "day_windows": day_count,
+    }))
+}
+
+struct SimNode {
+    info: InfoNodeEntity,
+}
+
+impl SimNode {
+    fn name(&self) -> &str {
+        self.info.node_name.as_deref().unwrap_or_default()
+    }
+}
+
+struct SimPod {
+    info: InfoPodEntity,
+    /// Index into [`SimCluster::nodes`]
+    node: usize,
+    /// Usage at peak load, before jitter
+    cpu_nano_cores: u64,
+    memory_bytes: u64,
+    network_bytes_per_hour: u64,
+}
+
+impl SimPod {
+    fn uid(&self) -> &str {
+        self.info.pod_uid.as_deref().unwrap_or_default()
+    }
+}
+
+struct SimCluster {
+    nodes: Vec<SimNode>,
+    pods: Vec<SimPod>,
+}
+
+impl SimCluster {
+    /// Sizes come from `seed`; pods are placed round-robin over the nodes.
+    fn generate(node_count: u32, pod_count: u32, seed: u64, start: DateTime<Utc>) -> Self {
+        let nodes = (0..node_count as u64)
+            .map(|i| {
+                let (cores, memory_gib) = NODE_SHAPES[(mix(seed ^ i) % NODE_SHAPES.len() as u64) as usize];
+                let name = format!("sim-node-{}", i + 1);
+                SimNode {
+                    info: InfoNodeEntity {
+                        node_uid: Some(format!("sim-uid-{}", name)),
+                        node_name: Some(name),
+                        creation_timestamp: Some(start - Duration::days(30)),
+                        cpu_capacity_cores: Some(cores),
+                        memory_capacity_bytes: Some(memory_gib * GIB),
+                        ephemeral_storage_capacity_bytes: Some(100 * GIB),
+                        cpu_allocatable_cores: Some(cores),
+                        memory_allocatable_bytes: Some(memory_gib * GIB),
+                        ready: Some(true),
+                        operating_system: Some("linux".to_string()),
+                        ..Default::default()
+                    },
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let pods = (0..pod_count as u64)
+            .map(|j| {
+                let (namespace, team, deployments) = WORKLOADS[(j % WORKLOADS.len() as u64) as usize];
+                let deployment = deployments[((j / WORKLOADS.len() as u64) % deployments.len() as u64) as usize];
+                let node = (j % node_count as u64) as usize;
+                let uid = format!("sim-pod-{}", j + 1);
+                let replica_set = format!("{}-{:05x}", deployment, mix(seed ^ deployment.len() as u64) & 0xfffff);
+
+                SimPod {
+                    info: InfoPodEntity {
+                        pod_name: Some(format!("{}-{}", replica_set, j + 1)),
+                        namespace: Some(namespace.to_string()),
+                        pod_uid: Some(uid),
+                        creation_timestamp: Some(start - Duration::days(1)),
+                        start_time: Some(start - Duration::days(1)),
+                        node_name: nodes[node].info.node_name.clone(),
+                        phase: Some("Running".to_string()),
+                        ready: Some(true),
+                        owner_kind: Some("ReplicaSet".to_string()),
+                        owner_name: Some(replica_set),
+                        workload_kind: Some("Deployment".to_string()),
+                        workload_name: Some(deployment.to_string()),
+                        container_count: Some(1),
+                        team: Some(team.to_string()),
+                        service: Some(deployment.to_string()),
+                        env: Some("prod".to_string()),
+                        label: Some(format!("app={},team={}", deployment, team)),
+                        ..Default::default()
+                    },
+                    node,
+                    // 50m–1000m CPU, 128–2048 MiB at peak
+                    cpu_nano_cores: 50_000_000 + mix(seed ^ (j << 8)) % 950_000_000,
+                    memory_bytes: (128 + mix(seed ^ (j << 16)) % 1920) * MIB,
+                    network_bytes_per_hour: (1 + mix(seed ^ (j << 24)) % 50) * MIB,
+                }
+            })
+            .collect();
+
+        Self { nodes, pods }
+    }
+}
+
+/// Load factor in `(0, 1]`: lowest around 02:00 UTC, highest around 14:00,
+/// with weekends at 60%.
+fn diurnal_load(t: DateTime<Utc>) -> f64 {
+    let hour = t.hour() as f64 + t.minute() as f64 / 60.0;
+    let daily = 0.55 + 0.45 * ((hour - 14.0) / 24.0 * 2.0 * PI).cos();
+    match t.weekday() {
+        Weekday::Sat | Weekday::Sun => daily * 0.6,
+        _ => daily,
+    }
+}
+
+/// Deterministic value in `[0, 1)` for `(seed, object, hour)`.
+fn unit(seed: u64, object: u64, hour: u64) -> f64 {
+    (mix(seed ^ object.wrapping_mul(0x9E37_79B9) ^ hour.rotate_left(32)) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// SplitMix64 finalizer.
+fn mix(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn load_peaks_in_the_afternoon_and_drops_on_weekends() {
+        // 2025-03-05 is a Wednesday, 2025-03-08 a Saturday
+        let wed = |h| diurnal_load(Utc.with_ymd_and_hms(2025, 3, 5, h, 0, 0).unwrap());
+        let sat_peak = diurnal_load(Utc.with_ymd_and_hms(2025, 3, 8, 14, 0, 0).unwrap());
+
+        assert!((wed(14) - 1.0).abs() < 1e-9);
+        assert!(wed(2) < 0.15);
+        assert!(wed(9) < wed(14));
+        assert!((sat_peak - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn same_seed_generates_the_same_cluster() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let a = SimCluster::generate(3, 10, 7, start);
+        let b = SimCluster::generate(3, 10, 7, start);
+
+        let sizes = |c: &SimCluster| c.pods.iter().map(|p| (p.cpu_nano_cores, p.memory_bytes)).collect::<Vec<_>>();
+        assert_eq!(sizes(&a), sizes(&b));
+        assert_eq!(a.pods[4].info.node_name.as_deref(), Some("sim-node-2"));
+        assert!(a.pods.iter().all(|p| (50_000_000..1_000_000_000).contains(&p.cpu_nano_cores)));
+        assert!((0..100).all(|h| (0.0..1.0).contains(&unit(7, 3, h))));
+    }
+}
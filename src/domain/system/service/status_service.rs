@@ -6,6 +6,8 @@ use anyhow::Result;
 use crate::core::state::runtime::k8s::k8s_runtime_state_manager::K8sRuntimeStateManager;
 use crate::core::state::runtime::k8s::k8s_runtime_state_repository::K8sRuntimeStateRepository;
 use crate::core::state::runtime::k8s::k8s_runtime_state_repository_trait::K8sRuntimeStateRepositoryTrait;
+use crate::config::is_reader;
+use crate::scheduler::leader::is_leader;
 pub async fn status_internal(
     k8s_state: Arc<K8sRuntimeStateManager<K8sRuntimeStateRepository>>,
 ) -> Result<Value> {
@@ -16,5 +18,7 @@ pub async fn status_internal(
         "last_error_at": st.last_error_at,
         "last_error_message": st.last_error_message,
         "resync_running": k8s_state.is_resyncing(),
+        "collector_leader": is_leader(),
+        "role": if is_reader() { "reader" } else { "writer" },
     }))
 }
@@ -1,6 +1,7 @@
 use axum::{http::StatusCode, response::IntoResponse, Json};
 use thiserror::Error;
 use crate::api::dto::ApiResponse;
+use crate::api::dto::metrics_dto::GranularityRejection;
 
 #[allow(dead_code)]
 #[derive(Debug, Error)]
@@ -22,6 +23,15 @@ pub enum AppError {
 
     #[error("Not Resync: {0}")]
     NotResynced(String),
+
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Invalid granularity: {}", .0.message)]
+    InvalidGranularity(GranularityRejection),
 }
 
 /// Helper for mapping any unknown error into internal error
@@ -39,6 +49,9 @@ impl IntoResponse for AppError {
             AppError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
             AppError::NotResynced(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::InvalidGranularity(_) => StatusCode::BAD_REQUEST,
         };
 
         // Extract error components
@@ -49,11 +62,41 @@ impl IntoResponse for AppError {
             AppError::DatabaseError(m) => ("DatabaseError", m.clone()),
             AppError::NotFound(m) => ("NotFound", m.clone()),
             AppError::NotResynced(m) => ("NotResynced", m.clone()),
+            AppError::Unauthorized(m) => ("Unauthorized", m.clone()),
+            AppError::Forbidden(m) => ("Forbidden", m.clone()),
+            AppError::InvalidGranularity(r) => ("InvalidGranularity", r.message.clone()),
+        };
+
+        // Structured details for errors the caller can correct
+        let data = match &self {
+            AppError::InvalidGranularity(r) => serde_json::to_value(r).ok(),
+            _ => None,
         };
 
         // Use your standardized ApiResponse
-        let body = Json(ApiResponse::<()>::err_with_code(code, msg));
+        let body = Json(ApiResponse::<serde_json::Value> {
+            data,
+            ..ApiResponse::err_with_code(code, msg)
+        });
 
         (status, body).into_response()
     }
 }
+
+impl From<AppError> for tonic::Status {
+    fn from(err: AppError) -> Self {
+        let msg = err.to_string();
+        match err {
+            AppError::BodyParsingError(_) | AppError::InvalidGranularity(_) => {
+                tonic::Status::invalid_argument(msg)
+            }
+            AppError::NotFound(_) => tonic::Status::not_found(msg),
+            AppError::NotResynced(_) => tonic::Status::unavailable(msg),
+            AppError::Unauthorized(_) => tonic::Status::unauthenticated(msg),
+            AppError::Forbidden(_) => tonic::Status::permission_denied(msg),
+            AppError::InternalServerError(_)
+            | AppError::K8sApiError(_)
+            | AppError::DatabaseError(_) => tonic::Status::internal(msg),
+        }
+    }
+}
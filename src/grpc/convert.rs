@@ -0,0 +1,234 @@
+//! Conversions between protobuf messages and the REST DTOs the metric services return.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use prost_types::Timestamp;
+use serde_json::Value;
+use tonic::Status;
+
+use crate::api::dto::metrics_dto::{CostMode, RangeQuery};
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::MetricCostSummaryResponseDto;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_trend_dto::MetricCostTrendResponseDto;
+use crate::domain::metric::k8s::common::dto::{
+    MetricGetResponseDto, MetricGranularity, MetricScope, MetricSeriesDto, UniversalMetricPointDto,
+};
+
+use super::pb;
+
+/// Builds the REST range query from a gRPC request.
+pub fn range_query(q: &pb::MetricQuery) -> Result<RangeQuery, Status> {
+    Ok(RangeQuery {
+        start: q.start.as_ref().map(naive_from_timestamp).transpose()?,
+        end: q.end.as_ref().map(naive_from_timestamp).transpose()?,
+        granularity: granularity_from_pb(q.granularity()),
+        tz: None,
+        range: None,
+        limit: q.limit.map(|v| v as usize),
+        offset: q.offset.map(|v| v as usize),
+        cursor: q.cursor.clone(),
+        sort: None,
+        mode: CostMode::default(),
+        cost_basis: None,
+        team: q.team.clone(),
+        service: q.service.clone(),
+        env: q.env.clone(),
+        namespace: q.namespace.clone(),
+        exclude_namespace: q.exclude_namespace.clone(),
+        exclude_team: q.exclude_team.clone(),
+        exclude_service: q.exclude_service.clone(),
+        exclude_env: q.exclude_env.clone(),
+        labels: q.labels.clone(),
+        key: None,
+    })
+}
+
+pub fn series_response(value: Value) -> Result<pb::MetricSeriesResponse, Status> {
+    let dto: MetricGetResponseDto = from_value(value)?;
+    Ok(pb::MetricSeriesResponse {
+        start: Some(timestamp(dto.start)),
+        end: Some(timestamp(dto.end)),
+        granularity: granularity_to_pb(&dto.granularity) as i32,
+        series: dto.series.into_iter().map(series).collect(),
+        total: dto.total.map(|t| t as u64),
+        next_cursor: dto.next_cursor,
+    })
+}
+
+pub fn cost_summary_response(value: Value) -> Result<pb::CostSummaryResponse, Status> {
+    let dto: MetricCostSummaryResponseDto = from_value(value)?;
+    let s = dto.summary;
+    Ok(pb::CostSummaryResponse {
+        start: Some(timestamp(dto.start)),
+        end: Some(timestamp(dto.end)),
+        scope: scope_to_pb(&dto.scope) as i32,
+        target: dto.target,
+        granularity: granularity_to_pb(&dto.granularity) as i32,
+        summary: Some(pb::CostBreakdown {
+            total_cost_usd: s.total_cost_usd,
+            cpu_cost_usd: s.cpu_cost_usd,
+            memory_cost_usd: s.memory_cost_usd,
+            ephemeral_storage_cost_usd: s.ephemeral_storage_cost_usd,
+            persistent_storage_cost_usd: s.persistent_storage_cost_usd,
+            network_cost_usd: s.network_cost_usd,
+            loadbalancer_cost_usd: s.loadbalancer_cost_usd,
+            fixed_cost_usd: s.fixed_cost_usd,
+        }),
+    })
+}
+
+pub fn cost_trend_response(value: Value) -> Result<pb::CostTrendResponse, Status> {
+    let dto: MetricCostTrendResponseDto = from_value(value)?;
+    let t = dto.trend;
+    Ok(pb::CostTrendResponse {
+        start: Some(timestamp(dto.start)),
+        end: Some(timestamp(dto.end)),
+        scope: scope_to_pb(&dto.scope) as i32,
+        target: dto.target,
+        granularity: granularity_to_pb(&dto.granularity) as i32,
+        start_cost_usd: t.start_cost_usd,
+        end_cost_usd: t.end_cost_usd,
+        cost_diff_usd: t.cost_diff_usd,
+        growth_rate_percent: t.growth_rate_percent,
+        regression_slope_usd_per_granularity: t.regression_slope_usd_per_granularity,
+        predicted_next_cost_usd: t.predicted_next_cost_usd,
+        points: dto
+            .points
+            .into_iter()
+            .map(|p| pb::CostTrendPoint {
+                time: Some(timestamp(p.time)),
+                total_cost_usd: p.total_cost_usd,
+                cpu_cost_usd: p.cpu_cost_usd,
+                memory_cost_usd: p.memory_cost_usd,
+                storage_cost_usd: p.storage_cost_usd,
+            })
+            .collect(),
+    })
+}
+
+fn series(s: MetricSeriesDto) -> pb::MetricSeries {
+    pb::MetricSeries {
+        key: s.key,
+        name: s.name,
+        scope: scope_to_pb(&s.scope) as i32,
+        points: s.points.into_iter().map(point).collect(),
+        running_hours: s.running_hours,
+        total_cost_usd: s.cost_summary.and_then(|c| c.total_cost_usd),
+    }
+}
+
+fn point(p: UniversalMetricPointDto) -> pb::MetricPoint {
+    let fs = p.filesystem.unwrap_or_default();
+    let net = p.network.unwrap_or_default();
+    let cost = p.cost.unwrap_or_default();
+    pb::MetricPoint {
+        time: Some(timestamp(p.time)),
+        cpu_usage_nano_cores: p.cpu_memory.cpu_usage_nano_cores,
+        memory_usage_bytes: p.cpu_memory.memory_usage_bytes,
+        memory_working_set_bytes: p.cpu_memory.memory_working_set_bytes,
+        filesystem_used_bytes: fs.used_bytes,
+        filesystem_capacity_bytes: fs.capacity_bytes,
+        network_rx_bytes: net.rx_bytes,
+        network_tx_bytes: net.tx_bytes,
+        total_cost_usd: cost.total_cost_usd,
+        cpu_cost_usd: cost.cpu_cost_usd,
+        memory_cost_usd: cost.memory_cost_usd,
+        storage_cost_usd: cost.storage_cost_usd,
+    }
+}
+
+fn from_value<T: serde::de::DeserializeOwned>(value: Value) -> Result<T, Status> {
+    serde_json::from_value(value)
+        .map_err(|e| Status::internal(format!("Unexpected metric response shape: {}", e)))
+}
+
+fn timestamp(t: DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: t.timestamp(),
+        nanos: t.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn naive_from_timestamp(t: &Timestamp) -> Result<NaiveDateTime, Status> {
+    u32::try_from(t.nanos)
+        .ok()
+        .and_then(|nanos| DateTime::from_timestamp(t.seconds, nanos))
+        .map(|dt| dt.naive_utc())
+        .ok_or_else(|| Status::invalid_argument("Timestamp out of range"))
+}
+
+fn granularity_from_pb(g: pb::Granularity) -> Option<MetricGranularity> {
+    match g {
+        pb::Granularity::Unspecified => None,
+        pb::Granularity::Minute => Some(MetricGranularity::Minute),
+        pb::Granularity::Hour => Some(MetricGranularity::Hour),
+        pb::Granularity::Day => Some(MetricGranularity::Day),
+        pb::Granularity::Month => Some(MetricGranularity::Month),
+    }
+}
+
+fn granularity_to_pb(g: &MetricGranularity) -> pb::Granularity {
+    match g {
+        MetricGranularity::Minute => pb::Granularity::Minute,
+        MetricGranularity::Hour => pb::Granularity::Hour,
+        MetricGranularity::Day => pb::Granularity::Day,
+        MetricGranularity::Month => pb::Granularity::Month,
+    }
+}
+
+fn scope_to_pb(s: &MetricScope) -> pb::Scope {
+    match s {
+        MetricScope::Cluster => pb::Scope::Cluster,
+        MetricScope::Node => pb::Scope::Node,
+        MetricScope::Namespace => pb::Scope::Namespace,
+        MetricScope::Deployment => pb::Scope::Deployment,
+        MetricScope::Pod => pb::Scope::Pod,
+        MetricScope::Container => pb::Scope::Container,
+        MetricScope::Job => pb::Scope::Job,
+        MetricScope::CronJob => pb::Scope::Cronjob,
+        MetricScope::NodePool => pb::Scope::NodePool,
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn converts_series_response() {
+        let value = json!({
+            "start": "2025-01-01T00:00:00Z",
+            "end": "2025-01-01T01:00:00Z",
+            "scope": "pod",
+            "target": null,
+            "granularity": "hour",
+            "series": [{
+                "key": "uid-1",
+                "name": "api-0",
+                "scope": "pod",
+                "points": [{
+                    "time": "2025-01-01T00:00:00Z",
+                    "cpu_memory": { "cpu_usage_nano_cores": 5.0e8 },
+                    "cost": { "total_cost_usd": 0.25 }
+                }],
+                "running_hours": 1.0,
+                "cost_summary": { "total_cost_usd": 0.25 }
+            }],
+            "total": 1,
+            "limit": null,
+            "offset": null
+        });
+
+        let res = series_response(value).unwrap();
+        assert_eq!(res.granularity(), pb::Granularity::Hour);
+        assert_eq!(res.start.unwrap().seconds, 1_735_689_600);
+        assert_eq!(res.total, Some(1));
+
+        let s = &res.series[0];
+        assert_eq!(s.scope(), pb::Scope::Pod);
+        assert_eq!(s.total_cost_usd, Some(0.25));
+        assert_eq!(s.points[0].cpu_usage_nano_cores, Some(5.0e8));
+        assert_eq!(s.points[0].network_rx_bytes, None);
+    }
+}
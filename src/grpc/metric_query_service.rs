@@ -0,0 +1,238 @@
+//! `rustcost.v1.MetricQueryService`: the REST metric/cost views over gRPC.
+//!
+//! Scope checks match the REST controllers: scoped keys only see their own
+//! pods/namespaces, and node or cluster queries need an unscoped key.
+
+use serde_json::Value;
+use tonic::metadata::MetadataMap;
+use tonic::{Request, Response, Status};
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::api::dto::metrics_query_dto::{MetricQueryAggregation, MetricQueryScope, MetricQueryView};
+use crate::api::middleware::auth::authorize;
+use crate::api::util::tenant_scope::TenantScope;
+use crate::app_state::AppState;
+use crate::domain::metric::k8s::query::engine::MetricQuery as EngineQuery;
+
+use super::convert::{cost_summary_response, cost_trend_response, range_query, series_response};
+use super::pb::metric_query_service_server::MetricQueryService;
+use super::pb::{
+    CostSummaryResponse, CostTrendResponse, MetricQuery, MetricSeriesResponse, Scope,
+};
+
+pub struct MetricQueryGrpcService {
+    state: AppState,
+}
+
+impl MetricQueryGrpcService {
+    pub fn new(state: AppState) -> Self {
+        Self { state }
+    }
+}
+
+/// Which family of metric endpoints a call maps to.
+#[derive(Debug, Clone, Copy)]
+enum View {
+    Usage,
+    Cost,
+    CostSummary,
+    CostTrend,
+}
+
+/// Calls the `raw` / `cost` / `cost_summary` / `cost_trend` variant of a metric service method.
+macro_rules! by_view {
+    ($view:expr, $svc:expr, ($($arg:expr),*), $raw:ident, $cost:ident, $summary:ident, $trend:ident) => {
+        match $view {
+            View::Usage => $svc.$raw($($arg),*).await,
+            View::Cost => $svc.$cost($($arg),*).await,
+            View::CostSummary => $svc.$summary($($arg),*).await,
+            View::CostTrend => $svc.$trend($($arg),*).await,
+        }
+    };
+}
+
+#[tonic::async_trait]
+impl MetricQueryService for MetricQueryGrpcService {
+    async fn get_usage_series(
+        &self,
+        request: Request<MetricQuery>,
+    ) -> Result<Response<MetricSeriesResponse>, Status> {
+        let value = self.query(request, View::Usage).await?;
+        Ok(Response::new(series_response(value)?))
+    }
+
+    async fn get_cost_series(
+        &self,
+        request: Request<MetricQuery>,
+    ) -> Result<Response<MetricSeriesResponse>, Status> {
+        let value = self.query(request, View::Cost).await?;
+        Ok(Response::new(series_response(value)?))
+    }
+
+    async fn get_cost_summary(
+        &self,
+        request: Request<MetricQuery>,
+    ) -> Result<Response<CostSummaryResponse>, Status> {
+        let value = self.query(request, View::CostSummary).await?;
+        Ok(Response::new(cost_summary_response(value)?))
+    }
+
+    async fn get_cost_trend(
+        &self,
+        request: Request<MetricQuery>,
+    ) -> Result<Response<CostTrendResponse>, Status> {
+        let value = self.query(request, View::CostTrend).await?;
+        Ok(Response::new(cost_trend_response(value)?))
+    }
+}
+
+impl MetricQueryGrpcService {
+    async fn query(&self, request: Request<MetricQuery>, view: View) -> Result<Value, Status> {
+        // Every RPC here is a read, so any authenticated role may call it
+        let (_, tenant) = authorize(extract_token(request.metadata())).await?;
+        let query = request.into_inner();
+
+        let state = &self.state;
+        state.k8s_state.ensure_resynced().await?;
+
+        let q = range_query(&query)?;
+        let target = query.target.clone().filter(|t| !t.is_empty());
+        let svc = &state.metric_service;
+
+        let result = match (query.scope(), target) {
+            (Scope::Unspecified, _) => {
+                return Err(Status::invalid_argument("scope is required"));
+            }
+            (Scope::Cluster, Some(_)) => {
+                return Err(Status::invalid_argument("cluster queries do not take a target"));
+            }
+            (Scope::Cluster, None) => {
+                svc.query(list_query(state, &tenant, MetricQueryScope::Cluster, view, q).await?).await
+            }
+            (Scope::Node, Some(node)) => {
+                tenant.require_unrestricted()?;
+                by_view!(view, svc, (node, q),
+                    get_metric_k8s_node_raw, get_metric_k8s_node_cost,
+                    get_metric_k8s_node_cost_summary, get_metric_k8s_node_cost_trend)
+            }
+            (Scope::Node, None) => {
+                svc.query(list_query(state, &tenant, MetricQueryScope::Node, view, q).await?).await
+            }
+            (Scope::NodePool, Some(pool)) => {
+                tenant.require_unrestricted()?;
+                let nodes = state.k8s_state.get_nodes().await;
+                by_view!(view, svc, (pool, q, nodes),
+                    get_metric_k8s_nodepool_raw, get_metric_k8s_nodepool_cost,
+                    get_metric_k8s_nodepool_cost_summary, get_metric_k8s_nodepool_cost_trend)
+            }
+            (Scope::NodePool, None) => {
+                tenant.require_unrestricted()?;
+                let nodes = state.k8s_state.get_nodes().await;
+                by_view!(view, svc, (q, nodes),
+                    get_metric_k8s_nodepools_raw, get_metric_k8s_nodepools_cost,
+                    get_metric_k8s_nodepools_cost_summary, get_metric_k8s_nodepools_cost_trend)
+            }
+            (Scope::Namespace, Some(ns)) => {
+                tenant.check_namespace(&ns)?;
+                by_view!(view, svc, (ns, q),
+                    get_metric_k8s_namespace_raw, get_metric_k8s_namespace_cost,
+                    get_metric_k8s_namespace_cost_summary, get_metric_k8s_namespace_cost_trend)
+            }
+            (Scope::Namespace, None) => {
+                svc.query(list_query(state, &tenant, MetricQueryScope::Namespace, view, q).await?).await
+            }
+            (Scope::Deployment, Some(deployment)) => {
+                tenant.check_deployment(state, &deployment).await?;
+                by_view!(view, svc, (deployment, q),
+                    get_metric_k8s_deployment_raw, get_metric_k8s_deployment_cost,
+                    get_metric_k8s_deployment_cost_summary, get_metric_k8s_deployment_cost_trend)
+            }
+            (Scope::Deployment, None) => {
+                svc.query(list_query(state, &tenant, MetricQueryScope::Deployment, view, q).await?).await
+            }
+            (Scope::Pod, Some(pod_uid)) => {
+                tenant.check_pod(state, &pod_uid).await?;
+                by_view!(view, svc, (pod_uid, q),
+                    get_metric_k8s_pod_raw, get_metric_k8s_pod_cost,
+                    get_metric_k8s_pod_cost_summary, get_metric_k8s_pod_cost_trend)
+            }
+            (Scope::Pod, None) => {
+                svc.query(list_query(state, &tenant, MetricQueryScope::Pod, view, q).await?).await
+            }
+            (Scope::Container, Some(id)) => {
+                tenant.check_container(state, &id).await?;
+                by_view!(view, svc, (id, q),
+                    get_metric_k8s_container_raw, get_metric_k8s_container_cost,
+                    get_metric_k8s_container_cost_summary, get_metric_k8s_container_cost_trend)
+            }
+            (Scope::Container, None) => {
+                svc.query(list_query(state, &tenant, MetricQueryScope::Container, view, q).await?).await
+            }
+            (Scope::Job | Scope::Cronjob, None) => {
+                return Err(Status::invalid_argument("job and cronjob queries need a namespace/name target"));
+            }
+            (Scope::Job, Some(target)) => {
+                let (ns, name) = split_namespaced(&target)?;
+                tenant.check_namespace(&ns)?;
+                by_view!(view, svc, (ns, name, q),
+                    get_metric_k8s_job_raw, get_metric_k8s_job_cost,
+                    get_metric_k8s_job_cost_summary, get_metric_k8s_job_cost_trend)
+            }
+            (Scope::Cronjob, Some(target)) => {
+                let (ns, name) = split_namespaced(&target)?;
+                tenant.check_namespace(&ns)?;
+                by_view!(view, svc, (ns, name, q),
+                    get_metric_k8s_cronjob_raw, get_metric_k8s_cronjob_cost,
+                    get_metric_k8s_cronjob_cost_summary, get_metric_k8s_cronjob_cost_trend)
+            }
+        };
+
+        result.map_err(|e| Status::internal(e.to_string()))
+    }
+}
+
+/// List queries go through the query engine, like `POST /metrics/query`.
+async fn list_query(
+    state: &AppState,
+    tenant: &TenantScope,
+    scope: MetricQueryScope,
+    view: View,
+    range: RangeQuery,
+) -> Result<EngineQuery, Status> {
+    let targets = tenant.query_targets(state, scope, Vec::new()).await?;
+    Ok(EngineQuery {
+        scope,
+        view: match view {
+            View::Usage => MetricQueryView::Raw,
+            View::Cost => MetricQueryView::Cost,
+            View::CostSummary => MetricQueryView::CostSummary,
+            View::CostTrend => MetricQueryView::CostTrend,
+        },
+        targets,
+        range,
+        group_by: None,
+        aggregation: MetricQueryAggregation::default(),
+    })
+}
+
+/// Jobs and CronJobs are addressed as `namespace/name`.
+fn split_namespaced(target: &str) -> Result<(String, String), Status> {
+    match target.split_once('/') {
+        Some((ns, name)) if !ns.is_empty() && !name.is_empty() => Ok((ns.to_string(), name.to_string())),
+        _ => Err(Status::invalid_argument("target must be namespace/name")),
+    }
+}
+
+/// `x-api-key` wins over `authorization: Bearer`, like the REST middleware.
+fn extract_token(metadata: &MetadataMap) -> Option<String> {
+    if let Some(value) = metadata.get("x-api-key").and_then(|v| v.to_str().ok()) {
+        return Some(value.trim().to_string()).filter(|t| !t.is_empty());
+    }
+
+    metadata
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer ").or_else(|| v.strip_prefix("bearer ")))
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
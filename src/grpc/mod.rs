@@ -0,0 +1,32 @@
+//! gRPC API (tonic) for batch consumers of metric and cost data.
+//!
+//! Served on `GRPC_PORT` next to the REST API and authenticated with the same
+//! API keys / tokens (`authorization: Bearer ...` or `x-api-key` metadata).
+
+use std::future::Future;
+use std::net::SocketAddr;
+
+use crate::app_state::AppState;
+
+pub mod convert;
+pub mod metric_query_service;
+
+#[allow(clippy::all)]
+pub mod pb {
+    tonic::include_proto!("rustcost.v1");
+}
+
+use metric_query_service::MetricQueryGrpcService;
+use pb::metric_query_service_server::MetricQueryServiceServer;
+
+/// Runs the gRPC server until `shutdown` resolves.
+pub async fn serve(
+    state: AppState,
+    addr: SocketAddr,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), tonic::transport::Error> {
+    tonic::transport::Server::builder()
+        .add_service(MetricQueryServiceServer::new(MetricQueryGrpcService::new(state)))
+        .serve_with_shutdown(addr, shutdown)
+        .await
+}
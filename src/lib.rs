@@ -0,0 +1,18 @@
+//! rustcost core: collectors, metric storage and the cost API.
+//!
+//! `main.rs` wires these modules into the server; the library target also lets
+//! the benchmarks under `benches/` reach the storage and query code.
+
+pub mod config;
+pub mod logging;
+pub mod domain;
+pub mod api;
+pub mod errors;
+pub mod routes;
+pub mod scheduler;
+pub mod core;
+pub mod debug;
+pub mod app_state;
+pub mod grpc;
+#[cfg(test)]
+mod test_support;
@@ -21,18 +21,42 @@ pub fn init_tracing() -> tracing_appender::non_blocking::WorkerGuard {
     let file_appender = rolling::daily(&rustcost_log_dir, "app.log");
     let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
 
-    let fmt_layer = fmt::layer()
-        .with_writer(non_blocking)
-        .with_target(false)
-        .with_level(true)
-        .with_ansi(false);
+    // `RUSTCOST_LOG_FORMAT=json` writes one JSON object per line (for Loki/ELK);
+    // the enclosing request span's fields, such as `request_id`, go on every line.
+    let json_format = env::var("RUSTCOST_LOG_FORMAT")
+        .map(|v| v.eq_ignore_ascii_case("json"))
+        .unwrap_or(false);
+
+    let (text_layer, json_layer) = if json_format {
+        let layer = fmt::layer()
+            .json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .with_writer(non_blocking)
+            .with_target(false)
+            .with_level(true);
+        (None, Some(layer))
+    } else {
+        let layer = fmt::layer()
+            .with_writer(non_blocking)
+            .with_target(false)
+            .with_level(true)
+            .with_ansi(false);
+        (Some(layer), None)
+    };
 
     let filter_layer = EnvFilter::new(rustcost_log_level);
 
-    tracing_subscriber::registry()
+    let registry = tracing_subscriber::registry()
         .with(filter_layer)
-        .with(fmt_layer)
-        .init();
+        .with(text_layer)
+        .with(json_layer);
+
+    // Spans go to the collector at OTEL_EXPORTER_OTLP_ENDPOINT when built with `otlp`
+    #[cfg(feature = "otlp")]
+    let registry = registry.with(otlp::layer());
+
+    registry.init();
 
     tracing::info!(
         "✅ Tracing initialized — daily logs in {}/app.log.YYYY-MM-DD",
@@ -41,3 +65,52 @@ pub fn init_tracing() -> tracing_appender::non_blocking::WorkerGuard {
 
     guard
 }
+
+/// Flushes spans still buffered for export. No-op without the `otlp` feature.
+pub fn shutdown_tracing() {
+    #[cfg(feature = "otlp")]
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+#[cfg(feature = "otlp")]
+mod otlp {
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry::KeyValue;
+    use opentelemetry_otlp::WithExportConfig;
+    use opentelemetry_sdk::{runtime, trace::TracerProvider, Resource};
+    use std::env;
+    use tracing::Subscriber;
+    use tracing_opentelemetry::OpenTelemetryLayer;
+    use tracing_subscriber::registry::LookupSpan;
+
+    /// OTLP/gRPC export layer, or `None` when no endpoint is configured or the
+    /// exporter can't be built (tracing then stays file-only).
+    pub fn layer<S>() -> Option<OpenTelemetryLayer<S, opentelemetry_sdk::trace::Tracer>>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let endpoint = env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok().filter(|v| !v.is_empty())?;
+        let service_name = env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| "rustcost".to_string());
+
+        let exporter = match opentelemetry_otlp::SpanExporter::builder()
+            .with_tonic()
+            .with_endpoint(endpoint)
+            .build()
+        {
+            Ok(exporter) => exporter,
+            Err(e) => {
+                eprintln!("Failed to build OTLP exporter, tracing export disabled: {e}");
+                return None;
+            }
+        };
+
+        let provider = TracerProvider::builder()
+            .with_batch_exporter(exporter, runtime::Tokio)
+            .with_resource(Resource::new(vec![KeyValue::new("service.name", service_name)]))
+            .build();
+        let tracer = provider.tracer("rustcost");
+        opentelemetry::global::set_tracer_provider(provider);
+
+        Some(tracing_opentelemetry::layer().with_tracer(tracer))
+    }
+}
@@ -1,26 +1,15 @@
 use std::net::SocketAddr;
 use tokio::sync::broadcast;
 
-// --- Modules ---
-mod config;
-mod logging;
-mod domain;
-mod api;
-mod errors;
-mod routes;
-mod scheduler;
-pub mod core;
-mod debug;
-mod app_state;
-
 // --- Imports ---
-use crate::config::config;
-use crate::debug::run_debug;
+use rustcost_core::config::{config, InstanceRole};
+use rustcost_core::debug::run_debug;
 // &'fixed Config
-use crate::routes::app_router;
-use crate::scheduler::scheduler_start_all_tasks;
-use tracing::{error, info};
-use crate::app_state::{build_app_state};
+use rustcost_core::routes::app_router;
+use rustcost_core::scheduler::{scheduler_start_all_tasks, scheduler_start_reader_tasks};
+use rustcost_core::{grpc, logging};
+use tracing::{error, info, warn};
+use rustcost_core::app_state::{build_app_state};
 
 // --- Entry Point ---
 #[tokio::main]
@@ -30,17 +19,20 @@ async fn main() {
 
     let app_config = config().await;
     run_server(app_config).await;
+
+    logging::shutdown_tracing();
 }
 
 /// ✅ Initialize tracing (logs stored in file)
 
 
 /// ✅ Run the Axum server
-async fn run_server(app_config: &crate::config::Config) {
+async fn run_server(app_config: &rustcost_core::config::Config) {
     let app_state = build_app_state();
     let scheduler_state  = app_state.clone();
+    let grpc_state = app_state.clone();
 
-    let app = app_router().with_state(app_state);
+    let app = app_router(&app_state).with_state(app_state);
     let address = format!("{}:{}", app_config.server_host(), app_config.server_port());
     let socket_addr: SocketAddr = address.parse().expect("Invalid socket address");
     let rustcost_debug_mode = std::env::var("RUSTCOST_DEBUG_MODE")
@@ -48,6 +40,9 @@ async fn run_server(app_config: &crate::config::Config) {
         .unwrap_or(false);
 
     info!("🚀 Listening on http://{}", socket_addr);
+    if !app_config.auth().is_enabled() {
+        warn!("⚠️ API authentication is disabled; set RUSTCOST_API_KEYS to require API keys");
+    }
 
     let listener = tokio::net::TcpListener::bind(socket_addr)
         .await
@@ -56,8 +51,31 @@ async fn run_server(app_config: &crate::config::Config) {
     // Keep the sender ALIVE for whole function lifetime
     let (shutdown_tx, mut shutdown_rx) = broadcast::channel::<()>(16);
 
+    if let Some(grpc_port) = app_config.grpc_port() {
+        let grpc_address = format!("{}:{}", app_config.server_host(), grpc_port);
+        let grpc_addr: SocketAddr = grpc_address.parse().expect("Invalid gRPC socket address");
+        let mut grpc_rx = shutdown_tx.subscribe();
+
+        info!("🚀 gRPC listening on {}", grpc_addr);
+        tokio::spawn(async move {
+            let shutdown = async move {
+                let _ = grpc_rx.recv().await;
+            };
+            if let Err(e) = grpc::serve(grpc_state, grpc_addr, shutdown).await {
+                error!(?e, "gRPC server failed");
+            }
+        });
+    }
+
     if rustcost_debug_mode {
-        run_debug().await;
+        run_debug(scheduler_state.clone()).await;
+    } else if app_config.role() == InstanceRole::Reader {
+        // Shared data directory belongs to the writer; only watch K8s and serve
+        info!("📖 Running as read-only replica (ROLE=reader)");
+        let reader_rx = shutdown_rx.resubscribe();
+        tokio::spawn(async move {
+            scheduler_start_reader_tasks(scheduler_state, reader_rx).await;
+        });
     } else {
         // Run the scheduler as a background task that blocks until it receives shutdown
         let sched_rx = shutdown_rx.resubscribe();
@@ -1,32 +1,56 @@
 use axum::{
-    http::StatusCode,
-    response::IntoResponse,
-    routing::get,
-    Router,
+    http::{header, StatusCode},
+    middleware,
+    response::{Html, IntoResponse},
+    routing::{get, post},
+    Json, Router,
 };
 use tower_http::cors::CorsLayer;
+use crate::api::middleware::auth::require_auth;
+use crate::api::middleware::read_only::reject_writes_on_reader;
+use crate::api::middleware::repositories_scope::scope_repositories;
+use crate::api::middleware::request_id::attach_request_id;
+use crate::api::middleware::request_metrics::record_request_metrics;
 use crate::app_state::AppState;
 
-/// Build the main application router
-pub fn app_router() -> Router<AppState> {
+/// Build the main application router; API requests run against `state`'s repositories
+pub fn app_router(state: &AppState) -> Router<AppState> {
     // Metrics, Info, System subrouters live under /api/v1
     let api_v1 = Router::new()
         .nest("/metrics", crate::api::routes::metrics_routes::metrics_routes())
         .nest("/info", crate::api::routes::info_routes::info_routes())
         .nest("/system", crate::api::routes::system_routes::system_routes())
         .nest("/llm", crate::api::routes::llm_routes::llm_routes())
-        .nest("/states", crate::api::routes::state_routes::state_routes());
+        .nest("/states", crate::api::routes::state_routes::state_routes())
+        .nest("/alerts", crate::api::routes::alert_routes::alert_routes())
+        .route("/graphql", post(crate::api::graphql::graphql_handler))
+        // 🗄️ Every handler reads through the state's repositories
+        .layer(middleware::from_fn_with_state(state.clone(), scope_repositories))
+        // 📖 Reader replicas refuse writes (after auth, so 401 still wins)
+        .layer(middleware::from_fn(reject_writes_on_reader))
+        // 🔐 API key / TokenReview auth; `/` and `/health` stay open for probes
+        .layer(middleware::from_fn(require_auth))
+        // ⏱️ Outermost, so rejected requests are timed too
+        .layer(middleware::from_fn(record_request_metrics));
 
     Router::new()
         // Root route
         .route("/", get(root))
         // Health check
         .route("/health", get(health_check))
+        // Prometheus scrape of rustcost's own metrics
+        .route("/metrics", get(self_metrics))
+        // API docs
+        .route("/openapi.json", get(openapi_json))
+        .route("/docs", get(swagger_ui))
+        .route("/graphiql", get(crate::api::graphql::graphiql))
         // API v1
         .nest("/api/v1", api_v1)
 
         // Fallback handler for 404
         .fallback(handler_404)
+        // 🏷️ Request id span around everything, so all log lines carry it
+        .layer(middleware::from_fn(attach_request_id))
         // Attach shared application state ONCE here
         // ✅ Apply CORS layer to all routes
         .layer(CorsLayer::very_permissive())
@@ -42,6 +66,24 @@ async fn health_check() -> &'static str {
     "OK"
 }
 
+// Handler for the Prometheus endpoint
+async fn self_metrics() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        crate::core::self_metrics::render(),
+    )
+}
+
+// Handler for the OpenAPI document
+async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(crate::api::openapi::api_doc())
+}
+
+// Handler for Swagger UI
+async fn swagger_ui() -> Html<&'static str> {
+    Html(crate::api::openapi::SWAGGER_UI_HTML)
+}
+
 // Handler for 404 Not Found
 async fn handler_404() -> impl IntoResponse {
     (
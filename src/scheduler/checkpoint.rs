@@ -0,0 +1,167 @@
+//! Aggregation checkpoints.
+//!
+//! Every successful minute→hour and hour→day run records its window end as a
+//! per-scope watermark. On startup, [`catch_up_missed_windows`] aggregates the
+//! windows that elapsed while rustcost was down, instead of leaving them
+//! permanently empty.
+
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, Utc};
+use tracing::{error, info, warn};
+
+use crate::core::persistence::jobs::aggregation_watermark_entity::AggregationLevel;
+use crate::core::persistence::jobs::aggregation_watermark_repository::{
+    AggregationWatermarkRepository, AggregationWatermarkRepositoryImpl,
+};
+use crate::scheduler::job_tracker::record_failure;
+use crate::scheduler::tasks::processors::day::container::task::process_container_hour_to_day;
+use crate::scheduler::tasks::processors::day::node::task::process_node_hour_to_day;
+use crate::scheduler::tasks::processors::day::pod::task::process_pod_hour_to_day;
+use crate::scheduler::tasks::processors::month::process_month_rollup;
+use crate::scheduler::tasks::processors::hour::container::task::process_container_minute_to_hour;
+use crate::scheduler::tasks::processors::hour::node::task::process_node_minute_to_hour;
+use crate::scheduler::tasks::processors::hour::pod::task::process_pod_minute_to_hour;
+use crate::scheduler::tasks::utils::time_util::TimeUtils;
+
+const SCOPES: [&str; 3] = ["node", "pod", "container"];
+
+/// Longest gap re-aggregated on startup; older windows need a manual backfill.
+const MAX_CATCH_UP_HOURS: i64 = 7 * 24;
+const MAX_CATCH_UP_DAYS: i64 = 31;
+
+/// Advances the watermark of `scope` to `window_end`. Failures are only logged:
+/// a missing checkpoint costs a redundant catch-up, not data.
+pub fn record_watermark(level: AggregationLevel, scope: &str, window_end: DateTime<Utc>) {
+    let repo = AggregationWatermarkRepositoryImpl::new();
+    let result = repo.read().and_then(|mut marks| {
+        marks.advance(level, scope, window_end);
+        repo.write(&marks)
+    });
+    if let Err(e) = result {
+        warn!(?level, scope, ?e, "Failed to record aggregation watermark");
+    }
+}
+
+/// Aggregates every hour and day window that closed after its scope's
+/// watermark and before `now`. Hours run first so the day windows read them.
+///
+/// Scopes without a watermark (fresh install) are skipped; the next scheduled
+/// run creates it.
+pub async fn catch_up_missed_windows(now: DateTime<Utc>) -> Result<()> {
+    let marks = AggregationWatermarkRepositoryImpl::new().read()?;
+
+    let latest_hour = TimeUtils::previous_hour_window(now)?.1;
+    for scope in SCOPES {
+        let windows = missed_windows(
+            marks.get(AggregationLevel::Hour, scope),
+            latest_hour,
+            Duration::hours(1),
+            MAX_CATCH_UP_HOURS,
+        );
+        if !windows.is_empty() {
+            info!(scope, windows = windows.len(), "Catching up missed hour aggregation");
+        }
+        for window_end in windows {
+            let res = match scope {
+                "node" => process_node_minute_to_hour(window_end).await,
+                "pod" => process_pod_minute_to_hour(window_end).await,
+                _ => process_container_minute_to_hour(window_end).await,
+            };
+            if let Err(e) = res {
+                // Stop here so the watermark stays before the gap and the next start retries it
+                error!(scope, %window_end, ?e, "Hour catch-up failed");
+                record_failure(scope, &e);
+                break;
+            }
+            record_watermark(AggregationLevel::Hour, scope, window_end);
+        }
+    }
+
+    let latest_day = TimeUtils::previous_day_window(now).1;
+    for scope in SCOPES {
+        let windows = missed_windows(
+            marks.get(AggregationLevel::Day, scope),
+            latest_day,
+            Duration::days(1),
+            MAX_CATCH_UP_DAYS,
+        );
+        if !windows.is_empty() {
+            info!(scope, windows = windows.len(), "Catching up missed day aggregation");
+        }
+        for window_end in windows {
+            let res = match scope {
+                "node" => process_node_hour_to_day(window_end).await,
+                "pod" => process_pod_hour_to_day(window_end).await,
+                _ => process_container_hour_to_day(window_end).await,
+            };
+            if let Err(e) = res {
+                error!(scope, %window_end, ?e, "Day catch-up failed");
+                record_failure(scope, &e);
+                break;
+            }
+            record_watermark(AggregationLevel::Day, scope, window_end);
+
+            // A missed 1st of the month also missed that month's rollup
+            if window_end.day() == 1 {
+                if let Err(e) = process_month_rollup(scope, window_end) {
+                    error!(scope, %window_end, ?e, "Month catch-up failed");
+                    record_failure(scope, &e);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Window ends after `watermark` up to and including `latest`, capped to the
+/// most recent `max` windows.
+fn missed_windows(
+    watermark: Option<DateTime<Utc>>,
+    latest: DateTime<Utc>,
+    step: Duration,
+    max: i64,
+) -> Vec<DateTime<Utc>> {
+    let Some(watermark) = watermark else {
+        return Vec::new();
+    };
+
+    let oldest_allowed = latest - step * (max as i32 - 1);
+    let mut current = (watermark + step).max(oldest_allowed);
+    let mut ends = Vec::new();
+    while current <= latest {
+        ends.push(current);
+        current += step;
+    }
+    ends
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 4, 1, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn gap_after_watermark_is_returned() {
+        let ends = missed_windows(Some(at(10)), at(13), Duration::hours(1), 168);
+        assert_eq!(ends, vec![at(11), at(12), at(13)]);
+    }
+
+    #[test]
+    fn up_to_date_or_unknown_watermark_needs_nothing() {
+        assert!(missed_windows(Some(at(13)), at(13), Duration::hours(1), 168).is_empty());
+        assert!(missed_windows(None, at(13), Duration::hours(1), 168).is_empty());
+    }
+
+    #[test]
+    fn long_gaps_are_capped_to_the_latest_windows() {
+        let ends = missed_windows(Some(at(0)), at(20), Duration::hours(1), 3);
+        assert_eq!(ends, vec![at(18), at(19), at(20)]);
+    }
+}
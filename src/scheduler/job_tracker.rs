@@ -0,0 +1,85 @@
+//! Records each background job run into the job history store.
+//!
+//! Jobs are wrapped with [`track_job`]; code running inside the job reports
+//! per-object outcomes with [`record_processed`] / [`record_failure`] without
+//! having to thread a counter through every call.
+
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use chrono::Utc;
+use tracing::warn;
+
+use crate::core::persistence::jobs::job_history_repository::{
+    JobHistoryRepository, JobHistoryRepositoryImpl,
+};
+use crate::core::persistence::jobs::job_run_entity::JobRunEntity;
+
+/// Per-object failures kept in a history entry.
+const MAX_FAILURES: usize = 20;
+
+#[derive(Debug, Default)]
+struct JobCounters {
+    processed: u64,
+    failed: u64,
+    failures: Vec<String>,
+}
+
+tokio::task_local! {
+    static CURRENT_JOB: Arc<Mutex<JobCounters>>;
+}
+
+/// Runs `fut` as job `name` and appends the outcome to the job history.
+pub async fn track_job<Fut>(name: &str, fut: Fut) -> Result<()>
+where
+    Fut: Future<Output = Result<()>>,
+{
+    let counters = Arc::new(Mutex::new(JobCounters::default()));
+    let started_at = Utc::now();
+
+    let result = CURRENT_JOB.scope(counters.clone(), fut).await;
+
+    let finished_at = Utc::now();
+    let counters = std::mem::take(&mut *counters.lock().unwrap());
+    let status = match (&result, counters.failed) {
+        (Err(_), _) => "failed",
+        (Ok(_), 0) => "success",
+        (Ok(_), _) => "partial",
+    };
+
+    let run = JobRunEntity {
+        job: name.to_string(),
+        started_at,
+        finished_at,
+        duration_ms: (finished_at - started_at).num_milliseconds(),
+        status: status.to_string(),
+        objects_processed: counters.processed,
+        objects_failed: counters.failed,
+        failures: counters.failures,
+        error: result.as_ref().err().map(|e| format!("{:#}", e)),
+    };
+
+    if let Err(e) = JobHistoryRepositoryImpl::new().append(&run) {
+        warn!(job = name, ?e, "Failed to record job history");
+    }
+
+    result
+}
+
+/// Count one successfully processed object in the current job (no-op outside a job).
+pub fn record_processed() {
+    let _ = CURRENT_JOB.try_with(|c| c.lock().unwrap().processed += 1);
+}
+
+/// Count one failed object in the current job (no-op outside a job).
+pub fn record_failure(object: &str, err: impl Display) {
+    let _ = CURRENT_JOB.try_with(|c| {
+        let mut c = c.lock().unwrap();
+        c.failed += 1;
+        if c.failures.len() < MAX_FAILURES {
+            c.failures.push(format!("{}: {}", object, err));
+        }
+    });
+}
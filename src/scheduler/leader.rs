@@ -0,0 +1,229 @@
+//! Lease-based leader election for HA deployments.
+//!
+//! With `RUSTCOST_LEADER_ELECTION` on, replicas compete for a
+//! `coordination.k8s.io/v1` Lease. Only the holder runs the collector and the
+//! aggregation jobs, so two replicas never append the same rows; every
+//! replica keeps serving the read APIs. With election off this process is
+//! always the leader.
+
+use std::sync::OnceLock;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use k8s_openapi::api::coordination::v1::{Lease, LeaseSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{MicroTime, ObjectMeta};
+use kube::api::PostParams;
+use kube::Api;
+use tokio::sync::{broadcast, watch};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+
+use crate::config::LeaderElectionConfig;
+use crate::core::client::kube_client::build_kube_client;
+
+/// How long a lease stays valid without renewal.
+const LEASE_DURATION_SECS: i32 = 15;
+/// How often the holder renews and followers retry.
+const RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+fn leadership() -> &'static watch::Sender<bool> {
+    static LEADER: OnceLock<watch::Sender<bool>> = OnceLock::new();
+    LEADER.get_or_init(|| watch::channel(false).0)
+}
+
+/// Whether this replica should scrape and write metrics right now.
+pub fn is_leader() -> bool {
+    *leadership().borrow()
+}
+
+/// Receiver that changes whenever leadership is gained or lost.
+pub fn subscribe() -> watch::Receiver<bool> {
+    leadership().subscribe()
+}
+
+fn set_leader(leader: bool, identity: &str) {
+    let changed = leadership().send_if_modified(|current| {
+        let changed = *current != leader;
+        *current = leader;
+        changed
+    });
+    if changed {
+        if leader {
+            info!(identity, "Acquired collector leadership");
+        } else {
+            warn!(identity, "Lost collector leadership");
+        }
+    }
+}
+
+/// Keeps acquiring/renewing the lease until shutdown, then releases it so a
+/// peer can take over without waiting for expiry.
+pub async fn run_leader_election(cfg: &LeaderElectionConfig, shutdown: &mut broadcast::Receiver<()>) {
+    if !cfg.enabled {
+        set_leader(true, &cfg.identity);
+        return;
+    }
+
+    info!(
+        lease = %cfg.lease_name,
+        namespace = %cfg.lease_namespace,
+        identity = %cfg.identity,
+        "Leader election enabled"
+    );
+
+    let client = loop {
+        match build_kube_client().await {
+            Ok(c) => break c,
+            Err(e) => {
+                warn!(?e, "Leader election: failed to build K8s client, retrying");
+                tokio::select! {
+                    _ = tokio::time::sleep(RETRY_INTERVAL) => {}
+                    _ = shutdown.recv() => return,
+                }
+            }
+        }
+    };
+    let api: Api<Lease> = Api::namespaced(client, &cfg.lease_namespace);
+
+    let mut ticker = interval(RETRY_INTERVAL);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                match try_acquire_or_renew(&api, cfg, Utc::now()).await {
+                    Ok(leader) => set_leader(leader, &cfg.identity),
+                    Err(e) => {
+                        // Can't prove we still hold it; step down rather than risk two writers
+                        warn!(?e, "Leader election: lease update failed");
+                        set_leader(false, &cfg.identity);
+                    }
+                }
+            }
+            _ = shutdown.recv() => {
+                if is_leader() {
+                    if let Err(e) = release(&api, cfg).await {
+                        warn!(?e, "Leader election: failed to release lease");
+                    }
+                    set_leader(false, &cfg.identity);
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// One election round. Returns whether we hold the lease afterwards.
+async fn try_acquire_or_renew(api: &Api<Lease>, cfg: &LeaderElectionConfig, now: DateTime<Utc>) -> Result<bool> {
+    let Some(mut lease) = api.get_opt(&cfg.lease_name).await? else {
+        let lease = Lease {
+            metadata: ObjectMeta {
+                name: Some(cfg.lease_name.clone()),
+                namespace: Some(cfg.lease_namespace.clone()),
+                ..Default::default()
+            },
+            spec: Some(held_spec(&cfg.identity, now, None)),
+        };
+        return match api.create(&PostParams::default(), &lease).await {
+            Ok(_) => Ok(true),
+            // Another replica created it first
+            Err(kube::Error::Api(ae)) if ae.code == 409 => Ok(false),
+            Err(e) => Err(e.into()),
+        };
+    };
+
+    let spec = lease.spec.clone().unwrap_or_default();
+    if !can_hold(&spec, &cfg.identity, now) {
+        return Ok(false);
+    }
+
+    let held_by_us = spec.holder_identity.as_deref() == Some(cfg.identity.as_str());
+    let acquired_at = if held_by_us { spec.acquire_time.map(|t| t.0) } else { None };
+    let mut next = held_spec(&cfg.identity, now, acquired_at);
+    next.lease_transitions = Some(spec.lease_transitions.unwrap_or(0) + i32::from(!held_by_us));
+    lease.spec = Some(next);
+
+    // `replace` carries the resourceVersion we read, so a concurrent winner makes this 409
+    match api.replace(&cfg.lease_name, &PostParams::default(), &lease).await {
+        Ok(_) => Ok(true),
+        Err(kube::Error::Api(ae)) if ae.code == 409 => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Clears the holder so followers can acquire on their next round.
+async fn release(api: &Api<Lease>, cfg: &LeaderElectionConfig) -> Result<()> {
+    let Some(mut lease) = api.get_opt(&cfg.lease_name).await? else {
+        return Ok(());
+    };
+    let mut spec = lease.spec.clone().unwrap_or_default();
+    if spec.holder_identity.as_deref() != Some(cfg.identity.as_str()) {
+        return Ok(());
+    }
+    spec.holder_identity = None;
+    lease.spec = Some(spec);
+    api.replace(&cfg.lease_name, &PostParams::default(), &lease).await?;
+    info!(identity = %cfg.identity, "Released collector leadership");
+    Ok(())
+}
+
+fn held_spec(identity: &str, now: DateTime<Utc>, acquired_at: Option<DateTime<Utc>>) -> LeaseSpec {
+    LeaseSpec {
+        holder_identity: Some(identity.to_string()),
+        lease_duration_seconds: Some(LEASE_DURATION_SECS),
+        acquire_time: Some(MicroTime(acquired_at.unwrap_or(now))),
+        renew_time: Some(MicroTime(now)),
+        lease_transitions: Some(0),
+        ..Default::default()
+    }
+}
+
+/// We may write the lease if we already hold it, nobody does, or the holder
+/// stopped renewing.
+fn can_hold(spec: &LeaseSpec, identity: &str, now: DateTime<Utc>) -> bool {
+    let holder = spec.holder_identity.as_deref().unwrap_or("");
+    if holder.is_empty() || holder == identity {
+        return true;
+    }
+
+    let duration = ChronoDuration::seconds(spec.lease_duration_seconds.unwrap_or(LEASE_DURATION_SECS) as i64);
+    match &spec.renew_time {
+        Some(renewed) => renewed.0 + duration < now,
+        None => true,
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn spec(holder: &str, renewed: DateTime<Utc>) -> LeaseSpec {
+        LeaseSpec {
+            holder_identity: Some(holder.to_string()),
+            lease_duration_seconds: Some(15),
+            renew_time: Some(MicroTime(renewed)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn live_lease_of_another_replica_is_respected() {
+        let now = Utc.with_ymd_and_hms(2025, 5, 1, 12, 0, 10).unwrap();
+        let renewed = Utc.with_ymd_and_hms(2025, 5, 1, 12, 0, 0).unwrap();
+
+        assert!(!can_hold(&spec("rustcost-a", renewed), "rustcost-b", now));
+        assert!(can_hold(&spec("rustcost-b", renewed), "rustcost-b", now));
+    }
+
+    #[test]
+    fn expired_or_released_lease_can_be_taken() {
+        let now = Utc.with_ymd_and_hms(2025, 5, 1, 12, 0, 30).unwrap();
+        let renewed = Utc.with_ymd_and_hms(2025, 5, 1, 12, 0, 0).unwrap();
+
+        assert!(can_hold(&spec("rustcost-a", renewed), "rustcost-b", now));
+        assert!(can_hold(&LeaseSpec::default(), "rustcost-b", now));
+    }
+}
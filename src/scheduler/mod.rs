@@ -1,5 +1,8 @@
 pub mod schedule;
 pub mod tasks;
+pub mod job_tracker;
+pub mod checkpoint;
+pub mod leader;
 
 
-pub use crate::scheduler::schedule::scheduler_start_all_tasks;
+pub use crate::scheduler::schedule::{scheduler_start_all_tasks, scheduler_start_reader_tasks};
@@ -2,11 +2,45 @@ use super::tasks::{day_task, hour_task, minute_task};
 // src/scheduler/schedule.rs
 use anyhow::Result;
 use chrono::{Timelike, Utc};
-use tokio::sync::broadcast;
+use std::sync::OnceLock;
+use tokio::sync::{broadcast, watch};
 use tokio::time::{interval, sleep, Duration, MissedTickBehavior};
 use tracing::{debug, error, info, warn};
 use chrono::{Duration as ChronoDuration};
 use crate::app_state::AppState;
+use crate::scheduler::checkpoint::catch_up_missed_windows;
+use crate::scheduler::job_tracker::track_job;
+use crate::scheduler::leader::{self, is_leader, run_leader_election};
+use crate::config::config;
+use crate::scheduler::tasks::info::k8s_watch::task::run_k8s_watch_loop;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
+use crate::core::persistence::metrics::metric_partition_recovery::recover_metric_partitions;
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+/// Whether this leadership term's partition recovery has finished. Cleared on
+/// every takeover so collection and aggregation never append to a partition
+/// a previous leader left torn.
+fn partitions_recovered() -> &'static watch::Sender<bool> {
+    static RECOVERED: OnceLock<watch::Sender<bool>> = OnceLock::new();
+    RECOVERED.get_or_init(|| watch::channel(false).0)
+}
+
+/// Leader whose partitions are safe to append to.
+fn may_write() -> bool {
+    is_leader() && *partitions_recovered().borrow()
+}
+
+/// Repairs partitions torn by a crash. Only the leader runs it, so a starting
+/// standby never truncates the tail the live leader is appending.
+async fn recover_partitions() {
+    let metric_root = get_rustcost_base_path().join("metric");
+    match tokio::task::spawn_blocking(move || recover_metric_partitions(&metric_root)).await {
+        Ok(Err(e)) => error!(?e, "Metric partition recovery failed"),
+        Err(e) => error!(?e, "Metric partition recovery panicked"),
+        Ok(Ok(_)) => {}
+    }
+}
 
 /// Entry point — start all periodic background tasks.
 /// Call this once from your main() function.
@@ -19,12 +53,46 @@ pub async fn scheduler_start_all_tasks(
     let mut s1 = shutdown.resubscribe();
     let mut s2 = shutdown.resubscribe();
     let mut s3 = shutdown.resubscribe();
+    let mut s4 = shutdown.resubscribe();
+
+    let mut s5 = shutdown.resubscribe();
+
+    // Leader election; only the leader collects, aggregates and writes info files.
+    // With election off this process leads at once, before the watchers start.
+    let election = config().await.leader_election();
+    if election.enabled {
+        tokio::spawn(async move {
+            run_leader_election(election, &mut s5).await;
+        });
+    } else {
+        run_leader_election(election, &mut s5).await;
+    }
+
+    // Each time we take over: repair torn partitions before anything appends,
+    // then aggregate windows that closed while no replica was leading
+    tokio::spawn(state.scoped(async {
+        let mut leader = leader::subscribe();
+        loop {
+            if leader.wait_for(|l| *l).await.is_err() {
+                break;
+            }
+            recover_partitions().await;
+            partitions_recovered().send_replace(true);
+            if let Err(e) = track_job("aggregation_catch_up", catch_up_missed_windows(Utc::now())).await {
+                error!(?e, "Aggregation catch-up failed");
+            }
+            if leader.wait_for(|l| !*l).await.is_err() {
+                break;
+            }
+            partitions_recovered().send_replace(false);
+        }
+    }));
 
     // Minute loop
     tokio::spawn({
         let state = state.clone();  // ✔ each spawn gets its own clone
         async move {
-            run_minute_loop(state, &mut s1).await;
+            state.scoped(run_minute_loop(state.clone(), &mut s1)).await;
         }
     });
 
@@ -32,7 +100,7 @@ pub async fn scheduler_start_all_tasks(
     tokio::spawn({
         let state = state.clone();  // ✔ another clone
         async move {
-            run_hour_loop(state, &mut s2).await;
+            state.scoped(run_hour_loop(state.clone(), &mut s2)).await;
         }
     });
 
@@ -40,7 +108,15 @@ pub async fn scheduler_start_all_tasks(
     tokio::spawn({
         let state = state.clone();  // ✔ another clone
         async move {
-            run_day_loop(state, &mut s3).await;
+            state.scoped(run_day_loop(state.clone(), &mut s3)).await;
+        }
+    });
+
+    // K8s watchers (incremental info sync + periodic full reconcile)
+    tokio::spawn({
+        let state = state.clone();
+        async move {
+            state.scoped(run_k8s_watch_loop(state.clone(), &mut s4)).await;
         }
     });
 
@@ -48,28 +124,52 @@ pub async fn scheduler_start_all_tasks(
     let _ = shutdown.recv().await;
 }
 
-/// Runs every aligned minute (e.g., 12:00:00, 12:01:00 …)
+/// Entry point for a `ROLE=reader` replica: no collection, aggregation or
+/// cleanup, only the K8s watchers that keep the in-memory runtime state fresh.
+pub async fn scheduler_start_reader_tasks(
+    state: AppState,
+    mut shutdown: broadcast::Receiver<()>
+) {
+    info!("Starting reader tasks (collection and aggregation disabled)...");
+    state.scoped(run_k8s_watch_loop(state.clone(), &mut shutdown)).await;
+}
+
+/// Runs the collection loop every `scrape_interval_sec`, aligned to wall-clock
+/// multiples of the interval (e.g. 12:00:00, 12:00:30 … for 30s).
+/// The interval is re-read after each run, so setting changes apply without restart.
 pub async fn run_minute_loop(
     state: AppState,
     shutdown: &mut broadcast::Receiver<()>
 ) {
-    align_to_next_minute().await;
-    let mut ticker = interval(Duration::from_secs(60));
+    let mut interval_sec = collection_interval_sec();
+    align_to_next_interval(interval_sec).await;
+    let mut ticker = interval(Duration::from_secs(interval_sec));
     ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
     loop {
         tokio::select! {
             _ = ticker.tick() => {
-                let state_clone = state.clone();
-                let task = {
-                    let state = state.clone();
-                    move || {
-                        let state2 = state.clone();
-                        minute_task(state2)
+                // Followers skip collection but keep tracking the interval
+                if may_write() {
+                    let task = {
+                        let state = state.clone();
+                        move || {
+                            let state2 = state.clone();
+                            minute_task(state2)
+                        }
+                    };
+                    if let Err(e) = retry_task("minute", task).await {
+                        error!(?e, "minute_task failed");
                     }
-                };
-                if let Err(e) = retry_task("minute", task).await {
-                    error!(?e, "minute_task failed");
+                }
+
+                let next = collection_interval_sec();
+                if next != interval_sec {
+                    info!(from = interval_sec, to = next, "Scrape interval changed");
+                    interval_sec = next;
+                    align_to_next_interval(interval_sec).await;
+                    ticker = interval(Duration::from_secs(interval_sec));
+                    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
                 }
             }
             _ = shutdown.recv() => {
@@ -80,6 +180,13 @@ pub async fn run_minute_loop(
     }
 }
 
+fn collection_interval_sec() -> u64 {
+    InfoSettingFsAdapter::new()
+        .read()
+        .unwrap_or_default()
+        .effective_scrape_interval_sec() as u64
+}
+
 /// Runs an hour loop that fires at HH:00:30 each hour (e.g., 01:00:30, 02:00:30 …)
 pub async fn run_hour_loop(state: AppState, shutdown: &mut broadcast::Receiver<()>) {
     align_to_next_hour_plus_30s().await;
@@ -90,7 +197,14 @@ pub async fn run_hour_loop(state: AppState, shutdown: &mut broadcast::Receiver<(
     loop {
         tokio::select! {
             _ = ticker.tick() => {
-                if let Err(e) = retry_task("hour", hour_task).await {
+                if !may_write() {
+                    continue;
+                }
+                let task = {
+                    let state = state.clone();
+                    move || hour_task(state.clone())
+                };
+                if let Err(e) = retry_task("hour", task).await {
                     error!(?e, "hour_task failed");
                 }
             }
@@ -112,6 +226,9 @@ pub async fn run_day_loop(state: AppState, shutdown: &mut broadcast::Receiver<()
     loop {
         tokio::select! {
             _ = ticker.tick() => {
+                if !may_write() {
+                    continue;
+                }
                 if let Err(e) = retry_task("day", day_task).await {
                     error!(?e, "day_task failed");
                 }
@@ -128,12 +245,14 @@ pub async fn run_day_loop(state: AppState, shutdown: &mut broadcast::Receiver<()
 // Alignment helpers
 //
 
-async fn align_to_next_minute() {
+async fn align_to_next_interval(interval_sec: u64) {
     let now = Utc::now();
-    let wait = 60 - now.second() as u64;
-    if wait < 60 {
-        info!(wait_sec = wait, "Aligning to next minute");
-        sleep(Duration::from_secs(wait)).await;
+    let into = (now.timestamp() as u64) % interval_sec;
+    if into != 0 || now.nanosecond() != 0 {
+        let wait = Duration::from_secs(interval_sec - into)
+            .saturating_sub(Duration::from_nanos(now.nanosecond() as u64 % 1_000_000_000));
+        info!(wait_sec = wait.as_secs(), interval_sec, "Aligning to next collection tick");
+        sleep(wait).await;
     }
 }
 
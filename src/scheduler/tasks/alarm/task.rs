@@ -1,62 +1,64 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use std::collections::HashSet;
-use std::sync::{Mutex, OnceLock};
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::app_state::AppState;
 use crate::core::persistence::info::fixed::alerts::alert_rule_entity::{
-    AlertMetricType, AlertRuleEntity, AlertSeverity,
+    AlertChannel, AlertRuleEntity, AlertSeverity,
 };
-use crate::domain::alert::alert_rule_evaluator::{AlertMetricSnapshot, AlertRuleEvaluator};
+use crate::core::persistence::info::fixed::alerts::info_alert_entity::InfoAlertEntity;
+use crate::domain::alert::alert_rule_evaluator::AlertMetricSnapshot;
 use crate::domain::alert::discord_webhook_sender::DiscordWebhookSender;
+use crate::domain::alert::dto::alert_firing_dto::AlertFiringDto;
+use crate::domain::alert::efficiency_drop::evaluate_efficiency_rules;
+use crate::domain::alert::webhook_text_sender::WebhookTextSender;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
 
-static EVALUATOR: OnceLock<Mutex<AlertRuleEvaluator>> = OnceLock::new();
-
 pub async fn handle_alarm(
     state: &AppState,
     summary: &Summary,
     now: DateTime<Utc>,
 ) -> Result<()> {
     let alert_cfg = state.info_service.get_info_alerts().await?;
+    let node = summary.node.node_name.as_str();
 
     let snapshot = build_snapshot(summary);
-    debug!(?snapshot, "alert_snapshot_built");
-
-    let (triggered, active_conditions): (Vec<AlertRuleEntity>, HashSet<String>) = {
-        let evaluator = EVALUATOR.get_or_init(|| Mutex::new(AlertRuleEvaluator::default()));
-        let mut guard = evaluator.lock().unwrap();
-        let outcome = guard.evaluate(&alert_cfg.rules, &snapshot, now);
-        debug!(
-            triggered_ids = ?outcome.triggered.iter().map(|r| &r.id).collect::<Vec<_>>(),
-            active_ids = ?outcome.active_conditions,
-            "alert_rules_evaluated"
-        );
-        (outcome.triggered, outcome.active_conditions)
-    };
+    debug!(node, ?snapshot, "alert_snapshot_built");
 
-    for rule in triggered.iter() {
-        let message = format_rule_message(rule, &snapshot);
-        debug!(rule_id = %rule.id, severity = ?rule.severity, "alert_rule_triggered");
+    let outcome = {
+        let mut evaluator = state.alert_rules.lock().unwrap();
+        evaluator.evaluate(&alert_cfg, node, &snapshot, now)
+    };
+    debug!(
+        node,
+        firing = ?outcome.firing.iter().map(|f| &f.alert_id).collect::<Vec<_>>(),
+        resolved = ?outcome.resolved,
+        "alert_rules_evaluated"
+    );
+
+    for firing in &outcome.firing {
+        let Some(rule) = alert_cfg.rules.iter().find(|r| r.id == firing.rule_id) else {
+            continue;
+        };
         state
             .alerts
-            .fire_alert(rule.id.clone(), message.clone(), severity_str(&rule.severity))
+            .fire_alert(
+                firing.alert_id.clone(),
+                format_rule_message(rule, firing),
+                severity_str(&rule.severity),
+            )
             .await;
+    }
 
-        if let Some(url) = alert_cfg.discord_webhook_url.as_deref() {
-            let sender = DiscordWebhookSender::default();
-            debug!(rule_id = %rule.id, "sending_discord_webhook");
-            if let Err(err) = sender.send(url, rule, &message).await {
-                tracing::warn!(error = ?err, "Failed to send Discord webhook alert");
-            }
+    for firing in &outcome.notify {
+        if let Some(rule) = alert_cfg.rules.iter().find(|r| r.id == firing.rule_id) {
+            debug!(alert_id = %firing.alert_id, severity = ?rule.severity, "alert_rule_triggered");
+            deliver(&alert_cfg, firing, &format_rule_message(rule, firing)).await;
         }
     }
 
-    for rule in alert_cfg.rules.iter().filter(|r| r.enabled) {
-        if !active_conditions.contains(&rule.id) {
-            state.alerts.resolve_alert(&rule.id).await;
-        }
+    for id in &outcome.resolved {
+        state.alerts.resolve_alert(id).await;
     }
 
     // Legacy heuristic alarms (kept until rules replace them fully)
@@ -67,6 +69,68 @@ pub async fn handle_alarm(
     Ok(())
 }
 
+/// Evaluates efficiency-drop rules over the hours completed before `now`.
+pub async fn handle_efficiency_alarm(state: &AppState, now: DateTime<Utc>) -> Result<()> {
+    let alert_cfg = state.info_service.get_info_alerts().await?;
+    let firing = evaluate_efficiency_rules(&alert_cfg, now).await?;
+
+    let outcome = state.alert_rules.lock().unwrap().update_workload_firing(firing);
+    debug!(
+        firing = ?outcome.firing.iter().map(|f| &f.alert_id).collect::<Vec<_>>(),
+        resolved = ?outcome.resolved,
+        "efficiency_rules_evaluated"
+    );
+
+    for firing in &outcome.firing {
+        state
+            .alerts
+            .fire_alert(
+                firing.alert_id.clone(),
+                format_efficiency_message(firing),
+                severity_str(&firing.severity),
+            )
+            .await;
+    }
+
+    for firing in &outcome.notify {
+        deliver(&alert_cfg, firing, &format_efficiency_message(firing)).await;
+    }
+
+    for id in &outcome.resolved {
+        state.alerts.resolve_alert(id).await;
+    }
+
+    Ok(())
+}
+
+/// Sends to every configured webhook the alert's channel includes.
+async fn deliver(cfg: &InfoAlertEntity, firing: &AlertFiringDto, message: &str) {
+    let (title, severity, channel) = (&firing.rule_name, &firing.severity, &firing.channel);
+
+    let discord = cfg
+        .discord_webhook_url
+        .as_deref()
+        .filter(|_| channel.includes(&AlertChannel::Discord));
+    if let Some(url) = discord {
+        if let Err(err) = DiscordWebhookSender::default().send(url, title, severity, message).await {
+            warn!(error = ?err, "Failed to send Discord webhook alert");
+        }
+    }
+
+    let text_hooks = [
+        (AlertChannel::Slack, cfg.slack_webhook_url.as_deref()),
+        (AlertChannel::Teams, cfg.teams_webhook_url.as_deref()),
+    ];
+    for (hook, url) in text_hooks {
+        let Some(url) = url.filter(|_| channel.includes(&hook)) else {
+            continue;
+        };
+        if let Err(err) = WebhookTextSender::default().send(url, title, severity, message).await {
+            warn!(error = ?err, channel = hook.as_code(), "Failed to send webhook alert");
+        }
+    }
+}
+
 fn build_snapshot(summary: &Summary) -> AlertMetricSnapshot {
     let mem = &summary.node.memory;
     let working = mem.working_set_bytes.or(mem.usage_bytes);
@@ -99,32 +163,29 @@ fn build_snapshot(summary: &Summary) -> AlertMetricSnapshot {
     }
 }
 
-fn format_rule_message(rule: &AlertRuleEntity, snapshot: &AlertMetricSnapshot) -> String {
-    let value = metric_value(rule.metric_type.clone(), snapshot);
-    match value {
-        Some(v) => format!(
-            "{}: observed {:.1}% {} (rule {} {:.1}% for {}s)",
-            rule.name,
-            v,
-            rule.metric_type.as_code(),
-            rule.operator.as_code(),
-            rule.threshold,
-            rule.for_duration_sec
-        ),
-        None => format!(
-            "{} triggered (metric unavailable for display, threshold {:.1})",
-            rule.name, rule.threshold
-        ),
-    }
+fn format_rule_message(rule: &AlertRuleEntity, firing: &AlertFiringDto) -> String {
+    format!(
+        "{} on {}: observed {:.1}% {} (rule {} {:.1}% for {}s)",
+        rule.name,
+        firing.target,
+        firing.value,
+        rule.metric_type.as_code(),
+        rule.operator.as_code(),
+        rule.threshold,
+        rule.for_duration_sec
+    )
 }
 
-fn metric_value(metric: AlertMetricType, snapshot: &AlertMetricSnapshot) -> Option<f64> {
-    match metric {
-        AlertMetricType::CpuUsagePercent => snapshot.cpu_usage_percent,
-        AlertMetricType::MemoryUsagePercent => snapshot.memory_usage_percent,
-        AlertMetricType::DiskUsagePercent => snapshot.disk_usage_percent,
-        AlertMetricType::GpuUsagePercent => snapshot.gpu_usage_percent,
-    }
+fn format_efficiency_message(firing: &AlertFiringDto) -> String {
+    format!(
+        "{} for {} {}: efficiency {:.1}% below {:.1}% since {}",
+        firing.rule_name,
+        firing.scope,
+        firing.target,
+        firing.value,
+        firing.threshold,
+        firing.pending_since.format("%Y-%m-%d %H:%M UTC")
+    )
 }
 
 fn severity_str(sev: &AlertSeverity) -> String {
@@ -1,14 +1,13 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_collector_repository_trait::MetricContainerMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
 
 pub struct MetricContainerMinuteCollectorRepositoryImpl {
-    pub adapter: MetricContainerMinuteFsAdapter,
+    pub adapter: Box<dyn MetricStore<MetricContainerEntity>>,
 }
 
 impl MetricContainerMinuteCollectorRepository for MetricContainerMinuteCollectorRepositoryImpl {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricContainerEntity> {
+        self.adapter.as_ref()
     }
 }
@@ -1,6 +1,5 @@
 use crate::core::persistence::info::k8s::container::info_container_collector_repository_trait::InfoContainerCollectorRepository;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_collector_repository_trait::MetricContainerMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
 use crate::scheduler::tasks::collectors::k8s::container::metric_container_minute_collector_repository::MetricContainerMinuteCollectorRepositoryImpl;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
 use anyhow::Result;
@@ -8,6 +7,7 @@ use chrono::{DateTime, Utc};
 use crate::scheduler::tasks::collectors::k8s::container::info_container_minute_collector_mapper::map_container_summary_to_info;
 use crate::scheduler::tasks::collectors::k8s::container::info_container_minute_collector_repository::InfoContainerCollectorRepositoryImpl;
 use crate::scheduler::tasks::collectors::k8s::container::metric_container_minute_collector_mapper::map_container_summary_to_metrics;
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::metric_container_minute_adapter;
 
 /// Collects container-level info and metrics from the node summary.
 pub async fn handle_container(summary: &Summary, now: DateTime<Utc>) -> Result<bool> {
@@ -57,10 +57,11 @@ pub async fn handle_container(summary: &Summary, now: DateTime<Utc>) -> Result<b
 
             // ---- Metrics section ----
             let metric_repo = MetricContainerMinuteCollectorRepositoryImpl {
-                adapter: MetricContainerMinuteFsAdapter,
+                adapter: metric_container_minute_adapter(),
             };
             let metrics_dto = map_container_summary_to_metrics(container, now);
             metric_repo.append_row(&container_key, &metrics_dto, now)?;
+            crate::core::self_metrics::add_rows_written("container", 1);
         }
     }
 
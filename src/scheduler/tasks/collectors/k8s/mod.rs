@@ -8,3 +8,5 @@ pub mod summary_dto;
 pub mod node;
 mod pod;
 mod container;
+pub mod pvc;
+pub mod source;
@@ -1,5 +1,7 @@
 /* Maps kubelet Summary DTO → internal models */
 
+use crate::core::client::kube_resources::Node;
+use crate::core::client::mappers::{quantity_to_bytes, quantity_to_cores};
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::{NetworkStats, Summary};
@@ -46,9 +48,38 @@ pub fn map_summary_to_metrics(summary: &Summary, now: DateTime<Utc>) -> MetricNo
         fs_capacity_bytes: n.fs.as_ref().and_then(|x| x.capacity_bytes),
         fs_inodes_used: n.fs.as_ref().and_then(|x| x.inodes_used),
         fs_inodes: n.fs.as_ref().and_then(|x| x.inodes),
+
+        // Saturation (from the Node object, see `apply_node_status`)
+        ..Default::default()
     }
 }
 
+/// Fills allocatable capacity and pressure conditions from the Node object
+/// listed alongside the summary. Leaves them `None` when the status lacks them.
+pub fn apply_node_status(metrics: &mut MetricNodeEntity, node: &Node) {
+    let Some(status) = node.status.as_ref() else {
+        return;
+    };
+
+    let allocatable = status.allocatable.as_ref();
+    metrics.cpu_allocatable_nano_cores = allocatable
+        .and_then(|a| a.get("cpu"))
+        .and_then(|q| quantity_to_cores(&q.0))
+        .map(|cores| (cores * 1e9).round() as u64);
+    metrics.memory_allocatable_bytes = allocatable
+        .and_then(|a| a.get("memory"))
+        .and_then(|q| quantity_to_bytes(&q.0));
+
+    let pressure = |kind: &str| {
+        status.conditions.as_ref()?
+            .iter()
+            .find(|c| c.type_ == kind)
+            .map(|c| if c.status == "True" { 100 } else { 0 })
+    };
+    metrics.memory_pressure_percent = pressure("MemoryPressure");
+    metrics.disk_pressure_percent = pressure("DiskPressure");
+}
+
 fn sum_network_interfaces(net: &NetworkStats) -> Option<(Option<u64>, Option<u64>, Option<u64>, Option<u64>)> {
     net.interfaces.as_ref().map(|interfaces| {
         let (rx, tx, rx_err, tx_err) = interfaces.iter().fold((0, 0, 0, 0), |acc, iface| {
@@ -62,3 +93,44 @@ fn sum_network_interfaces(net: &NetworkStats) -> Option<(Option<u64>, Option<u64
         (Some(rx), Some(tx), Some(rx_err), Some(tx_err))
     })
 }
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{NodeCondition, NodeStatus};
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn node_status_sets_allocatable_and_pressure() {
+        let condition = |kind: &str, status: &str| NodeCondition {
+            type_: kind.to_string(),
+            status: status.to_string(),
+            ..Default::default()
+        };
+        let node = Node {
+            status: Some(NodeStatus {
+                allocatable: Some(BTreeMap::from([
+                    ("cpu".to_string(), Quantity("3500m".to_string())),
+                    ("memory".to_string(), Quantity("8Gi".to_string())),
+                ])),
+                conditions: Some(vec![
+                    condition("Ready", "True"),
+                    condition("MemoryPressure", "True"),
+                    condition("DiskPressure", "False"),
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut metrics = MetricNodeEntity::default();
+        apply_node_status(&mut metrics, &node);
+        assert_eq!(metrics.cpu_allocatable_nano_cores, Some(3_500_000_000));
+        assert_eq!(metrics.memory_allocatable_bytes, Some(8 * 1024 * 1024 * 1024));
+        assert_eq!(metrics.memory_pressure_percent, Some(100));
+        assert_eq!(metrics.disk_pressure_percent, Some(0));
+    }
+}
@@ -1,14 +1,13 @@
-use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_store_trait::MetricStore;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_collector_repository_trait::MetricNodeMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_adapter::MetricNodeMinuteFsAdapter;
 
 pub struct MetricNodeMinuteCollectorRepositoryImpl {
-    pub adapter: MetricNodeMinuteFsAdapter,
+    pub adapter: Box<dyn MetricStore<MetricNodeEntity>>,
 }
 
 impl MetricNodeMinuteCollectorRepository for MetricNodeMinuteCollectorRepositoryImpl {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+    fn fs_adapter(&self) -> &dyn MetricStore<MetricNodeEntity> {
+        self.adapter.as_ref()
     }
 }
@@ -1,15 +1,15 @@
 use chrono::{DateTime, Utc};
 use crate::core::persistence::info::k8s::node::info_node_collector_repository_trait::InfoNodeCollectorRepository;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_collector_repository_trait::MetricNodeMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_adapter::MetricNodeMinuteFsAdapter;
 use crate::scheduler::tasks::collectors::k8s::node::info_node_minute_collector_repository::InfoNodeCollectorRepositoryImpl;
-use crate::scheduler::tasks::collectors::k8s::node::mappers::{map_summary_to_metrics, map_summary_to_node_info};
+use crate::scheduler::tasks::collectors::k8s::node::mappers::{apply_node_status, map_summary_to_metrics, map_summary_to_node_info};
 use crate::core::client::mappers::map_node_to_info_entity;
 use crate::scheduler::tasks::collectors::k8s::node::metric_node_minute_collector_repository::MetricNodeMinuteCollectorRepositoryImpl;
 use crate::core::client::kube_resources::Node;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::metric_node_minute_adapter;
 
-pub async fn handle_node(summary: &Summary, now: DateTime<Utc>) -> Result<bool, anyhow::Error> {
+pub async fn handle_node(summary: &Summary, node: &Node, now: DateTime<Utc>) -> Result<bool, anyhow::Error> {
     let node_name = &summary.node.node_name;
 
     // Step 1: Write info.rci if missing
@@ -18,11 +18,13 @@ pub async fn handle_node(summary: &Summary, now: DateTime<Utc>) -> Result<bool,
     let created = info_repo.create_if_missing(node_name, &node_info)?;
 
     // Step 2: Append metrics
-    let metrics_dto = map_summary_to_metrics(summary, now);
+    let mut metrics_dto = map_summary_to_metrics(summary, now);
+    apply_node_status(&mut metrics_dto, node);
     let metric_repo = MetricNodeMinuteCollectorRepositoryImpl {
-        adapter: MetricNodeMinuteFsAdapter,
+        adapter: metric_node_minute_adapter(),
     };
     metric_repo.append_row(node_name, &metrics_dto, now)?; // ✅ correct method
+    crate::core::self_metrics::add_rows_written("node", 1);
 
     Ok(created)
 }
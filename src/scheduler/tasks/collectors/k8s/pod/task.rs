@@ -1,6 +1,5 @@
 use crate::core::persistence::info::k8s::pod::info_pod_collector_repository_trait::InfoPodCollectorRepository;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_collector_repository_trait::MetricPodMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
 use crate::scheduler::tasks::collectors::k8s::pod::info_pod_minute_collector_mapper::map_pod_summary_to_info;
 use crate::scheduler::tasks::collectors::k8s::pod::info_pod_minute_collector_repository::InfoPodCollectorRepositoryImpl;
 use crate::scheduler::tasks::collectors::k8s::pod::metric_pod_minute_collector_mapper::map_pod_summary_to_metrics;
@@ -8,6 +7,7 @@ use crate::scheduler::tasks::collectors::k8s::pod::metric_pod_minute_collector_r
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::metric_pod_minute_adapter;
 
 pub async fn handle_pod(summary: &Summary, now: DateTime<Utc>) -> Result<bool> {
     let mut any_created = false;
@@ -38,10 +38,11 @@ pub async fn handle_pod(summary: &Summary, now: DateTime<Utc>) -> Result<bool> {
 
         // ---- Metrics section ----
         let metric_repo = MetricPodMinuteCollectorRepositoryImpl {
-            adapter: MetricPodMinuteFsAdapter,
+            adapter: metric_pod_minute_adapter(),
         };
         let metrics_dto = map_pod_summary_to_metrics(pod, now);
         metric_repo.append_row(pod_uid, &metrics_dto, now)?;
+        crate::core::self_metrics::add_rows_written("pod", 1);
     }
 
     Ok(any_created)
@@ -0,0 +1,126 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tracing::{debug, error, warn};
+
+use crate::core::client::kube_client::build_kube_client;
+use crate::core::client::kube_resources::PersistentVolumeClaim;
+use crate::core::client::mappers::quantity_to_bytes;
+use crate::core::client::nodes::{fetch_node_names, fetch_node_summary};
+use crate::core::client::other_resources::fetch_persistent_volume_claims;
+use crate::core::persistence::metrics::k8s::pvc::hour::metric_pvc_hour_repository::MetricPvcHourRepository;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::{metric_pvc_key, MetricPvcEntity};
+use crate::scheduler::job_tracker::{record_failure, record_processed};
+use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use crate::scheduler::tasks::utils::time_util::TimeUtils;
+
+/// Kubelet volume stats of a mounted claim.
+#[derive(Debug, Clone, Copy, Default)]
+struct VolumeUsage {
+    used_bytes: Option<u64>,
+    available_bytes: Option<u64>,
+}
+
+/// Samples every bound claim once per hour, stamped at the hour just closed like
+/// the aggregated hour rows. Claims no running pod mounts are still written
+/// (`mounted = false`), so orphaned volumes keep showing up in cost.
+pub async fn run(now: DateTime<Utc>) -> Result<()> {
+    let time = TimeUtils::previous_hour_window(now)?.1;
+    let client = build_kube_client().await?;
+
+    let claims = fetch_persistent_volume_claims(&client).await?;
+    if claims.is_empty() {
+        debug!("No persistent volume claims found");
+        return Ok(());
+    }
+
+    let usage = fetch_volume_usage(&client).await?;
+    let repo = MetricPvcHourRepository::new();
+
+    for claim in &claims {
+        let Some(row) = claim_row(claim, &usage, time) else {
+            continue;
+        };
+        let key = metric_pvc_key(
+            claim.metadata.namespace.as_deref().unwrap_or_default(),
+            claim.metadata.name.as_deref().unwrap_or_default(),
+        );
+
+        match repo.append_row(&key, &row, now) {
+            Ok(()) => record_processed(),
+            Err(e) => {
+                error!("❌ Failed to write PVC metrics for {}: {:?}", key, e);
+                record_failure(&key, &e);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Usage per `{namespace}_{claim}` from every node's `/stats/summary`.
+/// A node that can't be scraped only loses usage for its claims.
+async fn fetch_volume_usage(client: &kube::Client) -> Result<HashMap<String, VolumeUsage>> {
+    let mut usage = HashMap::new();
+
+    for node_name in fetch_node_names(client).await? {
+        let summary = match fetch_node_summary::<Summary>(client, &node_name).await {
+            Ok(s) => s,
+            Err(e) => {
+                warn!("Failed to fetch summary for {} while sampling PVCs: {:?}", node_name, e);
+                continue;
+            }
+        };
+
+        for pod in summary.pods.unwrap_or_default() {
+            for volume in pod.volume.unwrap_or_default() {
+                let Some(pvc) = volume.pvc_ref else { continue };
+                let namespace = pvc.namespace.unwrap_or_else(|| pod.pod_ref.namespace.clone());
+                let Some(name) = pvc.name else { continue };
+
+                // RWX claims mounted on several nodes report the same filesystem
+                usage.insert(
+                    metric_pvc_key(&namespace, &name),
+                    VolumeUsage {
+                        used_bytes: volume.used_bytes,
+                        available_bytes: volume.available_bytes,
+                    },
+                );
+            }
+        }
+    }
+    Ok(usage)
+}
+
+/// `None` for claims that aren't bound yet; pending claims have no volume to pay for.
+fn claim_row(
+    claim: &PersistentVolumeClaim,
+    usage: &HashMap<String, VolumeUsage>,
+    time: DateTime<Utc>,
+) -> Option<MetricPvcEntity> {
+    let status = claim.status.as_ref()?;
+    if status.phase.as_deref() != Some("Bound") {
+        return None;
+    }
+
+    let key = metric_pvc_key(claim.metadata.namespace.as_deref()?, claim.metadata.name.as_deref()?);
+    let mounted = usage.get(&key);
+
+    Some(MetricPvcEntity {
+        time,
+        capacity_bytes: status
+            .capacity
+            .as_ref()
+            .and_then(|c| c.get("storage"))
+            .and_then(|q| quantity_to_bytes(&q.0)),
+        requested_bytes: claim
+            .spec
+            .as_ref()
+            .and_then(|s| s.resources.as_ref())
+            .and_then(|r| r.requests.as_ref())
+            .and_then(|r| r.get("storage"))
+            .and_then(|q| quantity_to_bytes(&q.0)),
+        used_bytes: mounted.and_then(|u| u.used_bytes),
+        available_bytes: mounted.and_then(|u| u.available_bytes),
+        mounted: mounted.is_some(),
+    })
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use kube::Client;
+
+use crate::core::client::kube_resources::Node;
+use crate::core::client::nodes::fetch_node_summary;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+
+use super::MetricsSource;
+
+/// Kubelet `/stats/summary` through the API server node proxy (default source).
+pub struct KubeletSource {
+    client: Client,
+}
+
+impl KubeletSource {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl MetricsSource for KubeletSource {
+    fn name(&self) -> &'static str {
+        "kubelet"
+    }
+
+    async fn node_summary(&self, node: &Node) -> Result<Summary> {
+        let node_name = node.metadata.name.as_deref().unwrap_or_default();
+        let mut summary = fetch_node_summary::<Summary>(&self.client, node_name).await?;
+        summary.fill_missing_pod_usage();
+        Ok(summary)
+    }
+}
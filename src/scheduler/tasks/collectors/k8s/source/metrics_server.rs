@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use kube::Client;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+use crate::core::client::kube_resources::Node;
+use crate::core::client::mappers::{quantity_to_bytes, quantity_to_cores};
+use crate::core::client::pods::fetch_pods_by_node;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+
+use super::{usage_summary, ContainerUsage, MetricsSource, UsageSample};
+
+const METRICS_API: &str = "/apis/metrics.k8s.io/v1beta1";
+
+/// `metrics.k8s.io` (metrics-server): CPU rate and working set only.
+///
+/// Pod metrics carry no node, so the cluster-wide list is fetched once per
+/// source (i.e. once per collection cycle) and shared by every node.
+pub struct MetricsServerSource {
+    client: Client,
+    containers: OnceCell<ContainerUsage>,
+}
+
+impl MetricsServerSource {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            containers: OnceCell::new(),
+        }
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri(format!("{}{}", METRICS_API, path))
+            .body(vec![])
+            .map_err(|e| anyhow!("Failed to build request: {}", e))?;
+        let body = self.client.request_text(req).await?;
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    async fn container_usage(&self) -> Result<&ContainerUsage> {
+        self.containers
+            .get_or_try_init(|| async {
+                let list: PodMetricsList = self.get("/pods").await?;
+                Ok(container_usage_from(list))
+            })
+            .await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct MetricsMeta {
+    name: String,
+    #[serde(default)]
+    namespace: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NodeMetrics {
+    #[serde(default)]
+    timestamp: Option<String>,
+    #[serde(default)]
+    usage: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PodMetricsList {
+    #[serde(default)]
+    items: Vec<PodMetrics>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PodMetrics {
+    metadata: MetricsMeta,
+    #[serde(default)]
+    containers: Vec<ContainerMetrics>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContainerMetrics {
+    name: String,
+    #[serde(default)]
+    usage: HashMap<String, String>,
+}
+
+fn usage_from(usage: &HashMap<String, String>) -> UsageSample {
+    UsageSample {
+        cpu_usage_nano_cores: usage
+            .get("cpu")
+            .and_then(|q| quantity_to_cores(q))
+            .map(|cores| (cores * 1e9).round() as u64),
+        cpu_usage_core_nano_seconds: None,
+        memory_working_set_bytes: usage.get("memory").and_then(|q| quantity_to_bytes(q)),
+    }
+}
+
+fn container_usage_from(list: PodMetricsList) -> ContainerUsage {
+    let mut out = ContainerUsage::new();
+    for pod in list.items {
+        let namespace = pod.metadata.namespace.unwrap_or_default();
+        for container in pod.containers {
+            out.insert(
+                (namespace.clone(), pod.metadata.name.clone(), container.name),
+                usage_from(&container.usage),
+            );
+        }
+    }
+    out
+}
+
+#[async_trait]
+impl MetricsSource for MetricsServerSource {
+    fn name(&self) -> &'static str {
+        "metrics-server"
+    }
+
+    async fn node_summary(&self, node: &Node) -> Result<Summary> {
+        let node_name = node.metadata.name.as_deref().unwrap_or_default();
+        let node_metrics: NodeMetrics = self.get(&format!("/nodes/{}", node_name)).await?;
+        let time = node_metrics
+            .timestamp
+            .as_deref()
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+
+        let pods = fetch_pods_by_node(&self.client, node_name).await?;
+        let containers = self.container_usage().await?;
+
+        Ok(usage_summary(node, usage_from(&node_metrics.usage), &pods, containers, time))
+    }
+}
@@ -0,0 +1,272 @@
+//! Pluggable sources of node/pod/container usage.
+//!
+//! The collector always persists a kubelet-shaped [`Summary`]; sources that cannot
+//! reach the kubelet summary API (restricted clusters) rebuild a reduced summary
+//! from the metrics-server API or a Prometheus endpoint instead.
+
+mod kubelet;
+mod metrics_server;
+mod prometheus;
+
+pub use kubelet::KubeletSource;
+pub use metrics_server::MetricsServerSource;
+pub use prometheus::PrometheusSource;
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use kube::Client;
+
+use crate::core::client::kube_resources::{Node, Pod};
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::{
+    ContainerSummary, CpuStats, MemoryStats, NodeSummary, PodRef, PodSummary, Summary,
+};
+
+/// Fetches one node's usage in `/stats/summary` shape.
+#[async_trait]
+pub trait MetricsSource: Send + Sync {
+    /// Short name used in logs.
+    fn name(&self) -> &'static str;
+
+    async fn node_summary(&self, node: &Node) -> Result<Summary>;
+}
+
+/// Value of the `metrics_source` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricsSourceKind {
+    Kubelet,
+    MetricsServer,
+    Prometheus,
+}
+
+impl MetricsSourceKind {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "" | "kubelet" => Some(MetricsSourceKind::Kubelet),
+            "metrics-server" | "metrics_server" => Some(MetricsSourceKind::MetricsServer),
+            "prometheus" => Some(MetricsSourceKind::Prometheus),
+            _ => None,
+        }
+    }
+}
+
+/// Source selected by the settings; unknown values fall back to the kubelet.
+pub fn metrics_source_from_settings(
+    settings: &InfoSettingEntity,
+    client: &Client,
+) -> Result<Box<dyn MetricsSource>> {
+    let kind = MetricsSourceKind::parse(&settings.metrics_source).unwrap_or(MetricsSourceKind::Kubelet);
+    Ok(match kind {
+        MetricsSourceKind::Kubelet => Box::new(KubeletSource::new(client.clone())),
+        MetricsSourceKind::MetricsServer => Box::new(MetricsServerSource::new(client.clone())),
+        MetricsSourceKind::Prometheus => {
+            let url = settings
+                .metrics_source_url
+                .as_deref()
+                .ok_or_else(|| anyhow!("metrics_source_url must be set for the prometheus metrics source"))?;
+            Box::new(PrometheusSource::new(client.clone(), url)?)
+        }
+    })
+}
+
+/// Usage figures a fallback source can provide for one node, pod or container.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UsageSample {
+    pub cpu_usage_nano_cores: Option<u64>,
+    pub cpu_usage_core_nano_seconds: Option<u64>,
+    pub memory_working_set_bytes: Option<u64>,
+}
+
+impl UsageSample {
+    fn add(&mut self, other: &UsageSample) {
+        add_to(&mut self.cpu_usage_nano_cores, other.cpu_usage_nano_cores);
+        add_to(&mut self.cpu_usage_core_nano_seconds, other.cpu_usage_core_nano_seconds);
+        add_to(&mut self.memory_working_set_bytes, other.memory_working_set_bytes);
+    }
+}
+
+fn add_to(slot: &mut Option<u64>, value: Option<u64>) {
+    if let Some(v) = value {
+        *slot = Some(slot.map_or(v, |cur| cur + v));
+    }
+}
+
+/// Container usage keyed by `(namespace, pod, container)`.
+pub type ContainerUsage = HashMap<(String, String, String), UsageSample>;
+
+/// Builds a summary for `node` from per-container usage.
+///
+/// Pods come from the API (the fallback sources carry no UIDs); pod usage is the
+/// sum of its containers, and pods without any sample are left out like the
+/// kubelet does for pods that have not started yet.
+pub fn usage_summary(
+    node: &Node,
+    node_usage: UsageSample,
+    pods: &[Pod],
+    containers: &ContainerUsage,
+    time: DateTime<Utc>,
+) -> Summary {
+    let time_str = time.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let node_start = node
+        .metadata
+        .creation_timestamp
+        .as_ref()
+        .map(|t| t.0.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .unwrap_or_else(|| time_str.clone());
+
+    let mut pod_summaries = Vec::new();
+    for pod in pods {
+        let (Some(name), Some(namespace), Some(uid)) = (
+            pod.metadata.name.as_ref(),
+            pod.metadata.namespace.as_ref(),
+            pod.metadata.uid.as_ref(),
+        ) else {
+            continue;
+        };
+        let start_time = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.start_time.as_ref())
+            .map(|t| t.0.to_rfc3339_opts(SecondsFormat::Secs, true))
+            .unwrap_or_else(|| node_start.clone());
+
+        let mut pod_usage = UsageSample::default();
+        let mut container_summaries = Vec::new();
+        for container in pod.spec.iter().flat_map(|s| s.containers.iter()) {
+            let key = (namespace.clone(), name.clone(), container.name.clone());
+            let Some(usage) = containers.get(&key) else {
+                continue;
+            };
+            pod_usage.add(usage);
+            container_summaries.push(ContainerSummary {
+                name: container.name.clone(),
+                start_time: start_time.clone(),
+                cpu: cpu_stats(usage, &time_str),
+                memory: memory_stats(usage, &time_str),
+                rootfs: None,
+                logs: None,
+                swap: None,
+            });
+        }
+        if container_summaries.is_empty() {
+            continue;
+        }
+
+        pod_summaries.push(PodSummary {
+            pod_ref: PodRef {
+                name: name.clone(),
+                namespace: namespace.clone(),
+                uid: uid.clone(),
+            },
+            start_time,
+            containers: container_summaries,
+            cpu: cpu_stats(&pod_usage, &time_str),
+            memory: memory_stats(&pod_usage, &time_str),
+            network: None,
+            ephemeral_storage: None,
+            volume: None,
+            process_stats: None,
+            swap: None,
+        });
+    }
+
+    Summary {
+        node: NodeSummary {
+            node_name: node.metadata.name.clone().unwrap_or_default(),
+            start_time: node_start,
+            system_containers: None,
+            cpu: cpu_stats(&node_usage, &time_str),
+            memory: memory_stats(&node_usage, &time_str),
+            network: None,
+            fs: None,
+            runtime: None,
+            rlimit: None,
+            swap: None,
+        },
+        pods: Some(pod_summaries),
+    }
+}
+
+fn cpu_stats(usage: &UsageSample, time: &str) -> CpuStats {
+    CpuStats {
+        time: time.to_string(),
+        usage_nano_cores: usage.cpu_usage_nano_cores,
+        usage_core_nano_seconds: usage.cpu_usage_core_nano_seconds,
+    }
+}
+
+fn memory_stats(usage: &UsageSample, time: &str) -> MemoryStats {
+    MemoryStats {
+        time: time.to_string(),
+        available_bytes: None,
+        usage_bytes: None,
+        working_set_bytes: usage.memory_working_set_bytes,
+        rss_bytes: None,
+        page_faults: None,
+        major_page_faults: None,
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::kube_resources::{K8sContainer as Container, ObjectMeta, PodSpec};
+
+    fn pod(name: &str, containers: &[&str]) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(name.into()),
+                namespace: Some("default".into()),
+                uid: Some(format!("uid-{}", name)),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: containers
+                    .iter()
+                    .map(|c| Container { name: c.to_string(), ..Default::default() })
+                    .collect(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn pod_usage_sums_containers_and_idle_pods_are_skipped() {
+        let node = Node {
+            metadata: ObjectMeta { name: Some("node-a".into()), ..Default::default() },
+            ..Default::default()
+        };
+        let sample = |cpu, mem| UsageSample {
+            cpu_usage_nano_cores: Some(cpu),
+            cpu_usage_core_nano_seconds: None,
+            memory_working_set_bytes: Some(mem),
+        };
+        let mut containers = ContainerUsage::new();
+        containers.insert(("default".into(), "web".into(), "app".into()), sample(100, 1_000));
+        containers.insert(("default".into(), "web".into(), "sidecar".into()), sample(20, 200));
+
+        let summary = usage_summary(
+            &node,
+            sample(500, 10_000),
+            &[pod("web", &["app", "sidecar"]), pod("pending", &["app"])],
+            &containers,
+            Utc::now(),
+        );
+
+        assert_eq!(summary.node.node_name, "node-a");
+        assert_eq!(summary.node.cpu.usage_nano_cores, Some(500));
+        let pods = summary.pods.unwrap();
+        assert_eq!(pods.len(), 1);
+        assert_eq!(pods[0].pod_ref.uid, "uid-web");
+        assert_eq!(pods[0].containers.len(), 2);
+        assert_eq!(pods[0].cpu.usage_nano_cores, Some(120));
+        assert_eq!(pods[0].memory.working_set_bytes, Some(1_200));
+        assert_eq!(pods[0].cpu.usage_core_nano_seconds, None);
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use kube::Client;
+use serde::Deserialize;
+
+use crate::core::client::kube_resources::Node;
+use crate::core::client::pods::fetch_pods_by_node;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+
+use super::{usage_summary, ContainerUsage, MetricsSource, UsageSample};
+
+const QUERY_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Window of the `rate()` used for CPU usage.
+const RATE_WINDOW: &str = "5m";
+
+/// cAdvisor series scraped by Prometheus (kube-prometheus labels: `node`,
+/// `namespace`, `pod`, `container`; `id="/"` is the node root cgroup).
+pub struct PrometheusSource {
+    client: Client,
+    http: reqwest::Client,
+    url: String,
+}
+
+impl PrometheusSource {
+    pub fn new(client: Client, url: &str) -> Result<Self> {
+        Ok(Self {
+            client,
+            http: reqwest::Client::builder().timeout(QUERY_TIMEOUT).build()?,
+            url: url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    async fn query(&self, query: &str) -> Result<Vec<PromSample>> {
+        let endpoint = format!("{}/api/v1/query", self.url);
+        let resp: PromResponse = self
+            .http
+            .get(&endpoint)
+            .query(&[("query", query)])
+            .send()
+            .await
+            .with_context(|| format!("Failed to query usage from {}", endpoint))?
+            .json()
+            .await
+            .context("Failed to parse usage query response")?;
+
+        if resp.status != "success" {
+            return Err(anyhow!("Usage query failed: {}", resp.error.unwrap_or(resp.status)));
+        }
+        Ok(resp.data.map(|d| d.result).unwrap_or_default())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PromResponse {
+    status: String,
+    #[serde(default)]
+    data: Option<PromData>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PromData {
+    #[serde(default)]
+    result: Vec<PromSample>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PromSample {
+    #[serde(default)]
+    metric: HashMap<String, String>,
+    /// `[unix_time, "value"]`
+    value: (f64, String),
+}
+
+impl PromSample {
+    fn as_u64(&self, scale: f64) -> Option<u64> {
+        let v = self.value.1.parse::<f64>().ok()?;
+        (v.is_finite() && v >= 0.0).then(|| (v * scale).round() as u64)
+    }
+}
+
+fn node_queries(node: &str) -> [String; 3] {
+    let sel = format!(r#"id="/",node="{}""#, node);
+    [
+        format!("sum(rate(container_cpu_usage_seconds_total{{{}}}[{}]))", sel, RATE_WINDOW),
+        format!("sum(container_cpu_usage_seconds_total{{{}}})", sel),
+        format!("sum(container_memory_working_set_bytes{{{}}})", sel),
+    ]
+}
+
+fn container_queries(node: &str) -> [String; 3] {
+    let sel = format!(r#"node="{}",container!="",container!="POD""#, node);
+    let by = "sum by (namespace, pod, container)";
+    [
+        format!("{} (rate(container_cpu_usage_seconds_total{{{}}}[{}]))", by, sel, RATE_WINDOW),
+        format!("{} (container_cpu_usage_seconds_total{{{}}})", by, sel),
+        format!("{} (container_memory_working_set_bytes{{{}}})", by, sel),
+    ]
+}
+
+/// Merges the CPU rate, CPU counter and working-set results into one sample per container.
+fn container_usage_from(cpu_rate: &[PromSample], cpu_total: &[PromSample], memory: &[PromSample]) -> ContainerUsage {
+    let mut out = ContainerUsage::new();
+    let mut merge = |samples: &[PromSample], apply: &dyn Fn(&mut UsageSample, &PromSample)| {
+        for s in samples {
+            let label = |k: &str| s.metric.get(k).cloned().unwrap_or_default();
+            let key = (label("namespace"), label("pod"), label("container"));
+            apply(out.entry(key).or_default(), s);
+        }
+    };
+    merge(cpu_rate, &|u, s| u.cpu_usage_nano_cores = s.as_u64(1e9));
+    merge(cpu_total, &|u, s| u.cpu_usage_core_nano_seconds = s.as_u64(1e9));
+    merge(memory, &|u, s| u.memory_working_set_bytes = s.as_u64(1.0));
+    out
+}
+
+#[async_trait]
+impl MetricsSource for PrometheusSource {
+    fn name(&self) -> &'static str {
+        "prometheus"
+    }
+
+    async fn node_summary(&self, node: &Node) -> Result<Summary> {
+        let node_name = node.metadata.name.as_deref().unwrap_or_default();
+        let time = Utc::now();
+
+        let [n_rate, n_total, n_mem] = node_queries(node_name);
+        let [c_rate, c_total, c_mem] = container_queries(node_name);
+        let (n_rate, n_total, n_mem, c_rate, c_total, c_mem, pods) = futures::try_join!(
+            self.query(&n_rate),
+            self.query(&n_total),
+            self.query(&n_mem),
+            self.query(&c_rate),
+            self.query(&c_total),
+            self.query(&c_mem),
+            fetch_pods_by_node(&self.client, node_name),
+        )?;
+
+        if n_rate.is_empty() && n_mem.is_empty() {
+            return Err(anyhow!("Prometheus has no cAdvisor series for node {}", node_name));
+        }
+
+        let first = |samples: &[PromSample], scale| samples.first().and_then(|s| s.as_u64(scale));
+        let node_usage = UsageSample {
+            cpu_usage_nano_cores: first(&n_rate, 1e9),
+            cpu_usage_core_nano_seconds: first(&n_total, 1e9),
+            memory_working_set_bytes: first(&n_mem, 1.0),
+        };
+        let containers = container_usage_from(&c_rate, &c_total, &c_mem);
+
+        Ok(usage_summary(node, node_usage, &pods, &containers, time))
+    }
+}
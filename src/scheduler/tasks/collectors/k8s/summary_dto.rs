@@ -1,18 +1,46 @@
 use serde::{Deserialize, Serialize};
 
 /// Full /stats/summary response
+///
+/// Windows kubelets omit several fields Linux nodes always send (timestamps,
+/// rlimits, pod-level CPU/memory on older versions), so those default instead of
+/// failing the whole node.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Summary {
     pub node: NodeSummary,
     pub pods: Option<Vec<PodSummary>>,
 }
 
+impl Summary {
+    /// Fills pod CPU/memory that the kubelet left out from the pod's containers.
+    pub fn fill_missing_pod_usage(&mut self) {
+        for PodSummary { containers, cpu, memory, .. } in self.pods.iter_mut().flatten() {
+            let sum = |f: fn(&ContainerSummary) -> Option<u64>| -> Option<u64> {
+                containers.iter().filter_map(f).reduce(|a, b| a + b)
+            };
+            if cpu.usage_nano_cores.is_none() {
+                cpu.usage_nano_cores = sum(|c| c.cpu.usage_nano_cores);
+            }
+            if cpu.usage_core_nano_seconds.is_none() {
+                cpu.usage_core_nano_seconds = sum(|c| c.cpu.usage_core_nano_seconds);
+            }
+            if memory.working_set_bytes.is_none() {
+                memory.working_set_bytes = sum(|c| c.memory.working_set_bytes);
+            }
+            if memory.usage_bytes.is_none() {
+                memory.usage_bytes = sum(|c| c.memory.usage_bytes);
+            }
+        }
+    }
+}
+
 /* ---------------- Node Level ---------------- */
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NodeSummary {
     pub node_name: String,
+    #[serde(default)]
     pub start_time: String,
     pub system_containers: Option<Vec<SystemContainer>>,
     pub cpu: CpuStats,
@@ -34,17 +62,19 @@ pub struct SystemContainer {
     pub swap: Option<SwapStats>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CpuStats {
+    #[serde(default)]
     pub time: String,
     pub usage_nano_cores: Option<u64>,
     pub usage_core_nano_seconds: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MemoryStats {
+    #[serde(default)]
     pub time: String,
     pub available_bytes: Option<u64>,
     pub usage_bytes: Option<u64>, // ✅ make optional — some entries omit it
@@ -57,6 +87,7 @@ pub struct MemoryStats {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SwapStats {
+    #[serde(default)]
     pub time: String,
     pub swap_available_bytes: Option<u64>,
     pub swap_usage_bytes: Option<u64>,
@@ -84,14 +115,18 @@ pub struct RuntimeFs {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Rlimit {
+    #[serde(default)]
     pub time: String,
+    #[serde(default)]
     pub maxpid: u64,
+    #[serde(default)]
     pub curproc: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkStats {
+    #[serde(default)]
     pub time: String,
     pub name: Option<String>,
     pub rx_bytes: Option<u64>,
@@ -117,9 +152,13 @@ pub struct NetworkInterface {
 #[serde(rename_all = "camelCase")]
 pub struct PodSummary {
     pub pod_ref: PodRef,
+    #[serde(default)]
     pub start_time: String,
+    #[serde(default)]
     pub containers: Vec<ContainerSummary>,
+    #[serde(default)]
     pub cpu: CpuStats,
+    #[serde(default)]
     pub memory: MemoryStats,
     pub network: Option<NetworkStats>,
 
@@ -145,8 +184,11 @@ pub struct PodRef {
 #[serde(rename_all = "camelCase")]
 pub struct ContainerSummary {
     pub name: String,
+    #[serde(default)]
     pub start_time: String,
+    #[serde(default)]
     pub cpu: CpuStats,
+    #[serde(default)]
     pub memory: MemoryStats,
     pub rootfs: Option<FsStats>,
     pub logs: Option<FsStats>,
@@ -179,3 +221,38 @@ pub struct PvcRef {
 pub struct ProcessStats {
     pub process_count: Option<u64>, // ✅ your JSON has "null"
 }
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_windows_summary_without_pod_level_usage() {
+        let json = r#"{
+            "node": {
+                "nodeName": "akswin000000",
+                "cpu": {"usageNanoCores": 250000000},
+                "memory": {"workingSetBytes": 2147483648},
+                "rlimit": {}
+            },
+            "pods": [{
+                "podRef": {"name": "iis", "namespace": "web", "uid": "u1"},
+                "containers": [
+                    {"name": "iis", "cpu": {"usageNanoCores": 30}, "memory": {"workingSetBytes": 100}},
+                    {"name": "log", "cpu": {"usageNanoCores": 10}, "memory": {}}
+                ]
+            }]
+        }"#;
+
+        let mut summary: Summary = serde_json::from_str(json).unwrap();
+        summary.fill_missing_pod_usage();
+
+        assert_eq!(summary.node.cpu.usage_nano_cores, Some(250_000_000));
+        let pod = &summary.pods.unwrap()[0];
+        assert_eq!(pod.cpu.usage_nano_cores, Some(40));
+        assert_eq!(pod.memory.working_set_bytes, Some(100));
+        assert_eq!(pod.memory.usage_bytes, None);
+    }
+}
@@ -1,19 +1,34 @@
 use crate::core::client::kube_client::build_kube_client;
-use crate::core::client::nodes::{fetch_node_summary, fetch_nodes};
+use crate::core::client::kube_resources::Node;
+use crate::core::client::nodes::fetch_nodes;
 use crate::scheduler::tasks::collectors::k8s::node::task::{handle_node, update_node_info};
 use crate::scheduler::tasks::collectors::k8s::pod::task::handle_pod;
+use crate::scheduler::tasks::collectors::k8s::source::{metrics_source_from_settings, MetricsSource};
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
-use tracing::{debug, error};
+use futures::{stream, StreamExt};
+use std::time::Duration;
+use tracing::{debug, error, warn};
 use crate::app_state::AppState;
 use crate::scheduler::tasks::alarm::task::handle_alarm;
 use crate::scheduler::tasks::collectors::k8s::container::task::handle_container;
-
-/// Collects node-level stats from the Kubelet `/stats/summary` endpoint.
+use crate::scheduler::job_tracker::{record_failure, record_processed};
+use crate::core::self_metrics;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Collects node-level stats from the Kubelet `/stats/summary` endpoint, or from the
+/// fallback source selected by `metrics_source`.
+///
+/// Nodes are scraped concurrently (bounded by `scrape_concurrency`); each node gets
+/// its own timeout and retries, and failures are reported per node to the job
+/// tracker so one slow or broken kubelet never holds up the rest of the cycle.
 pub async fn run(state: AppState, now: DateTime<Utc>) -> Result<()> {
     debug!("Starting K8s node stats task...");
-    let state_clone = state.clone();
 
     // --- Build kube client ---
     let client = build_kube_client().await?;
@@ -21,32 +36,146 @@ pub async fn run(state: AppState, now: DateTime<Utc>) -> Result<()> {
     // --- Step 1: Fetch all nodes ---
     let node_list = fetch_nodes(&client).await?;
 
-    // --- Step 2: For each node, call /proxy/stats/summary ---
-    for node in node_list {
-        let node_name = node.metadata.name.clone().unwrap_or_default();
+    // --- Step 2: Spread nodes over the jitter window (stable per-node offsets) ---
+    let settings = InfoSettingFsAdapter::new().read().unwrap_or_default();
+    let policy = ScrapePolicy::from_settings(&settings);
+    let source = metrics_source_from_settings(&settings, &client)?;
+    let jitter_sec = settings.effective_scrape_jitter_sec();
+    let mut scheduled: Vec<(u64, _)> = node_list
+        .into_iter()
+        .map(|node| {
+            let name = node.metadata.name.clone().unwrap_or_default();
+            (node_jitter_ms(&name, jitter_sec), node)
+        })
+        .collect();
+    scheduled.sort_by_key(|(offset, _)| *offset);
+    let started = tokio::time::Instant::now();
+
+    // --- Step 3: Fetch summaries for up to `concurrency` nodes at a time ---
+    debug!(source = source.name(), "Scraping {} node(s)", scheduled.len());
+    let (source, state, policy) = (source.as_ref(), &state, &policy);
+    stream::iter(scheduled)
+        .map(|(offset_ms, node)| async move {
+            tokio::time::sleep_until(started + Duration::from_millis(offset_ms)).await;
+            scrape_node(source, state, node, now, policy).await;
+        })
+        .buffer_unordered(policy.concurrency)
+        .collect::<()>()
+        .await;
 
-        match fetch_node_summary::<Summary>(&client, &node_name).await {
-            Ok(summary) => {
+    Ok(())
+}
 
-                match handle_summary(&state_clone, &summary, now).await {
-                    Ok(result) => {
+/// Timeout and retry limits applied to each node's summary call.
+#[derive(Debug, Clone)]
+struct ScrapePolicy {
+    concurrency: usize,
+    timeout: Duration,
+    retries: u32,
+}
 
-                        // if new node
-                        if let Some(_name) = result.node_name {
-                            update_node_info(node, now).await?;
-                        }
-                        // new_pods.extend(result.updated_pods);
-                        // new_containers.extend(result.updated_containers);
+impl ScrapePolicy {
+    fn from_settings(settings: &InfoSettingEntity) -> Self {
+        Self {
+            concurrency: settings.effective_scrape_concurrency(),
+            timeout: Duration::from_secs(settings.effective_scrape_timeout_sec() as u64),
+            retries: settings.scrape_retries,
+        }
+    }
+}
+
+/// First retry waits this long; every further retry doubles it.
+const RETRY_BASE_DELAY_MS: u64 = 500;
+const RETRY_MAX_DELAY_MS: u64 = 8_000;
+
+/// Backoff before retry number `attempt` (1-based).
+fn retry_delay(attempt: u32) -> Duration {
+    let factor = 1u64 << attempt.saturating_sub(1).min(16);
+    Duration::from_millis((RETRY_BASE_DELAY_MS * factor).min(RETRY_MAX_DELAY_MS))
+}
+
+/// Fetch, persist and account for one node; never fails the surrounding cycle.
+async fn scrape_node(
+    source: &dyn MetricsSource,
+    state: &AppState,
+    node: Node,
+    now: DateTime<Utc>,
+    policy: &ScrapePolicy,
+) {
+    let node_name = node.metadata.name.clone().unwrap_or_default();
+    let scrape_started = std::time::Instant::now();
+
+    match fetch_summary_with_retry(source, &node, policy).await {
+        Ok(summary) => match handle_summary(state, &summary, &node, now).await {
+            Ok(result) => {
+                // if new node
+                let registered = match result.node_name {
+                    Some(_) => update_node_info(node, now).await,
+                    None => Ok(()),
+                };
+                match registered {
+                    Ok(()) => record_processed(),
+                    Err(e) => {
+                        error!("❌ Failed to update node info for {}: {:?}", node_name, e);
+                        record_failure(&node_name, &e);
                     }
-                    Err(e) => error!("❌ Failed to handle summary for {}: {:?}", node_name, e),
                 }
             }
             Err(e) => {
-                error!("❌ Failed to fetch summary for {}: {:?}", node_name, e);
+                error!("❌ Failed to handle summary for {}: {:?}", node_name, e);
+                record_failure(&node_name, &e);
+            }
+        },
+        Err(e) => {
+            error!("❌ Failed to fetch summary for {}: {:?}", node_name, e);
+            if e.downcast_ref::<serde_json::Error>().is_some() {
+                self_metrics::inc_parse_errors(&node_name);
             }
+            record_failure(&node_name, &e);
         }
     }
-    Ok(())
+    self_metrics::observe_scrape_duration(&node_name, scrape_started.elapsed().as_secs_f64());
+}
+
+/// Fetch a node's summary with a per-attempt timeout and exponential backoff.
+/// Malformed responses are not retried; another attempt would parse the same body.
+async fn fetch_summary_with_retry(
+    source: &dyn MetricsSource,
+    node: &Node,
+    policy: &ScrapePolicy,
+) -> Result<Summary> {
+    let node_name = node.metadata.name.as_deref().unwrap_or_default();
+    let mut attempt = 0;
+    loop {
+        let result = match tokio::time::timeout(policy.timeout, source.node_summary(node)).await {
+            Ok(result) => result,
+            Err(_) => Err(anyhow!("timed out after {}s", policy.timeout.as_secs())),
+        };
+
+        match result {
+            Ok(summary) => return Ok(summary),
+            Err(e) if attempt < policy.retries && e.downcast_ref::<serde_json::Error>().is_none() => {
+                attempt += 1;
+                let delay = retry_delay(attempt);
+                warn!(node = node_name, source = source.name(), attempt, ?delay, "Summary scrape failed, retrying: {:#}", e);
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => {
+                return Err(e.context(format!("after {} attempt(s)", attempt + 1)));
+            }
+        }
+    }
+}
+
+/// Stable delay for a node within `[0, jitter_sec)`, so each node is scraped at
+/// roughly the same point of every interval.
+fn node_jitter_ms(node_name: &str, jitter_sec: u32) -> u64 {
+    if jitter_sec == 0 {
+        return 0;
+    }
+    let mut hasher = DefaultHasher::new();
+    node_name.hash(&mut hasher);
+    hasher.finish() % (jitter_sec as u64 * 1000)
 }
 
 #[derive(Debug, Default)]
@@ -57,11 +186,11 @@ pub struct SummaryHandleResultDto {
 }
 
 
-/// Handle and persist one `/stats/summary` response
-pub async fn handle_summary(state: &AppState, summary: &Summary, now: DateTime<Utc>) -> Result<SummaryHandleResultDto> {
+/// Handle and persist one `/stats/summary` response of `node`
+pub async fn handle_summary(state: &AppState, summary: &Summary, node: &Node, now: DateTime<Utc>) -> Result<SummaryHandleResultDto> {
     let mut result = SummaryHandleResultDto::default();
 
-    if handle_node(summary, now).await? {
+    if handle_node(summary, node, now).await? {
         result.node_name = Some(summary.node.node_name.clone());
     }
 
@@ -102,4 +231,13 @@ mod tests {
             assert!(result.is_ok() || result.is_err());
         });
     }
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_cap() {
+        assert_eq!(retry_delay(1), Duration::from_millis(500));
+        assert_eq!(retry_delay(2), Duration::from_millis(1_000));
+        assert_eq!(retry_delay(3), Duration::from_millis(2_000));
+        assert_eq!(retry_delay(10), Duration::from_millis(RETRY_MAX_DELAY_MS));
+        assert_eq!(retry_delay(u32::MAX), Duration::from_millis(RETRY_MAX_DELAY_MS));
+    }
 }
@@ -0,0 +1,186 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::k8s::network_flow::hour::metric_network_flow_hour_repository::MetricNetworkFlowHourRepository;
+use crate::core::persistence::metrics::k8s::network_flow::metric_network_flow_entity::MetricNetworkFlowEntity;
+use crate::domain::info::service::info_settings_service::get_info_settings;
+use crate::scheduler::job_tracker::{record_failure, record_processed};
+use crate::scheduler::tasks::utils::time_util::TimeUtils;
+
+/// Egress counters of the network-costs exporter daemonset, one series per pod and
+/// destination class.
+pub const DEFAULT_NETWORK_FLOW_QUERY: &str =
+    "sum by (namespace, internet, same_zone) (increase(kubecost_pod_network_egress_bytes_total[1h]))";
+
+const QUERY_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Deserialize)]
+struct PromResponse {
+    status: String,
+    #[serde(default)]
+    data: Option<PromData>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PromData {
+    #[serde(default)]
+    result: Vec<PromSample>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PromSample {
+    #[serde(default)]
+    metric: HashMap<String, String>,
+    /// `[unix_time, "value"]`
+    value: (f64, String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlowClass {
+    IntraZone,
+    CrossZone,
+    Internet,
+}
+
+/// Internet beats zone labels; a flow not marked `same_zone="false"` stays in the zone.
+fn classify(labels: &HashMap<String, String>) -> FlowClass {
+    let flag = |name: &str| labels.get(name).map(|v| v.eq_ignore_ascii_case("true"));
+    if flag("internet") == Some(true) {
+        FlowClass::Internet
+    } else if flag("same_zone") == Some(false) {
+        FlowClass::CrossZone
+    } else {
+        FlowClass::IntraZone
+    }
+}
+
+/// Sums samples per namespace; samples without a namespace or a usable value are skipped.
+fn rows_by_namespace(samples: &[PromSample], time: DateTime<Utc>) -> BTreeMap<String, MetricNetworkFlowEntity> {
+    let mut rows: BTreeMap<String, MetricNetworkFlowEntity> = BTreeMap::new();
+
+    for sample in samples {
+        let Some(namespace) = sample.metric.get("namespace").filter(|ns| !ns.is_empty()) else {
+            continue;
+        };
+        let Ok(bytes) = sample.value.1.parse::<f64>() else {
+            continue;
+        };
+        if !bytes.is_finite() || bytes <= 0.0 {
+            continue;
+        }
+
+        let row = rows.entry(namespace.clone()).or_insert_with(|| MetricNetworkFlowEntity {
+            time,
+            ..Default::default()
+        });
+        let bytes = bytes.round() as u64;
+        match classify(&sample.metric) {
+            FlowClass::IntraZone => row.intra_zone_bytes += bytes,
+            FlowClass::CrossZone => row.cross_zone_bytes += bytes,
+            FlowClass::Internet => row.internet_bytes += bytes,
+        }
+    }
+    rows
+}
+
+async fn query_flows(url: &str, query: &str, time: DateTime<Utc>) -> Result<Vec<PromSample>> {
+    let endpoint = format!("{}/api/v1/query", url.trim_end_matches('/'));
+    let resp: PromResponse = Client::builder()
+        .timeout(QUERY_TIMEOUT)
+        .build()?
+        .get(&endpoint)
+        .query(&[("query", query), ("time", &time.timestamp().to_string())])
+        .send()
+        .await
+        .with_context(|| format!("Failed to query network flows from {}", endpoint))?
+        .json()
+        .await
+        .context("Failed to parse network flow query response")?;
+
+    if resp.status != "success" {
+        return Err(anyhow!("Network flow query failed: {}", resp.error.unwrap_or(resp.status)));
+    }
+    Ok(resp.data.map(|d| d.result).unwrap_or_default())
+}
+
+/// Writes each namespace's egress of the hour just closed, split by destination.
+/// A no-op until `network_flow_url` is configured.
+pub async fn run(now: DateTime<Utc>) -> Result<()> {
+    let settings = get_info_settings().await?;
+    let Some(url) = settings.network_flow_url.as_deref() else {
+        debug!("No network flow source configured, skipping flow collection");
+        return Ok(());
+    };
+    let query = settings.network_flow_query.as_deref().unwrap_or(DEFAULT_NETWORK_FLOW_QUERY);
+
+    let time = TimeUtils::previous_hour_window(now)?.1;
+    let samples = query_flows(url, query, time).await?;
+    let rows = rows_by_namespace(&samples, time);
+    if rows.is_empty() {
+        debug!("Network flow source returned no egress for the hour");
+        return Ok(());
+    }
+
+    let repo = MetricNetworkFlowHourRepository::new();
+    for (namespace, row) in &rows {
+        match repo.append_row(namespace, row, now) {
+            Ok(()) => record_processed(),
+            Err(e) => {
+                error!("❌ Failed to write network flows for {}: {:?}", namespace, e);
+                record_failure(namespace, &e);
+            }
+        }
+    }
+    Ok(())
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample(ns: &str, internet: &str, same_zone: &str, value: &str) -> PromSample {
+        let metric = [("namespace", ns), ("internet", internet), ("same_zone", same_zone)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        PromSample { metric, value: (0.0, value.to_string()) }
+    }
+
+    #[test]
+    fn splits_egress_by_destination_per_namespace() {
+        let time = Utc.with_ymd_and_hms(2025, 3, 1, 4, 0, 0).unwrap();
+        let samples = vec![
+            sample("shop", "false", "true", "100"),
+            sample("shop", "false", "false", "40.4"),
+            sample("shop", "true", "false", "7"),
+            sample("shop", "false", "true", "NaN"),
+            sample("", "true", "true", "5"),
+        ];
+
+        let rows = rows_by_namespace(&samples, time);
+        assert_eq!(rows.len(), 1);
+        let shop = &rows["shop"];
+        assert_eq!((shop.intra_zone_bytes, shop.cross_zone_bytes, shop.internet_bytes), (100, 40, 7));
+        assert_eq!(shop.time, time);
+    }
+
+    #[test]
+    fn parses_prometheus_vector() {
+        let raw = r#"{"status":"success","data":{"resultType":"vector","result":[
+            {"metric":{"namespace":"shop","internet":"true"},"value":[1740801600,"12"]}]}}"#;
+        let resp: PromResponse = serde_json::from_str(raw).unwrap();
+        let samples = resp.data.unwrap().result;
+        assert_eq!(classify(&samples[0].metric), FlowClass::Internet);
+        assert_eq!(samples[0].value.1, "12");
+    }
+}
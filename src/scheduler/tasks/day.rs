@@ -1,24 +1,48 @@
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{Datelike, Utc};
 use tracing::{debug, error};
+use crate::scheduler::job_tracker::track_job;
 use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
 use crate::scheduler::tasks::processors::retention::task::RetentionTask;
 
 pub async fn run() -> Result<()> {
     let now = Utc::now();
-    debug!("Running day task (aggregation + retention)...");
+    debug!("Running day task (aggregation + retention + GC)...");
 
-    if let Err(e) = super::processors::day::run(now).await {
+    if let Err(e) = track_job("day_aggregation", super::processors::day::run(now)).await {
         error!(?e, "Daily aggregator failed");
     }
 
+    // The day just aggregated closed a month
+    if now.day() == 1 {
+        if let Err(e) = track_job("month_aggregation", super::processors::month::run(now)).await {
+            error!(?e, "Monthly aggregator failed");
+        }
+    }
+
+    if let Err(e) = track_job("cost_export", crate::domain::export::export_service::run_daily_export(now)).await {
+        error!(?e, "Cost export failed");
+    }
+
+    if let Err(e) = track_job("cost_materialization", crate::domain::export::cost_materialization_service::run_cost_materialization(now)).await {
+        error!(?e, "Cost materialization failed");
+    }
+
     // Create settings repository DI
     let settings_repo = InfoSettingRepository::new();
     let retention_task = RetentionTask::new(settings_repo);
 
-    if let Err(e) = retention_task.run(now).await {
+    if let Err(e) = track_job("retention", retention_task.run(now)).await {
         error!(?e, "Retention cleanup failed");
     }
 
+    if let Err(e) = track_job("compaction", super::processors::compaction::task::run(now)).await {
+        error!(?e, "Partition compaction failed");
+    }
+
+    if let Err(e) = track_job("metric_gc", crate::domain::system::service::metric_gc_service::run_metric_gc(now)).await {
+        error!(?e, "Metric directory GC failed");
+    }
+
     Ok(())
 }
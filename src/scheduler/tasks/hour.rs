@@ -1,14 +1,29 @@
 use anyhow::Result;
 use chrono::Utc;
 use tracing::{debug, error};
+use crate::app_state::AppState;
+use crate::scheduler::job_tracker::track_job;
 
-pub async fn run() -> Result<()> {
+pub async fn run(state: AppState) -> Result<()> {
     let now = Utc::now();
     debug!("Running hour scheduler at {}", now);
 
-    if let Err(e) = super::processors::hour::run(now).await {
+    if let Err(e) = track_job("hour_aggregation", super::processors::hour::run(now)).await {
         error!(?e, "hour aggregator failed");
     }
 
+    if let Err(e) = track_job("pvc_collection", super::collectors::k8s::pvc::task::run(now)).await {
+        error!(?e, "PVC collection failed");
+    }
+
+    if let Err(e) = track_job("network_flow_collection", super::collectors::network_flow::task::run(now)).await {
+        error!(?e, "network flow collection failed");
+    }
+
+    // Runs after aggregation so the hour just closed is available
+    if let Err(e) = super::alarm::task::handle_efficiency_alarm(&state, now).await {
+        error!(?e, "efficiency alert evaluation failed");
+    }
+
     Ok(())
 }
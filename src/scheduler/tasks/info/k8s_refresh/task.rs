@@ -1,8 +1,29 @@
+use std::collections::HashSet;
+
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::autoscaling::v2::MetricTarget;
+use k8s_openapi::api::core::v1::{Namespace, Pod, PodStatus, ResourceQuota, Service};
 use kube::{
     api::{Api, ListParams},
 };
-use tracing::{error, info};
+use tracing::{debug, error, info, warn};
+use crate::scheduler::leader::is_leader;
+use crate::core::client::kube_resources::HorizontalPodAutoscaler;
+use crate::core::client::mappers::map_namespace_to_info_entity;
+use crate::core::client::other_resources::fetch_hpas;
+use crate::core::client::owner_chain::{controller_owner, OwnerChain, Workload};
+use crate::core::persistence::info::k8s::hpa::info_hpa_entity::InfoHpaEntity;
+use crate::core::persistence::info::k8s::hpa::info_hpa_fs_adapter::InfoHpaFsAdapter;
+use crate::core::persistence::info::k8s::load_balancer::info_load_balancer_entity::InfoLoadBalancerEntity;
+use crate::core::persistence::info::k8s::load_balancer::info_load_balancer_fs_adapter::InfoLoadBalancerFsAdapter;
+use crate::core::persistence::info::k8s::namespace::info_namespace_api_repository_trait::InfoNamespaceApiRepository;
+use crate::core::persistence::info::k8s::namespace::info_namespace_repository::InfoNamespaceRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
+use crate::core::persistence::info::path::info_k8s_pod_file_path;
+use crate::core::persistence::metrics::k8s::pod::restart::metric_pod_restart_entity::MetricPodRestartEntity;
+use crate::core::persistence::metrics::k8s::pod::restart::metric_pod_restart_fs_adapter::MetricPodRestartFsAdapter;
 use crate::core::state::runtime::k8s::k8s_runtime_state::RuntimePod;
 use crate::core::state::runtime::k8s::k8s_runtime_state_manager::K8sRuntimeStateManager;
 
@@ -46,6 +67,12 @@ where
         .await
         .context("failed to list namespaces")?;
 
+    if is_leader() {
+        persist_namespaces(&client, &namespaces.items).await;
+        persist_load_balancers(&client).await;
+        persist_hpas(&client).await;
+    }
+
     let namespace_names: Vec<String> = namespaces
         .items
         .into_iter()
@@ -68,7 +95,15 @@ where
         .collect();
 
     // ---------------------------
-    // 4. LOAD PODS
+    // 4. LOAD OWNER CHAIN
+    // ---------------------------
+    let owner_chain = OwnerChain::load(&client).await.unwrap_or_else(|e| {
+        warn!("failed to load owner chain, falling back to direct owners: {e}");
+        OwnerChain::default()
+    });
+
+    // ---------------------------
+    // 5. LOAD PODS
     // ---------------------------
     let pod_api: Api<k8s_openapi::api::core::v1::Pod> = Api::all(client.clone());
     let pods = pod_api
@@ -77,65 +112,14 @@ where
         .context("failed to list pods")?;
 
     let mut runtime_pods = Vec::<RuntimePod>::new();
+    let now = Utc::now();
 
     for pod in pods.items {
-        let metadata = pod.metadata;
-        let spec = pod.spec.clone();
-        let pod_name = metadata.name.clone().unwrap_or_default();
-        let namespace = metadata.namespace.clone().unwrap_or_default();
-        let uid = metadata.uid.clone().unwrap_or_else(|| format!("{}-no-uid", pod_name));
-
-        // Node assignment (may be empty for pending pods)
-        let node = spec
-            .as_ref()
-            .and_then(|s| s.node_name.clone())
-            .unwrap_or_else(|| "unknown".to_string());
-
-        // Deployment inference from labels
-        let deployment = metadata
-            .labels
-            .as_ref()
-            .and_then(|lbl| lbl.get("app.kubernetes.io/name").cloned()) // common label
-            .or_else(|| {
-                metadata
-                    .owner_references
-                    .as_ref()
-                    .and_then(|owners| {
-                        owners
-                            .iter()
-                            .find(|o| o.kind == "ReplicaSet")
-                            .and_then(|owner| {
-                                // drop last "-<hash>" if present
-                                let rs = owner.name.clone();
-                                rs.rsplit_once('-').map(|(base, _)| base.to_string())
-                            })
-                    })
-            });
-
-        // Container names
-        let containers = pod
-            .spec
-            .clone()
-            .map(|s| {
-                s.containers
-                    .into_iter()
-                    .map(|c| c.name)
-                    .collect::<Vec<String>>()
-            })
-            .unwrap_or_default();
-
-        runtime_pods.push(RuntimePod {
-            uid,
-            name: pod_name,
-            namespace,
-            deployment,
-            node,
-            containers,
-        });
+        runtime_pods.push(sync_pod(pod, &owner_chain, now));
     }
 
     // ---------------------------
-    // 5. UPDATE RUNTIME STATE
+    // 6. UPDATE RUNTIME STATE
     // ---------------------------
     info!(
         "K8s discovery complete: {} nodes, {} namespaces, {} deployments, {} pods",
@@ -163,3 +147,360 @@ where
 
     Ok(())
 }
+
+/// Stores every namespace with its ResourceQuota limits, keeping local
+/// team/service/env, and flags stored namespaces that no longer exist.
+/// Failures are logged; discovery goes on without namespace info.
+async fn persist_namespaces(client: &kube::Client, namespaces: &[Namespace]) {
+    let quota_api: Api<ResourceQuota> = Api::all(client.clone());
+    let quotas = match quota_api.list(&ListParams::default()).await {
+        Ok(list) => list.items,
+        Err(e) => {
+            warn!("failed to list resource quotas, storing namespaces without them: {e}");
+            Vec::new()
+        }
+    };
+
+    let repo = InfoNamespaceRepository::new();
+    let now = Utc::now();
+    let mut seen = HashSet::new();
+
+    for ns in namespaces {
+        let Some(name) = ns.metadata.name.as_deref() else {
+            continue;
+        };
+        seen.insert(name.to_string());
+
+        let in_ns: Vec<&ResourceQuota> = quotas
+            .iter()
+            .filter(|q| q.metadata.namespace.as_deref() == Some(name))
+            .collect();
+        let mapped = match map_namespace_to_info_entity(ns, &in_ns, now) {
+            Ok(m) => m,
+            Err(e) => {
+                warn!("failed to map namespace {name}: {e}");
+                continue;
+            }
+        };
+
+        let entity = match repo.read(name) {
+            Ok(mut existing) => {
+                existing.merge_from(mapped);
+                existing
+            }
+            Err(_) => mapped,
+        };
+        if let Err(e) = repo.update(&entity) {
+            warn!("failed to store namespace {name}: {e}");
+        }
+    }
+
+    let stored = repo.list().unwrap_or_default();
+    for mut gone in stored.into_iter().filter(|ns| {
+        ns.deleted != Some(true) && ns.name.as_ref().is_some_and(|n| !seen.contains(n))
+    }) {
+        gone.deleted = Some(true);
+        gone.last_updated_info_at = Some(now);
+        if let Err(e) = repo.update(&gone) {
+            warn!("failed to flag deleted namespace {:?}: {e}", gone.name);
+        }
+    }
+}
+
+/// Records every `LoadBalancer` Service and marks stored ones that disappeared as
+/// deleted, so their cost stops accruing. Failures are logged like namespaces.
+async fn persist_load_balancers(client: &kube::Client) {
+    let svc_api: Api<Service> = Api::all(client.clone());
+    let services = match svc_api.list(&ListParams::default()).await {
+        Ok(list) => list.items,
+        Err(e) => {
+            warn!("failed to list services, load balancers not updated: {e}");
+            return;
+        }
+    };
+
+    let adapter = InfoLoadBalancerFsAdapter::new();
+    let stored = match adapter.list() {
+        Ok(v) => v,
+        Err(e) => {
+            warn!("failed to read stored load balancers: {e}");
+            return;
+        }
+    };
+
+    let seen: Vec<InfoLoadBalancerEntity> = services
+        .iter()
+        .filter_map(|svc| map_load_balancer(svc, Utc::now()))
+        .collect();
+    let merged = merge_load_balancers(stored, seen, Utc::now());
+    if let Err(e) = adapter.write(&merged) {
+        warn!("failed to store load balancers: {e}");
+    }
+}
+
+fn map_load_balancer(svc: &Service, now: DateTime<Utc>) -> Option<InfoLoadBalancerEntity> {
+    let spec = svc.spec.as_ref()?;
+    if spec.type_.as_deref() != Some("LoadBalancer") {
+        return None;
+    }
+
+    let ingress = svc
+        .status
+        .as_ref()
+        .and_then(|s| s.load_balancer.as_ref())
+        .and_then(|lb| lb.ingress.as_ref())
+        .and_then(|ing| ing.first())
+        .and_then(|i| i.hostname.clone().or_else(|| i.ip.clone()));
+
+    Some(InfoLoadBalancerEntity {
+        uid: svc.metadata.uid.clone()?,
+        namespace: svc.metadata.namespace.clone().unwrap_or_default(),
+        name: svc.metadata.name.clone().unwrap_or_default(),
+        created_at: svc.metadata.creation_timestamp.as_ref().map(|t| t.0),
+        first_seen_at: now,
+        last_seen_at: now,
+        deleted_at: None,
+        class: spec.load_balancer_class.clone(),
+        ingress,
+    })
+}
+
+/// Keyed by UID, so a Service recreated under the same name starts a new lifetime.
+/// A Service switched away from `LoadBalancer` counts as deleted.
+fn merge_load_balancers(
+    stored: Vec<InfoLoadBalancerEntity>,
+    seen: Vec<InfoLoadBalancerEntity>,
+    now: DateTime<Utc>,
+) -> Vec<InfoLoadBalancerEntity> {
+    let mut merged: Vec<InfoLoadBalancerEntity> = stored
+        .into_iter()
+        .map(|mut lb| {
+            if lb.deleted_at.is_none() && !seen.iter().any(|s| s.uid == lb.uid) {
+                lb.deleted_at = Some(now);
+            }
+            lb
+        })
+        .collect();
+
+    for lb in seen {
+        match merged.iter_mut().find(|m| m.uid == lb.uid) {
+            Some(existing) => {
+                existing.last_seen_at = lb.last_seen_at;
+                existing.deleted_at = None;
+                existing.class = lb.class;
+                existing.ingress = lb.ingress.or(existing.ingress.take());
+            }
+            None => merged.push(lb),
+        }
+    }
+    merged
+}
+
+/// CPU utilization target the HPA controller applies when an HPA lists no metrics.
+const DEFAULT_HPA_CPU_UTILIZATION: i32 = 80;
+
+/// Replaces the stored HPAs with the current ones, so rightsizing can tell which
+/// workloads an autoscaler already sizes. Failures are logged like load balancers.
+async fn persist_hpas(client: &kube::Client) {
+    let hpas = match fetch_hpas(client).await {
+        Ok(list) => list,
+        Err(e) => {
+            warn!("failed to list HPAs, autoscaler targets not updated: {e}");
+            return;
+        }
+    };
+
+    let now = Utc::now();
+    let mapped: Vec<InfoHpaEntity> = hpas.iter().filter_map(|hpa| map_hpa(hpa, now)).collect();
+    if let Err(e) = InfoHpaFsAdapter::new().write(&mapped) {
+        warn!("failed to store HPAs: {e}");
+    }
+}
+
+fn map_hpa(hpa: &HorizontalPodAutoscaler, now: DateTime<Utc>) -> Option<InfoHpaEntity> {
+    let spec = hpa.spec.as_ref()?;
+    let metrics = spec.metrics.as_deref().unwrap_or_default();
+
+    // Resource metrics, pod-wide or per container, by resource name
+    let resources: Vec<(&str, &MetricTarget)> = metrics
+        .iter()
+        .filter_map(|m| {
+            m.resource
+                .as_ref()
+                .map(|r| (r.name.as_str(), &r.target))
+                .or_else(|| m.container_resource.as_ref().map(|r| (r.name.as_str(), &r.target)))
+        })
+        .collect();
+    let utilization = |name: &str| {
+        resources
+            .iter()
+            .filter(|(n, _)| *n == name)
+            .find_map(|(_, t)| t.average_utilization)
+    };
+
+    let (scales_on_cpu, cpu_target_utilization) = if metrics.is_empty() {
+        (true, Some(DEFAULT_HPA_CPU_UTILIZATION))
+    } else {
+        (resources.iter().any(|(n, _)| *n == "cpu"), utilization("cpu"))
+    };
+
+    Some(InfoHpaEntity {
+        namespace: hpa.metadata.namespace.clone().unwrap_or_default(),
+        name: hpa.metadata.name.clone()?,
+        target_kind: spec.scale_target_ref.kind.clone(),
+        target_name: spec.scale_target_ref.name.clone(),
+        min_replicas: spec.min_replicas,
+        max_replicas: spec.max_replicas,
+        scales_on_cpu,
+        cpu_target_utilization,
+        memory_target_utilization: utilization("memory"),
+        observed_at: now,
+    })
+}
+
+/// Builds the runtime entry for one pod, recording its resolved workload and any
+/// new container restarts on the way. Shared by full discovery and the pod watcher.
+pub(crate) fn sync_pod(pod: Pod, owner_chain: &OwnerChain, now: DateTime<Utc>) -> RuntimePod {
+    let metadata = pod.metadata;
+    let spec = pod.spec;
+    let pod_name = metadata.name.clone().unwrap_or_default();
+    let namespace = metadata.namespace.clone().unwrap_or_default();
+    let uid = metadata.uid.clone().unwrap_or_else(|| format!("{}-no-uid", pod_name));
+
+    // Node assignment (may be empty for pending pods)
+    let node = spec
+        .as_ref()
+        .and_then(|s| s.node_name.clone())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let workload = controller_owner(metadata.owner_references.as_deref())
+        .map(|owner| owner_chain.resolve(&namespace, &owner.kind, &owner.name));
+
+    // Readers and followers only mirror the runtime state; the leader owns the info files
+    if is_leader() {
+        if let Some(w) = &workload {
+            persist_workload(&uid, w);
+        }
+        record_restarts(&uid, pod.status.as_ref(), now);
+    }
+
+    // Resolved owner first, then inference from labels
+    let deployment = workload
+        .as_ref()
+        .filter(|w| w.kind == "Deployment")
+        .map(|w| w.name.clone())
+        .or_else(|| {
+            metadata
+                .labels
+                .as_ref()
+                .and_then(|lbl| lbl.get("app.kubernetes.io/name").cloned()) // common label
+        })
+        .or_else(|| {
+            metadata
+                .owner_references
+                .as_ref()
+                .and_then(|owners| {
+                    owners
+                        .iter()
+                        .find(|o| o.kind == "ReplicaSet")
+                        .and_then(|owner| {
+                            // drop last "-<hash>" if present
+                            let rs = owner.name.clone();
+                            rs.rsplit_once('-').map(|(base, _)| base.to_string())
+                        })
+                })
+        });
+
+    // Container names
+    let containers = spec
+        .map(|s| {
+            s.containers
+                .into_iter()
+                .map(|c| c.name)
+                .collect::<Vec<String>>()
+        })
+        .unwrap_or_default();
+
+    RuntimePod {
+        uid,
+        name: pod_name,
+        namespace,
+        deployment,
+        node,
+        containers,
+    }
+}
+
+/// Stores the resolved workload on the pod's info record, if one exists and it changed.
+fn persist_workload(pod_uid: &str, workload: &Workload) {
+    if !info_k8s_pod_file_path(pod_uid).exists() {
+        return;
+    }
+
+    let repo = InfoPodRepository::new();
+    let Ok(mut entity) = repo.read(pod_uid) else {
+        return;
+    };
+
+    if entity.workload_kind.as_deref() == Some(workload.kind.as_str())
+        && entity.workload_name.as_deref() == Some(workload.name.as_str())
+    {
+        return;
+    }
+
+    entity.workload_kind = Some(workload.kind.clone());
+    entity.workload_name = Some(workload.name.clone());
+    if let Err(e) = repo.update(&entity) {
+        debug!("failed to persist workload for pod {pod_uid}: {e}");
+    }
+}
+
+/// Appends a restart event for every container whose restart count grew since
+/// the last recorded event.
+fn record_restarts(pod_uid: &str, status: Option<&PodStatus>, now: DateTime<Utc>) {
+    let Some(statuses) = status.and_then(|s| s.container_statuses.as_ref()) else {
+        return;
+    };
+    // Most pods never restart; skip reading their history
+    if statuses.iter().all(|cs| cs.restart_count <= 0) {
+        return;
+    }
+
+    let adapter = MetricPodRestartFsAdapter::new();
+    let history = adapter.list(pod_uid).unwrap_or_default();
+
+    for cs in statuses {
+        let restart_count = cs.restart_count.max(0) as u32;
+        let previous = history
+            .iter()
+            .rev()
+            .find(|e| e.container == cs.name)
+            .map(|e| e.restart_count)
+            .unwrap_or(0);
+        if restart_count <= previous {
+            continue;
+        }
+
+        let terminated = cs.last_state.as_ref().and_then(|s| s.terminated.as_ref());
+        let event = MetricPodRestartEntity {
+            container: cs.name.clone(),
+            restart_count,
+            restarts: restart_count - previous,
+            reason: terminated.and_then(|t| t.reason.clone()),
+            exit_code: terminated.map(|t| t.exit_code),
+            started_at: terminated.and_then(|t| t.started_at.as_ref()).map(|t| t.0),
+            terminated_at: terminated.and_then(|t| t.finished_at.as_ref()).map(|t| t.0),
+            restarted_at: cs
+                .state
+                .as_ref()
+                .and_then(|s| s.running.as_ref())
+                .and_then(|r| r.started_at.as_ref())
+                .map(|t| t.0),
+            observed_at: now,
+        };
+
+        if let Err(e) = adapter.append(pod_uid, &event) {
+            debug!("failed to record restarts for pod {pod_uid}: {e}");
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod task;
\ No newline at end of file
@@ -0,0 +1,244 @@
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use tokio::sync::{broadcast, RwLock};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{debug, error, info, warn};
+
+use crate::scheduler::leader::is_leader;
+use crate::app_state::AppState;
+use crate::core::client::kube_resources::{Deployment, Node, Pod};
+use crate::core::client::mappers::{map_node_to_info_entity, map_pod_to_info_entity};
+use crate::core::client::owner_chain::OwnerChain;
+use crate::core::client::watchers::{watch_events, WatchEvent};
+use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
+use crate::core::persistence::info::k8s::node::info_node_repository::InfoNodeRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
+use crate::core::persistence::info::path::{info_k8s_node_file_path, info_k8s_pod_file_path};
+use crate::domain::info::service::info_attribution_rule_service::apply_stored_attribution_rules;
+use crate::scheduler::tasks::info::k8s_refresh::task::{refresh_k8s_object_info, sync_pod};
+
+/// Full discovery cadence; catches deletes missed while a watch was disconnected
+/// and keeps `last_discovered_at` fresh.
+const RECONCILE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Keeps the runtime state and the pod/node info store current from watch events,
+/// with a full discovery at start and every [`RECONCILE_INTERVAL`].
+pub async fn run_k8s_watch_loop(state: AppState, shutdown: &mut broadcast::Receiver<()>) {
+    // Resolve owners for the initial replay too; the reconcile loop refreshes it
+    let chain = load_owner_chain().await.unwrap_or_else(|e| {
+        warn!(?e, "failed to load owner chain, workloads resolve after first reconcile");
+        OwnerChain::default()
+    });
+    let owner_chain = Arc::new(RwLock::new(chain));
+
+    tokio::select! {
+        _ = reconcile_loop(state.clone(), owner_chain.clone()) => {}
+        res = watch_all(state, owner_chain) => {
+            if let Err(e) = res {
+                error!(?e, "K8s watchers stopped");
+            }
+        }
+        _ = shutdown.recv() => {
+            info!("K8s watch loop shutting down");
+        }
+    }
+}
+
+async fn reconcile_loop(state: AppState, owner_chain: Arc<RwLock<OwnerChain>>) {
+    let mut ticker = interval(RECONCILE_INTERVAL);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        let mgr = &state.k8s_state;
+        // A manual resync may already be running
+        if mgr.is_resyncing.swap(true, Ordering::SeqCst) {
+            continue;
+        }
+        if let Err(e) = refresh_k8s_object_info(mgr).await {
+            warn!(?e, "Periodic K8s reconcile failed");
+        }
+        mgr.is_resyncing.store(false, Ordering::SeqCst);
+
+        match load_owner_chain().await {
+            Ok(chain) => *owner_chain.write().await = chain,
+            Err(e) => warn!(?e, "failed to reload owner chain"),
+        }
+    }
+}
+
+async fn load_owner_chain() -> Result<OwnerChain> {
+    let client = crate::core::client::kube_client::build_kube_client().await?;
+    OwnerChain::load(&client).await
+}
+
+async fn watch_all(state: AppState, owner_chain: Arc<RwLock<OwnerChain>>) -> Result<()> {
+    let client = crate::core::client::kube_client::build_kube_client()
+        .await
+        .context("failed to create kube client")?;
+
+    let pods = watch_events::<Pod, _, _>(&client, |event| {
+        handle_pod_event(state.clone(), owner_chain.clone(), event)
+    });
+    let nodes = watch_events::<Node, _, _>(&client, |event| handle_node_event(state.clone(), event));
+    let deployments =
+        watch_events::<Deployment, _, _>(&client, |event| handle_deployment_event(state.clone(), event));
+
+    tokio::try_join!(pods, nodes, deployments)?;
+    Ok(())
+}
+
+async fn handle_pod_event(
+    state: AppState,
+    owner_chain: Arc<RwLock<OwnerChain>>,
+    event: WatchEvent<Pod>,
+) -> Result<()> {
+    match event {
+        WatchEvent::Applied(pod) => {
+            let Some(uid) = pod.metadata.uid.clone() else {
+                return Ok(());
+            };
+            // Info record first so the resolved workload lands on an existing file
+            if is_leader() {
+                store_pod_info(&uid, map_pod_to_info_entity(&pod)?)?;
+            }
+            let runtime = {
+                let chain = owner_chain.read().await;
+                sync_pod(pod, &chain, Utc::now())
+            };
+            state.k8s_state.apply_pod(runtime).await;
+        }
+        WatchEvent::Deleted(pod) => {
+            let Some(uid) = pod.metadata.uid else {
+                return Ok(());
+            };
+            let deleted_at = pod.metadata.deletion_timestamp.map(|t| t.0).unwrap_or_else(Utc::now);
+            state.k8s_state.remove_pod(&uid).await;
+            if is_leader() {
+                mark_pod_deleted(&uid, deleted_at)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn handle_node_event(state: AppState, event: WatchEvent<Node>) -> Result<()> {
+    match event {
+        WatchEvent::Applied(node) => {
+            let Some(name) = node.metadata.name.clone() else {
+                return Ok(());
+            };
+            if is_leader() {
+                store_node_info(&node)?;
+            }
+            state.k8s_state.apply_node(&name).await;
+        }
+        WatchEvent::Deleted(node) => {
+            let Some(name) = node.metadata.name else {
+                return Ok(());
+            };
+            let deleted_at = node
+                .metadata
+                .deletion_timestamp
+                .map(|t| t.0)
+                .unwrap_or_else(Utc::now);
+            state.k8s_state.remove_node(&name).await;
+            if is_leader() {
+                mark_node_deleted(&name, deleted_at)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn handle_deployment_event(state: AppState, event: WatchEvent<Deployment>) -> Result<()> {
+    match event {
+        WatchEvent::Applied(deployment) => {
+            let meta = deployment.metadata;
+            if let (Some(name), Some(namespace)) = (meta.name, meta.namespace) {
+                state.k8s_state.apply_deployment(&name, &namespace).await;
+            }
+        }
+        WatchEvent::Deleted(deployment) => {
+            if let Some(name) = deployment.metadata.name {
+                state.k8s_state.remove_deployment(&name).await;
+            }
+        }
+    }
+    Ok(())
+}
+
+// ------------------------------
+// Info store
+// ------------------------------
+
+/// Merges the mapped pod into its info record, keeping local annotations
+/// (team/service/env, resolved workload), then applies attribution rules.
+fn store_pod_info(uid: &str, mut mapped: InfoPodEntity) -> Result<()> {
+    let repo = InfoPodRepository::new();
+    mapped.pod_uid = Some(uid.to_string());
+    mapped.last_updated_info_at = Some(Utc::now());
+
+    let mut entity = match repo.read(uid) {
+        Ok(mut existing) => {
+            existing.merge_from(mapped);
+            existing
+        }
+        Err(_) => mapped,
+    };
+    apply_stored_attribution_rules(&mut entity);
+    repo.update(&entity)
+}
+
+fn mark_pod_deleted(uid: &str, deleted_at: DateTime<Utc>) -> Result<()> {
+    if !info_k8s_pod_file_path(uid).exists() {
+        return Ok(());
+    }
+    let repo = InfoPodRepository::new();
+    let mut entity = repo.read(uid)?;
+    entity.deleted = Some(true);
+    entity.deleted_at = Some(deleted_at);
+    entity.last_updated_info_at = Some(Utc::now());
+    debug!("Marking pod {uid} deleted from watch event");
+    repo.update(&entity)
+}
+
+/// Merges the mapped node into its info record, keeping local price overrides.
+/// A node that shows up again under a previously deleted name is live again.
+fn store_node_info(node: &Node) -> Result<()> {
+    let repo = InfoNodeRepository::new();
+    let mapped = map_node_to_info_entity(node, Utc::now())?;
+    let Some(name) = mapped.node_name.clone() else {
+        return Ok(());
+    };
+
+    let entity = match repo.read(&name) {
+        Ok(mut existing) => {
+            existing.merge_from(mapped);
+            existing.deleted = Some(false);
+            existing.deleted_at = None;
+            existing
+        }
+        Err(_) => mapped,
+    };
+    repo.update(&entity)
+}
+
+fn mark_node_deleted(name: &str, deleted_at: DateTime<Utc>) -> Result<()> {
+    if !info_k8s_node_file_path(name).exists() {
+        return Ok(());
+    }
+    let repo = InfoNodeRepository::new();
+    let mut entity = repo.read(name)?;
+    entity.deleted = Some(true);
+    entity.deleted_at = Some(deleted_at);
+    entity.last_updated_info_at = Some(Utc::now());
+    debug!("Marking node {name} deleted from watch event");
+    repo.update(&entity)
+}
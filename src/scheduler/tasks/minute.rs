@@ -2,6 +2,7 @@ use anyhow::Result;
 use chrono::Utc;
 use tracing::{debug, error};
 use crate::app_state::AppState;
+use crate::scheduler::job_tracker::track_job;
 
 pub async fn run(state: AppState) -> Result<()> {
     let now = Utc::now();
@@ -14,7 +15,7 @@ pub async fn run(state: AppState) -> Result<()> {
 
 
     // --- Collectors ---
-    if let Err(e) = super::collectors::k8s::run(state, now).await {
+    if let Err(e) = track_job("collection", super::collectors::k8s::run(state, now)).await {
         error!(?e, "K8s collector failed");
     }
 
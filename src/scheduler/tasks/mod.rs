@@ -4,7 +4,7 @@ mod minute;
 mod hour;
 mod day;
 pub mod info;
-mod utils;
+pub(crate) mod utils;
 mod alarm;
 
 pub use day::run as day_task;
@@ -0,0 +1,106 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::{fs, path::{Path, PathBuf}};
+use tracing::{debug, error, info};
+
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
+use crate::core::persistence::metrics::k8s::path::{metric_k8s_pod_dir_path, metric_k8s_pod_key_hour_dir_path};
+use crate::core::persistence::metrics::metric_partition_archive::MetricPartitionArchive;
+use crate::core::persistence::metrics::metric_storage_backend::{metric_object_keys, metric_storage_backend, MetricStorageBackend};
+use crate::scheduler::job_tracker::{record_failure, record_processed};
+
+/// Hour partitions smaller than this are merged into the namespace archive.
+/// A full month of hour rows is roughly 100 KiB, so this only catches pods that
+/// ran for a few days of the month.
+const COMPACT_MAX_BYTES: u64 = 16 * 1024;
+
+/// Moves small pod hour partitions of closed months into per-namespace
+/// archives (see `MetricPartitionArchive`). The current month is never touched,
+/// since it is still being written.
+pub async fn run(now: DateTime<Utc>) -> Result<()> {
+    if metric_storage_backend() != MetricStorageBackend::Fs {
+        debug!("Compaction only applies to the FS storage backend; skipping");
+        return Ok(());
+    }
+
+    let current_month = now.format("%Y-%m").to_string();
+    let repo = InfoPodRepository::new();
+    let mut compacted = 0usize;
+
+    for pod_uid in metric_object_keys("pod", &metric_k8s_pod_dir_path())? {
+        let candidates = small_closed_partitions(&metric_k8s_pod_key_hour_dir_path(&pod_uid), &current_month);
+        if candidates.is_empty() {
+            continue;
+        }
+
+        // Archives are per namespace; without one the pod stays as it is
+        let Some(namespace) = repo.read(&pod_uid).ok().and_then(|p| p.namespace) else {
+            debug!("No namespace recorded for pod '{}'; not compacting", pod_uid);
+            continue;
+        };
+
+        let mut failed = false;
+        for (month, path) in candidates {
+            let partition = format!("h/{}", month);
+            match MetricPartitionArchive::absorb("pod", &pod_uid, &partition, &namespace, &path) {
+                Ok(_) => compacted += 1,
+                Err(err) => {
+                    error!("⚠️ Compaction of {:?} failed: {}", path, err);
+                    record_failure(&pod_uid, &err);
+                    failed = true;
+                }
+            }
+        }
+        if !failed {
+            record_processed();
+        }
+    }
+
+    info!(compacted, "Compacted small pod hour partitions");
+    Ok(())
+}
+
+/// `(YYYY-MM, path)` of hour partitions before `current_month` under the size threshold.
+fn small_closed_partitions(dir: &Path, current_month: &str) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+
+    let mut out: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+                return None;
+            }
+            let month = path.file_stem()?.to_str()?.to_string();
+            let closed = month.len() == 7 && month.as_str() < current_month;
+            let small = entry.metadata().ok()?.len() < COMPACT_MAX_BYTES;
+            (closed && small).then_some((month, path))
+        })
+        .collect();
+    out.sort();
+    out
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_small_closed_months_are_picked() {
+        let dir = std::env::temp_dir().join("rustcost_compaction_test/h");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("2025-01.rcd"), "2025-01-31T23:00:00Z|1\n").unwrap();
+        fs::write(dir.join("2025-01.idx"), "2025-01-31|0\n").unwrap();
+        fs::write(dir.join("2025-02.rcd"), vec![b'x'; COMPACT_MAX_BYTES as usize]).unwrap();
+        fs::write(dir.join("2025-03.rcd"), "2025-03-01T00:00:00Z|1\n").unwrap();
+
+        let picked = small_closed_partitions(&dir, "2025-03");
+
+        assert_eq!(picked.len(), 1);
+        assert_eq!(picked[0].0, "2025-01");
+    }
+}
@@ -1,12 +1,11 @@
-use std::fs;
-use std::path::{PathBuf};
-
 use anyhow::{Result};
 use chrono::{DateTime, Utc};
 
 use tracing::{debug};
+use crate::scheduler::job_tracker::record_processed;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_processor_repository_trait::MetricContainerDayProcessorRepository;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_repository::MetricContainerDayRepository;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
 use crate::core::persistence::metrics::k8s::path::metric_k8s_container_dir_path;
 use crate::scheduler::tasks::utils::time_util::TimeUtils;
 
@@ -18,12 +17,7 @@ pub async fn process_container_hour_to_day(now: DateTime<Utc>) -> Result<()> {
     let (start, end) = TimeUtils::previous_day_window(now);
     let base_dir = metric_k8s_container_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No containers directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let container_keys = collect_container_keys(&base_dir)?;
+    let container_keys = metric_object_keys("container", &base_dir)?;
     if container_keys.is_empty() {
         debug!("No container metric directories found under {:?}", base_dir);
         return Ok(());
@@ -35,23 +29,6 @@ pub async fn process_container_hour_to_day(now: DateTime<Utc>) -> Result<()> {
     Ok(())
 }
 
-/// Collects all container UIDs (directory names) under the given base directory.
-fn collect_container_keys(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut container_keys = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(container_key) = entry.file_name().to_str() {
-                container_keys.push(container_key.to_string());
-            }
-        }
-    }
-
-    Ok(container_keys)
-}
-
 /// Aggregates minute-level data into dayly data for all given containers.
 fn process_all_containers<R: MetricContainerDayProcessorRepository>(
     repo: &R,
@@ -62,10 +39,14 @@ fn process_all_containers<R: MetricContainerDayProcessorRepository>(
 ) {
     for container_key in container_keys {
         match repo.append_row_aggregated(container_key, start, end, now) {
-            Ok(_) => debug!(
-                "✅ Aggregated container '{}' minute metrics from {} → {}",
-                container_key, start, end
-            ),
+            Ok(_) => {
+                record_processed();
+                debug!(
+                    "✅ Aggregated container '{}' minute metrics from {} → {}",
+                    container_key, start, end
+                )
+            }
+            // Not counted as a job failure: deleted containers routinely have no data
             Err(err) => debug!(
                 // TODO deleted container handling
                 "⚠️ Failed to aggregate container '{}' metrics: {}",
@@ -1,6 +1,3 @@
-use std::fs;
-use std::path::{PathBuf};
-
 use anyhow::{Result};
 use chrono::{DateTime,  Utc};
 
@@ -8,7 +5,9 @@ use crate::core::persistence::metrics::k8s::node::day::{
     metric_node_day_processor_repository_trait::MetricNodeDayProcessorRepository,
 };
 use tracing::{debug, error};
+use crate::scheduler::job_tracker::{record_failure, record_processed};
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repository::MetricNodeDayRepository;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
 use crate::core::persistence::metrics::k8s::path::metric_k8s_node_dir_path;
 use crate::scheduler::tasks::utils::time_util::TimeUtils;
 
@@ -20,12 +19,7 @@ pub async fn process_node_hour_to_day(now: DateTime<Utc>) -> Result<()> {
     let (start, end) = TimeUtils::previous_day_window(now);
     let base_dir = metric_k8s_node_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No nodes directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let node_names = collect_node_names(&base_dir)?;
+    let node_names = metric_object_keys("node", &base_dir)?;
     if node_names.is_empty() {
         debug!("No node metric directories found under {:?}", base_dir);
         return Ok(());
@@ -37,23 +31,6 @@ pub async fn process_node_hour_to_day(now: DateTime<Utc>) -> Result<()> {
     Ok(())
 }
 
-/// Collects all node UIDs (directory names) under the given base directory.
-fn collect_node_names(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut node_names = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(node_name) = entry.file_name().to_str() {
-                node_names.push(node_name.to_string());
-            }
-        }
-    }
-
-    Ok(node_names)
-}
-
 /// Aggregates minute-level data into dayly data for all given nodes.
 fn process_all_nodes<R: MetricNodeDayProcessorRepository>(
     repo: &R,
@@ -64,14 +41,20 @@ fn process_all_nodes<R: MetricNodeDayProcessorRepository>(
 ) {
     for node_name in node_names {
         match repo.append_row_aggregated(node_name, start, end, now) {
-            Ok(_) => debug!(
-                "✅ Aggregated node '{}' minute metrics from {} → {}",
-                node_name, start, end
-            ),
-            Err(err) => error!(
-                "⚠️ Failed to aggregate node '{}' metrics: {}",
-                node_name, err
-            ),
+            Ok(_) => {
+                record_processed();
+                debug!(
+                    "✅ Aggregated node '{}' minute metrics from {} → {}",
+                    node_name, start, end
+                )
+            }
+            Err(err) => {
+                error!(
+                    "⚠️ Failed to aggregate node '{}' metrics: {}",
+                    node_name, err
+                );
+                record_failure(node_name, &err);
+            }
         }
     }
 }
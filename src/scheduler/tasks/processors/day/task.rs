@@ -1,22 +1,29 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::{debug};
+use crate::core::persistence::jobs::aggregation_watermark_entity::AggregationLevel;
+use crate::scheduler::checkpoint::record_watermark;
+use crate::scheduler::tasks::utils::time_util::TimeUtils;
 use crate::scheduler::tasks::processors::day::pod::task::process_pod_hour_to_day;
 use crate::scheduler::tasks::processors::day::node::task::process_node_hour_to_day;
 use crate::scheduler::tasks::processors::day::container::task::process_container_hour_to_day;
 
 pub async fn run(now: DateTime<Utc>) -> Result<()> {
     debug!("Running day aggregation task...");
+    let window_end = TimeUtils::previous_day_window(now).1;
 
     process_pod_hour_to_day(now)
         .await
         .expect("Failed to process pod hour-to-day aggregation");
+    record_watermark(AggregationLevel::Day, "pod", window_end);
     process_container_hour_to_day(now)
         .await
         .expect("Failed to process container hour-to-day aggregation");
+    record_watermark(AggregationLevel::Day, "container", window_end);
     process_node_hour_to_day(now)
         .await
         .expect("Failed to process node hour-to-day aggregation");
+    record_watermark(AggregationLevel::Day, "node", window_end);
 
     Ok(())
 }
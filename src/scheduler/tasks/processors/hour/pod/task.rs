@@ -1,17 +1,16 @@
-use std::fs;
-use std::path::Path;
-
 use anyhow::{Result};
 use chrono::{DateTime, Utc};
 
 use crate::core::persistence::metrics::k8s::pod::hour::{
-    metric_pod_hour_fs_adapter::MetricPodHourFsAdapter,
     metric_pod_hour_processor_repository_trait::MetricPodHourProcessorRepository,
 };
 use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_processor_repository::MetricPodHourProcessorRepositoryImpl;
 use tracing::{debug, error};
+use crate::scheduler::job_tracker::{record_failure, record_processed};
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
 use crate::core::persistence::metrics::k8s::path::metric_k8s_pod_dir_path;
 use crate::scheduler::tasks::utils::time_util::TimeUtils;
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::metric_pod_hour_adapter;
 
 /// Aggregates all pods’ minute-level metrics into hour metrics.
 ///
@@ -21,42 +20,20 @@ pub async fn process_pod_minute_to_hour(now: DateTime<Utc>) -> Result<()> {
     let (start, end) = TimeUtils::previous_hour_window(now)?;
     let base_dir = metric_k8s_pod_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No pods directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let pod_uids = collect_pod_uids(&base_dir)?;
+    let pod_uids = metric_object_keys("pod", &base_dir)?;
     if pod_uids.is_empty() {
         debug!("No pod metric directories found under {:?}", base_dir);
         return Ok(());
     }
 
     let repo = MetricPodHourProcessorRepositoryImpl {
-        adapter: MetricPodHourFsAdapter,
+        adapter: metric_pod_hour_adapter(),
     };
 
     process_all_pods(&repo, &pod_uids, start, end, now);
     Ok(())
 }
 
-/// Collects all pod UIDs (directory names) under the given base directory.
-fn collect_pod_uids(base_dir: &Path) -> Result<Vec<String>> {
-    let mut pod_uids = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(pod_uid) = entry.file_name().to_str() {
-                pod_uids.push(pod_uid.to_string());
-            }
-        }
-    }
-
-    Ok(pod_uids)
-}
-
 /// Aggregates minute-level data into hour data for all given pods.
 fn process_all_pods<R: MetricPodHourProcessorRepository>(
     repo: &R,
@@ -67,14 +44,20 @@ fn process_all_pods<R: MetricPodHourProcessorRepository>(
 ) {
     for pod_uid in pod_uids {
         match repo.append_row_aggregated(pod_uid, start, end, now) {
-            Ok(_) => debug!(
-                "✅ Aggregated pod '{}' minute metrics from {} → {}",
-                pod_uid, start, end
-            ),
-            Err(err) => error!(
-                "⚠️ Failed to aggregate pod '{}' metrics: {}",
-                pod_uid, err
-            ),
+            Ok(_) => {
+                record_processed();
+                debug!(
+                    "✅ Aggregated pod '{}' minute metrics from {} → {}",
+                    pod_uid, start, end
+                )
+            }
+            Err(err) => {
+                error!(
+                    "⚠️ Failed to aggregate pod '{}' metrics: {}",
+                    pod_uid, err
+                );
+                record_failure(pod_uid, &err);
+            }
         }
     }
 }
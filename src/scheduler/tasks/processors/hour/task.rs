@@ -1,22 +1,29 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::{debug};
+use crate::core::persistence::jobs::aggregation_watermark_entity::AggregationLevel;
+use crate::scheduler::checkpoint::record_watermark;
+use crate::scheduler::tasks::utils::time_util::TimeUtils;
 use crate::scheduler::tasks::processors::hour::pod::task::process_pod_minute_to_hour;
 use crate::scheduler::tasks::processors::hour::node::task::process_node_minute_to_hour;
 use crate::scheduler::tasks::processors::hour::container::task::process_container_minute_to_hour;
 
 pub async fn run(now: DateTime<Utc>) -> Result<()> {
     debug!("Running hour aggregation task...");
+    let window_end = TimeUtils::previous_hour_window(now)?.1;
 
     process_node_minute_to_hour(now)
         .await
         .expect("Failed to process node minute-to-hour aggregation");
+    record_watermark(AggregationLevel::Hour, "node", window_end);
     process_pod_minute_to_hour(now)
         .await
         .expect("Failed to process pod minute-to-hour aggregation");
+    record_watermark(AggregationLevel::Hour, "pod", window_end);
     process_container_minute_to_hour(now)
         .await
         .expect("Failed to process container minute-to-hour aggregation");
+    record_watermark(AggregationLevel::Hour, "container", window_end);
 
 
     Ok(())
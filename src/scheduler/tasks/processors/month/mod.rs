@@ -0,0 +1,2 @@
+mod task;
+pub use task::{process_month_rollup, run};
@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::k8s::month::metric_month_repository::MetricMonthRepository;
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_dir_path, metric_k8s_node_dir_path, metric_k8s_pod_dir_path,
+};
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
+use crate::scheduler::job_tracker::{record_failure, record_processed};
+use crate::scheduler::tasks::utils::time_util::TimeUtils;
+
+/// Rolls the previous calendar month's day rows into month rows for every
+/// scope. Runs from the day task on the 1st; re-running replaces the rows.
+pub async fn run(now: DateTime<Utc>) -> Result<()> {
+    debug!("Running month aggregation task...");
+
+    for scope in ["pod", "container", "node"] {
+        process_month_rollup(scope, now)?;
+    }
+
+    Ok(())
+}
+
+/// Month rollup of one scope for the month that ended at or before `now`.
+pub fn process_month_rollup(scope: &str, now: DateTime<Utc>) -> Result<()> {
+    let (start, end) = TimeUtils::previous_month_window(now);
+
+    match scope {
+        "node" => {
+            let keys = metric_object_keys(scope, &metric_k8s_node_dir_path())?;
+            rollup_all(&MetricMonthRepository::node(), scope, &keys, start, end, now);
+        }
+        "pod" => {
+            let keys = metric_object_keys(scope, &metric_k8s_pod_dir_path())?;
+            rollup_all(&MetricMonthRepository::pod(), scope, &keys, start, end, now);
+        }
+        "container" => {
+            let keys = metric_object_keys(scope, &metric_k8s_container_dir_path())?;
+            rollup_all(&MetricMonthRepository::container(), scope, &keys, start, end, now);
+        }
+        other => return Err(anyhow!("unknown metric scope '{}'", other)),
+    }
+
+    Ok(())
+}
+
+fn rollup_all<T>(
+    repo: &MetricMonthRepository<T>,
+    scope: &str,
+    keys: &[String],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    now: DateTime<Utc>,
+) {
+    for key in keys {
+        match repo.append_row_aggregated(key, start, end, now) {
+            Ok(_) => {
+                record_processed();
+                debug!("Aggregated {} '{}' day metrics from {} → {}", scope, key, start, end);
+            }
+            Err(err) => {
+                error!("Failed to aggregate {} '{}' month metrics: {}", scope, key, err);
+                record_failure(key, &err);
+            }
+        }
+    }
+}
@@ -1,38 +1,31 @@
-use std::fs;
-use std::path::{PathBuf};
-
 use anyhow::{ Result};
-use chrono::{DateTime, Utc};
 use tracing::{debug, error};
+use crate::scheduler::job_tracker::{record_failure, record_processed};
 
+use crate::scheduler::tasks::processors::retention::task::RetentionCutoffs;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_repository::MetricContainerDayRepository;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_retention_repository_traits::MetricContainerDayRetentionRepository;
-use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_fs_adapter::MetricContainerHourFsAdapter;
 use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_retention_repository_traits::MetricContainerHourRetentionRepository;
 use crate::core::persistence::metrics::k8s::container::hour::metric_processor_retention_container_hour_repository::MetricContainerHourRetentionRepositoryImpl;
-use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_retention_repository_traits::MetricContainerMinuteRetentionRepository;
 use crate::core::persistence::metrics::k8s::container::minute::metric_processor_retention_container_minute_repository::MetricContainerMinuteRetentionRepositoryImpl;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
 use crate::core::persistence::metrics::k8s::path::metric_k8s_container_dir_path;
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::{metric_container_hour_adapter, metric_container_minute_adapter};
 
 /// Runs retention cleanup for all containers across minute/hour/day metrics.
-pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_before: DateTime<Utc>) -> Result<()> {
+pub async fn run(cutoffs: &RetentionCutoffs) -> Result<()> {
     let base_dir = metric_k8s_container_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No containers directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let container_uids = collect_container_uids(&base_dir)?;
+    let container_uids = metric_object_keys("container", &base_dir)?;
     if container_uids.is_empty() {
         debug!("No container metric directories found under {:?}", base_dir);
         return Ok(());
     }
 
-    // Create adapters (stateless, no constructor needed)
-    let hour_adapter = MetricContainerHourFsAdapter;
-    let minute_adapter = MetricContainerMinuteFsAdapter;
+    // Create adapters for the configured storage backend
+    let hour_adapter = metric_container_hour_adapter();
+    let minute_adapter = metric_container_minute_adapter();
 
     // Create repositories
     let day_repo = MetricContainerDayRepository::default();
@@ -42,15 +35,32 @@ pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_b
     // Run cleanup for each container
     for container_uid in &container_uids {
         debug!("🧹 Running retention cleanup for container '{}'", container_uid);
+        let mut failed = false;
 
-        if let Err(err) = minute_repo.cleanup_old(container_uid, minute_before) {
-            error!("⚠️ Minute cleanup failed for {}: {}", container_uid, err);
+        if let Some(before) = cutoffs.minute_before {
+            if let Err(err) = minute_repo.cleanup_old(container_uid, before) {
+                error!("⚠️ Minute cleanup failed for {}: {}", container_uid, err);
+                record_failure(container_uid, &err);
+                failed = true;
+            }
         }
-        if let Err(err) = hour_repo.cleanup_old(container_uid, hour_before) {
-            error!("⚠️ Hour cleanup failed for {}: {}", container_uid, err);
+        if let Some(before) = cutoffs.hour_before {
+            if let Err(err) = hour_repo.cleanup_old(container_uid, before) {
+                error!("⚠️ Hour cleanup failed for {}: {}", container_uid, err);
+                record_failure(container_uid, &err);
+                failed = true;
+            }
         }
-        if let Err(err) = day_repo.cleanup_old(container_uid, day_before) {
-            error!("⚠️ Day cleanup failed for {}: {}", container_uid, err);
+        if let Some(before) = cutoffs.day_before {
+            if let Err(err) = day_repo.cleanup_old(container_uid, before) {
+                error!("⚠️ Day cleanup failed for {}: {}", container_uid, err);
+                record_failure(container_uid, &err);
+                failed = true;
+            }
+        }
+
+        if !failed {
+            record_processed();
         }
     }
 
@@ -58,19 +68,3 @@ pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_b
     Ok(())
 }
 
-/// Collects all container UIDs (directory names) under the given base directory.
-fn collect_container_uids(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut container_uids = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(container_uid) = entry.file_name().to_str() {
-                container_uids.push(container_uid.to_string());
-            }
-        }
-    }
-
-    Ok(container_uids)
-}
@@ -2,4 +2,6 @@ pub mod task;
 pub mod container;
 pub mod node;
 pub mod pod;
+pub mod pvc;
+pub mod network_flow;
 
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::k8s::network_flow::hour::metric_network_flow_hour_repository::MetricNetworkFlowHourRepository;
+use crate::core::persistence::metrics::k8s::path::metric_k8s_network_flow_dir_path;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
+use crate::scheduler::job_tracker::{record_failure, record_processed};
+use crate::scheduler::tasks::processors::retention::task::RetentionCutoffs;
+
+/// Flows only have hour rows, so only the hour cutoff applies.
+pub async fn run(cutoffs: &RetentionCutoffs) -> Result<()> {
+    let Some(before) = cutoffs.hour_before else {
+        return Ok(());
+    };
+
+    let namespaces = metric_object_keys("network_flow", &metric_k8s_network_flow_dir_path())?;
+    if namespaces.is_empty() {
+        debug!("No network flow metric directories found");
+        return Ok(());
+    }
+
+    let repo = MetricNetworkFlowHourRepository::new();
+    for namespace in &namespaces {
+        match repo.cleanup_old(namespace, before) {
+            Ok(()) => record_processed(),
+            Err(err) => {
+                error!("⚠️ Hour cleanup failed for network flows of {}: {}", namespace, err);
+                record_failure(namespace, &err);
+            }
+        }
+    }
+
+    debug!("✅ Retention cleanup complete for all network flows");
+    Ok(())
+}
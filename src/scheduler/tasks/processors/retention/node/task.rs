@@ -1,38 +1,31 @@
-use std::fs;
-use std::path::{PathBuf};
-
 use anyhow::{Result};
-use chrono::{DateTime, Utc};
 use tracing::{debug, error};
+use crate::scheduler::job_tracker::{record_failure, record_processed};
 
+use crate::scheduler::tasks::processors::retention::task::RetentionCutoffs;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repository::MetricNodeDayRepository;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_retention_repository_traits::MetricNodeDayRetentionRepository;
-use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_fs_adapter::MetricNodeHourFsAdapter;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_retention_repository_traits::MetricNodeHourRetentionRepository;
-use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_adapter::MetricNodeMinuteFsAdapter;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_retention_repository_traits::MetricNodeMinuteRetentionRepository;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
 use crate::core::persistence::metrics::k8s::path::metric_k8s_node_dir_path;
 use crate::core::persistence::metrics::k8s::node::hour::metric_processor_retention_node_hour_repository::MetricNodeHourRetentionRepositoryImpl;
 use crate::core::persistence::metrics::k8s::node::minute::metric_processor_retention_node_minute_repository::MetricNodeMinuteRetentionRepositoryImpl;
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::{metric_node_hour_adapter, metric_node_minute_adapter};
 
 /// Runs retention cleanup for all nodes across minute/hour/day metrics.
-pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_before: DateTime<Utc>) -> Result<()> {
+pub async fn run(cutoffs: &RetentionCutoffs) -> Result<()> {
     let base_dir = metric_k8s_node_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No nodes directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let node_uids = collect_node_uids(&base_dir)?;
+    let node_uids = metric_object_keys("node", &base_dir)?;
     if node_uids.is_empty() {
         debug!("No node metric directories found under {:?}", base_dir);
         return Ok(());
     }
 
-    // Create adapters (stateless, no constructor needed)
-    let hour_adapter = MetricNodeHourFsAdapter;
-    let minute_adapter = MetricNodeMinuteFsAdapter;
+    // Create adapters for the configured storage backend
+    let hour_adapter = metric_node_hour_adapter();
+    let minute_adapter = metric_node_minute_adapter();
 
     // Create repositories
     let day_repo = MetricNodeDayRepository::default();
@@ -42,15 +35,32 @@ pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_b
     // Run cleanup for each node
     for node_uid in &node_uids {
         debug!("🧹 Running retention cleanup for node '{}'", node_uid);
+        let mut failed = false;
 
-        if let Err(err) = minute_repo.cleanup_old(node_uid, minute_before) {
-            error!("⚠️ Minute cleanup failed for {}: {}", node_uid, err);
+        if let Some(before) = cutoffs.minute_before {
+            if let Err(err) = minute_repo.cleanup_old(node_uid, before) {
+                error!("⚠️ Minute cleanup failed for {}: {}", node_uid, err);
+                record_failure(node_uid, &err);
+                failed = true;
+            }
         }
-        if let Err(err) = hour_repo.cleanup_old(node_uid, hour_before) {
-            error!("⚠️ Hour cleanup failed for {}: {}", node_uid, err);
+        if let Some(before) = cutoffs.hour_before {
+            if let Err(err) = hour_repo.cleanup_old(node_uid, before) {
+                error!("⚠️ Hour cleanup failed for {}: {}", node_uid, err);
+                record_failure(node_uid, &err);
+                failed = true;
+            }
         }
-        if let Err(err) = day_repo.cleanup_old(node_uid, day_before) {
-            error!("⚠️ Day cleanup failed for {}: {}", node_uid, err);
+        if let Some(before) = cutoffs.day_before {
+            if let Err(err) = day_repo.cleanup_old(node_uid, before) {
+                error!("⚠️ Day cleanup failed for {}: {}", node_uid, err);
+                record_failure(node_uid, &err);
+                failed = true;
+            }
+        }
+
+        if !failed {
+            record_processed();
         }
     }
 
@@ -58,19 +68,3 @@ pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_b
     Ok(())
 }
 
-/// Collects all node UIDs (directory names) under the given base directory.
-fn collect_node_uids(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut node_uids = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(node_uid) = entry.file_name().to_str() {
-                node_uids.push(node_uid.to_string());
-            }
-        }
-    }
-
-    Ok(node_uids)
-}
@@ -1,41 +1,34 @@
-use std::fs;
-use std::path::PathBuf;
-
 use anyhow::{ Result};
-use chrono::{DateTime, Utc};
 use tracing::{debug, error};
+use crate::scheduler::job_tracker::{record_failure, record_processed};
 
-use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_fs_adapter::MetricPodDayFsAdapter;
+use crate::scheduler::tasks::processors::retention::task::RetentionCutoffs;
 use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_retention_repository_traits::MetricPodDayRetentionRepository;
-use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_fs_adapter::MetricPodHourFsAdapter;
 use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_retention_repository_traits::MetricPodHourRetentionRepository;
-use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_retention_repository_traits::MetricPodMinuteRetentionRepository;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
+use crate::core::persistence::metrics::metric_partition_archive::MetricPartitionArchive;
 use crate::core::persistence::metrics::k8s::path::metric_k8s_pod_dir_path;
 use crate::core::persistence::metrics::k8s::pod::day::metric_processor_retention_pod_day_repository::MetricPodDayRetentionRepositoryImpl;
 use crate::core::persistence::metrics::k8s::pod::hour::metric_processor_retention_pod_hour_repository::MetricPodHourRetentionRepositoryImpl;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_processor_retention_pod_minute_repository::MetricPodMinuteRetentionRepositoryImpl;
+use crate::core::persistence::metrics::k8s::metric_adapter_factory::{metric_pod_day_adapter, metric_pod_hour_adapter, metric_pod_minute_adapter};
 
 /// Runs retention cleanup for all pods across minute/hour/day metrics.
-pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_before: DateTime<Utc>) -> Result<()> {
+pub async fn run(cutoffs: &RetentionCutoffs) -> Result<()> {
 
     let base_dir = metric_k8s_pod_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No pods directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let pod_uids = collect_pod_uids(&base_dir)?;
+    let pod_uids = metric_object_keys("pod", &base_dir)?;
     if pod_uids.is_empty() {
         debug!("No pod metric directories found under {:?}", base_dir);
         return Ok(());
     }
 
-    // Create adapters (stateless, no constructor needed)
-    let day_adapter = MetricPodDayFsAdapter;
-    let hour_adapter = MetricPodHourFsAdapter;
-    let minute_adapter = MetricPodMinuteFsAdapter;
+    // Create adapters for the configured storage backend
+    let day_adapter = metric_pod_day_adapter();
+    let hour_adapter = metric_pod_hour_adapter();
+    let minute_adapter = metric_pod_minute_adapter();
 
     // Create repositories
     let day_repo = MetricPodDayRetentionRepositoryImpl { adapter: day_adapter };
@@ -45,35 +38,44 @@ pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_b
     // Run cleanup for each pod
     for pod_uid in &pod_uids {
         debug!("🧹 Running retention cleanup for pod '{}'", pod_uid);
+        let mut failed = false;
 
-        if let Err(err) = minute_repo.cleanup_old(pod_uid, minute_before) {
-            error!("⚠️ Minute cleanup failed for {}: {}", pod_uid, err);
+        if let Some(before) = cutoffs.minute_before {
+            if let Err(err) = minute_repo.cleanup_old(pod_uid, before) {
+                error!("⚠️ Minute cleanup failed for {}: {}", pod_uid, err);
+                record_failure(pod_uid, &err);
+                failed = true;
+            }
         }
-        if let Err(err) = hour_repo.cleanup_old(pod_uid, hour_before) {
-            error!("⚠️ Hour cleanup failed for {}: {}", pod_uid, err);
+        if let Some(before) = cutoffs.hour_before {
+            if let Err(err) = hour_repo.cleanup_old(pod_uid, before) {
+                error!("⚠️ Hour cleanup failed for {}: {}", pod_uid, err);
+                record_failure(pod_uid, &err);
+                failed = true;
+            }
         }
-        if let Err(err) = day_repo.cleanup_old(pod_uid, day_before) {
-            error!("⚠️ Day cleanup failed for {}: {}", pod_uid, err);
+        if let Some(before) = cutoffs.day_before {
+            if let Err(err) = day_repo.cleanup_old(pod_uid, before) {
+                error!("⚠️ Day cleanup failed for {}: {}", pod_uid, err);
+                record_failure(pod_uid, &err);
+                failed = true;
+            }
         }
-    }
-
-    debug!("✅ Retention cleanup complete for all pods");
-    Ok(())
-}
 
-/// Collects all pod UIDs (directory names) under the given base directory.
-fn collect_pod_uids(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut pod_uids = Vec::new();
+        if !failed {
+            record_processed();
+        }
+    }
 
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(pod_uid) = entry.file_name().to_str() {
-                pod_uids.push(pod_uid.to_string());
-            }
+    // Compacted hour partitions follow the same cutoff
+    if let Some(before) = cutoffs.hour_before {
+        if let Err(err) = MetricPartitionArchive::cleanup_before("pod", "h", &before.format("%Y-%m").to_string()) {
+            error!("⚠️ Hour archive cleanup failed: {}", err);
+            record_failure("pod archive", &err);
         }
     }
 
-    Ok(pod_uids)
+    debug!("✅ Retention cleanup complete for all pods");
+    Ok(())
 }
+
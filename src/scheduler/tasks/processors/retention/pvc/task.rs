@@ -0,0 +1,35 @@
+use anyhow::Result;
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::k8s::path::metric_k8s_pvc_dir_path;
+use crate::core::persistence::metrics::k8s::pvc::hour::metric_pvc_hour_repository::MetricPvcHourRepository;
+use crate::core::persistence::metrics::metric_storage_backend::metric_object_keys;
+use crate::scheduler::job_tracker::{record_failure, record_processed};
+use crate::scheduler::tasks::processors::retention::task::RetentionCutoffs;
+
+/// Claims only have hour rows, so only the hour cutoff applies.
+pub async fn run(cutoffs: &RetentionCutoffs) -> Result<()> {
+    let Some(before) = cutoffs.hour_before else {
+        return Ok(());
+    };
+
+    let keys = metric_object_keys("pvc", &metric_k8s_pvc_dir_path())?;
+    if keys.is_empty() {
+        debug!("No PVC metric directories found");
+        return Ok(());
+    }
+
+    let repo = MetricPvcHourRepository::new();
+    for key in &keys {
+        match repo.cleanup_old(key, before) {
+            Ok(()) => record_processed(),
+            Err(err) => {
+                error!("⚠️ Hour cleanup failed for PVC {}: {}", key, err);
+                record_failure(key, &err);
+            }
+        }
+    }
+
+    debug!("✅ Retention cleanup complete for all PVCs");
+    Ok(())
+}
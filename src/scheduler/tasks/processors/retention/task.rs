@@ -1,8 +1,40 @@
 use anyhow::Result;
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, Months, Utc};
+use tracing::{debug, info};
 use crate::scheduler::tasks::processors::retention;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
 use crate::core::persistence::info::fixed::setting::info_setting_retention_repository_trait::InfoSettingRetentionRepository;
 
+/// Per-granularity deletion cutoffs. `None` keeps that granularity forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionCutoffs {
+    pub minute_before: Option<DateTime<Utc>>,
+    pub hour_before: Option<DateTime<Utc>>,
+    pub day_before: Option<DateTime<Utc>>,
+}
+
+impl RetentionCutoffs {
+    /// Derives cutoffs from the retention settings using calendar months/years,
+    /// so "13 months" lands on the same day-of-month rather than 390 days back.
+    /// A retention value of `0` disables cleanup for that granularity.
+    pub fn from_settings(settings: &InfoSettingEntity, now: DateTime<Utc>) -> Self {
+        let minute_before = match settings.minute_retention_days {
+            0 => None,
+            d => Some(now - Duration::days(d.into())),
+        };
+        let hour_before = match settings.hour_retention_months {
+            0 => None,
+            m => now.checked_sub_months(Months::new(m)),
+        };
+        let day_before = match settings.day_retention_years {
+            0 => None,
+            y => now.checked_sub_months(Months::new(y.saturating_mul(12))),
+        };
+
+        Self { minute_before, hour_before, day_before }
+    }
+}
+
 pub struct RetentionTask<R: InfoSettingRetentionRepository> {
     pub settings_repo: R,
 }
@@ -14,15 +46,66 @@ impl<R: InfoSettingRetentionRepository> RetentionTask<R> {
 
     pub async fn run(&self, now: DateTime<Utc>) -> Result<()> {
         let settings = self.settings_repo.read()?;  // Load config
+        let cutoffs = RetentionCutoffs::from_settings(&settings, now);
+
+        if cutoffs.minute_before.is_none() && cutoffs.hour_before.is_none() && cutoffs.day_before.is_none() {
+            debug!("Retention disabled for all granularities; skipping cleanup");
+            return Ok(());
+        }
 
-        let minute_before = now - Duration::days(settings.minute_retention_days.into());
-        let hour_before   = now - Duration::days((settings.hour_retention_months * 30).into());
-        let day_before    = now - Duration::days((settings.day_retention_years * 365).into());
+        info!(
+            minute_before = ?cutoffs.minute_before,
+            hour_before = ?cutoffs.hour_before,
+            day_before = ?cutoffs.day_before,
+            "Running metric retention cleanup"
+        );
 
-        retention::pod::task::run(minute_before, hour_before, day_before).await?;
-        retention::node::task::run(minute_before, hour_before, day_before).await?;
-        retention::container::task::run(minute_before, hour_before, day_before).await?;
+        retention::pod::task::run(&cutoffs).await?;
+        retention::node::task::run(&cutoffs).await?;
+        retention::container::task::run(&cutoffs).await?;
+        retention::pvc::task::run(&cutoffs).await?;
+        retention::network_flow::task::run(&cutoffs).await?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn cutoffs_use_calendar_months_and_years() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 31, 0, 30, 0).unwrap();
+        let settings = InfoSettingEntity {
+            minute_retention_days: 14,
+            hour_retention_months: 13,
+            day_retention_years: 3,
+            ..InfoSettingEntity::default()
+        };
+
+        let c = RetentionCutoffs::from_settings(&settings, now);
+
+        assert_eq!(c.minute_before, Some(Utc.with_ymd_and_hms(2025, 3, 17, 0, 30, 0).unwrap()));
+        // Feb 2024 has 29 days; chrono clamps to the last valid day.
+        assert_eq!(c.hour_before, Some(Utc.with_ymd_and_hms(2024, 2, 29, 0, 30, 0).unwrap()));
+        assert_eq!(c.day_before, Some(Utc.with_ymd_and_hms(2022, 3, 31, 0, 30, 0).unwrap()));
+    }
+
+    #[test]
+    fn zero_retention_disables_cleanup() {
+        let settings = InfoSettingEntity {
+            minute_retention_days: 0,
+            hour_retention_months: 0,
+            day_retention_years: 0,
+            ..InfoSettingEntity::default()
+        };
+
+        let c = RetentionCutoffs::from_settings(&settings, Utc::now());
+
+        assert_eq!(c.minute_before, None);
+        assert_eq!(c.hour_before, None);
+        assert_eq!(c.day_before, None);
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use chrono::{DateTime, Duration, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, Months, Timelike, Utc};
 
 pub struct TimeUtils;
 
@@ -60,4 +60,18 @@ impl TimeUtils {
 
         (start, end)
     }
+
+    /// Returns the start and end of the **previous full calendar month** (UTC).
+    ///
+    /// Example:
+    /// If `now = 2025-11-26T17:23:45Z`, this returns:
+    /// - `start = 2025-10-01T00:00:00Z`
+    /// - `end   = 2025-11-01T00:00:00Z`
+    pub(crate) fn previous_month_window(now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+        let first = now.date_naive().with_day(1).unwrap();
+        let end = DateTime::from_naive_utc_and_offset(first.and_hms_opt(0, 0, 0).unwrap(), Utc);
+        let start = end - Months::new(1);
+
+        (start, end)
+    }
 }
@@ -0,0 +1,190 @@
+//! Synthetic cluster fixtures with deterministic hourly series.
+//!
+//! Usage follows small fixed cycles over the hour index, so every run (and every
+//! machine) produces the same rows and the same endpoint responses.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde_json::json;
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::repositories::{MetricTier, Repositories};
+use crate::test_support::memory_store::{InMemoryInfoStore, InMemoryMetricStores};
+
+const GIB: u64 = 1024 * 1024 * 1024;
+const MIB: u64 = 1024 * 1024;
+const NANO: u64 = 1_000_000_000;
+
+/// Nodes and pods with `hours` hourly rows each, starting at `start`.
+pub struct FixtureCluster {
+    pub start: DateTime<Utc>,
+    pub hours: u32,
+    pub nodes: Vec<InfoNodeEntity>,
+    pub pods: Vec<InfoPodEntity>,
+}
+
+impl FixtureCluster {
+    pub fn new(start: DateTime<Utc>, hours: u32) -> Self {
+        Self { start, hours, nodes: Vec::new(), pods: Vec::new() }
+    }
+
+    pub fn node(mut self, name: &str, cpu_cores: u32, memory_gib: u64) -> Self {
+        self.nodes.push(InfoNodeEntity {
+            node_name: Some(name.to_string()),
+            node_uid: Some(format!("uid-{}", name)),
+            creation_timestamp: Some(self.start - Duration::days(30)),
+            cpu_capacity_cores: Some(cpu_cores),
+            memory_capacity_bytes: Some(memory_gib * GIB),
+            ephemeral_storage_capacity_bytes: Some(100 * GIB),
+            cpu_allocatable_cores: Some(cpu_cores),
+            memory_allocatable_bytes: Some(memory_gib * GIB),
+            ready: Some(true),
+            operating_system: Some("linux".to_string()),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Pod owned by `deployment` through a ReplicaSet, running for the whole window.
+    pub fn pod(mut self, uid: &str, namespace: &str, node: &str, deployment: &str) -> Self {
+        self.pods.push(InfoPodEntity {
+            pod_name: Some(format!("{}-7d9f8-{}", deployment, uid)),
+            namespace: Some(namespace.to_string()),
+            pod_uid: Some(uid.to_string()),
+            creation_timestamp: Some(self.start - Duration::days(1)),
+            start_time: Some(self.start - Duration::days(1)),
+            node_name: Some(node.to_string()),
+            phase: Some("Running".to_string()),
+            ready: Some(true),
+            owner_kind: Some("ReplicaSet".to_string()),
+            owner_name: Some(format!("{}-7d9f8", deployment)),
+            workload_kind: Some("Deployment".to_string()),
+            workload_name: Some(deployment.to_string()),
+            container_count: Some(1),
+            ..Default::default()
+        });
+        self
+    }
+
+    pub fn node_names(&self) -> Vec<String> {
+        self.nodes.iter().filter_map(|n| n.node_name.clone()).collect()
+    }
+
+    pub fn pod_uids(&self) -> Vec<String> {
+        self.pods.iter().filter_map(|p| p.pod_uid.clone()).collect()
+    }
+
+    /// Hour-granularity query over exactly the generated rows.
+    pub fn query(&self) -> RangeQuery {
+        let end = self.start + Duration::hours(self.hours as i64 - 1);
+        serde_json::from_value(json!({
+            "start": self.start.naive_utc(),
+            "end": end.naive_utc(),
+            "granularity": "hour",
+        }))
+        .expect("fixture range query")
+    }
+
+    /// In-memory repositories holding the fixture's info records and hour rows.
+    pub fn repositories(&self) -> Repositories {
+        let stores = InMemoryMetricStores::default();
+
+        let node_rows = stores.node.tier(MetricTier::Hour);
+        for (i, node) in self.nodes.iter().enumerate() {
+            let name = node.node_name.as_deref().unwrap_or_default();
+            for h in 0..self.hours {
+                node_rows.insert(name, self.node_row(node, i as u64, h as u64));
+            }
+        }
+
+        let pod_rows = stores.pod.tier(MetricTier::Hour);
+        for (j, pod) in self.pods.iter().enumerate() {
+            let uid = pod.pod_uid.as_deref().unwrap_or_default();
+            for h in 0..self.hours {
+                pod_rows.insert(uid, self.pod_row(j as u64, h as u64));
+            }
+        }
+
+        Repositories {
+            info_pods: Arc::new(InMemoryInfoStore::new(self.pods.clone(), |p| p.pod_uid.clone())),
+            info_nodes: Arc::new(InMemoryInfoStore::new(self.nodes.clone(), |n| n.node_name.clone())),
+            info_containers: Arc::new(InMemoryInfoStore::<InfoContainerEntity>::new(Vec::new(), |_| None)),
+            metric_stores: Arc::new(stores),
+        }
+    }
+
+    fn at(&self, h: u64) -> DateTime<Utc> {
+        self.start + Duration::hours(h as i64)
+    }
+
+    /// 30–45% CPU and 40–52% memory of capacity, cycling every 4 / 5 hours.
+    fn node_row(&self, node: &InfoNodeEntity, i: u64, h: u64) -> MetricNodeEntity {
+        let cores = node.cpu_capacity_cores.unwrap_or_default() as u64;
+        let memory = node.memory_capacity_bytes.unwrap_or_default();
+        let cpu = cores * NANO * (30 + 5 * (h % 4)) / 100;
+        let working_set = memory * (40 + 3 * (h % 5)) / 100;
+
+        MetricNodeEntity {
+            time: self.at(h),
+            cpu_usage_nano_cores: Some(cpu),
+            cpu_usage_core_nano_seconds: Some(cpu * 3600 * (h + 1)),
+            memory_usage_bytes: Some(working_set + 256 * MIB),
+            memory_working_set_bytes: Some(working_set),
+            memory_rss_bytes: Some(working_set / 2),
+            memory_page_faults: Some(1_000 * (h + 1)),
+            network_physical_rx_bytes: Some((h + 1) * (i + 1) * 50 * MIB),
+            network_physical_tx_bytes: Some((h + 1) * (i + 1) * 20 * MIB),
+            network_physical_rx_errors: Some(0),
+            network_physical_tx_errors: Some(0),
+            fs_used_bytes: Some((20 + h % 3) * GIB),
+            fs_capacity_bytes: node.ephemeral_storage_capacity_bytes,
+            fs_inodes_used: Some(100_000 + 1_000 * h),
+            fs_inodes: Some(6_000_000),
+            cpu_allocatable_nano_cores: Some(cores * NANO),
+            memory_allocatable_bytes: node.memory_allocatable_bytes,
+            ..Default::default()
+        }
+    }
+
+    /// `(j + 1) × 100m` CPU and `(j + 1) × 128Mi` memory plus a small hourly swing.
+    fn pod_row(&self, j: u64, h: u64) -> MetricPodEntity {
+        let cpu = (j + 1) * 100_000_000 + 10_000_000 * (h % 3);
+        let working_set = (j + 1) * 128 * MIB + 16 * MIB * (h % 4);
+
+        MetricPodEntity {
+            time: self.at(h),
+            cpu_usage_nano_cores: Some(cpu),
+            cpu_usage_core_nano_seconds: Some(cpu * 3600 * (h + 1)),
+            memory_usage_bytes: Some(working_set + 8 * MIB),
+            memory_working_set_bytes: Some(working_set),
+            memory_rss_bytes: Some(working_set / 2),
+            memory_page_faults: Some(100 * (h + 1)),
+            network_physical_rx_bytes: Some((h + 1) * (j + 1) * 5 * MIB),
+            network_physical_tx_bytes: Some((h + 1) * (j + 1) * 2 * MIB),
+            network_physical_rx_errors: Some(0),
+            network_physical_tx_errors: Some(0),
+            es_used_bytes: Some((j + 1) * 200 * MIB),
+            es_capacity_bytes: Some(100 * GIB),
+            es_inodes_used: Some(500 + 10 * h),
+            es_inodes: Some(6_000_000),
+            ..Default::default()
+        }
+    }
+}
+
+/// Two nodes, four pods in two namespaces over six hours of 2025-03-03.
+pub fn small_cluster() -> FixtureCluster {
+    FixtureCluster::new(Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap(), 6)
+        .node("node-a", 4, 16)
+        .node("node-b", 8, 32)
+        .pod("pod-1", "payments", "node-a", "api")
+        .pod("pod-2", "payments", "node-b", "api")
+        .pod("pod-3", "search", "node-a", "indexer")
+        .pod("pod-4", "search", "node-b", "query")
+}
@@ -0,0 +1,63 @@
+//! Golden-file assertions for JSON responses.
+//!
+//! Snapshots live in `src/test_support/golden/<name>.json` and are committed.
+//! A missing or different snapshot fails the test; `UPDATE_GOLDEN=1 cargo test`
+//! records them after an intended change, so the diff shows up in review.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::core::persistence::storage_path::TEST_BASE_PATH;
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/test_support/golden")
+        .join(format!("{}.json", name))
+}
+
+/// Compares `actual` with the snapshot `name`; `UPDATE_GOLDEN=1` records it instead.
+pub fn assert_golden(name: &str, actual: &Value) {
+    let path = golden_path(name);
+    let rendered = format!("{}\n", serde_json::to_string_pretty(actual).unwrap());
+
+    if env::var("UPDATE_GOLDEN").as_deref() == Ok("1") {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, rendered).unwrap();
+        return;
+    }
+
+    if !path.exists() {
+        panic!(
+            "golden file {} is missing\n--- actual\n{}\nrecord it with UPDATE_GOLDEN=1 and commit it",
+            path.display(),
+            rendered,
+        );
+    }
+
+    let expected: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap())
+        .unwrap_or_else(|e| panic!("golden file {} is not JSON: {}", path.display(), e));
+    if &expected != actual {
+        panic!(
+            "response differs from {}\n--- expected\n{}\n--- actual\n{}\nrerun with UPDATE_GOLDEN=1 if the change is intended",
+            path.display(),
+            serde_json::to_string_pretty(&expected).unwrap(),
+            rendered,
+        );
+    }
+}
+
+/// Points the data directory at an empty temp dir (once per test binary), so
+/// settings, unit prices and other fixed info read as their defaults. The path
+/// goes through [`TEST_BASE_PATH`], not the environment, so parallel tests
+/// never see it change.
+pub fn empty_base_path() {
+    TEST_BASE_PATH.get_or_init(|| {
+        let dir = env::temp_dir().join(format!("rustcost_golden_base_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    });
+}
@@ -0,0 +1,119 @@
+//! Golden responses of the metric list endpoints over [`small_cluster`].
+
+use std::future::Future;
+
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::repositories::with_repositories;
+use crate::domain::metric::k8s::cluster::service as cluster;
+use crate::domain::metric::k8s::deployment::service as deployment;
+use crate::domain::metric::k8s::namespace::service as namespace;
+use crate::domain::metric::k8s::node::service as node;
+use crate::domain::metric::k8s::pod::service as pod;
+use crate::test_support::fixtures::{small_cluster, FixtureCluster};
+use crate::test_support::golden::{assert_golden, empty_base_path};
+
+/// Runs `endpoint` against the fixture's repositories and checks the snapshot.
+async fn check<F, Fut>(name: &str, endpoint: F)
+where
+    F: FnOnce(&FixtureCluster) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    empty_base_path();
+    let fixture = small_cluster();
+    let value = with_repositories(fixture.repositories(), endpoint(&fixture))
+        .await
+        .unwrap_or_else(|e| panic!("{} failed: {:#}", name, e));
+    assert_golden(name, &value);
+}
+
+#[tokio::test]
+async fn pods_raw() {
+    check("pods_raw", |c| pod::get_metric_k8s_pods_raw(c.query(), c.pod_uids())).await;
+}
+
+#[tokio::test]
+async fn pods_raw_summary() {
+    check("pods_raw_summary", |c| pod::get_metric_k8s_pods_raw_summary(c.query(), c.pod_uids())).await;
+}
+
+#[tokio::test]
+async fn pods_cost() {
+    check("pods_cost", |c| pod::get_metric_k8s_pods_cost(c.query(), c.pod_uids())).await;
+}
+
+#[tokio::test]
+async fn pods_cost_summary() {
+    check("pods_cost_summary", |c| pod::get_metric_k8s_pods_cost_summary(c.query(), c.pod_uids())).await;
+}
+
+#[tokio::test]
+async fn nodes_raw() {
+    check("nodes_raw", |c| node::get_metric_k8s_nodes_raw(c.query(), c.node_names())).await;
+}
+
+#[tokio::test]
+async fn nodes_raw_summary() {
+    check("nodes_raw_summary", |c| node::get_metric_k8s_nodes_raw_summary(c.query(), c.node_names())).await;
+}
+
+#[tokio::test]
+async fn nodes_cost() {
+    check("nodes_cost", |c| node::get_metric_k8s_nodes_cost(c.query(), c.node_names())).await;
+}
+
+#[tokio::test]
+async fn nodes_cost_summary() {
+    check("nodes_cost_summary", |c| node::get_metric_k8s_nodes_cost_summary(c.query(), c.node_names())).await;
+}
+
+#[tokio::test]
+async fn namespaces_raw() {
+    check("namespaces_raw", |c| namespace::get_metric_k8s_namespaces_raw(c.query(), Vec::new())).await;
+}
+
+#[tokio::test]
+async fn namespaces_cost() {
+    check("namespaces_cost", |c| namespace::get_metric_k8s_namespaces_cost(c.query(), Vec::new())).await;
+}
+
+#[tokio::test]
+async fn namespaces_cost_summary() {
+    check("namespaces_cost_summary", |c| namespace::get_metric_k8s_namespaces_cost_summary(c.query(), Vec::new())).await;
+}
+
+#[tokio::test]
+async fn deployments_cost() {
+    check("deployments_cost", |c| deployment::get_metric_k8s_deployments_cost(c.query(), Vec::new())).await;
+}
+
+#[tokio::test]
+async fn cluster_raw() {
+    check("cluster_raw", |c| cluster::get_metric_k8s_cluster_raw(c.node_names(), c.query())).await;
+}
+
+#[tokio::test]
+async fn cluster_cost() {
+    check("cluster_cost", |c| {
+        cluster::get_metric_k8s_cluster_cost(c.node_names(), InfoUnitPriceEntity::default(), c.query())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn cost_reconcile() {
+    check("cost_reconcile", |c| {
+        cluster::get_metric_k8s_cost_reconcile(c.node_names(), InfoUnitPriceEntity::default(), c.query())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn cluster_cost_summary() {
+    check("cluster_cost_summary", |c| {
+        cluster::get_metric_k8s_cluster_cost_summary(c.node_names(), InfoUnitPriceEntity::default(), c.query())
+    })
+    .await;
+}
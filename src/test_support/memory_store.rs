@@ -0,0 +1,186 @@
+//! In-memory stores behind the repository trait objects.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::metric_store_trait::{MetricStore, MetricStoreRow};
+use crate::core::persistence::repositories::{InfoStore, MetricStoreProvider, MetricTier};
+
+/// Rows per object, kept in time order. Clones share the same rows.
+pub struct InMemoryMetricStore<T> {
+    rows: Arc<Mutex<BTreeMap<String, Vec<T>>>>,
+}
+
+impl<T> Clone for InMemoryMetricStore<T> {
+    fn clone(&self) -> Self {
+        Self { rows: self.rows.clone() }
+    }
+}
+
+impl<T> Default for InMemoryMetricStore<T> {
+    fn default() -> Self {
+        Self { rows: Arc::new(Mutex::new(BTreeMap::new())) }
+    }
+}
+
+impl<T: MetricStoreRow + Clone> InMemoryMetricStore<T> {
+    pub fn insert(&self, object_name: &str, row: T) {
+        let mut rows = self.rows.lock().unwrap();
+        let series = rows.entry(object_name.to_string()).or_default();
+        series.push(row);
+        series.sort_by_key(|r| r.time());
+    }
+}
+
+impl<T: MetricStoreRow + Clone> MetricStore<T> for InMemoryMetricStore<T> {
+    fn append_row(&self, name: &str, data: &T, _now: DateTime<Utc>) -> Result<()> {
+        self.insert(name, data.clone());
+        Ok(())
+    }
+
+    fn cleanup_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        if let Some(series) = self.rows.lock().unwrap().get_mut(name) {
+            series.retain(|r| r.time() >= before);
+        }
+        Ok(())
+    }
+
+    /// Whole rows; callers only read the requested column.
+    fn get_column_between(
+        &self,
+        _column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.get_row_between(start, end, object_name, limit, offset)
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        let rows = self.rows.lock().unwrap();
+        let in_range = rows
+            .get(object_name)
+            .into_iter()
+            .flatten()
+            .filter(|r| r.time() >= start && r.time() <= end)
+            .skip(offset.unwrap_or(0))
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+        Ok(in_range)
+    }
+}
+
+/// One in-memory store per metric tier.
+pub struct TierStores<T> {
+    tiers: Arc<Mutex<HashMap<MetricTier, InMemoryMetricStore<T>>>>,
+}
+
+impl<T> Clone for TierStores<T> {
+    fn clone(&self) -> Self {
+        Self { tiers: self.tiers.clone() }
+    }
+}
+
+impl<T> Default for TierStores<T> {
+    fn default() -> Self {
+        Self { tiers: Arc::new(Mutex::new(HashMap::new())) }
+    }
+}
+
+impl<T> TierStores<T> {
+    pub fn tier(&self, tier: MetricTier) -> InMemoryMetricStore<T> {
+        self.tiers.lock().unwrap().entry(tier).or_default().clone()
+    }
+}
+
+/// [`MetricStoreProvider`] over in-memory pod, node and container stores.
+#[derive(Clone, Default)]
+pub struct InMemoryMetricStores {
+    pub pod: TierStores<MetricPodEntity>,
+    pub node: TierStores<MetricNodeEntity>,
+    pub container: TierStores<MetricContainerEntity>,
+}
+
+impl MetricStoreProvider for InMemoryMetricStores {
+    fn pod(&self, tier: MetricTier) -> Box<dyn MetricStore<MetricPodEntity>> {
+        Box::new(self.pod.tier(tier))
+    }
+
+    fn node(&self, tier: MetricTier) -> Box<dyn MetricStore<MetricNodeEntity>> {
+        Box::new(self.node.tier(tier))
+    }
+
+    fn container(&self, tier: MetricTier) -> Box<dyn MetricStore<MetricContainerEntity>> {
+        Box::new(self.container.tier(tier))
+    }
+}
+
+/// Info records by key (pod UID, node name, container key).
+pub struct InMemoryInfoStore<T> {
+    records: BTreeMap<String, T>,
+}
+
+impl<T> InMemoryInfoStore<T> {
+    /// Records without a key are dropped.
+    pub fn new(records: impl IntoIterator<Item = T>, key: impl Fn(&T) -> Option<String>) -> Self {
+        Self {
+            records: records
+                .into_iter()
+                .filter_map(|r| Some((key(&r)?, r)))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> InfoStore<T> for InMemoryInfoStore<T> {
+    async fn read(&self, key: &str) -> Result<T> {
+        self.records
+            .get(key)
+            .cloned()
+            .ok_or_else(|| anyhow!("'{}' not found", key))
+    }
+
+    async fn list(&self) -> Result<Vec<T>> {
+        Ok(self.records.values().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn rows_are_read_back_in_time_order_within_the_window() {
+        let store = InMemoryMetricStore::<MetricPodEntity>::default();
+        let t0 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        for h in [2, 0, 1, 3] {
+            store.insert("pod-a", MetricPodEntity { time: t0 + Duration::hours(h), ..Default::default() });
+        }
+
+        let rows = store
+            .get_row_between(t0 + Duration::hours(1), t0 + Duration::hours(2), "pod-a", None, None)
+            .unwrap();
+        let hours: Vec<i64> = rows.iter().map(|r| (r.time - t0).num_hours()).collect();
+        assert_eq!(hours, vec![1, 2]);
+        assert!(store.get_row_between(t0, t0, "pod-b", None, None).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+//! Test support: deterministic fixture data for the metric domain.
+//!
+//! - `memory_store`: in-memory [`MetricStore`] and info stores plugged in through
+//!   [`Repositories`], so services run without files or a cluster
+//! - `fixtures`: a small synthetic cluster (nodes, pods, hourly series)
+//! - `golden`: JSON snapshot assertions for endpoint responses
+//!
+//! [`MetricStore`]: crate::core::persistence::metrics::metric_store_trait::MetricStore
+//! [`Repositories`]: crate::core::persistence::repositories::Repositories
+
+pub mod fixtures;
+pub mod golden;
+pub mod memory_store;
+
+mod golden_tests;